@@ -1,5 +1,7 @@
 //! Animate your applications.
+use crate::border::{self, Border};
 use crate::time::{Duration, Instant};
+use crate::{Background, Color, Shadow, Vector};
 
 pub use lilt::{Easing, FloatRepresentable as Float, Interpolable};
 
@@ -144,3 +146,90 @@ impl Animation<bool> {
         Duration::from_secs_f32(self.interpolate(self.duration.as_secs_f32(), 0.0, at))
     }
 }
+
+impl Interpolable for Color {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        // Interpolate in premultiplied space, so transparent colors
+        // do not tint the transition
+        let a = self.a.interpolated(other.a, ratio);
+
+        if a <= 0.0 {
+            return Color::TRANSPARENT;
+        }
+
+        let channel = |from: f32, to: f32| {
+            ((from * self.a).interpolated(to * other.a, ratio) / a).clamp(0.0, 1.0)
+        };
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a,
+        }
+    }
+}
+
+impl Interpolable for Vector {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolated(other.x, ratio),
+            self.y.interpolated(other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for border::Radius {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        border::Radius {
+            top_left: self.top_left.interpolated(other.top_left, ratio),
+            top_right: self.top_right.interpolated(other.top_right, ratio),
+            bottom_right: self.bottom_right.interpolated(other.bottom_right, ratio),
+            bottom_left: self.bottom_left.interpolated(other.bottom_left, ratio),
+        }
+    }
+}
+
+impl Interpolable for Border {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Border {
+            color: self.color.interpolated(other.color, ratio),
+            width: self.width.interpolated(other.width, ratio),
+            radius: self.radius.interpolated(other.radius, ratio),
+        }
+    }
+}
+
+impl Interpolable for Shadow {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Shadow {
+            color: self.color.interpolated(other.color, ratio),
+            offset: self.offset.interpolated(other.offset, ratio),
+            blur_radius: self.blur_radius.interpolated(other.blur_radius, ratio),
+        }
+    }
+}
+
+impl Interpolable for Background {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolated(b, ratio))
+            }
+            // Gradients are not interpolated; they snap halfway through
+            _ if ratio < 0.5 => *self,
+            _ => other,
+        }
+    }
+}
+
+/// Interpolates two optional [`Background`] values; fading the present one in or
+/// out if the other is missing.
+pub fn fade(from: Option<Background>, to: Option<Background>, ratio: f32) -> Option<Background> {
+    match (from, to) {
+        (Some(from), Some(to)) => Some(from.interpolated(to, ratio)),
+        (Some(from), None) => Some(from.scale_alpha(1.0 - ratio)),
+        (None, Some(to)) => Some(to.scale_alpha(ratio)),
+        (None, None) => None,
+    }
+}
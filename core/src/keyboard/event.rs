@@ -9,6 +9,7 @@ use crate::keyboard::{Key, Location, Modifiers};
 ///
 /// [open an issue]: https://github.com/iced-rs/iced/issues
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
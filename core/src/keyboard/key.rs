@@ -7,6 +7,7 @@ use crate::SmolStr;
 ///
 /// [`winit`]: https://docs.rs/winit/0.30/winit/keyboard/enum.Key.html
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Key<C = SmolStr> {
     /// A key with an established name.
     Named(Named),
@@ -129,6 +130,7 @@ impl From<Named> for Key {
 ///
 /// [`winit`]: https://docs.rs/winit/0.30/winit/keyboard/enum.Key.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum Named {
     /// The `Alt` (Alternative) key.
@@ -844,6 +846,7 @@ pub enum Named {
 ///
 /// [`KeyboardEvent.code`]: https://w3c.github.io/uievents-code/#code-value-tables
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub enum Code {
@@ -1304,6 +1307,7 @@ pub enum Code {
 /// - Correctly match key press and release events.
 /// - On non-web platforms, support assigning keybinds to virtually any key through a UI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NativeCode {
     /// An unidentified code.
     Unidentified,
@@ -1322,6 +1326,7 @@ pub enum NativeCode {
 /// This type is a superset of [`Code`], including an [`Unidentified`][Self::Unidentified]
 /// variant.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Physical {
     /// A known key code
     Code(Code),
@@ -1,9 +1,11 @@
 //! Position your widgets properly.
+mod direction;
 mod limits;
 mod node;
 
 pub mod flex;
 
+pub use direction::Direction;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -95,7 +97,7 @@ pub fn next_to_each_other(
         ((right_size.height - left_size.height) / 2.0, 0.0)
     };
 
-    Node::with_children(
+    Direction::current().orient(Node::with_children(
         Size::new(
             left_size.width + spacing + right_size.width,
             left_size.height.max(right_size.height),
@@ -104,7 +106,7 @@ pub fn next_to_each_other(
             left_node.move_to(Point::new(0.0, left_y)),
             right_node.move_to(Point::new(left_size.width + spacing, right_y)),
         ],
-    )
+    ))
 }
 
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
@@ -186,8 +188,8 @@ pub fn positioned(
         .shrink(padding)
         .resolve(width, height, content.size());
 
-    Node::with_children(
+    Direction::current().orient(Node::with_children(
         size.expand(padding),
         vec![position(content.move_to((padding.left, padding.top)), size)],
-    )
+    ))
 }
@@ -0,0 +1,97 @@
+use crate::layout::Node;
+
+use std::cell::Cell;
+
+/// The horizontal direction in which content flows.
+///
+/// When the [`Direction`] is [`RightToLeft`](Self::RightToLeft), layouts
+/// mirror the horizontal position of their children; so rows are ordered
+/// from right to left, paddings and alignments are swapped, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right.
+    #[default]
+    LeftToRight,
+    /// Content flows from right to left.
+    RightToLeft,
+}
+
+thread_local! {
+    static SCOPE: Cell<Option<Direction>> = const { Cell::new(None) };
+}
+
+impl Direction {
+    /// Returns the [`Direction`] layouts should currently follow.
+    ///
+    /// This is the [`Direction`] of the innermost [`scope`](Self::scope),
+    /// if any; or [`LeftToRight`](Self::LeftToRight) otherwise.
+    ///
+    /// A user interface lays out its widgets in the [`scope`](Self::scope)
+    /// of its own [`Direction`]; so every window can have a different one.
+    pub fn current() -> Self {
+        SCOPE.with(Cell::get).unwrap_or_default()
+    }
+
+    /// Runs the given closure with the [`Direction`] as the [`current`](Self::current) one.
+    ///
+    /// The previous [`Direction`] is restored even if the closure panics.
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<Direction>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SCOPE.with(|scope| scope.set(self.0));
+            }
+        }
+
+        let _restore = Restore(SCOPE.with(|scope| scope.replace(Some(self))));
+
+        f()
+    }
+
+    /// Returns `true` if the [`Direction`] is [`RightToLeft`](Self::RightToLeft).
+    pub fn is_right_to_left(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// Orients the children of the given [`Node`] according to the [`Direction`].
+    ///
+    /// Children are assumed to be laid out from left to right, and they are
+    /// [mirrored](Node::mirror) if the [`Direction`] is [`RightToLeft`](Self::RightToLeft).
+    pub fn orient(self, node: Node) -> Node {
+        match self {
+            Self::LeftToRight => node,
+            Self::RightToLeft => node.mirror(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_restores_the_scope_after_a_panic() {
+        let result = std::panic::catch_unwind(|| {
+            Direction::RightToLeft.scope(|| panic!("layout failed"));
+        });
+
+        assert!(result.is_err());
+        assert_eq!(Direction::current(), Direction::LeftToRight);
+    }
+
+    #[test]
+    fn it_nests_scopes() {
+        Direction::RightToLeft.scope(|| {
+            assert_eq!(Direction::current(), Direction::RightToLeft);
+
+            Direction::LeftToRight.scope(|| {
+                assert_eq!(Direction::current(), Direction::LeftToRight);
+            });
+
+            assert_eq!(Direction::current(), Direction::RightToLeft);
+        });
+
+        assert_eq!(Direction::current(), Direction::LeftToRight);
+    }
+}
@@ -18,7 +18,7 @@
 // limitations under the License.
 use crate::Element;
 
-use crate::layout::{Limits, Node};
+use crate::layout::{Direction, Limits, Node};
 use crate::widget;
 use crate::{Alignment, Length, Padding, Point, Size};
 
@@ -290,5 +290,5 @@ where
     let (intrinsic_width, intrinsic_height) = axis.pack(main - pad.0, cross);
     let size = limits.resolve(width, height, Size::new(intrinsic_width, intrinsic_height));
 
-    Node::with_children(size.expand(padding), nodes)
+    Direction::current().orient(Node::with_children(size.expand(padding), nodes))
 }
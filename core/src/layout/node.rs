@@ -78,6 +78,19 @@ impl Node {
         }
     }
 
+    /// Mirrors the children of the [`Node`] horizontally inside its bounds.
+    ///
+    /// The children of the children are left untouched.
+    pub fn mirror(mut self) -> Self {
+        let width = self.bounds.width;
+
+        for child in &mut self.children {
+            child.bounds.x = width - child.bounds.x - child.bounds.width;
+        }
+
+        self
+    }
+
     /// Moves the [`Node`] to the given position.
     pub fn move_to(mut self, position: impl Into<Point>) -> Self {
         self.move_to_mut(position);
@@ -5,6 +5,8 @@ use crate::svg;
 use crate::text::{self, Text};
 use crate::{Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation};
 
+use std::ops::Range;
+
 impl Renderer for () {
     fn start_layer(&mut self, _bounds: Rectangle) {}
 
@@ -186,6 +188,14 @@ impl text::Editor for () {
         0
     }
 
+    fn hit(&self, _point: Point) -> Option<text::editor::Position> {
+        None
+    }
+
+    fn range_bounds(&self, _line: usize, _range: Range<usize>) -> Vec<Rectangle> {
+        vec![]
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
     fn move_to(&mut self, _cursor: text::editor::Cursor) {}
@@ -40,6 +40,16 @@ pub struct Settings {
     ///
     /// By default, it is enabled.
     pub vsync: bool,
+
+    /// The maximum size, in bytes, of the decoded images that the renderer
+    /// may keep in memory.
+    ///
+    /// A bigger cache avoids decoding images again when scrolling back and
+    /// forth through a large collection, at the cost of memory. Every
+    /// window has its own cache of this size.
+    ///
+    /// By default, it is 64 MiB.
+    pub image_cache_size: usize,
 }
 
 impl Default for Settings {
@@ -51,6 +61,7 @@ impl Default for Settings {
             default_text_size: Pixels(16.0),
             antialiasing: true,
             vsync: true,
+            image_cache_size: 64 * 1024 * 1024,
         }
     }
 }
@@ -1,10 +1,16 @@
 //! Draw and interact with text.
 pub mod editor;
 pub mod highlighter;
+pub mod link;
 pub mod paragraph;
 
+mod hyphenation;
+mod measure;
+
 pub use editor::Editor;
 pub use highlighter::Highlighter;
+pub use hyphenation::{Hyphenation, SOFT_HYPHEN};
+pub use measure::{Ellipsis, measure, truncate};
 pub use paragraph::Paragraph;
 
 use crate::alignment;
@@ -4,6 +4,7 @@ use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::Arc;
 
 /// A component that can be used by widgets to edit multi-line text.
@@ -32,6 +33,14 @@ pub trait Editor: Sized + Default {
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the [`Position`] of the character at the given [`Point`],
+    /// if any.
+    fn hit(&self, point: Point) -> Option<Position>;
+
+    /// Returns the bounds of the given byte range of a line of the
+    /// [`Editor`]; one [`Rectangle`] per visual line.
+    fn range_bounds(&self, line: usize, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
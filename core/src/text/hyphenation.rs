@@ -0,0 +1,224 @@
+use rustc_hash::FxHashMap;
+
+use std::borrow::Cow;
+
+/// The soft hyphen character (`U+00AD`).
+///
+/// It marks a place where a word may be broken across lines. It is only
+/// drawn, as a regular hyphen, when a line is actually broken there.
+pub const SOFT_HYPHEN: char = '\u{AD}';
+
+/// A hyphenation dictionary for a language.
+///
+/// It finds the places where words may be broken using
+/// [Liang's algorithm](https://tug.org/docs/liang/) and the
+/// hyphenation patterns used by TeX, which are freely available
+/// for most languages.
+///
+/// # Example
+/// ```
+/// use iced_core::text::Hyphenation;
+///
+/// let english = Hyphenation::new("hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n");
+///
+/// assert_eq!(english.hyphenate_word("hyphenation"), vec![2, 6]);
+/// assert_eq!(english.hyphenate("Hyphenation!"), "Hy\u{AD}phen\u{AD}ation!");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Hyphenation {
+    patterns: FxHashMap<Box<str>, Box<[u8]>>,
+    exceptions: FxHashMap<Box<str>, Box<[usize]>>,
+    max_pattern: usize,
+    left_min: usize,
+    right_min: usize,
+}
+
+impl Hyphenation {
+    /// Creates a new [`Hyphenation`] dictionary from a list of TeX patterns
+    /// separated by whitespace; like `.ach4 1ba 4b1b`.
+    ///
+    /// By default, at least 2 letters are kept before a break and at least
+    /// 3 letters after it.
+    pub fn new(patterns: &str) -> Self {
+        let mut hyphenation = Self {
+            left_min: 2,
+            right_min: 3,
+            ..Self::default()
+        };
+
+        for pattern in patterns.split_whitespace() {
+            let mut letters = String::with_capacity(pattern.len());
+            let mut priorities = vec![0];
+
+            for c in pattern.chars() {
+                if let Some(priority) = c.to_digit(10) {
+                    *priorities.last_mut().expect("Priorities are never empty") = priority as u8;
+                } else {
+                    letters.push(c);
+                    priorities.push(0);
+                }
+            }
+
+            hyphenation.max_pattern = hyphenation.max_pattern.max(priorities.len() - 1);
+
+            let _ = hyphenation
+                .patterns
+                .insert(letters.into_boxed_str(), priorities.into_boxed_slice());
+        }
+
+        hyphenation
+    }
+
+    /// Adds a list of exceptions to the [`Hyphenation`] dictionary, separated
+    /// by whitespace and hyphenated explicitly; like `ta-ble project`.
+    ///
+    /// Exceptions take precedence over the patterns.
+    pub fn exceptions(mut self, exceptions: &str) -> Self {
+        for exception in exceptions.split_whitespace() {
+            let mut word = String::with_capacity(exception.len());
+            let mut breaks = Vec::new();
+
+            for c in exception.chars() {
+                if c == '-' {
+                    breaks.push(word.chars().count());
+                } else {
+                    word.extend(c.to_lowercase());
+                }
+            }
+
+            let _ = self
+                .exceptions
+                .insert(word.into_boxed_str(), breaks.into_boxed_slice());
+        }
+
+        self
+    }
+
+    /// Sets the minimum amount of letters kept before and after a break.
+    pub fn min(mut self, left: usize, right: usize) -> Self {
+        self.left_min = left.max(1);
+        self.right_min = right.max(1);
+        self
+    }
+
+    /// Returns the places where the given word may be broken, as indices of
+    /// the characters that would start a new line.
+    pub fn hyphenate_word(&self, word: &str) -> Vec<usize> {
+        // Letters are lowercased one by one, so indices are preserved
+        let letters: Vec<char> = word
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+
+        let length = letters.len();
+
+        if length < self.left_min + self.right_min {
+            return Vec::new();
+        }
+
+        let lowercase: String = letters.iter().collect();
+
+        if let Some(breaks) = self.exceptions.get(lowercase.as_str()) {
+            return breaks.to_vec();
+        }
+
+        let dotted: Vec<char> = std::iter::once('.')
+            .chain(letters)
+            .chain(std::iter::once('.'))
+            .collect();
+
+        let mut points = vec![0u8; dotted.len() + 1];
+        let mut candidate = String::with_capacity(self.max_pattern * 4);
+
+        for start in 0..dotted.len() {
+            candidate.clear();
+
+            for (offset, c) in dotted[start..].iter().take(self.max_pattern).enumerate() {
+                candidate.push(*c);
+
+                let Some(priorities) = self.patterns.get(candidate.as_str()) else {
+                    continue;
+                };
+
+                debug_assert_eq!(priorities.len(), offset + 2);
+
+                for (i, priority) in priorities.iter().enumerate() {
+                    let point = &mut points[start + i];
+                    *point = (*point).max(*priority);
+                }
+            }
+        }
+
+        // The point before the letter `i` of the word is `points[i + 1]`,
+        // because of the leading dot
+        (self.left_min..=length - self.right_min)
+            .filter(|i| points[i + 1] % 2 == 1)
+            .collect()
+    }
+
+    /// Inserts a [`SOFT_HYPHEN`] in every place the words of the given text
+    /// may be broken.
+    ///
+    /// Words that already contain a [`SOFT_HYPHEN`] are left untouched.
+    pub fn hyphenate<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut output = String::new();
+        let mut copied = 0;
+
+        for (start, word) in words(text) {
+            if word.contains(SOFT_HYPHEN) {
+                continue;
+            }
+
+            let breaks = self.hyphenate_word(word);
+
+            if breaks.is_empty() {
+                continue;
+            }
+
+            output.push_str(&text[copied..start]);
+
+            for (i, c) in word.chars().enumerate() {
+                if breaks.contains(&i) {
+                    output.push(SOFT_HYPHEN);
+                }
+
+                output.push(c);
+            }
+
+            copied = start + word.len();
+        }
+
+        if copied == 0 {
+            return Cow::Borrowed(text);
+        }
+
+        output.push_str(&text[copied..]);
+
+        Cow::Owned(output)
+    }
+}
+
+/// Returns the words of the given text, together with their byte offsets.
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = text.char_indices().peekable();
+
+    std::iter::from_fn(move || {
+        let (start, _) = rest.find(|(_, c)| is_letter(*c))?;
+        let mut end = text.len();
+
+        while let Some((i, c)) = rest.peek() {
+            if !is_letter(*c) {
+                end = *i;
+                break;
+            }
+
+            let _ = rest.next();
+        }
+
+        Some((start, &text[start..end]))
+    })
+}
+
+fn is_letter(c: char) -> bool {
+    c.is_alphabetic() || c == SOFT_HYPHEN
+}
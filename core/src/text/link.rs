@@ -0,0 +1,250 @@
+//! Detect links in plain text and report how they are clicked.
+use crate::keyboard;
+use crate::mouse;
+use crate::text::{Fragment, Span};
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// The kind of a detected link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A web address; like `https://iced.rs` or `www.iced.rs`.
+    Url,
+    /// An email address; like `hello@iced.rs`.
+    Email,
+}
+
+/// A link found in some text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The [`Kind`] of link.
+    pub kind: Kind,
+    /// The byte range of the link in the text.
+    pub range: Range<usize>,
+}
+
+impl Match {
+    /// Returns the URL the [`Match`] points to in the given text.
+    ///
+    /// Email addresses are prefixed with `mailto:` and addresses
+    /// starting with `www.` with `https://`.
+    pub fn url(&self, text: &str) -> String {
+        let link = &text[self.range.clone()];
+
+        match self.kind {
+            Kind::Email => format!("mailto:{link}"),
+            Kind::Url if link.starts_with("www.") => format!("https://{link}"),
+            Kind::Url => link.to_owned(),
+        }
+    }
+}
+
+/// Finds all the URLs and email addresses in the given text.
+pub fn find(text: &str) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut rest = text;
+    let mut offset = 0;
+
+    while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+        let end = rest[start..]
+            .find(char::is_whitespace)
+            .map_or(rest.len(), |end| start + end);
+
+        let word = &rest[start..end];
+        let trimmed = word.trim_start_matches(OPENING);
+        let link = trim_end(trimmed);
+
+        if let Some(kind) = classify(link) {
+            let start = offset + start + word.len() - trimmed.len();
+
+            matches.push(Match {
+                kind,
+                range: start..start + link.len(),
+            });
+        }
+
+        rest = &rest[end..];
+        offset += end;
+    }
+
+    matches
+}
+
+/// Splits the given spans so every URL and email address without a link
+/// becomes a span linking to it.
+///
+/// Spans that already have a link are left untouched.
+pub fn linkify<'a, Link, Font>(spans: &[Span<'a, Link, Font>]) -> Vec<Span<'a, Link, Font>>
+where
+    Link: Clone + From<String>,
+    Font: Clone,
+{
+    let mut linkified = Vec::with_capacity(spans.len());
+
+    for span in spans {
+        let matches = if span.link.is_none() {
+            find(&span.text)
+        } else {
+            Vec::new()
+        };
+
+        if matches.is_empty() {
+            linkified.push(span.clone());
+            continue;
+        }
+
+        let mut last = 0;
+
+        for link in matches {
+            if link.range.start > last {
+                linkified.push(Span {
+                    text: slice(&span.text, last..link.range.start),
+                    ..span.clone()
+                });
+            }
+
+            linkified.push(Span {
+                text: slice(&span.text, link.range.clone()),
+                link: Some(Link::from(link.url(&span.text))),
+                ..span.clone()
+            });
+
+            last = link.range.end;
+        }
+
+        if last < span.text.len() {
+            linkified.push(Span {
+                text: slice(&span.text, last..span.text.len()),
+                ..span.clone()
+            });
+        }
+    }
+
+    linkified
+}
+
+/// How a link was clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Click {
+    /// The mouse button used.
+    pub button: mouse::Button,
+    /// The keyboard modifiers pressed during the click.
+    pub modifiers: keyboard::Modifiers,
+}
+
+impl Click {
+    /// Returns true if the link was middle clicked or clicked while
+    /// holding the command key; which usually means opening it in the
+    /// background.
+    pub fn is_auxiliary(&self) -> bool {
+        self.button == mouse::Button::Middle || self.modifiers.command()
+    }
+}
+
+fn slice<'a>(text: &Fragment<'a>, range: Range<usize>) -> Fragment<'a> {
+    match text {
+        Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+        Cow::Owned(text) => Cow::Owned(text[range].to_owned()),
+    }
+}
+
+const OPENING: [char; 5] = ['(', '[', '<', '"', '\''];
+
+/// Removes trailing punctuation that is most likely not part of a link.
+fn trim_end(mut word: &str) -> &str {
+    loop {
+        let trimmed = word.trim_end_matches(['.', ',', ';', ':', '!', '?', '"', '\'', '>']);
+
+        let trimmed = match trimmed.chars().last() {
+            Some(close @ (')' | ']')) => {
+                let open = if close == ')' { '(' } else { '[' };
+
+                if trimmed.matches(open).count() < trimmed.matches(close).count() {
+                    &trimmed[..trimmed.len() - 1]
+                } else {
+                    trimmed
+                }
+            }
+            _ => trimmed,
+        };
+
+        if trimmed.len() == word.len() {
+            return word;
+        }
+
+        word = trimmed;
+    }
+}
+
+fn classify(word: &str) -> Option<Kind> {
+    const SCHEMES: &[&str] = &["https://", "http://", "ftp://", "mailto:"];
+
+    if let Some(scheme) = SCHEMES.iter().find(|scheme| word.starts_with(**scheme)) {
+        return (word.len() > scheme.len()).then_some(Kind::Url);
+    }
+
+    if let Some(domain) = word.strip_prefix("www.") {
+        let host = domain.split(['/', '?', '#']).next().unwrap_or_default();
+
+        return is_domain(host).then_some(Kind::Url);
+    }
+
+    let (local, domain) = word.split_once('@')?;
+
+    let is_local = !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "._%+-".contains(c));
+
+    (is_local && is_domain(domain)).then_some(Kind::Email)
+}
+
+fn is_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+
+    let Some(tld) = labels.last() else {
+        return false;
+    };
+
+    labels.len() >= 2
+        && tld.len() >= 2
+        && tld.chars().all(char::is_alphabetic)
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_links() {
+        let text = "Visit https://iced.rs/docs, (www.example.com) or mail hello@iced.rs! \
+            See https://en.wikipedia.org/wiki/Iced_(drink).";
+
+        let links: Vec<_> = find(text)
+            .into_iter()
+            .map(|link| (link.kind, link.url(text)))
+            .collect();
+
+        assert_eq!(
+            links,
+            vec![
+                (Kind::Url, "https://iced.rs/docs".to_owned()),
+                (Kind::Url, "https://www.example.com".to_owned()),
+                (Kind::Email, "mailto:hello@iced.rs".to_owned()),
+                (
+                    Kind::Url,
+                    "https://en.wikipedia.org/wiki/Iced_(drink)".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_ignores_plain_words() {
+        assert!(find("an @ sign, version 1.0, and www. alone").is_empty());
+    }
+}
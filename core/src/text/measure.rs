@@ -0,0 +1,90 @@
+use crate::text::{Paragraph, Text, Wrapping};
+use crate::{Size, alignment};
+
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The position of the ellipsis of some truncated text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsis {
+    /// Keep the end of the text; like `…/src/main.rs`.
+    Start,
+    /// Keep both ends of the text; like `~/Doc…/main.rs`.
+    ///
+    /// This is useful for paths and usernames, where both the
+    /// beginning and the end are meaningful.
+    Middle,
+    /// Keep the beginning of the text; like `A very long ti…`.
+    ///
+    /// This is the default.
+    #[default]
+    End,
+}
+
+/// Measures the given [`Text`] with the [`Paragraph`] implementation `P`,
+/// without building any widget.
+///
+/// The returned [`Size`] is the minimum size needed to fit the text
+/// within its bounds; the same one a text widget would use in layout.
+pub fn measure<P: Paragraph>(text: Text<&str, P::Font>) -> Size {
+    P::with_text(text).min_bounds()
+}
+
+/// Truncates the given [`Text`] to a single line that fits in the width
+/// of its bounds, replacing the removed graphemes with an ellipsis (`…`).
+///
+/// The text is measured with the [`Paragraph`] implementation `P`, so
+/// the result fits exactly when drawn with the same font and size.
+///
+/// If the text already fits, it is returned as is.
+pub fn truncate<'a, P: Paragraph>(
+    text: Text<&'a str, P::Font>,
+    ellipsis: Ellipsis,
+) -> Cow<'a, str> {
+    let max_width = text.bounds.width;
+
+    let single_line = Text {
+        bounds: Size::INFINITE,
+        wrapping: Wrapping::None,
+        align_y: alignment::Vertical::Top,
+        ..text
+    };
+
+    let fits = |content: &str| measure::<P>(single_line.with_content(content)).width <= max_width;
+
+    if fits(text.content) {
+        return Cow::Borrowed(text.content);
+    }
+
+    let graphemes: Vec<&str> = text.content.graphemes(true).collect();
+
+    let candidate = |kept: usize| -> String {
+        let (head, tail) = match ellipsis {
+            Ellipsis::Start => (0, kept),
+            Ellipsis::Middle => (kept.div_ceil(2), kept / 2),
+            Ellipsis::End => (kept, 0),
+        };
+
+        let mut candidate = graphemes[..head].concat();
+        candidate.push('…');
+        candidate.push_str(&graphemes[graphemes.len() - tail..].concat());
+
+        candidate
+    };
+
+    // Binary search for the largest amount of graphemes that fits
+    let mut low = 0;
+    let mut high = graphemes.len();
+
+    while low < high {
+        let middle = (low + high).div_ceil(2);
+
+        if fits(&candidate(middle)) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    Cow::Owned(candidate(low))
+}
@@ -3,6 +3,9 @@ use crate::alignment;
 use crate::text::{Alignment, Difference, Hit, LineHeight, Shaping, Span, Text, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -68,6 +71,30 @@ pub trait Paragraph: Sized + Default {
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Tests whether the provided point is within the boundaries of the
+    /// [`Paragraph`], returning the byte offset of the closest character
+    /// in its content.
+    ///
+    /// Unlike [`hit_test`](Self::hit_test), the offset accounts for every
+    /// line of the content.
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    /// Returns the bounds of the given byte range of the content of the
+    /// [`Paragraph`]; one [`Rectangle`] per visual line.
+    fn range_bounds(&self, _range: Range<usize>) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    /// Returns the positions where a line was broken at a soft hyphen,
+    /// relative to the top-left corner of the [`Paragraph`].
+    ///
+    /// A hyphen should be drawn at each position.
+    fn soft_hyphens(&self) -> Vec<Point> {
+        Vec::new()
+    }
+
     /// Returns the minimum width that can fit the contents of the [`Paragraph`].
     fn min_width(&self) -> f32 {
         self.min_bounds().width
@@ -84,6 +111,8 @@ pub trait Paragraph: Sized + Default {
 pub struct Plain<P: Paragraph> {
     raw: P,
     content: String,
+    max_lines: Option<usize>,
+    is_truncated: bool,
 }
 
 impl<P: Paragraph> Plain<P> {
@@ -92,6 +121,8 @@ impl<P: Paragraph> Plain<P> {
         Self {
             raw: P::with_text(text.as_ref()),
             content: text.content,
+            max_lines: None,
+            is_truncated: false,
         }
     }
 
@@ -99,25 +130,98 @@ impl<P: Paragraph> Plain<P> {
     ///
     /// Returns true if the [`Paragraph`] changed.
     pub fn update(&mut self, text: Text<&str, P::Font>) -> bool {
-        if self.content != text.content {
+        self.update_clamped(text, None)
+    }
+
+    /// Updates the plain [`Paragraph`] to match the given [`Text`], if needed;
+    /// clamping it to the given maximum amount of lines.
+    ///
+    /// If the [`Text`] does not fit, its last visible line will end with an
+    /// ellipsis (`…`) and the [`Plain`] paragraph will be
+    /// [truncated](Self::is_truncated).
+    ///
+    /// Returns true if the [`Paragraph`] changed.
+    pub fn update_clamped(&mut self, text: Text<&str, P::Font>, max_lines: Option<usize>) -> bool {
+        if self.content != text.content || self.max_lines != max_lines {
             text.content.clone_into(&mut self.content);
+            self.max_lines = max_lines;
             self.raw = P::with_text(text);
+            self.clamp(text);
             return true;
         }
 
         match self.raw.compare(text.with_content(())) {
             Difference::None => false,
-            Difference::Bounds => {
+            Difference::Bounds if self.max_lines.is_none() => {
                 self.raw.resize(text.bounds);
                 true
             }
-            Difference::Shape => {
+            Difference::Bounds | Difference::Shape => {
                 self.raw = P::with_text(text);
+                self.clamp(text);
                 true
             }
         }
     }
 
+    /// Replaces the [`Paragraph`] with the longest prefix of the [`Text`]
+    /// followed by an ellipsis that fits in the maximum amount of lines.
+    fn clamp(&mut self, text: Text<&str, P::Font>) {
+        self.is_truncated = false;
+
+        let Some(max_lines) = self.max_lines else {
+            return;
+        };
+
+        let max_height = text.line_height.to_absolute(text.size).0 * max_lines as f32;
+
+        // Some leeway for rounding errors of the text engine
+        let fits = |paragraph: &P| paragraph.min_bounds().height <= max_height + 0.5;
+
+        if fits(&self.raw) {
+            return;
+        }
+
+        let graphemes: Vec<(usize, &str)> = text.content.grapheme_indices(true).collect();
+
+        let candidate = |kept: usize| {
+            let end = graphemes
+                .get(kept)
+                .map_or(text.content.len(), |(offset, _)| *offset);
+
+            let mut candidate = text.content[..end].trim_end().to_owned();
+            candidate.push('…');
+
+            P::with_text(text.with_content(&candidate))
+        };
+
+        // Binary search for the largest amount of graphemes that fits
+        let mut low = 0;
+        let mut high = graphemes.len();
+        let mut best = None;
+
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+            let paragraph = candidate(middle);
+
+            if fits(&paragraph) {
+                low = middle;
+                best = Some(paragraph);
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        self.raw = best.unwrap_or_else(|| candidate(low));
+        self.is_truncated = true;
+    }
+
+    /// Returns true if the [`Plain`] paragraph was truncated to fit its
+    /// maximum amount of lines.
+    pub fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
     /// Returns the horizontal alignment of the [`Paragraph`].
     pub fn align_x(&self) -> Alignment {
         self.raw.align_x()
@@ -4,6 +4,7 @@ pub mod palette;
 pub use palette::Palette;
 
 use crate::Color;
+use crate::image;
 
 use std::borrow::Cow;
 use std::fmt;
@@ -102,6 +103,35 @@ impl Theme {
         Self::Custom(Arc::new(Custom::with_fn(name, palette, generate)))
     }
 
+    /// Creates a new custom [`Theme`] with a [`Palette`] generated from the
+    /// given accent [`Color`] for the given [`Mode`].
+    ///
+    /// See [`Palette::from_accent`] for more details.
+    pub fn from_accent(accent: Color, mode: Mode) -> Self {
+        Self::custom(
+            format!("Accent {accent} ({mode:?})"),
+            Palette::from_accent(accent, mode),
+        )
+    }
+
+    /// Creates a new custom [`Theme`] with a [`Palette`] generated from the
+    /// dominant color of the given [`image::Handle`]; like a wallpaper.
+    ///
+    /// Only decoded images, created with [`image::Handle::from_rgba`], can be
+    /// sampled. Returns `None` otherwise, or if the image is fully transparent.
+    pub fn from_image(image: &image::Handle, mode: Mode) -> Option<Self> {
+        let image::Handle::Rgba { pixels, .. } = image else {
+            return None;
+        };
+
+        let accent = palette::dominant(pixels)?;
+
+        Some(Self::custom(
+            format!("Image {accent} ({mode:?})"),
+            Palette::from_accent(accent, mode),
+        ))
+    }
+
     /// Returns the [`Palette`] of the [`Theme`].
     pub fn palette(&self) -> Palette {
         match self {
@@ -1,6 +1,8 @@
 //! Define the colors of a theme.
+use crate::theme::Mode;
 use crate::{Color, color};
 
+use std::f32::consts::{PI, TAU};
 use std::sync::LazyLock;
 
 /// A color palette.
@@ -281,6 +283,71 @@ impl Palette {
         warning: color!(0xf5d76e), // Honey
         danger: color!(0xe06b75),
     };
+
+    /// Generates a [`Palette`] from an accent [`Color`] for the given [`Mode`].
+    ///
+    /// The background and text colors are tinted neutrals with the hue of
+    /// the accent; while the success, warning, and danger colors are
+    /// harmonized towards it. The text color is guaranteed to be readable
+    /// on top of the background.
+    ///
+    /// [`Mode::None`] produces a light [`Palette`].
+    pub fn from_accent(accent: Color, mode: Mode) -> Self {
+        let accent = to_oklch(Color { a: 1.0, ..accent });
+        let is_dark = mode == Mode::Dark;
+
+        let neutral = |l: f32, tint: f32| {
+            from_oklch(Oklch {
+                l,
+                c: accent.c.min(0.1) * tint,
+                h: accent.h,
+                a: 1.0,
+            })
+        };
+
+        let (background, text) = if is_dark {
+            (neutral(0.2, 0.2), neutral(0.94, 0.1))
+        } else {
+            (neutral(0.985, 0.1), neutral(0.2, 0.1))
+        };
+
+        let (min_lightness, max_lightness) = if is_dark { (0.62, 0.82) } else { (0.4, 0.6) };
+
+        let primary = from_oklch(Oklch {
+            l: accent.l.clamp(min_lightness, max_lightness),
+            ..accent
+        });
+
+        let semantic = |hue: f32| {
+            // Rotate the hue towards the accent, up to 15 degrees
+            let difference = (accent.h - hue + PI).rem_euclid(TAU) - PI;
+            let rotation = (difference.abs() * 0.5).min(15f32.to_radians());
+
+            from_oklch(Oklch {
+                l: if is_dark { 0.72 } else { 0.52 },
+                c: accent.c.clamp(0.1, 0.16),
+                h: hue + rotation.copysign(difference),
+                a: 1.0,
+            })
+        };
+
+        Self {
+            background,
+            text: readable(background, text),
+            primary,
+            success: semantic(145f32.to_radians()),
+            warning: semantic(75f32.to_radians()),
+            danger: semantic(27f32.to_radians()),
+        }
+    }
+
+    /// Generates a [`Palette`] from the dominant color of the given RGBA
+    /// pixels for the given [`Mode`].
+    ///
+    /// Returns `None` if the pixels are all transparent.
+    pub fn from_pixels(pixels: &[u8], mode: Mode) -> Option<Self> {
+        Some(Self::from_accent(dominant(pixels)?, mode))
+    }
 }
 
 /// An extended set of colors generated from a [`Palette`].
@@ -701,6 +768,84 @@ pub fn readable(background: Color, text: Color) -> Color {
     }
 }
 
+/// Returns the given [`Color`] with its perceptual lightness set to the
+/// given value; keeping its hue and chroma.
+///
+/// The lightness is a value in [0, 1]. This can be used to build the tonal
+/// ramp of a [`Color`].
+pub fn tone(color: Color, lightness: f32) -> Color {
+    from_oklch(Oklch {
+        l: lightness.clamp(0.0, 1.0),
+        ..to_oklch(color)
+    })
+}
+
+/// Returns the dominant [`Color`] of the given RGBA pixels.
+///
+/// Vivid colors are preferred over neutral ones, so the result is suitable
+/// as an accent. If the pixels are mostly neutral, their average color is
+/// returned instead.
+///
+/// Returns `None` if the pixels are all transparent.
+pub fn dominant(pixels: &[u8]) -> Option<Color> {
+    const BINS: usize = 36;
+    const MAX_SAMPLES: usize = 10_000;
+
+    let total = pixels.len() / 4;
+    let step = total.div_ceil(MAX_SAMPLES).max(1);
+
+    let mut bins = [(0.0f32, 0.0f32, 0.0f32, 0.0f32); BINS];
+    let mut average = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+
+    for pixel in pixels.chunks_exact(4).step_by(step) {
+        if pixel[3] < 128 {
+            continue;
+        }
+
+        let oklch = to_oklch(Color::from_rgb8(pixel[0], pixel[1], pixel[2]));
+        let (a, b) = (oklch.c * oklch.h.cos(), oklch.c * oklch.h.sin());
+
+        average.0 += oklch.l;
+        average.1 += a;
+        average.2 += b;
+        average.3 += 1.0;
+
+        if oklch.c < 0.03 || oklch.l < 0.15 || oklch.l > 0.95 {
+            continue;
+        }
+
+        let bin = ((oklch.h.rem_euclid(TAU) / TAU) * BINS as f32) as usize % BINS;
+
+        // Favor vivid colors with balanced lightness
+        let weight = oklch.c * (1.0 - (oklch.l - 0.6).abs());
+
+        bins[bin].0 += oklch.l * weight;
+        bins[bin].1 += a * weight;
+        bins[bin].2 += b * weight;
+        bins[bin].3 += weight;
+    }
+
+    if average.3 == 0.0 {
+        return None;
+    }
+
+    let (l, a, b, weight) = bins
+        .iter()
+        .copied()
+        .max_by(|a, b| a.3.total_cmp(&b.3))
+        .filter(|(_, _, _, weight)| *weight > 0.0)
+        .unwrap_or(average);
+
+    let (l, a, b) = (l / weight, a / weight, b / weight);
+
+    Some(from_oklch(Oklch {
+        l,
+        c: (a * a + b * b).sqrt(),
+        h: b.atan2(a),
+        a: 1.0,
+    }))
+}
+
 /// Returns true if the [`Color`] is dark.
 pub fn is_dark(color: Color) -> bool {
     to_oklch(color).l < 0.6
@@ -760,3 +905,35 @@ fn from_oklch(oklch: Oklch) -> Color {
         alpha,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_accent() {
+        let accent = color!(0x6c4cf5);
+
+        let light = Palette::from_accent(accent, Mode::Light);
+        let dark = Palette::from_accent(accent, Mode::Dark);
+
+        assert!(!is_dark(light.background));
+        assert!(is_dark(dark.background));
+
+        assert!(light.text.is_readable_on(light.background));
+        assert!(dark.text.is_readable_on(dark.background));
+    }
+
+    #[test]
+    fn dominant_color() {
+        let red = [200, 30, 30, 255];
+        let gray = [128, 128, 128, 255];
+        let transparent = [0, 0, 255, 0];
+
+        let pixels: Vec<u8> = [red, gray, gray, transparent].concat();
+        let [r, g, b, _] = dominant(&pixels).expect("dominant color").into_rgba8();
+
+        assert!(r > g && r > b);
+        assert_eq!(dominant(&transparent), None);
+    }
+}
@@ -1,8 +1,11 @@
 //! Query or update internal widget state.
+pub mod bounds;
 pub mod focusable;
 pub mod scrollable;
+pub mod text;
 pub mod text_input;
 
+pub use bounds::Bounds;
 pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
@@ -29,6 +32,12 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a widget that contains other widgets.
     fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {}
 
+    /// Sets the tab index of the focusable widgets of the last container.
+    ///
+    /// It must be called after [`container`](Self::container) and before
+    /// traversing its children.
+    fn tab_index(&mut self, _index: i32) {}
+
     /// Operates on a widget that can be scrolled.
     fn scrollable(
         &mut self,
@@ -70,6 +79,10 @@ where
         self.as_mut().container(id, bounds);
     }
 
+    fn tab_index(&mut self, index: i32) {
+        self.as_mut().tab_index(index);
+    }
+
     fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Focusable) {
         self.as_mut().focusable(id, bounds, state);
     }
@@ -151,6 +164,10 @@ where
             self.operation.container(id, bounds);
         }
 
+        fn tab_index(&mut self, index: i32) {
+            self.operation.tab_index(index);
+        }
+
         fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Focusable) {
             self.operation.focusable(id, bounds, state);
         }
@@ -225,6 +242,12 @@ where
                     operation.container(id, bounds);
                 }
 
+                fn tab_index(&mut self, index: i32) {
+                    let Self { operation, .. } = self;
+
+                    operation.tab_index(index);
+                }
+
                 fn scrollable(
                     &mut self,
                     id: Option<&Id>,
@@ -273,6 +296,10 @@ where
             self.operation.container(id, bounds);
         }
 
+        fn tab_index(&mut self, index: i32) {
+            self.operation.tab_index(index);
+        }
+
         fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Focusable) {
             self.operation.focusable(id, bounds, state);
         }
@@ -354,6 +381,10 @@ where
             self.operation.container(id, bounds);
         }
 
+        fn tab_index(&mut self, index: i32) {
+            self.operation.tab_index(index);
+        }
+
         fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Focusable) {
             self.operation.focusable(id, bounds, state);
         }
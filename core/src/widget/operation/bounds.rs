@@ -0,0 +1,134 @@
+//! Query the bounds and the visibility of widgets.
+use crate::widget::Id;
+use crate::widget::operation::{self, Focusable, Operation, Outcome, Scrollable, TextInput};
+use crate::{Rectangle, Vector};
+
+use std::any::Any;
+
+/// The bounds of a widget in a widget tree.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bounds {
+    /// The bounds of the widget, in window coordinates.
+    ///
+    /// The scroll offsets of its ancestors are already applied.
+    pub bounds: Rectangle,
+
+    /// The part of the widget that is not clipped by the scrollables
+    /// containing it, in window coordinates; if any.
+    pub visible_bounds: Option<Rectangle>,
+}
+
+impl Bounds {
+    /// Returns true if any part of the widget is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible_bounds
+            .is_some_and(|bounds| bounds.width > 0.0 && bounds.height > 0.0)
+    }
+
+    /// Returns true if the whole widget is visible.
+    pub fn is_fully_visible(&self) -> bool {
+        self.visible_bounds == Some(self.bounds)
+    }
+}
+
+/// Produces an [`Operation`] that returns the [`Bounds`] of the widget
+/// with the given [`Id`].
+pub fn find(target: Id) -> impl Operation<Bounds> {
+    struct Find {
+        target: Id,
+        stack: Vec<(Rectangle, Vector)>,
+        viewport: Rectangle,
+        translation: Vector,
+        bounds: Option<Bounds>,
+    }
+
+    impl Find {
+        fn visit(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            if self.bounds.is_none() && id == Some(&self.target) {
+                let bounds = bounds + self.translation;
+
+                self.bounds = Some(Bounds {
+                    bounds,
+                    visible_bounds: self.viewport.intersection(&bounds),
+                });
+            }
+        }
+    }
+
+    impl Operation<Bounds> for Find {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Bounds>)) {
+            if self.bounds.is_some() {
+                return;
+            }
+
+            self.stack.push((self.viewport, self.translation));
+            operate(self);
+            let _ = self.stack.pop();
+
+            // Siblings are not affected by the scrollables of their children
+            if let Some((viewport, translation)) = self.stack.last() {
+                self.viewport = *viewport;
+                self.translation = *translation;
+            }
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.visit(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            self.visit(id, bounds);
+
+            // The contents of a scrollable are clipped by it and offset
+            // by its scroll
+            self.viewport = self
+                .viewport
+                .intersection(&(bounds + self.translation))
+                .unwrap_or_default();
+
+            self.translation -= translation;
+        }
+
+        fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn Focusable) {
+            self.visit(id, bounds);
+        }
+
+        fn text_input(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn TextInput) {
+            self.visit(id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, _text: &str) {
+            self.visit(id, bounds);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn Any) {
+            self.visit(id, bounds);
+        }
+
+        fn finish(&self) -> Outcome<Bounds> {
+            self.bounds.map_or(Outcome::None, Outcome::Some)
+        }
+    }
+
+    Find {
+        target,
+        stack: vec![(Rectangle::INFINITE, Vector::ZERO)],
+        viewport: Rectangle::INFINITE,
+        translation: Vector::ZERO,
+        bounds: None,
+    }
+}
+
+/// Produces an [`Operation`] that returns whether any part of the widget
+/// with the given [`Id`] is visible; that is, not clipped by the scrollables
+/// containing it.
+pub fn is_visible(target: Id) -> impl Operation<bool> {
+    operation::map(find(target), |bounds| bounds.is_visible())
+}
@@ -101,71 +101,186 @@ pub fn count() -> impl Operation<Count> {
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
+///
+/// Focusable widgets are visited in ascending order of their tab index; and
+/// in tree order for equal indices. Widgets with a negative tab index are
+/// skipped.
 pub fn focus_previous<T>() -> impl Operation<T>
 where
     T: Send + 'static,
 {
-    struct FocusPrevious {
-        count: Count,
-        current: usize,
-    }
-
-    impl<T> Operation<T> for FocusPrevious {
-        fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
-            if self.count.total == 0 {
-                return;
-            }
-
-            match self.count.focused {
-                None if self.current == self.count.total - 1 => state.focus(),
-                Some(0) if self.current == 0 => state.unfocus(),
-                Some(0) => {}
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused - 1 == self.current => state.focus(),
-                _ => {}
-            }
-
-            self.current += 1;
-        }
-
-        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
-            operate(self);
-        }
-    }
-
-    operation::then(count(), |count| FocusPrevious { count, current: 0 })
+    operation::then(Order::new(None, Direction::Previous), Apply::new)
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the next focusable widget.
 /// - if not found, focuses the first focusable widget.
+///
+/// Focusable widgets are visited in ascending order of their tab index; and
+/// in tree order for equal indices. Widgets with a negative tab index are
+/// skipped.
 pub fn focus_next<T>() -> impl Operation<T>
 where
     T: Send + 'static,
 {
-    struct FocusNext {
-        count: Count,
-        current: usize,
+    operation::then(Order::new(None, Direction::Next), Apply::new)
+}
+
+/// Produces an [`Operation`] that behaves like [`focus_previous`], but only
+/// among the focusable widgets inside the container with the given [`Id`].
+///
+/// Any focused widget outside of the group is unfocused.
+pub fn focus_previous_in<T>(group: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    operation::then(Order::new(Some(group), Direction::Previous), Apply::new)
+}
+
+/// Produces an [`Operation`] that behaves like [`focus_next`], but only
+/// among the focusable widgets inside the container with the given [`Id`].
+///
+/// Any focused widget outside of the group is unfocused.
+pub fn focus_next_in<T>(group: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    operation::then(Order::new(Some(group), Direction::Next), Apply::new)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Previous,
+    Next,
+}
+
+/// The tab index and group of the widgets being traversed.
+#[derive(Debug, Clone, Copy)]
+struct Scope {
+    tab_index: i32,
+    is_in_group: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    scope: Scope,
+    is_focused: bool,
+}
+
+/// Finds the position in tree order of the widget to focus next.
+struct Order {
+    group: Option<Id>,
+    direction: Direction,
+    current: Scope,
+    pending: Scope,
+    entries: Vec<Entry>,
+}
+
+impl Order {
+    fn new(group: Option<Id>, direction: Direction) -> Self {
+        let scope = Scope {
+            tab_index: 0,
+            is_in_group: group.is_none(),
+        };
+
+        Self {
+            group,
+            direction,
+            current: scope,
+            pending: scope,
+            entries: Vec::new(),
+        }
     }
+}
 
-    impl<T> Operation<T> for FocusNext {
-        fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
-            match self.count.focused {
-                None if self.current == 0 => state.focus(),
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused + 1 == self.current => state.focus(),
-                _ => {}
-            }
+impl Operation<Option<usize>> for Order {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Option<usize>>)) {
+        let parent = self.current;
+
+        self.current = self.pending;
+        operate(self);
 
-            self.current += 1;
+        self.current = parent;
+        self.pending = parent;
+    }
+
+    fn container(&mut self, id: Option<&Id>, _bounds: Rectangle) {
+        self.pending = Scope {
+            is_in_group: self.current.is_in_group
+                || id.is_some_and(|id| Some(id) == self.group.as_ref()),
+            ..self.current
+        };
+    }
+
+    fn tab_index(&mut self, index: i32) {
+        self.pending.tab_index = index;
+    }
+
+    fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+        self.entries.push(Entry {
+            scope: self.current,
+            is_focused: state.is_focused(),
+        });
+    }
+
+    fn finish(&self) -> Outcome<Option<usize>> {
+        let mut order: Vec<usize> = (0..self.entries.len())
+            .filter(|&i| {
+                let scope = self.entries[i].scope;
+
+                scope.is_in_group && scope.tab_index >= 0
+            })
+            .collect();
+
+        if order.is_empty() {
+            return Outcome::None;
         }
 
-        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
-            operate(self);
+        // The sort is stable; so tree order is kept for equal indices
+        order.sort_by_key(|&i| self.entries[i].scope.tab_index);
+
+        let focused = order.iter().position(|&i| self.entries[i].is_focused);
+
+        let target = match (self.direction, focused) {
+            (Direction::Next, None) => order.first(),
+            (Direction::Next, Some(focused)) => order.get(focused + 1),
+            (Direction::Previous, None) => order.last(),
+            (Direction::Previous, Some(focused)) => focused
+                .checked_sub(1)
+                .and_then(|previous| order.get(previous)),
+        };
+
+        Outcome::Some(target.copied())
+    }
+}
+
+/// Focuses the widget at the given position in tree order and unfocuses the
+/// rest.
+struct Apply {
+    target: Option<usize>,
+    current: usize,
+}
+
+impl Apply {
+    fn new(target: Option<usize>) -> Self {
+        Self { target, current: 0 }
+    }
+}
+
+impl<T> Operation<T> for Apply {
+    fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+        if Some(self.current) == self.target {
+            state.focus();
+        } else {
+            state.unfocus();
         }
+
+        self.current += 1;
     }
 
-    operation::then(count(), |count| FocusNext { count, current: 0 })
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget
@@ -232,3 +347,67 @@ pub fn is_focused(target: Id) -> impl Operation<bool> {
         is_focused: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Widget(bool);
+
+    impl Focusable for Widget {
+        fn is_focused(&self) -> bool {
+            self.0
+        }
+
+        fn focus(&mut self) {
+            self.0 = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.0 = false;
+        }
+    }
+
+    /// Runs the operation over a group with the given [`Id`] and tab index,
+    /// followed by a widget outside of it.
+    fn run(operation: &mut dyn Operation<Option<usize>>, widgets: &mut [Widget; 3]) {
+        let [first, second, outside] = widgets;
+
+        operation.container(Some(&Id::new("group")), Rectangle::default());
+        operation.tab_index(1);
+        operation.traverse(&mut |operation| {
+            operation.focusable(None, Rectangle::default(), first);
+            operation.focusable(None, Rectangle::default(), second);
+        });
+
+        operation.focusable(None, Rectangle::default(), outside);
+    }
+
+    #[test]
+    fn it_orders_by_tab_index() {
+        let mut widgets = [Widget(false), Widget(false), Widget(true)];
+        let mut order = Order::new(None, Direction::Next);
+
+        run(&mut order, &mut widgets);
+
+        // The widget outside has a lower tab index; so the group follows it
+        assert!(matches!(order.finish(), Outcome::Some(Some(0))));
+    }
+
+    #[test]
+    fn it_navigates_within_a_group() {
+        let mut widgets = [Widget(false), Widget(true), Widget(false)];
+        let mut order = Order::new(Some(Id::new("group")), Direction::Previous);
+
+        run(&mut order, &mut widgets);
+
+        assert!(matches!(order.finish(), Outcome::Some(Some(0))));
+
+        let mut widgets = [Widget(false), Widget(false), Widget(true)];
+        let mut order = Order::new(Some(Id::new("group")), Direction::Next);
+
+        run(&mut order, &mut widgets);
+
+        assert!(matches!(order.finish(), Outcome::Some(Some(0))));
+    }
+}
@@ -1,4 +1,7 @@
 //! Operate on widgets that can be scrolled.
+use crate::animation::Easing;
+use crate::time::Duration;
+use crate::widget::operation::Outcome;
 use crate::widget::{Id, Operation};
 use crate::{Rectangle, Vector};
 
@@ -12,6 +15,82 @@ pub trait Scrollable {
 
     /// Scroll the widget by the given [`AbsoluteOffset`] along the horizontal & vertical axis.
     fn scroll_by(&mut self, offset: AbsoluteOffset, bounds: Rectangle, content_bounds: Rectangle);
+
+    /// Snaps the scroll of the widget to the given `percentage` along the horizontal & vertical axis,
+    /// animating the transition with the given [`Motion`].
+    ///
+    /// By default, the widget snaps instantly.
+    fn snap_to_animated(
+        &mut self,
+        offset: RelativeOffset<Option<f32>>,
+        motion: Motion,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let _ = (motion, bounds, content_bounds);
+
+        self.snap_to(offset);
+    }
+
+    /// Scroll the widget to the given [`AbsoluteOffset`] along the horizontal & vertical axis,
+    /// animating the transition with the given [`Motion`].
+    ///
+    /// By default, the widget scrolls instantly.
+    fn scroll_to_animated(
+        &mut self,
+        offset: AbsoluteOffset<Option<f32>>,
+        motion: Motion,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let _ = (motion, bounds, content_bounds);
+
+        self.scroll_to(offset);
+    }
+}
+
+/// The motion of an animated scroll.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Motion {
+    /// The duration of the scroll.
+    pub duration: Duration,
+    /// The [`Easing`] function of the scroll.
+    pub easing: Easing,
+}
+
+impl Motion {
+    /// Creates a new [`Motion`] with the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the [`Easing`] function of the [`Motion`].
+    pub fn easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Returns the progress of the [`Motion`] after the given elapsed time,
+    /// eased and between 0 and 1.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration {
+            return 1.0;
+        }
+
+        self.easing
+            .value(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+}
+
+impl Default for Motion {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(250),
+            easing: Easing::EaseOut,
+        }
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -74,6 +153,84 @@ pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset<Option<f32>>) -> impl Ope
     ScrollTo { target, offset }
 }
 
+/// Produces an [`Operation`] that smoothly snaps the widget with the given [`Id`] to
+/// the provided `percentage`, with the given [`Motion`].
+pub fn snap_to_animated<T>(
+    target: Id,
+    offset: RelativeOffset<Option<f32>>,
+    motion: Motion,
+) -> impl Operation<T> {
+    struct SnapToAnimated {
+        target: Id,
+        offset: RelativeOffset<Option<f32>>,
+        motion: Motion,
+    }
+
+    impl<T> Operation<T> for SnapToAnimated {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            _translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            if Some(&self.target) == id {
+                state.snap_to_animated(self.offset, self.motion, bounds, content_bounds);
+            }
+        }
+    }
+
+    SnapToAnimated {
+        target,
+        offset,
+        motion,
+    }
+}
+
+/// Produces an [`Operation`] that smoothly scrolls the widget with the given [`Id`] to
+/// the provided [`AbsoluteOffset`], with the given [`Motion`].
+pub fn scroll_to_animated<T>(
+    target: Id,
+    offset: AbsoluteOffset<Option<f32>>,
+    motion: Motion,
+) -> impl Operation<T> {
+    struct ScrollToAnimated {
+        target: Id,
+        offset: AbsoluteOffset<Option<f32>>,
+        motion: Motion,
+    }
+
+    impl<T> Operation<T> for ScrollToAnimated {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            _translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            if Some(&self.target) == id {
+                state.scroll_to_animated(self.offset, self.motion, bounds, content_bounds);
+            }
+        }
+    }
+
+    ScrollToAnimated {
+        target,
+        offset,
+        motion,
+    }
+}
+
 /// Produces an [`Operation`] that scrolls the widget with the given [`Id`] by
 /// the provided [`AbsoluteOffset`].
 pub fn scroll_by<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
@@ -104,6 +261,50 @@ pub fn scroll_by<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
     ScrollBy { target, offset }
 }
 
+/// Produces an [`Operation`] that returns the current [`AbsoluteOffset`] of
+/// the widget with the given [`Id`].
+pub fn offset(target: Id) -> impl Operation<AbsoluteOffset> {
+    struct Offset {
+        target: Id,
+        offset: Option<AbsoluteOffset>,
+    }
+
+    impl Operation<AbsoluteOffset> for Offset {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<AbsoluteOffset>)) {
+            if self.offset.is_some() {
+                return;
+            }
+
+            operate(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            if Some(&self.target) == id {
+                self.offset = Some(AbsoluteOffset {
+                    x: translation.x,
+                    y: translation.y,
+                });
+            }
+        }
+
+        fn finish(&self) -> Outcome<AbsoluteOffset> {
+            self.offset.map_or(Outcome::None, Outcome::Some)
+        }
+    }
+
+    Offset {
+        target,
+        offset: None,
+    }
+}
+
 /// The amount of absolute offset in each direction of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct AbsoluteOffset<T = f32> {
@@ -0,0 +1,52 @@
+//! Query the state of widgets displaying text.
+use crate::Rectangle;
+use crate::widget::Id;
+use crate::widget::operation::{Operation, Outcome};
+
+use std::any::Any;
+
+/// The clamping state of a widget displaying text.
+///
+/// Widgets with a maximum amount of lines provide it to
+/// [`Operation::custom`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Clamp {
+    /// Whether the text was truncated to fit the maximum amount of lines.
+    pub is_truncated: bool,
+}
+
+/// Produces an [`Operation`] that returns whether the text of the widget
+/// with the given [`Id`] was truncated to fit its maximum amount of lines.
+pub fn is_truncated(target: Id) -> impl Operation<bool> {
+    struct IsTruncated {
+        target: Id,
+        is_truncated: Option<bool>,
+    }
+
+    impl Operation<bool> for IsTruncated {
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id.is_some_and(|id| *id == self.target)
+                && let Some(clamp) = state.downcast_ref::<Clamp>()
+            {
+                self.is_truncated = Some(clamp.is_truncated);
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<bool>)) {
+            if self.is_truncated.is_some() {
+                return;
+            }
+
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<bool> {
+            self.is_truncated.map_or(Outcome::None, Outcome::Some)
+        }
+    }
+
+    IsTruncated {
+        target,
+        is_truncated: None,
+    }
+}
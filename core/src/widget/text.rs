@@ -21,13 +21,22 @@
 //! }
 //! ```
 use crate::alignment;
+use crate::clipboard;
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
+use crate::widget;
 use crate::widget::tree::{self, Tree};
-use crate::{Color, Element, Layout, Length, Pixels, Rectangle, Size, Theme, Widget};
+use crate::{
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Theme,
+    Vector, Widget,
+};
+
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub use text::{Alignment, LineHeight, Shaping, Wrapping};
 
@@ -58,8 +67,10 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     fragment: text::Fragment<'a>,
     format: Format<Renderer::Font>,
+    is_selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -71,12 +82,20 @@ where
     /// Create a new fragment of [`Text`] with the given contents.
     pub fn new(fragment: impl text::IntoFragment<'a>) -> Self {
         Text {
+            id: None,
             fragment: fragment.into_fragment(),
             format: Format::default(),
+            is_selectable: false,
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Text`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the size of the [`Text`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.format.size = Some(size.into());
@@ -147,6 +166,40 @@ where
         self
     }
 
+    /// Sets the maximum amount of lines of the [`Text`].
+    ///
+    /// If the [`Text`] does not fit, its last line will end with an
+    /// ellipsis (`…`). You can check whether this happened with the
+    /// [`is_truncated`] operation.
+    ///
+    /// [`is_truncated`]: widget::operation::text::is_truncated
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.format.max_lines = Some(max_lines.max(1));
+        self
+    }
+
+    /// Sets the [`Hyphenation`] dictionary of the [`Text`].
+    ///
+    /// Words will be broken across lines where the dictionary allows it,
+    /// and a hyphen will be drawn at the end of each broken line. This
+    /// pairs well with [`Alignment::Justified`] in narrow layouts.
+    ///
+    /// [`Hyphenation`]: text::Hyphenation
+    pub fn hyphenation(mut self, hyphenation: &'static text::Hyphenation) -> Self {
+        self.format.hyphenation = Some(hyphenation);
+        self
+    }
+
+    /// Makes the [`Text`] selectable.
+    ///
+    /// The contents of a selectable [`Text`] can be selected by dragging
+    /// the mouse over them, double clicking a word, or triple clicking a
+    /// line. The selection can be copied to the clipboard with `Ctrl+C`.
+    pub fn selectable(mut self) -> Self {
+        self.is_selectable = true;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -172,7 +225,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            selection: None,
+        })
     }
 
     /// Sets the style class of the [`Text`].
@@ -187,17 +243,111 @@ where
 /// The internal state of a [`Text`] widget.
 pub type State<P> = paragraph::Plain<P>;
 
+/// The internal state of a selectable [`Text`] widget.
+#[derive(Debug, Default)]
+pub struct Selectable<P: Paragraph> {
+    paragraph: State<P>,
+    selection: Option<Range<usize>>,
+    anchor: usize,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl<P: Paragraph> Selectable<P> {
+    /// Returns the selected contents, if any.
+    pub fn selection(&self) -> Option<String> {
+        let selection = self.selection.clone()?;
+
+        let selected: String = self
+            .paragraph
+            .content()
+            .get(selection)?
+            .chars()
+            .filter(|c| *c != text::SOFT_HYPHEN)
+            .collect();
+
+        (!selected.is_empty()).then_some(selected)
+    }
+
+    fn select(&mut self, start: usize, end: usize) {
+        self.selection = (start != end).then(|| start.min(end)..start.max(end));
+    }
+
+    fn hit(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let paragraph = self.paragraph.raw();
+
+        let top_left = top_left(
+            paragraph,
+            bounds.anchor(
+                paragraph.min_bounds(),
+                paragraph.align_x(),
+                paragraph.align_y(),
+            ),
+        );
+
+        paragraph.hit_offset(position - Vector::new(top_left.x, top_left.y))
+    }
+
+    fn word(&self, offset: usize) -> Range<usize> {
+        self.paragraph
+            .content()
+            .split_word_bound_indices()
+            .map(|(start, word)| start..start + word.len())
+            .find(|word| word.contains(&offset))
+            .unwrap_or(offset..offset)
+    }
+
+    fn line(&self, offset: usize) -> Range<usize> {
+        let content = self.paragraph.content();
+
+        let start = content[..offset.min(content.len())]
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+
+        let end = content[start..]
+            .find('\n')
+            .map_or(content.len(), |i| start + i);
+
+        start..end
+    }
+}
+
+impl<Theme, Renderer> Text<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn paragraph<'t>(&self, tree: &'t Tree) -> &'t State<Renderer::Paragraph> {
+        if self.is_selectable {
+            &tree
+                .state
+                .downcast_ref::<Selectable<Renderer::Paragraph>>()
+                .paragraph
+        } else {
+            tree.state.downcast_ref::<State<Renderer::Paragraph>>()
+        }
+    }
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Text<'_, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Renderer::Paragraph>>()
+        if self.is_selectable {
+            tree::Tag::of::<Selectable<Renderer::Paragraph>>()
+        } else {
+            tree::Tag::of::<State<Renderer::Paragraph>>()
+        }
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        if self.is_selectable {
+            tree::State::new(Selectable::<Renderer::Paragraph>::default())
+        } else {
+            tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -213,13 +363,19 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            tree.state.downcast_mut::<State<Renderer::Paragraph>>(),
-            renderer,
-            limits,
-            &self.fragment,
-            self.format,
-        )
+        let paragraph = if self.is_selectable {
+            let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+            if state.paragraph.content() != self.fragment.as_ref() {
+                state.selection = None;
+            }
+
+            &mut state.paragraph
+        } else {
+            tree.state.downcast_mut::<State<Renderer::Paragraph>>()
+        };
+
+        layout(paragraph, renderer, limits, &self.fragment, self.format)
     }
 
     fn draw(
@@ -232,9 +388,42 @@ where
         _cursor_position: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let state = self.paragraph(tree);
         let style = theme.style(&self.class);
 
+        if self.is_selectable
+            && let Some(selection) = &tree
+                .state
+                .downcast_ref::<Selectable<Renderer::Paragraph>>()
+                .selection
+        {
+            let bounds = layout.bounds();
+            let paragraph = state.raw();
+
+            let top_left = top_left(
+                paragraph,
+                bounds.anchor(
+                    paragraph.min_bounds(),
+                    paragraph.align_x(),
+                    paragraph.align_y(),
+                ),
+            );
+
+            let color = style
+                .selection
+                .unwrap_or_else(|| style.color.unwrap_or(defaults.text_color).scale_alpha(0.3));
+
+            for range in paragraph.range_bounds(selection.clone()) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: range + Vector::new(top_left.x, top_left.y),
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+
         draw(
             renderer,
             defaults,
@@ -247,12 +436,126 @@ where
 
     fn operate(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn super::Operation,
     ) {
-        operation.text(None, layout.bounds(), &self.fragment);
+        operation.text(self.id.as_ref(), layout.bounds(), &self.fragment);
+
+        if self.format.max_lines.is_some() {
+            let state = self.paragraph(tree);
+
+            operation.custom(
+                self.id.as_ref(),
+                layout.bounds(),
+                &mut widget::operation::text::Clamp {
+                    is_truncated: state.is_truncated(),
+                },
+            );
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.is_selectable {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    if state.selection.take().is_some() {
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                let Some(offset) = state.hit(bounds, position) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+
+                match click.kind() {
+                    mouse::click::Kind::Single => {
+                        state.anchor = offset;
+                        state.selection = None;
+                        state.is_dragging = true;
+                    }
+                    mouse::click::Kind::Double => {
+                        let word = state.word(offset);
+
+                        state.select(word.start, word.end);
+                        state.is_dragging = false;
+                    }
+                    mouse::click::Kind::Triple => {
+                        let line = state.line(offset);
+
+                        state.select(line.start, line.end);
+                        state.is_dragging = false;
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.is_dragging => {
+                if let Some(offset) = state.hit(bounds, *position) {
+                    let anchor = state.anchor;
+                    state.select(anchor, offset);
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) if modifiers.command() && key.to_latin(*physical_key) == Some('c') => {
+                if let Some(selection) = state.selection() {
+                    clipboard.write(clipboard::Kind::Standard, selection);
+
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
     }
 }
 
@@ -272,6 +575,8 @@ pub struct Format<Font> {
     pub align_y: alignment::Vertical,
     pub shaping: Shaping,
     pub wrapping: Wrapping,
+    pub hyphenation: Option<&'static text::Hyphenation>,
+    pub max_lines: Option<usize>,
 }
 
 impl<Font> Default for Format<Font> {
@@ -286,6 +591,8 @@ impl<Font> Default for Format<Font> {
             align_y: alignment::Vertical::Top,
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            hyphenation: None,
+            max_lines: None,
         }
     }
 }
@@ -307,18 +614,26 @@ where
         let size = format.size.unwrap_or_else(|| renderer.default_size());
         let font = format.font.unwrap_or_else(|| renderer.default_font());
 
-        let _ = paragraph.update(text::Text {
-            content,
-            bounds,
-            size,
-            line_height: format.line_height,
-            font,
-            align_x: format.align_x,
-            align_y: format.align_y,
-            shaping: format.shaping,
-            wrapping: format.wrapping,
-            hint_factor: renderer.scale_factor(),
-        });
+        let content = match format.hyphenation {
+            Some(hyphenation) => hyphenation.hyphenate(content),
+            None => content.into(),
+        };
+
+        let _ = paragraph.update_clamped(
+            text::Text {
+                content: &content,
+                bounds,
+                size,
+                line_height: format.line_height,
+                font,
+                align_x: format.align_x,
+                align_y: format.align_y,
+                shaping: format.shaping,
+                wrapping: format.wrapping,
+                hint_factor: renderer.scale_factor(),
+            },
+            format.max_lines,
+        );
 
         paragraph.min_bounds()
     })
@@ -341,12 +656,55 @@ pub fn draw<Renderer>(
         paragraph.align_y(),
     );
 
-    renderer.fill_paragraph(
-        paragraph,
-        anchor,
-        appearance.color.unwrap_or(style.text_color),
-        *viewport,
-    );
+    let color = appearance.color.unwrap_or(style.text_color);
+
+    renderer.fill_paragraph(paragraph, anchor, color, *viewport);
+
+    let hyphens = paragraph.soft_hyphens();
+
+    if hyphens.is_empty() {
+        return;
+    }
+
+    let top_left = top_left(paragraph, anchor);
+
+    for hyphen in hyphens {
+        renderer.fill_text(
+            text::Text {
+                content: String::from("-"),
+                bounds: Size::INFINITE,
+                size: paragraph.size(),
+                line_height: paragraph.line_height(),
+                font: paragraph.font(),
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                shaping: paragraph.shaping(),
+                wrapping: Wrapping::None,
+                hint_factor: paragraph.hint_factor(),
+            },
+            top_left + Vector::new(hyphen.x, hyphen.y),
+            color,
+            *viewport,
+        );
+    }
+}
+
+/// Returns the top left corner of a [`Paragraph`] drawn at the given anchor.
+fn top_left(paragraph: &impl Paragraph, anchor: Point) -> Point {
+    let min_bounds = paragraph.min_bounds();
+
+    Point::new(
+        match paragraph.align_x() {
+            text::Alignment::Center => anchor.x - min_bounds.width / 2.0,
+            text::Alignment::Right => anchor.x - min_bounds.width,
+            _ => anchor.x,
+        },
+        match paragraph.align_y() {
+            alignment::Vertical::Top => anchor.y,
+            alignment::Vertical::Center => anchor.y - min_bounds.height / 2.0,
+            alignment::Vertical::Bottom => anchor.y - min_bounds.height,
+        },
+    )
 }
 
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
@@ -387,6 +745,11 @@ pub struct Style {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+
+    /// The [`Color`] of the selection of a selectable [`Text`].
+    ///
+    /// The default, `None`, means using a translucent text color.
+    pub selection: Option<Color>,
 }
 
 /// The theme catalog of a [`Text`].
@@ -420,13 +783,17 @@ impl Catalog for Theme {
 
 /// The default text styling; color is inherited.
 pub fn default(_theme: &Theme) -> Style {
-    Style { color: None }
+    Style {
+        color: None,
+        selection: None,
+    }
 }
 
 /// Text with the default base color.
 pub fn base(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().text),
+        selection: None,
     }
 }
 
@@ -434,6 +801,7 @@ pub fn base(theme: &Theme) -> Style {
 pub fn primary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().primary),
+        selection: None,
     }
 }
 
@@ -441,6 +809,7 @@ pub fn primary(theme: &Theme) -> Style {
 pub fn secondary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.extended_palette().secondary.base.color),
+        selection: None,
     }
 }
 
@@ -448,6 +817,7 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().success),
+        selection: None,
     }
 }
 
@@ -455,6 +825,7 @@ pub fn success(theme: &Theme) -> Style {
 pub fn warning(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().warning),
+        selection: None,
     }
 }
 
@@ -462,5 +833,6 @@ pub fn warning(theme: &Theme) -> Style {
 pub fn danger(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().danger),
+        selection: None,
     }
 }
@@ -25,6 +25,7 @@ mod platform;
 mod platform;
 
 use crate::Size;
+use crate::time::Duration;
 use crate::window::{Icon, Level, Position};
 
 pub use platform::PlatformSpecific;
@@ -98,6 +99,17 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// Whether the contents of the window should be scaled while it is being resized,
+    /// instead of being laid out again on every frame.
+    ///
+    /// When set, the last laid out frame is scaled to fit the window (and letterboxed to
+    /// keep its aspect ratio) until the window has not been resized for the given duration;
+    /// at which point the contents are laid out for the final size. This avoids a layout
+    /// per frame during interactive or animated resizes on slow hardware.
+    ///
+    /// By default this is disabled.
+    pub elastic_resize: Option<Duration>,
 }
 
 impl Default for Settings {
@@ -119,6 +131,7 @@ impl Default for Settings {
             level: Level::default(),
             icon: None,
             exit_on_close_request: true,
+            elastic_resize: None,
             platform_specific: PlatformSpecific::default(),
         }
     }
@@ -2,6 +2,7 @@ pub use iced_core as core;
 pub use iced_futures as futures;
 
 use crate::core::theme;
+use crate::core::time::Duration;
 use crate::core::window;
 use crate::futures::Subscription;
 
@@ -14,7 +15,7 @@ pub struct Metadata {
     pub can_time_travel: bool,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Primitive {
     Quad,
     Triangle,
@@ -23,6 +24,45 @@ pub enum Primitive {
     Text,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Update,
+    View,
+    Layout,
+    Interact,
+    Draw,
+    Prepare(Primitive),
+    Render(Primitive),
+    Present,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    pub frames: Vec<Duration>,
+    pub stages: Vec<(Stage, Duration)>,
+    pub layers: usize,
+    pub reallocations: usize,
+}
+
+impl Stats {
+    pub fn fps(&self) -> f32 {
+        let total: Duration = self.frames.iter().sum();
+
+        if total.is_zero() {
+            0.0
+        } else {
+            self.frames.len() as f32 / total.as_secs_f32()
+        }
+    }
+
+    pub fn stage(&self, stage: Stage) -> Option<Duration> {
+        self.stages
+            .iter()
+            .find(|(candidate, _)| *candidate == stage)
+            .map(|(_, duration)| *duration)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Command {
     RewindTo { message: usize },
@@ -62,6 +102,14 @@ pub fn layers_rendered(amount: impl FnOnce() -> usize) {
     internal::layers_rendered(amount);
 }
 
+pub fn buffers_reallocated(amount: usize) {
+    internal::buffers_reallocated(amount);
+}
+
+pub fn stats() -> Stats {
+    internal::stats()
+}
+
 pub fn boot() -> Span {
     internal::boot()
 }
@@ -129,11 +177,11 @@ pub fn is_stale() -> bool {
 #[cfg(all(feature = "enable", not(target_arch = "wasm32")))]
 mod internal {
     use crate::core::theme;
-    use crate::core::time::Instant;
+    use crate::core::time::{Duration, Instant};
     use crate::core::window;
     use crate::futures::Subscription;
     use crate::futures::futures::Stream;
-    use crate::{Command, Metadata, Primitive};
+    use crate::{Command, Metadata, Primitive, Stage, Stats};
 
     use iced_beacon as beacon;
 
@@ -141,8 +189,9 @@ mod internal {
     use beacon::span;
     use beacon::span::present;
 
+    use std::collections::VecDeque;
     use std::sync::atomic::{self, AtomicBool, AtomicUsize};
-    use std::sync::{LazyLock, RwLock};
+    use std::sync::{LazyLock, Mutex, RwLock};
 
     pub fn init(metadata: Metadata) {
         let name = metadata.name.split("::").next().unwrap_or(metadata.name);
@@ -185,7 +234,26 @@ mod internal {
     }
 
     pub fn layers_rendered(amount: impl FnOnce() -> usize) {
-        log(client::Event::LayersRendered(amount()));
+        let amount = amount();
+
+        STATS.lock().expect("Lock performance stats").layers = amount;
+
+        log(client::Event::LayersRendered(amount));
+    }
+
+    pub fn buffers_reallocated(amount: usize) {
+        STATS.lock().expect("Lock performance stats").reallocations += amount;
+    }
+
+    pub fn stats() -> Stats {
+        let recorder = STATS.lock().expect("Lock performance stats");
+
+        Stats {
+            frames: recorder.frames.iter().copied().collect(),
+            stages: recorder.stages.clone(),
+            layers: recorder.layers,
+            reallocations: recorder.reallocations,
+        }
     }
 
     pub fn boot() -> Span {
@@ -296,6 +364,16 @@ mod internal {
         }
     }
 
+    fn from_primitive(primitive: present::Primitive) -> Primitive {
+        match primitive {
+            present::Primitive::Quad => Primitive::Quad,
+            present::Primitive::Triangle => Primitive::Triangle,
+            present::Primitive::Shader => Primitive::Shader,
+            present::Primitive::Text => Primitive::Text,
+            present::Primitive::Image => Primitive::Image,
+        }
+    }
+
     fn log(event: client::Event) {
         if ENABLED.load(atomic::Ordering::Relaxed) {
             BEACON.log(event);
@@ -310,7 +388,69 @@ mod internal {
 
     impl Span {
         pub fn finish(self) {
-            log(client::Event::SpanFinished(self.span, self.start.elapsed()));
+            let duration = self.start.elapsed();
+
+            STATS
+                .lock()
+                .expect("Lock performance stats")
+                .record(&self.span, duration);
+
+            log(client::Event::SpanFinished(self.span, duration));
+        }
+    }
+
+    struct Recorder {
+        frames: VecDeque<Duration>,
+        last_present: Option<Instant>,
+        stages: Vec<(Stage, Duration)>,
+        layers: usize,
+        reallocations: usize,
+    }
+
+    impl Recorder {
+        const MAX_FRAMES: usize = 120;
+        const SMOOTHING: f32 = 0.1;
+
+        fn record(&mut self, span: &span::Stage, duration: Duration) {
+            let stage = match span {
+                span::Stage::Update => Stage::Update,
+                span::Stage::View(_) => Stage::View,
+                span::Stage::Layout(_) => Stage::Layout,
+                span::Stage::Interact(_) => Stage::Interact,
+                span::Stage::Draw(_) => Stage::Draw,
+                span::Stage::Prepare(primitive) => Stage::Prepare(from_primitive(*primitive)),
+                span::Stage::Render(primitive) => Stage::Render(from_primitive(*primitive)),
+                span::Stage::Present(_) => {
+                    let now = Instant::now();
+
+                    if let Some(last_present) = self.last_present.replace(now) {
+                        if self.frames.len() == Self::MAX_FRAMES {
+                            let _ = self.frames.pop_front();
+                        }
+
+                        self.frames.push_back(now - last_present);
+                    }
+
+                    Stage::Present
+                }
+                span::Stage::Boot | span::Stage::Custom(_) => return,
+            };
+
+            // Exponential moving average, so a single slow frame
+            // does not dominate the reported cost of a stage
+            match self
+                .stages
+                .iter_mut()
+                .find(|(candidate, _)| *candidate == stage)
+            {
+                Some((_, average)) => {
+                    *average =
+                        average.mul_f32(1.0 - Self::SMOOTHING) + duration.mul_f32(Self::SMOOTHING);
+                }
+                None => {
+                    self.stages.push((stage, duration));
+                }
+            }
         }
     }
 
@@ -326,6 +466,14 @@ mod internal {
         can_time_travel: false,
     });
 
+    static STATS: Mutex<Recorder> = Mutex::new(Recorder {
+        frames: VecDeque::new(),
+        last_present: None,
+        stages: Vec::new(),
+        layers: 0,
+        reallocations: 0,
+    });
+
     static LAST_UPDATE: AtomicUsize = AtomicUsize::new(0);
     static ENABLED: AtomicBool = AtomicBool::new(true);
 }
@@ -335,7 +483,7 @@ mod internal {
     use crate::core::theme;
     use crate::core::window;
     use crate::futures::Subscription;
-    use crate::{Command, Metadata, Primitive};
+    use crate::{Command, Metadata, Primitive, Stats};
 
     pub fn enable() {}
     pub fn disable() {}
@@ -354,6 +502,12 @@ mod internal {
 
     pub fn layers_rendered(_amount: impl FnOnce() -> usize) {}
 
+    pub fn buffers_reallocated(_amount: usize) {}
+
+    pub fn stats() -> Stats {
+        Stats::default()
+    }
+
     pub fn boot() -> Span {
         Span
     }
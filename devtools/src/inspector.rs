@@ -0,0 +1,494 @@
+//! Inspect the layout of an interface at runtime.
+//!
+//! Press `Ctrl+Shift+I` to toggle the inspector. While it is enabled, the layout
+//! node under the cursor is highlighted and a panel lists the path from the root
+//! to it, alongside the bounds and padding of every node. Clicking selects a node
+//! and logs its description.
+//!
+//! Widgets are opaque to the runtime, so only layout information can be
+//! inspected; nodes are identified by their path of child indices.
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget, alignment,
+};
+
+use std::fmt::Write;
+
+/// Wraps the given content with an [`Inspector`].
+pub fn inspector<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Inspector<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    Inspector {
+        content: content.into(),
+    }
+}
+
+/// A widget that can highlight and describe the layout of its content.
+#[allow(missing_debug_implementations)]
+pub struct Inspector<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+#[derive(Default)]
+struct State {
+    is_enabled: bool,
+    hovered: Vec<usize>,
+    selected: Option<Vec<usize>>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Inspector<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+            && modifiers.command()
+            && modifiers.shift()
+            && matches!(key.as_ref(), keyboard::Key::Character("i" | "I"))
+        {
+            state.is_enabled = !state.is_enabled;
+            state.hovered.clear();
+            state.selected = None;
+
+            shell.capture_event();
+            shell.request_redraw();
+            return;
+        }
+
+        if state.is_enabled
+            && let Event::Mouse(event) = event
+        {
+            let hovered = cursor
+                .position()
+                .map(|position| hovered(layout, position))
+                .unwrap_or_default();
+
+            if hovered != state.hovered {
+                state.hovered = hovered;
+                shell.request_redraw();
+            }
+
+            match event {
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if cursor.is_over(layout.bounds()) {
+                        if let Some(node) = resolve(layout, &state.hovered).last() {
+                            log::info!("{}", describe(&state.hovered, *node));
+                        }
+
+                        state.selected = Some(state.hovered.clone());
+                    } else {
+                        state.selected = None;
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                mouse::Event::ButtonPressed(_) | mouse::Event::ButtonReleased(_) => {
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_enabled && cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::Crosshair;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.is_enabled {
+            return;
+        }
+
+        let path = state.selected.as_deref().unwrap_or(&state.hovered);
+        let nodes = resolve(layout, path);
+
+        renderer.with_layer(*viewport, |renderer| {
+            if let Some(node) = nodes.last() {
+                highlight(renderer, *node, *viewport);
+            }
+
+            panel(renderer, path, &nodes, *viewport);
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Inspector<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(inspector: Inspector<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(inspector)
+    }
+}
+
+const ACCENT: Color = Color::from_rgb(0.2, 0.6, 1.0);
+const PADDING: Color = Color::from_rgb(0.3, 0.9, 0.5);
+const TEXT_SIZE: f32 = 12.0;
+const LINE_HEIGHT: f32 = 16.0;
+const PANEL_WIDTH: f32 = 360.0;
+
+/// Returns the path of the deepest layout node under the given position.
+fn hovered(layout: Layout<'_>, position: Point) -> Vec<usize> {
+    let mut path = Vec::new();
+    let mut current = layout;
+
+    if !current.bounds().contains(position) {
+        return path;
+    }
+
+    // The last child is drawn on top; so it takes precedence
+    while let Some((index, child)) = current
+        .children()
+        .enumerate()
+        .rev()
+        .find(|(_, child)| child.bounds().contains(position))
+    {
+        path.push(index);
+        current = child;
+    }
+
+    path
+}
+
+/// Returns the layout nodes found along the given path, starting at the root.
+fn resolve<'a>(layout: Layout<'a>, path: &[usize]) -> Vec<Layout<'a>> {
+    let mut nodes = vec![layout];
+    let mut current = layout;
+
+    for index in path {
+        let Some(child) = current.children().nth(*index) else {
+            break;
+        };
+
+        nodes.push(child);
+        current = child;
+    }
+
+    nodes
+}
+
+/// Returns the padding of a layout node, when it wraps a single child.
+fn padding(node: Layout<'_>) -> Option<[f32; 4]> {
+    let mut children = node.children();
+
+    let (Some(child), None) = (children.next(), children.next()) else {
+        return None;
+    };
+
+    let bounds = node.bounds();
+    let inner = child.bounds();
+
+    Some([
+        inner.y - bounds.y,
+        (bounds.x + bounds.width) - (inner.x + inner.width),
+        (bounds.y + bounds.height) - (inner.y + inner.height),
+        inner.x - bounds.x,
+    ])
+}
+
+fn describe(path: &[usize], node: Layout<'_>) -> String {
+    let bounds = node.bounds();
+
+    let mut description = format!(
+        "[{path}] {width}×{height} at ({x}, {y})",
+        path = path
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join("."),
+        width = bounds.width,
+        height = bounds.height,
+        x = bounds.x,
+        y = bounds.y,
+    );
+
+    if let Some([top, right, bottom, left]) = padding(node)
+        && [top, right, bottom, left] != [0.0; 4]
+    {
+        let _ = write!(description, " padding [{top}, {right}, {bottom}, {left}]");
+    }
+
+    let children = node.children().len();
+
+    if children > 0 {
+        let _ = write!(description, " with {children} children");
+    }
+
+    description
+}
+
+fn highlight<Renderer>(renderer: &mut Renderer, node: Layout<'_>, viewport: Rectangle)
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let bounds = node.bounds();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color: ACCENT,
+                width: 1.0,
+                ..Border::default()
+            },
+            ..renderer::Quad::default()
+        },
+        ACCENT.scale_alpha(0.2),
+    );
+
+    if padding(node).is_some_and(|padding| padding != [0.0; 4])
+        && let Some(child) = node.children().next()
+    {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: child.bounds(),
+                border: Border {
+                    color: PADDING,
+                    width: 1.0,
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    let label = format!("{} × {}", bounds.width, bounds.height);
+    let position = Point::new(bounds.x, (bounds.y - LINE_HEIGHT - 4.0).max(viewport.y));
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: position.x,
+                y: position.y,
+                width: label.chars().count() as f32 * TEXT_SIZE * 0.6 + 8.0,
+                height: LINE_HEIGHT + 4.0,
+            },
+            ..renderer::Quad::default()
+        },
+        ACCENT,
+    );
+
+    line(renderer, label, position + Vector::new(4.0, 2.0), viewport);
+}
+
+fn panel<Renderer>(
+    renderer: &mut Renderer,
+    path: &[usize],
+    nodes: &[Layout<'_>],
+    viewport: Rectangle,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let mut lines = vec![String::from("Inspector (Ctrl+Shift+I)")];
+
+    for (depth, node) in nodes.iter().enumerate() {
+        lines.push(format!(
+            "{indent}{description}",
+            indent = "  ".repeat(depth),
+            description = describe(&path[..depth], *node)
+        ));
+    }
+
+    lines.push(String::from("Click to select and log a node"));
+
+    let height = lines.len() as f32 * LINE_HEIGHT + 20.0;
+
+    let bounds = Rectangle {
+        x: viewport.x + (viewport.width - PANEL_WIDTH - 10.0).max(0.0),
+        y: viewport.y + 10.0,
+        width: PANEL_WIDTH.min(viewport.width),
+        height: height.min(viewport.height - 20.0),
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color: ACCENT,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::BLACK.scale_alpha(0.85),
+    );
+
+    for (i, content) in lines.into_iter().enumerate() {
+        let position = Point::new(bounds.x + 10.0, bounds.y + 10.0 + i as f32 * LINE_HEIGHT);
+
+        if position.y + LINE_HEIGHT > bounds.y + bounds.height {
+            break;
+        }
+
+        line(renderer, content, position, bounds);
+    }
+}
+
+fn line<Renderer>(renderer: &mut Renderer, content: String, position: Point, clip_bounds: Rectangle)
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, LINE_HEIGHT),
+            size: Pixels(TEXT_SIZE),
+            line_height: text::LineHeight::Absolute(Pixels(LINE_HEIGHT)),
+            font: Font::MONOSPACE,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        },
+        position,
+        Color::WHITE,
+        clip_bounds,
+    );
+}
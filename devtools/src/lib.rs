@@ -9,6 +9,9 @@ use iced_widget::core;
 mod comet;
 mod time_machine;
 
+#[cfg(feature = "inspector")]
+mod inspector;
+
 use crate::core::border;
 use crate::core::keyboard;
 use crate::core::theme::{self, Theme};
@@ -28,6 +31,9 @@ use crate::widget::{
 use std::fmt;
 use std::thread;
 
+#[cfg(feature = "time-travel")]
+const REPLAY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
 pub fn attach<P: Program + 'static>(program: P) -> Attach<P> {
     Attach { program }
 }
@@ -114,6 +120,8 @@ where
     show_notification: bool,
     time_machine: TimeMachine<P>,
     mode: Mode,
+    #[cfg(feature = "time-travel")]
+    show_time_travel: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -124,6 +132,18 @@ pub enum Message {
     InstallComet,
     Installing(comet::install::Result),
     CancelSetup,
+    #[cfg(feature = "time-travel")]
+    ToggleTimeTravel,
+    #[cfg(feature = "time-travel")]
+    StepBack,
+    #[cfg(feature = "time-travel")]
+    StepForward,
+    #[cfg(feature = "time-travel")]
+    Replay,
+    #[cfg(feature = "time-travel")]
+    GoLive,
+    #[cfg(feature = "time-travel")]
+    ExportHistory,
 }
 
 enum Mode {
@@ -153,6 +173,8 @@ where
                 mode: Mode::Hidden,
                 show_notification: true,
                 time_machine: TimeMachine::new(),
+                #[cfg(feature = "time-travel")]
+                show_time_travel: false,
             },
             Task::batch([task::blocking(|mut sender| {
                 thread::sleep(seconds(2));
@@ -251,6 +273,61 @@ where
                 Message::CancelSetup => {
                     self.mode = Mode::Hidden;
 
+                    Task::none()
+                }
+                #[cfg(feature = "time-travel")]
+                Message::ToggleTimeTravel => {
+                    self.show_time_travel = !self.show_time_travel;
+
+                    Task::none()
+                }
+                #[cfg(feature = "time-travel")]
+                Message::StepBack => {
+                    self.time_machine.step_back(program);
+
+                    Task::none()
+                }
+                #[cfg(feature = "time-travel")]
+                Message::StepForward => {
+                    self.time_machine.step_forward(program);
+
+                    Task::none()
+                }
+                #[cfg(feature = "time-travel")]
+                Message::Replay => {
+                    let (_, total) = self.time_machine.position();
+
+                    self.time_machine.rewind(program, 0);
+
+                    task::blocking(move |mut sender| {
+                        for _ in 0..total {
+                            thread::sleep(REPLAY_INTERVAL);
+
+                            if sender.try_send(()).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .map(|_| Event::Message(Message::StepForward))
+                }
+                #[cfg(feature = "time-travel")]
+                Message::GoLive => {
+                    self.time_machine.go_to_present();
+
+                    Task::none()
+                }
+                #[cfg(feature = "time-travel")]
+                Message::ExportHistory => {
+                    let path = std::env::temp_dir().join(format!(
+                        "{name}-history.log",
+                        name = P::name().replace(char::is_whitespace, "-")
+                    ));
+
+                    match self.time_machine.export(&path) {
+                        Ok(()) => log::info!("message history exported to {}", path.display()),
+                        Err(error) => log::error!("message history could not be exported: {error}"),
+                    }
+
                     Task::none()
                 }
             },
@@ -305,6 +382,9 @@ where
             }
         };
 
+        #[cfg(feature = "inspector")]
+        let view = Element::from(inspector::inspector(view));
+
         let theme = || {
             program
                 .theme(state, window)
@@ -349,7 +429,36 @@ where
                 )
             });
 
-        stack![view, setup, notification]
+        #[cfg(feature = "time-travel")]
+        let time_travel = self.show_time_travel.then(|| {
+            let (position, total) = self.time_machine.position();
+            let is_rewinding = self.time_machine.is_rewinding();
+
+            let controls = row![
+                button(text("Back")).on_press_maybe((position > 0).then_some(Message::StepBack)),
+                button(text("Forward"))
+                    .on_press_maybe(is_rewinding.then_some(Message::StepForward)),
+                button(text("Replay")).on_press_maybe((total > 0).then_some(Message::Replay)),
+                button(text("Live")).on_press_maybe(is_rewinding.then_some(Message::GoLive)),
+                button(text("Export")).on_press(Message::ExportHistory),
+                text!("{position} / {total}").font(Font::MONOSPACE),
+            ]
+            .spacing(10)
+            .align_y(Center);
+
+            themer(
+                theme(),
+                widget::bottom(
+                    opaque(container(controls).padding(10).style(container::dark))
+                        .map(Event::Message),
+                ),
+            )
+        });
+
+        #[cfg(not(feature = "time-travel"))]
+        let time_travel: Option<Element<'_, Event<P>, P::Theme, P::Renderer>> = None;
+
+        stack![view, setup, time_travel, notification]
             .width(Fill)
             .height(Fill)
             .into()
@@ -365,6 +474,11 @@ where
                     modified_key: keyboard::Key::Named(keyboard::key::Named::F12),
                     ..
                 } => Some(Message::ToggleComet),
+                #[cfg(feature = "time-travel")]
+                keyboard::Event::KeyPressed {
+                    modified_key: keyboard::Key::Named(keyboard::key::Named::F11),
+                    ..
+                } => Some(Message::ToggleTimeTravel),
                 _ => None,
             })
             .map(Event::Message);
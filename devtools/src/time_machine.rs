@@ -1,11 +1,15 @@
 use crate::Program;
 
+#[cfg(feature = "time-travel")]
+use std::{fmt, io, path::Path};
+
 #[cfg(feature = "time-travel")]
 pub struct TimeMachine<P>
 where
     P: Program,
 {
     state: Option<P::State>,
+    position: usize,
     messages: Vec<P::Message>,
 }
 
@@ -18,6 +22,7 @@ where
     pub fn new() -> Self {
         Self {
             state: None,
+            position: 0,
             messages: Vec::new(),
         }
     }
@@ -42,6 +47,7 @@ where
         }
 
         self.state = Some(state);
+        self.position = message.min(self.messages.len());
     }
 
     pub fn go_to_present(&mut self) {
@@ -52,6 +58,53 @@ where
     pub fn state(&self) -> Option<&P::State> {
         self.state.as_ref()
     }
+
+    /// Returns the amount of messages applied to the current state and
+    /// the total amount of recorded messages.
+    pub fn position(&self) -> (usize, usize) {
+        if self.is_rewinding() {
+            (self.position, self.messages.len())
+        } else {
+            (self.messages.len(), self.messages.len())
+        }
+    }
+
+    pub fn step_back(&mut self, program: &P) {
+        let (position, _) = self.position();
+
+        self.rewind(program, position.saturating_sub(1));
+    }
+
+    pub fn step_forward(&mut self, program: &P) {
+        let Some(state) = &mut self.state else {
+            return;
+        };
+
+        if self.position + 1 >= self.messages.len() {
+            self.go_to_present();
+            return;
+        }
+
+        // Stepping forward only needs to apply the next message
+        let _ = program.update(state, self.messages[self.position].clone());
+        self.position += 1;
+    }
+
+    /// Writes the recorded messages to the file in the given path, one per line.
+    pub fn export(&self, path: &Path) -> io::Result<()>
+    where
+        P::Message: fmt::Debug,
+    {
+        use std::fmt::Write;
+
+        let mut history = String::new();
+
+        for (i, message) in self.messages.iter().enumerate() {
+            let _ = writeln!(history, "{i}: {message:?}");
+        }
+
+        std::fs::write(path, history)
+    }
 }
 
 #[cfg(not(feature = "time-travel"))]
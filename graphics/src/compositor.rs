@@ -61,11 +61,13 @@ pub trait Compositor: Sized {
     fn information(&self) -> Information;
 
     /// Loads a font from its bytes.
-    fn load_font(&mut self, font: Cow<'static, [u8]>) {
+    ///
+    /// Returns `false` if the bytes do not contain any valid font face.
+    fn load_font(&mut self, font: Cow<'static, [u8]>) -> bool {
         crate::text::font_system()
             .write()
             .expect("Write to font system")
-            .load_font(font);
+            .load_font(font)
     }
 
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
@@ -171,7 +173,9 @@ impl Compositor for () {
 
     fn configure_surface(&mut self, _surface: &mut Self::Surface, _width: u32, _height: u32) {}
 
-    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) -> bool {
+        true
+    }
 
     fn information(&self) -> Information {
         Information {
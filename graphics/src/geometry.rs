@@ -5,6 +5,7 @@ pub mod path;
 pub mod stroke;
 
 mod cache;
+mod recorder;
 mod style;
 mod text;
 
@@ -12,6 +13,7 @@ pub use cache::Cache;
 pub use fill::Fill;
 pub use frame::Frame;
 pub use path::Path;
+pub use recorder::Recorder;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use style::Style;
 pub use text::Text;
@@ -184,6 +184,99 @@ where
     }
 }
 
+impl<Renderer> Backend for Frame<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    type Geometry = Renderer::Geometry;
+
+    fn width(&self) -> f32 {
+        self.raw.width()
+    }
+
+    fn height(&self) -> f32 {
+        self.raw.height()
+    }
+
+    fn size(&self) -> Size {
+        self.raw.size()
+    }
+
+    fn center(&self) -> Point {
+        self.raw.center()
+    }
+
+    fn push_transform(&mut self) {
+        self.raw.push_transform();
+    }
+
+    fn pop_transform(&mut self) {
+        self.raw.pop_transform();
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.raw.translate(translation);
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.raw.rotate(angle);
+    }
+
+    fn scale(&mut self, scale: impl Into<f32>) {
+        self.raw.scale(scale);
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        self.raw.scale_nonuniform(scale);
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        Self {
+            raw: self.raw.draft(clip_bounds),
+        }
+    }
+
+    fn paste(&mut self, frame: Self) {
+        self.raw.paste(frame.raw);
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        self.raw.stroke(path, stroke);
+    }
+
+    fn stroke_rectangle<'a>(&mut self, top_left: Point, size: Size, stroke: impl Into<Stroke<'a>>) {
+        self.raw.stroke_rectangle(top_left, size, stroke);
+    }
+
+    fn stroke_text<'a>(&mut self, text: impl Into<Text>, stroke: impl Into<Stroke<'a>>) {
+        self.raw.stroke_text(text, stroke);
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        self.raw.fill(path, fill);
+    }
+
+    fn fill_text(&mut self, text: impl Into<Text>) {
+        self.raw.fill_text(text);
+    }
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: impl Into<Fill>) {
+        self.raw.fill_rectangle(top_left, size, fill);
+    }
+
+    fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
+        self.raw.draw_image(bounds, image);
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
+        self.raw.draw_svg(bounds, svg);
+    }
+
+    fn into_geometry(self) -> Self::Geometry {
+        self.raw.into_geometry()
+    }
+}
+
 /// The internal implementation of a [`Frame`].
 ///
 /// Analogous to [`Frame`]. See [`Frame`] for the documentation
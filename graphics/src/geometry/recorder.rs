@@ -0,0 +1,408 @@
+use crate::core::image;
+use crate::core::svg;
+use crate::core::{Color, Point, Radians, Rectangle, Size, Vector};
+use crate::geometry::fill::{self, Fill};
+use crate::geometry::frame::Backend;
+use crate::geometry::stroke::{self, LineCap, LineJoin, Stroke};
+use crate::geometry::{Gradient, Image, Path, Style, Svg, Text};
+
+use lyon_path::math::{self, Transform};
+use std::fmt::Write;
+
+/// A frame that records geometry as an SVG document.
+///
+/// A [`Recorder`] implements the same drawing operations as a `Frame`;
+/// so the drawing logic of a `Canvas` can be reused to export its contents
+/// by writing it once against the [`Backend`] trait.
+///
+/// Paths, fills, strokes, and gradients are written as their SVG
+/// counterparts. Text is written as the outlines of its glyphs; so the
+/// document looks the same without the fonts installed.
+///
+/// Images and SVG handles are linked by their path; in-memory ones are
+/// skipped.
+#[derive(Debug)]
+pub struct Recorder {
+    clip_bounds: Rectangle,
+    transform: Transform,
+    stack: Vec<Transform>,
+    prefix: String,
+    ids: usize,
+    definitions: String,
+    content: String,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] with the given clip bounds.
+    pub fn new(clip_bounds: Rectangle) -> Self {
+        Self::with_prefix(clip_bounds, String::from("g"))
+    }
+
+    fn with_prefix(clip_bounds: Rectangle, prefix: String) -> Self {
+        Self {
+            clip_bounds,
+            transform: Transform::identity(),
+            stack: Vec::new(),
+            prefix,
+            ids: 0,
+            definitions: String::new(),
+            content: String::new(),
+        }
+    }
+
+    /// Turns the [`Recorder`] into an SVG document.
+    pub fn into_svg(self) -> String {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = self.clip_bounds;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+            width=\"{width}\" height=\"{height}\" viewBox=\"{x} {y} {width} {height}\">"
+        );
+
+        if !self.definitions.is_empty() {
+            let _ = write!(svg, "<defs>{}</defs>", self.definitions);
+        }
+
+        svg.push_str(&self.content);
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Returns a new unique identifier for a definition.
+    fn id(&mut self) -> String {
+        self.ids += 1;
+
+        format!("{}{}", self.prefix, self.ids)
+    }
+
+    /// Returns the `transform` attribute of the current transform.
+    fn transform(&self) -> String {
+        if self.transform == Transform::identity() {
+            return String::new();
+        }
+
+        let Transform {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+            ..
+        } = self.transform;
+
+        format!(" transform=\"matrix({m11} {m12} {m21} {m22} {m31} {m32})\"")
+    }
+
+    /// Returns the attributes to paint with the given [`Style`].
+    fn paint(&mut self, attribute: &str, style: &Style) -> String {
+        match style {
+            Style::Solid(color) => color_attributes(attribute, *color),
+            Style::Gradient(Gradient::Linear(linear)) => {
+                let id = self.id();
+
+                let _ = write!(
+                    self.definitions,
+                    "<linearGradient id=\"{id}\" gradientUnits=\"userSpaceOnUse\" \
+                    x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\">",
+                    linear.start.x, linear.start.y, linear.end.x, linear.end.y,
+                );
+
+                for stop in linear.stops.iter().flatten() {
+                    let _ = write!(
+                        self.definitions,
+                        "<stop offset=\"{}\"{}/>",
+                        stop.offset,
+                        color_attributes("stop-color", stop.color),
+                    );
+                }
+
+                self.definitions.push_str("</linearGradient>");
+
+                format!(" {attribute}=\"url(#{id})\"")
+            }
+        }
+    }
+}
+
+impl Backend for Recorder {
+    type Geometry = String;
+
+    fn width(&self) -> f32 {
+        self.clip_bounds.width
+    }
+
+    fn height(&self) -> f32 {
+        self.clip_bounds.height
+    }
+
+    fn size(&self) -> Size {
+        self.clip_bounds.size()
+    }
+
+    fn center(&self) -> Point {
+        Point::new(self.clip_bounds.width / 2.0, self.clip_bounds.height / 2.0)
+    }
+
+    fn push_transform(&mut self) {
+        self.stack.push(self.transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transform = self.stack.pop().expect("Pop transform");
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.transform = self
+            .transform
+            .pre_translate(math::Vector::new(translation.x, translation.y));
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.transform = self
+            .transform
+            .pre_rotate(math::Angle::radians(angle.into().0));
+    }
+
+    fn scale(&mut self, scale: impl Into<f32>) {
+        let scale = scale.into();
+
+        self.scale_nonuniform(Vector::new(scale, scale));
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+
+        self.transform = self.transform.pre_scale(scale.x, scale.y);
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        let prefix = format!("{}-", self.id());
+
+        Self::with_prefix(clip_bounds, prefix)
+    }
+
+    fn paste(&mut self, frame: Self) {
+        let id = self.id();
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = frame.clip_bounds;
+
+        let _ = write!(
+            self.definitions,
+            "<clipPath id=\"{id}\"><rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\"/></clipPath>{}",
+            frame.definitions,
+        );
+
+        let _ = write!(
+            self.content,
+            "<g clip-path=\"url(#{id})\">{}</g>",
+            frame.content
+        );
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+        let paint = self.paint("stroke", &stroke.style);
+
+        let line_cap = match stroke.line_cap {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        };
+
+        let line_join = match stroke.line_join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        };
+
+        let _ = write!(
+            self.content,
+            "<path d=\"{}\" fill=\"none\"{paint} stroke-width=\"{}\" \
+            stroke-linecap=\"{line_cap}\" stroke-linejoin=\"{line_join}\"{}{}/>",
+            data(path),
+            stroke.width,
+            dash(&stroke.line_dash),
+            self.transform(),
+        );
+    }
+
+    fn stroke_rectangle<'a>(&mut self, top_left: Point, size: Size, stroke: impl Into<Stroke<'a>>) {
+        self.stroke(&Path::rectangle(top_left, size), stroke);
+    }
+
+    fn stroke_text<'a>(&mut self, text: impl Into<Text>, stroke: impl Into<Stroke<'a>>) {
+        let text = text.into();
+        let stroke = stroke.into();
+
+        text.draw_with(|path, _color| self.stroke(&path, stroke));
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+        let paint = self.paint("fill", &fill.style);
+
+        let rule = match fill.rule {
+            fill::Rule::NonZero => "nonzero",
+            fill::Rule::EvenOdd => "evenodd",
+        };
+
+        let _ = write!(
+            self.content,
+            "<path d=\"{}\"{paint} fill-rule=\"{rule}\"{}/>",
+            data(path),
+            self.transform(),
+        );
+    }
+
+    fn fill_text(&mut self, text: impl Into<Text>) {
+        let text = text.into();
+
+        text.draw_with(|path, color| self.fill(&path, color));
+    }
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: impl Into<Fill>) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
+        let image = image.into();
+
+        let image::Handle::Path(_, path) = &image.handle else {
+            log::warn!(
+                "Unsupported image handle in SVG recording: {:?}",
+                image.handle
+            );
+            return;
+        };
+
+        self.link(
+            &path.to_string_lossy(),
+            bounds,
+            image.rotation,
+            image.opacity,
+        );
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
+        let svg = svg.into();
+
+        let svg::Data::Path(path) = svg.handle.data() else {
+            log::warn!("Unsupported SVG handle in SVG recording: {:?}", svg.handle);
+            return;
+        };
+
+        self.link(&path.to_string_lossy(), bounds, svg.rotation, svg.opacity);
+    }
+
+    fn into_geometry(self) -> String {
+        self.into_svg()
+    }
+}
+
+impl Recorder {
+    /// Links an external image at the given path.
+    fn link(&mut self, path: &str, bounds: Rectangle, rotation: Radians, opacity: f32) {
+        let center = bounds.center();
+
+        let _ = write!(
+            self.content,
+            "<g{}><image href=\"{}\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+            preserveAspectRatio=\"none\" opacity=\"{opacity}\" transform=\"rotate({} {} {})\"/></g>",
+            self.transform(),
+            escape(path),
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+            rotation.0.to_degrees(),
+            center.x,
+            center.y,
+        );
+    }
+}
+
+/// Returns the path data of a [`Path`].
+fn data(path: &Path) -> String {
+    use lyon_path::Event;
+
+    let mut data = String::new();
+
+    for event in path.raw().iter() {
+        let _ = match event {
+            Event::Begin { at } => write!(data, "M{} {}", at.x, at.y),
+            Event::Line { to, .. } => write!(data, "L{} {}", to.x, to.y),
+            Event::Quadratic { ctrl, to, .. } => {
+                write!(data, "Q{} {} {} {}", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            Event::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(
+                data,
+                "C{} {} {} {} {} {}",
+                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+            ),
+            Event::End { close: true, .. } => write!(data, "Z"),
+            Event::End { close: false, .. } => Ok(()),
+        };
+    }
+
+    data
+}
+
+/// Returns the color and opacity attributes of a [`Color`].
+fn color_attributes(attribute: &str, color: Color) -> String {
+    let [r, g, b, _a] = color.into_rgba8();
+
+    if color.a < 1.0 {
+        let opacity = match attribute {
+            "stop-color" => "stop-opacity",
+            "stroke" => "stroke-opacity",
+            _ => "fill-opacity",
+        };
+
+        format!(
+            " {attribute}=\"#{r:02x}{g:02x}{b:02x}\" {opacity}=\"{}\"",
+            color.a
+        )
+    } else {
+        format!(" {attribute}=\"#{r:02x}{g:02x}{b:02x}\"")
+    }
+}
+
+/// Returns the dash attributes of a [`stroke::LineDash`].
+fn dash(line_dash: &stroke::LineDash<'_>) -> String {
+    if line_dash.segments.is_empty() {
+        return String::new();
+    }
+
+    let segments: Vec<String> = line_dash
+        .segments
+        .iter()
+        .map(|segment| segment.to_string())
+        .collect();
+
+    format!(
+        " stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\"",
+        segments.join(" "),
+        line_dash.offset
+    )
+}
+
+/// Escapes the special characters of an XML attribute.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
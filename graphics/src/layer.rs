@@ -199,7 +199,8 @@ impl<T: Layer> Stack<T> {
 
     /// Clears the layers of the [`Stack`], allowing reuse.
     ///
-    /// It resizes the base layer bounds to the `new_bounds`.
+    /// It resizes the base layer bounds to the `new_bounds`, in the coordinates
+    /// of the current [`Transformation`].
     ///
     /// This will normally keep layer allocations for future drawing operations.
     pub fn reset(&mut self, new_bounds: Rectangle) {
@@ -207,7 +208,7 @@ impl<T: Layer> Stack<T> {
             layer.reset();
         }
 
-        self.layers[0].resize(new_bounds);
+        self.layers[0].resize(new_bounds * self.transformation());
         self.current = 0;
         self.active_count = 1;
         self.previous.clear();
@@ -21,6 +21,15 @@ pub struct Settings {
     ///
     /// By default, it is `true`.
     pub vsync: bool,
+
+    /// The maximum size, in bytes, of the decoded images kept in memory.
+    ///
+    /// Renderers may keep recently decoded images around; so they can be
+    /// uploaded again without decoding them. The budget applies to each
+    /// renderer on its own.
+    ///
+    /// By default, it is 64 MiB.
+    pub image_cache_size: usize,
 }
 
 impl Default for Settings {
@@ -30,6 +39,7 @@ impl Default for Settings {
             default_text_size: Pixels(16.0),
             antialiasing: None,
             vsync: true,
+            image_cache_size: 64 * 1024 * 1024,
         }
     }
 }
@@ -47,6 +57,7 @@ impl From<core::Settings> for Settings {
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing.then_some(Antialiasing::MSAAx4),
             vsync: settings.vsync,
+            image_cache_size: settings.image_cache_size,
         }
     }
 }
@@ -146,23 +146,31 @@ impl FontSystem {
     }
 
     /// Loads a font from its bytes.
-    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
+    ///
+    /// Returns `false` if the bytes do not contain any valid font face.
+    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) -> bool {
         if let Cow::Borrowed(bytes) = bytes {
             let address = bytes.as_ptr() as usize;
 
             if !self.loaded_fonts.insert(address) {
-                return;
+                return true;
             }
         }
 
-        let _ = self
+        let faces = self
             .raw
             .db_mut()
             .load_font_source(cosmic_text::fontdb::Source::Binary(Arc::new(
                 bytes.into_owned(),
             )));
 
+        if faces.is_empty() {
+            return false;
+        }
+
         self.version = Version(self.version.0 + 1);
+
+        true
     }
 
     /// Returns the current [`Version`] of the [`FontSystem`].
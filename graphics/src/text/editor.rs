@@ -11,6 +11,7 @@ use cosmic_text::Edit as _;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc, RwLock};
 
 /// A multi-line text editor.
@@ -134,6 +135,46 @@ impl editor::Editor for Editor {
         self.internal().editor.copy_selection()
     }
 
+    fn hit(&self, point: Point) -> Option<Position> {
+        let internal = self.internal();
+
+        let cursor = buffer_from_editor(&internal.editor).hit(
+            point.x * internal.hint_factor,
+            point.y * internal.hint_factor,
+        )?;
+
+        Some(Position {
+            line: cursor.line,
+            column: cursor.index,
+        })
+    }
+
+    fn range_bounds(&self, line: usize, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let Some(buffer_line) = buffer.lines.get(line) else {
+            return Vec::new();
+        };
+
+        let line_height = buffer.metrics().line_height;
+        let visual_lines_offset = visual_lines_offset(line, buffer);
+
+        highlight_line(buffer_line, range.start, range.end)
+            .enumerate()
+            .filter(|(_, (_, width))| *width > 0.0)
+            .map(|(visual_line, (x, width))| {
+                Rectangle {
+                    x,
+                    width,
+                    y: (visual_line as i32 + visual_lines_offset) as f32 * line_height
+                        - buffer.scroll().vertical,
+                    height: line_height,
+                } * (1.0 / internal.hint_factor)
+            })
+            .collect()
+    }
+
     fn selection(&self) -> editor::Selection {
         let internal = self.internal();
 
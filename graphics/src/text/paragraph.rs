@@ -5,8 +5,11 @@ use crate::core::text::{Alignment, Hit, LineHeight, Shaping, Span, Text, Wrappin
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::fmt;
-use std::sync::{self, Arc};
+use std::ops::Range;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{self, Arc, Mutex, OnceLock};
 
 /// A bunch of text.
 #[derive(Clone, PartialEq)]
@@ -57,12 +60,8 @@ impl Paragraph {
     fn internal(&self) -> &Arc<Internal> {
         &self.0
     }
-}
-
-impl core::text::Paragraph for Paragraph {
-    type Font = Font;
 
-    fn with_text(text: Text<&str>) -> Self {
+    fn shape(text: Text<&str>) -> Self {
         log::trace!("Allocating plain paragraph: {}", text.content);
 
         let mut font_system = text::font_system().write().expect("Write font system");
@@ -116,6 +115,33 @@ impl core::text::Paragraph for Paragraph {
             version: font_system.version(),
         }))
     }
+}
+
+impl core::text::Paragraph for Paragraph {
+    type Font = Font;
+
+    fn with_text(text: Text<&str>) -> Self {
+        let key = Key::new(&text);
+
+        if let Some(internal) = cache().lock().expect("Lock paragraph cache").get(&key) {
+            let mut paragraph = Self(internal);
+
+            if paragraph.0.bounds != text.bounds {
+                paragraph.resize(text.bounds);
+            }
+
+            return paragraph;
+        }
+
+        let paragraph = Self::shape(text);
+
+        cache()
+            .lock()
+            .expect("Lock paragraph cache")
+            .insert(key, paragraph.0.clone());
+
+        paragraph
+    }
 
     fn with_spans<Link>(text: Text<&[Span<'_, Link>]>) -> Self {
         log::trace!("Allocating rich paragraph: {} spans", text.content.len());
@@ -413,6 +439,103 @@ impl core::text::Paragraph for Paragraph {
             (glyph.y - glyph.y_offset * glyph.font_size) / self.0.hint_factor,
         ))
     }
+
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+
+        let cursor = internal.buffer.hit(
+            point.x * internal.hint_factor,
+            point.y * internal.hint_factor,
+        )?;
+
+        Some(line_offset(&internal.buffer, cursor.line) + cursor.index)
+    }
+
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        let start = to_cursor(&internal.buffer, range.start);
+        let end = to_cursor(&internal.buffer, range.end);
+
+        internal
+            .buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                Some(
+                    Rectangle {
+                        x,
+                        y: run.line_top,
+                        width,
+                        height: run.line_height,
+                    } * (1.0 / internal.hint_factor),
+                )
+            })
+            .collect()
+    }
+
+    fn soft_hyphens(&self) -> Vec<Point> {
+        let internal = self.internal();
+
+        internal
+            .buffer
+            .layout_runs()
+            .filter(|run| !run.rtl)
+            .filter_map(|run| {
+                // The soft hyphen may be shaped as an invisible glyph
+                let last = run
+                    .glyphs
+                    .iter()
+                    .rev()
+                    .find(|glyph| &run.text[glyph.start..glyph.end] != "\u{AD}")?;
+
+                let rest = run.text[last.end..].strip_prefix('\u{AD}')?;
+
+                // A soft hyphen at the end of the text does not break anything
+                if rest.is_empty() {
+                    return None;
+                }
+
+                Some(Point::new(
+                    (last.x + last.w) / internal.hint_factor,
+                    run.line_top / internal.hint_factor,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Returns the byte offset where the given line of the buffer starts.
+fn line_offset(buffer: &cosmic_text::Buffer, line: usize) -> usize {
+    buffer
+        .lines
+        .iter()
+        .take(line)
+        .map(|line| line.text().len() + line.ending().as_str().len())
+        .sum()
+}
+
+/// Converts a byte offset of the content of a buffer into a [`cosmic_text::Cursor`].
+fn to_cursor(buffer: &cosmic_text::Buffer, offset: usize) -> cosmic_text::Cursor {
+    let mut start = 0;
+
+    for (i, line) in buffer.lines.iter().enumerate() {
+        let end = start + line.text().len();
+
+        if offset <= end {
+            return cosmic_text::Cursor::new(i, offset - start);
+        }
+
+        start = end + line.ending().as_str().len();
+    }
+
+    let last = buffer.lines.len().saturating_sub(1);
+
+    cosmic_text::Cursor::new(
+        last,
+        buffer.lines.last().map_or(0, |line| line.text().len()),
+    )
 }
 
 impl Default for Paragraph {
@@ -496,3 +619,129 @@ impl PartialEq for Weak {
         }
     }
 }
+
+/// A renderer trimming the shaping cache of plain paragraphs.
+///
+/// The cache ages one generation once every [`Trimmer`] has trimmed it;
+/// so paragraphs live for the same amount of frames no matter how many
+/// windows are drawn.
+#[derive(Debug)]
+pub struct Trimmer(u64);
+
+impl Trimmer {
+    /// Creates a new [`Trimmer`].
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+
+    /// Trims the shaping cache of plain paragraphs at the end of a frame.
+    ///
+    /// The paragraphs that have not been reused during the last few
+    /// generations are dropped.
+    pub fn trim(&self) {
+        cache().lock().expect("Lock paragraph cache").trim(self.0);
+    }
+}
+
+impl Default for Trimmer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn cache() -> &'static Mutex<Cache> {
+    static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+    CACHE.get_or_init(Mutex::default)
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: FxHashMap<Key, Entry>,
+    generation: u64,
+    trimmers: FxHashSet<u64>,
+}
+
+struct Entry {
+    internal: Arc<Internal>,
+    generation: u64,
+}
+
+impl Cache {
+    /// The amount of generations a paragraph is kept without being reused.
+    const GENERATIONS: u64 = 60;
+
+    fn get(&mut self, key: &Key) -> Option<Arc<Internal>> {
+        let entry = self.entries.get_mut(key)?;
+        entry.generation = self.generation;
+
+        Some(entry.internal.clone())
+    }
+
+    fn insert(&mut self, key: Key, internal: Arc<Internal>) {
+        let _ = self.entries.insert(
+            key,
+            Entry {
+                internal,
+                generation: self.generation,
+            },
+        );
+    }
+
+    fn trim(&mut self, trimmer: u64) {
+        // A new generation starts when a trimmer trims twice in the current one
+        if self.trimmers.insert(trimmer) {
+            return;
+        }
+
+        let generation = self.generation;
+
+        self.entries
+            .retain(|_, entry| generation - entry.generation < Self::GENERATIONS);
+
+        self.generation += 1;
+        self.trimmers.clear();
+        let _ = self.trimmers.insert(trimmer);
+    }
+}
+
+/// The content, font, size, and width of some plain text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    content: String,
+    font: Font,
+    size: u32,
+    line_height: u32,
+    width: u32,
+    shaping: Shaping,
+    wrapping: Wrapping,
+    align_x: Alignment,
+    align_y: alignment::Vertical,
+    hint_factor: Option<u32>,
+    version: text::Version,
+}
+
+impl Key {
+    fn new(text: &Text<&str>) -> Self {
+        let version = text::font_system()
+            .read()
+            .expect("Read font system")
+            .version();
+
+        Self {
+            content: text.content.to_owned(),
+            font: text.font,
+            size: text.size.0.to_bits(),
+            line_height: text.line_height.to_absolute(text.size).0.to_bits(),
+            width: text.bounds.width.to_bits(),
+            shaping: text.shaping,
+            wrapping: text.wrapping,
+            align_x: text.align_x,
+            align_y: text.align_y,
+            hint_factor: text::hint_factor(text.size, text.hint_factor).map(f32::to_bits),
+            version,
+        }
+    }
+}
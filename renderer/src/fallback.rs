@@ -360,8 +360,8 @@ where
         }
     }
 
-    fn load_font(&mut self, font: Cow<'static, [u8]>) {
-        delegate!(self, compositor, compositor.load_font(font));
+    fn load_font(&mut self, font: Cow<'static, [u8]>) -> bool {
+        delegate!(self, compositor, compositor.load_font(font))
     }
 
     fn information(&self) -> compositor::Information {
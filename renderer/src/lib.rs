@@ -6,6 +6,9 @@ pub use iced_wgpu as wgpu;
 
 pub mod fallback;
 
+#[cfg(feature = "pdf")]
+pub mod pdf;
+
 pub use iced_graphics as graphics;
 pub use iced_graphics::core;
 
@@ -0,0 +1,32 @@
+//! Export the contents of a [`Renderer`] as a paginated PDF document.
+use crate::Renderer;
+use crate::core::{Color, Font, Padding, Pixels, Size};
+
+/// Creates a [`Renderer`] that can export its contents with [`export`].
+pub fn renderer(default_font: Font, default_text_size: Pixels) -> Renderer {
+    let renderer = iced_tiny_skia::Renderer::new(default_font, default_text_size);
+
+    #[cfg(feature = "wgpu-bare")]
+    let renderer = crate::fallback::Renderer::Secondary(renderer);
+
+    renderer
+}
+
+/// Exports the contents drawn with the [`Renderer`] as a PDF document split
+/// vertically in pages of the given size.
+///
+/// Returns `None` if the [`Renderer`] was not created with [`renderer`].
+pub fn export(
+    renderer: &mut Renderer,
+    height: f32,
+    page: Size,
+    margin: Padding,
+    background: Color,
+) -> Option<Vec<u8>> {
+    #[cfg(feature = "wgpu-bare")]
+    let crate::fallback::Renderer::Secondary(renderer) = renderer else {
+        return None;
+    };
+
+    Some(renderer.pdf(height, page, margin, background))
+}
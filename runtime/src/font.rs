@@ -1,16 +1,49 @@
 //! Load and use fonts.
 use crate::Action;
 use crate::task::{self, Task};
+
 use std::borrow::Cow;
+use std::io;
+use std::path::PathBuf;
 
 /// An error while loading a font.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Error {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The font file could not be read.
+    #[error("the font file could not be read: {0}")]
+    Io(io::ErrorKind),
+    /// The bytes do not contain any valid font.
+    #[error("the bytes do not contain any valid font")]
+    Invalid,
+}
 
 /// Load a font from its bytes.
+///
+/// This is an alias of [`load_from_bytes`].
 pub fn load(bytes: impl Into<Cow<'static, [u8]>>) -> Task<Result<(), Error>> {
+    load_from_bytes(bytes)
+}
+
+/// Load a font from its bytes.
+///
+/// Once loaded, the font can be used by its family name; like any
+/// font provided in the settings of the application.
+pub fn load_from_bytes(bytes: impl Into<Cow<'static, [u8]>>) -> Task<Result<(), Error>> {
     task::oneshot(|channel| Action::LoadFont {
         bytes: bytes.into(),
         channel,
     })
 }
+
+/// Load a font from the file at the given path.
+///
+/// The file is read in the background, so it can be used to load fonts
+/// downloaded or chosen by the user after startup.
+pub fn load_from_path(path: impl Into<PathBuf>) -> Task<Result<(), Error>> {
+    let path = path.into();
+
+    Task::future(async move { std::fs::read(path) }).then(|result| match result {
+        Ok(bytes) => load_from_bytes(bytes),
+        Err(error) => Task::done(Err(Error::Io(error.kind()))),
+    })
+}
@@ -15,6 +15,7 @@ pub mod image;
 pub mod keyboard;
 pub mod system;
 pub mod task;
+pub mod undo;
 pub mod user_interface;
 pub mod widget;
 pub mod window;
@@ -23,6 +24,7 @@ pub use iced_core as core;
 pub use iced_futures as futures;
 
 pub use task::Task;
+pub use undo::UndoStack;
 pub use user_interface::UserInterface;
 pub use window::Window;
 
@@ -65,6 +67,12 @@ pub enum Action<T> {
     /// Recreate all user interfaces and redraw all windows.
     Reload,
 
+    /// Change the [`Direction`] of the user interfaces of all windows,
+    /// including the ones opened later, and recreate them.
+    ///
+    /// [`Direction`]: core::layout::Direction
+    SetDirection(core::layout::Direction),
+
     /// Exits the runtime.
     ///
     /// This will normally close any application windows and
@@ -89,6 +97,7 @@ impl<T> Action<T> {
             Action::Image(action) => Err(Action::Image(action)),
             Action::Tick => Err(Action::Tick),
             Action::Reload => Err(Action::Reload),
+            Action::SetDirection(direction) => Err(Action::SetDirection(direction)),
             Action::Exit => Err(Action::Exit),
         }
     }
@@ -115,6 +124,9 @@ where
             Action::Image(_) => write!(f, "Action::Image"),
             Action::Tick => write!(f, "Action::Tick"),
             Action::Reload => write!(f, "Action::Reload"),
+            Action::SetDirection(direction) => {
+                write!(f, "Action::SetDirection({direction:?})")
+            }
             Action::Exit => write!(f, "Action::Exit"),
         }
     }
@@ -127,3 +139,14 @@ where
 pub fn exit<T>() -> Task<T> {
     task::effect(Action::Exit)
 }
+
+/// Creates a [`Task`] that changes the [`Direction`] of layouts in every
+/// window, including the ones opened later, and rebuilds their interfaces.
+///
+/// The [`Direction`] of a single window can be changed with
+/// [`window::set_layout_direction`].
+///
+/// [`Direction`]: core::layout::Direction
+pub fn set_direction<T>(direction: core::layout::Direction) -> Task<T> {
+    task::effect(Action::SetDirection(direction))
+}
@@ -6,6 +6,7 @@ use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::future::{self, FutureExt};
 use crate::futures::futures::stream::{self, Stream, StreamExt};
 use crate::futures::{BoxStream, MaybeSend, boxed_stream};
+use crate::undo::Undoable;
 
 use std::convert::Infallible;
 use std::pin::Pin;
@@ -209,6 +210,25 @@ impl<T> Task<T> {
         self.then(|_| Task::none())
     }
 
+    /// Pairs every output of the [`Task`] with its inverse, produced by the
+    /// given closure.
+    ///
+    /// The resulting [`Undoable`] changes can be recorded in an
+    /// [`UndoStack`](crate::undo::UndoStack) once applied.
+    pub fn undoable(
+        self,
+        mut inverse: impl FnMut(&T) -> T + MaybeSend + 'static,
+    ) -> Task<Undoable<T>>
+    where
+        T: MaybeSend + 'static,
+    {
+        self.map(move |change| {
+            let inverse = inverse(&change);
+
+            Undoable::new(change, inverse)
+        })
+    }
+
     /// Creates a new [`Task`] that can be aborted with the returned [`Handle`].
     pub fn abortable(self) -> (Self, Handle)
     where
@@ -0,0 +1,208 @@
+//! Undo and redo the changes of an application.
+//!
+//! An [`UndoStack`] records every change of an application together with
+//! its inverse; that is, the change that reverts it. Undoing a change
+//! produces its inverse, while redoing it produces the change again.
+//!
+//! Changes are usually messages; applied with the same logic no matter
+//! if they come from the user, an undo, or a redo.
+//!
+//! # Example
+//! ```
+//! use iced_runtime::undo::UndoStack;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Edit {
+//!     Rename(String),
+//! }
+//!
+//! struct State {
+//!     name: String,
+//!     history: UndoStack<Edit>,
+//! }
+//!
+//! impl State {
+//!     // Applies an edit and returns its inverse
+//!     fn apply(&mut self, edit: Edit) -> Edit {
+//!         match edit {
+//!             Edit::Rename(name) => Edit::Rename(std::mem::replace(&mut self.name, name)),
+//!         }
+//!     }
+//!
+//!     fn edit(&mut self, edit: Edit) {
+//!         let inverse = self.apply(edit.clone());
+//!         self.history.push(edit, inverse);
+//!     }
+//!
+//!     fn undo(&mut self) {
+//!         if let Some(edit) = self.history.undo() {
+//!             let _ = self.apply(edit);
+//!         }
+//!     }
+//!
+//!     fn redo(&mut self) {
+//!         if let Some(edit) = self.history.redo() {
+//!             let _ = self.apply(edit);
+//!         }
+//!     }
+//! }
+//!
+//! let mut state = State {
+//!     name: "Foo".to_owned(),
+//!     history: UndoStack::new(),
+//! };
+//!
+//! state.edit(Edit::Rename("Bar".to_owned()));
+//! assert_eq!(state.name, "Bar");
+//!
+//! state.undo();
+//! assert_eq!(state.name, "Foo");
+//!
+//! state.redo();
+//! assert_eq!(state.name, "Bar");
+//! ```
+
+/// A change paired with the change that reverts it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Undoable<T> {
+    /// The change.
+    pub change: T,
+    /// The change that reverts it.
+    pub inverse: T,
+}
+
+impl<T> Undoable<T> {
+    /// Creates a new [`Undoable`] change with the given inverse.
+    pub fn new(change: T, inverse: T) -> Self {
+        Self { change, inverse }
+    }
+
+    /// Swaps the change with its inverse.
+    pub fn invert(self) -> Self {
+        Self {
+            change: self.inverse,
+            inverse: self.change,
+        }
+    }
+
+    /// Maps both the change and its inverse with the given closure.
+    pub fn map<O>(self, mut f: impl FnMut(T) -> O) -> Undoable<O> {
+        Undoable {
+            change: f(self.change),
+            inverse: f(self.inverse),
+        }
+    }
+}
+
+/// A history of [`Undoable`] changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndoStack<T> {
+    undo: Vec<Undoable<T>>,
+    redo: Vec<Undoable<T>>,
+    limit: Option<usize>,
+}
+
+impl<T> UndoStack<T> {
+    /// Creates a new empty [`UndoStack`] with no limit.
+    pub fn new() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Creates a new empty [`UndoStack`] that remembers the given amount
+    /// of changes at most.
+    ///
+    /// The oldest changes are forgotten first.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Records a change with its inverse.
+    ///
+    /// Every undone change is forgotten; so it cannot be redone anymore.
+    pub fn push(&mut self, change: T, inverse: T) {
+        self.record(Undoable::new(change, inverse));
+    }
+
+    /// Records an [`Undoable`] change.
+    ///
+    /// Every undone change is forgotten; so it cannot be redone anymore.
+    pub fn record(&mut self, undoable: Undoable<T>) {
+        self.redo.clear();
+        self.undo.push(undoable);
+
+        if let Some(limit) = self.limit
+            && self.undo.len() > limit
+        {
+            let _ = self.undo.drain(..self.undo.len() - limit);
+        }
+    }
+
+    /// Undoes the last recorded change and returns its inverse, if any.
+    ///
+    /// The inverse must be applied without recording it.
+    pub fn undo(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let undoable = self.undo.pop()?;
+        let inverse = undoable.inverse.clone();
+
+        self.redo.push(undoable);
+
+        Some(inverse)
+    }
+
+    /// Redoes the last undone change and returns it, if any.
+    ///
+    /// The change must be applied without recording it.
+    pub fn redo(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let undoable = self.redo.pop()?;
+        let change = undoable.change.clone();
+
+        self.undo.push(undoable);
+
+        Some(change)
+    }
+
+    /// Returns true if there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there is a change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the next change to undo, if any.
+    pub fn peek_undo(&self) -> Option<&Undoable<T>> {
+        self.undo.last()
+    }
+
+    /// Returns the next change to redo, if any.
+    pub fn peek_redo(&self) -> Option<&Undoable<T>> {
+        self.redo.last()
+    }
+
+    /// Forgets every recorded change.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+impl<T> Default for UndoStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -26,6 +26,7 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<Overlay>,
     bounds: Size,
+    direction: layout::Direction,
 }
 
 struct Overlay {
@@ -96,14 +97,19 @@ where
     ) -> Self {
         let mut root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache {
+            mut state,
+            direction,
+        } = cache;
         state.diff(root.as_widget());
 
-        let base = root.as_widget_mut().layout(
-            &mut state,
-            renderer,
-            &layout::Limits::new(Size::ZERO, bounds),
-        );
+        let base = direction.scope(|| {
+            root.as_widget_mut().layout(
+                &mut state,
+                renderer,
+                &layout::Limits::new(Size::ZERO, bounds),
+            )
+        });
 
         UserInterface {
             root,
@@ -111,6 +117,7 @@ where
             state,
             overlay: None,
             bounds,
+            direction,
         }
     }
 
@@ -207,9 +214,10 @@ where
 
         let (base_cursor, overlay_statuses, overlay_interaction) = if maybe_overlay.is_some() {
             let bounds = self.bounds;
+            let direction = self.direction;
 
             let mut overlay = maybe_overlay.as_mut().unwrap();
-            let mut layout = overlay.layout(renderer, bounds);
+            let mut layout = direction.scope(|| overlay.layout(renderer, bounds));
             let mut event_statuses = Vec::new();
 
             for event in events {
@@ -231,11 +239,13 @@ where
                 if shell.is_layout_invalid() {
                     drop(maybe_overlay);
 
-                    self.base = self.root.as_widget_mut().layout(
-                        &mut self.state,
-                        renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
-                    );
+                    self.base = direction.scope(|| {
+                        self.root.as_widget_mut().layout(
+                            &mut self.state,
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, self.bounds),
+                        )
+                    });
 
                     maybe_overlay = self
                         .root
@@ -256,7 +266,7 @@ where
                     overlay = maybe_overlay.as_mut().unwrap();
 
                     shell.revalidate_layout(|| {
-                        layout = overlay.layout(renderer, bounds);
+                        layout = direction.scope(|| overlay.layout(renderer, bounds));
                         has_layout_changed = true;
                     });
                 }
@@ -334,11 +344,15 @@ where
                 shell.revalidate_layout(|| {
                     has_layout_changed = true;
 
-                    self.base = self.root.as_widget_mut().layout(
-                        &mut self.state,
-                        renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
-                    );
+                    let direction = self.direction;
+
+                    self.base = direction.scope(|| {
+                        self.root.as_widget_mut().layout(
+                            &mut self.state,
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, self.bounds),
+                        )
+                    });
 
                     if let Some(mut overlay) = self
                         .root
@@ -352,7 +366,7 @@ where
                         )
                         .map(overlay::Nested::new)
                     {
-                        let layout = overlay.layout(renderer, self.bounds);
+                        let layout = direction.scope(|| overlay.layout(renderer, self.bounds));
                         let interaction =
                             overlay.mouse_interaction(Layout::new(&layout), cursor, renderer);
 
@@ -552,7 +566,9 @@ where
         {
             if self.overlay.is_none() {
                 self.overlay = Some(Overlay {
-                    layout: overlay.layout(renderer, self.bounds),
+                    layout: self
+                        .direction
+                        .scope(|| overlay.layout(renderer, self.bounds)),
                     interaction: mouse::Interaction::None,
                 });
             }
@@ -565,16 +581,32 @@ where
         }
     }
 
+    /// Returns the bounds the [`UserInterface`] was laid out with.
+    pub fn bounds(&self) -> Size {
+        self.bounds
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                direction: self.direction,
+            },
+            renderer,
+        )
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            direction: self.direction,
+        }
     }
 }
 
@@ -582,6 +614,7 @@ where
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    direction: layout::Direction,
 }
 
 impl Cache {
@@ -592,8 +625,23 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            direction: layout::Direction::default(),
         }
     }
+
+    /// Returns the [`Direction`] the next [`UserInterface`] will be laid out with.
+    ///
+    /// [`Direction`]: layout::Direction
+    pub fn direction(&self) -> layout::Direction {
+        self.direction
+    }
+
+    /// Sets the [`Direction`] the next [`UserInterface`] will be laid out with.
+    ///
+    /// [`Direction`]: layout::Direction
+    pub fn set_direction(&mut self, direction: layout::Direction) {
+        self.direction = direction;
+    }
 }
 
 impl Default for Cache {
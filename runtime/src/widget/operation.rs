@@ -4,7 +4,8 @@ use crate::core::widget::operation;
 use crate::task;
 use crate::{Action, Task};
 
-pub use crate::core::widget::operation::scrollable::{AbsoluteOffset, RelativeOffset};
+pub use crate::core::widget::operation::Bounds;
+pub use crate::core::widget::operation::scrollable::{AbsoluteOffset, Motion, RelativeOffset};
 
 /// Snaps the scrollable with the given [`Id`] to the provided [`RelativeOffset`].
 pub fn snap_to<T>(id: impl Into<Id>, offset: impl Into<RelativeOffset<Option<f32>>>) -> Task<T> {
@@ -30,6 +31,38 @@ pub fn scroll_to<T>(id: impl Into<Id>, offset: impl Into<AbsoluteOffset<Option<f
     )))
 }
 
+/// Smoothly snaps the scrollable with the given [`Id`] to the provided [`RelativeOffset`],
+/// with the given [`Motion`].
+///
+/// The animation is interrupted if the user scrolls while it is in progress.
+pub fn snap_to_animated<T>(
+    id: impl Into<Id>,
+    offset: impl Into<RelativeOffset<Option<f32>>>,
+    motion: Motion,
+) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::snap_to_animated(
+        id.into(),
+        offset.into(),
+        motion,
+    )))
+}
+
+/// Smoothly scrolls the scrollable with the given [`Id`] to the provided [`AbsoluteOffset`],
+/// with the given [`Motion`].
+///
+/// The animation is interrupted if the user scrolls while it is in progress.
+pub fn scroll_to_animated<T>(
+    id: impl Into<Id>,
+    offset: impl Into<AbsoluteOffset<Option<f32>>>,
+    motion: Motion,
+) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::scroll_to_animated(
+        id.into(),
+        offset.into(),
+        motion,
+    )))
+}
+
 /// Scrolls the scrollable with the given [`Id`] by the provided [`AbsoluteOffset`].
 pub fn scroll_by<T>(id: impl Into<Id>, offset: AbsoluteOffset) -> Task<T> {
     task::effect(Action::widget(operation::scrollable::scroll_by(
@@ -38,6 +71,26 @@ pub fn scroll_by<T>(id: impl Into<Id>, offset: AbsoluteOffset) -> Task<T> {
     )))
 }
 
+/// Returns the current [`AbsoluteOffset`] of the scrollable with the given
+/// [`Id`].
+pub fn scroll_offset(id: impl Into<Id>) -> Task<AbsoluteOffset> {
+    task::widget(operation::scrollable::offset(id.into()))
+}
+
+/// Returns the current [`Bounds`] of the widget with the given [`Id`].
+///
+/// The [`Bounds`] are in window coordinates; which makes them useful to
+/// position overlays on top of the widget.
+pub fn bounds(id: impl Into<Id>) -> Task<Bounds> {
+    task::widget(operation::bounds::find(id.into()))
+}
+
+/// Returns whether any part of the widget with the given [`Id`] is visible;
+/// that is, not clipped by the scrollables containing it.
+pub fn is_visible(id: impl Into<Id>) -> Task<bool> {
+    task::widget(operation::bounds::is_visible(id.into()))
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_previous()))
@@ -48,11 +101,33 @@ pub fn focus_next<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_next()))
 }
 
+/// Focuses the previous focusable widget inside the container with the given
+/// [`Id`].
+pub fn focus_previous_in<T>(group: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_previous_in(
+        group.into(),
+    )))
+}
+
+/// Focuses the next focusable widget inside the container with the given
+/// [`Id`].
+pub fn focus_next_in<T>(group: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_next_in(
+        group.into(),
+    )))
+}
+
 /// Returns whether the widget with the given [`Id`] is focused or not.
 pub fn is_focused(id: impl Into<Id>) -> Task<bool> {
     task::widget(operation::focusable::is_focused(id.into()))
 }
 
+/// Returns whether the text of the widget with the given [`Id`] was truncated
+/// to fit its maximum amount of lines.
+pub fn is_truncated(id: impl Into<Id>) -> Task<bool> {
+    task::widget(operation::text::is_truncated(id.into()))
+}
+
 /// Focuses the widget with the given [`Id`].
 pub fn focus<T>(id: impl Into<Id>) -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus(id.into())))
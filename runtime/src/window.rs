@@ -1,9 +1,12 @@
 //! Build window-based GUI applications.
+pub mod geometry;
+pub mod pip;
+
 use crate::core::time::Instant;
 use crate::core::window::{
     Direction, Event, Icon, Id, Level, Mode, Screenshot, Settings, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::futures::Subscription;
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
@@ -119,6 +122,26 @@ pub enum Action {
     /// Change the window [`Level`].
     SetLevel(Id, Level),
 
+    /// Set the radius of the corners of the window.
+    ///
+    /// ## Platform-specific
+    /// - **Windows:** The radius is rounded to the closest corner preference.
+    /// - **Others:** The background of the window is drawn with rounded corners;
+    ///   which needs a transparent window.
+    SetCornerRadius(Id, f32),
+
+    /// Set whether the window should have a drop shadow when it has no decorations.
+    ///
+    /// ## Platform-specific
+    /// Android / iOS / Linux / Orbital / Web: Unsupported; the compositor decides.
+    SetShadow(Id, bool),
+
+    /// Change the layout [`Direction`] of the user interface of the window,
+    /// and recreate it.
+    ///
+    /// [`Direction`]: crate::core::layout::Direction
+    SetLayoutDirection(Id, crate::core::layout::Direction),
+
     /// Show the system menu at cursor position.
     ///
     /// ## Platform-specific
@@ -177,6 +200,10 @@ pub enum Action {
     /// Get the logical dimensions of the monitor containing the window with the given [`Id`].
     GetMonitorSize(Id, oneshot::Sender<Option<Size>>),
 
+    /// Get the logical bounds, in desktop coordinates, of the monitor containing the window
+    /// with the given [`Id`].
+    GetMonitorBounds(Id, oneshot::Sender<Option<Rectangle>>),
+
     /// Set whether the system can automatically organize windows into tabs.
     ///
     /// See <https://developer.apple.com/documentation/appkit/nswindow/1646657-allowsautomaticwindowtabbing>
@@ -419,6 +446,35 @@ pub fn set_level<T>(id: Id, level: Level) -> Task<T> {
     task::effect(crate::Action::Window(Action::SetLevel(id, level)))
 }
 
+/// Sets the radius of the corners of the window.
+///
+/// Compositors that can round windows will do it themselves; otherwise,
+/// the background of the window is drawn with rounded corners; which needs
+/// a [transparent] window.
+///
+/// [transparent]: Settings::transparent
+pub fn set_corner_radius<T>(id: Id, radius: f32) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetCornerRadius(id, radius)))
+}
+
+/// Sets whether the window should have a drop shadow when it has no
+/// decorations, if the compositor supports it.
+pub fn set_shadow<T>(id: Id, shadow: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetShadow(id, shadow)))
+}
+
+/// Changes the layout [`Direction`] of the window with the given [`Id`].
+///
+/// It is replaced the next time [`set_direction`](crate::set_direction)
+/// changes the layout [`Direction`] of every window.
+///
+/// [`Direction`]: crate::core::layout::Direction
+pub fn set_layout_direction<T>(id: Id, direction: crate::core::layout::Direction) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetLayoutDirection(
+        id, direction,
+    )))
+}
+
 /// Shows the [system menu] at cursor position.
 ///
 /// [system menu]: https://en.wikipedia.org/wiki/Common_menus_in_Microsoft_Windows#System_menu
@@ -480,6 +536,12 @@ pub fn monitor_size(id: Id) -> Task<Option<Size>> {
     task::oneshot(move |channel| crate::Action::Window(Action::GetMonitorSize(id, channel)))
 }
 
+/// Gets the logical bounds, in desktop coordinates, of the monitor containing the window
+/// with the given [`Id`].
+pub fn monitor_bounds(id: Id) -> Task<Option<Rectangle>> {
+    task::oneshot(move |channel| crate::Action::Window(Action::GetMonitorBounds(id, channel)))
+}
+
 /// Sets whether the system can automatically organize windows into tabs.
 ///
 /// See <https://developer.apple.com/documentation/appkit/nswindow/1646657-allowsautomaticwindowtabbing>
@@ -0,0 +1,313 @@
+//! Save and restore the geometry of windows across runs.
+//!
+//! A [`Store`] persists the size, position, and maximization of windows to
+//! a file, keyed by the role of each window; like `"main"` or
+//! `"preferences"`. The position is stored in desktop coordinates; so
+//! windows are restored in the same monitor they were left in.
+//!
+//! # Example
+//! ```no_run
+//! use iced_runtime::core::window::{Id, Settings};
+//! use iced_runtime::window::{self, geometry};
+//! use iced_runtime::futures::Subscription;
+//! use iced_runtime::Task;
+//!
+//! struct State {
+//!     windows: geometry::Store,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     WindowOpened(Id),
+//!     WindowChanged(Id),
+//!     CloseRequested(Id),
+//! }
+//!
+//! fn new() -> (State, Task<Message>) {
+//!     let windows = geometry::Store::new("/home/user/.config/app/windows");
+//!     let (_id, open) = windows.open("main", Settings::default());
+//!
+//!     (State { windows }, open.map(Message::WindowOpened))
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::WindowOpened(_) => Task::none(),
+//!         Message::WindowChanged(id) => state.windows.save(id).discard(),
+//!         Message::CloseRequested(id) => state.windows.close(id),
+//!     }
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     Subscription::batch([
+//!         state.windows.listen().map(Message::WindowChanged),
+//!         window::close_requests().map(Message::CloseRequested),
+//!     ])
+//! }
+//! ```
+use crate::core::window::{Event, Id, Position, Settings};
+use crate::core::{Point, Size};
+use crate::futures::event;
+use crate::futures::{MaybeSend, Subscription};
+use crate::task::Task;
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// The persisted geometry of a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    /// The size of the window, when not maximized.
+    pub size: Size,
+    /// The position of the window in desktop coordinates, when not
+    /// maximized; if known.
+    pub position: Option<Point>,
+    /// Whether the window is maximized.
+    pub is_maximized: bool,
+}
+
+impl Geometry {
+    /// Applies the [`Geometry`] to the given window [`Settings`].
+    pub fn apply(&self, settings: Settings) -> Settings {
+        Settings {
+            size: self.size,
+            position: self.position.map_or(settings.position, Position::Specific),
+            maximized: self.is_maximized,
+            ..settings
+        }
+    }
+}
+
+/// A file storing the [`Geometry`] of windows by role.
+///
+/// A [`Store`] can be cheaply cloned; every clone shares the same windows.
+#[derive(Debug, Clone)]
+pub struct Store {
+    path: Arc<PathBuf>,
+    roles: Arc<Mutex<HashMap<Id, String>>>,
+}
+
+impl Store {
+    /// Creates a new [`Store`] persisting to the given file.
+    ///
+    /// The file and its parent directories are created on the first
+    /// save, if needed.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Arc::new(path.into()),
+            roles: Arc::default(),
+        }
+    }
+
+    /// Returns the persisted [`Geometry`] of the given role, if any.
+    pub fn load(&self, role: &str) -> Option<Geometry> {
+        read(&self.path).remove(role)
+    }
+
+    /// Opens a new window with the given role, restoring its persisted
+    /// [`Geometry`] on top of the given [`Settings`].
+    ///
+    /// The window is tracked by the [`Store`]; so it can be saved later
+    /// with [`Store::save`].
+    pub fn open(&self, role: impl Into<String>, settings: Settings) -> (Id, Task<Id>) {
+        let role = role.into();
+
+        let settings = match self.load(&role) {
+            Some(geometry) => geometry.apply(settings),
+            None => settings,
+        };
+
+        let (id, task) = super::open(settings);
+
+        self.track(id, role);
+
+        (id, task)
+    }
+
+    /// Tracks an already opened window with the given role.
+    pub fn track(&self, id: Id, role: impl Into<String>) {
+        let _ = self
+            .roles
+            .lock()
+            .expect("Lock window roles")
+            .insert(id, role.into());
+    }
+
+    /// Returns the role of a tracked window, if any.
+    pub fn role(&self, id: Id) -> Option<String> {
+        self.roles
+            .lock()
+            .expect("Lock window roles")
+            .get(&id)
+            .cloned()
+    }
+
+    /// Saves the current [`Geometry`] of a tracked window.
+    ///
+    /// Windows that are not tracked are ignored. The size and position of
+    /// maximized windows are not saved; so they are restored to their
+    /// previous size when unmaximized.
+    pub fn save(&self, id: Id) -> Task<Result<(), Error>> {
+        let Some(role) = self.role(id) else {
+            return Task::done(Ok(()));
+        };
+
+        let path = Arc::clone(&self.path);
+
+        super::is_maximized(id).then(move |is_maximized| {
+            let path = Arc::clone(&path);
+            let role = role.clone();
+
+            if is_maximized {
+                return Task::done(update(&path, &role, |geometry| {
+                    geometry.is_maximized = true;
+                }));
+            }
+
+            super::size(id).then(move |size| {
+                let path = Arc::clone(&path);
+                let role = role.clone();
+
+                super::position(id).map(move |position| {
+                    update(&path, &role, |geometry| {
+                        *geometry = Geometry {
+                            size,
+                            position,
+                            is_maximized: false,
+                        };
+                    })
+                })
+            })
+        })
+    }
+
+    /// Saves the current [`Geometry`] of a tracked window and closes it.
+    ///
+    /// Errors saving the [`Geometry`] are ignored.
+    pub fn close<T>(&self, id: Id) -> Task<T>
+    where
+        T: MaybeSend + 'static,
+    {
+        let roles = Arc::clone(&self.roles);
+
+        self.save(id).then(move |_| {
+            let _ = roles.lock().expect("Lock window roles").remove(&id);
+
+            super::close(id)
+        })
+    }
+
+    /// Subscribes to the changes of geometry of any window; producing the
+    /// [`Id`] of the window that should be saved.
+    pub fn listen(&self) -> Subscription<Id> {
+        event::listen_with(|event, _status, id| match event {
+            crate::core::Event::Window(Event::Moved(_) | Event::Resized(_)) => Some(id),
+            _ => None,
+        })
+    }
+}
+
+/// An error saving the [`Geometry`] of a window.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("failed to save window geometry: {0}")]
+pub struct Error(Arc<io::Error>);
+
+fn update(path: &Path, role: &str, f: impl FnOnce(&mut Geometry)) -> Result<(), Error> {
+    let mut geometries = read(path);
+
+    let geometry = geometries.entry(role.to_owned()).or_insert(Geometry {
+        size: Settings::default().size,
+        position: None,
+        is_maximized: false,
+    });
+
+    f(geometry);
+
+    write(path, &geometries).map_err(|error| Error(Arc::new(error)))
+}
+
+/// Reads the geometries of a file with a section per role; like:
+///
+/// ```text
+/// [main]
+/// width = 1024
+/// height = 768
+/// x = 100
+/// y = 50
+/// maximized = false
+/// ```
+///
+/// Invalid entries are ignored.
+fn read(path: &Path) -> BTreeMap<String, Geometry> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return BTreeMap::new();
+    };
+
+    let mut geometries = BTreeMap::new();
+    let mut section: Option<(String, BTreeMap<&str, &str>)> = None;
+
+    let mut finish = |section: Option<(String, BTreeMap<&str, &str>)>| {
+        let Some((role, values)) = section else {
+            return;
+        };
+
+        let number = |key: &str| values.get(key).and_then(|value| value.parse::<f32>().ok());
+
+        let (Some(width), Some(height)) = (number("width"), number("height")) else {
+            return;
+        };
+
+        let _ = geometries.insert(
+            role,
+            Geometry {
+                size: Size::new(width, height),
+                position: number("x").zip(number("y")).map(|(x, y)| Point::new(x, y)),
+                is_maximized: values.get("maximized") == Some(&"true"),
+            },
+        );
+    };
+
+    for line in contents.lines().map(str::trim) {
+        if let Some(role) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            finish(section.take());
+            section = Some((role.to_owned(), BTreeMap::new()));
+        } else if let Some((key, value)) = line.split_once('=')
+            && let Some((_, values)) = &mut section
+        {
+            let _ = values.insert(key.trim(), value.trim());
+        }
+    }
+
+    finish(section);
+
+    geometries
+}
+
+fn write(path: &Path, geometries: &BTreeMap<String, Geometry>) -> io::Result<()> {
+    let mut contents = String::new();
+
+    for (role, geometry) in geometries {
+        let _ = writeln!(contents, "[{role}]");
+        let _ = writeln!(contents, "width = {}", geometry.size.width);
+        let _ = writeln!(contents, "height = {}", geometry.size.height);
+
+        if let Some(position) = geometry.position {
+            let _ = writeln!(contents, "x = {}", position.x);
+            let _ = writeln!(contents, "y = {}", position.y);
+        }
+
+        let _ = writeln!(contents, "maximized = {}\n", geometry.is_maximized);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(path, contents)
+}
@@ -0,0 +1,158 @@
+//! Open small picture-in-picture windows that stay on top and snap to the
+//! corners of the screen.
+//!
+//! A picture-in-picture window is a regular window; so it mirrors a widget
+//! subtree by rendering the same subtree in the `view` of its [`Id`]. Since
+//! both windows read the same application state, they always show the same
+//! contents.
+//!
+//! # Example
+//! ```no_run
+//! use iced_runtime::core::window::Id;
+//! use iced_runtime::core::Size;
+//! use iced_runtime::window::pip::{self, Corner};
+//! use iced_runtime::Task;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     PipOpened(Id),
+//!     PipPressed(Id),
+//!     PipReleased(Id),
+//!     PipSnapped(Corner),
+//! }
+//!
+//! fn update(message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::PipOpened(_) => Task::none(),
+//!         Message::PipPressed(id) => pip::drag(id),
+//!         Message::PipReleased(id) => pip::snap(id).map(Message::PipSnapped),
+//!         Message::PipSnapped(_) => Task::none(),
+//!     }
+//! }
+//!
+//! let (_id, open) = pip::open(Size::new(320.0, 180.0), Corner::BottomRight);
+//! let _task = open.map(Message::PipOpened);
+//! ```
+use crate::core::window::{Id, Level, Position, Settings};
+use crate::core::{Point, Rectangle, Size};
+use crate::futures::MaybeSend;
+use crate::task::Task;
+
+/// The margin between a picture-in-picture window and the edges of its
+/// monitor, in logical pixels.
+pub const MARGIN: f32 = 16.0;
+
+/// A corner of a monitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl Corner {
+    /// Returns the [`Corner`] of the given monitor bounds closest to the
+    /// center of the given window bounds.
+    pub fn nearest(monitor: Rectangle, window: Rectangle) -> Self {
+        let center = window.center();
+        let monitor_center = monitor.center();
+
+        match (center.x < monitor_center.x, center.y < monitor_center.y) {
+            (true, true) => Self::TopLeft,
+            (false, true) => Self::TopRight,
+            (true, false) => Self::BottomLeft,
+            (false, false) => Self::BottomRight,
+        }
+    }
+
+    /// Returns the position of a window of the given size placed in the
+    /// [`Corner`] of the given monitor bounds, with the given margin.
+    pub fn position(self, monitor: Rectangle, size: Size, margin: f32) -> Point {
+        let left = monitor.x + margin;
+        let top = monitor.y + margin;
+        let right = monitor.x + monitor.width - size.width - margin;
+        let bottom = monitor.y + monitor.height - size.height - margin;
+
+        match self {
+            Self::TopLeft => Point::new(left, top),
+            Self::TopRight => Point::new(right, top),
+            Self::BottomLeft => Point::new(left, bottom),
+            Self::BottomRight => Point::new(right, bottom),
+        }
+    }
+}
+
+/// Returns the window [`Settings`] of a picture-in-picture window of the
+/// given size.
+///
+/// The window is frameless, transparent, and always on top.
+pub fn settings(size: Size) -> Settings {
+    Settings {
+        size,
+        position: Position::Default,
+        decorations: false,
+        transparent: true,
+        resizable: true,
+        level: Level::AlwaysOnTop,
+        ..Settings::default()
+    }
+}
+
+/// Opens a new picture-in-picture window of the given size in the given
+/// [`Corner`] of the monitor; producing the [`Id`] of the new window on
+/// completion.
+pub fn open(size: Size, corner: Corner) -> (Id, Task<Id>) {
+    let (id, open) = super::open(settings(size));
+
+    (
+        id,
+        open.then(move |id| move_to(id, corner).chain(Task::done(id))),
+    )
+}
+
+/// Moves the picture-in-picture window with the given [`Id`] to the given
+/// [`Corner`] of its monitor.
+pub fn move_to<T>(id: Id, corner: Corner) -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    super::monitor_bounds(id).then(move |monitor| {
+        let Some(monitor) = monitor else {
+            return Task::none();
+        };
+
+        super::size(id).then(move |size| super::move_to(id, corner.position(monitor, size, MARGIN)))
+    })
+}
+
+/// Begins dragging the picture-in-picture window with the given [`Id`]
+/// while the left mouse button is held.
+///
+/// Once the drag is over, the window should be snapped with [`snap`].
+pub fn drag<T>(id: Id) -> Task<T> {
+    super::drag(id)
+}
+
+/// Snaps the picture-in-picture window with the given [`Id`] to the
+/// nearest [`Corner`] of its monitor; producing the [`Corner`] on
+/// completion.
+///
+/// Nothing is produced if the position of the window or its monitor is
+/// unknown; like on Wayland.
+pub fn snap(id: Id) -> Task<Corner> {
+    super::monitor_bounds(id).and_then(move |monitor| {
+        super::position(id).and_then(move |position| {
+            super::size(id).then(move |size| {
+                let corner = Corner::nearest(monitor, Rectangle::new(position, size));
+
+                super::move_to(id, corner.position(monitor, size, MARGIN)).chain(Task::done(corner))
+            })
+        })
+    })
+}
@@ -34,15 +34,20 @@ use crate::message;
 use crate::program::{self, Program};
 use crate::shell;
 use crate::theme;
+use crate::time::Duration;
+use crate::widget;
 use crate::window;
 use crate::{
     Element, Executor, Font, Never, Preset, Result, Settings, Size, Subscription, Task, Theme,
 };
 
 use iced_debug as debug;
+use iced_futures::MaybeSend;
 
 use std::borrow::Cow;
 
+pub mod crash;
+pub mod single_instance;
 pub mod timed;
 
 pub use timed::timed;
@@ -158,6 +163,7 @@ where
         settings: Settings::default(),
         window: window::Settings::default(),
         presets: Vec::new(),
+        single_instance: None,
     }
 }
 
@@ -174,6 +180,7 @@ pub struct Application<P: Program> {
     settings: Settings,
     window: window::Settings,
     presets: Vec<Preset<P::State, P::Message>>,
+    single_instance: Option<single_instance::Instance>,
 }
 
 impl<P: Program> Application<P> {
@@ -183,6 +190,13 @@ impl<P: Program> Application<P> {
         Self: 'static,
         P::Message: message::MaybeDebug + message::MaybeClone,
     {
+        if let Some(instance) = &self.single_instance {
+            // Another instance received the arguments
+            if let Ok(single_instance::Claim::Secondary) = instance.claim() {
+                return Ok(());
+            }
+        }
+
         #[cfg(feature = "debug")]
         iced_debug::init(iced_debug::Metadata {
             name: P::name(),
@@ -271,6 +285,17 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::elastic_resize`] of the [`Application`].
+    pub fn elastic_resize(self, settle: impl Into<Option<Duration>>) -> Self {
+        Self {
+            window: window::Settings {
+                elastic_resize: settle.into(),
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::size`] of the [`Application`].
     pub fn window_size(self, size: impl Into<Size>) -> Self {
         Self {
@@ -347,6 +372,7 @@ impl<P: Program> Application<P> {
             settings: self.settings,
             window: self.window,
             presets: self.presets,
+            single_instance: self.single_instance,
         }
     }
 
@@ -360,6 +386,7 @@ impl<P: Program> Application<P> {
             settings: self.settings,
             window: self.window,
             presets: self.presets,
+            single_instance: self.single_instance,
         }
     }
 
@@ -373,6 +400,7 @@ impl<P: Program> Application<P> {
             settings: self.settings,
             window: self.window,
             presets: self.presets,
+            single_instance: self.single_instance,
         }
     }
 
@@ -386,6 +414,7 @@ impl<P: Program> Application<P> {
             settings: self.settings,
             window: self.window,
             presets: self.presets,
+            single_instance: self.single_instance,
         }
     }
 
@@ -399,6 +428,7 @@ impl<P: Program> Application<P> {
             settings: self.settings,
             window: self.window,
             presets: self.presets,
+            single_instance: self.single_instance,
         }
     }
 
@@ -414,6 +444,63 @@ impl<P: Program> Application<P> {
             settings: self.settings,
             window: self.window,
             presets: self.presets,
+            single_instance: self.single_instance,
+        }
+    }
+
+    /// Makes the [`Application`] single instance with the given name.
+    ///
+    /// When the [`Application`] runs, if another instance with the same
+    /// name is already running, the command-line arguments are forwarded to
+    /// it and [`run`](Self::run) returns right away. Otherwise, the arguments
+    /// of every new instance are turned into messages with the given closure
+    /// and the window of the [`Application`] is raised.
+    pub fn single_instance<F>(
+        self,
+        name: impl Into<String>,
+        on_activate: F,
+    ) -> Application<impl Program<State = P::State, Message = P::Message, Theme = P::Theme>>
+    where
+        F: Fn(single_instance::Activation) -> P::Message + MaybeSend + Clone + 'static,
+    {
+        let instance = single_instance::Instance::new(name.into());
+
+        Application {
+            raw: single_instance::attach(self.raw, instance.clone(), on_activate),
+            settings: self.settings,
+            window: self.window,
+            presets: self.presets,
+            single_instance: Some(instance),
+        }
+    }
+
+    /// Catches the panics of the [`Application`] and reports them with the
+    /// given closure.
+    ///
+    /// A panic in the `update`, `view`, or `subscription` logic of the
+    /// [`Application`] does not abort it; its windows display an error
+    /// screen until the user chooses to continue instead.
+    ///
+    /// See the [`crash`] module for more details.
+    pub fn on_crash(
+        self,
+        on_crash: impl Fn(&crash::Report) + 'static,
+    ) -> Application<impl Program<State = P::State, Message = P::Message, Theme = P::Theme>>
+    where
+        P::Message: std::fmt::Debug,
+        P::Theme: widget::text::Catalog
+            + widget::container::Catalog
+            + widget::button::Catalog
+            + widget::scrollable::Catalog
+            + 'static,
+        P::Renderer: 'static,
+    {
+        Application {
+            raw: crash::attach(self.raw, on_crash),
+            settings: self.settings,
+            window: self.window,
+            presets: self.presets,
+            single_instance: self.single_instance,
         }
     }
 
@@ -0,0 +1,489 @@
+//! Recover from the panics of an [`Application`] and report them.
+//!
+//! When an [`Application`] handles its crashes, a panic in its `update`,
+//! `view`, or `subscription` logic does not abort the process. Instead, a
+//! [`Report`] is built with the latest messages of the [`Application`] and
+//! handed to a callback; which can log it or send it somewhere. Meanwhile,
+//! its windows display an error screen until the user chooses to continue.
+//!
+//! # Example
+//! ```no_run
+//! use iced::widget::{button, text};
+//! use iced::Element;
+//!
+//! pub fn main() -> iced::Result {
+//!     iced::application(u64::default, update, view)
+//!         .on_crash(|report| eprintln!("{report}"))
+//!         .run()
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! fn update(value: &mut u64, message: Message) {
+//!     match message {
+//!         Message::Increment => *value += 1,
+//!     }
+//! }
+//!
+//! fn view(value: &u64) -> Element<'_, Message> {
+//!     button(text(value)).on_press(Message::Increment).into()
+//! }
+//! ```
+//!
+//! [`Application`]: crate::Application
+use crate::advanced::layout::{self, Layout};
+use crate::advanced::renderer;
+use crate::advanced::widget::{self, Tree, Widget};
+use crate::advanced::{Clipboard, Shell, mouse};
+use crate::core::clipboard;
+use crate::theme;
+use crate::widget::{button, column, container, row, scrollable, text};
+use crate::window;
+use crate::{Element, Event, Fill, Font, Length, Program, Rectangle, Settings, Size};
+use crate::{Subscription, Task};
+
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, Once};
+
+/// The amount of messages kept for a [`Report`].
+const MESSAGES: usize = 50;
+
+/// The report of a panic of an [`Application`].
+///
+/// [`Application`]: crate::Application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The logic of the [`Application`] that panicked.
+    ///
+    /// [`Application`]: crate::Application
+    pub phase: Phase,
+    /// The message of the panic.
+    pub message: String,
+    /// The source location of the panic, if known.
+    pub location: Option<String>,
+    /// The backtrace of the panic.
+    pub backtrace: String,
+    /// The latest messages processed before the panic, oldest first.
+    pub messages: Vec<String>,
+    /// The type name of the renderer of the [`Application`].
+    ///
+    /// [`Application`]: crate::Application
+    pub renderer: &'static str,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "panicked in {}: {}", self.phase, self.message)?;
+
+        if let Some(location) = &self.location {
+            writeln!(f, "at {location}")?;
+        }
+
+        writeln!(f, "\nrenderer: {}", self.renderer)?;
+        writeln!(f, "\nlatest messages:")?;
+
+        for message in &self.messages {
+            writeln!(f, "  {message}")?;
+        }
+
+        write!(f, "\nbacktrace:\n{}", self.backtrace)
+    }
+}
+
+/// The logic of an [`Application`] that may panic.
+///
+/// [`Application`]: crate::Application
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The `update` logic.
+    Update,
+    /// The `view` logic.
+    View,
+    /// The `subscription` logic.
+    Subscription,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Update => "update",
+            Self::View => "view",
+            Self::Subscription => "subscription",
+        })
+    }
+}
+
+/// Decorates a [`Program`] to catch the panics of its logic, report them
+/// with the given closure, and display an error screen afterwards.
+pub(crate) fn attach<P, F>(
+    program: P,
+    on_crash: F,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme>
+where
+    P: Program,
+    P::Message: fmt::Debug,
+    P::Theme: text::Catalog + container::Catalog + button::Catalog + scrollable::Catalog + 'static,
+    P::Renderer: 'static,
+    F: Fn(&Report) + 'static,
+{
+    struct Crashes<P, F> {
+        program: P,
+        on_crash: F,
+        messages: Mutex<VecDeque<String>>,
+        report: Arc<Mutex<Option<Report>>>,
+    }
+
+    impl<P, F> Crashes<P, F>
+    where
+        P: Program,
+        F: Fn(&Report),
+    {
+        fn guard<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> Result<T, Report> {
+            let payload = match panic::catch_unwind(AssertUnwindSafe(f)) {
+                Ok(output) => return Ok(output),
+                Err(payload) => payload,
+            };
+
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| (*message).to_owned())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| String::from("Box<dyn Any>"));
+
+            let (location, backtrace) = PANIC.with_borrow_mut(Option::take).unwrap_or_default();
+
+            let report = Report {
+                phase,
+                message,
+                location,
+                backtrace,
+                messages: self
+                    .messages
+                    .lock()
+                    .expect("Lock messages")
+                    .iter()
+                    .cloned()
+                    .collect(),
+                renderer: std::any::type_name::<P::Renderer>(),
+            };
+
+            (self.on_crash)(&report);
+
+            *self.report.lock().expect("Lock report") = Some(report.clone());
+
+            Err(report)
+        }
+    }
+
+    impl<P, F> Program for Crashes<P, F>
+    where
+        P: Program,
+        P::Message: fmt::Debug,
+        P::Theme:
+            text::Catalog + container::Catalog + button::Catalog + scrollable::Catalog + 'static,
+        P::Renderer: 'static,
+        F: Fn(&Report) + 'static,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn name() -> &'static str {
+            P::name()
+        }
+
+        fn settings(&self) -> Settings {
+            self.program.settings()
+        }
+
+        fn window(&self) -> Option<window::Settings> {
+            self.program.window()
+        }
+
+        fn boot(&self) -> (Self::State, Task<Self::Message>) {
+            self.program.boot()
+        }
+
+        fn update(&self, state: &mut Self::State, message: Self::Message) -> Task<Self::Message> {
+            {
+                let mut messages = self.messages.lock().expect("Lock messages");
+
+                if messages.len() == MESSAGES {
+                    let _ = messages.pop_front();
+                }
+
+                messages.push_back(format!("{message:?}"));
+            }
+
+            self.guard(Phase::Update, || self.program.update(state, message))
+                .unwrap_or_else(|_| Task::none())
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            let report = self.report.lock().expect("Lock report").clone();
+
+            let report = match report {
+                Some(report) => report,
+                None => match self.guard(Phase::View, || self.program.view(state, window)) {
+                    Ok(view) => return view,
+                    Err(report) => report,
+                },
+            };
+
+            screen(report, &self.report)
+        }
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn subscription(&self, state: &Self::State) -> Subscription<Self::Message> {
+            self.guard(Phase::Subscription, || self.program.subscription(state))
+                .unwrap_or_else(|_| Subscription::none())
+        }
+
+        fn theme(&self, state: &Self::State, window: window::Id) -> Option<Self::Theme> {
+            self.program.theme(state, window)
+        }
+
+        fn style(&self, state: &Self::State, theme: &Self::Theme) -> theme::Style {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f32 {
+            self.program.scale_factor(state, window)
+        }
+    }
+
+    install_hook();
+
+    Crashes {
+        program,
+        on_crash,
+        messages: Mutex::new(VecDeque::with_capacity(MESSAGES)),
+        report: Arc::new(Mutex::new(None)),
+    }
+}
+
+thread_local! {
+    /// The location and backtrace of the latest panic of the thread.
+    static PANIC: RefCell<Option<(Option<String>, String)>> = const { RefCell::new(None) };
+}
+
+/// Chains a panic hook that keeps the location and backtrace of every
+/// panic; which are not available once caught.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(ToString::to_string);
+            let backtrace = Backtrace::force_capture().to_string();
+
+            PANIC.set(Some((location, backtrace)));
+
+            previous(info);
+        }));
+    });
+}
+
+/// The error screen displayed after a crash.
+fn screen<'a, Message, Theme, Renderer>(
+    report: Report,
+    current: &Arc<Mutex<Option<Report>>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text::Catalog + container::Catalog + button::Catalog + scrollable::Catalog + 'a,
+    Renderer: crate::advanced::text::Renderer<Font = Font> + 'a,
+{
+    let details = report.to_string();
+    let current = Arc::clone(current);
+
+    let copy = {
+        let details = details.clone();
+
+        move |clipboard: &mut dyn Clipboard| {
+            clipboard.write(clipboard::Kind::Standard, details.clone());
+        }
+    };
+
+    let actions = row![
+        Action::new(button(text("Copy report")), copy),
+        Action::new(button(text("Continue")), move |_clipboard| {
+            *current.lock().expect("Lock report") = None;
+        }),
+    ]
+    .spacing(10);
+
+    container(
+        column![
+            text("Something went wrong").size(24),
+            text(format!(
+                "The application panicked in its {} logic.",
+                report.phase
+            )),
+            text(report.message),
+            scrollable(text(details).font(Font::MONOSPACE).size(12))
+                .width(Fill)
+                .height(Fill),
+            actions,
+        ]
+        .spacing(10),
+    )
+    .padding(20)
+    .into()
+}
+
+/// A widget that runs an action when its content publishes a message.
+struct Action<'a, Theme, Renderer> {
+    content: Element<'a, (), Theme, Renderer>,
+    on_press: Box<dyn Fn(&mut dyn Clipboard) + 'a>,
+}
+
+impl<'a, Theme, Renderer> Action<'a, Theme, Renderer> {
+    fn new(
+        content: button::Button<'a, (), Theme, Renderer>,
+        on_press: impl Fn(&mut dyn Clipboard) + 'a,
+    ) -> Self
+    where
+        Theme: button::Catalog + 'a,
+        Renderer: crate::advanced::Renderer + 'a,
+    {
+        Self {
+            content: content.on_press(()).into(),
+            on_press: Box::new(on_press),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Action<'_, Theme, Renderer>
+where
+    Renderer: crate::advanced::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(tree, renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut presses = Vec::new();
+        let mut local_shell = Shell::new(&mut presses);
+
+        self.content.as_widget_mut().update(
+            tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        drop(local_shell);
+
+        if !presses.is_empty() {
+            (self.on_press)(clipboard);
+            shell.invalidate_widgets();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Action<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: 'a,
+    Renderer: crate::advanced::Renderer + 'a,
+{
+    fn from(action: Action<'a, Theme, Renderer>) -> Self {
+        Element::new(action)
+    }
+}
@@ -0,0 +1,441 @@
+//! Run a single instance of an [`Application`] at a time.
+//!
+//! When an [`Application`] is made single instance, launching it again
+//! forwards its command-line arguments to the running instance and exits
+//! right away. The running instance receives them as an [`Activation`]
+//! message and its window is raised.
+//!
+//! The name is claimed when the [`Application`] runs. Instances hold a lock
+//! file while running and talk to each other over a local socket stored in a
+//! directory private to the user.
+//!
+//! [`Application`]: crate::Application
+use crate::futures::channel::mpsc;
+use crate::theme;
+use crate::window;
+use crate::{Element, Program, Settings, Subscription, Task};
+
+use iced_futures::MaybeSend;
+
+use std::fs::{File, TryLockError};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The arguments forwarded by a new instance of an application.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Activation {
+    /// The command-line arguments of the new instance, without the name
+    /// of the executable.
+    pub arguments: Vec<String>,
+}
+
+impl Activation {
+    /// Returns the arguments of the [`Activation`] that look like URIs;
+    /// like `https://iced.rs` or `myapp://open/42`.
+    pub fn uris(&self) -> impl Iterator<Item = &str> {
+        self.arguments
+            .iter()
+            .map(String::as_str)
+            .filter(|argument| {
+                argument.split_once("://").is_some_and(|(scheme, _)| {
+                    !scheme.is_empty()
+                        && scheme
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                })
+            })
+    }
+}
+
+/// The result of trying to become the running instance.
+pub(crate) enum Claim {
+    /// This is the only instance.
+    Primary,
+    /// Another instance was running and the arguments were forwarded to it.
+    Secondary,
+}
+
+/// The name of a single instance application, claimed when it runs.
+#[derive(Debug, Clone)]
+pub(crate) struct Instance {
+    name: String,
+    listener: Arc<Mutex<Option<Listener>>>,
+}
+
+impl Instance {
+    /// Creates a new [`Instance`] with the given name.
+    pub(crate) fn new(name: String) -> Self {
+        Self {
+            name,
+            listener: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Tries to become the running instance of the application; forwarding
+    /// the arguments of the process otherwise.
+    pub(crate) fn claim(&self) -> io::Result<Claim> {
+        let arguments: Vec<String> = std::env::args().skip(1).collect();
+        let message = arguments.join("\0");
+
+        let directory = platform::directory()?;
+        let name = sanitize(&self.name);
+
+        let lock = File::options()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(directory.join(format!("{name}.lock")))?;
+
+        match lock.try_lock() {
+            Ok(()) => {
+                let listener = platform::bind(&directory, &name)?;
+
+                *self.listener.lock().expect("Lock listener") = Some(Listener {
+                    raw: listener,
+                    _lock: lock,
+                });
+
+                Ok(Claim::Primary)
+            }
+            Err(TryLockError::WouldBlock) => {
+                // The running instance may not be listening yet
+                let mut attempts = 0;
+
+                loop {
+                    match platform::forward(&directory, &name, &message) {
+                        Ok(()) => return Ok(Claim::Secondary),
+                        Err(_) if attempts < 20 => {
+                            attempts += 1;
+                            std::thread::sleep(Duration::from_millis(50));
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+            }
+            Err(TryLockError::Error(error)) => Err(error),
+        }
+    }
+}
+
+/// The listener of the running instance; holding its lock file.
+#[derive(Debug)]
+pub(crate) struct Listener {
+    raw: platform::Listener,
+    _lock: File,
+}
+
+/// Decorates a [`Program`] to produce a message for every [`Activation`]
+/// received by the given [`Listener`] and raise its latest window.
+pub(crate) fn attach<P, F>(
+    program: P,
+    instance: Instance,
+    on_activate: F,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme>
+where
+    P: Program,
+    F: Fn(Activation) -> P::Message + MaybeSend + Clone + 'static,
+{
+    struct SingleInstance<P, F> {
+        program: P,
+        on_activate: F,
+        receiver: Receiver,
+    }
+
+    impl<P, F> Program for SingleInstance<P, F>
+    where
+        P: Program,
+        F: Fn(Activation) -> P::Message + MaybeSend + Clone + 'static,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn name() -> &'static str {
+            P::name()
+        }
+
+        fn settings(&self) -> Settings {
+            self.program.settings()
+        }
+
+        fn window(&self) -> Option<window::Settings> {
+            self.program.window()
+        }
+
+        fn boot(&self) -> (Self::State, Task<Self::Message>) {
+            self.program.boot()
+        }
+
+        fn update(&self, state: &mut Self::State, message: Self::Message) -> Task<Self::Message> {
+            let task = self.program.update(state, message);
+
+            if self
+                .receiver
+                .should_raise
+                .swap(false, atomic::Ordering::Relaxed)
+            {
+                Task::batch([
+                    task,
+                    window::latest().and_then(|id| {
+                        Task::batch([window::minimize(id, false), window::gain_focus(id)])
+                    }),
+                ])
+            } else {
+                task
+            }
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn subscription(&self, state: &Self::State) -> Subscription<Self::Message> {
+            Subscription::batch([
+                self.program.subscription(state),
+                Subscription::run_with(self.receiver.clone(), Receiver::listen)
+                    .map(self.on_activate.clone()),
+            ])
+        }
+
+        fn theme(&self, state: &Self::State, window: window::Id) -> Option<Self::Theme> {
+            self.program.theme(state, window)
+        }
+
+        fn style(&self, state: &Self::State, theme: &Self::Theme) -> theme::Style {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f32 {
+            self.program.scale_factor(state, window)
+        }
+    }
+
+    SingleInstance {
+        program,
+        on_activate,
+        receiver: Receiver {
+            instance,
+            should_raise: Arc::new(AtomicBool::new(false)),
+        },
+    }
+}
+
+#[derive(Clone)]
+struct Receiver {
+    instance: Instance,
+    should_raise: Arc<AtomicBool>,
+}
+
+impl Receiver {
+    fn listen(&self) -> mpsc::UnboundedReceiver<Activation> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let Some(listener) = self.instance.listener.lock().expect("Lock listener").take() else {
+            return receiver;
+        };
+
+        let should_raise = Arc::clone(&self.should_raise);
+
+        let _ = std::thread::Builder::new()
+            .name(format!("{} single instance", self.instance.name))
+            .spawn(move || {
+                loop {
+                    let Ok(contents) = listener.raw.accept() else {
+                        continue;
+                    };
+
+                    let arguments = if contents.is_empty() {
+                        Vec::new()
+                    } else {
+                        contents.split('\0').map(str::to_owned).collect()
+                    };
+
+                    should_raise.store(true, atomic::Ordering::Relaxed);
+
+                    if sender.unbounded_send(Activation { arguments }).is_err() {
+                        break;
+                    }
+                }
+            });
+
+        receiver
+    }
+}
+
+impl Hash for Receiver {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance.name.hash(state);
+    }
+}
+
+/// Replaces the characters of a name that are not safe in a file name.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::fs;
+    use std::io::{self, Read, Write};
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+
+    #[derive(Debug)]
+    pub struct Listener(UnixListener);
+
+    impl Listener {
+        /// Waits for a new instance and returns its message.
+        pub fn accept(&self) -> io::Result<String> {
+            let (mut stream, _) = self.0.accept()?;
+
+            let mut contents = String::new();
+            let _ = stream.read_to_string(&mut contents)?;
+
+            Ok(contents)
+        }
+    }
+
+    /// Returns the directory of the sockets; only accessible by the user.
+    pub fn directory() -> io::Result<PathBuf> {
+        if let Some(directory) = std::env::var_os("XDG_RUNTIME_DIR") {
+            return Ok(PathBuf::from(directory));
+        }
+
+        // A private directory inside the shared temporary one
+        let user = std::env::var("USER").unwrap_or_default();
+        let directory = std::env::temp_dir().join(format!("iced-{}", super::sanitize(&user)));
+
+        match fs::DirBuilder::new().mode(0o700).create(&directory) {
+            Err(error) if error.kind() != io::ErrorKind::AlreadyExists => {
+                return Err(error);
+            }
+            _ => {}
+        }
+
+        let metadata = fs::symlink_metadata(&directory)?;
+
+        if !metadata.is_dir() || metadata.permissions().mode() & 0o077 != 0 {
+            return Err(io::ErrorKind::PermissionDenied.into());
+        }
+
+        Ok(directory)
+    }
+
+    pub fn forward(directory: &Path, name: &str, message: &str) -> io::Result<()> {
+        let mut stream = UnixStream::connect(directory.join(format!("{name}.sock")))?;
+
+        stream.write_all(message.as_bytes())?;
+        stream.flush()
+    }
+
+    pub fn bind(directory: &Path, name: &str) -> io::Result<Listener> {
+        let path = directory.join(format!("{name}.sock"));
+
+        // Only the socket of a crashed instance is removed
+        match UnixStream::connect(&path) {
+            Ok(_) => return Err(io::ErrorKind::AddrInUse.into()),
+            Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => {
+                fs::remove_file(&path)?;
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error),
+        }
+
+        UnixListener::bind(path).map(Listener)
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    use std::fs;
+    use std::hash::{BuildHasher, Hasher, RandomState};
+    use std::io::{self, Read, Write};
+    use std::net::{Ipv4Addr, TcpListener, TcpStream};
+    use std::path::{Path, PathBuf};
+
+    /// Any local process can connect to the [`Listener`]; so a new instance
+    /// must send the token stored next to the port in a file of the user.
+    #[derive(Debug)]
+    pub struct Listener {
+        raw: TcpListener,
+        token: String,
+    }
+
+    impl Listener {
+        /// Waits for a new instance and returns its message.
+        pub fn accept(&self) -> io::Result<String> {
+            let (mut stream, _) = self.raw.accept()?;
+
+            let mut contents = String::new();
+            let _ = stream.read_to_string(&mut contents)?;
+
+            match contents.split_once('\n') {
+                Some((token, message)) if token == self.token => Ok(message.to_owned()),
+                _ => Err(io::ErrorKind::PermissionDenied.into()),
+            }
+        }
+    }
+
+    /// Returns the directory of the port files; only accessible by the user.
+    pub fn directory() -> io::Result<PathBuf> {
+        Ok(std::env::var_os("LOCALAPPDATA")
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir))
+    }
+
+    pub fn forward(directory: &Path, name: &str, message: &str) -> io::Result<()> {
+        let contents = fs::read_to_string(directory.join(format!("{name}.port")))?;
+
+        let (port, token) = contents
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))?;
+
+        let port: u16 = port
+            .parse()
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidData))?;
+
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))?;
+
+        stream.write_all(format!("{token}\n{message}").as_bytes())?;
+        stream.flush()
+    }
+
+    pub fn bind(directory: &Path, name: &str) -> io::Result<Listener> {
+        let raw = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+
+        // Every `RandomState` is seeded with random keys
+        let token: String = (0..4)
+            .map(|_| format!("{:016x}", RandomState::new().build_hasher().finish()))
+            .collect();
+
+        fs::write(
+            directory.join(format!("{name}.port")),
+            format!("{} {token}", raw.local_addr()?.port()),
+        )?;
+
+        Ok(Listener { raw, token })
+    }
+}
@@ -125,6 +125,7 @@ where
         settings: Settings::default(),
         window: window::Settings::default(),
         presets: Vec::new(),
+        single_instance: None,
     }
 }
 
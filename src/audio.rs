@@ -0,0 +1,549 @@
+//! Control the volume and the devices of the audio server of the system.
+//!
+//! The audio server is driven through `pactl`; which talks to both
+//! PulseAudio and PipeWire (through `pipewire-pulse`) on Linux and BSDs.
+//! `pactl` is not bundled; it must be installed on the system at runtime.
+//!
+//! # Example
+//! ```no_run
+//! use iced::audio::{self, Device};
+//! use iced::widget::{column, slider, text};
+//! use iced::{Element, Subscription, Task};
+//!
+//! #[derive(Default)]
+//! struct State {
+//!     speakers: Vec<Device>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     SinksChanged(Result<Vec<Device>, audio::Error>),
+//!     VolumeChanged(Device, f32),
+//!     VolumeSet(Result<(), audio::Error>),
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::SinksChanged(Ok(speakers)) => {
+//!             state.speakers = speakers;
+//!             Task::none()
+//!         }
+//!         Message::SinksChanged(Err(error)) => {
+//!             eprintln!("{error}");
+//!             Task::none()
+//!         }
+//!         Message::VolumeChanged(device, volume) => {
+//!             audio::set_volume(&device, volume).map(Message::VolumeSet)
+//!         }
+//!         Message::VolumeSet(_) => Task::none(),
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column(state.speakers.iter().map(|device| {
+//!         column![
+//!             text(device.description.as_str()),
+//!             slider(0.0..=1.0, device.volume, |volume| {
+//!                 Message::VolumeChanged(device.clone(), volume)
+//!             })
+//!             .step(0.01),
+//!         ]
+//!         .into()
+//!     }))
+//!     .into()
+//! }
+//!
+//! fn subscription(_state: &State) -> Subscription<Message> {
+//!     audio::sinks().map(Message::SinksChanged)
+//! }
+//! ```
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::runtime::task;
+use crate::stream;
+use crate::{Subscription, Task};
+
+use std::io;
+use std::sync::Arc;
+
+/// Returns a [`Subscription`] that produces the output devices of the
+/// system every time any of them changes; including their list when it
+/// starts.
+pub fn sinks() -> Subscription<Result<Vec<Device>, Error>> {
+    watch(Kind::Sink)
+}
+
+/// Returns a [`Subscription`] that produces the input devices of the
+/// system every time any of them changes; including their list when it
+/// starts.
+///
+/// The monitors of output devices are not included.
+pub fn sources() -> Subscription<Result<Vec<Device>, Error>> {
+    watch(Kind::Source)
+}
+
+/// Lists the devices of the given [`Kind`] once.
+pub fn list(kind: Kind) -> Task<Result<Vec<Device>, Error>> {
+    task::try_blocking(move |mut sender| {
+        let _ = sender.try_send(platform::list(kind)?);
+
+        Ok(())
+    })
+}
+
+/// Sets the volume of the given [`Device`] for all of its channels.
+///
+/// A volume of `1.0` is the nominal volume of the device; higher values
+/// amplify its signal.
+pub fn set_volume(device: &Device, volume: f32) -> Task<Result<(), Error>> {
+    let percent = format!("{}%", (volume.max(0.0) * 100.0).round());
+
+    set(device, "volume", percent)
+}
+
+/// Mutes or unmutes the given [`Device`].
+pub fn set_muted(device: &Device, is_muted: bool) -> Task<Result<(), Error>> {
+    set(device, "mute", if is_muted { "1" } else { "0" }.to_owned())
+}
+
+/// Makes the given [`Device`] the default one of its [`Kind`].
+pub fn set_default(device: &Device) -> Task<Result<(), Error>> {
+    let kind = device.kind;
+    let name = device.name.clone();
+
+    task::try_blocking(move |mut sender| {
+        platform::pactl(&[&format!("set-default-{}", kind.as_str()), &name])?;
+
+        let _ = sender.try_send(());
+
+        Ok(())
+    })
+}
+
+/// An audio device of the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Device {
+    /// The [`Kind`] of the device.
+    pub kind: Kind,
+    /// The unique name of the device; like
+    /// `alsa_output.pci-0000_00_1f.3.analog-stereo`.
+    pub name: String,
+    /// The human-readable description of the device; like `Built-in Audio`.
+    pub description: String,
+    /// The average volume of the channels of the device.
+    ///
+    /// A volume of `1.0` is the nominal volume of the device.
+    pub volume: f32,
+    /// Whether the device is muted.
+    pub is_muted: bool,
+    /// Whether the device is the default one of its [`Kind`].
+    pub is_default: bool,
+}
+
+/// The kind of an audio [`Device`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// An output device; like speakers or headphones.
+    Sink,
+    /// An input device; like a microphone.
+    Source,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::Sink => "sink",
+            Kind::Source => "source",
+        }
+    }
+}
+
+/// An error controlling the audio server.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The platform does not support controlling the audio server.
+    #[error("controlling the audio server is not supported on this platform")]
+    Unsupported,
+
+    /// A program needed to control the audio server is not installed.
+    #[error("`{0}` is needed to control the audio server, but it is not installed")]
+    MissingProgram(&'static str),
+
+    /// The audio server refused a request; or it is not running.
+    #[error("the audio server failed: {0}")]
+    Failed(String),
+
+    /// The audio server replied with an unexpected output.
+    #[error("the audio server replied with an unexpected output: {0}")]
+    InvalidOutput(String),
+
+    /// The audio server could not be reached.
+    #[error("the audio server could not be reached: {0}")]
+    Io(Arc<io::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+fn set(device: &Device, property: &'static str, value: String) -> Task<Result<(), Error>> {
+    let command = format!("set-{}-{property}", device.kind.as_str());
+    let name = device.name.clone();
+
+    task::try_blocking(move |mut sender| {
+        platform::pactl(&[&command, &name, &value])?;
+
+        let _ = sender.try_send(());
+
+        Ok(())
+    })
+}
+
+fn watch(kind: Kind) -> Subscription<Result<Vec<Device>, Error>> {
+    Subscription::run_with(kind, |kind| {
+        let kind = *kind;
+
+        stream::channel(1, async move |mut output| {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            // The watcher is owned by the stream; so it is stopped as soon
+            // as the subscription is dropped
+            let _watcher = match platform::watch(kind, sender) {
+                Ok(watcher) => watcher,
+                Err(error) => {
+                    let _ = output.send(Err(error)).await;
+                    return;
+                }
+            };
+
+            while let Some(devices) = receiver.next().await {
+                let _ = output.send(devices).await;
+            }
+        })
+    })
+}
+
+type Sender = mpsc::UnboundedSender<Result<Vec<Device>, Error>>;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    use super::{Device, Error, Kind, Sender};
+
+    use std::io::{self, BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+
+    /// The volume of a channel at the nominal volume of a device.
+    const NORMAL_VOLUME: f32 = 65536.0;
+
+    /// A running device watcher, which stops when dropped.
+    pub struct Watcher {
+        _subscriber: Guard,
+    }
+
+    /// Starts sending the devices of the given [`Kind`] to the given
+    /// [`Sender`] every time they change, until it is closed.
+    pub fn watch(kind: Kind, sender: Sender) -> Result<Watcher, Error> {
+        let mut subscriber = Guard(
+            Command::new("pactl")
+                .arg("subscribe")
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .map_err(missing)?,
+        );
+
+        let stdout = subscriber
+            .0
+            .stdout
+            .take()
+            .ok_or_else(|| io::Error::other("`pactl subscribe` has no output"))?;
+
+        // Every event is a line like `Event 'change' on sink #42`; the
+        // server changes when the default devices do.
+        let facility = format!(" on {} #", kind.as_str());
+
+        let run = move |sender: &Sender| -> Result<(), Error> {
+            if sender.unbounded_send(Ok(list(kind)?)).is_err() {
+                return Ok(());
+            }
+
+            // Killing the subscriber when the watcher is dropped closes its
+            // output; which ends the loop
+            for line in BufReader::new(stdout).lines() {
+                let line = line?;
+
+                if sender.is_closed() {
+                    break;
+                }
+
+                if !line.contains(&facility) && !line.contains(" on server ") {
+                    continue;
+                }
+
+                if sender.unbounded_send(Ok(list(kind)?)).is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        };
+
+        let _ = std::thread::Builder::new()
+            .name(format!("iced-audio-{}", kind.as_str()))
+            .spawn(move || {
+                if let Err(error) = run(&sender) {
+                    let _ = sender.unbounded_send(Err(error));
+                }
+            })?;
+
+        Ok(Watcher {
+            _subscriber: subscriber,
+        })
+    }
+
+    pub fn list(kind: Kind) -> Result<Vec<Device>, Error> {
+        let output = pactl(&["list", &format!("{}s", kind.as_str())])?;
+        let info = pactl(&["info"])?;
+
+        let default = match kind {
+            Kind::Sink => "Default Sink:",
+            Kind::Source => "Default Source:",
+        };
+
+        let default = info
+            .lines()
+            .find_map(|line| line.strip_prefix(default))
+            .unwrap_or_default();
+
+        parse(kind, &output, default.trim())
+    }
+
+    /// Runs `pactl` with the given arguments and returns its output.
+    pub fn pactl(arguments: &[&str]) -> Result<String, Error> {
+        let output = Command::new("pactl")
+            .args(arguments)
+            // The output of `pactl` is localized
+            .env("LC_ALL", "C")
+            .stdin(Stdio::null())
+            .output()
+            .map_err(missing)?;
+
+        if !output.status.success() {
+            return Err(Error::Failed(
+                String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Parses the output of `pactl list`; which is supported by every
+    /// version of `pactl`, unlike its JSON output.
+    ///
+    /// Every device is a header line, like `Sink #0`, followed by its
+    /// properties indented by a tab, like `\tName: speakers`.
+    fn parse(kind: Kind, output: &str, default: &str) -> Result<Vec<Device>, Error> {
+        let mut devices = Vec::new();
+        let mut device: Option<Device> = None;
+        let mut is_monitor = false;
+
+        for line in output.lines() {
+            let Some(property) = line.strip_prefix('\t') else {
+                if !line.trim().is_empty() {
+                    devices.extend(device.take().filter(|_| !is_monitor));
+
+                    device = Some(Device {
+                        kind,
+                        name: String::new(),
+                        description: String::new(),
+                        volume: 0.0,
+                        is_muted: false,
+                        is_default: false,
+                    });
+
+                    is_monitor = false;
+                }
+
+                continue;
+            };
+
+            let Some(device) = device.as_mut() else {
+                continue;
+            };
+
+            // Nested properties and continuation lines are indented further
+            if property.starts_with(char::is_whitespace) {
+                continue;
+            }
+
+            let Some((key, value)) = property.split_once(':') else {
+                continue;
+            };
+
+            let value = value.trim();
+
+            match key {
+                "Name" => {
+                    device.name = value.to_owned();
+                    device.is_default = value == default;
+                }
+                "Description" => {
+                    device.description = value.to_owned();
+                }
+                "Mute" => {
+                    device.is_muted = value == "yes";
+                }
+                "Volume" => {
+                    device.volume = volume(value)
+                        .ok_or_else(|| Error::InvalidOutput(line.trim().to_owned()))?;
+                }
+                "Monitor of Sink" => {
+                    is_monitor = value != "n/a";
+                }
+                _ => {}
+            }
+        }
+
+        devices.extend(device.filter(|_| !is_monitor));
+
+        Ok(devices
+            .into_iter()
+            .map(|mut device| {
+                if device.description.is_empty() {
+                    device.description = device.name.clone();
+                }
+
+                device
+            })
+            .collect())
+    }
+
+    /// Parses the average volume of a list of channels, like
+    /// `front-left: 65536 / 100% / 0.00 dB, front-right: 65536 / 100% / 0.00 dB`.
+    fn volume(channels: &str) -> Option<f32> {
+        let values = channels
+            .split(',')
+            .map(|channel| {
+                let (_name, volume) = channel.split_once(':')?;
+                let (value, _percent) = volume.split_once('/')?;
+
+                value.trim().parse::<f32>().ok()
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if values.is_empty() {
+            return Some(0.0);
+        }
+
+        Some(values.iter().sum::<f32>() / values.len() as f32 / NORMAL_VOLUME)
+    }
+
+    fn missing(error: io::Error) -> Error {
+        if error.kind() == io::ErrorKind::NotFound {
+            Error::MissingProgram("pactl")
+        } else {
+            Error::from(error)
+        }
+    }
+
+    /// A child process that is killed when dropped.
+    struct Guard(Child);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const SINKS: &str = "\
+Sink #0
+\tState: SUSPENDED
+\tName: alsa_output.analog-stereo
+\tDescription: Built-in Audio Analog Stereo
+\tMute: no
+\tVolume: front-left: 32768 /  50% / -18.06 dB,   front-right: 65536 / 100% / 0.00 dB
+\t        balance 0.00
+\tMonitor of Sink: n/a
+\tProperties:
+\t\tdevice.description = \"Built-in Audio\"
+
+Sink #1
+\tName: bluez_output.headphones
+\tDescription: Headphones
+\tMute: yes
+\tVolume: mono: 65536 / 100% / 0.00 dB
+";
+
+        #[test]
+        fn it_parses_devices() {
+            let devices = parse(Kind::Sink, SINKS, "bluez_output.headphones").unwrap();
+
+            assert_eq!(
+                devices,
+                vec![
+                    Device {
+                        kind: Kind::Sink,
+                        name: "alsa_output.analog-stereo".to_owned(),
+                        description: "Built-in Audio Analog Stereo".to_owned(),
+                        volume: 0.75,
+                        is_muted: false,
+                        is_default: false,
+                    },
+                    Device {
+                        kind: Kind::Sink,
+                        name: "bluez_output.headphones".to_owned(),
+                        description: "Headphones".to_owned(),
+                        volume: 1.0,
+                        is_muted: true,
+                        is_default: true,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn it_skips_monitors() {
+            let sources = "\
+Source #0
+\tName: alsa_output.analog-stereo.monitor
+\tMonitor of Sink: alsa_output.analog-stereo
+Source #1
+\tName: alsa_input.mic
+\tMonitor of Sink: n/a
+";
+
+            let devices = parse(Kind::Source, sources, "").unwrap();
+
+            assert_eq!(devices.len(), 1);
+            assert_eq!(devices[0].name, "alsa_input.mic");
+            assert_eq!(devices[0].description, "alsa_input.mic");
+        }
+    }
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+mod platform {
+    use super::{Device, Error, Kind, Sender};
+
+    pub struct Watcher;
+
+    pub fn watch(_kind: Kind, _sender: Sender) -> Result<Watcher, Error> {
+        Err(Error::Unsupported)
+    }
+
+    pub fn list(_kind: Kind) -> Result<Vec<Device>, Error> {
+        Err(Error::Unsupported)
+    }
+
+    pub fn pactl(_arguments: &[&str]) -> Result<String, Error> {
+        Err(Error::Unsupported)
+    }
+}
@@ -0,0 +1,381 @@
+//! Access the clipboard.
+pub use crate::runtime::clipboard::{read, read_primary, write, write_primary};
+
+#[cfg(feature = "clipboard-watch")]
+pub use watch::{Change, Error, watch, watch_primary};
+
+#[cfg(feature = "clipboard-watch")]
+mod watch {
+    use crate::Subscription;
+    use crate::futures::channel::mpsc;
+    use crate::futures::{SinkExt, StreamExt};
+    use crate::stream;
+
+    use std::io;
+    use std::sync::Arc;
+
+    /// Returns a [`Subscription`] that produces a [`Change`] every time the
+    /// contents of the clipboard change; including the contents present when
+    /// it starts.
+    ///
+    /// Watching the clipboard is supported on Wayland, through `wl-paste`,
+    /// and on X11, through `xclip`. These programs are not bundled; they must
+    /// be installed on the system at runtime (i.e. the `wl-clipboard` and
+    /// `xclip` packages). The [`Subscription`] produces an [`Error`] on any
+    /// other platform or if these programs are missing.
+    ///
+    /// The programs are stopped as soon as the [`Subscription`] is dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use iced::Subscription;
+    /// use iced::clipboard::{self, Change};
+    ///
+    /// struct State {
+    ///     history: Vec<String>,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     ClipboardChanged(Result<Change, clipboard::Error>),
+    /// }
+    ///
+    /// fn update(state: &mut State, message: Message) {
+    ///     match message {
+    ///         Message::ClipboardChanged(Ok(change)) => {
+    ///             if let Some(text) = change.text {
+    ///                 state.history.push(text);
+    ///             }
+    ///         }
+    ///         Message::ClipboardChanged(Err(error)) => {
+    ///             eprintln!("{error}");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn subscription(_state: &State) -> Subscription<Message> {
+    ///     clipboard::watch().map(Message::ClipboardChanged)
+    /// }
+    /// ```
+    pub fn watch() -> Subscription<Result<Change, Error>> {
+        listen(false)
+    }
+
+    /// Returns a [`Subscription`] that produces a [`Change`] every time the
+    /// contents of the primary clipboard change.
+    ///
+    /// See [`watch`] for the supported platforms.
+    pub fn watch_primary() -> Subscription<Result<Change, Error>> {
+        listen(true)
+    }
+
+    /// New contents of the clipboard.
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct Change {
+        /// The MIME types the contents are offered as; like `text/plain` or
+        /// `image/png`.
+        ///
+        /// It is empty when the clipboard was cleared.
+        pub mime_types: Vec<String>,
+        /// The contents as text; if they are offered as text.
+        pub text: Option<String>,
+    }
+
+    impl Change {
+        /// Returns true if the contents are offered as the given MIME type.
+        pub fn has(&self, mime_type: &str) -> bool {
+            self.mime_types
+                .iter()
+                .any(|offered| offered.eq_ignore_ascii_case(mime_type))
+        }
+
+        /// Returns true if the clipboard was cleared.
+        pub fn is_empty(&self) -> bool {
+            self.mime_types.is_empty()
+        }
+    }
+
+    /// An error watching the clipboard.
+    #[derive(Debug, Clone, thiserror::Error)]
+    pub enum Error {
+        /// The platform does not support watching the clipboard.
+        #[error("watching the clipboard is not supported on this platform")]
+        Unsupported,
+
+        /// A program needed to watch the clipboard is not installed.
+        #[error("`{0}` is needed to watch the clipboard, but it is not installed")]
+        MissingProgram(&'static str),
+
+        /// The clipboard could not be read.
+        #[error("the clipboard could not be read: {0}")]
+        Io(Arc<io::Error>),
+    }
+
+    impl From<io::Error> for Error {
+        fn from(error: io::Error) -> Self {
+            Self::Io(Arc::new(error))
+        }
+    }
+
+    fn listen(is_primary: bool) -> Subscription<Result<Change, Error>> {
+        Subscription::run_with(is_primary, |is_primary| {
+            let is_primary = *is_primary;
+
+            stream::channel(1, async move |mut output| {
+                let (sender, mut receiver) = mpsc::unbounded();
+
+                // The watcher is owned by the stream; so it is stopped as soon
+                // as the subscription is dropped
+                let _watcher = match platform::watch(is_primary, sender) {
+                    Ok(watcher) => watcher,
+                    Err(error) => {
+                        let _ = output.send(Err(error)).await;
+                        return;
+                    }
+                };
+
+                while let Some(change) = receiver.next().await {
+                    let _ = output.send(change).await;
+                }
+            })
+        })
+    }
+
+    type Sender = mpsc::UnboundedSender<Result<Change, Error>>;
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    mod platform {
+        use super::{Change, Error, Sender};
+
+        use std::io::{self, BufRead, BufReader};
+        use std::process::{Child, Command, Stdio};
+        use std::time::Duration;
+
+        /// The interval between reads of the clipboard on X11, which has no
+        /// program to be notified of changes.
+        const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+        /// Targets of X11 selections that are not MIME types.
+        const X11_META_TARGETS: &[&str] = &["TARGETS", "TIMESTAMP", "MULTIPLE", "SAVE_TARGETS"];
+
+        /// A running clipboard watcher, which stops when dropped.
+        pub struct Watcher {
+            _child: Option<Guard>,
+        }
+
+        /// Starts sending every change of the clipboard to the given
+        /// [`Sender`] until it is closed.
+        pub fn watch(is_primary: bool, sender: Sender) -> Result<Watcher, Error> {
+            if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+                wayland(is_primary, sender)
+            } else if std::env::var_os("DISPLAY").is_some() {
+                x11(is_primary, sender)
+            } else {
+                Err(Error::Unsupported)
+            }
+        }
+
+        fn wayland(is_primary: bool, sender: Sender) -> Result<Watcher, Error> {
+            let wl_paste = move |arguments: &[&str]| {
+                let mut command = Command::new("wl-paste");
+
+                if is_primary {
+                    let _ = command.arg("--primary");
+                }
+
+                let _ = command.args(arguments);
+                command
+            };
+
+            // `wl-paste` runs the given command on every change; which prints
+            // an empty line to its output.
+            let mut child = Guard(
+                wl_paste(&["--watch", "echo"])
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .map_err(|error| missing("wl-paste", error))?,
+            );
+
+            let stdout = child
+                .0
+                .stdout
+                .take()
+                .ok_or_else(|| io::Error::other("`wl-paste` has no output"))?;
+
+            // Killing the child when the watcher is dropped closes its output;
+            // which ends the thread
+            spawn(sender, move |sender| {
+                for line in BufReader::new(stdout).lines() {
+                    let _ = line?;
+
+                    if sender.is_closed() {
+                        break;
+                    }
+
+                    let mime_types = read(&mut wl_paste(&["--list-types"]))?
+                        .map(|types| lines(&types))
+                        .unwrap_or_default();
+
+                    let text = if mime_types.iter().any(|mime_type| is_text(mime_type)) {
+                        read(&mut wl_paste(&["--no-newline", "--type", "text"]))?
+                    } else {
+                        None
+                    };
+
+                    if sender
+                        .unbounded_send(Ok(Change { mime_types, text }))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+
+                Ok(())
+            })?;
+
+            Ok(Watcher {
+                _child: Some(child),
+            })
+        }
+
+        fn x11(is_primary: bool, sender: Sender) -> Result<Watcher, Error> {
+            let xclip = move |target: &str| {
+                let mut command = Command::new("xclip");
+
+                let _ = command
+                    .args([
+                        "-selection",
+                        if is_primary { "primary" } else { "clipboard" },
+                    ])
+                    .args(["-out", "-target", target]);
+
+                command
+            };
+
+            spawn(sender, move |sender| {
+                let mut last_owner = None;
+                let mut last = None;
+
+                while !sender.is_closed() {
+                    // The timestamp of a selection changes every time it is
+                    // acquired; so the contents are only read when it does.
+                    // Owners that do not report it are compared by their targets.
+                    let mut owner =
+                        read(&mut xclip("TIMESTAMP")).map_err(|error| missing("xclip", error))?;
+
+                    if owner.is_none() {
+                        owner = read(&mut xclip("TARGETS"))?;
+                    }
+
+                    if last_owner.as_ref() != Some(&owner) {
+                        last_owner = Some(owner);
+
+                        let mime_types: Vec<_> = read(&mut xclip("TARGETS"))?
+                            .map(|targets| {
+                                lines(&targets)
+                                    .into_iter()
+                                    .filter(|target| !X11_META_TARGETS.contains(&target.as_str()))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+
+                        let text = if mime_types.iter().any(|mime_type| is_text(mime_type)) {
+                            read(&mut xclip("UTF8_STRING"))?
+                        } else {
+                            None
+                        };
+
+                        let change = Change { mime_types, text };
+
+                        if last.as_ref() != Some(&change) {
+                            last = Some(change.clone());
+
+                            if sender.unbounded_send(Ok(change)).is_err() {
+                                break;
+                            }
+                        }
+                    }
+
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+
+                Ok(())
+            })?;
+
+            Ok(Watcher { _child: None })
+        }
+
+        /// Runs the given watch loop in a new thread; sending its error, if
+        /// any, to the given [`Sender`].
+        fn spawn(
+            sender: Sender,
+            watch: impl FnOnce(&Sender) -> Result<(), Error> + Send + 'static,
+        ) -> io::Result<()> {
+            let _ = std::thread::Builder::new()
+                .name("iced-clipboard-watch".to_owned())
+                .spawn(move || {
+                    if let Err(error) = watch(&sender) {
+                        let _ = sender.unbounded_send(Err(error));
+                    }
+                })?;
+
+            Ok(())
+        }
+
+        /// A child process that is killed when dropped.
+        struct Guard(Child);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                let _ = self.0.kill();
+                let _ = self.0.wait();
+            }
+        }
+
+        /// Runs the given command and returns its output; if it succeeds.
+        fn read(command: &mut Command) -> io::Result<Option<String>> {
+            let output = command
+                .stdin(Stdio::null())
+                .stderr(Stdio::null())
+                .output()?;
+
+            Ok(output
+                .status
+                .success()
+                .then(|| String::from_utf8_lossy(&output.stdout).into_owned()))
+        }
+
+        fn lines(output: &str) -> Vec<String> {
+            output
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_owned)
+                .collect()
+        }
+
+        fn is_text(mime_type: &str) -> bool {
+            mime_type.starts_with("text/") || matches!(mime_type, "UTF8_STRING" | "STRING" | "TEXT")
+        }
+
+        fn missing(program: &'static str, error: io::Error) -> Error {
+            if error.kind() == io::ErrorKind::NotFound {
+                Error::MissingProgram(program)
+            } else {
+                Error::from(error)
+            }
+        }
+    }
+
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    mod platform {
+        use super::{Error, Sender};
+
+        pub struct Watcher;
+
+        pub fn watch(_is_primary: bool, _sender: Sender) -> Result<Watcher, Error> {
+            Err(Error::Unsupported)
+        }
+    }
+}
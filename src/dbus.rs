@@ -0,0 +1,376 @@
+//! Talk to other processes over D-Bus.
+//!
+//! A [`Connection`] to the session or system bus can call the methods of
+//! other services with tasks, listen to their signals with subscriptions,
+//! and [`serve`] an interface of its own whose incoming method calls become
+//! messages of the application.
+//!
+//! The [`mpris`] module builds on top of it to integrate with the media
+//! controls of the system, the `portal` module to capture the screen
+//! through the XDG desktop portal on Linux, and the `bluetooth` module to
+//! manage Bluetooth devices through BlueZ.
+//!
+//! Arguments and return values are any serializable types that implement
+//! [`Type`]; like primitives, strings, tuples, vectors, or structs deriving
+//! it.
+//!
+//! # Example
+//! ```no_run
+//! use iced::dbus::{self, Connection};
+//! use iced::{Subscription, Task};
+//!
+//! struct State {
+//!     connection: Option<Connection>,
+//!     volume: f64,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Connected(Result<Connection, dbus::Error>),
+//!     VolumeChanged(f64),
+//!     Called(dbus::Call),
+//! }
+//!
+//! fn new() -> (State, Task<Message>) {
+//!     (
+//!         State {
+//!             connection: None,
+//!             volume: 1.0,
+//!         },
+//!         dbus::session().map(Message::Connected),
+//!     )
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Connected(Ok(connection)) => {
+//!             state.connection = Some(connection.clone());
+//!
+//!             connection.request_name("com.example.Player").discard()
+//!         }
+//!         Message::Connected(Err(_)) => Task::none(),
+//!         Message::VolumeChanged(volume) => {
+//!             state.volume = volume;
+//!             Task::none()
+//!         }
+//!         Message::Called(call) => match call.member() {
+//!             "GetVolume" => call.reply(state.volume).discard(),
+//!             _ => call.reply_error("org.freedesktop.DBus.Error.UnknownMethod").discard(),
+//!         },
+//!     }
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     let Some(connection) = &state.connection else {
+//!         return Subscription::none();
+//!     };
+//!
+//!     Subscription::batch([
+//!         dbus::signal(connection, "com.example.Mixer", "VolumeChanged")
+//!             .map(Message::VolumeChanged),
+//!         dbus::serve(connection, "/com/example/Player", "com.example.Player")
+//!             .map(Message::Called),
+//!     ])
+//! }
+//! ```
+#[cfg(feature = "bluetooth")]
+pub mod bluetooth;
+pub mod mpris;
+#[cfg(unix)]
+pub mod portal;
+
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::{Subscription, Task};
+
+use iced_futures::{MaybeSend, MaybeSync};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+pub use zbus;
+pub use zbus::zvariant::Type;
+
+/// Connects to the session bus.
+pub fn session() -> Task<Result<Connection, Error>> {
+    Task::future(async { Ok(Connection(zbus::Connection::session().await?)) })
+}
+
+/// Connects to the system bus.
+pub fn system() -> Task<Result<Connection, Error>> {
+    Task::future(async { Ok(Connection(zbus::Connection::system().await?)) })
+}
+
+/// A connection to a D-Bus bus.
+///
+/// A [`Connection`] can be cheaply cloned; every clone shares the same
+/// connection.
+#[derive(Debug, Clone)]
+pub struct Connection(zbus::Connection);
+
+impl Connection {
+    /// Calls the method of a service with the given arguments; producing
+    /// its return value.
+    ///
+    /// Use `()` for methods without arguments or return values.
+    pub fn call<A, R>(
+        &self,
+        destination: impl Into<String>,
+        path: impl Into<String>,
+        interface: impl Into<String>,
+        method: impl Into<String>,
+        arguments: A,
+    ) -> Task<Result<R, Error>>
+    where
+        A: Serialize + Type + MaybeSend + MaybeSync + 'static,
+        R: DeserializeOwned + Type + MaybeSend + 'static,
+    {
+        let connection = self.0.clone();
+        let destination = destination.into();
+        let path = path.into();
+        let interface = interface.into();
+        let method = method.into();
+
+        Task::future(async move {
+            let reply = connection
+                .call_method(
+                    Some(destination.as_str()),
+                    path.as_str(),
+                    Some(interface.as_str()),
+                    method.as_str(),
+                    &arguments,
+                )
+                .await?;
+
+            Ok(reply.body().deserialize()?)
+        })
+    }
+
+    /// Requests a well-known name on the bus; like `com.example.App`.
+    pub fn request_name(&self, name: impl Into<String>) -> Task<Result<(), Error>> {
+        let connection = self.0.clone();
+        let name = name.into();
+
+        Task::future(async move { Ok(connection.request_name(name.as_str()).await?) })
+    }
+
+    /// Returns the unique name of the [`Connection`] on the bus, if any.
+    pub fn unique_name(&self) -> Option<String> {
+        self.0.unique_name().map(ToString::to_string)
+    }
+
+    /// Returns the underlying [`zbus::Connection`].
+    pub fn raw(&self) -> &zbus::Connection {
+        &self.0
+    }
+}
+
+impl From<zbus::Connection> for Connection {
+    fn from(connection: zbus::Connection) -> Self {
+        Self(connection)
+    }
+}
+
+/// Listens to a signal of the given interface; producing its arguments
+/// every time it is emitted.
+///
+/// Signals with arguments that cannot be deserialized are ignored.
+pub fn signal<T>(
+    connection: &Connection,
+    interface: impl Into<String>,
+    member: impl Into<String>,
+) -> Subscription<T>
+where
+    T: DeserializeOwned + Type + MaybeSend + 'static,
+{
+    Subscription::run_with(
+        Rule {
+            connection: connection.clone(),
+            kind: zbus::message::Type::Signal,
+            path: None,
+            interface: interface.into(),
+            member: Some(member.into()),
+        },
+        |rule| {
+            let rule = rule.clone();
+
+            stream::channel(100, async move |mut output| {
+                let Some(mut messages) = rule.listen().await else {
+                    return;
+                };
+
+                while let Some(message) = messages.next().await {
+                    if let Ok(arguments) = message.and_then(|message| message.body().deserialize())
+                    {
+                        let _ = output.send(arguments).await;
+                    }
+                }
+            })
+        },
+    )
+}
+
+/// Serves an interface at the given object path; producing a [`Call`]
+/// every time one of its methods is called.
+///
+/// Every [`Call`] should be answered with [`Call::reply`] or
+/// [`Call::reply_error`].
+pub fn serve(
+    connection: &Connection,
+    path: impl Into<String>,
+    interface: impl Into<String>,
+) -> Subscription<Call> {
+    Subscription::run_with(
+        Rule {
+            connection: connection.clone(),
+            kind: zbus::message::Type::MethodCall,
+            path: Some(path.into()),
+            interface: interface.into(),
+            member: None,
+        },
+        |rule| {
+            let rule = rule.clone();
+
+            stream::channel(100, async move |mut output| {
+                let Some(mut messages) = rule.listen().await else {
+                    return;
+                };
+
+                while let Some(message) = messages.next().await {
+                    let Ok(message) = message else {
+                        continue;
+                    };
+
+                    let member = message
+                        .header()
+                        .member()
+                        .map(ToString::to_string)
+                        .unwrap_or_default();
+
+                    let _ = output
+                        .send(Call {
+                            connection: rule.connection.0.clone(),
+                            message,
+                            member,
+                        })
+                        .await;
+                }
+            })
+        },
+    )
+}
+
+/// An incoming method call of a served interface.
+#[derive(Debug, Clone)]
+pub struct Call {
+    connection: zbus::Connection,
+    message: zbus::Message,
+    member: String,
+}
+
+impl Call {
+    /// Returns the name of the method called.
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    /// Returns the unique name of the caller, if known.
+    pub fn sender(&self) -> Option<String> {
+        self.message.header().sender().map(ToString::to_string)
+    }
+
+    /// Deserializes the arguments of the [`Call`].
+    pub fn arguments<T>(&self) -> Result<T, Error>
+    where
+        T: DeserializeOwned + Type,
+    {
+        Ok(self.message.body().deserialize()?)
+    }
+
+    /// Replies to the [`Call`] with the given return value.
+    pub fn reply<T>(&self, value: T) -> Task<Result<(), Error>>
+    where
+        T: Serialize + Type + MaybeSend + MaybeSync + 'static,
+    {
+        let call = self.clone();
+
+        Task::future(async move {
+            Ok(call
+                .connection
+                .reply(&call.message.header(), &value)
+                .await?)
+        })
+    }
+
+    /// Replies to the [`Call`] with the given error name; like
+    /// `org.freedesktop.DBus.Error.InvalidArgs`.
+    pub fn reply_error(&self, name: impl Into<String>) -> Task<Result<(), Error>> {
+        let call = self.clone();
+        let name = name.into();
+
+        Task::future(async move {
+            Ok(call
+                .connection
+                .reply_error(&call.message.header(), name.as_str(), &())
+                .await?)
+        })
+    }
+
+    /// Returns the underlying [`zbus::Message`].
+    pub fn raw(&self) -> &zbus::Message {
+        &self.message
+    }
+}
+
+/// An error of a D-Bus [`Connection`].
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("d-bus error: {0}")]
+pub struct Error(Arc<zbus::Error>);
+
+impl From<zbus::Error> for Error {
+    fn from(error: zbus::Error) -> Self {
+        Self(Arc::new(error))
+    }
+}
+
+#[derive(Clone)]
+struct Rule {
+    connection: Connection,
+    kind: zbus::message::Type,
+    path: Option<String>,
+    interface: String,
+    member: Option<String>,
+}
+
+impl Rule {
+    async fn listen(&self) -> Option<zbus::MessageStream> {
+        let mut rule = zbus::MatchRule::builder()
+            .msg_type(self.kind)
+            .interface(self.interface.as_str())
+            .ok()?;
+
+        if let Some(path) = &self.path {
+            rule = rule.path(path.as_str()).ok()?;
+        }
+
+        if let Some(member) = &self.member {
+            rule = rule.member(member.as_str()).ok()?;
+        }
+
+        zbus::MessageStream::for_match_rule(rule.build(), &self.connection.0, None)
+            .await
+            .ok()
+    }
+}
+
+impl Hash for Rule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connection.unique_name().hash(state);
+        (self.kind as u8).hash(state);
+        self.path.hash(state);
+        self.interface.hash(state);
+        self.member.hash(state);
+    }
+}
@@ -0,0 +1,403 @@
+//! Manage Bluetooth devices through BlueZ.
+//!
+//! The [`watch`] subscription produces the [`Status`] of the Bluetooth
+//! adapters and devices of the system every time it changes; while tasks
+//! like [`pair`] or [`connect`] act on them.
+//!
+//! BlueZ is served on the system bus of most Linux distributions. Pairing
+//! devices that need a PIN or a passkey requires an agent; which desktops
+//! usually register already. Game controllers and other "just works"
+//! devices do not need one.
+//!
+//! # Example
+//! ```no_run
+//! use iced::dbus::bluetooth::{self, Device, Status};
+//! use iced::dbus::{self, Connection};
+//! use iced::{Subscription, Task};
+//!
+//! #[derive(Default)]
+//! struct State {
+//!     connection: Option<Connection>,
+//!     status: Status,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Connected(Result<Connection, dbus::Error>),
+//!     StatusChanged(Status),
+//!     Scan,
+//!     Pair(Device),
+//!     Done(Result<(), dbus::Error>),
+//! }
+//!
+//! fn new() -> (State, Task<Message>) {
+//!     (State::default(), dbus::system().map(Message::Connected))
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     let Some(connection) = &state.connection else {
+//!         if let Message::Connected(connection) = message {
+//!             state.connection = connection.ok();
+//!         }
+//!
+//!         return Task::none();
+//!     };
+//!
+//!     match message {
+//!         Message::StatusChanged(status) => {
+//!             state.status = status;
+//!             Task::none()
+//!         }
+//!         Message::Scan => Task::batch(
+//!             state
+//!                 .status
+//!                 .adapters
+//!                 .iter()
+//!                 .map(|adapter| bluetooth::start_discovery(connection, adapter)),
+//!         )
+//!         .map(Message::Done),
+//!         Message::Pair(device) => bluetooth::pair(connection, &device)
+//!             .and_then({
+//!                 let connection = connection.clone();
+//!
+//!                 move |_| bluetooth::connect(&connection, &device)
+//!             })
+//!             .map(Message::Done),
+//!         Message::Connected(_) | Message::Done(_) => Task::none(),
+//!     }
+//! }
+//!
+//! fn controllers(state: &State) -> impl Iterator<Item = &Device> {
+//!     state.status.devices.iter().filter(|device| device.is_controller())
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     state
+//!         .connection
+//!         .as_ref()
+//!         .map(bluetooth::watch)
+//!         .unwrap_or_else(Subscription::none)
+//!         .map(Message::StatusChanged)
+//! }
+//! ```
+use crate::dbus::{Connection, Error};
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::{Subscription, Task};
+
+use zbus::zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Value};
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+const SERVICE: &str = "org.bluez";
+const ADAPTER: &str = "org.bluez.Adapter1";
+const DEVICE: &str = "org.bluez.Device1";
+const BATTERY: &str = "org.bluez.Battery1";
+const PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+const OBJECT_MANAGER: &str = "org.freedesktop.DBus.ObjectManager";
+
+/// The adapters and devices known to BlueZ.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Status {
+    /// The Bluetooth adapters of the system.
+    pub adapters: Vec<Adapter>,
+    /// The devices discovered or paired by any of the adapters.
+    pub devices: Vec<Device>,
+}
+
+/// A Bluetooth adapter of the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Adapter {
+    /// The object path of the adapter; like `/org/bluez/hci0`.
+    pub path: String,
+    /// The address of the adapter.
+    pub address: String,
+    /// The name of the adapter.
+    pub name: String,
+    /// Whether the adapter is turned on.
+    pub is_powered: bool,
+    /// Whether the adapter is discovering new devices.
+    pub is_discovering: bool,
+}
+
+/// A Bluetooth device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Device {
+    /// The object path of the device; like
+    /// `/org/bluez/hci0/dev_00_11_22_33_44_55`.
+    pub path: String,
+    /// The object path of the [`Adapter`] of the device.
+    pub adapter: String,
+    /// The address of the device.
+    pub address: String,
+    /// The name of the device; its address if it has none.
+    pub name: String,
+    /// The freedesktop icon name of the kind of device; like
+    /// `input-gaming` or `audio-headset`.
+    pub icon: Option<String>,
+    /// Whether the device is paired.
+    pub is_paired: bool,
+    /// Whether the device is trusted; so it can connect by itself.
+    pub is_trusted: bool,
+    /// Whether the device is connected.
+    pub is_connected: bool,
+    /// The strength of the signal of the device while discovering, in dBm.
+    pub rssi: Option<i16>,
+    /// The battery level of the device, in the `[0, 100]` range; if it
+    /// reports one.
+    pub battery: Option<u8>,
+}
+
+impl Device {
+    /// Returns true if the [`Device`] is a game controller.
+    pub fn is_controller(&self) -> bool {
+        self.icon.as_deref() == Some("input-gaming")
+    }
+}
+
+/// Watches the adapters and devices known to BlueZ; producing a [`Status`]
+/// right away and every time it changes.
+pub fn watch(connection: &Connection) -> Subscription<Status> {
+    Subscription::run_with(
+        Watch {
+            connection: connection.clone(),
+        },
+        |watch| {
+            let connection = watch.connection.raw().clone();
+
+            stream::channel(10, async move |mut output| {
+                let Ok(owner) = connection
+                    .call_method(
+                        Some("org.freedesktop.DBus"),
+                        "/org/freedesktop/DBus",
+                        Some("org.freedesktop.DBus"),
+                        "GetNameOwner",
+                        &SERVICE,
+                    )
+                    .await
+                    .and_then(|reply| reply.body().deserialize::<String>())
+                else {
+                    return;
+                };
+
+                // Devices appearing, vanishing, and changing are all signals of
+                // BlueZ
+                let Some(rule) = zbus::MatchRule::builder()
+                    .msg_type(zbus::message::Type::Signal)
+                    .sender(owner.as_str())
+                    .ok()
+                else {
+                    return;
+                };
+
+                let Ok(mut signals) =
+                    zbus::MessageStream::for_match_rule(rule.build(), &connection, None).await
+                else {
+                    return;
+                };
+
+                let mut last = None;
+
+                loop {
+                    if let Ok(status) = fetch(&connection).await
+                        && last.as_ref() != Some(&status)
+                    {
+                        last = Some(status.clone());
+
+                        let _ = output.send(status).await;
+                    }
+
+                    if signals.next().await.is_none() {
+                        break;
+                    }
+                }
+            })
+        },
+    )
+}
+
+/// Fetches the adapters and devices known to BlueZ once.
+pub fn status(connection: &Connection) -> Task<Result<Status, Error>> {
+    let connection = connection.raw().clone();
+
+    Task::future(async move { fetch(&connection).await })
+}
+
+/// Turns the given [`Adapter`] on or off.
+pub fn set_powered(
+    connection: &Connection,
+    adapter: &Adapter,
+    is_powered: bool,
+) -> Task<Result<(), Error>> {
+    set(connection, &adapter.path, ADAPTER, "Powered", is_powered)
+}
+
+/// Starts discovering new devices with the given [`Adapter`].
+///
+/// Discovered devices are produced by [`watch`] until [`stop_discovery`]
+/// is called or the [`Connection`] is closed.
+pub fn start_discovery(connection: &Connection, adapter: &Adapter) -> Task<Result<(), Error>> {
+    connection.call(SERVICE, adapter.path.clone(), ADAPTER, "StartDiscovery", ())
+}
+
+/// Stops discovering new devices with the given [`Adapter`].
+pub fn stop_discovery(connection: &Connection, adapter: &Adapter) -> Task<Result<(), Error>> {
+    connection.call(SERVICE, adapter.path.clone(), ADAPTER, "StopDiscovery", ())
+}
+
+/// Pairs the given [`Device`].
+///
+/// The resulting [`Task`] finishes once the device is paired; which may
+/// take a while.
+pub fn pair(connection: &Connection, device: &Device) -> Task<Result<(), Error>> {
+    connection.call(SERVICE, device.path.clone(), DEVICE, "Pair", ())
+}
+
+/// Connects the given [`Device`]; pairing it first if needed.
+pub fn connect(connection: &Connection, device: &Device) -> Task<Result<(), Error>> {
+    connection.call(SERVICE, device.path.clone(), DEVICE, "Connect", ())
+}
+
+/// Disconnects the given [`Device`].
+pub fn disconnect(connection: &Connection, device: &Device) -> Task<Result<(), Error>> {
+    connection.call(SERVICE, device.path.clone(), DEVICE, "Disconnect", ())
+}
+
+/// Trusts or distrusts the given [`Device`].
+///
+/// Trusted devices can connect by themselves; like a controller waking up.
+pub fn set_trusted(
+    connection: &Connection,
+    device: &Device,
+    is_trusted: bool,
+) -> Task<Result<(), Error>> {
+    set(connection, &device.path, DEVICE, "Trusted", is_trusted)
+}
+
+/// Removes the given [`Device`] from its [`Adapter`]; unpairing it.
+pub fn remove(connection: &Connection, device: &Device) -> Task<Result<(), Error>> {
+    connection.call(
+        SERVICE,
+        device.adapter.clone(),
+        ADAPTER,
+        "RemoveDevice",
+        ObjectPath::from_string_unchecked(device.path.clone()),
+    )
+}
+
+fn set(
+    connection: &Connection,
+    path: &str,
+    interface: &'static str,
+    property: &'static str,
+    value: bool,
+) -> Task<Result<(), Error>> {
+    connection.call(
+        SERVICE,
+        path,
+        PROPERTIES,
+        "Set",
+        (interface, property, Value::from(value)),
+    )
+}
+
+#[derive(Clone)]
+struct Watch {
+    connection: Connection,
+}
+
+impl Hash for Watch {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connection.unique_name().hash(state);
+    }
+}
+
+type Objects = HashMap<OwnedObjectPath, HashMap<String, HashMap<String, OwnedValue>>>;
+
+async fn fetch(connection: &zbus::Connection) -> Result<Status, Error> {
+    let reply = connection
+        .call_method(
+            Some(SERVICE),
+            "/",
+            Some(OBJECT_MANAGER),
+            "GetManagedObjects",
+            &(),
+        )
+        .await?;
+
+    let objects: Objects = reply.body().deserialize()?;
+
+    let mut adapters = Vec::new();
+    let mut devices = Vec::new();
+
+    for (path, interfaces) in &objects {
+        if let Some(properties) = interfaces.get(ADAPTER) {
+            let text = |name: &str| properties.get(name).and_then(|value| string(value));
+
+            adapters.push(Adapter {
+                path: path.to_string(),
+                address: text("Address").unwrap_or_default(),
+                name: text("Alias").or_else(|| text("Name")).unwrap_or_default(),
+                is_powered: boolean(properties, "Powered"),
+                is_discovering: boolean(properties, "Discovering"),
+            });
+        }
+
+        if let Some(properties) = interfaces.get(DEVICE) {
+            let text = |name: &str| properties.get(name).and_then(|value| string(value));
+            let address = text("Address").unwrap_or_default();
+
+            devices.push(Device {
+                path: path.to_string(),
+                adapter: text("Adapter").unwrap_or_default(),
+                name: text("Alias")
+                    .or_else(|| text("Name"))
+                    .unwrap_or_else(|| address.clone()),
+                address,
+                icon: text("Icon"),
+                is_paired: boolean(properties, "Paired"),
+                is_trusted: boolean(properties, "Trusted"),
+                is_connected: boolean(properties, "Connected"),
+                rssi: properties.get("RSSI").and_then(|value| match &**value {
+                    Value::I16(rssi) => Some(*rssi),
+                    _ => None,
+                }),
+                battery: interfaces
+                    .get(BATTERY)
+                    .and_then(|battery| battery.get("Percentage"))
+                    .and_then(|value| match &**value {
+                        Value::U8(percentage) => Some((*percentage).min(100)),
+                        _ => None,
+                    }),
+            });
+        }
+    }
+
+    // Connected and paired devices come first; and the order of the rest
+    // does not jump around as signals arrive
+    adapters.sort_by(|a, b| a.path.cmp(&b.path));
+    devices.sort_by(|a, b| {
+        b.is_connected
+            .cmp(&a.is_connected)
+            .then(b.is_paired.cmp(&a.is_paired))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(Status { adapters, devices })
+}
+
+fn boolean(properties: &HashMap<String, OwnedValue>, name: &str) -> bool {
+    properties
+        .get(name)
+        .is_some_and(|value| matches!(**value, Value::Bool(true)))
+}
+
+fn string(value: &Value<'_>) -> Option<String> {
+    match value {
+        Value::Str(string) => Some(string.to_string()),
+        Value::ObjectPath(path) => Some(path.to_string()),
+        Value::Value(value) => string(value),
+        _ => None,
+    }
+}
@@ -0,0 +1,742 @@
+//! Integrate with system media controls through MPRIS.
+//!
+//! [MPRIS] is the D-Bus interface that media players use to expose what
+//! they are playing and to be controlled by media keys, desktop shells, and
+//! other applications.
+//!
+//! An application can control other players with [`players`],
+//! [`now_playing`], [`watch`], and [`control`]; or act as a player itself
+//! with a [`Server`].
+//!
+//! [MPRIS]: https://specifications.freedesktop.org/mpris-spec/latest/
+//!
+//! # Example
+//! ```no_run
+//! use iced::dbus::{self, Connection};
+//! use iced::dbus::mpris::{self, Command, NowPlaying, Server};
+//! use iced::{Subscription, Task};
+//!
+//! struct State {
+//!     server: Option<Server>,
+//!     is_playing: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Connected(Result<Connection, dbus::Error>),
+//!     Media(Command),
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Connected(Ok(connection)) => {
+//!             state.server = Some(Server::new(&connection, "example", "Example Player"));
+//!             Task::none()
+//!         }
+//!         Message::Connected(Err(_)) => Task::none(),
+//!         Message::Media(Command::PlayPause) => {
+//!             state.is_playing = !state.is_playing;
+//!
+//!             let Some(server) = &state.server else {
+//!                 return Task::none();
+//!             };
+//!
+//!             server
+//!                 .update(NowPlaying {
+//!                     status: if state.is_playing {
+//!                         mpris::Status::Playing
+//!                     } else {
+//!                         mpris::Status::Paused
+//!                     },
+//!                     title: Some("Song".to_owned()),
+//!                     ..NowPlaying::default()
+//!                 })
+//!                 .discard()
+//!         }
+//!         Message::Media(_) => Task::none(),
+//!     }
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     state
+//!         .server
+//!         .as_ref()
+//!         .map(Server::listen)
+//!         .unwrap_or_else(Subscription::none)
+//!         .map(Message::Media)
+//! }
+//! ```
+use crate::dbus::{Connection, Error};
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::{Subscription, Task};
+
+use zbus::names::{InterfaceName, MemberName};
+use zbus::zvariant::{ObjectPath, OwnedValue, Value};
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The object path of every MPRIS player.
+pub const PATH: &str = "/org/mpris/MediaPlayer2";
+
+/// The prefix of the bus name of every MPRIS player.
+pub const PREFIX: &str = "org.mpris.MediaPlayer2.";
+
+const ROOT: &str = "org.mpris.MediaPlayer2";
+const PLAYER: &str = "org.mpris.MediaPlayer2.Player";
+const PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+
+/// The playback status of a player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// A track is playing.
+    Playing,
+    /// A track is paused.
+    Paused,
+    /// Nothing is playing.
+    #[default]
+    Stopped,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Playing => "Playing",
+            Self::Paused => "Paused",
+            Self::Stopped => "Stopped",
+        }
+    }
+}
+
+/// What a player is playing and what it can do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NowPlaying {
+    /// The playback status.
+    pub status: Status,
+    /// The title of the track, if any.
+    pub title: Option<String>,
+    /// The artists of the track.
+    pub artists: Vec<String>,
+    /// The album of the track, if any.
+    pub album: Option<String>,
+    /// The URL of the cover art of the track, if any.
+    pub art_url: Option<String>,
+    /// The length of the track, if known.
+    pub length: Option<Duration>,
+    /// The current position in the track.
+    pub position: Duration,
+    /// Whether playback can be started.
+    pub can_play: bool,
+    /// Whether playback can be paused.
+    pub can_pause: bool,
+    /// Whether the next track can be played.
+    pub can_go_next: bool,
+    /// Whether the previous track can be played.
+    pub can_go_previous: bool,
+    /// Whether the position in the track can be changed.
+    pub can_seek: bool,
+}
+
+impl Default for NowPlaying {
+    fn default() -> Self {
+        Self {
+            status: Status::Stopped,
+            title: None,
+            artists: Vec::new(),
+            album: None,
+            art_url: None,
+            length: None,
+            position: Duration::ZERO,
+            can_play: true,
+            can_pause: true,
+            can_go_next: false,
+            can_go_previous: false,
+            can_seek: false,
+        }
+    }
+}
+
+/// A request to control a player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Starts or resumes playback.
+    Play,
+    /// Pauses playback.
+    Pause,
+    /// Pauses playback if playing; resumes it otherwise.
+    PlayPause,
+    /// Stops playback.
+    Stop,
+    /// Skips to the next track.
+    Next,
+    /// Skips to the previous track.
+    Previous,
+    /// Moves the position forward by the given amount of microseconds; or
+    /// backward if negative.
+    Seek(i64),
+    /// Moves to the given position in the track.
+    SetPosition(Duration),
+    /// Brings the user interface of the player to the front.
+    Raise,
+    /// Quits the player.
+    Quit,
+}
+
+/// Lists the bus names of the running players.
+pub fn players(connection: &Connection) -> Task<Result<Vec<String>, Error>> {
+    connection
+        .call::<_, Vec<String>>(
+            "org.freedesktop.DBus",
+            "/org/freedesktop/DBus",
+            "org.freedesktop.DBus",
+            "ListNames",
+            (),
+        )
+        .map(|names| {
+            names.map(|names| {
+                names
+                    .into_iter()
+                    .filter(|name| name.starts_with(PREFIX))
+                    .collect()
+            })
+        })
+}
+
+/// Fetches what the given player is playing.
+pub fn now_playing(
+    connection: &Connection,
+    player: impl Into<String>,
+) -> Task<Result<NowPlaying, Error>> {
+    let connection = connection.raw().clone();
+    let player = player.into();
+
+    Task::future(async move { fetch(&connection, &player).await })
+}
+
+/// Sends a [`Command`] to the given player.
+pub fn control(
+    connection: &Connection,
+    player: impl Into<String>,
+    command: Command,
+) -> Task<Result<(), Error>> {
+    let player = player.into();
+
+    let call = |interface: &str, method: &str| {
+        connection.call::<_, ()>(player.clone(), PATH, interface, method, ())
+    };
+
+    match command {
+        Command::Play => call(PLAYER, "Play"),
+        Command::Pause => call(PLAYER, "Pause"),
+        Command::PlayPause => call(PLAYER, "PlayPause"),
+        Command::Stop => call(PLAYER, "Stop"),
+        Command::Next => call(PLAYER, "Next"),
+        Command::Previous => call(PLAYER, "Previous"),
+        Command::Raise => call(ROOT, "Raise"),
+        Command::Quit => call(ROOT, "Quit"),
+        Command::Seek(offset) => connection.call(player, PATH, PLAYER, "Seek", offset),
+        Command::SetPosition(position) => {
+            let connection = connection.clone();
+
+            now_playing(&connection, player.clone()).then(move |now_playing| {
+                let Ok(now_playing) = now_playing else {
+                    return Task::done(now_playing.map(|_| ()));
+                };
+
+                let track = ObjectPath::from_string_unchecked(track_id(&now_playing));
+
+                connection.call(
+                    player.clone(),
+                    PATH,
+                    PLAYER,
+                    "SetPosition",
+                    (track, micros(position)),
+                )
+            })
+        }
+    }
+}
+
+/// Watches what the given player is playing; producing a [`NowPlaying`]
+/// right away and every time it changes.
+pub fn watch(connection: &Connection, player: impl Into<String>) -> Subscription<NowPlaying> {
+    Subscription::run_with(
+        Player {
+            connection: connection.clone(),
+            name: player.into(),
+        },
+        |player| {
+            let connection = player.connection.raw().clone();
+            let name = player.name.clone();
+
+            stream::channel(10, async move |mut output| {
+                let Ok(owner) = connection
+                    .call_method(
+                        Some("org.freedesktop.DBus"),
+                        "/org/freedesktop/DBus",
+                        Some("org.freedesktop.DBus"),
+                        "GetNameOwner",
+                        &name,
+                    )
+                    .await
+                    .and_then(|reply| reply.body().deserialize::<String>())
+                else {
+                    return;
+                };
+
+                let Some(rule) = zbus::MatchRule::builder()
+                    .msg_type(zbus::message::Type::Signal)
+                    .sender(owner.as_str())
+                    .and_then(|rule| rule.path(PATH))
+                    .and_then(|rule| rule.interface(PROPERTIES))
+                    .and_then(|rule| rule.member("PropertiesChanged"))
+                    .ok()
+                else {
+                    return;
+                };
+
+                let Ok(mut signals) =
+                    zbus::MessageStream::for_match_rule(rule.build(), &connection, None).await
+                else {
+                    return;
+                };
+
+                let mut last = None;
+
+                loop {
+                    if let Ok(now_playing) = fetch(&connection, &name).await
+                        && last.as_ref() != Some(&now_playing)
+                    {
+                        last = Some(now_playing.clone());
+
+                        let _ = output.send(now_playing).await;
+                    }
+
+                    if signals.next().await.is_none() {
+                        break;
+                    }
+                }
+            })
+        },
+    )
+}
+
+/// An MPRIS player served by the application.
+///
+/// A [`Server`] can be cheaply cloned; every clone shares the same state.
+#[derive(Debug, Clone)]
+pub struct Server {
+    connection: Connection,
+    name: String,
+    identity: String,
+    now_playing: Arc<Mutex<NowPlaying>>,
+}
+
+impl Server {
+    /// Creates a new [`Server`] with the given name and identity.
+    ///
+    /// The name is appended to the MPRIS bus name prefix; like
+    /// `org.mpris.MediaPlayer2.example`. The identity is the name of the
+    /// player displayed to users.
+    pub fn new(
+        connection: &Connection,
+        name: impl Into<String>,
+        identity: impl Into<String>,
+    ) -> Self {
+        Self {
+            connection: connection.clone(),
+            name: name.into(),
+            identity: identity.into(),
+            now_playing: Arc::default(),
+        }
+    }
+
+    /// Returns what the [`Server`] is currently playing.
+    pub fn now_playing(&self) -> NowPlaying {
+        self.now_playing.lock().expect("Lock now playing").clone()
+    }
+
+    /// Changes what the [`Server`] is playing and notifies the system.
+    pub fn update(&self, now_playing: NowPlaying) -> Task<Result<(), Error>> {
+        *self.now_playing.lock().expect("Lock now playing") = now_playing.clone();
+
+        let connection = self.connection.raw().clone();
+
+        Task::future(async move {
+            connection
+                .emit_signal(
+                    None::<&str>,
+                    PATH,
+                    PROPERTIES,
+                    "PropertiesChanged",
+                    &(
+                        PLAYER,
+                        player_properties(&now_playing),
+                        Vec::<String>::new(),
+                    ),
+                )
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    /// Publishes the [`Server`] on the bus; producing a [`Command`] every
+    /// time the system or another application controls it.
+    pub fn listen(&self) -> Subscription<Command> {
+        Subscription::run_with(self.clone(), |server| {
+            let server = server.clone();
+
+            stream::channel(10, async move |mut output| {
+                let connection = server.connection.raw();
+
+                if connection
+                    .request_name(format!("{PREFIX}{}", server.name))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+
+                let Some(rule) = zbus::MatchRule::builder()
+                    .msg_type(zbus::message::Type::MethodCall)
+                    .path(PATH)
+                    .ok()
+                else {
+                    return;
+                };
+
+                let Ok(mut calls) =
+                    zbus::MessageStream::for_match_rule(rule.build(), connection, None).await
+                else {
+                    return;
+                };
+
+                while let Some(call) = calls.next().await {
+                    let Ok(call) = call else {
+                        continue;
+                    };
+
+                    if let Some(command) = server.handle(&call).await {
+                        let _ = output.send(command).await;
+                    }
+                }
+            })
+        })
+    }
+
+    /// Replies to a method call; producing the [`Command`] requested, if
+    /// any.
+    async fn handle(&self, call: &zbus::Message) -> Option<Command> {
+        let connection = self.connection.raw();
+        let header = call.header();
+
+        let interface = header.interface().map(InterfaceName::as_str)?;
+        let member = header.member().map(MemberName::as_str)?;
+
+        let command = match (interface, member) {
+            (PROPERTIES, "Get") => {
+                let (interface, property) = call.body().deserialize::<(String, String)>().ok()?;
+
+                let value = self.properties(&interface).remove(property.as_str());
+
+                let _ = match value {
+                    Some(value) => connection.reply(&header, &value).await,
+                    None => {
+                        connection
+                            .reply_error(&header, "org.freedesktop.DBus.Error.UnknownProperty", &())
+                            .await
+                    }
+                };
+
+                return None;
+            }
+            (PROPERTIES, "GetAll") => {
+                let interface = call.body().deserialize::<String>().ok()?;
+                let _ = connection
+                    .reply(&header, &self.properties(&interface))
+                    .await;
+
+                return None;
+            }
+            (PROPERTIES, "Set") => {
+                let _ = connection
+                    .reply_error(&header, "org.freedesktop.DBus.Error.PropertyReadOnly", &())
+                    .await;
+
+                return None;
+            }
+            ("org.freedesktop.DBus.Introspectable", "Introspect") => {
+                let _ = connection.reply(&header, &INTROSPECTION).await;
+
+                return None;
+            }
+            (ROOT, "Raise") => Some(Command::Raise),
+            (ROOT, "Quit") => Some(Command::Quit),
+            (PLAYER, "Play") => Some(Command::Play),
+            (PLAYER, "Pause") => Some(Command::Pause),
+            (PLAYER, "PlayPause") => Some(Command::PlayPause),
+            (PLAYER, "Stop") => Some(Command::Stop),
+            (PLAYER, "Next") => Some(Command::Next),
+            (PLAYER, "Previous") => Some(Command::Previous),
+            (PLAYER, "Seek") => call.body().deserialize::<i64>().ok().map(Command::Seek),
+            (PLAYER, "SetPosition") => {
+                call.body()
+                    .deserialize::<(ObjectPath<'_>, i64)>()
+                    .ok()
+                    .map(|(_, position)| {
+                        Command::SetPosition(Duration::from_micros(position.max(0) as u64))
+                    })
+            }
+            _ => None,
+        };
+
+        let _ = match command {
+            Some(_) => connection.reply(&header, &()).await,
+            None => {
+                connection
+                    .reply_error(&header, "org.freedesktop.DBus.Error.UnknownMethod", &())
+                    .await
+            }
+        };
+
+        command
+    }
+
+    fn properties(&self, interface: &str) -> HashMap<&'static str, Value<'static>> {
+        match interface {
+            ROOT => HashMap::from([
+                ("Identity", Value::from(self.identity.clone())),
+                ("CanQuit", Value::from(true)),
+                ("CanRaise", Value::from(true)),
+                ("HasTrackList", Value::from(false)),
+                ("SupportedUriSchemes", Value::from(Vec::<String>::new())),
+                ("SupportedMimeTypes", Value::from(Vec::<String>::new())),
+            ]),
+            PLAYER => player_properties(&self.now_playing()),
+            _ => HashMap::new(),
+        }
+    }
+}
+
+impl Hash for Server {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connection.unique_name().hash(state);
+        self.name.hash(state);
+    }
+}
+
+#[derive(Clone)]
+struct Player {
+    connection: Connection,
+    name: String,
+}
+
+impl Hash for Player {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connection.unique_name().hash(state);
+        self.name.hash(state);
+    }
+}
+
+async fn fetch(connection: &zbus::Connection, player: &str) -> Result<NowPlaying, Error> {
+    let reply = connection
+        .call_method(Some(player), PATH, Some(PROPERTIES), "GetAll", &PLAYER)
+        .await?;
+
+    let properties: HashMap<String, OwnedValue> = reply.body().deserialize()?;
+
+    let boolean = |name: &str| {
+        properties
+            .get(name)
+            .is_some_and(|value| matches!(**value, Value::Bool(true)))
+    };
+
+    let metadata: HashMap<String, OwnedValue> = properties
+        .get("Metadata")
+        .and_then(|metadata| metadata.try_clone().ok())
+        .and_then(|metadata| HashMap::try_from(metadata).ok())
+        .unwrap_or_default();
+
+    let text = |name: &str| metadata.get(name).and_then(|value| string(value));
+
+    Ok(NowPlaying {
+        status: match properties
+            .get("PlaybackStatus")
+            .and_then(|value| string(value))
+            .as_deref()
+        {
+            Some("Playing") => Status::Playing,
+            Some("Paused") => Status::Paused,
+            _ => Status::Stopped,
+        },
+        title: text("xesam:title"),
+        artists: metadata
+            .get("xesam:artist")
+            .map(|value| strings(value))
+            .unwrap_or_default(),
+        album: text("xesam:album"),
+        art_url: text("mpris:artUrl"),
+        length: metadata
+            .get("mpris:length")
+            .and_then(|value| integer(value))
+            .map(|length| Duration::from_micros(length.max(0) as u64)),
+        position: properties
+            .get("Position")
+            .and_then(|value| integer(value))
+            .map(|position| Duration::from_micros(position.max(0) as u64))
+            .unwrap_or_default(),
+        can_play: boolean("CanPlay"),
+        can_pause: boolean("CanPause"),
+        can_go_next: boolean("CanGoNext"),
+        can_go_previous: boolean("CanGoPrevious"),
+        can_seek: boolean("CanSeek"),
+    })
+}
+
+fn player_properties(now_playing: &NowPlaying) -> HashMap<&'static str, Value<'static>> {
+    let mut metadata: HashMap<&'static str, Value<'static>> = HashMap::from([(
+        "mpris:trackid",
+        Value::from(ObjectPath::from_string_unchecked(track_id(now_playing))),
+    )]);
+
+    if let Some(title) = &now_playing.title {
+        let _ = metadata.insert("xesam:title", Value::from(title.clone()));
+    }
+
+    if !now_playing.artists.is_empty() {
+        let _ = metadata.insert("xesam:artist", Value::from(now_playing.artists.clone()));
+    }
+
+    if let Some(album) = &now_playing.album {
+        let _ = metadata.insert("xesam:album", Value::from(album.clone()));
+    }
+
+    if let Some(art_url) = &now_playing.art_url {
+        let _ = metadata.insert("mpris:artUrl", Value::from(art_url.clone()));
+    }
+
+    if let Some(length) = now_playing.length {
+        let _ = metadata.insert("mpris:length", Value::from(micros(length)));
+    }
+
+    HashMap::from([
+        ("PlaybackStatus", Value::from(now_playing.status.as_str())),
+        ("Metadata", Value::from(metadata)),
+        ("Position", Value::from(micros(now_playing.position))),
+        ("Rate", Value::from(1.0)),
+        ("MinimumRate", Value::from(1.0)),
+        ("MaximumRate", Value::from(1.0)),
+        ("Volume", Value::from(1.0)),
+        ("CanControl", Value::from(true)),
+        ("CanPlay", Value::from(now_playing.can_play)),
+        ("CanPause", Value::from(now_playing.can_pause)),
+        ("CanGoNext", Value::from(now_playing.can_go_next)),
+        ("CanGoPrevious", Value::from(now_playing.can_go_previous)),
+        ("CanSeek", Value::from(now_playing.can_seek)),
+    ])
+}
+
+/// Produces a stable track identifier out of the metadata of a track.
+fn track_id(now_playing: &NowPlaying) -> String {
+    let mut hasher = DefaultHasher::new();
+
+    now_playing.title.hash(&mut hasher);
+    now_playing.artists.hash(&mut hasher);
+    now_playing.album.hash(&mut hasher);
+
+    format!("/org/iced/Track/T{:016x}", hasher.finish())
+}
+
+fn micros(duration: Duration) -> i64 {
+    i64::try_from(duration.as_micros()).unwrap_or(i64::MAX)
+}
+
+fn string(value: &Value<'_>) -> Option<String> {
+    match value {
+        Value::Str(string) => Some(string.to_string()),
+        Value::ObjectPath(path) => Some(path.to_string()),
+        Value::Value(value) => string(value),
+        _ => None,
+    }
+}
+
+fn strings(value: &Value<'_>) -> Vec<String> {
+    match value {
+        Value::Array(array) => array.iter().filter_map(string).collect(),
+        Value::Value(value) => strings(value),
+        _ => string(value).into_iter().collect(),
+    }
+}
+
+fn integer(value: &Value<'_>) -> Option<i64> {
+    match value {
+        Value::I64(integer) => Some(*integer),
+        Value::U64(integer) => i64::try_from(*integer).ok(),
+        Value::I32(integer) => Some(i64::from(*integer)),
+        Value::U32(integer) => Some(i64::from(*integer)),
+        Value::Value(value) => integer(value),
+        _ => None,
+    }
+}
+
+const INTROSPECTION: &str = r#"<!DOCTYPE node PUBLIC "-//freedesktop//DTD D-BUS Object Introspection 1.0//EN"
+ "http://www.freedesktop.org/standards/dbus/1.0/introspect.dtd">
+<node>
+  <interface name="org.mpris.MediaPlayer2">
+    <method name="Raise"/>
+    <method name="Quit"/>
+    <property name="Identity" type="s" access="read"/>
+    <property name="CanQuit" type="b" access="read"/>
+    <property name="CanRaise" type="b" access="read"/>
+    <property name="HasTrackList" type="b" access="read"/>
+    <property name="SupportedUriSchemes" type="as" access="read"/>
+    <property name="SupportedMimeTypes" type="as" access="read"/>
+  </interface>
+  <interface name="org.mpris.MediaPlayer2.Player">
+    <method name="Play"/>
+    <method name="Pause"/>
+    <method name="PlayPause"/>
+    <method name="Stop"/>
+    <method name="Next"/>
+    <method name="Previous"/>
+    <method name="Seek"><arg name="Offset" type="x" direction="in"/></method>
+    <method name="SetPosition">
+      <arg name="TrackId" type="o" direction="in"/>
+      <arg name="Position" type="x" direction="in"/>
+    </method>
+    <signal name="Seeked"><arg name="Position" type="x"/></signal>
+    <property name="PlaybackStatus" type="s" access="read"/>
+    <property name="Metadata" type="a{sv}" access="read"/>
+    <property name="Position" type="x" access="read"/>
+    <property name="Rate" type="d" access="read"/>
+    <property name="MinimumRate" type="d" access="read"/>
+    <property name="MaximumRate" type="d" access="read"/>
+    <property name="Volume" type="d" access="read"/>
+    <property name="CanControl" type="b" access="read"/>
+    <property name="CanPlay" type="b" access="read"/>
+    <property name="CanPause" type="b" access="read"/>
+    <property name="CanGoNext" type="b" access="read"/>
+    <property name="CanGoPrevious" type="b" access="read"/>
+    <property name="CanSeek" type="b" access="read"/>
+  </interface>
+  <interface name="org.freedesktop.DBus.Properties">
+    <method name="Get">
+      <arg name="interface" type="s" direction="in"/>
+      <arg name="property" type="s" direction="in"/>
+      <arg name="value" type="v" direction="out"/>
+    </method>
+    <method name="GetAll">
+      <arg name="interface" type="s" direction="in"/>
+      <arg name="properties" type="a{sv}" direction="out"/>
+    </method>
+    <signal name="PropertiesChanged">
+      <arg name="interface" type="s"/>
+      <arg name="changed" type="a{sv}"/>
+      <arg name="invalidated" type="as"/>
+    </signal>
+  </interface>
+</node>"#;
@@ -0,0 +1,646 @@
+//! Capture the screen and locate the user through the XDG desktop portal.
+//!
+//! The [`screenshot`] task asks the user for a screenshot and produces it
+//! as an image [`Handle`]; ready to be displayed by an `image` widget.
+//!
+//! A [`ScreenCast`] asks the user for monitors or windows to share and
+//! produces a [`Capture`] with a PipeWire [`Stream`] for each of them;
+//! which can be consumed with any PipeWire client.
+//!
+//! The [`location`] task asks for the geographic [`Location`] of the
+//! device; like a weather widget would.
+//!
+//! All of them are available on Linux desktops with a portal implementation
+//! running; like the ones of GNOME and KDE.
+//!
+//! # Example
+//! ```no_run
+//! use iced::Task;
+//! use iced::dbus::portal::{self, Screenshot};
+//! use iced::dbus::{self, Connection};
+//!
+//! struct State {
+//!     connection: Option<Connection>,
+//!     screenshot: Option<Screenshot>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Connected(Result<Connection, dbus::Error>),
+//!     TakeScreenshot,
+//!     ScreenshotTaken(Result<Screenshot, portal::Error>),
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Connected(connection) => {
+//!             state.connection = connection.ok();
+//!             Task::none()
+//!         }
+//!         Message::TakeScreenshot => {
+//!             let Some(connection) = &state.connection else {
+//!                 return Task::none();
+//!             };
+//!
+//!             portal::screenshot(connection, true).map(Message::ScreenshotTaken)
+//!         }
+//!         Message::ScreenshotTaken(Ok(screenshot)) => {
+//!             state.screenshot = Some(screenshot);
+//!             Task::none()
+//!         }
+//!         Message::ScreenshotTaken(Err(_)) => Task::none(),
+//!     }
+//! }
+//! ```
+use crate::Task;
+use crate::core::image::Handle;
+use crate::dbus::{self, Connection};
+use crate::futures::StreamExt;
+
+use zbus::zvariant::{self, ObjectPath, OwnedValue, Value};
+
+use std::collections::HashMap;
+use std::os::fd;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicU64};
+
+const DESTINATION: &str = "org.freedesktop.portal.Desktop";
+const PATH: &str = "/org/freedesktop/portal/desktop";
+const SCREENSHOT: &str = "org.freedesktop.portal.Screenshot";
+const SCREEN_CAST: &str = "org.freedesktop.portal.ScreenCast";
+const REQUEST: &str = "org.freedesktop.portal.Request";
+const SESSION: &str = "org.freedesktop.portal.Session";
+const LOCATION: &str = "org.freedesktop.portal.Location";
+
+/// Asks the user for a screenshot.
+///
+/// When `interactive` is true, the portal lets the user choose what to
+/// capture before taking it.
+pub fn screenshot(connection: &Connection, interactive: bool) -> Task<Result<Screenshot, Error>> {
+    let connection = connection.raw().clone();
+
+    Task::future(async move {
+        let token = token();
+
+        let mut results = request(
+            &connection,
+            SCREENSHOT,
+            "Screenshot",
+            &token,
+            |mut options| {
+                let _ = options.insert("interactive", Value::from(interactive));
+
+                ("", options)
+            },
+        )
+        .await?;
+
+        let uri = results
+            .remove("uri")
+            .and_then(|uri| string(&uri))
+            .ok_or(Error::InvalidResponse)?;
+
+        let path = uri
+            .strip_prefix("file://")
+            .map(decode)
+            .ok_or(Error::InvalidResponse)?;
+
+        Ok(Screenshot {
+            path: PathBuf::from(path),
+        })
+    })
+}
+
+/// A screenshot taken through the portal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    path: PathBuf,
+}
+
+impl Screenshot {
+    /// Returns the path of the file where the [`Screenshot`] was saved.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns an image [`Handle`] of the [`Screenshot`].
+    pub fn handle(&self) -> Handle {
+        Handle::from_path(&self.path)
+    }
+}
+
+/// Asks for the current geographic [`Location`] of the device with the
+/// given [`Accuracy`].
+///
+/// The portal asks the user for permission the first time; and it
+/// remembers the answer.
+pub fn location(connection: &Connection, accuracy: Accuracy) -> Task<Result<Location, Error>> {
+    let connection = connection.raw().clone();
+
+    Task::future(async move {
+        let session_token = token();
+
+        let reply = connection
+            .call_method(
+                Some(DESTINATION),
+                PATH,
+                Some(LOCATION),
+                "CreateSession",
+                &HashMap::from([
+                    ("session_handle_token", Value::from(session_token.as_str())),
+                    ("accuracy", Value::from(accuracy as u32)),
+                ]),
+            )
+            .await?;
+
+        let session: zvariant::OwnedObjectPath = reply.body().deserialize()?;
+
+        // The first location may arrive as soon as the session starts; so
+        // we listen to it in advance.
+        let rule = zbus::MatchRule::builder()
+            .msg_type(zbus::message::Type::Signal)
+            .interface(LOCATION)?
+            .member("LocationUpdated")?
+            .build();
+
+        let mut updates = zbus::MessageStream::for_match_rule(rule, &connection, None).await?;
+
+        let _ = request(&connection, LOCATION, "Start", &token(), |options| {
+            (&session, "", options)
+        })
+        .await?;
+
+        let location = loop {
+            let update = updates.next().await.ok_or(Error::Cancelled)??;
+
+            let (path, location): (zvariant::OwnedObjectPath, HashMap<String, OwnedValue>) =
+                update.body().deserialize()?;
+
+            if path == session {
+                break location;
+            }
+        };
+
+        let _ = connection
+            .call_method(
+                Some(DESTINATION),
+                session.as_str(),
+                Some(SESSION),
+                "Close",
+                &(),
+            )
+            .await;
+
+        let number = |name: &str| match location.get(name).map(|value| &**value) {
+            Some(Value::F64(number)) => Some(*number),
+            _ => None,
+        };
+
+        Ok(Location {
+            latitude: number("Latitude").ok_or(Error::InvalidResponse)?,
+            longitude: number("Longitude").ok_or(Error::InvalidResponse)?,
+            accuracy: number("Accuracy").unwrap_or_default(),
+            // The portal reports an unknown altitude as the lowest number
+            altitude: number("Altitude").filter(|altitude| *altitude > f64::MIN),
+            description: location
+                .get("Description")
+                .and_then(|description| string(description))
+                .filter(|description| !description.is_empty()),
+        })
+    })
+}
+
+/// The geographic location of the device.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    /// The latitude, in degrees.
+    pub latitude: f64,
+    /// The longitude, in degrees.
+    pub longitude: f64,
+    /// The accuracy of the location, in meters.
+    pub accuracy: f64,
+    /// The altitude, in meters; if known.
+    pub altitude: Option<f64>,
+    /// A description of the location; if any.
+    pub description: Option<String>,
+}
+
+/// The accuracy of a [`Location`].
+///
+/// Coarser accuracies disclose less about the user; so they should be
+/// preferred when enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Accuracy {
+    /// The country of the device.
+    Country = 1,
+    /// The city of the device.
+    #[default]
+    City = 2,
+    /// The neighborhood of the device.
+    Neighborhood = 3,
+    /// The street of the device.
+    Street = 4,
+    /// The exact location of the device.
+    Exact = 5,
+}
+
+/// A request to share the contents of the screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenCast {
+    monitors: bool,
+    windows: bool,
+    multiple: bool,
+    cursor: Cursor,
+    restore_token: Option<String>,
+}
+
+impl ScreenCast {
+    /// Creates a new [`ScreenCast`] of a single monitor.
+    pub fn new() -> Self {
+        Self {
+            monitors: true,
+            windows: false,
+            multiple: false,
+            cursor: Cursor::Embedded,
+            restore_token: None,
+        }
+    }
+
+    /// Sets whether monitors can be shared.
+    pub fn monitors(mut self, monitors: bool) -> Self {
+        self.monitors = monitors;
+        self
+    }
+
+    /// Sets whether windows can be shared.
+    pub fn windows(mut self, windows: bool) -> Self {
+        self.windows = windows;
+        self
+    }
+
+    /// Sets whether the user can share more than one source.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Sets how the cursor is captured.
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Sets the token of a previous [`Capture`] to share the same sources
+    /// again without asking the user.
+    ///
+    /// The token is persisted by the portal until the application
+    /// explicitly revokes it.
+    pub fn restore(mut self, token: impl Into<String>) -> Self {
+        self.restore_token = Some(token.into());
+        self
+    }
+
+    /// Asks the user for the sources to share and starts sharing them.
+    pub fn start(&self, connection: &Connection) -> Task<Result<Capture, Error>> {
+        let connection = connection.raw().clone();
+        let screen_cast = self.clone();
+
+        Task::future(async move { screen_cast.run(&connection).await })
+    }
+
+    async fn run(self, connection: &zbus::Connection) -> Result<Capture, Error> {
+        let session_token = token();
+
+        let mut results = request(
+            connection,
+            SCREEN_CAST,
+            "CreateSession",
+            &token(),
+            |mut options| {
+                let _ = options.insert("session_handle_token", Value::from(session_token.as_str()));
+
+                (options,)
+            },
+        )
+        .await?;
+
+        let session = results
+            .remove("session_handle")
+            .and_then(|session| string(&session))
+            .ok_or(Error::InvalidResponse)?;
+
+        let session_path =
+            ObjectPath::try_from(session.as_str()).map_err(|_| Error::InvalidResponse)?;
+
+        let types = u32::from(self.monitors) | (u32::from(self.windows) << 1);
+
+        let _ = request(
+            connection,
+            SCREEN_CAST,
+            "SelectSources",
+            &token(),
+            |mut options| {
+                let _ = options.insert("types", Value::from(types));
+                let _ = options.insert("multiple", Value::from(self.multiple));
+                let _ = options.insert("cursor_mode", Value::from(self.cursor as u32));
+                let _ = options.insert("persist_mode", Value::from(2u32));
+
+                if let Some(restore_token) = &self.restore_token {
+                    let _ = options.insert("restore_token", Value::from(restore_token.as_str()));
+                }
+
+                (&session_path, options)
+            },
+        )
+        .await?;
+
+        let mut results = request(connection, SCREEN_CAST, "Start", &token(), |options| {
+            (&session_path, "", options)
+        })
+        .await?;
+
+        let streams = results
+            .remove("streams")
+            .map(|streams| read_streams(&streams))
+            .ok_or(Error::InvalidResponse)?;
+
+        let restore_token = results
+            .remove("restore_token")
+            .and_then(|restore_token| string(&restore_token));
+
+        let reply = connection
+            .call_method(
+                Some(DESTINATION),
+                PATH,
+                Some(SCREEN_CAST),
+                "OpenPipeWireRemote",
+                &(&session_path, HashMap::<&str, Value<'_>>::new()),
+            )
+            .await?;
+
+        let remote: zvariant::OwnedFd = reply.body().deserialize()?;
+
+        Ok(Capture {
+            connection: connection.clone(),
+            session,
+            streams,
+            remote: Arc::new(remote.into()),
+            restore_token,
+        })
+    }
+}
+
+impl Default for ScreenCast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How the cursor is captured by a [`ScreenCast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cursor {
+    /// The cursor is not captured.
+    Hidden = 1,
+    /// The cursor is drawn in the frames.
+    #[default]
+    Embedded = 2,
+    /// The cursor is sent as metadata of the frames.
+    Metadata = 4,
+}
+
+/// The sources shared by a [`ScreenCast`].
+///
+/// The sources stay shared until the [`Capture`] is closed.
+#[derive(Debug, Clone)]
+pub struct Capture {
+    connection: zbus::Connection,
+    session: String,
+    streams: Vec<Stream>,
+    remote: Arc<fd::OwnedFd>,
+    restore_token: Option<String>,
+}
+
+impl Capture {
+    /// Returns the PipeWire [`Stream`] of each shared source.
+    pub fn streams(&self) -> &[Stream] {
+        &self.streams
+    }
+
+    /// Returns the file descriptor of the PipeWire remote where the
+    /// [`streams`](Self::streams) can be consumed.
+    pub fn remote(&self) -> fd::BorrowedFd<'_> {
+        fd::AsFd::as_fd(self.remote.as_ref())
+    }
+
+    /// Returns the token to share the same sources again with
+    /// [`ScreenCast::restore`]; if the portal provided one.
+    pub fn restore_token(&self) -> Option<&str> {
+        self.restore_token.as_deref()
+    }
+
+    /// Stops sharing the sources of the [`Capture`].
+    pub fn close(&self) -> Task<Result<(), Error>> {
+        let connection = self.connection.clone();
+        let session = self.session.clone();
+
+        Task::future(async move {
+            let _ = connection
+                .call_method(
+                    Some(DESTINATION),
+                    session.as_str(),
+                    Some(SESSION),
+                    "Close",
+                    &(),
+                )
+                .await?;
+
+            Ok(())
+        })
+    }
+}
+
+/// A PipeWire stream of a source shared by a [`ScreenCast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stream {
+    /// The PipeWire node of the [`Stream`].
+    pub node_id: u32,
+    /// The position of the source in the compositor space; if known.
+    pub position: Option<(i32, i32)>,
+    /// The size of the source; if known.
+    pub size: Option<(i32, i32)>,
+}
+
+/// An error of a portal request.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The D-Bus connection failed.
+    #[error(transparent)]
+    DBus(#[from] dbus::Error),
+
+    /// The user cancelled the request.
+    #[error("the request was cancelled")]
+    Cancelled,
+
+    /// The portal failed to fulfill the request.
+    #[error("the request failed")]
+    Failed,
+
+    /// The portal produced an unexpected response.
+    #[error("the portal produced an invalid response")]
+    InvalidResponse,
+}
+
+impl From<zbus::Error> for Error {
+    fn from(error: zbus::Error) -> Self {
+        Self::DBus(dbus::Error::from(error))
+    }
+}
+
+/// Calls a method of the portal that answers with a request; producing
+/// the results of its response.
+///
+/// The arguments are built from the options of the request, which already
+/// contain its handle token.
+async fn request<'a, A>(
+    connection: &zbus::Connection,
+    interface: &str,
+    method: &str,
+    token: &'a str,
+    arguments: impl FnOnce(HashMap<&'a str, Value<'a>>) -> A,
+) -> Result<HashMap<String, OwnedValue>, Error>
+where
+    A: serde::Serialize + zvariant::Type,
+{
+    let sender = connection
+        .unique_name()
+        .map(|name| name.as_str().trim_start_matches(':').replace('.', "_"))
+        .ok_or(Error::InvalidResponse)?;
+
+    // The response may arrive before the method returns; so we listen
+    // to it in advance.
+    let path = format!("{PATH}/request/{sender}/{token}");
+
+    let rule = zbus::MatchRule::builder()
+        .msg_type(zbus::message::Type::Signal)
+        .interface(REQUEST)?
+        .member("Response")?
+        .path(path.as_str())?
+        .build();
+
+    let mut responses = zbus::MessageStream::for_match_rule(rule, connection, None).await?;
+
+    let options = HashMap::from([("handle_token", Value::from(token))]);
+
+    let _ = connection
+        .call_method(
+            Some(DESTINATION),
+            PATH,
+            Some(interface),
+            method,
+            &arguments(options),
+        )
+        .await?;
+
+    let response = responses.next().await.ok_or(Error::Cancelled)??;
+
+    let (code, results): (u32, HashMap<String, OwnedValue>) = response.body().deserialize()?;
+
+    match code {
+        0 => Ok(results),
+        1 => Err(Error::Cancelled),
+        _ => Err(Error::Failed),
+    }
+}
+
+/// Returns a new unique handle token.
+fn token() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+
+    format!(
+        "iced_{}_{}",
+        std::process::id(),
+        NEXT.fetch_add(1, atomic::Ordering::Relaxed)
+    )
+}
+
+fn read_streams(value: &Value<'_>) -> Vec<Stream> {
+    let Value::Array(streams) = value else {
+        return match value {
+            Value::Value(value) => read_streams(value),
+            _ => Vec::new(),
+        };
+    };
+
+    streams
+        .iter()
+        .filter_map(|stream| {
+            let Value::Structure(stream) = stream else {
+                return None;
+            };
+
+            let [Value::U32(node_id), properties] = stream.fields() else {
+                return None;
+            };
+
+            let property = |name: &str| match properties {
+                Value::Dict(properties) => properties
+                    .iter()
+                    .find(|(key, _)| string(key).as_deref() == Some(name))
+                    .and_then(|(_, value)| pair(value)),
+                _ => None,
+            };
+
+            Some(Stream {
+                node_id: *node_id,
+                position: property("position"),
+                size: property("size"),
+            })
+        })
+        .collect()
+}
+
+fn pair(value: &Value<'_>) -> Option<(i32, i32)> {
+    match value {
+        Value::Structure(structure) => match structure.fields() {
+            [Value::I32(a), Value::I32(b)] => Some((*a, *b)),
+            _ => None,
+        },
+        Value::Value(value) => pair(value),
+        _ => None,
+    }
+}
+
+fn string(value: &Value<'_>) -> Option<String> {
+    match value {
+        Value::Str(string) => Some(string.to_string()),
+        Value::ObjectPath(path) => Some(path.to_string()),
+        Value::Value(value) => string(value),
+        _ => None,
+    }
+}
+
+/// Decodes the percent-encoded characters of a path.
+fn decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
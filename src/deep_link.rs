@@ -0,0 +1,416 @@
+//! Open an application from links with custom URI schemes and from files.
+//!
+//! A [`Handler`] registers the current executable as the handler of some
+//! URI schemes; like `myapp://`. Then, [`listen`] produces an [`Opened`]
+//! message for every link or file the application is opened with.
+//!
+//! Registration is supported on Linux, through the desktop entries of the
+//! user, and on Windows, through the registry of the user. On macOS, the
+//! schemes must be declared in the `Info.plist` of the application bundle
+//! instead.
+//!
+//! # Example
+//! ```no_run
+//! use iced::deep_link::{self, Handler, Opened};
+//! use iced::{Subscription, Task};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Registered(Result<(), deep_link::Error>),
+//!     Opened(Opened),
+//! }
+//!
+//! fn boot() -> Task<Message> {
+//!     Handler::new("com.example.Store")
+//!         .name("Example Store")
+//!         .scheme("example")
+//!         .register()
+//!         .map(Message::Registered)
+//! }
+//!
+//! fn update(message: Message) {
+//!     match message {
+//!         Message::Opened(Opened::Link(link)) if link.host() == Some("game") => {
+//!             let id = link.segments().next();
+//!             // Open the screen of the game...
+//!         }
+//!         Message::Opened(_) | Message::Registered(_) => {}
+//!     }
+//! }
+//!
+//! fn subscription() -> Subscription<Message> {
+//!     deep_link::listen().map(Message::Opened)
+//! }
+//! ```
+//!
+//! Links opened while the application is running are forwarded to it as
+//! command-line arguments when it is made
+//! [`single_instance`](crate::Application::single_instance); these can be
+//! turned into [`Opened`] messages with [`opened`].
+use crate::event;
+use crate::futures::stream;
+use crate::runtime::task;
+use crate::{Subscription, Task};
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Produces an [`Opened`] message for the links and files the application
+/// was launched with and, on macOS, for every link opened afterwards.
+pub fn listen() -> Subscription<Opened> {
+    Subscription::batch([
+        Subscription::run(|| stream::iter(opened(std::env::args().skip(1)))),
+        event::listen_url().filter_map(|url| Link::parse(&url).map(Opened::Link)),
+    ])
+}
+
+/// Returns the links and files among the given command-line arguments.
+pub fn opened(arguments: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<Opened> {
+    arguments
+        .into_iter()
+        .filter_map(|argument| Opened::parse(argument.as_ref()))
+        .collect()
+}
+
+/// Something an application was opened with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Opened {
+    /// A link with some URI scheme.
+    Link(Link),
+    /// A local file.
+    File(PathBuf),
+}
+
+impl Opened {
+    /// Parses a command-line argument as an [`Opened`] link or file; if
+    /// it is any of them.
+    ///
+    /// `file://` links and paths of existing files are parsed as files.
+    pub fn parse(argument: &str) -> Option<Self> {
+        if let Some(path) = argument.strip_prefix("file://") {
+            return Some(Self::File(PathBuf::from(decode(path))));
+        }
+
+        if let Some(link) = Link::parse(argument) {
+            return Some(Self::Link(link));
+        }
+
+        Path::new(argument)
+            .exists()
+            .then(|| Self::File(PathBuf::from(argument)))
+    }
+}
+
+/// A link with some URI scheme; like `myapp://game/123?tab=news`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Link {
+    uri: String,
+    scheme: usize,
+}
+
+impl Link {
+    /// Parses a [`Link`]; if the given text starts with a valid scheme.
+    pub fn parse(uri: &str) -> Option<Self> {
+        let (scheme, _) = uri.split_once(':')?;
+
+        let is_valid = scheme.len() > 1
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+
+        is_valid.then(|| Self {
+            uri: uri.to_owned(),
+            scheme: scheme.len(),
+        })
+    }
+
+    /// Returns the whole [`Link`].
+    pub fn as_str(&self) -> &str {
+        &self.uri
+    }
+
+    /// Returns the scheme of the [`Link`]; like `myapp`.
+    pub fn scheme(&self) -> &str {
+        &self.uri[..self.scheme]
+    }
+
+    /// Returns the host of the [`Link`], if any; like `game` in
+    /// `myapp://game/123`.
+    pub fn host(&self) -> Option<&str> {
+        let authority = self.rest().strip_prefix("//")?;
+        let end = authority.find(['/', '?', '#']).unwrap_or(authority.len());
+
+        Some(&authority[..end]).filter(|host| !host.is_empty())
+    }
+
+    /// Returns the path of the [`Link`]; like `/123` in
+    /// `myapp://game/123`.
+    pub fn path(&self) -> &str {
+        let rest = self.rest();
+
+        let rest = match rest.strip_prefix("//") {
+            Some(authority) => {
+                &authority[authority.find(['/', '?', '#']).unwrap_or(authority.len())..]
+            }
+            None => rest,
+        };
+
+        &rest[..rest.find(['?', '#']).unwrap_or(rest.len())]
+    }
+
+    /// Returns the non-empty segments of the path of the [`Link`].
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.path().split('/').filter(|segment| !segment.is_empty())
+    }
+
+    /// Returns the value of the given query parameter of the [`Link`], if
+    /// present.
+    pub fn query(&self, key: &str) -> Option<String> {
+        let rest = self.rest();
+        let query = &rest[rest.find('?')? + 1..];
+        let query = &query[..query.find('#').unwrap_or(query.len())];
+
+        query.split('&').find_map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+
+            (decode(name) == key).then(|| decode(value))
+        })
+    }
+
+    /// Returns the fragment of the [`Link`], if any.
+    pub fn fragment(&self) -> Option<&str> {
+        self.uri.split_once('#').map(|(_, fragment)| fragment)
+    }
+
+    fn rest(&self) -> &str {
+        &self.uri[self.scheme + 1..]
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.uri)
+    }
+}
+
+/// The registration of the current executable as the handler of some URI
+/// schemes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Handler {
+    id: String,
+    name: Option<String>,
+    schemes: Vec<String>,
+}
+
+impl Handler {
+    /// Creates a new [`Handler`] with the given application identifier;
+    /// like `com.example.App`.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: None,
+            schemes: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the application displayed to users.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds a URI scheme to be handled; like `myapp`.
+    pub fn scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.schemes.push(scheme.into());
+        self
+    }
+
+    /// Registers the current executable as the handler of the schemes of
+    /// the [`Handler`].
+    pub fn register(&self) -> Task<Result<(), Error>> {
+        let handler = self.clone();
+
+        task::try_blocking(move |mut sender| {
+            handler.register_blocking()?;
+            let _ = sender.try_send(());
+
+            Ok(())
+        })
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn register_blocking(&self) -> Result<(), Error> {
+        use std::fmt::Write;
+        use std::process::Command;
+
+        let executable = std::env::current_exe()?;
+
+        let directory = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| Some(PathBuf::from(std::env::var_os("HOME")?).join(".local/share")))
+            .ok_or(Error::Unsupported)?
+            .join("applications");
+
+        let entry = format!("{}.desktop", self.id);
+
+        let mut contents = String::from("[Desktop Entry]\nType=Application\n");
+        let _ = writeln!(
+            contents,
+            "Name={}",
+            self.name.as_deref().unwrap_or(&self.id)
+        );
+        let _ = writeln!(contents, "Exec={} %u", quote(&executable.to_string_lossy()));
+        let _ = writeln!(contents, "NoDisplay=true");
+
+        let _ = write!(contents, "MimeType=");
+
+        for scheme in &self.schemes {
+            let _ = write!(contents, "x-scheme-handler/{scheme};");
+        }
+
+        contents.push('\n');
+
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join(&entry), contents)?;
+
+        for scheme in &self.schemes {
+            let status = Command::new("xdg-mime")
+                .args(["default", &entry, &format!("x-scheme-handler/{scheme}")])
+                .status()?;
+
+            if !status.success() {
+                return Err(Error::Command(format!("xdg-mime failed with {status}")));
+            }
+        }
+
+        let _ = Command::new("update-desktop-database")
+            .arg(&directory)
+            .status();
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    fn register_blocking(&self) -> Result<(), Error> {
+        use std::process::Command;
+
+        let executable = std::env::current_exe()?;
+        let command = format!("\"{}\" \"%1\"", executable.display());
+        let name = self.name.as_deref().unwrap_or(&self.id);
+
+        for scheme in &self.schemes {
+            let key = format!("HKCU\\Software\\Classes\\{scheme}");
+
+            for arguments in [
+                vec!["add", &key, "/ve", "/d", &format!("URL:{name}"), "/f"],
+                vec!["add", &key, "/v", "URL Protocol", "/d", "", "/f"],
+                vec![
+                    "add",
+                    &format!("{key}\\shell\\open\\command"),
+                    "/ve",
+                    "/d",
+                    &command,
+                    "/f",
+                ],
+            ] {
+                let status = Command::new("reg").args(arguments).status()?;
+
+                if !status.success() {
+                    return Err(Error::Command(format!("reg failed with {status}")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(any(all(unix, not(target_os = "macos")), target_os = "windows")))]
+    fn register_blocking(&self) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+}
+
+/// An error registering a [`Handler`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The platform does not support registering URI schemes at runtime.
+    #[error("registering URI schemes is not supported on this platform")]
+    Unsupported,
+
+    /// The handler could not be written.
+    #[error("the handler could not be written: {0}")]
+    Io(Arc<io::Error>),
+
+    /// A system command failed.
+    #[error("the handler could not be registered: {0}")]
+    Command(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+/// Quotes an argument of the `Exec` key of a desktop entry.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn quote(argument: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in argument.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+
+        quoted.push(c);
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Decodes the percent-encoded characters of some text.
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(byte) = text
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_links() {
+        let link = Link::parse("playtron://game/123?tab=news%20feed#top").unwrap();
+
+        assert_eq!(link.scheme(), "playtron");
+        assert_eq!(link.host(), Some("game"));
+        assert_eq!(link.path(), "/123");
+        assert_eq!(link.segments().collect::<Vec<_>>(), ["123"]);
+        assert_eq!(link.query("tab").as_deref(), Some("news feed"));
+        assert_eq!(link.fragment(), Some("top"));
+
+        assert!(Link::parse("C:\\Games").is_none());
+        assert!(Link::parse("no scheme").is_none());
+    }
+}
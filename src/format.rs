@@ -0,0 +1,41 @@
+use std::path::Path;
+
+/// The file format of a theme or stylesheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// [TOML](https://toml.io).
+    Toml,
+    /// [JSON](https://www.json.org).
+    Json,
+}
+
+impl Format {
+    /// Guesses the [`Format`] of a file from its extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn parse<T>(self, source: &str) -> Result<T, String>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        match self {
+            Self::Toml => toml::from_str(source).map_err(|error| error.to_string()),
+            Self::Json => serde_json::from_str(source).map_err(|error| error.to_string()),
+        }
+    }
+
+    pub(crate) fn serialize<T>(self, value: &T) -> Result<String, String>
+    where
+        T: serde::Serialize,
+    {
+        match self {
+            Self::Toml => toml::to_string_pretty(value).map_err(|error| error.to_string()),
+            Self::Json => serde_json::to_string_pretty(value).map_err(|error| error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! Reload the `view` logic of an application from a dynamic library.
+//!
+//! A [`View`] loads a `view` function from a dynamic library and loads it
+//! again every time the library is rebuilt; without restarting the
+//! application or losing its state. This makes iterating on the layout and
+//! styling of an application much faster.
+//!
+//! The dynamic library must be a `cdylib` crate that exports a function
+//! named `iced_hot_view` with the signature of the `view` function of the
+//! application:
+//!
+//! ```ignore
+//! #[unsafe(no_mangle)]
+//! pub fn iced_hot_view(state: &State) -> Element<'_, Message> {
+//!     column![text(state.title()), button("Play").on_press(Message::Play)].into()
+//! }
+//! ```
+//!
+//! Since Rust has no stable ABI, the library must be built with the same
+//! compiler, the same version of `iced`, and the same `State` and `Message`
+//! types of the application; usually by sharing them in a common crate.
+//! Otherwise, the behavior is undefined! This is a development tool and,
+//! therefore, the statically linked fallback should be used in release
+//! builds.
+//!
+//! For hot reloading of any function in the application, take a look at
+//! the `hot` feature instead.
+//!
+//! # Example
+//! ```no_run
+//! use iced::hot_view;
+//! use iced::widget::text;
+//! use iced::{Element, Subscription};
+//!
+//! struct State {
+//!     view: hot_view::View<State, Message>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Reloaded(Result<(), hot_view::Error>),
+//! }
+//!
+//! fn fallback(_state: &State) -> Element<'_, Message> {
+//!     text("Hello!").into()
+//! }
+//!
+//! fn new() -> State {
+//!     State {
+//!         view: hot_view::View::new("target/debug/libui.so", fallback),
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     state.view.view(state)
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     state.view.watch().map(Message::Reloaded)
+//! }
+//! ```
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::{Element, Subscription};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::{Arc, Mutex};
+
+/// The name of the function exported by the dynamic library of a [`View`].
+pub const SYMBOL: &str = "iced_hot_view";
+
+/// The signature of a `view` function.
+pub type Function<State, Message, Theme, Renderer> =
+    for<'a> fn(&'a State) -> Element<'a, Message, Theme, Renderer>;
+
+/// A `view` function loaded from a dynamic library.
+///
+/// A [`View`] can be cheaply cloned; every clone shares the same loaded
+/// function.
+pub struct View<State, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    path: PathBuf,
+    fallback: Function<State, Message, Theme, Renderer>,
+    loaded: Arc<Mutex<Option<Function<State, Message, Theme, Renderer>>>>,
+}
+
+impl<State, Message, Theme, Renderer> View<State, Message, Theme, Renderer> {
+    /// Creates a new [`View`] that loads its function from the dynamic
+    /// library at the given path; using the given fallback until then.
+    ///
+    /// The library is loaded once [`watch`](Self::watch) is subscribed to.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        fallback: Function<State, Message, Theme, Renderer>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            fallback,
+            loaded: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the path of the dynamic library of the [`View`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the [`View`] has loaded its function from the
+    /// dynamic library.
+    pub fn is_loaded(&self) -> bool {
+        self.loaded.lock().expect("Lock hot view").is_some()
+    }
+
+    /// Produces the [`Element`] of the latest loaded function; or of the
+    /// fallback, if none has been loaded yet.
+    pub fn view<'a>(&self, state: &'a State) -> Element<'a, Message, Theme, Renderer> {
+        let view = self
+            .loaded
+            .lock()
+            .expect("Lock hot view")
+            .unwrap_or(self.fallback);
+
+        view(state)
+    }
+
+    /// Returns a [`Subscription`] that loads the dynamic library of the
+    /// [`View`] and reloads it every time it changes.
+    ///
+    /// The [`Subscription`] produces the result of every load. A message
+    /// triggers a new `view` call, so mapping it to any message is enough
+    /// for the application to display the latest function.
+    pub fn watch(&self) -> Subscription<Result<(), Error>>
+    where
+        State: 'static,
+        Message: 'static,
+        Theme: 'static,
+        Renderer: 'static,
+    {
+        Subscription::run_with(
+            Watch {
+                path: self.path.clone(),
+                loaded: Arc::clone(&self.loaded),
+            },
+            |watch| {
+                let watch = watch.clone();
+
+                stream::channel(1, async move |mut output| {
+                    let _ = output.send(watch.load()).await;
+
+                    let (sender, mut receiver) = mpsc::unbounded();
+
+                    let watcher = notify::recommended_watcher(move |event| {
+                        let _ = sender.unbounded_send(event);
+                    });
+
+                    let mut watcher = match watcher {
+                        Ok(watcher) => watcher,
+                        Err(error) => {
+                            let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                            return;
+                        }
+                    };
+
+                    // Linkers usually replace libraries on build; so we watch
+                    // the parent directory instead of the library itself.
+                    let directory = match watch.path.parent() {
+                        Some(parent) if !parent.as_os_str().is_empty() => parent,
+                        _ => Path::new("."),
+                    };
+
+                    if let Err(error) = notify::Watcher::watch(
+                        &mut watcher,
+                        directory,
+                        notify::RecursiveMode::NonRecursive,
+                    ) {
+                        let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                        return;
+                    }
+
+                    while let Some(event) = receiver.next().await {
+                        let changed = match event {
+                            Ok(event) => {
+                                (event.kind.is_create() || event.kind.is_modify())
+                                    && event.paths.iter().any(|changed| {
+                                        changed.file_name() == watch.path.file_name()
+                                    })
+                            }
+                            Err(error) => {
+                                let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                                continue;
+                            }
+                        };
+
+                        if changed {
+                            let _ = output.send(watch.load()).await;
+                        }
+                    }
+                })
+            },
+        )
+    }
+}
+
+impl<State, Message, Theme, Renderer> Clone for View<State, Message, Theme, Renderer> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            fallback: self.fallback,
+            loaded: Arc::clone(&self.loaded),
+        }
+    }
+}
+
+impl<State, Message, Theme, Renderer> fmt::Debug for View<State, Message, Theme, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("View")
+            .field("path", &self.path)
+            .field("is_loaded", &self.is_loaded())
+            .finish()
+    }
+}
+
+/// An error loading the dynamic library of a [`View`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The dynamic library could not be loaded.
+    #[error("the library could not be loaded: {0}")]
+    Load(String),
+
+    /// The dynamic library does not export the [`SYMBOL`] function.
+    #[error("the library does not export `{SYMBOL}`: {0}")]
+    Symbol(String),
+
+    /// The dynamic library could not be watched for changes.
+    #[error("the library could not be watched: {0}")]
+    Watch(String),
+}
+
+struct Watch<State, Message, Theme, Renderer> {
+    path: PathBuf,
+    loaded: Arc<Mutex<Option<Function<State, Message, Theme, Renderer>>>>,
+}
+
+impl<State, Message, Theme, Renderer> Watch<State, Message, Theme, Renderer> {
+    #[allow(unsafe_code)]
+    fn load(&self) -> Result<(), Error> {
+        static COPIES: AtomicUsize = AtomicUsize::new(0);
+
+        // Dynamic loaders cache libraries by path; so every version is
+        // loaded from a fresh copy.
+        let copy = std::env::temp_dir().join(format!(
+            "iced-hot-view-{}-{}-{}",
+            std::process::id(),
+            COPIES.fetch_add(1, atomic::Ordering::Relaxed),
+            self.path.file_name().unwrap_or_default().to_string_lossy()
+        ));
+
+        let _ = std::fs::copy(&self.path, &copy).map_err(|error| Error::Load(error.to_string()))?;
+
+        // SAFETY: The library is trusted to be built like the application;
+        // as documented in the module.
+        let loaded = unsafe { libloading::Library::new(&copy) };
+        let _ = std::fs::remove_file(&copy);
+
+        let library = loaded.map_err(|error| Error::Load(error.to_string()))?;
+
+        // SAFETY: The exported function is trusted to have the signature of
+        // a `view` function; as documented in the module.
+        let view = unsafe {
+            library
+                .get::<Function<State, Message, Theme, Renderer>>(SYMBOL.as_bytes())
+                .map(|symbol| *symbol)
+                .map_err(|error| Error::Symbol(error.to_string()))?
+        };
+
+        // Widgets produced by previous versions may still be alive and
+        // point to their code; so libraries are never unloaded.
+        std::mem::forget(library);
+
+        *self.loaded.lock().expect("Lock hot view") = Some(view);
+
+        Ok(())
+    }
+}
+
+impl<State, Message, Theme, Renderer> Clone for Watch<State, Message, Theme, Renderer> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            loaded: Arc::clone(&self.loaded),
+        }
+    }
+}
+
+impl<State, Message, Theme, Renderer> Hash for Watch<State, Message, Theme, Renderer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+    }
+}
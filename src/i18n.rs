@@ -0,0 +1,517 @@
+//! Localize your application with [Fluent](https://projectfluent.org) bundles.
+//!
+//! Translations are grouped in a [`Bundle`] per [`Locale`], which can be
+//! registered globally with [`add_bundle`]. Then, the [`tr!`] macro can be
+//! used in any `view` to translate a message into the current [`Locale`].
+//!
+//! The current [`Locale`] defaults to the [`Locale::system`] and can be
+//! changed at runtime with [`set_locale`], which rebuilds the interface of
+//! every window.
+//!
+//! If a message is missing from the [`Bundle`] of the current [`Locale`],
+//! the first registered [`Bundle`] is used as a fallback. If no [`Bundle`]
+//! contains the message, the key itself is returned.
+//!
+//! # Example
+//! ```no_run
+//! use iced::i18n::{self, Bundle, Locale};
+//! use iced::widget::{button, column, text};
+//! use iced::{Element, Task, tr};
+//!
+//! const EN: &str = "
+//! greeting = Hello, { $name }!
+//! switch = Switch to Spanish
+//! ";
+//!
+//! const ES: &str = "
+//! greeting = ¡Hola, { $name }!
+//! switch = Cambiar a inglés
+//! ";
+//!
+//! fn boot() -> (State, Task<Message>) {
+//!     i18n::add_bundle(Bundle::parse(Locale::new("en", None), EN).unwrap());
+//!     i18n::add_bundle(Bundle::parse(Locale::new("es", None), ES).unwrap());
+//!
+//!     (State, i18n::set_locale(Locale::new("en", None)))
+//! }
+//!
+//! struct State;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Switch,
+//! }
+//!
+//! fn update(_state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Switch => {
+//!             let locale = if i18n::locale().language() == "en" {
+//!                 Locale::new("es", None)
+//!             } else {
+//!                 Locale::new("en", None)
+//!             };
+//!
+//!             i18n::set_locale(locale)
+//!         }
+//!     }
+//! }
+//!
+//! fn view(_state: &State) -> Element<'_, Message> {
+//!     column![
+//!         text(tr!("greeting", name = "Ferris")),
+//!         button(text(tr!("switch"))).on_press(Message::Switch),
+//!     ]
+//!     .into()
+//! }
+//! ```
+mod bundle;
+mod locale;
+mod plural;
+
+pub use bundle::Bundle;
+pub use locale::{Date, Locale};
+pub use plural::Plural;
+
+use crate::Task;
+use iced_futures::MaybeSend;
+
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// The value of an argument of a translated message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A string.
+    String(String),
+    /// A number; formatted according to the [`Locale`] of the [`Bundle`].
+    Number(f64),
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Self::String(value.clone())
+    }
+}
+
+macro_rules! number {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// Translates a message into the current [`Locale`].
+///
+/// Arguments can be provided as `name = value` pairs after the key of the
+/// message. Any value convertible into a [`Value`] can be used.
+///
+/// ```no_run
+/// use iced::tr;
+///
+/// let title: String = tr!("title");
+/// let greeting: String = tr!("greeting", name = "Ferris", unread = 3);
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($key:expr $(,)?) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate(
+            $key,
+            &[$((stringify!($name), $crate::i18n::Value::from($value))),+],
+        )
+    };
+}
+
+/// Declares a module of message keys that are validated at compile time
+/// against a Fluent resource; given as a `&'static str` expression.
+///
+/// Every key becomes a `&str` constant with the name of the message. If the
+/// resource does not define the message, compilation fails. Keys that are
+/// not valid Rust identifiers (like `save.tooltip` or `new-file`) can be
+/// given explicitly.
+///
+/// The resource is usually embedded with [`include_str!`], relative to the
+/// file invoking the macro:
+///
+/// ```no_run
+/// use iced::{i18n, tr};
+///
+/// const EN: &str = "
+/// greeting = Hello, { $name }!
+/// unread = { $count ->
+///     [one] One unread message
+///    *[other] { $count } unread messages
+/// }
+/// save = Save
+///     .tooltip = Save the current document
+/// ";
+///
+/// i18n::keys! {
+///     EN;
+///
+///     pub mod key {
+///         greeting,
+///         unread,
+///         save_tooltip = "save.tooltip",
+///     }
+/// }
+///
+/// let greeting: String = tr!(key::greeting, name = "Ferris");
+/// let unread: String = tr!(key::unread, count = 3);
+/// ```
+#[macro_export]
+macro_rules! __i18n_keys {
+    (
+        $source:expr;
+
+        $vis:vis mod $module:ident {
+            $($name:ident $(= $key:literal)?),* $(,)?
+        }
+    ) => {
+        #[allow(non_upper_case_globals)]
+        $vis mod $module {
+            $(
+                #[doc = concat!("The `", $crate::__i18n_key!($name $(= $key)?), "` message.")]
+                pub const $name: &str = $crate::__i18n_key!($name $(= $key)?);
+            )*
+        }
+
+        $(
+            const _: () = assert!(
+                $crate::i18n::defines($source, $module::$name),
+                concat!(
+                    "the resource does not define the `",
+                    $crate::__i18n_key!($name $(= $key)?),
+                    "` message",
+                )
+            );
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __i18n_key {
+    ($name:ident) => {
+        stringify!($name)
+    };
+    ($name:ident = $key:literal) => {
+        $key
+    };
+}
+
+#[doc(inline)]
+pub use crate::__i18n_keys as keys;
+
+#[derive(Debug, Default)]
+struct Catalog {
+    bundles: Vec<Bundle>,
+    locale: Option<Locale>,
+}
+
+impl Catalog {
+    fn locale(&self) -> Locale {
+        self.locale.clone().unwrap_or_else(|| SYSTEM_LOCALE.clone())
+    }
+
+    fn translate(&self, key: &str, args: &[(&str, Value)]) -> Option<String> {
+        let locale = self.locale();
+
+        let best = self
+            .bundles
+            .iter()
+            .find(|bundle| bundle.locale() == &locale)
+            .or_else(|| {
+                self.bundles
+                    .iter()
+                    .find(|bundle| bundle.locale().matches_language(&locale))
+            });
+
+        best.into_iter()
+            .chain(self.bundles.first())
+            .find_map(|bundle| bundle.format(key, args))
+    }
+}
+
+static CATALOG: RwLock<Catalog> = RwLock::new(Catalog {
+    bundles: Vec::new(),
+    locale: None,
+});
+
+static SYSTEM_LOCALE: LazyLock<Locale> = LazyLock::new(|| Locale::system().unwrap_or_default());
+
+/// Registers a [`Bundle`] globally.
+///
+/// If a [`Bundle`] with the same [`Locale`] was already registered, its
+/// messages will be extended and overridden by the new [`Bundle`].
+///
+/// The first registered [`Bundle`] is the fallback for missing messages.
+pub fn add_bundle(bundle: Bundle) {
+    let mut catalog = CATALOG.write().expect("Write i18n catalog");
+
+    if let Some(existing) = catalog
+        .bundles
+        .iter_mut()
+        .find(|existing| existing.locale() == bundle.locale())
+    {
+        existing.extend(bundle);
+    } else {
+        catalog.bundles.push(bundle);
+    }
+}
+
+/// Loads all the Fluent files in the given directory as a [`Task`].
+///
+/// Every subdirectory named after a [`Locale`] (like `en-US` or `es`) is
+/// loaded as a single [`Bundle`] containing all of its `.ftl` files, and
+/// registered with [`add_bundle`].
+///
+/// The [`Task`] produces the loaded locales, sorted by name.
+pub fn load(directory: impl Into<PathBuf>) -> Task<Result<Vec<Locale>, Error>> {
+    let directory = directory.into();
+
+    Task::future(async move {
+        let mut locales = Vec::new();
+        let mut entries = std::fs::read_dir(&directory)?
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .collect::<Vec<_>>();
+
+        entries.sort_by_key(std::fs::DirEntry::file_name);
+
+        for entry in entries {
+            let Some(locale) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<Locale>().ok())
+            else {
+                continue;
+            };
+
+            let mut bundle = Bundle::new(locale.clone());
+            let mut files = std::fs::read_dir(entry.path())?
+                .filter_map(Result::ok)
+                .map(|file| file.path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "ftl"))
+                .collect::<Vec<_>>();
+
+            files.sort();
+
+            for file in files {
+                bundle.add_resource(&std::fs::read_to_string(file)?)?;
+            }
+
+            add_bundle(bundle);
+            locales.push(locale);
+        }
+
+        Ok(locales)
+    })
+}
+
+/// Returns the current [`Locale`].
+pub fn locale() -> Locale {
+    CATALOG.read().expect("Read i18n catalog").locale()
+}
+
+/// Returns the locales of all the registered bundles, in registration order.
+pub fn available_locales() -> Vec<Locale> {
+    CATALOG
+        .read()
+        .expect("Read i18n catalog")
+        .bundles
+        .iter()
+        .map(|bundle| bundle.locale().clone())
+        .collect()
+}
+
+/// Changes the current [`Locale`] and rebuilds the interface of every window,
+/// so layouts are recomputed with the new translations.
+///
+/// The [`Direction`](crate::Direction) of layouts is changed to the
+/// [`Locale::direction`] as well.
+///
+/// Nothing changes until the [`Task`] runs.
+pub fn set_locale<T>(locale: Locale) -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    Task::future(async move {
+        let direction = locale.direction();
+
+        CATALOG.write().expect("Write i18n catalog").locale = Some(locale);
+
+        direction
+    })
+    .then(crate::set_direction)
+}
+
+/// Translates the message with the given key into the current [`Locale`].
+///
+/// You will most likely want to use the [`tr!`] macro instead.
+pub fn translate(key: &str, args: &[(&str, Value)]) -> String {
+    CATALOG
+        .read()
+        .expect("Read i18n catalog")
+        .translate(key, args)
+        .unwrap_or_else(|| key.to_owned())
+}
+
+/// Returns the cardinal [`Plural`] category of a number in the current
+/// [`Locale`].
+pub fn plural(number: impl Into<f64>) -> Plural {
+    locale().plural(number.into())
+}
+
+/// Returns `true` if the given Fluent resource defines the message with
+/// the given key.
+///
+/// Attributes can be queried with a dot; like `save.tooltip`.
+///
+/// This function is `const`, so it can validate keys at compile time.
+/// You will most likely want to use the [`keys!`] macro instead.
+///
+/// ```
+/// use iced::i18n;
+///
+/// const RESOURCE: &str = "greeting = Hello!\nsave = Save\n    .tooltip = Save now";
+///
+/// const _: () = assert!(i18n::defines(RESOURCE, "save.tooltip"));
+///
+/// assert!(i18n::defines(RESOURCE, "greeting"));
+/// assert!(!i18n::defines(RESOURCE, "farewell"));
+/// assert!(!i18n::defines(RESOURCE, "greeting.tooltip"));
+/// ```
+pub const fn defines(source: &str, key: &str) -> bool {
+    /// Returns `true` if the identifier at `start` is followed by `=`,
+    /// optionally after some spaces.
+    const fn defines_at(source: &[u8], start: usize, id: &[u8]) -> bool {
+        let mut i = 0;
+
+        while i < id.len() {
+            if start + i >= source.len() || source[start + i] != id[i] {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        let mut i = start + id.len();
+
+        while i < source.len() && source[i] == b' ' {
+            i += 1;
+        }
+
+        i < source.len() && source[i] == b'='
+    }
+
+    let source = source.as_bytes();
+    let key = key.as_bytes();
+
+    let mut dot = 0;
+
+    while dot < key.len() && key[dot] != b'.' {
+        dot += 1;
+    }
+
+    let (message, attribute) = key.split_at(dot);
+    let mut start = 0;
+    let mut in_message = false;
+
+    while start < source.len() {
+        if source[start] == b' ' {
+            let mut i = start;
+
+            while i < source.len() && source[i] == b' ' {
+                i += 1;
+            }
+
+            if in_message
+                && !attribute.is_empty()
+                && i < source.len()
+                && source[i] == b'.'
+                && defines_at(source, i + 1, attribute.split_at(1).1)
+            {
+                return true;
+            }
+        } else if source[start] != b'\n' && source[start] != b'\r' {
+            in_message = defines_at(source, start, message);
+
+            if in_message && attribute.is_empty() {
+                return true;
+            }
+        }
+
+        while start < source.len() && source[start] != b'\n' {
+            start += 1;
+        }
+
+        start += 1;
+    }
+
+    false
+}
+
+/// Formats a number with up to 3 fraction digits in the current [`Locale`].
+pub fn number(value: impl Into<f64>) -> String {
+    locale().number(value.into())
+}
+
+/// Formats a number with exactly the given amount of fraction digits in the
+/// current [`Locale`].
+pub fn fixed(value: impl Into<f64>, decimals: usize) -> String {
+    locale().fixed(value.into(), decimals)
+}
+
+/// Formats a [`Date`] in the current [`Locale`].
+pub fn date(date: Date) -> String {
+    locale().date(date)
+}
+
+/// An error that occurred while loading a [`Bundle`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// A Fluent file could not be read.
+    #[error("the fluent file could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+
+    /// A Fluent resource is invalid.
+    #[error("invalid fluent resource at line {line}: {message}")]
+    Parse {
+        /// The line where the error was found, starting at 1.
+        line: usize,
+        /// A description of the error.
+        message: String,
+    },
+
+    /// A locale could not be parsed.
+    #[error("invalid locale: {0}")]
+    InvalidLocale(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
@@ -0,0 +1,383 @@
+use crate::i18n::locale::NumberFormat;
+use crate::i18n::{Error, Locale, Value};
+
+use fluent_bundle::memoizer::MemoizerKind;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use intl_memoizer::concurrent::IntlLangMemoizer;
+
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A set of translated messages for a single [`Locale`].
+///
+/// A [`Bundle`] is built from resources written in the
+/// [Fluent](https://projectfluent.org) syntax, and formatted by
+/// [`fluent-bundle`](https://docs.rs/fluent-bundle):
+///
+/// ```ftl
+/// # Comments start with a hash
+/// -brand = Iced
+///
+/// welcome = Welcome to { -brand }, { $name }!
+/// about =
+///     { -brand } is a cross-platform GUI library
+///     focused on simplicity and type-safety.
+///
+/// save = Save
+///     .tooltip = Save the current document
+///
+/// price = { NUMBER($amount, minimumFractionDigits: 2) } €
+///
+/// unread =
+///     { $count ->
+///         [0] No unread messages
+///         [one] One unread message
+///        *[other] { $count } unread messages
+///     }
+/// ```
+///
+/// Placeables are not wrapped in Unicode isolation marks, and numbers are
+/// formatted with the separators of the [`Locale`]. The `NUMBER` function
+/// accepts the `minimumFractionDigits` and `maximumFractionDigits` options.
+pub struct Bundle {
+    locale: Locale,
+    resources: Vec<Arc<FluentResource>>,
+    raw: fluent_bundle::concurrent::FluentBundle<Arc<FluentResource>>,
+}
+
+impl Bundle {
+    /// Creates a new empty [`Bundle`] for the given [`Locale`].
+    pub fn new(locale: Locale) -> Self {
+        let mut raw =
+            fluent_bundle::concurrent::FluentBundle::new_concurrent(vec![locale.identifier()]);
+
+        raw.set_use_isolating(false);
+        raw.set_formatter(Some(format_number));
+
+        let _ = raw.add_function("NUMBER", |positional, named| match positional.first() {
+            Some(FluentValue::Number(number)) => {
+                let mut number = number.clone();
+                number.options.merge(named);
+
+                FluentValue::Number(number)
+            }
+            _ => FluentValue::Error,
+        });
+
+        Self {
+            locale,
+            resources: Vec::new(),
+            raw,
+        }
+    }
+
+    /// Parses a [`Bundle`] for the given [`Locale`] from a Fluent resource.
+    pub fn parse(locale: Locale, source: &str) -> Result<Self, Error> {
+        let mut bundle = Self::new(locale);
+        bundle.add_resource(source)?;
+
+        Ok(bundle)
+    }
+
+    /// Loads a [`Bundle`] for the given [`Locale`] from a Fluent file
+    /// (usually with the `.ftl` extension).
+    pub fn load(locale: Locale, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let source = std::fs::read_to_string(path)?;
+
+        Self::parse(locale, &source)
+    }
+
+    /// Adds the messages of the given Fluent resource to the [`Bundle`].
+    ///
+    /// Messages already present in the [`Bundle`] will be overridden.
+    pub fn add_resource(&mut self, source: &str) -> Result<(), Error> {
+        let resource = FluentResource::try_new(source.to_owned()).map_err(|(_, errors)| {
+            let Some(error) = errors.first() else {
+                return Error::Parse {
+                    line: 1,
+                    message: String::from("invalid resource"),
+                };
+            };
+
+            Error::Parse {
+                line: source[..error.pos.start.min(source.len())]
+                    .matches('\n')
+                    .count()
+                    + 1,
+                message: error.to_string(),
+            }
+        })?;
+
+        self.add(Arc::new(resource));
+
+        Ok(())
+    }
+
+    fn add(&mut self, resource: Arc<FluentResource>) {
+        self.raw.add_resource_overriding(resource.clone());
+        self.resources.push(resource);
+    }
+
+    /// Adds all the messages of another [`Bundle`] to the [`Bundle`],
+    /// overriding any existing ones.
+    pub(crate) fn extend(&mut self, other: Bundle) {
+        for resource in other.resources {
+            self.add(resource);
+        }
+    }
+
+    /// Returns the [`Locale`] of the [`Bundle`].
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Returns `true` if the [`Bundle`] contains a message with the given key.
+    ///
+    /// Attributes can be queried with a dot; like `save.tooltip`.
+    pub fn contains(&self, key: &str) -> bool {
+        let (id, attribute) = split_key(key);
+
+        self.raw
+            .get_message(id)
+            .is_some_and(|message| match attribute {
+                Some(attribute) => message.get_attribute(attribute).is_some(),
+                None => message.value().is_some(),
+            })
+    }
+
+    /// Formats the message with the given key using the given arguments.
+    ///
+    /// Returns `None` if the [`Bundle`] does not contain the message.
+    pub fn format(&self, key: &str, args: &[(&str, Value)]) -> Option<String> {
+        let (id, attribute) = split_key(key);
+        let message = self.raw.get_message(id)?;
+
+        let pattern = match attribute {
+            Some(attribute) => message.get_attribute(attribute)?.value(),
+            None => message.value()?,
+        };
+
+        let mut arguments = FluentArgs::with_capacity(args.len());
+
+        for (name, value) in args {
+            arguments.set(
+                *name,
+                match value {
+                    Value::String(value) => FluentValue::from(value.as_str()),
+                    Value::Number(value) => FluentValue::from(*value),
+                },
+            );
+        }
+
+        // Errors are rendered inline; like `{$missing}`
+        let mut errors = Vec::new();
+
+        Some(
+            self.raw
+                .format_pattern(pattern, Some(&arguments), &mut errors)
+                .into_owned(),
+        )
+    }
+}
+
+impl Clone for Bundle {
+    fn clone(&self) -> Self {
+        let mut bundle = Self::new(self.locale.clone());
+
+        for resource in &self.resources {
+            bundle.add(resource.clone());
+        }
+
+        bundle
+    }
+}
+
+impl fmt::Debug for Bundle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bundle")
+            .field("locale", &self.locale)
+            .field("resources", &self.resources.len())
+            .finish()
+    }
+}
+
+/// Splits a key into the id of its message and its attribute, if any.
+fn split_key(key: &str) -> (&str, Option<&str>) {
+    match key.split_once('.') {
+        Some((id, attribute)) => (id, Some(attribute)),
+        None => (key, None),
+    }
+}
+
+/// Formats the numbers in messages with the separators of the locale of the
+/// bundle.
+fn format_number(value: &FluentValue<'_>, memoizer: &IntlLangMemoizer) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+
+    let maximum = number.options.maximum_fraction_digits.unwrap_or(3);
+    let minimum = number
+        .options
+        .minimum_fraction_digits
+        .unwrap_or(0)
+        .min(maximum);
+
+    memoizer
+        .with_try_get_threadsafe::<NumberFormat, _, _>((), |format| {
+            format.format(number.value, minimum, maximum)
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESOURCE: &str = r#"
+# A comment
+-brand = Iced
+
+welcome = Welcome to { -brand }, { $name }!
+about =
+    { -brand } is a GUI library
+    focused on simplicity.
+count = You have { $count } messages
+price = { NUMBER($amount, minimumFractionDigits: 2) } €
+save = Save
+    .tooltip = Save { "{" }now{ "}" }
+missing = Hello, { $name } and { nowhere }
+"#;
+
+    fn bundle() -> Bundle {
+        Bundle::parse(Locale::new("en", Some("US")), RESOURCE).expect("Parse resource")
+    }
+
+    #[test]
+    fn it_formats_messages() {
+        let bundle = bundle();
+
+        assert_eq!(
+            bundle.format("welcome", &[("name", Value::from("Ferris"))]),
+            Some(String::from("Welcome to Iced, Ferris!"))
+        );
+
+        assert_eq!(
+            bundle.format("about", &[]),
+            Some(String::from(
+                "Iced is a GUI library\nfocused on simplicity."
+            ))
+        );
+
+        assert_eq!(
+            bundle.format("count", &[("count", Value::from(1200))]),
+            Some(String::from("You have 1,200 messages"))
+        );
+
+        assert_eq!(
+            bundle.format("price", &[("amount", Value::from(1234.5))]),
+            Some(String::from("1,234.50 €"))
+        );
+
+        assert_eq!(
+            bundle.format("save.tooltip", &[]),
+            Some(String::from("Save {now}"))
+        );
+
+        assert_eq!(
+            bundle.format("missing", &[]),
+            Some(String::from("Hello, {$name} and {nowhere}"))
+        );
+
+        assert_eq!(bundle.format("nothing", &[]), None);
+        assert!(bundle.contains("save.tooltip"));
+        assert!(!bundle.contains("save.label"));
+    }
+
+    #[test]
+    fn it_selects_variants() {
+        let english = Bundle::parse(
+            Locale::new("en", None),
+            r#"
+emails =
+    { $count ->
+        [0] No emails
+        [one] One email
+       *[other] { $count } emails
+    }
+theme = { $mode ->
+    [dark] Dark { "{" }mode{ "}" }
+   *[light] Light
+}
+"#,
+        )
+        .expect("Parse resource");
+
+        let emails = |count: i32| english.format("emails", &[("count", Value::from(count))]);
+
+        assert_eq!(emails(0), Some(String::from("No emails")));
+        assert_eq!(emails(1), Some(String::from("One email")));
+        assert_eq!(emails(1500), Some(String::from("1,500 emails")));
+
+        assert_eq!(
+            english.format("theme", &[("mode", Value::from("dark"))]),
+            Some(String::from("Dark {mode}"))
+        );
+        assert_eq!(
+            english.format("theme", &[("mode", Value::from("sepia"))]),
+            Some(String::from("Light"))
+        );
+
+        let russian = Bundle::parse(
+            Locale::new("ru", None),
+            "files = { $n ->\n [one] { $n } файл\n [few] { $n } файла\n *[many] { $n } файлов\n}",
+        )
+        .expect("Parse resource");
+
+        let files = |n: i32| russian.format("files", &[("n", Value::from(n))]);
+
+        assert_eq!(files(21), Some(String::from("21 файл")));
+        assert_eq!(files(3), Some(String::from("3 файла")));
+        assert_eq!(files(11), Some(String::from("11 файлов")));
+    }
+
+    #[test]
+    fn it_overrides_messages() {
+        let mut bundle = bundle();
+
+        bundle
+            .add_resource("welcome = Hi, { $name }!")
+            .expect("Parse resource");
+
+        assert_eq!(
+            bundle
+                .clone()
+                .format("welcome", &[("name", Value::from("Ferris"))]),
+            Some(String::from("Hi, Ferris!"))
+        );
+    }
+
+    #[test]
+    fn it_reports_invalid_resources() {
+        let locale = Locale::default();
+
+        assert!(matches!(
+            Bundle::parse(locale.clone(), "ok = fine\n1nvalid = nope"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+
+        assert!(matches!(
+            Bundle::parse(locale.clone(), "unclosed = { $name"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+
+        assert!(matches!(
+            Bundle::parse(
+                locale,
+                "ok = fine\nno-default = { $n ->\n [one] One\n [other] Many\n}"
+            ),
+            Err(Error::Parse { .. })
+        ));
+    }
+}
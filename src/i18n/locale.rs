@@ -0,0 +1,311 @@
+use crate::Direction;
+use crate::i18n::{Error, Plural};
+
+use fixed_decimal::FixedDecimal;
+use icu::datetime::DateFormatter;
+use icu::datetime::options::length;
+use icu::decimal::FixedDecimalFormatter;
+use icu::decimal::options::FixedDecimalFormatterOptions;
+use unic_langid::LanguageIdentifier;
+
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A language identifier, like `en-US` or `pt-BR`.
+///
+/// A [`Locale`] also knows how to format numbers and dates the way its
+/// language and region expect.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    language: String,
+    region: Option<String>,
+}
+
+impl Locale {
+    /// Creates a new [`Locale`] from a language and an optional region.
+    ///
+    /// The language is lowercased and the region is uppercased.
+    pub fn new(language: impl AsRef<str>, region: Option<&str>) -> Self {
+        Self {
+            language: language.as_ref().to_ascii_lowercase(),
+            region: region.map(str::to_ascii_uppercase),
+        }
+    }
+
+    /// Returns the [`Locale`] of the system, if it can be determined.
+    ///
+    /// It is read from the `LC_ALL`, `LC_MESSAGES`, and `LANG` environment
+    /// variables, in that order.
+    pub fn system() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|variable| env::var(variable).ok())
+            .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+            .and_then(|value| value.parse().ok())
+    }
+
+    /// Returns the language of the [`Locale`]; like `en` or `pt`.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Returns the region of the [`Locale`], if any; like `US` or `BR`.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// Returns `true` if both locales share the same language.
+    pub fn matches_language(&self, other: &Self) -> bool {
+        self.language == other.language
+    }
+
+    /// Returns the [`Direction`] of the script of the [`Locale`].
+    ///
+    /// ```
+    /// # use iced::Direction;
+    /// # use iced::i18n::Locale;
+    /// assert_eq!(Locale::new("en", None).direction(), Direction::LeftToRight);
+    /// assert_eq!(Locale::new("ar", Some("EG")).direction(), Direction::RightToLeft);
+    /// ```
+    pub fn direction(&self) -> Direction {
+        match self.language.as_str() {
+            "ar" | "he" | "iw" | "fa" | "ur" | "ps" | "yi" | "dv" | "ckb" | "sd" | "ug" => {
+                Direction::RightToLeft
+            }
+            _ => Direction::LeftToRight,
+        }
+    }
+
+    /// Returns the cardinal [`Plural`] category of a number in the [`Locale`].
+    ///
+    /// ```
+    /// # use iced::i18n::{Locale, Plural};
+    /// let polish = Locale::new("pl", None);
+    ///
+    /// assert_eq!(polish.plural(1.0), Plural::One);
+    /// assert_eq!(polish.plural(23.0), Plural::Few);
+    /// assert_eq!(polish.plural(25.0), Plural::Many);
+    /// ```
+    pub fn plural(&self, number: f64) -> Plural {
+        Plural::cardinal(self, number)
+    }
+
+    /// Formats a number with up to 3 fraction digits, using the separators
+    /// of the [`Locale`].
+    ///
+    /// ```
+    /// # use iced::i18n::Locale;
+    /// let english: Locale = "en-US".parse().unwrap();
+    /// let german: Locale = "de-DE".parse().unwrap();
+    ///
+    /// assert_eq!(english.number(1234567.891), "1,234,567.891");
+    /// assert_eq!(german.number(1234.5), "1.234,5");
+    /// ```
+    pub fn number(&self, value: f64) -> String {
+        self.format_number(value, 0, 3)
+    }
+
+    /// Formats a number with exactly the given amount of fraction digits,
+    /// using the separators of the [`Locale`].
+    pub fn fixed(&self, value: f64, decimals: usize) -> String {
+        self.format_number(value, decimals, decimals)
+    }
+
+    fn format_number(&self, value: f64, minimum: usize, maximum: usize) -> String {
+        match NumberFormat::new(&self.to_string()) {
+            Some(format) => format.format(value, minimum, maximum),
+            None => format!("{value:.maximum$}"),
+        }
+    }
+
+    /// Formats a [`Date`] in the short form of the [`Locale`].
+    ///
+    /// ```
+    /// # use iced::i18n::{Date, Locale};
+    /// let date = Date::new(2025, 3, 9);
+    ///
+    /// let english = "en-US".parse::<Locale>().unwrap().date(date);
+    /// let german = "de".parse::<Locale>().unwrap().date(date);
+    ///
+    /// assert_ne!(english, german);
+    /// ```
+    pub fn date(&self, date: Date) -> String {
+        let Date { year, month, day } = date;
+
+        let formatted = self
+            .to_string()
+            .parse::<icu::locid::Locale>()
+            .ok()
+            .and_then(|locale| {
+                let formatter =
+                    DateFormatter::try_new_with_length(&(&locale).into(), length::Date::Short)
+                        .ok()?;
+
+                let date = icu::calendar::Date::try_new_iso_date(year, month, day)
+                    .ok()?
+                    .to_any();
+
+                formatter.format_to_string(&date).ok()
+            });
+
+        formatted.unwrap_or_else(|| format!("{year}-{month:02}-{day:02}"))
+    }
+
+    /// Returns the [`LanguageIdentifier`] of the [`Locale`], used by Fluent.
+    pub(crate) fn identifier(&self) -> LanguageIdentifier {
+        self.to_string().parse().unwrap_or_default()
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new("en", Some("US"))
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Error;
+
+    /// Parses a [`Locale`] from a BCP 47 tag (`en-US`) or a POSIX
+    /// locale (`en_US.UTF-8`).
+    fn from_str(locale: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidLocale(locale.to_owned());
+
+        let tag = locale.split(['.', '@']).next().unwrap_or_default();
+        let mut subtags = tag.split(['-', '_']);
+
+        let language = subtags.next().unwrap_or_default();
+
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid());
+        }
+
+        // Scripts (e.g. `Hant`) and variants are ignored
+        let region = subtags.find(|subtag| {
+            (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        });
+
+        Ok(Self::new(language, region))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.region {
+            Some(region) => write!(f, "{}-{region}", self.language),
+            None => f.write_str(&self.language),
+        }
+    }
+}
+
+/// A calendar date, in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year.
+    pub year: i32,
+    /// The month, from 1 to 12.
+    pub month: u8,
+    /// The day of the month, from 1 to 31.
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new [`Date`].
+    pub const fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Returns the current [`Date`], in UTC.
+    pub fn today() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+
+    /// Returns the [`Date`] of the given [`SystemTime`], in UTC.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let seconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(error) => -(error.duration().as_secs() as i64),
+        };
+
+        // Howard Hinnant's `civil_from_days` algorithm
+        let days = seconds.div_euclid(86_400) + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+/// A number formatter for a language.
+///
+/// It is memoized per [`Bundle`](crate::i18n::Bundle) to format the numbers
+/// in messages.
+pub(crate) struct NumberFormat(FixedDecimalFormatter);
+
+impl NumberFormat {
+    fn new(language: &str) -> Option<Self> {
+        let locale: icu::locid::Locale = language.parse().ok()?;
+
+        FixedDecimalFormatter::try_new(&(&locale).into(), FixedDecimalFormatterOptions::default())
+            .ok()
+            .map(Self)
+    }
+
+    /// Formats a number with at least `minimum` and at most `maximum`
+    /// fraction digits.
+    pub(crate) fn format(&self, value: f64, minimum: usize, maximum: usize) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let mut formatted = format!("{value:.maximum$}");
+
+        if let Some((integer, fraction)) = formatted.split_once('.') {
+            let digits = fraction.trim_end_matches('0').len().max(minimum);
+
+            formatted = if digits == 0 {
+                integer.to_owned()
+            } else {
+                format!("{integer}.{}", &fraction[..digits])
+            };
+        }
+
+        if !formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            formatted = formatted.trim_start_matches('-').to_owned();
+        }
+
+        match formatted.parse::<FixedDecimal>() {
+            Ok(decimal) => self.0.format_to_string(&decimal),
+            Err(_) => formatted,
+        }
+    }
+}
+
+impl intl_memoizer::Memoizable for NumberFormat {
+    type Args = ();
+    type Error = ();
+
+    fn construct(language: LanguageIdentifier, _args: ()) -> Result<Self, ()> {
+        Self::new(&language.to_string()).ok_or(())
+    }
+}
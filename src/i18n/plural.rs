@@ -0,0 +1,100 @@
+use crate::i18n::Locale;
+
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+
+/// The plural category of a number, as defined by the
+/// [CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules).
+///
+/// Variants of a select expression in a [`Bundle`](crate::i18n::Bundle)
+/// are matched against the name of the category of a number:
+///
+/// ```ftl
+/// emails =
+///     { $count ->
+///         [0] You have no emails
+///         [one] You have one email
+///        *[other] You have { $count } emails
+///     }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Plural {
+    /// The `zero` category.
+    Zero,
+    /// The `one` category.
+    One,
+    /// The `two` category.
+    Two,
+    /// The `few` category.
+    Few,
+    /// The `many` category.
+    Many,
+    /// The `other` category.
+    Other,
+}
+
+impl Plural {
+    /// Returns the cardinal [`Plural`] category of a number in the given
+    /// [`Locale`], using the rules of [`intl_pluralrules`].
+    ///
+    /// Languages without known rules always use the `other` category.
+    pub fn cardinal(locale: &Locale, number: f64) -> Self {
+        let rules =
+            PluralRules::create(locale.identifier(), PluralRuleType::CARDINAL).or_else(|_| {
+                PluralRules::create(
+                    Locale::new(locale.language(), None).identifier(),
+                    PluralRuleType::CARDINAL,
+                )
+            });
+
+        let Ok(rules) = rules else {
+            return Self::Other;
+        };
+
+        match rules.select(number) {
+            Ok(PluralCategory::ZERO) => Self::Zero,
+            Ok(PluralCategory::ONE) => Self::One,
+            Ok(PluralCategory::TWO) => Self::Two,
+            Ok(PluralCategory::FEW) => Self::Few,
+            Ok(PluralCategory::MANY) => Self::Many,
+            Ok(PluralCategory::OTHER) | Err(_) => Self::Other,
+        }
+    }
+
+    /// Returns the CLDR name of the [`Plural`] category; like `one` or `few`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_follows_the_cldr_rules() {
+        let plural = |locale: &str, number: f64| {
+            Plural::cardinal(&locale.parse().expect("Parse locale"), number)
+        };
+
+        assert_eq!(plural("en-US", 1.0), Plural::One);
+        assert_eq!(plural("en-US", 1.5), Plural::Other);
+        assert_eq!(plural("cs", 3.0), Plural::Few);
+        assert_eq!(plural("cs", 1.5), Plural::Many);
+        assert_eq!(plural("sk", 0.5), Plural::Many);
+        assert_eq!(plural("pt-PT", 0.0), Plural::Other);
+        assert_eq!(plural("pt-BR", 0.0), Plural::One);
+        assert_eq!(plural("lt", 2.0), Plural::Few);
+        assert_eq!(plural("lv", 0.0), Plural::Zero);
+        assert_eq!(plural("ga", 2.0), Plural::Two);
+        assert_eq!(plural("cy", 3.0), Plural::Few);
+        assert_eq!(plural("ja", 1.0), Plural::Other);
+        assert_eq!(plural("xx", 1.0), Plural::Other);
+    }
+}
@@ -0,0 +1,443 @@
+//! Run long-running background jobs that report their progress.
+//!
+//! A [`Jobs`] queue spawns asynchronous jobs; like downloads, installations,
+//! or scans. Every job reports typed progress with its [`Context`] and
+//! produces some output when finished.
+//!
+//! Jobs run while they are part of the [`Jobs::subscription`]; so the queue
+//! must be kept up to date by feeding it every [`Event`] with
+//! [`Jobs::update`]. The amount of jobs running at the same time can be
+//! limited with [`Jobs::limit`]; the rest wait in the queue.
+//!
+//! # Example
+//! ```no_run
+//! use iced::jobs::{self, Jobs};
+//! use iced::widget::{column, progress_bar, text};
+//! use iced::{Element, Subscription};
+//!
+//! struct State {
+//!     downloads: Jobs<f32, Result<(), String>>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Download(String),
+//!     Cancel(jobs::Id),
+//!     Job(jobs::Event<f32, Result<(), String>>),
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Download(url) => {
+//!             let _ = state.downloads.spawn(url.clone(), async move |context| {
+//!                 for chunk in 0..100 {
+//!                     if context.is_cancelled() {
+//!                         return Err("Cancelled".to_owned());
+//!                     }
+//!
+//!                     // Download chunk...
+//!                     context.progress(chunk as f32 / 100.0).await;
+//!                 }
+//!
+//!                 Ok(())
+//!             });
+//!         }
+//!         Message::Cancel(id) => {
+//!             state.downloads.cancel(id);
+//!         }
+//!         Message::Job(event) => {
+//!             if let Some((_id, result)) = state.downloads.update(event) {
+//!                 // Handle the result of the download...
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column(state.downloads.iter().map(|job| {
+//!         column![
+//!             text(job.name()),
+//!             progress_bar(0.0..=1.0, job.progress().copied().unwrap_or_default()),
+//!         ]
+//!         .into()
+//!     }))
+//!     .into()
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     state.downloads.subscription().map(Message::Job)
+//! }
+//! ```
+use crate::Subscription;
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, Stream};
+use crate::stream;
+
+use iced_futures::{BoxFuture, MaybeSend};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicBool, AtomicU64};
+use std::sync::{Arc, Mutex};
+
+/// The identifier of a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    fn unique() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+/// The status of a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The job is waiting for other jobs to finish.
+    Queued,
+    /// The job is running.
+    Running,
+    /// The job was asked to cancel, but it is still running.
+    Cancelling,
+}
+
+/// A notification of a running job.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<P, O> {
+    /// The job reported some progress.
+    Progress(Id, P),
+    /// The job finished with some output.
+    Finished(Id, O),
+}
+
+/// A token used to ask a job to cancel.
+///
+/// Cancellation is cooperative; jobs must check [`Token::is_cancelled`]
+/// and stop on their own.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    /// Creates a new [`Token`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the job of the [`Token`] to cancel.
+    pub fn cancel(&self) {
+        self.0.store(true, atomic::Ordering::Relaxed);
+    }
+
+    /// Returns true if the job of the [`Token`] was asked to cancel.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(atomic::Ordering::Relaxed)
+    }
+}
+
+/// The context of a running job.
+pub struct Context<P, O> {
+    id: Id,
+    token: Token,
+    sender: mpsc::Sender<Event<P, O>>,
+}
+
+impl<P, O> Context<P, O> {
+    /// Returns the [`Id`] of the job.
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the cancellation [`Token`] of the job.
+    pub fn token(&self) -> &Token {
+        &self.token
+    }
+
+    /// Returns true if the job was asked to cancel.
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Reports the progress of the job.
+    pub async fn progress(&self, progress: P) {
+        let _ = self
+            .sender
+            .clone()
+            .send(Event::Progress(self.id, progress))
+            .await;
+    }
+}
+
+/// A job in a [`Jobs`] queue.
+pub struct Job<P, O> {
+    id: Id,
+    name: String,
+    status: Status,
+    progress: Option<P>,
+    token: Token,
+    work: Arc<Mutex<Option<Work<P, O>>>>,
+}
+
+type Work<P, O> = Box<dyn FnOnce(Context<P, O>) -> BoxFuture<O> + Send>;
+
+impl<P, O> Job<P, O> {
+    /// Returns the [`Id`] of the [`Job`].
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the name of the [`Job`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`Status`] of the [`Job`].
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns the last progress reported by the [`Job`], if any.
+    pub fn progress(&self) -> Option<&P> {
+        self.progress.as_ref()
+    }
+}
+
+impl<P: fmt::Debug, O> fmt::Debug for Job<P, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Job")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("status", &self.status)
+            .field("progress", &self.progress)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A queue of background jobs with progress of type `P` and output of
+/// type `O`.
+pub struct Jobs<P, O> {
+    jobs: Vec<Job<P, O>>,
+    limit: Option<usize>,
+}
+
+impl<P, O> Jobs<P, O> {
+    /// Creates a new empty [`Jobs`] queue with no concurrency limit.
+    pub fn new() -> Self {
+        Self {
+            jobs: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Sets the maximum amount of jobs running at the same time.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit.max(1));
+        self.schedule();
+        self
+    }
+
+    /// Spawns a new job with the given name; producing its [`Id`].
+    ///
+    /// The job starts as soon as the concurrency limit allows it.
+    pub fn spawn<F>(
+        &mut self,
+        name: impl Into<String>,
+        job: impl FnOnce(Context<P, O>) -> F + Send + 'static,
+    ) -> Id
+    where
+        F: Future<Output = O> + MaybeSend + 'static,
+    {
+        let id = Id::unique();
+        let work: Work<P, O> = Box::new(move |context| Box::pin(job(context)));
+
+        self.jobs.push(Job {
+            id,
+            name: name.into(),
+            status: Status::Queued,
+            progress: None,
+            token: Token::new(),
+            work: Arc::new(Mutex::new(Some(work))),
+        });
+
+        self.schedule();
+
+        id
+    }
+
+    /// Asks the job with the given [`Id`] to cancel.
+    ///
+    /// Queued jobs are removed right away, since they have not started.
+    pub fn cancel(&mut self, id: Id) {
+        let Some(index) = self.jobs.iter().position(|job| job.id == id) else {
+            return;
+        };
+
+        if self.jobs[index].status == Status::Queued {
+            let _ = self.jobs.remove(index);
+        } else {
+            let job = &mut self.jobs[index];
+
+            job.token.cancel();
+            job.status = Status::Cancelling;
+        }
+    }
+
+    /// Stops the job with the given [`Id`] immediately, without waiting
+    /// for it to finish.
+    pub fn abort(&mut self, id: Id) {
+        self.jobs.retain(|job| job.id != id);
+        self.schedule();
+    }
+
+    /// Processes an [`Event`] of the [`Jobs::subscription`].
+    ///
+    /// Returns the output of the job if the [`Event`] finished it.
+    pub fn update(&mut self, event: Event<P, O>) -> Option<(Id, O)> {
+        match event {
+            Event::Progress(id, progress) => {
+                if let Some(job) = self.jobs.iter_mut().find(|job| job.id == id) {
+                    job.progress = Some(progress);
+                }
+
+                None
+            }
+            Event::Finished(id, output) => {
+                self.jobs.retain(|job| job.id != id);
+                self.schedule();
+
+                Some((id, output))
+            }
+        }
+    }
+
+    /// Returns the [`Job`] with the given [`Id`], if queued or running.
+    pub fn get(&self, id: Id) -> Option<&Job<P, O>> {
+        self.jobs.iter().find(|job| job.id == id)
+    }
+
+    /// Returns an iterator over the queued and running jobs, in spawn
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = &Job<P, O>> {
+        self.jobs.iter()
+    }
+
+    /// Returns the amount of queued and running jobs.
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Returns true if there are no queued or running jobs.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Returns the [`Subscription`] that runs the jobs of the queue.
+    pub fn subscription(&self) -> Subscription<Event<P, O>>
+    where
+        P: MaybeSend + 'static,
+        O: MaybeSend + 'static,
+    {
+        Subscription::batch(
+            self.jobs
+                .iter()
+                .filter(|job| job.status != Status::Queued)
+                .map(|job| {
+                    Subscription::run_with(
+                        Running {
+                            id: job.id,
+                            token: job.token.clone(),
+                            work: Arc::clone(&job.work),
+                        },
+                        run,
+                    )
+                }),
+        )
+    }
+
+    fn schedule(&mut self) {
+        let limit = self.limit.unwrap_or(usize::MAX);
+
+        for job in self.jobs.iter_mut().take(limit) {
+            if job.status == Status::Queued {
+                job.status = Status::Running;
+            }
+        }
+    }
+}
+
+impl<P, O> Default for Jobs<P, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: fmt::Debug, O> fmt::Debug for Jobs<P, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jobs")
+            .field("jobs", &self.jobs)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+struct Running<P, O> {
+    id: Id,
+    token: Token,
+    work: Arc<Mutex<Option<Work<P, O>>>>,
+}
+
+impl<P, O> Hash for Running<P, O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+fn run<P, O>(running: &Running<P, O>) -> impl Stream<Item = Event<P, O>> + use<P, O>
+where
+    P: MaybeSend + 'static,
+    O: MaybeSend + 'static,
+{
+    let id = running.id;
+    let token = running.token.clone();
+    let work = running.work.lock().expect("Lock job").take();
+
+    stream::channel(100, async move |mut sender| {
+        let Some(work) = work else {
+            return;
+        };
+
+        let output = work(Context {
+            id,
+            token,
+            sender: sender.clone(),
+        })
+        .await;
+
+        let _ = sender.send(Event::Finished(id, output)).await;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_limits_concurrency() {
+        let mut jobs = Jobs::<(), ()>::new().limit(1);
+
+        let a = jobs.spawn("A", async |_| {});
+        let b = jobs.spawn("B", async |_| {});
+        let c = jobs.spawn("C", async |_| {});
+
+        assert_eq!(jobs.get(a).map(Job::status), Some(Status::Running));
+        assert_eq!(jobs.get(b).map(Job::status), Some(Status::Queued));
+
+        jobs.cancel(b);
+        assert!(jobs.get(b).is_none());
+
+        assert_eq!(jobs.update(Event::Finished(a, ())), Some((a, ())));
+        assert_eq!(jobs.get(c).map(Job::status), Some(Status::Running));
+        assert_eq!(jobs.len(), 1);
+    }
+}
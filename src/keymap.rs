@@ -0,0 +1,408 @@
+//! Declare rebindable keyboard shortcuts for the actions of your application.
+//!
+//! A [`Keymap`] maps named actions to a [`Chord`]; like `Ctrl+S`. Every
+//! action has a default chord, which users can change at runtime with the
+//! [`editor`] of the [`Keymap`] or any [`Recorder`].
+//!
+//! The [`Keymap::listen`] subscription produces the action bound to every
+//! key press in any window, so shortcuts work no matter what widget has
+//! focus.
+//!
+//! Chords bound to more than one action are reported as [`Conflict`]s.
+//! When a conflicting chord is pressed, the first declared action wins.
+//!
+//! # Example
+//! ```no_run
+//! use iced::keymap::{self, Chord, Keymap};
+//! use iced::widget::{column, text};
+//! use iced::{Element, Subscription};
+//!
+//! #[derive(Debug, Clone, PartialEq, Hash)]
+//! enum Action {
+//!     Save,
+//!     Open,
+//!     Quit,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Perform(Action),
+//!     Rebind(Action, Option<Chord>),
+//! }
+//!
+//! struct State {
+//!     keymap: Keymap<Action>,
+//! }
+//!
+//! impl Default for State {
+//!     fn default() -> Self {
+//!         Self {
+//!             keymap: Keymap::new()
+//!                 .action(Action::Save, "Save", "Cmd+S")
+//!                 .action(Action::Open, "Open", "Cmd+O")
+//!                 .action(Action::Quit, "Quit", "Cmd+Q"),
+//!         }
+//!     }
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Perform(action) => {
+//!             // ...
+//!         }
+//!         Message::Rebind(action, chord) => {
+//!             let _conflicts = state.keymap.bind(&action, chord);
+//!         }
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         text("Shortcuts").size(20),
+//!         keymap::editor(&state.keymap, Message::Rebind),
+//!     ]
+//!     .spacing(10)
+//!     .into()
+//! }
+//!
+//! fn subscription(state: &State) -> Subscription<Message> {
+//!     state.keymap.listen().map(Message::Perform)
+//! }
+//! ```
+mod chord;
+mod recorder;
+
+pub use chord::{Chord, Error};
+pub use recorder::{Recorder, recorder};
+
+use crate::core;
+use crate::core::keyboard::{self, key};
+use crate::widget::command_palette::Command;
+use crate::widget::{button, column, row, text};
+use crate::{Element, Fill, Subscription};
+
+use iced_futures::MaybeSend;
+use iced_futures::subscription;
+
+use std::hash::Hash;
+
+/// A set of named actions bound to keyboard [`Chord`]s.
+#[derive(Debug, Clone)]
+pub struct Keymap<A> {
+    bindings: Vec<Binding<A>>,
+}
+
+/// An action declared in a [`Keymap`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding<A> {
+    /// The action.
+    pub action: A,
+    /// The human-readable name of the action.
+    pub name: String,
+    /// The [`Chord`] currently bound to the action, if any.
+    pub chord: Option<Chord>,
+    /// The default [`Chord`] of the action, if any.
+    pub default: Option<Chord>,
+}
+
+/// A [`Chord`] bound to more than one action of a [`Keymap`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict<A> {
+    /// The conflicting [`Chord`].
+    pub chord: Chord,
+    /// The actions bound to the [`Chord`], in declaration order.
+    pub actions: Vec<A>,
+}
+
+impl<A> Keymap<A>
+where
+    A: Clone + PartialEq,
+{
+    /// Creates a new empty [`Keymap`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Declares an action with the given name and default [`Chord`].
+    ///
+    /// An empty `chord` declares the action unbound by default.
+    ///
+    /// # Panics
+    /// Panics if the action has already been declared or if the `chord`
+    /// is not a valid [`Chord`]. Use [`Keymap::with`] to declare actions
+    /// with chords coming from untrusted sources.
+    pub fn action(self, action: A, name: impl Into<String>, chord: &str) -> Self {
+        let chord = if chord.is_empty() {
+            None
+        } else {
+            Some(
+                Chord::parse(chord)
+                    .unwrap_or_else(|error| panic!("invalid chord {chord:?}: {error}")),
+            )
+        };
+
+        self.with(action, name, chord)
+    }
+
+    /// Declares an action with the given name and an optional default
+    /// [`Chord`].
+    ///
+    /// # Panics
+    /// Panics if the action has already been declared.
+    pub fn with(mut self, action: A, name: impl Into<String>, chord: Option<Chord>) -> Self {
+        assert!(
+            self.binding(&action).is_none(),
+            "the action has already been declared"
+        );
+
+        self.bindings.push(Binding {
+            action,
+            name: name.into(),
+            chord: chord.clone(),
+            default: chord,
+        });
+
+        self
+    }
+
+    /// Binds the given action to a [`Chord`], or unbinds it with `None`.
+    ///
+    /// Returns the other actions bound to the same [`Chord`], if any.
+    /// Undeclared actions are ignored.
+    pub fn bind(&mut self, action: &A, chord: Option<Chord>) -> Vec<A> {
+        let Some(binding) = self
+            .bindings
+            .iter_mut()
+            .find(|binding| binding.action == *action)
+        else {
+            return Vec::new();
+        };
+
+        binding.chord = chord;
+
+        self.conflicts_with(action)
+    }
+
+    /// Resets the given action to its default [`Chord`].
+    ///
+    /// Returns the other actions bound to the same [`Chord`], if any.
+    pub fn reset(&mut self, action: &A) -> Vec<A> {
+        let Some(default) = self.binding(action).map(|binding| binding.default.clone()) else {
+            return Vec::new();
+        };
+
+        self.bind(action, default)
+    }
+
+    /// Resets every action to its default [`Chord`].
+    pub fn reset_all(&mut self) {
+        for binding in &mut self.bindings {
+            binding.chord.clone_from(&binding.default);
+        }
+    }
+
+    /// Returns the [`Chord`] bound to the given action, if any.
+    pub fn chord(&self, action: &A) -> Option<&Chord> {
+        self.binding(action)?.chord.as_ref()
+    }
+
+    /// Returns the [`Binding`] of the given action, if declared.
+    pub fn binding(&self, action: &A) -> Option<&Binding<A>> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == *action)
+    }
+
+    /// Returns an iterator over the [`Binding`]s of the [`Keymap`], in
+    /// declaration order.
+    pub fn bindings(&self) -> impl Iterator<Item = &Binding<A>> {
+        self.bindings.iter()
+    }
+
+    /// Returns the [`Command`]s of every action, which can be searched and
+    /// run with a [`command_palette`].
+    ///
+    /// The current [`Chord`] of each action is displayed as its shortcut.
+    ///
+    /// [`command_palette`]: crate::widget::command_palette
+    pub fn commands<Message>(&self, on_run: impl Fn(A) -> Message) -> Vec<Command<Message>> {
+        self.bindings
+            .iter()
+            .map(|binding| Command {
+                name: binding.name.clone(),
+                shortcut: binding.chord.as_ref().map(Chord::to_string),
+                message: on_run(binding.action.clone()),
+            })
+            .collect()
+    }
+
+    /// Returns the action triggered by the given key press, if any.
+    pub fn find(
+        &self,
+        key: &keyboard::Key,
+        physical_key: key::Physical,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<&A> {
+        self.bindings
+            .iter()
+            .find(|binding| {
+                binding
+                    .chord
+                    .as_ref()
+                    .is_some_and(|chord| chord.matches(key, physical_key, modifiers))
+            })
+            .map(|binding| &binding.action)
+    }
+
+    /// Returns every [`Conflict`] of the [`Keymap`].
+    pub fn conflicts(&self) -> Vec<Conflict<A>> {
+        let mut conflicts: Vec<Conflict<A>> = Vec::new();
+
+        for binding in &self.bindings {
+            let Some(chord) = &binding.chord else {
+                continue;
+            };
+
+            if let Some(conflict) = conflicts
+                .iter_mut()
+                .find(|conflict| conflict.chord == *chord)
+            {
+                conflict.actions.push(binding.action.clone());
+            } else {
+                conflicts.push(Conflict {
+                    chord: chord.clone(),
+                    actions: vec![binding.action.clone()],
+                });
+            }
+        }
+
+        conflicts.retain(|conflict| conflict.actions.len() > 1);
+        conflicts
+    }
+
+    /// Returns the other actions bound to the same [`Chord`] as the given
+    /// action.
+    pub fn conflicts_with(&self, action: &A) -> Vec<A> {
+        let Some(chord) = self.chord(action) else {
+            return Vec::new();
+        };
+
+        self.bindings
+            .iter()
+            .filter(|binding| binding.action != *action && binding.chord.as_ref() == Some(chord))
+            .map(|binding| binding.action.clone())
+            .collect()
+    }
+
+    /// Returns a [`Subscription`] that produces the action bound to every
+    /// key press of any window.
+    ///
+    /// Key presses captured by a widget only trigger chords with a
+    /// modifier other than shift; so typing in a text input does not
+    /// trigger single-key shortcuts. Key repeats are ignored.
+    pub fn listen(&self) -> Subscription<A>
+    where
+        A: Hash + MaybeSend + 'static,
+    {
+        #[derive(Hash)]
+        struct Listen;
+
+        let bindings: Vec<(Chord, A)> = self
+            .bindings
+            .iter()
+            .filter_map(|binding| Some((binding.chord.clone()?, binding.action.clone())))
+            .collect();
+
+        subscription::filter_map((Listen, bindings.clone()), move |event| {
+            let subscription::Event::Interaction {
+                event:
+                    core::Event::Keyboard(keyboard::Event::KeyPressed {
+                        key,
+                        physical_key,
+                        modifiers,
+                        repeat: false,
+                        ..
+                    }),
+                status,
+                ..
+            } = event
+            else {
+                return None;
+            };
+
+            let (chord, action) = bindings
+                .iter()
+                .find(|(chord, _)| chord.matches(&key, physical_key, modifiers))?;
+
+            (status == core::event::Status::Ignored || chord.is_global()).then(|| action.clone())
+        })
+    }
+}
+
+impl<A> Default for Keymap<A>
+where
+    A: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates an editor of the given [`Keymap`].
+///
+/// The editor lists every action with a [`Recorder`] of its [`Chord`] and
+/// a button to reset it to its default. Conflicting actions are flagged.
+///
+/// `on_rebind` is called with the new [`Chord`] of an action; which
+/// should be applied with [`Keymap::bind`].
+pub fn editor<'a, A, Message>(
+    keymap: &'a Keymap<A>,
+    on_rebind: impl Fn(A, Option<Chord>) -> Message + Clone + 'a,
+) -> Element<'a, Message>
+where
+    A: Clone + PartialEq + 'a,
+    Message: Clone + 'a,
+{
+    column(keymap.bindings().map(|binding| {
+        let conflicts = keymap.conflicts_with(&binding.action);
+
+        let warning = (!conflicts.is_empty()).then(|| {
+            let names: Vec<&str> = conflicts
+                .iter()
+                .filter_map(|action| keymap.binding(action))
+                .map(|binding| binding.name.as_str())
+                .collect();
+
+            text(format!("Conflicts with {}", names.join(", ")))
+                .size(12)
+                .style(text::danger)
+        });
+
+        let on_record = {
+            let on_rebind = on_rebind.clone();
+            let action = binding.action.clone();
+
+            move |chord| on_rebind(action.clone(), chord)
+        };
+
+        let reset = button(text("Reset").size(12))
+            .style(button::text)
+            .on_press_maybe(
+                (binding.chord != binding.default)
+                    .then(|| on_rebind(binding.action.clone(), binding.default.clone())),
+            );
+
+        row![
+            column![text(binding.name.as_str()), warning].width(Fill),
+            recorder(binding.chord.as_ref()).on_record(on_record),
+            reset,
+        ]
+        .spacing(10)
+        .align_y(crate::Center)
+        .into()
+    }))
+    .spacing(10)
+    .into()
+}
@@ -0,0 +1,242 @@
+use crate::keyboard::Modifiers;
+use crate::keyboard::key::{self, Key, Named};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A combination of keyboard modifiers and a key; like `Ctrl+Shift+S`.
+///
+/// A [`Chord`] can be parsed from and displayed as a string, which makes
+/// it easy to persist custom bindings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Chord {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+impl Chord {
+    /// Creates a new [`Chord`] with the given [`Modifiers`] and [`Key`].
+    ///
+    /// Characters are matched regardless of their case; use
+    /// [`Modifiers::SHIFT`] to require the shift key.
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        let key = match key {
+            Key::Character(c) => Key::Character(c.to_lowercase().into()),
+            key => key,
+        };
+
+        Self { modifiers, key }
+    }
+
+    /// Parses a [`Chord`] from a string; like `Ctrl+S` or `Alt+F4`.
+    ///
+    /// The `Cmd` modifier means `Ctrl` on every platform but macOS, where
+    /// it is the actual command key.
+    pub fn parse(chord: &str) -> Result<Self, Error> {
+        let mut modifiers = Modifiers::empty();
+        let mut parts: Vec<&str> = chord.split('+').map(str::trim).collect();
+
+        // A trailing `+` is the plus key itself; like `Ctrl++`
+        if chord.ends_with("++") {
+            let _ = parts.pop();
+            let _ = parts.pop();
+            parts.push("+");
+        }
+
+        let Some((key, modifier_names)) = parts.split_last() else {
+            return Err(Error::Empty);
+        };
+
+        for modifier in modifier_names {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CTRL,
+                "shift" => Modifiers::SHIFT,
+                "alt" | "option" => Modifiers::ALT,
+                "super" | "logo" | "meta" | "win" => Modifiers::LOGO,
+                "cmd" | "command" | "mod" => Modifiers::COMMAND,
+                _ => return Err(Error::UnknownModifier((*modifier).to_owned())),
+            };
+        }
+
+        let key = if key.is_empty() {
+            return Err(Error::Empty);
+        } else if let Some(named) = NAMED
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, named)| *named)
+        {
+            Key::Named(named)
+        } else if let Some(n) = key
+            .strip_prefix(['F', 'f'])
+            .and_then(|n| n.parse::<u8>().ok())
+            .filter(|n| (1..=12).contains(n))
+        {
+            Key::Named(FUNCTION[usize::from(n) - 1])
+        } else if key.chars().count() == 1 {
+            Key::Character((*key).into())
+        } else {
+            return Err(Error::UnknownKey((*key).to_owned()));
+        };
+
+        Ok(Self::new(modifiers, key))
+    }
+
+    /// Returns the [`Modifiers`] of the [`Chord`].
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Returns the [`Key`] of the [`Chord`].
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns true if the [`Chord`] is triggered by the given key press.
+    ///
+    /// Characters are also matched by their latin equivalent using the
+    /// [`key::Physical`] key, so shortcuts keep working with non-latin
+    /// keyboard layouts.
+    pub fn matches(&self, key: &Key, physical_key: key::Physical, modifiers: Modifiers) -> bool {
+        if self.modifiers != modifiers {
+            return false;
+        }
+
+        match (&self.key, key) {
+            (Key::Named(expected), Key::Named(named)) => expected == named,
+            (Key::Character(expected), Key::Character(c)) => {
+                c.to_lowercase() == expected.as_str()
+                    || key
+                        .to_latin(physical_key)
+                        .is_some_and(|latin| expected.chars().eq(latin.to_lowercase()))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if the [`Chord`] uses any modifier other than shift.
+    ///
+    /// These chords trigger even when a widget has captured the key press;
+    /// like a focused text input.
+    pub fn is_global(&self) -> bool {
+        self.modifiers.control() || self.modifiers.alt() || self.modifiers.logo()
+    }
+}
+
+impl FromStr for Chord {
+    type Err = Error;
+
+    fn from_str(chord: &str) -> Result<Self, Self::Err> {
+        Self::parse(chord)
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (
+                Modifiers::LOGO,
+                if cfg!(target_os = "macos") {
+                    "Cmd"
+                } else {
+                    "Super"
+                },
+            ),
+        ];
+
+        for (modifier, name) in modifiers {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match &self.key {
+            Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            Key::Named(named) => {
+                if let Some(n) = FUNCTION.iter().position(|function| function == named) {
+                    write!(f, "F{}", n + 1)
+                } else if let Some((name, _)) = NAMED.iter().find(|(_, key)| key == named) {
+                    write!(f, "{name}")
+                } else {
+                    write!(f, "{named:?}")
+                }
+            }
+            Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
+
+/// An error produced when parsing a [`Chord`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The chord has no key.
+    #[error("the chord has no key")]
+    Empty,
+    /// The chord contains an unknown modifier.
+    #[error("unknown modifier: {0}")]
+    UnknownModifier(String),
+    /// The chord contains an unknown key.
+    #[error("unknown key: {0}")]
+    UnknownKey(String),
+}
+
+const NAMED: &[(&str, Named)] = &[
+    ("Enter", Named::Enter),
+    ("Tab", Named::Tab),
+    ("Space", Named::Space),
+    ("Backspace", Named::Backspace),
+    ("Escape", Named::Escape),
+    ("Esc", Named::Escape),
+    ("Delete", Named::Delete),
+    ("Insert", Named::Insert),
+    ("Home", Named::Home),
+    ("End", Named::End),
+    ("PageUp", Named::PageUp),
+    ("PageDown", Named::PageDown),
+    ("Up", Named::ArrowUp),
+    ("Down", Named::ArrowDown),
+    ("Left", Named::ArrowLeft),
+    ("Right", Named::ArrowRight),
+];
+
+const FUNCTION: [Named; 12] = [
+    Named::F1,
+    Named::F2,
+    Named::F3,
+    Named::F4,
+    Named::F5,
+    Named::F6,
+    Named::F7,
+    Named::F8,
+    Named::F9,
+    Named::F10,
+    Named::F11,
+    Named::F12,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_and_displays_chords() {
+        let chord = Chord::parse("ctrl+shift+s").unwrap();
+
+        assert_eq!(chord.modifiers(), Modifiers::CTRL | Modifiers::SHIFT);
+        assert_eq!(chord.key(), &Key::Character("s".into()));
+        assert_eq!(chord.to_string(), "Ctrl+Shift+S");
+
+        assert_eq!(Chord::parse("Alt+F4").unwrap().to_string(), "Alt+F4");
+        assert_eq!(Chord::parse("Ctrl++").unwrap().to_string(), "Ctrl++");
+        assert_eq!(
+            Chord::parse("Hyper+A"),
+            Err(Error::UnknownModifier("Hyper".to_owned()))
+        );
+        assert_eq!(
+            Chord::parse("Ctrl+Foo"),
+            Err(Error::UnknownKey("Foo".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use crate::core::keyboard::{self, key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::text as text_widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Rectangle, Shell, Size,
+    Widget,
+};
+use crate::keymap::Chord;
+use crate::widget::button::{self, Catalog, Status, Style};
+
+/// A button that records the next [`Chord`] pressed after clicking it.
+///
+/// While recording, pressing `Escape` cancels the recording and pressing
+/// `Backspace` or `Delete` clears the [`Chord`].
+pub struct Recorder<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    chord: Option<Chord>,
+    on_record: Option<Box<dyn Fn(Option<Chord>) -> Message + 'a>>,
+    placeholder: String,
+    prompt: String,
+    width: Length,
+    padding: Padding,
+    size: Option<f32>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+impl<'a, Message, Theme, Renderer> Recorder<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Recorder`] displaying the given [`Chord`].
+    pub fn new(chord: Option<&Chord>) -> Self {
+        Self {
+            chord: chord.cloned(),
+            on_record: None,
+            placeholder: String::from("Unbound"),
+            prompt: String::from("Press a shortcut..."),
+            width: Length::Shrink,
+            padding: button::DEFAULT_PADDING,
+            size: None,
+            font: None,
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the message produced when a [`Chord`] is recorded or cleared.
+    ///
+    /// If this method is not called, the [`Recorder`] will be disabled.
+    pub fn on_record(mut self, on_record: impl Fn(Option<Chord>) -> Message + 'a) -> Self {
+        self.on_record = Some(Box::new(on_record));
+        self
+    }
+
+    /// Sets the text displayed when there is no [`Chord`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the text displayed while recording.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the width of the [`Recorder`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Recorder`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Recorder`].
+    pub fn size(mut self, size: impl Into<crate::Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`Recorder`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Recorder`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as button::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Recorder`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn label(&self, state: &State<Renderer::Paragraph>) -> String {
+        if state.is_recording {
+            self.prompt.clone()
+        } else {
+            self.chord
+                .as_ref()
+                .map_or_else(|| self.placeholder.clone(), Chord::to_string)
+        }
+    }
+}
+
+#[derive(Default)]
+struct State<P: text::Paragraph> {
+    is_recording: bool,
+    is_pressed: bool,
+    paragraph: text_widget::State<P>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Recorder<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let label = self.label(state);
+
+        layout::padded(limits, self.width, Length::Shrink, self.padding, |limits| {
+            text_widget::layout(
+                &mut state.paragraph,
+                renderer,
+                limits,
+                &label,
+                text_widget::Format {
+                    size: self.size.map(crate::Pixels),
+                    font: self.font,
+                    shaping: text::Shaping::Advanced,
+                    ..text_widget::Format::default()
+                },
+            )
+        })
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let Some(on_record) = &self.on_record else {
+            state.is_recording = false;
+            self.status = Some(Status::Disabled);
+            return;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if cursor.is_over(layout.bounds()) {
+                    state.is_pressed = true;
+                    shell.capture_event();
+                } else if state.is_recording {
+                    state.is_recording = false;
+                    shell.invalidate_layout();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.is_pressed => {
+                state.is_pressed = false;
+
+                if cursor.is_over(layout.bounds()) {
+                    state.is_recording = !state.is_recording;
+                    shell.invalidate_layout();
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) if state.is_recording => {
+                shell.capture_event();
+
+                let chord = match key {
+                    keyboard::Key::Named(
+                        key::Named::Shift
+                        | key::Named::Control
+                        | key::Named::Alt
+                        | key::Named::Super
+                        | key::Named::Meta,
+                    ) => return,
+                    keyboard::Key::Named(key::Named::Escape) if modifiers.is_empty() => None,
+                    keyboard::Key::Named(key::Named::Backspace | key::Named::Delete)
+                        if modifiers.is_empty() =>
+                    {
+                        Some(None)
+                    }
+                    keyboard::Key::Character(_) => {
+                        let key = key.to_latin(*physical_key).map_or_else(
+                            || key.clone(),
+                            |latin| keyboard::Key::Character(latin.to_string().into()),
+                        );
+
+                        Some(Some(Chord::new(*modifiers, key)))
+                    }
+                    _ => Some(Some(Chord::new(*modifiers, key.clone()))),
+                };
+
+                if let Some(chord) = chord {
+                    shell.publish(on_record(chord));
+                }
+
+                state.is_recording = false;
+                shell.invalidate_layout();
+            }
+            Event::Window(window::Event::Unfocused) if state.is_recording => {
+                state.is_recording = false;
+                shell.invalidate_layout();
+            }
+            _ => {}
+        }
+
+        let current_status = if state.is_recording || state.is_pressed {
+            Status::Pressed
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            self.status = Some(current_status);
+        } else if self.status.is_some_and(|status| status != current_status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class, self.status.unwrap_or(Status::Disabled));
+
+        if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    snap: style.snap,
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        text_widget::draw(
+            renderer,
+            &renderer::Style {
+                text_color: style.text_color,
+            },
+            layout.children().next().unwrap().bounds(),
+            state.paragraph.raw(),
+            text_widget::Style::default(),
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_record.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Recorder<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(recorder: Recorder<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(recorder)
+    }
+}
+
+/// Creates a new [`Recorder`] displaying the given [`Chord`].
+pub fn recorder<'a, Message, Theme, Renderer>(
+    chord: Option<&Chord>,
+) -> Recorder<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Recorder::new(chord)
+}
@@ -516,8 +516,38 @@ pub use iced_renderer::wgpu::wgpu;
 
 mod error;
 
+#[cfg(any(feature = "theme-reload", feature = "stylesheet", feature = "storage"))]
+mod format;
+
 pub mod application;
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod clipboard;
 pub mod daemon;
+#[cfg(feature = "dbus")]
+pub mod dbus;
+#[cfg(feature = "deep-link")]
+pub mod deep_link;
+#[cfg(feature = "hot-view")]
+pub mod hot_view;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+#[cfg(feature = "jobs")]
+pub mod jobs;
+#[cfg(feature = "keymap")]
+pub mod keymap;
+#[cfg(feature = "markup")]
+pub mod markup;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(feature = "pdf")]
+pub mod pdf;
+#[cfg(feature = "storage")]
+pub mod storage;
+#[cfg(feature = "stylesheet")]
+pub mod stylesheet;
+pub mod system;
+pub mod theme;
 pub mod time;
 pub mod window;
 
@@ -529,8 +559,8 @@ pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
+pub use crate::core::layout::Direction;
 pub use crate::core::padding;
-pub use crate::core::theme;
 pub use crate::core::{
     Alignment, Animation, Background, Border, Color, ContentFit, Degrees, Function, Gradient,
     Length, Never, Padding, Pixels, Point, Radians, Rectangle, Rotation, Settings, Shadow, Size,
@@ -538,7 +568,7 @@ pub use crate::core::{
 };
 pub use crate::program::Preset;
 pub use crate::program::message;
-pub use crate::runtime::exit;
+pub use crate::runtime::{exit, set_direction};
 pub use iced_futures::Subscription;
 
 pub use Alignment::Center;
@@ -559,9 +589,9 @@ pub mod task {
     pub use crate::runtime::task::{Never, Sipper, Straw, sipper, stream};
 }
 
-pub mod clipboard {
-    //! Access the clipboard.
-    pub use crate::runtime::clipboard::{read, read_primary, write, write_primary};
+pub mod undo {
+    //! Undo and redo the changes of an application.
+    pub use crate::runtime::undo::{UndoStack, Undoable};
 }
 
 pub mod executor {
@@ -594,14 +624,6 @@ pub mod mouse {
     pub use crate::core::mouse::{Button, Cursor, Event, Interaction, ScrollDelta};
 }
 
-pub mod system {
-    //! Retrieve system information.
-    pub use crate::runtime::system::{theme, theme_changes};
-
-    #[cfg(feature = "sysinfo")]
-    pub use crate::runtime::system::{Information, information};
-}
-
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
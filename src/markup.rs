@@ -0,0 +1,753 @@
+//! Describe screens with markup files loaded at runtime.
+//!
+//! A [`Markup`] is a widget tree written in [RON](https://github.com/ron-rs/ron)
+//! that binds to the fields of the state of an application and produces its
+//! messages by name; so screens can be authored and tweaked without writing
+//! or compiling any Rust:
+//!
+//! ```ron
+//! Column(
+//!     spacing: 10,
+//!     padding: 20,
+//!     children: [
+//!         Text(content: "Welcome back, {user.name}!", size: 24),
+//!         TextInput(placeholder: "Search", value: "query", on_input: "search"),
+//!         Each(items: "games", item: "game", content: Row(
+//!             spacing: 10,
+//!             children: [
+//!                 Text(content: "{game.title}"),
+//!                 Button(content: Text(content: "Play"), on_press: "play({game.id})"),
+//!             ],
+//!         )),
+//!         Toggler(label: "Fullscreen", value: "settings.fullscreen", on_toggle: "fullscreen"),
+//!     ],
+//! )
+//! ```
+//!
+//! Text can interpolate the fields of the state between braces; while
+//! values of inputs are bound to the field at the given path directly.
+//! Paths are separated by dots and can index lists by position.
+//!
+//! Messages are named and registered in a [`Messages`] map with their
+//! constructors. An action like `play({game.id})` produces the message
+//! named `play` with the interpolated text between parentheses.
+//!
+//! # Example
+//! ```no_run
+//! use iced::markup::{self, Markup, Messages};
+//! use iced::widget::text;
+//! use iced::{Element, Subscription};
+//!
+//! #[derive(serde::Serialize)]
+//! struct State {
+//!     #[serde(skip)]
+//!     screen: Option<Markup>,
+//!     query: String,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ScreenLoaded(Result<Markup, markup::Error>),
+//!     Search(String),
+//!     Play(String),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let Some(screen) = &state.screen else {
+//!         return text("Loading...").into();
+//!     };
+//!
+//!     screen.view(
+//!         state,
+//!         Messages::new()
+//!             .on_text("search", Message::Search)
+//!             .on_text("play", Message::Play),
+//!     )
+//! }
+//!
+//! fn subscription(_state: &State) -> Subscription<Message> {
+//!     markup::watch("screens/home.ron").map(Message::ScreenLoaded)
+//! }
+//! ```
+use crate::Element;
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::widget::{
+    button, checkbox, column, container, row, scrollable, slider, space, text, text_input, toggler,
+};
+use crate::{Alignment, Subscription};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A widget tree described with markup.
+///
+/// Cloning a [`Markup`] is cheap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Markup {
+    root: Arc<Node>,
+}
+
+impl Markup {
+    /// Parses a [`Markup`] from the given RON string.
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let root = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(source)
+            .map_err(|error| Error::Parse(error.to_string()))?;
+
+        Ok(Self {
+            root: Arc::new(root),
+        })
+    }
+
+    /// Loads a [`Markup`] from the given RON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Produces the widgets of the [`Markup`] for the given state; using the
+    /// given [`Messages`] to produce the messages of its actions.
+    ///
+    /// Fields of the state that cannot be found are treated as empty.
+    pub fn view<'a, State, Message>(
+        &self,
+        state: &State,
+        messages: Messages<'a, Message>,
+    ) -> Element<'a, Message>
+    where
+        State: Serialize,
+        Message: Clone + 'a,
+    {
+        let state = serde_json::to_value(state).unwrap_or(Value::Null);
+
+        let scope = Scope {
+            state: &state,
+            items: Vec::new(),
+        };
+
+        self.root.view(&scope, &messages)
+    }
+}
+
+/// Returns a [`Subscription`] that loads a [`Markup`] from the given RON
+/// file and reloads it every time the file changes.
+///
+/// The [`Markup`] is produced once when the [`Subscription`] starts.
+pub fn watch(path: impl Into<PathBuf>) -> Subscription<Result<Markup, Error>> {
+    Subscription::run_with(path.into(), |path| {
+        let path = path.clone();
+
+        stream::channel(1, async move |mut output| {
+            let _ = output.send(Markup::load(&path)).await;
+
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            let watcher = notify::recommended_watcher(move |event| {
+                let _ = sender.unbounded_send(event);
+            });
+
+            let mut watcher = match watcher {
+                Ok(watcher) => watcher,
+                Err(error) => {
+                    let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                    return;
+                }
+            };
+
+            // Editors usually replace files on save; so we watch the
+            // parent directory instead of the file itself.
+            let directory = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+
+            if let Err(error) =
+                notify::Watcher::watch(&mut watcher, directory, notify::RecursiveMode::NonRecursive)
+            {
+                let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                return;
+            }
+
+            while let Some(event) = receiver.next().await {
+                let changed = match event {
+                    Ok(event) => {
+                        (event.kind.is_create() || event.kind.is_modify())
+                            && event
+                                .paths
+                                .iter()
+                                .any(|changed| changed.file_name() == path.file_name())
+                    }
+                    Err(error) => {
+                        let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                        continue;
+                    }
+                };
+
+                if changed {
+                    let _ = output.send(Markup::load(&path)).await;
+                }
+            }
+        })
+    })
+}
+
+/// The named message constructors of the actions of a [`Markup`].
+pub struct Messages<'a, Message> {
+    constructors: HashMap<String, Constructor<'a, Message>>,
+}
+
+enum Constructor<'a, Message> {
+    Unit(Message),
+    Text(Rc<dyn Fn(String) -> Message + 'a>),
+    Bool(Rc<dyn Fn(bool) -> Message + 'a>),
+    Number(Rc<dyn Fn(f32) -> Message + 'a>),
+}
+
+impl<'a, Message> Messages<'a, Message> {
+    /// Creates an empty set of [`Messages`].
+    pub fn new() -> Self {
+        Self {
+            constructors: HashMap::new(),
+        }
+    }
+
+    /// Registers a message without arguments; like the `on_press` action of
+    /// a button.
+    pub fn on(mut self, name: impl Into<String>, message: Message) -> Self {
+        let _ = self
+            .constructors
+            .insert(name.into(), Constructor::Unit(message));
+
+        self
+    }
+
+    /// Registers a message constructor that takes some text; like the
+    /// `on_input` action of a text input or actions with arguments.
+    pub fn on_text(mut self, name: impl Into<String>, f: impl Fn(String) -> Message + 'a) -> Self {
+        let _ = self
+            .constructors
+            .insert(name.into(), Constructor::Text(Rc::new(f)));
+
+        self
+    }
+
+    /// Registers a message constructor that takes a boolean; like the
+    /// `on_toggle` action of a checkbox.
+    pub fn on_bool(mut self, name: impl Into<String>, f: impl Fn(bool) -> Message + 'a) -> Self {
+        let _ = self
+            .constructors
+            .insert(name.into(), Constructor::Bool(Rc::new(f)));
+
+        self
+    }
+
+    /// Registers a message constructor that takes a number; like the
+    /// `on_change` action of a slider.
+    pub fn on_number(mut self, name: impl Into<String>, f: impl Fn(f32) -> Message + 'a) -> Self {
+        let _ = self
+            .constructors
+            .insert(name.into(), Constructor::Number(Rc::new(f)));
+
+        self
+    }
+
+    fn text(&self, name: &str) -> Option<Rc<dyn Fn(String) -> Message + 'a>> {
+        match self.constructors.get(name)? {
+            Constructor::Text(f) => Some(Rc::clone(f)),
+            _ => None,
+        }
+    }
+
+    fn bool(&self, name: &str) -> Option<Rc<dyn Fn(bool) -> Message + 'a>> {
+        match self.constructors.get(name)? {
+            Constructor::Bool(f) => Some(Rc::clone(f)),
+            _ => None,
+        }
+    }
+
+    fn number(&self, name: &str) -> Option<Rc<dyn Fn(f32) -> Message + 'a>> {
+        match self.constructors.get(name)? {
+            Constructor::Number(f) => Some(Rc::clone(f)),
+            _ => None,
+        }
+    }
+}
+
+impl<Message> Messages<'_, Message>
+where
+    Message: Clone,
+{
+    fn action(&self, action: &str) -> Option<Message> {
+        let (name, argument) = match action.strip_suffix(')') {
+            Some(call) => {
+                let (name, argument) = call.split_once('(')?;
+                (name, Some(argument))
+            }
+            None => (action, None),
+        };
+
+        match (self.constructors.get(name.trim())?, argument) {
+            (Constructor::Unit(message), None) => Some(message.clone()),
+            (Constructor::Text(f), argument) => Some(f(argument.unwrap_or_default().to_owned())),
+            _ => None,
+        }
+    }
+}
+
+impl<Message> Default for Messages<'_, Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An error loading a [`Markup`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The markup file could not be read.
+    #[error("the markup file could not be read: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The markup is invalid.
+    #[error("the markup is invalid: {0}")]
+    Parse(String),
+
+    /// The markup file could not be watched for changes.
+    #[error("the markup file could not be watched: {0}")]
+    Watch(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+enum Node {
+    Column {
+        #[serde(default)]
+        spacing: f32,
+        #[serde(default)]
+        padding: f32,
+        #[serde(default)]
+        align: Align,
+        #[serde(default)]
+        width: Length,
+        #[serde(default)]
+        height: Length,
+        children: Vec<Node>,
+    },
+    Row {
+        #[serde(default)]
+        spacing: f32,
+        #[serde(default)]
+        padding: f32,
+        #[serde(default)]
+        align: Align,
+        #[serde(default)]
+        width: Length,
+        #[serde(default)]
+        height: Length,
+        children: Vec<Node>,
+    },
+    Container {
+        #[serde(default)]
+        padding: f32,
+        #[serde(default)]
+        center: bool,
+        #[serde(default)]
+        width: Length,
+        #[serde(default)]
+        height: Length,
+        content: Box<Node>,
+    },
+    Scrollable {
+        #[serde(default = "Length::fill")]
+        width: Length,
+        #[serde(default = "Length::fill")]
+        height: Length,
+        content: Box<Node>,
+    },
+    Each {
+        items: String,
+        item: String,
+        #[serde(default)]
+        spacing: f32,
+        content: Box<Node>,
+    },
+    Text {
+        content: String,
+        size: Option<f32>,
+    },
+    Button {
+        content: Box<Node>,
+        on_press: Option<String>,
+        #[serde(default)]
+        width: Length,
+    },
+    TextInput {
+        #[serde(default)]
+        placeholder: String,
+        value: String,
+        on_input: Option<String>,
+        on_submit: Option<String>,
+    },
+    Checkbox {
+        #[serde(default)]
+        label: String,
+        value: String,
+        on_toggle: Option<String>,
+    },
+    Toggler {
+        #[serde(default)]
+        label: String,
+        value: String,
+        on_toggle: Option<String>,
+    },
+    Slider {
+        range: (f32, f32),
+        #[serde(default = "default_step")]
+        step: f32,
+        value: String,
+        on_change: Option<String>,
+    },
+    Space {
+        #[serde(default)]
+        width: Length,
+        #[serde(default)]
+        height: Length,
+    },
+}
+
+impl Node {
+    fn view<'a, Message>(
+        &self,
+        scope: &Scope<'_>,
+        messages: &Messages<'a, Message>,
+    ) -> Element<'a, Message>
+    where
+        Message: Clone + 'a,
+    {
+        match self {
+            Node::Column {
+                spacing,
+                padding,
+                align,
+                width,
+                height,
+                children,
+            } => column(children.iter().map(|child| child.view(scope, messages)))
+                .spacing(*spacing)
+                .padding(*padding)
+                .align_x(Alignment::from(*align))
+                .width(*width)
+                .height(*height)
+                .into(),
+            Node::Row {
+                spacing,
+                padding,
+                align,
+                width,
+                height,
+                children,
+            } => row(children.iter().map(|child| child.view(scope, messages)))
+                .spacing(*spacing)
+                .padding(*padding)
+                .align_y(Alignment::from(*align))
+                .width(*width)
+                .height(*height)
+                .into(),
+            Node::Container {
+                padding,
+                center,
+                width,
+                height,
+                content,
+            } => {
+                let container = container(content.view(scope, messages))
+                    .padding(*padding)
+                    .width(*width)
+                    .height(*height);
+
+                if *center {
+                    container.center_x(*width).center_y(*height).into()
+                } else {
+                    container.into()
+                }
+            }
+            Node::Scrollable {
+                width,
+                height,
+                content,
+            } => scrollable(content.view(scope, messages))
+                .width(*width)
+                .height(*height)
+                .into(),
+            Node::Each {
+                items,
+                item,
+                spacing,
+                content,
+            } => {
+                let items = match scope.get(items) {
+                    Some(Value::Array(items)) => items.as_slice(),
+                    _ => &[],
+                };
+
+                column(items.iter().map(|value| {
+                    let mut scope = scope.clone();
+                    scope.items.push((item, value));
+
+                    content.view(&scope, messages)
+                }))
+                .spacing(*spacing)
+                .into()
+            }
+            Node::Text { content, size } => {
+                let text = text(scope.interpolate(content));
+
+                match size {
+                    Some(size) => text.size(*size).into(),
+                    None => text.into(),
+                }
+            }
+            Node::Button {
+                content,
+                on_press,
+                width,
+            } => button(content.view(scope, messages))
+                .on_press_maybe(
+                    on_press
+                        .as_ref()
+                        .and_then(|action| messages.action(&scope.interpolate(action))),
+                )
+                .width(*width)
+                .into(),
+            Node::TextInput {
+                placeholder,
+                value,
+                on_input,
+                on_submit,
+            } => {
+                let input = text_input(&scope.interpolate(placeholder), &scope.text(value))
+                    .on_submit_maybe(
+                        on_submit
+                            .as_ref()
+                            .and_then(|action| messages.action(&scope.interpolate(action))),
+                    );
+
+                match on_input.as_deref().and_then(|name| messages.text(name)) {
+                    Some(on_input) => input.on_input(move |text| on_input(text)).into(),
+                    None => input.into(),
+                }
+            }
+            Node::Checkbox {
+                label,
+                value,
+                on_toggle,
+            } => {
+                let checkbox = checkbox(scope.bool(value)).label(scope.interpolate(label));
+
+                match on_toggle.as_deref().and_then(|name| messages.bool(name)) {
+                    Some(on_toggle) => checkbox.on_toggle(move |value| on_toggle(value)).into(),
+                    None => checkbox.into(),
+                }
+            }
+            Node::Toggler {
+                label,
+                value,
+                on_toggle,
+            } => {
+                let toggler = toggler(scope.bool(value)).label(scope.interpolate(label));
+
+                match on_toggle.as_deref().and_then(|name| messages.bool(name)) {
+                    Some(on_toggle) => toggler.on_toggle(move |value| on_toggle(value)).into(),
+                    None => toggler.into(),
+                }
+            }
+            Node::Slider {
+                range,
+                step,
+                value,
+                on_change,
+            } => {
+                let (start, end) = *range;
+                let value = scope.number(value).clamp(start, end);
+
+                match on_change.as_deref().and_then(|name| messages.number(name)) {
+                    Some(on_change) => slider(start..=end, value, move |value| on_change(value))
+                        .step(*step)
+                        .into(),
+                    None => text(value).into(),
+                }
+            }
+            Node::Space { width, height } => space().width(*width).height(*height).into(),
+        }
+    }
+}
+
+fn default_step() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl From<Align> for Alignment {
+    fn from(align: Align) -> Self {
+        match align {
+            Align::Start => Alignment::Start,
+            Align::Center => Alignment::Center,
+            Align::End => Alignment::End,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+enum Length {
+    Fill,
+    FillPortion(u16),
+    #[default]
+    Shrink,
+    Fixed(f32),
+}
+
+impl Length {
+    fn fill() -> Self {
+        Self::Fill
+    }
+}
+
+impl From<Length> for crate::Length {
+    fn from(length: Length) -> Self {
+        match length {
+            Length::Fill => Self::Fill,
+            Length::FillPortion(portion) => Self::FillPortion(portion),
+            Length::Shrink => Self::Shrink,
+            Length::Fixed(size) => Self::Fixed(size),
+        }
+    }
+}
+
+/// The values a [`Markup`] can bind to.
+#[derive(Clone)]
+struct Scope<'a> {
+    state: &'a Value,
+    items: Vec<(&'a str, &'a Value)>,
+}
+
+impl Scope<'_> {
+    fn get(&self, path: &str) -> Option<&Value> {
+        let mut keys = path.trim().split('.');
+        let first = keys.next()?;
+
+        let root = self
+            .items
+            .iter()
+            .rev()
+            .find_map(|(name, value)| (*name == first).then_some(*value));
+
+        let (mut value, keys) = match root {
+            Some(value) => (value, keys),
+            None => (self.state, path.trim().split('.')),
+        };
+
+        for key in keys {
+            value = match value {
+                Value::Object(fields) => fields.get(key)?,
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        Some(value)
+    }
+
+    fn text(&self, path: &str) -> String {
+        match self.get(path) {
+            Some(Value::String(text)) => text.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(value) => value.to_string(),
+        }
+    }
+
+    fn bool(&self, path: &str) -> bool {
+        self.get(path).and_then(Value::as_bool).unwrap_or_default()
+    }
+
+    fn number(&self, path: &str) -> f32 {
+        self.get(path).and_then(Value::as_f64).unwrap_or_default() as f32
+    }
+
+    fn interpolate(&self, template: &str) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+
+            output.push_str(&rest[..start]);
+            let _ = write!(output, "{}", self.text(&rest[start + 1..start + end]));
+
+            rest = &rest[start + end + 1..];
+        }
+
+        output.push_str(rest);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_interpolates_bindings() {
+        let state = serde_json::json!({
+            "user": { "name": "Ferris" },
+            "games": [{ "title": "Crab Rave" }],
+        });
+
+        let mut scope = Scope {
+            state: &state,
+            items: Vec::new(),
+        };
+
+        assert_eq!(scope.interpolate("Hi, {user.name}!"), "Hi, Ferris!");
+        assert_eq!(scope.interpolate("{games.0.title}"), "Crab Rave");
+        assert_eq!(scope.interpolate("{missing}"), "");
+
+        let games = &state["games"][0];
+        scope.items.push(("game", games));
+
+        assert_eq!(scope.interpolate("Play {game.title}"), "Play Crab Rave");
+    }
+
+    #[test]
+    fn it_parses_markup() {
+        let markup = Markup::parse(
+            r#"Column(spacing: 10, children: [
+                Text(content: "Hello"),
+                Button(content: Text(content: "Play"), on_press: "play"),
+            ])"#,
+        );
+
+        assert!(markup.is_ok(), "{markup:?}");
+    }
+}
@@ -0,0 +1,487 @@
+//! Fetch resources over HTTP with tasks.
+//!
+//! A [`Request`] can be turned into a [`Task`] that produces a [`Response`],
+//! its body as text or bytes, or some JSON parsed into any deserializable
+//! type. Large downloads can report their [`Progress`] with
+//! [`Request::download`].
+//!
+//! Responses of `GET` requests can be stored in a disk [`Cache`]; so they
+//! are not fetched again until they expire.
+//!
+//! Enable the `websocket` feature to connect to WebSocket servers with
+//! [`websocket()`].
+//!
+//! # Example
+//! ```no_run
+//! use iced::Task;
+//! use iced::net;
+//!
+//! #[derive(Debug, Clone, serde::Deserialize)]
+//! struct Release {
+//!     tag_name: String,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Refresh,
+//!     Fetched(Result<Release, net::Error>),
+//! }
+//!
+//! fn update(message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Refresh => {
+//!             net::fetch("https://api.github.com/repos/iced-rs/iced/releases/latest")
+//!                 .header("User-Agent", "iced")
+//!                 .parse()
+//!                 .map(Message::Fetched)
+//!         }
+//!         Message::Fetched(release) => {
+//!             // Display the release...
+//!             Task::none()
+//!         }
+//!     }
+//! }
+//! ```
+use crate::Task;
+use crate::core::Bytes;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+#[cfg(feature = "websocket")]
+pub use websocket::websocket;
+
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+
+use iced_futures::MaybeSend;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime};
+
+/// Creates a new `GET` [`Request`] to the given URL.
+pub fn fetch(url: impl Into<String>) -> Request {
+    Request::get(url)
+}
+
+/// Creates a new `POST` [`Request`] to the given URL.
+pub fn post(url: impl Into<String>) -> Request {
+    Request::post(url)
+}
+
+/// The method of a [`Request`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// A `GET` request.
+    Get,
+    /// A `POST` request.
+    Post,
+}
+
+/// An HTTP request.
+#[derive(Debug, Clone)]
+pub struct Request {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Result<Bytes, Error>,
+    timeout: Option<Duration>,
+    cache: Option<Cache>,
+}
+
+impl Request {
+    /// Creates a new `GET` [`Request`] to the given URL.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::Get, url)
+    }
+
+    /// Creates a new `POST` [`Request`] to the given URL.
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::Post, url)
+    }
+
+    /// Creates a new [`Request`] with the given [`Method`] and URL.
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: Ok(Bytes::new()),
+            timeout: None,
+            cache: None,
+        }
+    }
+
+    /// Adds a header to the [`Request`].
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of the [`Request`].
+    pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = Ok(body.into());
+        self
+    }
+
+    /// Sets the body of the [`Request`] to the given value serialized as
+    /// JSON.
+    pub fn json(mut self, value: &impl Serialize) -> Self {
+        self.body = serde_json::to_vec(value)
+            .map(Bytes::from)
+            .map_err(|error| Error::Serialize(error.to_string()));
+
+        self.header("Content-Type", "application/json")
+    }
+
+    /// Sets the maximum duration of the [`Request`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Stores the responses of the [`Request`] in the given [`Cache`].
+    ///
+    /// Only successful `GET` requests are cached.
+    pub fn cache(mut self, cache: &Cache) -> Self {
+        self.cache = Some(cache.clone());
+        self
+    }
+
+    /// Sends the [`Request`]; producing its [`Response`].
+    ///
+    /// Responses with an unsuccessful status fail with [`Error::Status`].
+    pub fn send(self) -> Task<Result<Response, Error>> {
+        Task::future(self.execute(None))
+    }
+
+    /// Sends the [`Request`]; producing the body of its [`Response`] as
+    /// bytes.
+    pub fn bytes(self) -> Task<Result<Bytes, Error>> {
+        self.send()
+            .map(|response| response.map(Response::into_body))
+    }
+
+    /// Sends the [`Request`]; producing the body of its [`Response`] as
+    /// text.
+    pub fn text(self) -> Task<Result<String, Error>> {
+        self.send()
+            .map(|response| response.and_then(|response| response.text()))
+    }
+
+    /// Sends the [`Request`]; producing the body of its [`Response`]
+    /// parsed as JSON.
+    pub fn parse<T>(self) -> Task<Result<T, Error>>
+    where
+        T: DeserializeOwned + MaybeSend + 'static,
+    {
+        self.send()
+            .map(|response| response.and_then(|response| response.json()))
+    }
+
+    /// Sends the [`Request`]; producing a [`Download`] event every time a
+    /// chunk of its body is received, and once it finishes.
+    pub fn download(self) -> Task<Download> {
+        Task::stream(stream::channel(10, async move |mut sender| {
+            let result = self.execute(Some(sender.clone())).await;
+
+            let _ = sender.send(Download::Finished(result)).await;
+        }))
+    }
+
+    async fn execute(self, progress: Option<mpsc::Sender<Download>>) -> Result<Response, Error> {
+        run(self.fetch(progress)).await
+    }
+
+    async fn fetch(self, mut progress: Option<mpsc::Sender<Download>>) -> Result<Response, Error> {
+        let cache = self.cache.filter(|_| self.method == Method::Get);
+        let key = cache.as_ref().map(|_| key(&self.url, &self.headers));
+
+        if let Some((cache, key)) = cache.as_ref().zip(key)
+            && let Some(body) = cache.read(key)
+        {
+            return Ok(Response {
+                status: 200,
+                body,
+                is_cached: true,
+            });
+        }
+
+        let method = match self.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+        };
+
+        let mut request = client().request(method, &self.url).body(self.body?);
+
+        for (name, value) in self.headers {
+            request = request.header(name, value);
+        }
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(Error::Status(status.as_u16()));
+        }
+
+        let total = response.content_length();
+        let mut chunks = response.bytes_stream();
+        let mut body = Vec::with_capacity(total.unwrap_or_default() as usize);
+
+        while let Some(chunk) = chunks.next().await {
+            body.extend_from_slice(&chunk?);
+
+            if let Some(progress) = &mut progress {
+                let _ = progress
+                    .send(Download::Progress(Progress {
+                        downloaded: body.len() as u64,
+                        total,
+                    }))
+                    .await;
+            }
+        }
+
+        let body = Bytes::from(body);
+
+        if let Some((cache, key)) = cache.as_ref().zip(key) {
+            cache.write(key, &body)?;
+        }
+
+        Ok(Response {
+            status: status.as_u16(),
+            body,
+            is_cached: false,
+        })
+    }
+}
+
+/// A successful HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    status: u16,
+    body: Bytes,
+    is_cached: bool,
+}
+
+impl Response {
+    /// Returns the status code of the [`Response`].
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns the body of the [`Response`].
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Returns the body of the [`Response`], consuming it.
+    pub fn into_body(self) -> Bytes {
+        self.body
+    }
+
+    /// Returns the body of the [`Response`] as text.
+    pub fn text(&self) -> Result<String, Error> {
+        String::from_utf8(self.body.to_vec()).map_err(|error| Error::Decode(error.to_string()))
+    }
+
+    /// Returns the body of the [`Response`] parsed as JSON.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_slice(&self.body).map_err(|error| Error::Decode(error.to_string()))
+    }
+
+    /// Returns true if the [`Response`] was read from a [`Cache`].
+    pub fn is_cached(&self) -> bool {
+        self.is_cached
+    }
+}
+
+/// An event of a [`Request::download`].
+#[derive(Debug, Clone)]
+pub enum Download {
+    /// A chunk of the body was received.
+    Progress(Progress),
+    /// The download finished.
+    Finished(Result<Response, Error>),
+}
+
+/// The progress of a download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// The amount of bytes received so far.
+    pub downloaded: u64,
+    /// The total amount of bytes of the body, if known.
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    /// Returns the percentage of the body received, from `0.0` to `100.0`;
+    /// if the total size is known.
+    pub fn percent(&self) -> Option<f32> {
+        self.total
+            .filter(|total| *total > 0)
+            .map(|total| 100.0 * self.downloaded as f32 / total as f32)
+    }
+}
+
+/// A disk cache of HTTP responses.
+///
+/// A [`Cache`] can be cheaply cloned; every clone shares the same
+/// directory.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    directory: Arc<PathBuf>,
+    max_age: Duration,
+}
+
+impl Cache {
+    /// Creates a new [`Cache`] storing responses in the given directory
+    /// for an hour.
+    ///
+    /// The directory is created on the first write, if needed.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: Arc::new(directory.into()),
+            max_age: Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Sets the duration that cached responses are considered fresh.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Removes every response stored in the [`Cache`].
+    pub fn clear(&self) -> Result<(), Error> {
+        match std::fs::remove_dir_all(self.directory.as_path()) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn read(&self, key: u64) -> Option<Bytes> {
+        let path = self.directory.join(format!("{key:016x}"));
+
+        let age = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+
+        if age > self.max_age {
+            return None;
+        }
+
+        std::fs::read(path).ok().map(Bytes::from)
+    }
+
+    fn write(&self, key: u64, body: &[u8]) -> Result<(), Error> {
+        std::fs::create_dir_all(self.directory.as_path())?;
+        std::fs::write(self.directory.join(format!("{key:016x}")), body)?;
+
+        Ok(())
+    }
+}
+
+/// An error fetching an HTTP resource.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The request could not be sent or its response could not be
+    /// received.
+    #[error("the request failed: {0}")]
+    Request(Arc<reqwest::Error>),
+
+    /// The response has an unsuccessful status code.
+    #[error("the server responded with status {0}")]
+    Status(u16),
+
+    /// The body of the request could not be serialized.
+    #[error("the request body could not be serialized: {0}")]
+    Serialize(String),
+
+    /// The body of the response could not be decoded.
+    #[error("the response body could not be decoded: {0}")]
+    Decode(String),
+
+    /// The [`Cache`] could not be accessed.
+    #[error("the cache could not be accessed: {0}")]
+    Cache(Arc<io::Error>),
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Request(Arc::new(error))
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Cache(Arc::new(error))
+    }
+}
+
+/// Runs the given future with a `tokio` reactor, which `reqwest` and
+/// `async-tungstenite` need.
+///
+/// The future runs in a shared runtime, unless the executor already
+/// provides one; and it is aborted when dropped.
+async fn run<T>(future: impl Future<Output = T> + MaybeSend + 'static) -> T
+where
+    T: MaybeSend + 'static,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    if tokio::runtime::Handle::try_current().is_err() {
+        static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+        let runtime = RUNTIME.get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("iced_net")
+                .enable_all()
+                .build()
+                .expect("Create network runtime")
+        });
+
+        struct Abort(tokio::task::AbortHandle);
+
+        impl Drop for Abort {
+            fn drop(&mut self) {
+                self.0.abort();
+            }
+        }
+
+        let task = runtime.spawn(future);
+        let _abort = Abort(task.abort_handle());
+
+        return task
+            .await
+            .unwrap_or_else(|error| std::panic::resume_unwind(error.into_panic()));
+    }
+
+    future.await
+}
+
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
+fn key(url: &str, headers: &[(String, String)]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    url.hash(&mut hasher);
+    headers.hash(&mut hasher);
+
+    hasher.finish()
+}
@@ -0,0 +1,232 @@
+//! Connect to WebSocket servers with subscriptions.
+//!
+//! A [`websocket`] subscription connects to a server and produces an
+//! [`Event`] every time the connection changes or a message is received.
+//! Messages are exchanged as JSON text frames and typed on both ends.
+//!
+//! Lost connections are retried automatically with an exponential backoff.
+//!
+//! # Example
+//! ```no_run
+//! use iced::net::websocket::{self, Connection};
+//! use iced::Subscription;
+//!
+//! #[derive(Debug, Clone, serde::Deserialize)]
+//! enum Incoming {
+//!     Chat { user: String, text: String },
+//! }
+//!
+//! #[derive(Debug, Clone, serde::Serialize)]
+//! enum Outgoing {
+//!     Chat { text: String },
+//! }
+//!
+//! struct State {
+//!     connection: Option<Connection<Outgoing>>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Send(String),
+//!     Server(websocket::Event<Incoming, Outgoing>),
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Send(text) => {
+//!             if let Some(connection) = &mut state.connection {
+//!                 let _ = connection.send(&Outgoing::Chat { text });
+//!             }
+//!         }
+//!         Message::Server(websocket::Event::Connected(connection)) => {
+//!             state.connection = Some(connection);
+//!         }
+//!         Message::Server(websocket::Event::Disconnected { .. }) => {
+//!             state.connection = None;
+//!         }
+//!         Message::Server(websocket::Event::Received(message)) => {
+//!             // Display the message...
+//!         }
+//!         Message::Server(websocket::Event::Error(_)) => {}
+//!     }
+//! }
+//!
+//! fn subscription(_state: &State) -> Subscription<Message> {
+//!     websocket::websocket("wss://chat.example.com").map(Message::Server)
+//! }
+//! ```
+use crate::Subscription;
+use crate::futures::channel::mpsc;
+use crate::futures::{self, SinkExt, StreamExt};
+use crate::stream;
+
+use async_tungstenite::tungstenite;
+use iced_futures::MaybeSend;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// The delay before the first reconnection attempt.
+const MIN_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The maximum delay between reconnection attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Connects to the WebSocket server at the given URL; receiving messages
+/// of type `I` and sending messages of type `O`.
+///
+/// The connection is kept alive while the [`Subscription`] is active and
+/// retried automatically when lost.
+pub fn websocket<I, O>(url: impl Into<String>) -> Subscription<Event<I, O>>
+where
+    I: DeserializeOwned + MaybeSend + 'static,
+    O: Serialize + 'static,
+{
+    Subscription::run_with(url.into(), |url: &String| connect::<I, O>(url))
+}
+
+/// An event of a [`websocket`] subscription.
+#[derive(Debug, Clone)]
+pub enum Event<I, O> {
+    /// The connection was established.
+    Connected(Connection<O>),
+    /// The connection was lost and will be retried after the given delay.
+    Disconnected {
+        /// The delay before the next reconnection attempt.
+        retry_in: Duration,
+    },
+    /// A message was received.
+    Received(I),
+    /// A message could not be decoded.
+    Error(Error),
+}
+
+/// A handle to send messages to a connected WebSocket server.
+pub struct Connection<O> {
+    sender: mpsc::Sender<tungstenite::Message>,
+    _message: PhantomData<fn(&O)>,
+}
+
+impl<O: Serialize> Connection<O> {
+    /// Sends a message to the server, serialized as JSON.
+    pub fn send(&mut self, message: &O) -> Result<(), Error> {
+        let text =
+            serde_json::to_string(message).map_err(|error| Error::Serialize(error.to_string()))?;
+
+        self.sender
+            .try_send(tungstenite::Message::Text(text))
+            .map_err(|_| Error::Closed)
+    }
+
+    /// Sends raw text to the server.
+    pub fn send_text(&mut self, text: impl Into<String>) -> Result<(), Error> {
+        self.sender
+            .try_send(tungstenite::Message::Text(text.into()))
+            .map_err(|_| Error::Closed)
+    }
+}
+
+impl<O> Clone for Connection<O> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl<O> fmt::Debug for Connection<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection").finish_non_exhaustive()
+    }
+}
+
+/// An error of a WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// A message could not be serialized.
+    #[error("the message could not be serialized: {0}")]
+    Serialize(String),
+
+    /// A received message could not be decoded.
+    #[error("the received message could not be decoded: {0}")]
+    Decode(String),
+
+    /// The connection is closed or too busy to send more messages.
+    #[error("the connection is closed")]
+    Closed,
+}
+
+fn connect<I, O>(url: &str) -> impl futures::Stream<Item = Event<I, O>> + use<I, O>
+where
+    I: DeserializeOwned + MaybeSend + 'static,
+    O: 'static,
+{
+    let url = url.to_owned();
+
+    stream::channel(100, async move |output| {
+        super::run(maintain(url, output)).await;
+    })
+}
+
+async fn maintain<I, O>(url: String, mut output: mpsc::Sender<Event<I, O>>)
+where
+    I: DeserializeOwned + MaybeSend + 'static,
+    O: 'static,
+{
+    let mut backoff = MIN_BACKOFF;
+
+    loop {
+        let Ok((websocket, _)) = async_tungstenite::tokio::connect_async(&url).await else {
+            let _ = output.send(Event::Disconnected { retry_in: backoff }).await;
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+
+            continue;
+        };
+
+        backoff = MIN_BACKOFF;
+
+        let (sender, mut input) = mpsc::channel(100);
+        let mut websocket = websocket.fuse();
+
+        let _ = output
+            .send(Event::Connected(Connection {
+                sender,
+                _message: PhantomData,
+            }))
+            .await;
+
+        loop {
+            futures::select! {
+                received = websocket.next() => {
+                    let event = match received {
+                        Some(Ok(tungstenite::Message::Text(text))) => {
+                            match serde_json::from_str(&text) {
+                                Ok(message) => Event::Received(message),
+                                Err(error) => Event::Error(Error::Decode(error.to_string())),
+                            }
+                        }
+                        Some(Ok(tungstenite::Message::Close(_)) | Err(_)) | None => break,
+                        Some(Ok(_)) => continue,
+                    };
+
+                    let _ = output.send(event).await;
+                }
+                message = input.select_next_some() => {
+                    if websocket.send(message).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let _ = output.send(Event::Disconnected { retry_in: backoff }).await;
+
+        tokio::time::sleep(backoff).await;
+    }
+}
@@ -0,0 +1,138 @@
+//! Export element trees as paginated PDF documents.
+//!
+//! [`export`] renders an [`Element`] into a PDF document; so reports,
+//! receipts, and invoices can be produced with the same view logic that
+//! displays them on screen.
+//!
+//! Text, quads, and opaque gradients are written as vector graphics and
+//! stay sharp at any zoom level; while shadows, canvas geometry, and images
+//! are rasterized.
+//!
+//! # Example
+//! ```no_run
+//! use iced::pdf::{self, Page};
+//! use iced::widget::{column, text};
+//! use iced::{Element, Theme};
+//!
+//! struct Receipt {
+//!     items: Vec<(String, u32)>,
+//! }
+//!
+//! fn view(receipt: &Receipt) -> Element<'_, ()> {
+//!     column(receipt.items.iter().map(|(name, price)| {
+//!         text(format!("{name}: ${price}")).into()
+//!     }))
+//!     .spacing(10)
+//!     .into()
+//! }
+//!
+//! let receipt = Receipt {
+//!     items: vec![("Controller".to_owned(), 60)],
+//! };
+//!
+//! let document = pdf::export(view(&receipt), &Theme::Light, Page::A4);
+//!
+//! std::fs::write("receipt.pdf", document).expect("Write receipt");
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::theme::Base;
+use crate::core::widget::Tree;
+use crate::core::{Element, Layout, Padding, Point, Rectangle, Size};
+use crate::{Renderer, Settings, Theme};
+
+/// The size and the margin of the pages of a PDF document, in points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Page {
+    /// The size of the [`Page`].
+    pub size: Size,
+    /// The margin of the [`Page`].
+    pub margin: Padding,
+}
+
+impl Page {
+    /// The A4 paper size, with a margin of 2 centimeters.
+    pub const A4: Self = Self {
+        size: Size::new(595.0, 842.0),
+        margin: Padding::new(56.7),
+    };
+
+    /// The US Letter paper size, with a margin of 1 inch.
+    pub const LETTER: Self = Self {
+        size: Size::new(612.0, 792.0),
+        margin: Padding::new(72.0),
+    };
+
+    /// Creates a new [`Page`] of the given size, without a margin.
+    pub fn new(size: impl Into<Size>) -> Self {
+        Self {
+            size: size.into(),
+            margin: Padding::ZERO,
+        }
+    }
+
+    /// Sets the margin of the [`Page`].
+    pub fn margin(self, margin: impl Into<Padding>) -> Self {
+        Self {
+            margin: margin.into(),
+            ..self
+        }
+    }
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Self::A4
+    }
+}
+
+/// Exports an [`Element`] as a PDF document with the given [`Page`] format.
+///
+/// The [`Element`] is laid out with the width of the pages minus their
+/// margin and as much height as it needs; which is split across pages.
+/// Like the contents of a vertical scrollable, it cannot fill the
+/// vertical axis.
+pub fn export<'a, Message>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    theme: &Theme,
+    page: Page,
+) -> Vec<u8> {
+    let mut element = element.into();
+
+    let settings = Settings::default();
+    let mut renderer =
+        iced_renderer::pdf::renderer(settings.default_font, settings.default_text_size);
+
+    let width = (page.size.width - page.margin.horizontal()).max(0.0);
+    let limits = layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY));
+
+    let mut tree = Tree::new(&element);
+    let node = element
+        .as_widget_mut()
+        .layout(&mut tree, &renderer, &limits);
+
+    let height = node.size().height;
+    let style = theme.base();
+
+    element.as_widget().draw(
+        &tree,
+        &mut renderer,
+        theme,
+        &renderer::Style {
+            text_color: style.text_color,
+        },
+        Layout::new(&node),
+        mouse::Cursor::Unavailable,
+        &Rectangle::new(Point::ORIGIN, Size::new(width, height)),
+    );
+
+    iced_renderer::pdf::export(
+        &mut renderer,
+        height,
+        page.size,
+        page.margin,
+        style.background_color,
+    )
+    .expect("Export PDF with the software renderer")
+}
@@ -0,0 +1,411 @@
+//! Persist the state of your application in the directories of the user.
+//!
+//! A [`Storage`] is a TOML or JSON file where some serializable state is
+//! saved and loaded with asynchronous tasks. [`Storage::config`] and
+//! [`Storage::data`] place the file in the standard configuration and data
+//! directories of the platform; like `~/.config` and `~/.local/share` on
+//! Linux.
+//!
+//! Saved files are tagged with a schema version. When the version of the
+//! [`Storage`] is increased, the migrations registered with
+//! [`Storage::migration`] upgrade old files on load.
+//!
+//! [`Storage::autosave`] can be called on every change of the state; it
+//! only writes the latest state once changes settle down.
+//!
+//! # Example
+//! ```no_run
+//! use iced::storage::{self, Storage};
+//! use iced::Task;
+//!
+//! #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+//! struct Settings {
+//!     username: String,
+//!     volume: f32,
+//! }
+//!
+//! struct State {
+//!     settings: Settings,
+//!     storage: Storage,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Loaded(Result<Option<Settings>, storage::Error>),
+//!     VolumeChanged(f32),
+//!     Saved(Result<(), storage::Error>),
+//! }
+//!
+//! fn boot() -> (State, Task<Message>) {
+//!     let storage = Storage::config("my-app", "settings.toml")
+//!         .expect("Find configuration directory")
+//!         .version(2)
+//!         .migration(1, |mut settings| {
+//!             // Version 2 renamed the `name` field to `username`
+//!             if let Some(name) = settings.as_object_mut().and_then(|settings| settings.remove("name")) {
+//!                 settings["username"] = name;
+//!             }
+//!
+//!             settings
+//!         });
+//!
+//!     let load = storage.load().map(Message::Loaded);
+//!
+//!     (State { settings: Settings::default(), storage }, load)
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Loaded(Ok(settings)) => {
+//!             state.settings = settings.unwrap_or_default();
+//!             Task::none()
+//!         }
+//!         Message::VolumeChanged(volume) => {
+//!             state.settings.volume = volume;
+//!             state.storage.autosave(&state.settings).map(Message::Saved)
+//!         }
+//!         Message::Loaded(Err(_)) | Message::Saved(_) => Task::none(),
+//!     }
+//! }
+//! ```
+pub use crate::format::Format;
+
+#[doc(no_inline)]
+pub use serde_json::Value;
+
+use crate::Task;
+use crate::runtime::task;
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicU64};
+use std::time::Duration;
+
+/// The default delay of [`Storage::autosave`].
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// A file where the state of an application is persisted.
+///
+/// Cloning a [`Storage`] is cheap; and every clone shares the same
+/// [`Storage::autosave`] debouncing.
+#[derive(Clone)]
+pub struct Storage {
+    path: Arc<PathBuf>,
+    version: u32,
+    migrations: BTreeMap<u32, Migration>,
+    debounce: Duration,
+    generation: Arc<AtomicU64>,
+}
+
+type Migration = Arc<dyn Fn(Value) -> Value + Send + Sync>;
+
+impl Storage {
+    /// Creates a new [`Storage`] backed by the given TOML or JSON file.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Arc::new(path.into()),
+            version: 1,
+            migrations: BTreeMap::new(),
+            debounce: DEFAULT_DEBOUNCE,
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Creates a new [`Storage`] backed by the given file in the
+    /// configuration directory of the application; if the directory is
+    /// known.
+    ///
+    /// See [`config_dir`].
+    pub fn config(application: &str, file: impl AsRef<Path>) -> Option<Self> {
+        Some(Self::new(config_dir()?.join(application).join(file)))
+    }
+
+    /// Creates a new [`Storage`] backed by the given file in the data
+    /// directory of the application; if the directory is known.
+    ///
+    /// See [`data_dir`].
+    pub fn data(application: &str, file: impl AsRef<Path>) -> Option<Self> {
+        Some(Self::new(data_dir()?.join(application).join(file)))
+    }
+
+    /// Sets the current schema version of the [`Storage`].
+    ///
+    /// By default, it is `1`.
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Registers a migration that upgrades the state from the given
+    /// version to the next one.
+    ///
+    /// Migrations are chained when loading; so a file saved with version
+    /// `1` is upgraded to version `3` by the migrations of versions `1`
+    /// and `2`.
+    pub fn migration(
+        mut self,
+        from: u32,
+        migration: impl Fn(Value) -> Value + Send + Sync + 'static,
+    ) -> Self {
+        let _ = self.migrations.insert(from, Arc::new(migration));
+        self
+    }
+
+    /// Sets the delay of [`Storage::autosave`].
+    ///
+    /// By default, it is [`DEFAULT_DEBOUNCE`].
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Returns the path of the file of the [`Storage`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the state of the [`Storage`]; producing `None` if nothing has
+    /// been saved yet.
+    pub fn load<T>(&self) -> Task<Result<Option<T>, Error>>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+    {
+        let storage = self.clone();
+
+        task::try_blocking(move |mut sender| {
+            let state = storage.read()?;
+            let _ = sender.try_send(state);
+
+            Ok(())
+        })
+    }
+
+    /// Saves the given state in the [`Storage`].
+    pub fn save<T>(&self, state: &T) -> Task<Result<(), Error>>
+    where
+        T: serde::Serialize,
+    {
+        self.write_after(state, Duration::ZERO)
+    }
+
+    /// Saves the given state in the [`Storage`] after the debounce delay
+    /// has elapsed; unless the [`Storage`] is saved again in the meantime.
+    ///
+    /// The resulting [`Task`] produces nothing if the state is superseded
+    /// by a newer one.
+    pub fn autosave<T>(&self, state: &T) -> Task<Result<(), Error>>
+    where
+        T: serde::Serialize,
+    {
+        self.write_after(state, self.debounce)
+    }
+
+    fn write_after<T>(&self, state: &T, delay: Duration) -> Task<Result<(), Error>>
+    where
+        T: serde::Serialize,
+    {
+        let contents = match self.serialize(state) {
+            Ok(contents) => contents,
+            Err(error) => return Task::done(Err(error)),
+        };
+
+        let path = Arc::clone(&self.path);
+        let generation = Arc::clone(&self.generation);
+        let current = generation.fetch_add(1, atomic::Ordering::SeqCst) + 1;
+
+        task::try_blocking(move |mut sender| {
+            std::thread::sleep(delay);
+
+            if generation.load(atomic::Ordering::SeqCst) != current {
+                return Ok(());
+            }
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            // Write to a temporary file first, so a crash never leaves a
+            // half-written state behind
+            let temporary = path.with_extension("tmp");
+
+            std::fs::write(&temporary, contents)?;
+            std::fs::rename(&temporary, path.as_path())?;
+
+            let _ = sender.try_send(());
+
+            Ok(())
+        })
+    }
+
+    fn format(&self) -> Result<Format, Error> {
+        Format::from_path(self.path.as_path())
+            .ok_or_else(|| Error::UnsupportedFormat(self.path.to_path_buf()))
+    }
+
+    fn serialize<T>(&self, state: &T) -> Result<String, Error>
+    where
+        T: serde::Serialize,
+    {
+        let format = self.format()?;
+
+        let mut state =
+            serde_json::to_value(state).map_err(|error| Error::Serialize(error.to_string()))?;
+
+        // TOML has no null values; missing keys deserialize to `None` anyways
+        if format == Format::Toml {
+            remove_nulls(&mut state);
+        }
+
+        format
+            .serialize(&File {
+                version: self.version,
+                state,
+            })
+            .map_err(Error::Serialize)
+    }
+
+    fn read<T>(&self) -> Result<Option<T>, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let format = self.format()?;
+
+        let source = match std::fs::read_to_string(self.path.as_path()) {
+            Ok(source) => source,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        let File { version, mut state } = format.parse(&source).map_err(Error::Parse)?;
+
+        if version > self.version {
+            return Err(Error::UnknownVersion(version));
+        }
+
+        for version in version..self.version {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(Error::MissingMigration(version))?;
+
+            state = migration(state);
+        }
+
+        serde_json::from_value(state)
+            .map(Some)
+            .map_err(|error| Error::Parse(error.to_string()))
+    }
+}
+
+impl std::fmt::Debug for Storage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Storage")
+            .field("path", &self.path)
+            .field("version", &self.version)
+            .field("migrations", &self.migrations.keys().collect::<Vec<_>>())
+            .field("debounce", &self.debounce)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct File {
+    version: u32,
+    state: Value,
+}
+
+/// An error that occurred while loading or saving a [`Storage`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read or written.
+    #[error("the storage file could not be accessed: {0}")]
+    Io(Arc<std::io::Error>),
+
+    /// The format of the file is not supported.
+    #[error("unsupported storage file (expected .toml or .json): {}", .0.display())]
+    UnsupportedFormat(PathBuf),
+
+    /// The contents of the file are invalid.
+    #[error("the storage file is invalid: {0}")]
+    Parse(String),
+
+    /// The state could not be serialized.
+    #[error("the state could not be serialized: {0}")]
+    Serialize(String),
+
+    /// The file was saved with a newer version than the [`Storage`].
+    #[error("the storage file has an unknown version: {0}")]
+    UnknownVersion(u32),
+
+    /// The file was saved with an older version and there is no
+    /// migration from it.
+    #[error("there is no migration from version {0}")]
+    MissingMigration(u32),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+/// Returns the configuration directory of the user; if known.
+///
+/// | Platform | Directory                                   |
+/// | -------- | ------------------------------------------- |
+/// | Linux    | `$XDG_CONFIG_HOME` or `~/.config`           |
+/// | macOS    | `~/Library/Application Support`             |
+/// | Windows  | `%APPDATA%`                                 |
+pub fn config_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        env("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        Some(home()?.join("Library/Application Support"))
+    } else if cfg!(target_arch = "wasm32") {
+        None
+    } else {
+        env("XDG_CONFIG_HOME").or_else(|| Some(home()?.join(".config")))
+    }
+}
+
+/// Returns the data directory of the user; if known.
+///
+/// | Platform | Directory                                   |
+/// | -------- | ------------------------------------------- |
+/// | Linux    | `$XDG_DATA_HOME` or `~/.local/share`        |
+/// | macOS    | `~/Library/Application Support`             |
+/// | Windows  | `%APPDATA%`                                 |
+pub fn data_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        env("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        Some(home()?.join("Library/Application Support"))
+    } else if cfg!(target_arch = "wasm32") {
+        None
+    } else {
+        env("XDG_DATA_HOME").or_else(|| Some(home()?.join(".local/share")))
+    }
+}
+
+fn remove_nulls(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            object.retain(|_, value| !value.is_null());
+            object.values_mut().for_each(remove_nulls);
+        }
+        Value::Array(array) => array.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+fn home() -> Option<PathBuf> {
+    env("HOME").or_else(|| env("USERPROFILE"))
+}
+
+/// Returns the path in the given environment variable, if absolute.
+fn env(variable: &str) -> Option<PathBuf> {
+    std::env::var_os(variable)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
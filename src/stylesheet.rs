@@ -0,0 +1,532 @@
+//! Restyle the built-in widgets with selector-based stylesheets.
+//!
+//! A [`Stylesheet`] is a list of rules that match widgets by kind, id, class,
+//! and status—similar to CSS selectors—and produce the existing `Style` structs
+//! of the built-in widgets.
+//!
+//! Stylesheets can be loaded at runtime from TOML or JSON files:
+//!
+//! ```toml
+//! [[rules]]
+//! selector = "button"
+//! border-radius = 8.0
+//!
+//! [[rules]]
+//! selector = "button.primary:hovered"
+//! background = "#6c4cf5"
+//! text-color = "#ffffff"
+//!
+//! [[rules]]
+//! selector = "container#sidebar"
+//! background = "#18181c"
+//! border-color = "#2a2a30"
+//! border-width = 1.0
+//! ```
+//!
+//! A selector starts with an optional widget kind (`button`, `container`, `text`,
+//! or `text_input`), followed by an optional `#id`, any amount of `.class` names, and
+//! an optional `:status`. When multiple rules match, more specific rules win; and
+//! later rules win between rules of equal specificity.
+//!
+//! # Example
+//! ```no_run
+//! use iced::stylesheet::Stylesheet;
+//! use iced::widget::{button, column, container};
+//! use iced::Element;
+//!
+//! struct State {
+//!     stylesheet: Stylesheet,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     container(
+//!         button("Save")
+//!             .on_press(Message::Save)
+//!             .style(state.stylesheet.button("#save.primary")),
+//!     )
+//!     .style(state.stylesheet.container("#sidebar"))
+//!     .into()
+//! }
+//! ```
+use crate::Theme;
+use crate::core::{Background, Border, Color, Shadow, Vector};
+use crate::format::Format;
+use crate::widget::{button, container, text, text_input};
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A list of rules used to style the built-in widgets.
+///
+/// Cloning a [`Stylesheet`] is cheap.
+#[derive(Debug, Clone, Default)]
+pub struct Stylesheet {
+    rules: Arc<[Rule]>,
+}
+
+impl Stylesheet {
+    /// Parses a [`Stylesheet`] from the given string in the given [`Format`].
+    pub fn parse(source: &str, format: Format) -> Result<Self, Error> {
+        let file: File = format.parse(source).map_err(Error::Parse)?;
+
+        let mut rules = file
+            .rules
+            .into_iter()
+            .map(Rule::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // A stable sort keeps the order of rules with the same specificity
+        rules.sort_by_key(|rule| rule.selector.specificity());
+
+        Ok(Self {
+            rules: Arc::from(rules),
+        })
+    }
+
+    /// Loads a [`Stylesheet`] from the given TOML or JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let format =
+            Format::from_path(path).ok_or_else(|| Error::UnsupportedFormat(path.to_path_buf()))?;
+
+        let source = std::fs::read_to_string(path)?;
+
+        Self::parse(&source, format)
+    }
+
+    /// Returns the style function of a [`button`] with the given selector.
+    ///
+    /// The matching rules are applied on top of [`button::primary`].
+    pub fn button<S: Into<Selector>>(
+        &self,
+        selector: S,
+    ) -> impl Fn(&Theme, button::Status) -> button::Style + use<S> {
+        let rules = self.rules.clone();
+        let selector = selector.into();
+
+        move |theme, status| {
+            let status_name = match status {
+                button::Status::Active => "active",
+                button::Status::Hovered => "hovered",
+                button::Status::Pressed => "pressed",
+                button::Status::Disabled => "disabled",
+            };
+
+            let mut style = button::primary(theme, status);
+
+            for properties in matching(&rules, "button", &selector, Some(status_name)) {
+                if let Some(background) = properties.background {
+                    style.background = Some(Background::Color(background));
+                }
+
+                if let Some(text_color) = properties.text_color {
+                    style.text_color = text_color;
+                }
+
+                properties.apply_border(&mut style.border);
+                properties.apply_shadow(&mut style.shadow);
+            }
+
+            style
+        }
+    }
+
+    /// Returns the style function of a [`container`] with the given selector.
+    ///
+    /// The matching rules are applied on top of [`container::transparent`].
+    pub fn container<S: Into<Selector>>(
+        &self,
+        selector: S,
+    ) -> impl Fn(&Theme) -> container::Style + use<S> {
+        let rules = self.rules.clone();
+        let selector = selector.into();
+
+        move |theme| {
+            let mut style = container::transparent(theme);
+
+            for properties in matching(&rules, "container", &selector, None) {
+                if let Some(background) = properties.background {
+                    style.background = Some(Background::Color(background));
+                }
+
+                if let Some(text_color) = properties.text_color {
+                    style.text_color = Some(text_color);
+                }
+
+                properties.apply_border(&mut style.border);
+                properties.apply_shadow(&mut style.shadow);
+            }
+
+            style
+        }
+    }
+
+    /// Returns the style function of a [`text`] widget with the given selector.
+    pub fn text<S: Into<Selector>>(&self, selector: S) -> impl Fn(&Theme) -> text::Style + use<S> {
+        let rules = self.rules.clone();
+        let selector = selector.into();
+
+        move |_theme| {
+            let mut style = text::Style::default();
+
+            for properties in matching(&rules, "text", &selector, None) {
+                if let Some(text_color) = properties.text_color {
+                    style.color = Some(text_color);
+                }
+            }
+
+            style
+        }
+    }
+
+    /// Returns the style function of a [`text_input`] with the given selector.
+    ///
+    /// The matching rules are applied on top of [`text_input::default`].
+    pub fn text_input<S: Into<Selector>>(
+        &self,
+        selector: S,
+    ) -> impl Fn(&Theme, text_input::Status) -> text_input::Style + use<S> {
+        let rules = self.rules.clone();
+        let selector = selector.into();
+
+        move |theme, status| {
+            let status_name = match status {
+                text_input::Status::Active => "active",
+                text_input::Status::Hovered => "hovered",
+                text_input::Status::Focused { .. } => "focused",
+                text_input::Status::Disabled => "disabled",
+            };
+
+            let mut style = text_input::default(theme, status);
+
+            for properties in matching(&rules, "text_input", &selector, Some(status_name)) {
+                if let Some(background) = properties.background {
+                    style.background = Background::Color(background);
+                }
+
+                if let Some(text_color) = properties.text_color {
+                    style.value = text_color;
+                }
+
+                if let Some(placeholder_color) = properties.placeholder_color {
+                    style.placeholder = placeholder_color;
+                }
+
+                if let Some(selection_color) = properties.selection_color {
+                    style.selection = selection_color;
+                }
+
+                if let Some(icon_color) = properties.icon_color {
+                    style.icon = icon_color;
+                }
+
+                properties.apply_border(&mut style.border);
+            }
+
+            style
+        }
+    }
+}
+
+/// The selector of a widget instance; with an optional id and any amount
+/// of classes.
+///
+/// It can be parsed from a string like `#save.primary.large`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selector {
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl Selector {
+    /// Creates an empty [`Selector`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the id of the [`Selector`].
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Adds a class to the [`Selector`].
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(selector: &str) -> Self {
+        let pattern = Pattern::parse(selector);
+
+        Self {
+            id: pattern.id,
+            classes: pattern.classes,
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = std::convert::Infallible;
+
+    fn from_str(selector: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(selector))
+    }
+}
+
+/// An error that occurred while loading a [`Stylesheet`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The stylesheet file could not be read.
+    #[error("the stylesheet file could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+
+    /// The format of the stylesheet file is not supported.
+    #[error("unsupported stylesheet file (expected .toml or .json): {}", .0.display())]
+    UnsupportedFormat(PathBuf),
+
+    /// The contents of the stylesheet are invalid.
+    #[error("the stylesheet is invalid: {0}")]
+    Parse(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    selector: Pattern,
+    properties: Properties,
+}
+
+impl TryFrom<RuleFile> for Rule {
+    type Error = Error;
+
+    fn try_from(rule: RuleFile) -> Result<Self, Error> {
+        let color = |value: Option<String>| {
+            value
+                .map(|value| {
+                    value
+                        .parse::<Color>()
+                        .map_err(|error| Error::Parse(format!("invalid color `{value}`: {error}")))
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            selector: Pattern::parse(&rule.selector),
+            properties: Properties {
+                background: color(rule.background)?,
+                text_color: color(rule.text_color)?,
+                placeholder_color: color(rule.placeholder_color)?,
+                selection_color: color(rule.selection_color)?,
+                icon_color: color(rule.icon_color)?,
+                border_color: color(rule.border_color)?,
+                border_width: rule.border_width,
+                border_radius: rule.border_radius,
+                shadow_color: color(rule.shadow_color)?,
+                shadow_offset: rule.shadow_offset.map(|[x, y]| Vector::new(x, y)),
+                shadow_blur: rule.shadow_blur,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Pattern {
+    kind: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+    status: Option<String>,
+}
+
+impl Pattern {
+    fn parse(selector: &str) -> Self {
+        let mut pattern = Self::default();
+        let mut rest = selector.trim();
+
+        let end = rest.find(['#', '.', ':']).unwrap_or(rest.len());
+
+        if end > 0 {
+            pattern.kind = Some(rest[..end].to_owned());
+        }
+
+        rest = &rest[end..];
+
+        while let Some(prefix) = rest.chars().next() {
+            let end = rest[1..]
+                .find(['#', '.', ':'])
+                .map_or(rest.len(), |end| end + 1);
+            let name = rest[1..end].to_owned();
+
+            match prefix {
+                '#' => pattern.id = Some(name),
+                '.' => pattern.classes.push(name),
+                _ => pattern.status = Some(name),
+            }
+
+            rest = &rest[end..];
+        }
+
+        pattern
+    }
+
+    fn specificity(&self) -> (usize, usize, usize) {
+        (
+            usize::from(self.id.is_some()),
+            self.classes.len() + usize::from(self.status.is_some()),
+            usize::from(self.kind.is_some()),
+        )
+    }
+
+    fn matches(&self, kind: &str, selector: &Selector, status: Option<&str>) -> bool {
+        self.kind.as_deref().is_none_or(|expected| expected == kind)
+            && self
+                .id
+                .as_ref()
+                .is_none_or(|id| selector.id.as_ref() == Some(id))
+            && self
+                .classes
+                .iter()
+                .all(|class| selector.classes.contains(class))
+            && self
+                .status
+                .as_deref()
+                .is_none_or(|expected| Some(expected) == status)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Properties {
+    background: Option<Color>,
+    text_color: Option<Color>,
+    placeholder_color: Option<Color>,
+    selection_color: Option<Color>,
+    icon_color: Option<Color>,
+    border_color: Option<Color>,
+    border_width: Option<f32>,
+    border_radius: Option<f32>,
+    shadow_color: Option<Color>,
+    shadow_offset: Option<Vector>,
+    shadow_blur: Option<f32>,
+}
+
+impl Properties {
+    fn apply_border(&self, border: &mut Border) {
+        if let Some(color) = self.border_color {
+            border.color = color;
+        }
+
+        if let Some(width) = self.border_width {
+            border.width = width;
+        }
+
+        if let Some(radius) = self.border_radius {
+            border.radius = radius.into();
+        }
+    }
+
+    fn apply_shadow(&self, shadow: &mut Shadow) {
+        if let Some(color) = self.shadow_color {
+            shadow.color = color;
+        }
+
+        if let Some(offset) = self.shadow_offset {
+            shadow.offset = offset;
+        }
+
+        if let Some(blur) = self.shadow_blur {
+            shadow.blur_radius = blur;
+        }
+    }
+}
+
+fn matching<'a>(
+    rules: &'a [Rule],
+    kind: &'a str,
+    selector: &'a Selector,
+    status: Option<&'a str>,
+) -> impl Iterator<Item = &'a Properties> {
+    rules
+        .iter()
+        .filter(move |rule| rule.selector.matches(kind, selector, status))
+        .map(|rule| &rule.properties)
+}
+
+#[derive(serde::Deserialize)]
+struct File {
+    #[serde(default)]
+    rules: Vec<RuleFile>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct RuleFile {
+    selector: String,
+    background: Option<String>,
+    text_color: Option<String>,
+    placeholder_color: Option<String>,
+    selection_color: Option<String>,
+    icon_color: Option<String>,
+    border_color: Option<String>,
+    border_width: Option<f32>,
+    border_radius: Option<f32>,
+    shadow_color: Option<String>,
+    shadow_offset: Option<[f32; 2]>,
+    shadow_blur: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pattern() {
+        assert_eq!(
+            Pattern::parse("button#save.primary.large:hovered"),
+            Pattern {
+                kind: Some("button".to_owned()),
+                id: Some("save".to_owned()),
+                classes: vec!["primary".to_owned(), "large".to_owned()],
+                status: Some("hovered".to_owned()),
+            }
+        );
+
+        assert_eq!(
+            Selector::from("#save.primary"),
+            Selector::new().id("save").class("primary")
+        );
+    }
+
+    #[test]
+    fn specific_rules_win() {
+        let stylesheet = Stylesheet::parse(
+            r##"
+            [[rules]]
+            selector = "button.primary"
+            text-color = "#ff0000"
+
+            [[rules]]
+            selector = "button"
+            text-color = "#00ff00"
+            "##,
+            Format::Toml,
+        )
+        .expect("stylesheet must parse");
+
+        let style = stylesheet.button(".primary")(&Theme::Dark, button::Status::Active);
+
+        assert_eq!(style.text_color, Color::from_rgb8(255, 0, 0));
+    }
+}
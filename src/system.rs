@@ -0,0 +1,17 @@
+//! Retrieve system information.
+pub use crate::runtime::system::{theme, theme_changes};
+
+#[cfg(feature = "sysinfo")]
+pub use crate::runtime::system::{Information, information};
+
+#[cfg(feature = "network-status")]
+mod network;
+
+#[cfg(feature = "network-status")]
+pub use network::{Connectivity, Network, Wifi, network_events};
+
+#[cfg(feature = "dbus")]
+mod timezone;
+
+#[cfg(feature = "dbus")]
+pub use timezone::timezone_changes;
@@ -0,0 +1,259 @@
+use crate::Subscription;
+use crate::dbus;
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+
+use zbus::zvariant::{self, OwnedObjectPath, OwnedValue, Value};
+
+const DESTINATION: &str = "org.freedesktop.NetworkManager";
+const PATH: &str = "/org/freedesktop/NetworkManager";
+const PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+
+/// Returns a [`Subscription`] that produces the [`Network`] status of the
+/// system every time it changes; including the status when it starts.
+///
+/// The status is read from NetworkManager through the system bus; which
+/// is available on most Linux desktops. The [`Subscription`] produces a
+/// [`dbus::Error`] if NetworkManager cannot be reached.
+///
+/// # Example
+/// ```no_run
+/// use iced::{Subscription, dbus, system};
+///
+/// struct State {
+///     network: Option<system::Network>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NetworkChanged(Result<system::Network, dbus::Error>),
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::NetworkChanged(network) => {
+///             state.network = network.ok();
+///         }
+///     }
+/// }
+///
+/// fn should_download_updates(state: &State) -> bool {
+///     state
+///         .network
+///         .as_ref()
+///         .is_some_and(|network| network.is_online() && !network.is_metered)
+/// }
+///
+/// fn subscription(_state: &State) -> Subscription<Message> {
+///     system::network_events().map(Message::NetworkChanged)
+/// }
+/// ```
+pub fn network_events() -> Subscription<Result<Network, dbus::Error>> {
+    Subscription::run(|| {
+        stream::channel(10, async |mut output| {
+            if let Err(error) = listen(&mut output).await {
+                let _ = output.send(Err(error)).await;
+            }
+        })
+    })
+}
+
+/// The network status of the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    /// The [`Connectivity`] of the system.
+    pub connectivity: Connectivity,
+    /// The Wi-Fi network of the primary connection; if it is wireless.
+    pub wifi: Option<Wifi>,
+    /// Whether the primary connection is metered; like a mobile hotspot.
+    ///
+    /// Large downloads should be deferred while it is.
+    pub is_metered: bool,
+}
+
+impl Network {
+    /// Returns true if the system can reach the Internet.
+    pub fn is_online(&self) -> bool {
+        self.connectivity == Connectivity::Full
+    }
+}
+
+/// The connectivity of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// The connectivity could not be determined.
+    #[default]
+    Unknown,
+    /// The system is not connected to any network.
+    None,
+    /// The system is behind a captive portal; like the sign in page of a
+    /// public Wi-Fi network.
+    Portal,
+    /// The system is connected to a network, but cannot reach the Internet.
+    Limited,
+    /// The system can reach the Internet.
+    Full,
+}
+
+/// A Wi-Fi network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wifi {
+    /// The name of the network.
+    pub ssid: String,
+    /// The strength of the signal of the network, in the `[0, 100]` range.
+    pub strength: u8,
+}
+
+async fn listen(
+    output: &mut mpsc::Sender<Result<Network, dbus::Error>>,
+) -> Result<(), dbus::Error> {
+    let connection = zbus::Connection::system().await?;
+
+    // Signals are sent from the unique name of NetworkManager; not its
+    // well-known one.
+    let owner: String = connection
+        .call_method(
+            Some("org.freedesktop.DBus"),
+            "/org/freedesktop/DBus",
+            Some("org.freedesktop.DBus"),
+            "GetNameOwner",
+            &DESTINATION,
+        )
+        .await?
+        .body()
+        .deserialize()?;
+
+    let rule = zbus::MatchRule::builder()
+        .msg_type(zbus::message::Type::Signal)
+        .sender(owner.as_str())?
+        .interface(PROPERTIES)?
+        .member("PropertiesChanged")?
+        .build();
+
+    // Access points notify changes of their signal strength; so every
+    // property of NetworkManager is watched.
+    let mut signals = zbus::MessageStream::for_match_rule(rule, &connection, None).await?;
+    let mut last = None;
+
+    loop {
+        let network = fetch(&connection).await?;
+
+        if last.as_ref() != Some(&network) {
+            last = Some(network.clone());
+
+            let _ = output.send(Ok(network)).await;
+        }
+
+        if signals.next().await.is_none() {
+            return Ok(());
+        }
+    }
+}
+
+async fn fetch(connection: &zbus::Connection) -> Result<Network, dbus::Error> {
+    let connectivity = match property::<u32>(connection, PATH, DESTINATION, "Connectivity").await? {
+        1 => Connectivity::None,
+        2 => Connectivity::Portal,
+        3 => Connectivity::Limited,
+        4 => Connectivity::Full,
+        _ => Connectivity::Unknown,
+    };
+
+    // `NMMetered` is either yes (1) or guessed yes (3) for metered
+    // connections
+    let is_metered = matches!(
+        property::<u32>(connection, PATH, DESTINATION, "Metered").await?,
+        1 | 3
+    );
+
+    let primary: OwnedObjectPath =
+        property(connection, PATH, DESTINATION, "PrimaryConnection").await?;
+
+    let wifi = if primary.as_str() == "/" {
+        None
+    } else {
+        wifi(connection, &primary).await.unwrap_or_default()
+    };
+
+    Ok(Network {
+        connectivity,
+        wifi,
+        is_metered,
+    })
+}
+
+async fn wifi(
+    connection: &zbus::Connection,
+    active: &OwnedObjectPath,
+) -> Result<Option<Wifi>, dbus::Error> {
+    const ACTIVE: &str = "org.freedesktop.NetworkManager.Connection.Active";
+    const ACCESS_POINT: &str = "org.freedesktop.NetworkManager.AccessPoint";
+
+    let kind: String = property(connection, active.as_str(), ACTIVE, "Type").await?;
+
+    if kind != "802-11-wireless" {
+        return Ok(None);
+    }
+
+    let access_point: OwnedObjectPath =
+        property(connection, active.as_str(), ACTIVE, "SpecificObject").await?;
+
+    if access_point.as_str() == "/" {
+        return Ok(None);
+    }
+
+    let ssid = get(connection, access_point.as_str(), ACCESS_POINT, "Ssid").await?;
+    let strength: u8 =
+        property(connection, access_point.as_str(), ACCESS_POINT, "Strength").await?;
+
+    // The SSID is an array of bytes; which is not guaranteed to be UTF-8
+    let ssid: Vec<u8> = match &*ssid {
+        Value::Array(bytes) => bytes
+            .iter()
+            .filter_map(|byte| match byte {
+                Value::U8(byte) => Some(*byte),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(Some(Wifi {
+        ssid: String::from_utf8_lossy(&ssid).into_owned(),
+        strength: strength.min(100),
+    }))
+}
+
+async fn property<T>(
+    connection: &zbus::Connection,
+    path: &str,
+    interface: &str,
+    name: &str,
+) -> Result<T, dbus::Error>
+where
+    T: TryFrom<OwnedValue, Error = zvariant::Error>,
+{
+    let value = get(connection, path, interface, name).await?;
+
+    Ok(T::try_from(value).map_err(zbus::Error::from)?)
+}
+
+async fn get(
+    connection: &zbus::Connection,
+    path: &str,
+    interface: &str,
+    name: &str,
+) -> Result<OwnedValue, dbus::Error> {
+    let reply = connection
+        .call_method(
+            Some(DESTINATION),
+            path,
+            Some(PROPERTIES),
+            "Get",
+            &(interface, name),
+        )
+        .await?;
+
+    Ok(reply.body().deserialize()?)
+}
@@ -0,0 +1,99 @@
+use crate::Subscription;
+use crate::dbus;
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+
+const DESTINATION: &str = "org.freedesktop.timedate1";
+const PATH: &str = "/org/freedesktop/timedate1";
+const PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+
+/// Returns a [`Subscription`] that produces the timezone of the system
+/// every time it changes; including the timezone when it starts.
+///
+/// Timezones are named after the IANA time zone database; like
+/// `Europe/Madrid` or `America/New_York`.
+///
+/// The timezone is read from `systemd-timedated` through the system bus;
+/// which is available on most Linux distributions.
+///
+/// # Example
+/// ```no_run
+/// use iced::{Subscription, dbus, system};
+///
+/// struct State {
+///     timezone: Option<String>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TimezoneChanged(Result<String, dbus::Error>),
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::TimezoneChanged(timezone) => {
+///             state.timezone = timezone.ok();
+///         }
+///     }
+/// }
+///
+/// fn subscription(_state: &State) -> Subscription<Message> {
+///     system::timezone_changes().map(Message::TimezoneChanged)
+/// }
+/// ```
+pub fn timezone_changes() -> Subscription<Result<String, dbus::Error>> {
+    Subscription::run(|| {
+        stream::channel(10, async |mut output| {
+            if let Err(error) = listen(&mut output).await {
+                let _ = output.send(Err(error)).await;
+            }
+        })
+    })
+}
+
+async fn listen(output: &mut mpsc::Sender<Result<String, dbus::Error>>) -> Result<(), dbus::Error> {
+    let connection = zbus::Connection::system().await?;
+
+    // `timedated` is started on demand and exits when idle; so its signals
+    // are matched by path instead of by its ever-changing unique name.
+    let rule = zbus::MatchRule::builder()
+        .msg_type(zbus::message::Type::Signal)
+        .path(PATH)?
+        .interface(PROPERTIES)?
+        .member("PropertiesChanged")?
+        .build();
+
+    let mut signals = zbus::MessageStream::for_match_rule(rule, &connection, None).await?;
+    let mut last = None;
+
+    loop {
+        let timezone = fetch(&connection).await?;
+
+        if last.as_ref() != Some(&timezone) {
+            last = Some(timezone.clone());
+
+            let _ = output.send(Ok(timezone)).await;
+        }
+
+        if signals.next().await.is_none() {
+            return Ok(());
+        }
+    }
+}
+
+async fn fetch(connection: &zbus::Connection) -> Result<String, dbus::Error> {
+    let reply = connection
+        .call_method(
+            Some(DESTINATION),
+            PATH,
+            Some(PROPERTIES),
+            "Get",
+            &(DESTINATION, "Timezone"),
+        )
+        .await?;
+
+    let timezone: zbus::zvariant::OwnedValue = reply.body().deserialize()?;
+
+    Ok(String::try_from(timezone).map_err(zbus::Error::from)?)
+}
@@ -0,0 +1,265 @@
+//! Use the built-in theme and styles.
+//!
+//! With the `theme-reload` feature enabled, a [`Theme`] can also be loaded from a
+//! TOML or JSON file and hot-reloaded whenever the file changes; which is useful to
+//! iterate on a theme without restarting the application.
+//!
+//! A theme file defines a `name` and the colors of its [`Palette`] as
+//! hexadecimal strings:
+//!
+//! ```toml
+//! name = "Playtron"
+//!
+//! [palette]
+//! background = "#101014"
+//! text = "#eeeeee"
+//! primary = "#6c4cf5"
+//! success = "#12664f"
+//! warning = "#b77e33"
+//! danger = "#c3423f"
+//! ```
+pub use crate::core::theme::*;
+
+#[cfg(feature = "theme-reload")]
+pub use crate::format::Format;
+
+#[cfg(feature = "theme-reload")]
+pub use reload::{Definition, Error, load, save, watch};
+
+#[cfg(feature = "theme-reload")]
+mod reload {
+    use crate::Subscription;
+    use crate::core::Color;
+    use crate::core::theme::{Palette, Theme};
+    use crate::format::Format;
+    use crate::futures::channel::mpsc;
+    use crate::futures::{SinkExt, StreamExt};
+    use crate::stream;
+
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    /// The serializable definition of a custom [`Theme`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Definition {
+        /// The name of the [`Theme`].
+        pub name: String,
+        /// The [`Palette`] of the [`Theme`].
+        pub palette: Palette,
+    }
+
+    impl Definition {
+        /// Creates the [`Definition`] of an existing [`Theme`].
+        pub fn new(theme: &Theme) -> Self {
+            Self {
+                name: theme.to_string(),
+                palette: theme.palette(),
+            }
+        }
+
+        /// Parses a [`Definition`] from the given string in the given [`Format`].
+        pub fn parse(source: &str, format: Format) -> Result<Self, Error> {
+            let file: File = format.parse(source).map_err(Error::Parse)?;
+
+            let color = |value: &str| {
+                value
+                    .parse::<Color>()
+                    .map_err(|error| Error::Parse(format!("invalid color `{value}`: {error}")))
+            };
+
+            Ok(Self {
+                name: file.name,
+                palette: Palette {
+                    background: color(&file.palette.background)?,
+                    text: color(&file.palette.text)?,
+                    primary: color(&file.palette.primary)?,
+                    success: color(&file.palette.success)?,
+                    warning: color(&file.palette.warning)?,
+                    danger: color(&file.palette.danger)?,
+                },
+            })
+        }
+
+        /// Serializes the [`Definition`] to a string in the given [`Format`].
+        pub fn serialize(&self, format: Format) -> Result<String, Error> {
+            let file = File {
+                name: self.name.clone(),
+                palette: PaletteFile {
+                    background: self.palette.background.to_string(),
+                    text: self.palette.text.to_string(),
+                    primary: self.palette.primary.to_string(),
+                    success: self.palette.success.to_string(),
+                    warning: self.palette.warning.to_string(),
+                    danger: self.palette.danger.to_string(),
+                },
+            };
+
+            format.serialize(&file).map_err(Error::Parse)
+        }
+
+        /// Builds the custom [`Theme`] of the [`Definition`].
+        pub fn theme(&self) -> Theme {
+            Theme::custom(self.name.clone(), self.palette)
+        }
+    }
+
+    /// An error that occurred while loading, saving, or watching a theme file.
+    #[derive(Debug, Clone, thiserror::Error)]
+    pub enum Error {
+        /// The theme file could not be read or written.
+        #[error("the theme file could not be accessed: {0}")]
+        Io(Arc<std::io::Error>),
+
+        /// The format of the theme file is not supported.
+        #[error("unsupported theme file (expected .toml or .json): {}", .0.display())]
+        UnsupportedFormat(PathBuf),
+
+        /// The contents of the theme file are invalid.
+        #[error("the theme file is invalid: {0}")]
+        Parse(String),
+
+        /// The theme file could not be watched for changes.
+        #[error("the theme file could not be watched: {0}")]
+        Watch(String),
+    }
+
+    impl From<std::io::Error> for Error {
+        fn from(error: std::io::Error) -> Self {
+            Self::Io(Arc::new(error))
+        }
+    }
+
+    /// Loads a custom [`Theme`] from the given TOML or JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Theme, Error> {
+        let path = path.as_ref();
+        let format =
+            Format::from_path(path).ok_or_else(|| Error::UnsupportedFormat(path.to_path_buf()))?;
+
+        let source = std::fs::read_to_string(path)?;
+
+        Ok(Definition::parse(&source, format)?.theme())
+    }
+
+    /// Saves the given [`Theme`] to a TOML or JSON file.
+    ///
+    /// This can be used to persist a [`Theme`] edited at runtime.
+    pub fn save(path: impl AsRef<Path>, theme: &Theme) -> Result<(), Error> {
+        let path = path.as_ref();
+        let format =
+            Format::from_path(path).ok_or_else(|| Error::UnsupportedFormat(path.to_path_buf()))?;
+
+        std::fs::write(path, Definition::new(theme).serialize(format)?)?;
+
+        Ok(())
+    }
+
+    /// Returns a [`Subscription`] that loads a custom [`Theme`] from the given
+    /// TOML or JSON file and reloads it every time the file changes.
+    ///
+    /// The [`Theme`] is produced once when the [`Subscription`] starts.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use iced::Subscription;
+    /// use iced::theme::{self, Theme};
+    ///
+    /// struct State {
+    ///     theme: Theme,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     ThemeChanged(Result<Theme, theme::Error>),
+    /// }
+    ///
+    /// fn update(state: &mut State, message: Message) {
+    ///     match message {
+    ///         Message::ThemeChanged(Ok(theme)) => {
+    ///             state.theme = theme;
+    ///         }
+    ///         Message::ThemeChanged(Err(error)) => {
+    ///             eprintln!("{error}");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn subscription(_state: &State) -> Subscription<Message> {
+    ///     theme::watch("theme.toml").map(Message::ThemeChanged)
+    /// }
+    /// ```
+    pub fn watch(path: impl Into<PathBuf>) -> Subscription<Result<Theme, Error>> {
+        Subscription::run_with(path.into(), |path| {
+            let path = path.clone();
+
+            stream::channel(1, async move |mut output| {
+                let _ = output.send(load(&path)).await;
+
+                let (sender, mut receiver) = mpsc::unbounded();
+
+                let watcher = notify::recommended_watcher(move |event| {
+                    let _ = sender.unbounded_send(event);
+                });
+
+                let mut watcher = match watcher {
+                    Ok(watcher) => watcher,
+                    Err(error) => {
+                        let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                        return;
+                    }
+                };
+
+                // Editors usually replace files on save; so we watch the
+                // parent directory instead of the file itself.
+                let directory = match path.parent() {
+                    Some(parent) if !parent.as_os_str().is_empty() => parent,
+                    _ => Path::new("."),
+                };
+
+                if let Err(error) = notify::Watcher::watch(
+                    &mut watcher,
+                    directory,
+                    notify::RecursiveMode::NonRecursive,
+                ) {
+                    let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                    return;
+                }
+
+                while let Some(event) = receiver.next().await {
+                    let changed = match event {
+                        Ok(event) => {
+                            (event.kind.is_create() || event.kind.is_modify())
+                                && event
+                                    .paths
+                                    .iter()
+                                    .any(|changed| changed.file_name() == path.file_name())
+                        }
+                        Err(error) => {
+                            let _ = output.send(Err(Error::Watch(error.to_string()))).await;
+                            continue;
+                        }
+                    };
+
+                    if changed {
+                        let _ = output.send(load(&path)).await;
+                    }
+                }
+            })
+        })
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct File {
+        name: String,
+        palette: PaletteFile,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct PaletteFile {
+        background: String,
+        text: String,
+        primary: String,
+        success: String,
+        warning: String,
+        danger: String,
+    }
+}
@@ -269,6 +269,11 @@ impl<P: Program + 'static> Emulator<P> {
                 runtime::Action::Reload => {
                     // TODO
                 }
+                runtime::Action::SetDirection(direction) => {
+                    if let Some(cache) = &mut self.cache {
+                        cache.set_direction(direction);
+                    }
+                }
             },
         }
     }
@@ -82,6 +82,8 @@
 //! [`Simulator`] contains additional operations you can use to simulate more interactions—like [`tap_key`](Simulator::tap_key) or
 //! [`typewrite`](Simulator::typewrite)—and even perform [_snapshot testing_](Simulator::snapshot)!
 //!
+//! If you want to drive the whole update and view loop of your application instead, take a look at [`Session`].
+//!
 //! [the classical counter interface]: https://book.iced.rs/architecture.html#dissecting-an-interface
 pub use iced_futures as futures;
 pub use iced_program as program;
@@ -94,6 +96,7 @@ pub use iced_selector as selector;
 pub mod emulator;
 pub mod ice;
 pub mod instruction;
+pub mod session;
 pub mod simulator;
 
 mod error;
@@ -103,6 +106,7 @@ pub use error::Error;
 pub use ice::Ice;
 pub use instruction::Instruction;
 pub use selector::Selector;
+pub use session::{Session, session};
 pub use simulator::{Simulator, simulator};
 
 use crate::core::Size;
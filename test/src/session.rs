@@ -0,0 +1,343 @@
+//! Drive a whole application headlessly, without side effects.
+use crate::core::clipboard;
+use crate::core::event;
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::theme;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget;
+use crate::core::window;
+use crate::core::{Event, Font, Point, Size};
+use crate::program::Program;
+use crate::runtime::UserInterface;
+use crate::runtime::user_interface;
+use crate::selector::Bounded;
+use crate::simulator::{self, Snapshot};
+use crate::{Error, Selector};
+
+use std::env;
+use std::sync::Arc;
+
+/// A headless session of a [`Program`] that can be interacted with and
+/// inspected programmatically.
+///
+/// Unlike a [`Simulator`](crate::Simulator), a [`Session`] runs the whole
+/// update and view loop of a [`Program`]: any messages produced by an
+/// interaction are fed back to the [`Program`], and the interface is rebuilt
+/// before the next interaction.
+///
+/// A [`Session`] does _not_ have side effects. The [`Task`] returned by
+/// [`Program::update`] is discarded, and subscriptions are never run. Use an
+/// [`Emulator`](crate::Emulator) if you need the real thing.
+///
+/// Time does not pass on its own in a [`Session`] either; it must be
+/// [advanced](Self::advance) explicitly, which keeps animations and
+/// snapshots deterministic.
+///
+/// # Example
+/// ```no_run
+/// # use iced_test::program::Program;
+/// # #[derive(Debug, Clone, PartialEq)] enum Message { Increment }
+/// # fn test(counter: impl Program<Message = Message> + 'static) {
+/// use iced_test::Session;
+///
+/// let mut session = Session::new(counter);
+///
+/// let _ = session.click("+");
+/// let _ = session.click("+");
+///
+/// assert_eq!(session.messages(), &[Message::Increment, Message::Increment]);
+/// assert!(session.find("2").is_ok());
+/// # }
+/// ```
+///
+/// [`Task`]: crate::runtime::Task
+pub struct Session<P: Program> {
+    program: P,
+    state: P::State,
+    renderer: P::Renderer,
+    cache: Option<user_interface::Cache>,
+    window: window::Id,
+    size: Size,
+    cursor: mouse::Cursor,
+    now: Instant,
+    messages: Vec<P::Message>,
+}
+
+impl<P> Session<P>
+where
+    P: Program + 'static,
+    P::Message: Clone,
+{
+    /// Creates a new [`Session`] of the given [`Program`] with a default size (1024x768).
+    pub fn new(program: P) -> Self {
+        Self::with_size(program, window::Settings::default().size)
+    }
+
+    /// Creates a new [`Session`] of the given [`Program`] with the given size.
+    pub fn with_size(program: P, size: impl Into<Size>) -> Self {
+        use renderer::Headless;
+
+        let settings = program.settings();
+
+        let default_font = match settings.default_font {
+            Font::DEFAULT => Font::with_name("Fira Sans"),
+            _ => settings.default_font,
+        };
+
+        for font in settings.fonts {
+            simulator::load_font(font).expect("Font must be valid");
+        }
+
+        let renderer = {
+            let backend = env::var("ICED_TEST_BACKEND").ok();
+
+            crate::futures::futures::executor::block_on(P::Renderer::new(
+                default_font,
+                settings.default_text_size,
+                backend.as_deref(),
+            ))
+            .expect("Create new headless renderer")
+        };
+
+        let (state, _task) = program.boot();
+
+        Self {
+            program,
+            state,
+            renderer,
+            cache: Some(user_interface::Cache::default()),
+            window: window::Id::unique(),
+            size: size.into(),
+            cursor: mouse::Cursor::Unavailable,
+            now: Instant::now(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the target of the given widget [`Selector`] in the current
+    /// interface of the [`Session`].
+    pub fn find<S>(&mut self, selector: S) -> Result<S::Output, Error>
+    where
+        S: Selector + Send,
+        S::Output: Clone + Send,
+    {
+        use widget::Operation;
+
+        let description = selector.description();
+        let mut operation = selector.find();
+
+        let mut user_interface = UserInterface::build(
+            self.program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        user_interface.operate(
+            &self.renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        self.cache = Some(user_interface.into_cache());
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(output) => output.ok_or(Error::SelectorNotFound {
+                selector: description,
+            }),
+            _ => Err(Error::SelectorNotFound {
+                selector: description,
+            }),
+        }
+    }
+
+    /// Points the mouse cursor at the given position in the [`Session`].
+    ///
+    /// This does _not_ produce mouse movement events!
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        self.cursor = mouse::Cursor::Available(position.into());
+    }
+
+    /// Clicks the [`Bounded`] target found by the given [`Selector`], if any.
+    ///
+    /// Widgets can be targeted by their [`widget::Id`], since it implements
+    /// [`Selector`].
+    pub fn click<S>(&mut self, selector: S) -> Result<S::Output, Error>
+    where
+        S: Selector + Send,
+        S::Output: Bounded + Clone + Send + Sync + 'static,
+    {
+        let target = self.find(selector)?;
+
+        let Some(visible_bounds) = target.visible_bounds() else {
+            return Err(Error::TargetNotVisible {
+                target: Arc::new(target),
+            });
+        };
+
+        self.point_at(visible_bounds.center());
+
+        let _ = self.simulate(simulator::click());
+
+        Ok(target)
+    }
+
+    /// Simulates a key press, followed by a release, in the [`Session`].
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
+        self.simulate(simulator::tap_key(key, None))
+            .first()
+            .copied()
+            .unwrap_or(event::Status::Ignored)
+    }
+
+    /// Simulates a user typing in the keyboard the given text in the [`Session`].
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        self.simulate(simulator::typewrite(text))
+            .into_iter()
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Advances the clock of the [`Session`] by the given [`Duration`] and
+    /// simulates a redraw request.
+    pub fn advance(&mut self, duration: Duration) -> event::Status {
+        self.now += duration;
+
+        self.simulate([Event::Window(window::Event::RedrawRequested(self.now))])
+            .first()
+            .copied()
+            .unwrap_or(event::Status::Ignored)
+    }
+
+    /// Simulates the given raw sequence of events in the [`Session`].
+    ///
+    /// Any messages produced will be processed by the [`Program`] before
+    /// returning.
+    pub fn simulate(&mut self, events: impl IntoIterator<Item = Event>) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+        let mut messages = Vec::new();
+
+        for event in &events {
+            if let Event::Mouse(mouse::Event::CursorMoved { position }) = event {
+                self.cursor = mouse::Cursor::Available(*position);
+            }
+        }
+
+        let mut user_interface = UserInterface::build(
+            self.program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        let (_state, statuses) = user_interface.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        self.cache = Some(user_interface.into_cache());
+
+        for message in messages {
+            self.messages.push(message.clone());
+
+            let _task = self.program.update(&mut self.state, message);
+        }
+
+        statuses
+    }
+
+    /// Draws and takes a [`Snapshot`] of the current interface of the [`Session`].
+    ///
+    /// The theme of the [`Program`] will be used, if any.
+    pub fn snapshot(&mut self) -> Result<Snapshot, Error> {
+        use renderer::Headless;
+
+        let theme = self
+            .program
+            .theme(&self.state, self.window)
+            .unwrap_or_else(|| <P::Theme as theme::Base>::default(theme::Mode::default()));
+
+        let style = self.program.style(&self.state, &theme);
+
+        let mut user_interface = UserInterface::build(
+            self.program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        let _ = user_interface.update(
+            &[Event::Window(window::Event::RedrawRequested(self.now))],
+            self.cursor,
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut Vec::new(),
+        );
+
+        user_interface.draw(
+            &mut self.renderer,
+            &theme,
+            &renderer::Style {
+                text_color: style.text_color,
+            },
+            self.cursor,
+        );
+
+        self.cache = Some(user_interface.into_cache());
+
+        let scale_factor = 2.0;
+
+        let physical_size = Size::new(
+            (self.size.width * scale_factor).round() as u32,
+            (self.size.height * scale_factor).round() as u32,
+        );
+
+        let rgba = self
+            .renderer
+            .screenshot(physical_size, scale_factor, style.background_color);
+
+        Ok(Snapshot::new(
+            window::Screenshot::new(rgba, physical_size, scale_factor),
+            self.renderer.name(),
+        ))
+    }
+
+    /// Returns the current state of the [`Program`] in the [`Session`].
+    pub fn state(&self) -> &P::State {
+        &self.state
+    }
+
+    /// Returns all the messages produced in the [`Session`] so far, in order.
+    pub fn messages(&self) -> &[P::Message] {
+        &self.messages
+    }
+
+    /// Turns the [`Session`] into the final state of its [`Program`].
+    pub fn into_state(self) -> P::State {
+        self.state
+    }
+}
+
+impl<P: Program> std::fmt::Debug for Session<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Session")
+            .field("size", &self.size)
+            .field("cursor", &self.cursor)
+            .field("now", &self.now)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Creates a new [`Session`] of the given [`Program`].
+///
+/// This is just a function version of [`Session::new`].
+pub fn session<P>(program: P) -> Session<P>
+where
+    P: Program + 'static,
+    P::Message: Clone,
+{
+    Session::new(program)
+}
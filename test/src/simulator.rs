@@ -29,6 +29,7 @@ pub struct Simulator<'a, Message, Theme = core::Theme, Renderer = renderer::Rend
     size: Size,
     cursor: mouse::Cursor,
     messages: Vec<Message>,
+    now: time::Instant,
 }
 
 impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
@@ -90,6 +91,7 @@ where
             size,
             cursor: mouse::Cursor::Unavailable,
             messages: Vec::new(),
+            now: time::Instant::now(),
         }
     }
 
@@ -183,14 +185,26 @@ where
         statuses
     }
 
+    /// Advances the clock of the [`Simulator`] by the given [`Duration`](time::Duration)
+    /// and simulates a redraw request.
+    ///
+    /// The clock of a [`Simulator`] only moves when advanced; so animations
+    /// progress deterministically between [snapshots](Self::snapshot).
+    pub fn advance(&mut self, duration: time::Duration) -> event::Status {
+        self.now += duration;
+
+        self.simulate([Event::Window(window::Event::RedrawRequested(self.now))])
+            .first()
+            .copied()
+            .unwrap_or(event::Status::Ignored)
+    }
+
     /// Draws and takes a [`Snapshot`] of the interface in the [`Simulator`].
     pub fn snapshot(&mut self, theme: &Theme) -> Result<Snapshot, Error> {
         let base = theme.base();
 
         let _ = self.raw.update(
-            &[Event::Window(window::Event::RedrawRequested(
-                time::Instant::now(),
-            ))],
+            &[Event::Window(window::Event::RedrawRequested(self.now))],
             self.cursor,
             &mut self.renderer,
             &mut clipboard::Null,
@@ -237,6 +251,13 @@ pub struct Snapshot {
 }
 
 impl Snapshot {
+    pub(crate) fn new(screenshot: window::Screenshot, renderer: String) -> Self {
+        Self {
+            screenshot,
+            renderer,
+        }
+    }
+
     /// Compares the [`Snapshot`] with the PNG image found in the given path, returning
     /// `true` if they are identical.
     ///
@@ -246,36 +267,68 @@ impl Snapshot {
         let path = self.path(path, "png");
 
         if path.exists() {
-            let file = fs::File::open(&path)?;
-            let decoder = png::Decoder::new(io::BufReader::new(file));
-
-            let mut reader = decoder.read_info()?;
-            let n = reader
-                .output_buffer_size()
-                .expect("snapshot should fit in memory");
-            let mut bytes = vec![0; n];
-            let info = reader.next_frame(&mut bytes)?;
+            let (_size, bytes) = load_png(&path)?;
 
-            Ok(self.screenshot.rgba == bytes[..info.buffer_size()])
+            Ok(self.screenshot.rgba == bytes)
         } else {
-            if let Some(directory) = path.parent() {
-                fs::create_dir_all(directory)?;
-            }
+            save_png(&path, self.screenshot.size, &self.screenshot.rgba)?;
+
+            Ok(true)
+        }
+    }
 
-            let file = fs::File::create(path)?;
+    /// Compares the [`Snapshot`] with the PNG image found in the given path using
+    /// perceptual diffing, returning `true` if they are equal within the given [`Tolerance`].
+    ///
+    /// When the comparison fails, an image highlighting the different pixels in red will be
+    /// saved next to the PNG image with a `diff.png` extension.
+    ///
+    /// If the PNG image does not exist, it will be created by the [`Snapshot`] for future
+    /// testing and `true` will be returned.
+    pub fn matches_image_with(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance: Tolerance,
+    ) -> Result<bool, Error> {
+        let path = self.path(path, "png");
 
-            let mut encoder = png::Encoder::new(
-                file,
-                self.screenshot.size.width,
-                self.screenshot.size.height,
-            );
-            encoder.set_color(png::ColorType::Rgba);
+        if !path.exists() {
+            save_png(&path, self.screenshot.size, &self.screenshot.rgba)?;
 
-            let mut writer = encoder.write_header()?;
-            writer.write_image_data(&self.screenshot.rgba)?;
-            writer.finish()?;
+            return Ok(true);
+        }
+
+        let (size, bytes) = load_png(&path)?;
 
+        if size != self.screenshot.size {
+            return Ok(false);
+        }
+
+        let mut different = 0;
+        let mut diff = Vec::with_capacity(bytes.len());
+
+        for (a, b) in self
+            .screenshot
+            .rgba
+            .chunks_exact(4)
+            .zip(bytes.chunks_exact(4))
+        {
+            if perceptual_difference(a, b) > tolerance.threshold {
+                different += 1;
+                diff.extend([255, 0, 0, 255]);
+            } else {
+                diff.extend([b[0], b[1], b[2], b[3] / 4]);
+            }
+        }
+
+        let ratio = different as f32 / (size.width * size.height).max(1) as f32;
+
+        if ratio <= tolerance.max_ratio {
             Ok(true)
+        } else {
+            save_png(&path.with_extension("diff.png"), size, &diff)?;
+
+            Ok(false)
         }
     }
 
@@ -324,6 +377,60 @@ impl Snapshot {
     }
 }
 
+/// The tolerance of a perceptual [`Snapshot`] comparison.
+///
+/// Different platforms and graphics drivers can rasterize the same interface slightly
+/// differently (e.g. antialiasing, text hinting, color conversion). A [`Tolerance`] can
+/// absorb these differences while still catching actual regressions.
+///
+/// # Example
+/// ```no_run
+/// use iced_test::simulator::Tolerance;
+///
+/// let tolerance = Tolerance::new(0.1, 0.0)
+///     .on("macos", Tolerance::new(0.15, 0.001))
+///     .on("windows", Tolerance::new(0.2, 0.005));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// The maximum perceived color difference between two pixels for them
+    /// to be considered equal, from `0.0` to `1.0`.
+    pub threshold: f32,
+    /// The maximum ratio of different pixels for two images to be considered
+    /// equal, from `0.0` to `1.0`.
+    pub max_ratio: f32,
+}
+
+impl Tolerance {
+    /// A [`Tolerance`] that only considers identical images equal.
+    pub const EXACT: Self = Self::new(0.0, 0.0);
+
+    /// Creates a new [`Tolerance`] with the given threshold and maximum ratio of
+    /// different pixels.
+    pub const fn new(threshold: f32, max_ratio: f32) -> Self {
+        Self {
+            threshold,
+            max_ratio,
+        }
+    }
+
+    /// Overrides the [`Tolerance`] when running on the given platform, as
+    /// reported by [`std::env::consts::OS`].
+    pub fn on(self, platform: &str, tolerance: Self) -> Self {
+        if env::consts::OS == platform {
+            tolerance
+        } else {
+            self
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self::new(0.1, 0.0)
+    }
+}
+
 /// Creates a new [`Simulator`].
 ///
 /// This is just a function version of [`Simulator::new`].
@@ -395,8 +502,73 @@ pub fn typewrite(text: &str) -> impl Iterator<Item = Event> + '_ {
         .flat_map(|c| tap_key(keyboard::Key::Character(c.clone()), Some(c)))
 }
 
-fn load_font(font: impl Into<Cow<'static, [u8]>>) -> Result<(), Error> {
-    renderer::graphics::text::font_system()
+fn load_png(path: &Path) -> Result<(Size<u32>, Vec<u8>), Error> {
+    let file = fs::File::open(path)?;
+    let decoder = png::Decoder::new(io::BufReader::new(file));
+
+    let mut reader = decoder.read_info()?;
+    let n = reader
+        .output_buffer_size()
+        .expect("snapshot should fit in memory");
+    let mut bytes = vec![0; n];
+    let info = reader.next_frame(&mut bytes)?;
+
+    bytes.truncate(info.buffer_size());
+
+    Ok((Size::new(info.width, info.height), bytes))
+}
+
+fn save_png(path: &Path, size: Size<u32>, rgba: &[u8]) -> Result<(), Error> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let file = fs::File::create(path)?;
+
+    let mut encoder = png::Encoder::new(file, size.width, size.height);
+    encoder.set_color(png::ColorType::Rgba);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgba)?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+/// Returns the perceived difference between two RGBA pixels, from `0.0` to `1.0`.
+///
+/// The difference is measured in the YIQ color space, after blending both
+/// pixels with a white background, like `pixelmatch` does.
+fn perceptual_difference(a: &[u8], b: &[u8]) -> f32 {
+    // The maximum possible squared YIQ delta
+    const MAX_DELTA: f32 = 35215.0;
+
+    if a == b {
+        return 0.0;
+    }
+
+    let blend = |pixel: &[u8]| {
+        let alpha = f32::from(pixel[3]) / 255.0;
+
+        [0, 1, 2].map(|i| 255.0 + (f32::from(pixel[i]) - 255.0) * alpha)
+    };
+
+    let [r1, g1, b1] = blend(a);
+    let [r2, g2, b2] = blend(b);
+
+    let (r, g, b) = (r1 - r2, g1 - g2, b1 - b2);
+
+    let y = r * 0.298_895 + g * 0.586_622 + b * 0.114_482;
+    let i = r * 0.595_978 - g * 0.274_176 - b * 0.321_802;
+    let q = r * 0.211_470 - g * 0.522_617 + b * 0.311_147;
+
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+
+    (delta / MAX_DELTA).sqrt().min(1.0)
+}
+
+pub(crate) fn load_font(font: impl Into<Cow<'static, [u8]>>) -> Result<(), Error> {
+    let _ = renderer::graphics::text::font_system()
         .write()
         .expect("Write to font system")
         .load_font(font.into());
@@ -725,6 +725,7 @@ impl<P: Program + 'static> Tester<P> {
                                             }
                                             _ => None,
                                         },
+                                        selection: None,
                                     })
                                     .into()
                             }),
@@ -890,6 +891,7 @@ where
             text(label).size(14).style(|theme: &core::Theme| {
                 text::Style {
                     color: Some(theme.extended_palette().background.weak.text),
+                    selection: None,
                 }
             }),
             space::horizontal(),
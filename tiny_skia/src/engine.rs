@@ -525,15 +525,50 @@ impl Engine {
     ) {
         match image {
             #[cfg(feature = "image")]
-            Image::Raster { image, bounds, .. } => {
+            Image::Raster {
+                image,
+                bounds,
+                clip_bounds,
+            } => {
                 let physical_bounds = *bounds * _transformation;
+                let image_clip_bounds = *clip_bounds * _transformation;
 
-                if !_clip_bounds.intersects(&physical_bounds) {
+                let Some(visible_bounds) = image_clip_bounds.intersection(&_clip_bounds) else {
+                    return;
+                };
+
+                if !visible_bounds.intersects(&physical_bounds) {
                     return;
                 }
 
-                let clip_mask =
-                    (!physical_bounds.is_within(&_clip_bounds)).then_some(_clip_mask as &_);
+                // The border radius applies to the clip bounds of the image
+                let mut border_radius =
+                    <[f32; 4]>::from(image.border_radius * _transformation.scale_factor());
+
+                for radius in &mut border_radius {
+                    *radius = radius
+                        .min(image_clip_bounds.width / 2.0)
+                        .min(image_clip_bounds.height / 2.0);
+                }
+
+                let is_rounded = border_radius.iter().any(|radius| *radius > 0.0);
+                let is_clipped = !physical_bounds.is_within(&visible_bounds);
+                let is_mask_adjusted = is_rounded || (is_clipped && visible_bounds != _clip_bounds);
+
+                if is_mask_adjusted {
+                    adjust_clip_mask(_clip_mask, visible_bounds);
+                }
+
+                if is_rounded {
+                    _clip_mask.intersect_path(
+                        &rounded_rectangle(image_clip_bounds, border_radius),
+                        tiny_skia::FillRule::EvenOdd,
+                        true,
+                        tiny_skia::Transform::default(),
+                    );
+                }
+
+                let clip_mask = (is_rounded || is_clipped).then_some(&*_clip_mask);
 
                 let center = physical_bounds.center();
                 let radians = f32::from(image.rotation);
@@ -553,6 +588,10 @@ impl Engine {
                     transform,
                     clip_mask,
                 );
+
+                if is_mask_adjusted {
+                    adjust_clip_mask(_clip_mask, _clip_bounds);
+                }
             }
             #[cfg(feature = "svg")]
             Image::Vector { svg, bounds, .. } => {
@@ -624,7 +663,7 @@ fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     }
 }
 
-fn rounded_rectangle(bounds: Rectangle, border_radius: [f32; 4]) -> tiny_skia::Path {
+pub fn rounded_rectangle(bounds: Rectangle, border_radius: [f32; 4]) -> tiny_skia::Path {
     let [top_left, top_right, bottom_right, bottom_left] = border_radius;
 
     if top_left == 0.0 && top_right == 0.0 && bottom_right == 0.0 && bottom_left == 0.0 {
@@ -17,6 +17,9 @@ mod vector;
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
+#[cfg(feature = "pdf")]
+mod pdf;
+
 use iced_debug as debug;
 pub use iced_graphics as graphics;
 pub use iced_graphics::core;
@@ -0,0 +1,881 @@
+//! Export the contents of a [`Renderer`] as a paginated PDF document.
+use crate::Renderer;
+use crate::core::alignment;
+use crate::core::gradient::{ColorStop, Linear};
+use crate::core::renderer::Quad;
+use crate::core::text::Alignment;
+use crate::core::{Background, Color, Gradient, Padding, Point, Rectangle, Size, Transformation};
+use crate::engine::{self, Engine};
+use crate::graphics::text::{Text, cache, font_system};
+
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The resolution of the rasterized parts of a document, in pixels per point.
+const RASTER_SCALE: f32 = 2.0;
+
+impl Renderer {
+    /// Exports the contents drawn with the [`Renderer`] as a PDF document.
+    ///
+    /// The contents start at the top of the first page and are split
+    /// vertically across as many pages as needed to fit the given `height`.
+    /// A logical pixel maps to a PDF point.
+    ///
+    /// Text, quads, and opaque gradients are written as vector graphics;
+    /// while shadows, geometry, and images are rasterized.
+    pub fn pdf(&mut self, height: f32, page: Size, margin: Padding, background: Color) -> Vec<u8> {
+        self.layers.flush();
+
+        let area = Size::new(
+            (page.width - margin.horizontal()).max(1.0),
+            (page.height - margin.vertical()).max(1.0),
+        );
+
+        let count = (height / area.height).ceil().max(1.0) as usize;
+
+        let mut document = Document::new();
+        let mut text_cache = cache::Cache::new();
+        let catalog = document.allocate();
+        let tree = document.allocate();
+
+        let pages: Vec<Ref> = (0..count).map(|_| document.allocate()).collect();
+
+        let _ = document.pdf.catalog(catalog).pages(tree);
+        let _ = document
+            .pdf
+            .pages(tree)
+            .kids(pages.iter().copied())
+            .count(count as i32);
+
+        for (index, &id) in pages.iter().enumerate() {
+            let viewport = Rectangle::new(Point::new(0.0, index as f32 * area.height), area);
+
+            let mut canvas = Canvas::new();
+
+            let _ = canvas.content.transform([
+                1.0,
+                0.0,
+                0.0,
+                -1.0,
+                margin.left,
+                page.height - margin.top + viewport.y,
+            ]);
+
+            canvas.clip(viewport);
+
+            if background.a > 0.0 {
+                canvas.fill(&mut document, background, |content| {
+                    let _ = content.rect(viewport.x, viewport.y, viewport.width, viewport.height);
+                });
+            }
+
+            for layer in self.layers.iter() {
+                let Some(bounds) = layer.bounds.intersection(&viewport) else {
+                    continue;
+                };
+
+                let _ = canvas.content.save_state();
+                canvas.clip(bounds);
+
+                let shadows: Vec<_> = layer
+                    .quads
+                    .iter()
+                    .filter(|(quad, _)| quad.shadow.color.a > 0.0)
+                    .collect();
+
+                if !shadows.is_empty() {
+                    canvas.raster(&mut document, &mut self.engine, bounds, |engine, target| {
+                        for (quad, _) in &shadows {
+                            let shadow = Quad {
+                                border: Default::default(),
+                                ..*quad
+                            };
+
+                            engine.draw_quad(
+                                &shadow,
+                                &Background::Color(Color::TRANSPARENT),
+                                target.transformation,
+                                target.pixels,
+                                target.clip_mask,
+                                target.clip_bounds,
+                            );
+                        }
+                    });
+                }
+
+                for (quad, background) in &layer.quads {
+                    if !quad.bounds.intersects(&bounds) {
+                        continue;
+                    }
+
+                    if !canvas.quad(&mut document, quad, background) {
+                        let quad = Quad {
+                            shadow: Default::default(),
+                            ..*quad
+                        };
+
+                        canvas.raster(&mut document, &mut self.engine, bounds, |engine, target| {
+                            engine.draw_quad(
+                                &quad,
+                                background,
+                                target.transformation,
+                                target.pixels,
+                                target.clip_mask,
+                                target.clip_bounds,
+                            );
+                        });
+                    }
+                }
+
+                if !layer.primitives.is_empty() || !layer.images.is_empty() {
+                    canvas.raster(&mut document, &mut self.engine, bounds, |engine, target| {
+                        for group in &layer.primitives {
+                            let transformation = target.transformation * group.transformation();
+
+                            let Some(clip_bounds) = (group.clip_bounds() * transformation)
+                                .intersection(&target.clip_bounds)
+                            else {
+                                continue;
+                            };
+
+                            engine::adjust_clip_mask(target.clip_mask, clip_bounds);
+
+                            for primitive in group.as_slice() {
+                                engine.draw_primitive(
+                                    primitive,
+                                    transformation,
+                                    target.pixels,
+                                    target.clip_mask,
+                                    clip_bounds,
+                                );
+                            }
+
+                            engine::adjust_clip_mask(target.clip_mask, target.clip_bounds);
+                        }
+
+                        for image in &layer.images {
+                            engine.draw_image(
+                                image,
+                                target.transformation,
+                                target.pixels,
+                                target.clip_mask,
+                                target.clip_bounds,
+                            );
+                        }
+                    });
+                }
+
+                for group in &layer.text {
+                    for text in group.as_slice() {
+                        canvas.text(
+                            &mut document,
+                            &mut text_cache,
+                            text,
+                            group.transformation(),
+                            bounds,
+                        );
+                    }
+                }
+
+                let _ = canvas.content.restore_state();
+            }
+
+            canvas.finish(&mut document, id, tree, page);
+        }
+
+        self.engine.trim();
+
+        document.pdf.finish()
+    }
+}
+
+/// A PDF document being written.
+struct Document {
+    pdf: Pdf,
+    next: i32,
+    alphas: BTreeMap<u8, Ref>,
+    swash: cosmic_text::SwashCache,
+}
+
+impl Document {
+    fn new() -> Self {
+        Self {
+            pdf: Pdf::new(),
+            next: 1,
+            alphas: BTreeMap::new(),
+            swash: cosmic_text::SwashCache::new(),
+        }
+    }
+
+    fn allocate(&mut self) -> Ref {
+        let id = Ref::new(self.next);
+        self.next += 1;
+
+        id
+    }
+
+    /// Returns the graphics state that applies the given alpha.
+    fn alpha(&mut self, alpha: u8) -> Ref {
+        if let Some(id) = self.alphas.get(&alpha) {
+            return *id;
+        }
+
+        let id = self.allocate();
+        let opacity = f32::from(alpha) / 255.0;
+
+        let _ = self
+            .pdf
+            .ext_graphics(id)
+            .non_stroking_alpha(opacity)
+            .stroking_alpha(opacity);
+
+        let _ = self.alphas.insert(alpha, id);
+
+        id
+    }
+
+    /// Writes an axial shading that interpolates the given color stops.
+    fn shading(&mut self, start: Point, end: Point, stops: &[ColorStop]) -> Ref {
+        let mut stops = stops.to_vec();
+
+        if let Some(first) = stops.first().copied()
+            && first.offset > 0.0
+        {
+            stops.insert(
+                0,
+                ColorStop {
+                    offset: 0.0,
+                    ..first
+                },
+            );
+        }
+
+        if let Some(last) = stops.last().copied()
+            && last.offset < 1.0
+        {
+            stops.push(ColorStop {
+                offset: 1.0,
+                ..last
+            });
+        }
+
+        let functions: Vec<Ref> = stops
+            .windows(2)
+            .map(|pair| {
+                let id = self.allocate();
+
+                let _ = self
+                    .pdf
+                    .exponential_function(id)
+                    .domain([0.0, 1.0])
+                    .c0(rgb(pair[0].color))
+                    .c1(rgb(pair[1].color))
+                    .n(1.0);
+
+                id
+            })
+            .collect();
+
+        let function = match functions.as_slice() {
+            [function] => *function,
+            _ => {
+                let id = self.allocate();
+
+                let _ = self
+                    .pdf
+                    .stitching_function(id)
+                    .domain([0.0, 1.0])
+                    .functions(functions.iter().copied())
+                    .bounds(stops[1..stops.len() - 1].iter().map(|stop| stop.offset))
+                    .encode(functions.iter().flat_map(|_| [0.0, 1.0]));
+
+                id
+            }
+        };
+
+        let id = self.allocate();
+        let mut shading = self.pdf.function_shading(id);
+
+        let _ = shading.shading_type(pdf_writer::types::FunctionShadingType::Axial);
+        shading.color_space().device_rgb();
+        let _ = shading
+            .function(function)
+            .coords([start.x, start.y, end.x, end.y])
+            .extend([true, true]);
+
+        shading.finish();
+
+        id
+    }
+}
+
+/// The contents and the resources of a page.
+struct Canvas {
+    content: Content,
+    alphas: BTreeSet<u8>,
+    images: Vec<Ref>,
+    shadings: Vec<Ref>,
+}
+
+/// The pixmap a rasterized part of a page is drawn into.
+struct Target<'a, 'b> {
+    transformation: Transformation,
+    pixels: &'a mut tiny_skia::PixmapMut<'b>,
+    clip_mask: &'a mut tiny_skia::Mask,
+    clip_bounds: Rectangle,
+}
+
+impl Canvas {
+    fn new() -> Self {
+        Self {
+            content: Content::new(),
+            alphas: BTreeSet::new(),
+            images: Vec::new(),
+            shadings: Vec::new(),
+        }
+    }
+
+    fn clip(&mut self, bounds: Rectangle) {
+        let _ = self
+            .content
+            .rect(bounds.x, bounds.y, bounds.width, bounds.height)
+            .clip_nonzero()
+            .end_path();
+    }
+
+    /// Fills the path built by `path` with the given color.
+    fn fill(&mut self, document: &mut Document, color: Color, path: impl FnOnce(&mut Content)) {
+        self.paint(document, color, |content| {
+            let [r, g, b] = rgb(color);
+
+            let _ = content.set_fill_rgb(r, g, b);
+            path(content);
+            let _ = content.fill_nonzero();
+        });
+    }
+
+    /// Strokes the path built by `path` with the given color and width.
+    fn stroke(
+        &mut self,
+        document: &mut Document,
+        color: Color,
+        width: f32,
+        path: impl FnOnce(&mut Content),
+    ) {
+        self.paint(document, color, |content| {
+            let [r, g, b] = rgb(color);
+
+            let _ = content.set_stroke_rgb(r, g, b).set_line_width(width);
+            path(content);
+            let _ = content.stroke();
+        });
+    }
+
+    fn paint(&mut self, document: &mut Document, color: Color, draw: impl FnOnce(&mut Content)) {
+        let alpha = (color.a.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        if alpha == u8::MAX {
+            draw(&mut self.content);
+            return;
+        }
+
+        let _ = document.alpha(alpha);
+        let _ = self.alphas.insert(alpha);
+
+        let _ = self.content.save_state();
+        let _ = self
+            .content
+            .set_parameters(Name(format!("Gs{alpha}").as_bytes()));
+        draw(&mut self.content);
+        let _ = self.content.restore_state();
+    }
+
+    /// Draws a [`Quad`] with vector graphics.
+    ///
+    /// Returns false if the [`Quad`] needs to be rasterized instead.
+    fn quad(&mut self, document: &mut Document, quad: &Quad, background: &Background) -> bool {
+        let mut radii = <[f32; 4]>::from(quad.border.radius);
+
+        for radius in &mut radii {
+            *radius = radius
+                .min(quad.bounds.width / 2.0)
+                .min(quad.bounds.height / 2.0);
+        }
+
+        let path = engine::rounded_rectangle(quad.bounds, radii);
+
+        match background {
+            Background::Color(color) => {
+                if color.a > 0.0 {
+                    self.fill(document, *color, |content| append(content, &path));
+                }
+            }
+            Background::Gradient(Gradient::Linear(linear)) => {
+                if !self.gradient(document, quad.bounds, linear, &path) {
+                    return false;
+                }
+            }
+        }
+
+        let width = quad
+            .border
+            .width
+            .min(quad.bounds.width / 2.0)
+            .min(quad.bounds.height / 2.0);
+
+        if width > 0.0 && quad.border.color.a > 0.0 {
+            let bounds = Rectangle {
+                x: quad.bounds.x + width / 2.0,
+                y: quad.bounds.y + width / 2.0,
+                width: quad.bounds.width - width,
+                height: quad.bounds.height - width,
+            };
+
+            for radius in &mut radii {
+                *radius = (*radius - width / 2.0)
+                    .max(0.0)
+                    .min(bounds.width / 2.0)
+                    .min(bounds.height / 2.0);
+            }
+
+            let path = engine::rounded_rectangle(bounds, radii);
+
+            self.stroke(document, quad.border.color, width, |content| {
+                append(content, &path);
+            });
+        }
+
+        true
+    }
+
+    /// Fills a path with a [`Linear`] gradient.
+    ///
+    /// Returns false if the gradient is translucent, since shadings
+    /// cannot be blended by themselves.
+    fn gradient(
+        &mut self,
+        document: &mut Document,
+        bounds: Rectangle,
+        linear: &Linear,
+        path: &tiny_skia::Path,
+    ) -> bool {
+        let stops: Vec<ColorStop> = linear.stops.into_iter().flatten().collect();
+
+        if stops.is_empty() {
+            return true;
+        }
+
+        if stops.iter().any(|stop| stop.color.a < 1.0) {
+            return false;
+        }
+
+        let (start, end) = linear.angle.to_distance(&bounds);
+        let shading = document.shading(start, end, &stops);
+
+        self.shadings.push(shading);
+
+        let name = format!("Sh{}", self.shadings.len() - 1);
+
+        let _ = self.content.save_state();
+        append(&mut self.content, path);
+        let _ = self.content.clip_nonzero().end_path();
+        let _ = self.content.shading(Name(name.as_bytes()));
+        let _ = self.content.restore_state();
+
+        true
+    }
+
+    /// Rasterizes the contents drawn by `draw` inside of the given bounds
+    /// and places them on the page as an image.
+    fn raster(
+        &mut self,
+        document: &mut Document,
+        engine: &mut Engine,
+        bounds: Rectangle,
+        draw: impl FnOnce(&mut Engine, Target<'_, '_>),
+    ) {
+        let width = (bounds.width * RASTER_SCALE).ceil() as u32;
+        let height = (bounds.height * RASTER_SCALE).ceil() as u32;
+
+        let (Some(mut pixmap), Some(mut clip_mask)) = (
+            tiny_skia::Pixmap::new(width, height),
+            tiny_skia::Mask::new(width, height),
+        ) else {
+            return;
+        };
+
+        let clip_bounds = Rectangle::with_size(Size::new(width as f32, height as f32));
+
+        engine::adjust_clip_mask(&mut clip_mask, clip_bounds);
+
+        draw(
+            engine,
+            Target {
+                transformation: Transformation::scale(RASTER_SCALE)
+                    * Transformation::translate(-bounds.x, -bounds.y),
+                pixels: &mut pixmap.as_mut(),
+                clip_mask: &mut clip_mask,
+                clip_bounds,
+            },
+        );
+
+        if pixmap.pixels().iter().all(|pixel| pixel.alpha() == 0) {
+            return;
+        }
+
+        let mut colors = Vec::with_capacity(pixmap.pixels().len() * 3);
+        let mut alphas = Vec::with_capacity(pixmap.pixels().len());
+
+        // The engine draws in BGRA
+        for pixel in pixmap.pixels() {
+            let color = pixel.demultiply();
+
+            colors.extend([color.blue(), color.green(), color.red()]);
+            alphas.push(color.alpha());
+        }
+
+        let image = document.allocate();
+        let mask = document.allocate();
+
+        let mut xobject = document.pdf.image_xobject(image, &colors);
+        let _ = xobject
+            .width(width as i32)
+            .height(height as i32)
+            .bits_per_component(8)
+            .s_mask(mask);
+        xobject.color_space().device_rgb();
+        xobject.finish();
+
+        let mut xobject = document.pdf.image_xobject(mask, &alphas);
+        let _ = xobject
+            .width(width as i32)
+            .height(height as i32)
+            .bits_per_component(8);
+        xobject.color_space().device_gray();
+        xobject.finish();
+
+        self.images.push(image);
+
+        let name = format!("Im{}", self.images.len() - 1);
+
+        let _ = self.content.save_state();
+        let _ = self.content.transform([
+            width as f32 / RASTER_SCALE,
+            0.0,
+            0.0,
+            -(height as f32 / RASTER_SCALE),
+            bounds.x,
+            bounds.y + height as f32 / RASTER_SCALE,
+        ]);
+        let _ = self.content.x_object(Name(name.as_bytes()));
+        let _ = self.content.restore_state();
+    }
+
+    /// Draws some [`Text`] with the outlines of its glyphs.
+    fn text(
+        &mut self,
+        document: &mut Document,
+        text_cache: &mut cache::Cache,
+        text: &Text,
+        transformation: Transformation,
+        bounds: Rectangle,
+    ) {
+        let mut font_system = font_system().write().expect("Write font system");
+        let font_system = font_system.raw();
+
+        let (buffer, position, color, clip_bounds, transformation) = match text {
+            Text::Paragraph {
+                paragraph,
+                position,
+                color,
+                clip_bounds,
+                transformation: local_transformation,
+            } => {
+                let Some(paragraph) = paragraph.upgrade() else {
+                    return;
+                };
+
+                (
+                    Buffer::Shared(paragraph),
+                    *position,
+                    *color,
+                    *clip_bounds,
+                    transformation * *local_transformation,
+                )
+            }
+            Text::Editor {
+                editor,
+                position,
+                color,
+                clip_bounds,
+                transformation: local_transformation,
+            } => {
+                let Some(editor) = editor.upgrade() else {
+                    return;
+                };
+
+                (
+                    Buffer::Editor(editor),
+                    *position,
+                    *color,
+                    *clip_bounds,
+                    transformation * *local_transformation,
+                )
+            }
+            Text::Cached {
+                content,
+                bounds: text_bounds,
+                color,
+                size,
+                line_height,
+                font,
+                align_x,
+                align_y,
+                shaping,
+                clip_bounds,
+            } => {
+                let key = cache::Key {
+                    bounds: text_bounds.size(),
+                    content,
+                    font: *font,
+                    size: f32::from(*size),
+                    line_height: f32::from(*line_height),
+                    shaping: *shaping,
+                    align_x: *align_x,
+                };
+
+                let (hash, entry) = text_cache.allocate(font_system, key);
+
+                let x = match align_x {
+                    Alignment::Default | Alignment::Left | Alignment::Justified => text_bounds.x,
+                    Alignment::Center => text_bounds.x - entry.min_bounds.width / 2.0,
+                    Alignment::Right => text_bounds.x - entry.min_bounds.width,
+                };
+
+                let y = match align_y {
+                    alignment::Vertical::Top => text_bounds.y,
+                    alignment::Vertical::Center => text_bounds.y - entry.min_bounds.height / 2.0,
+                    alignment::Vertical::Bottom => text_bounds.y - entry.min_bounds.height,
+                };
+
+                (
+                    Buffer::Cached(hash),
+                    Point::new(x, y),
+                    *color,
+                    *clip_bounds,
+                    transformation,
+                )
+            }
+            Text::Raw {
+                raw,
+                transformation: local_transformation,
+            } => {
+                let Some(buffer) = raw.buffer.upgrade() else {
+                    return;
+                };
+
+                (
+                    Buffer::Raw(buffer),
+                    raw.position,
+                    raw.color,
+                    raw.clip_bounds,
+                    transformation * *local_transformation,
+                )
+            }
+        };
+
+        let Some(clip_bounds) = (clip_bounds * transformation).intersection(&bounds) else {
+            return;
+        };
+
+        let Some(buffer) = (match &buffer {
+            Buffer::Shared(paragraph) => Some(paragraph.buffer()),
+            Buffer::Editor(editor) => Some(editor.buffer()),
+            Buffer::Cached(hash) => text_cache.get(hash).map(|entry| &entry.buffer),
+            Buffer::Raw(buffer) => Some(buffer.as_ref()),
+        }) else {
+            return;
+        };
+
+        let translation = transformation.translation();
+        let scale = transformation.scale_factor();
+
+        let _ = self.content.save_state();
+        self.clip(clip_bounds);
+        let _ = self
+            .content
+            .transform([scale, 0.0, 0.0, scale, translation.x, translation.y]);
+
+        for run in buffer.layout_runs() {
+            for glyph in run.glyphs {
+                let physical = glyph.physical((0.0, 0.0), 1.0);
+
+                let Some(commands) = document
+                    .swash
+                    .get_outline_commands(font_system, physical.cache_key)
+                else {
+                    continue;
+                };
+
+                if commands.is_empty() {
+                    continue;
+                }
+
+                let commands = commands.to_vec();
+                let color = glyph
+                    .color_opt
+                    .map(crate::text::from_color)
+                    .unwrap_or(color);
+
+                let origin = Point::new(
+                    position.x + glyph.x + glyph.font_size * glyph.x_offset,
+                    position.y + run.line_y + glyph.y - glyph.font_size * glyph.y_offset,
+                );
+
+                self.fill(document, color, |content| {
+                    outline(content, origin, &commands)
+                });
+            }
+        }
+
+        let _ = self.content.restore_state();
+    }
+
+    fn finish(self, document: &mut Document, id: Ref, tree: Ref, size: Size) {
+        let content = document.allocate();
+        let _ = document.pdf.stream(content, &self.content.finish());
+
+        let mut page = document.pdf.page(id);
+
+        let _ = page
+            .media_box(Rect::new(0.0, 0.0, size.width, size.height))
+            .parent(tree)
+            .contents(content);
+
+        let mut resources = page.resources();
+
+        if !self.images.is_empty() {
+            let mut x_objects = resources.x_objects();
+
+            for (i, image) in self.images.iter().enumerate() {
+                let _ = x_objects.pair(Name(format!("Im{i}").as_bytes()), *image);
+            }
+        }
+
+        if !self.shadings.is_empty() {
+            let mut shadings = resources.shadings();
+
+            for (i, shading) in self.shadings.iter().enumerate() {
+                let _ = shadings.pair(Name(format!("Sh{i}").as_bytes()), *shading);
+            }
+        }
+
+        if !self.alphas.is_empty() {
+            let mut states = resources.ext_g_states();
+
+            for alpha in &self.alphas {
+                let _ = states.pair(
+                    Name(format!("Gs{alpha}").as_bytes()),
+                    document.alphas[alpha],
+                );
+            }
+        }
+    }
+}
+
+/// The source of the buffer of some [`Text`].
+enum Buffer {
+    Shared(crate::graphics::text::Paragraph),
+    Editor(crate::graphics::text::Editor),
+    Cached(cache::KeyHash),
+    Raw(std::sync::Arc<cosmic_text::Buffer>),
+}
+
+/// Appends a [`tiny_skia::Path`] to the current path.
+fn append(content: &mut Content, path: &tiny_skia::Path) {
+    let mut current = tiny_skia::Point::zero();
+
+    for segment in path.segments() {
+        let _ = match segment {
+            tiny_skia::PathSegment::MoveTo(point) => {
+                current = point;
+                content.move_to(point.x, point.y)
+            }
+            tiny_skia::PathSegment::LineTo(point) => {
+                current = point;
+                content.line_to(point.x, point.y)
+            }
+            tiny_skia::PathSegment::QuadTo(control, point) => {
+                let (a, b) = cubic(current, control, point);
+                current = point;
+
+                content.cubic_to(a.x, a.y, b.x, b.y, point.x, point.y)
+            }
+            tiny_skia::PathSegment::CubicTo(a, b, point) => {
+                current = point;
+                content.cubic_to(a.x, a.y, b.x, b.y, point.x, point.y)
+            }
+            tiny_skia::PathSegment::Close => content.close_path(),
+        };
+    }
+}
+
+/// Appends the outline of a glyph placed at the given origin.
+///
+/// Outlines point upwards, so their vertical axis is flipped.
+fn outline(content: &mut Content, origin: Point, commands: &[cosmic_text::Command]) {
+    let at = |x: f32, y: f32| tiny_skia::Point::from_xy(origin.x + x, origin.y - y);
+
+    let mut current = tiny_skia::Point::from_xy(origin.x, origin.y);
+
+    for command in commands {
+        let _ = match *command {
+            cosmic_text::Command::MoveTo(point) => {
+                current = at(point.x, point.y);
+                content.move_to(current.x, current.y)
+            }
+            cosmic_text::Command::LineTo(point) => {
+                current = at(point.x, point.y);
+                content.line_to(current.x, current.y)
+            }
+            cosmic_text::Command::CurveTo(a, b, point) => {
+                let (a, b) = (at(a.x, a.y), at(b.x, b.y));
+                current = at(point.x, point.y);
+
+                content.cubic_to(a.x, a.y, b.x, b.y, current.x, current.y)
+            }
+            cosmic_text::Command::QuadTo(control, point) => {
+                let control = at(control.x, control.y);
+                let end = at(point.x, point.y);
+                let (a, b) = cubic(current, control, end);
+                current = end;
+
+                content.cubic_to(a.x, a.y, b.x, b.y, end.x, end.y)
+            }
+            cosmic_text::Command::Close => content.close_path(),
+        };
+    }
+}
+
+/// Returns the control points of the cubic Bézier curve equivalent to the
+/// given quadratic one, since PDF only supports the former.
+fn cubic(
+    start: tiny_skia::Point,
+    control: tiny_skia::Point,
+    end: tiny_skia::Point,
+) -> (tiny_skia::Point, tiny_skia::Point) {
+    (
+        tiny_skia::Point::from_xy(
+            start.x + 2.0 / 3.0 * (control.x - start.x),
+            start.y + 2.0 / 3.0 * (control.y - start.y),
+        ),
+        tiny_skia::Point::from_xy(
+            end.x + 2.0 / 3.0 * (control.x - end.x),
+            end.y + 2.0 / 3.0 * (control.y - end.y),
+        ),
+    )
+}
+
+fn rgb(color: Color) -> [f32; 3] {
+    [color.r, color.g, color.b]
+}
@@ -15,6 +15,7 @@ use std::collections::hash_map;
 pub struct Pipeline {
     glyph_cache: GlyphCache,
     cache: RefCell<Cache>,
+    trimmer: paragraph::Trimmer,
 }
 
 impl Pipeline {
@@ -22,13 +23,14 @@ impl Pipeline {
         Pipeline {
             glyph_cache: GlyphCache::new(),
             cache: RefCell::new(Cache::new()),
+            trimmer: paragraph::Trimmer::new(),
         }
     }
 
     // TODO: Shared engine
     #[allow(dead_code)]
     pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
-        font_system()
+        let _ = font_system()
             .write()
             .expect("Write font system")
             .load_font(bytes);
@@ -175,6 +177,7 @@ impl Pipeline {
     pub fn trim_cache(&mut self) {
         self.cache.get_mut().trim();
         self.glyph_cache.trim();
+        self.trimmer.trim();
     }
 }
 
@@ -227,7 +230,7 @@ fn draw(
     }
 }
 
-fn from_color(color: cosmic_text::Color) -> Color {
+pub fn from_color(color: cosmic_text::Color) -> Color {
     let [r, g, b, a] = color.as_rgba();
 
     Color::from_rgba8(r, g, b, a as f32 / 255.0)
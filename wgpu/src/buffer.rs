@@ -4,14 +4,18 @@ use std::ops::RangeBounds;
 
 pub const MAX_WRITE_SIZE: usize = 100 * 1024;
 
+/// The amount of resizes a [`Buffer`] must stay oversized for before shrinking.
+const SHRINK_AFTER: usize = 120;
+
 const MAX_WRITE_SIZE_U64: NonZeroU64 =
     NonZeroU64::new(MAX_WRITE_SIZE as u64).expect("MAX_WRITE_SIZE must be non-zero");
 
 #[derive(Debug)]
 pub struct Buffer<T> {
     label: &'static str,
-    size: u64,
+    capacity: Capacity,
     usage: wgpu::BufferUsages,
+    reallocations: usize,
     pub(crate) raw: wgpu::Buffer,
     type_: PhantomData<T>,
 }
@@ -23,7 +27,7 @@ impl<T: bytemuck::Pod> Buffer<T> {
         amount: usize,
         usage: wgpu::BufferUsages,
     ) -> Self {
-        let size = next_copy_size::<T>(amount);
+        let size = next_copy_size::<T>(amount.max(1));
 
         let raw = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some(label),
@@ -34,30 +38,42 @@ impl<T: bytemuck::Pod> Buffer<T> {
 
         Self {
             label,
-            size,
+            capacity: Capacity::new(size),
             usage,
+            reallocations: 0,
             raw,
             type_: PhantomData,
         }
     }
 
+    /// Makes room for `new_count` items, growing the [`Buffer`] if needed.
+    ///
+    /// A [`Buffer`] that has only needed a quarter of its size for a while
+    /// is shrunk back to the largest size it needed in the meantime; but
+    /// never below the size it was created with.
+    ///
+    /// Returns `true` if the [`Buffer`] was reallocated; its contents are lost.
     pub fn resize(&mut self, device: &wgpu::Device, new_count: usize) -> bool {
-        let new_size = next_copy_size::<T>(new_count);
+        let Some(size) = self.capacity.resize(next_copy_size::<T>(new_count)) else {
+            return false;
+        };
 
-        if self.size < new_size {
-            self.raw = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some(self.label),
-                size: new_size,
-                usage: self.usage,
-                mapped_at_creation: false,
-            });
+        self.raw = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(self.label),
+            size,
+            usage: self.usage,
+            mapped_at_creation: false,
+        });
 
-            self.size = new_size;
+        self.reallocations += 1;
 
-            true
-        } else {
-            false
-        }
+        true
+    }
+
+    /// Returns the amount of reallocations of the [`Buffer`] since the last
+    /// call and resets it.
+    pub fn take_reallocations(&mut self) -> usize {
+        std::mem::take(&mut self.reallocations)
     }
 
     /// Returns the size of the written bytes.
@@ -117,9 +133,78 @@ impl<T: bytemuck::Pod> Buffer<T> {
     }
 }
 
+/// The size bookkeeping of a [`Buffer`].
+#[derive(Debug)]
+struct Capacity {
+    size: u64,
+    minimum: u64,
+    peak: u64,
+    resizes: usize,
+}
+
+impl Capacity {
+    fn new(size: u64) -> Self {
+        Self {
+            size,
+            minimum: size,
+            peak: 0,
+            resizes: 0,
+        }
+    }
+
+    /// Returns the new size of the buffer, if it needs to be reallocated
+    /// to fit `new_size` bytes.
+    fn resize(&mut self, new_size: u64) -> Option<u64> {
+        self.peak = self.peak.max(new_size).max(self.minimum);
+        self.resizes += 1;
+
+        let size = if self.size < new_size {
+            Some(new_size)
+        } else if self.resizes >= SHRINK_AFTER && self.peak <= self.size / 4 {
+            Some(self.peak)
+        } else {
+            None
+        };
+
+        if self.resizes >= SHRINK_AFTER {
+            self.peak = 0;
+            self.resizes = 0;
+        }
+
+        if let Some(size) = size {
+            self.size = size;
+        }
+
+        size
+    }
+}
+
 fn next_copy_size<T>(amount: usize) -> u64 {
     let align_mask = wgpu::COPY_BUFFER_ALIGNMENT - 1;
 
     (((std::mem::size_of::<T>() * amount).next_power_of_two() as u64 + align_mask) & !align_mask)
         .max(wgpu::COPY_BUFFER_ALIGNMENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_never_shrinks_below_its_initial_size() {
+        let initial = next_copy_size::<[f32; 16]>(1);
+        let mut capacity = Capacity::new(initial);
+
+        assert_eq!(
+            capacity.resize(next_copy_size::<[f32; 16]>(100)),
+            Some(next_copy_size::<[f32; 16]>(100))
+        );
+
+        let sizes: Vec<_> = (0..SHRINK_AFTER * 2)
+            .filter_map(|_| capacity.resize(next_copy_size::<[f32; 16]>(0)))
+            .collect();
+
+        assert_eq!(sizes, vec![initial]);
+        assert_eq!(capacity.size, initial);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::graphics::{Antialiasing, Shell};
+use crate::graphics::{Antialiasing, Settings, Shell};
 use crate::primitive;
 use crate::quad;
 use crate::text;
@@ -23,12 +23,37 @@ pub struct Engine {
 
 impl Engine {
     pub fn new(
-        _adapter: &wgpu::Adapter,
+        adapter: &wgpu::Adapter,
         device: wgpu::Device,
         queue: wgpu::Queue,
         format: wgpu::TextureFormat,
         antialiasing: Option<Antialiasing>, // TODO: Initialize AA pipelines lazily
         shell: Shell,
+    ) -> Self {
+        Self::with_image_cache_size(
+            adapter,
+            device,
+            queue,
+            format,
+            antialiasing,
+            Settings::default().image_cache_size,
+            shell,
+        )
+    }
+
+    /// Creates a new [`Engine`] whose renderers keep up to the given amount
+    /// of bytes of decoded images in memory.
+    ///
+    /// Every renderer has its own image cache; so each window decodes its
+    /// images in its own worker and within its own budget.
+    pub fn with_image_cache_size(
+        _adapter: &wgpu::Adapter,
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<Antialiasing>,
+        _image_cache_size: usize,
+        shell: Shell,
     ) -> Self {
         Self {
             format,
@@ -41,7 +66,7 @@ impl Engine {
             image_pipeline: {
                 let backend = _adapter.get_info().backend;
 
-                crate::image::Pipeline::new(&device, format, backend)
+                crate::image::Pipeline::new(&device, format, backend, _image_cache_size)
             },
 
             primitive_storage: Arc::new(RwLock::new(primitive::Storage::default())),
@@ -27,9 +27,17 @@ impl Cache {
         backend: wgpu::Backend,
         layout: wgpu::BindGroupLayout,
         _shell: &Shell,
+        _decoded_size: usize,
     ) -> Self {
         #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
-        let worker = Worker::new(device, _queue, backend, layout.clone(), _shell);
+        let worker = Worker::new(
+            device,
+            _queue,
+            backend,
+            layout.clone(),
+            _shell,
+            _decoded_size,
+        );
 
         Self {
             atlas: Atlas::new(device, backend, layout),
@@ -56,8 +64,12 @@ impl Cache {
 
         let callback = Box::new(callback);
 
-        if let Some(callbacks) = self.raster.pending.get_mut(&handle.id()) {
-            callbacks.push(callback);
+        if let Some(pending) = self.raster.pending.get_mut(&handle.id()) {
+            pending.callbacks.push(callback);
+
+            #[cfg(not(target_arch = "wasm32"))]
+            pending.prioritize(&self.worker, handle, Priority::Visible);
+
             return;
         }
 
@@ -81,10 +93,13 @@ impl Cache {
             return;
         }
 
-        let _ = self.raster.pending.insert(handle.id(), vec![callback]);
+        let _ = self
+            .raster
+            .pending
+            .insert(handle.id(), Pending::new(vec![callback], Priority::Visible));
 
         #[cfg(not(target_arch = "wasm32"))]
-        self.worker.load(handle, true);
+        self.worker.load(handle, Priority::Visible, true);
     }
 
     #[cfg(feature = "image")]
@@ -176,6 +191,7 @@ impl Cache {
             &self.worker,
             handle,
             None,
+            Priority::Background,
         )?;
 
         Some(image.dimensions())
@@ -194,19 +210,27 @@ impl Cache {
         encoder: &mut wgpu::CommandEncoder,
         belt: &mut wgpu::util::StagingBelt,
         handle: &core::image::Handle,
+        priority: Priority,
     ) -> Option<(&atlas::Entry, &Arc<wgpu::BindGroup>)> {
         use crate::image::raster::Memory;
 
         self.receive();
 
-        let memory = load_image(
+        let Some(memory) = load_image(
             &mut self.raster.cache,
             &mut self.raster.pending,
             #[cfg(not(target_arch = "wasm32"))]
             &self.worker,
             handle,
             None,
-        )?;
+            priority,
+        ) else {
+            if let Some(pending) = self.raster.pending.get_mut(&handle.id()) {
+                pending.draw();
+            }
+
+            return None;
+        };
 
         if let Memory::Device {
             entry, bind_group, ..
@@ -239,8 +263,13 @@ impl Cache {
             }
         }
 
-        if !self.raster.pending.contains_key(&handle.id()) {
-            let _ = self.raster.pending.insert(handle.id(), Vec::new());
+        if let Some(pending) = self.raster.pending.get_mut(&handle.id()) {
+            pending.draw();
+        } else {
+            let mut pending = Pending::new(Vec::new(), priority);
+            pending.draw();
+
+            let _ = self.raster.pending.insert(handle.id(), pending);
 
             #[cfg(not(target_arch = "wasm32"))]
             self.worker.upload(handle, image);
@@ -279,6 +308,20 @@ impl Cache {
         #[cfg(feature = "image")]
         {
             self.receive();
+
+            // Cancel the loads of the images that went offscreen before
+            // they could be decoded
+            self.raster.pending.retain(|_id, pending| {
+                if !pending.is_abandoned() {
+                    return true;
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                self.worker.cancel(*_id);
+
+                false
+            });
+
             self.raster.cache.trim(&mut self.atlas, |_bind_group| {
                 #[cfg(not(target_arch = "wasm32"))]
                 self.worker.drop(_bind_group);
@@ -301,7 +344,11 @@ impl Cache {
                     entry,
                     bind_group,
                 } => {
-                    let callbacks = self.raster.pending.remove(&handle.id());
+                    let callbacks = self
+                        .raster
+                        .pending
+                        .remove(&handle.id())
+                        .map(|pending| pending.callbacks);
 
                     let allocation = if let Some(callbacks) = callbacks {
                         #[allow(unsafe_code)]
@@ -328,7 +375,11 @@ impl Cache {
                     );
                 }
                 worker::Work::Error { handle, error } => {
-                    let callbacks = self.raster.pending.remove(&handle.id());
+                    let callbacks = self
+                        .raster
+                        .pending
+                        .remove(&handle.id())
+                        .map(|pending| pending.callbacks);
 
                     if let Some(callbacks) = callbacks {
                         for callback in callbacks {
@@ -353,20 +404,79 @@ impl Drop for Cache {
 #[cfg(feature = "image")]
 struct Raster {
     cache: crate::image::raster::Cache,
-    pending: HashMap<core::image::Id, Vec<Callback>>,
+    pending: HashMap<core::image::Id, Pending>,
     belt: wgpu::util::StagingBelt,
 }
 
+/// The priority of an image that is being loaded.
+#[cfg(feature = "image")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// The image is only needed for layout, or it is out of sight.
+    Background,
+    /// The image is visible or it was explicitly allocated.
+    Visible,
+}
+
+#[cfg(feature = "image")]
+struct Pending {
+    callbacks: Vec<Callback>,
+    priority: Priority,
+    is_drawn: bool,
+    is_requested: bool,
+}
+
+#[cfg(feature = "image")]
+impl Pending {
+    fn new(callbacks: Vec<Callback>, priority: Priority) -> Self {
+        Self {
+            callbacks,
+            priority,
+            is_drawn: false,
+            is_requested: true,
+        }
+    }
+
+    fn draw(&mut self) {
+        self.is_drawn = true;
+        self.is_requested = true;
+    }
+
+    /// Returns whether the load was drawn and then nobody asked for it since
+    /// the last time this was called.
+    ///
+    /// Loads that were never drawn are never abandoned; since they are needed
+    /// to measure images whose size is still unknown.
+    fn is_abandoned(&mut self) -> bool {
+        let is_abandoned = self.is_drawn && !self.is_requested && self.callbacks.is_empty();
+
+        self.is_requested = false;
+
+        is_abandoned
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn prioritize(&mut self, worker: &Worker, handle: &core::image::Handle, priority: Priority) {
+        self.is_requested = true;
+
+        if priority > self.priority {
+            self.priority = priority;
+            worker.prioritize(handle.id());
+        }
+    }
+}
+
 #[cfg(feature = "image")]
 type Callback = Box<dyn FnOnce(Result<core::image::Allocation, core::image::Error>) + Send>;
 
 #[cfg(feature = "image")]
 fn load_image<'a>(
     cache: &'a mut crate::image::raster::Cache,
-    pending: &mut HashMap<core::image::Id, Vec<Callback>>,
+    pending: &mut HashMap<core::image::Id, Pending>,
     #[cfg(not(target_arch = "wasm32"))] worker: &Worker,
     handle: &core::image::Handle,
     callback: Option<Callback>,
+    priority: Priority,
 ) -> Option<&'a mut crate::image::raster::Memory> {
     use crate::image::raster::Memory;
 
@@ -377,11 +487,17 @@ fn load_image<'a>(
         } else if let core::image::Handle::Rgba { .. } = handle {
             // Load RGBA handles synchronously, since it's very cheap
             cache.insert(handle, Memory::load(handle));
-        } else if !pending.contains_key(&handle.id()) {
-            let _ = pending.insert(handle.id(), Vec::from_iter(callback));
+        } else if let Some(_pending) = pending.get_mut(&handle.id()) {
+            #[cfg(not(target_arch = "wasm32"))]
+            _pending.prioritize(worker, handle, priority);
+        } else {
+            let _ = pending.insert(
+                handle.id(),
+                Pending::new(Vec::from_iter(callback), priority),
+            );
 
             #[cfg(not(target_arch = "wasm32"))]
-            worker.load(handle, false);
+            worker.load(handle, priority, false);
         }
     }
 
@@ -390,19 +506,21 @@ fn load_image<'a>(
 
 #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
 mod worker {
+    use super::Priority;
     use crate::core::Bytes;
     use crate::core::image;
     use crate::graphics::Shell;
     use crate::image::atlas::{self, Atlas};
     use crate::image::raster;
 
-    use std::sync::Arc;
+    use rustc_hash::FxHashMap;
+    use std::collections::VecDeque;
     use std::sync::mpsc;
+    use std::sync::{Arc, Condvar, Mutex};
     use std::thread;
 
     pub struct Worker {
-        jobs: mpsc::SyncSender<Job>,
-        quit: mpsc::SyncSender<()>,
+        queue: Arc<Queue>,
         work: mpsc::Receiver<Work>,
         handle: Option<std::thread::JoinHandle<()>>,
     }
@@ -414,9 +532,9 @@ mod worker {
             backend: wgpu::Backend,
             texture_layout: wgpu::BindGroupLayout,
             shell: &Shell,
+            decoded_size: usize,
         ) -> Self {
-            let (jobs_sender, jobs_receiver) = mpsc::sync_channel(1_000);
-            let (quit_sender, quit_receiver) = mpsc::sync_channel(1);
+            let jobs = Arc::new(Queue::default());
             let (work_sender, work_receiver) = mpsc::sync_channel(1_000);
 
             let instance = Instance {
@@ -426,30 +544,40 @@ mod worker {
                 texture_layout,
                 shell: shell.clone(),
                 belt: wgpu::util::StagingBelt::new(4 * 1024 * 1024),
-                jobs: jobs_receiver,
+                jobs: jobs.clone(),
                 output: work_sender,
-                quit: quit_receiver,
+                decoded: Decoded::new(decoded_size),
             };
 
             let handle = thread::spawn(move || instance.run());
 
             Self {
-                jobs: jobs_sender,
-                quit: quit_sender,
+                queue: jobs,
                 work: work_receiver,
                 handle: Some(handle),
             }
         }
 
-        pub fn load(&self, handle: &image::Handle, is_allocation: bool) {
-            let _ = self.jobs.send(Job::Load {
-                handle: handle.clone(),
-                is_allocation,
-            });
+        pub fn load(&self, handle: &image::Handle, priority: Priority, is_allocation: bool) {
+            self.queue.load(
+                Load {
+                    handle: handle.clone(),
+                    is_allocation,
+                },
+                priority,
+            );
+        }
+
+        pub fn prioritize(&self, id: image::Id) {
+            self.queue.prioritize(id);
+        }
+
+        pub fn cancel(&self, id: image::Id) {
+            self.queue.cancel(id);
         }
 
         pub fn upload(&self, handle: &image::Handle, image: raster::Image) {
-            let _ = self.jobs.send(Job::Upload {
+            self.queue.push(Job::Upload {
                 handle: handle.clone(),
                 width: image.width(),
                 height: image.height(),
@@ -458,7 +586,7 @@ mod worker {
         }
 
         pub fn drop(&self, bind_group: Arc<wgpu::BindGroup>) {
-            let _ = self.jobs.send(Job::Drop(bind_group));
+            self.queue.push(Job::Drop(bind_group));
         }
 
         pub fn try_recv(&self) -> Result<Work, mpsc::TryRecvError> {
@@ -466,8 +594,7 @@ mod worker {
         }
 
         pub fn quit(&mut self) {
-            let _ = self.quit.try_send(());
-            let _ = self.jobs.send(Job::Quit);
+            self.queue.quit();
             let _ = self.handle.take().map(thread::JoinHandle::join);
         }
     }
@@ -479,17 +606,14 @@ mod worker {
         texture_layout: wgpu::BindGroupLayout,
         shell: Shell,
         belt: wgpu::util::StagingBelt,
-        jobs: mpsc::Receiver<Job>,
+        jobs: Arc<Queue>,
         output: mpsc::SyncSender<Work>,
-        quit: mpsc::Receiver<()>,
+        decoded: Decoded,
     }
 
     #[derive(Debug)]
     enum Job {
-        Load {
-            handle: image::Handle,
-            is_allocation: bool,
-        },
+        Load(Load),
         Upload {
             handle: image::Handle,
             rgba: Bytes,
@@ -497,7 +621,12 @@ mod worker {
             height: u32,
         },
         Drop(Arc<wgpu::BindGroup>),
-        Quit,
+    }
+
+    #[derive(Debug)]
+    struct Load {
+        handle: image::Handle,
+        is_allocation: bool,
     }
 
     pub enum Work {
@@ -514,47 +643,52 @@ mod worker {
 
     impl Instance {
         fn run(mut self) {
-            loop {
-                if self.quit.try_recv().is_ok() {
-                    return;
-                }
-
-                let Ok(job) = self.jobs.recv() else {
-                    return;
-                };
-
+            while let Some(job) = self.jobs.pop() {
                 match job {
-                    Job::Load {
+                    Job::Load(Load {
                         handle,
                         is_allocation,
-                    } => match crate::graphics::image::load(&handle) {
-                        Ok(image) => self.upload(
-                            handle,
-                            image.width(),
-                            image.height(),
-                            image.into_raw(),
-                            if is_allocation {
-                                Shell::tick
-                            } else {
-                                Shell::invalidate_layout
-                            },
-                        ),
-                        Err(error) => {
-                            let _ = self.output.send(Work::Error { handle, error });
+                    }) => {
+                        let callback = if is_allocation {
+                            Shell::tick
+                        } else {
+                            Shell::invalidate_layout
+                        };
+
+                        if let Some((width, height, rgba)) = self.decoded.get(handle.id()) {
+                            self.upload(handle, width, height, rgba, callback);
+                            continue;
                         }
-                    },
+
+                        match crate::graphics::image::load(&handle) {
+                            Ok(image) => {
+                                let (width, height) = image.dimensions();
+                                let rgba = image.into_raw();
+
+                                self.decoded
+                                    .insert(handle.id(), width, height, rgba.clone());
+
+                                self.upload(handle, width, height, rgba, callback);
+                            }
+                            Err(error) => {
+                                let _ = self.output.send(Work::Error { handle, error });
+                            }
+                        }
+                    }
                     Job::Upload {
                         handle,
                         rgba,
                         width,
                         height,
                     } => {
+                        self.decoded
+                            .insert(handle.id(), width, height, rgba.clone());
+
                         self.upload(handle, width, height, rgba, Shell::request_redraw);
                     }
                     Job::Drop(bind_group) => {
                         drop(bind_group);
                     }
-                    Job::Quit => return,
                 }
             }
         }
@@ -616,4 +750,175 @@ mod worker {
             });
         }
     }
+
+    /// The jobs of a [`Worker`].
+    ///
+    /// Uploads and drops run first, in order. Then, loads run by
+    /// [`Priority`]; so visible images are decoded before the rest.
+    #[derive(Default)]
+    struct Queue {
+        jobs: Mutex<Jobs>,
+        available: Condvar,
+    }
+
+    #[derive(Default)]
+    struct Jobs {
+        tasks: VecDeque<Job>,
+        visible: VecDeque<Load>,
+        background: VecDeque<Load>,
+        is_quitting: bool,
+    }
+
+    impl Queue {
+        fn push(&self, job: Job) {
+            self.jobs
+                .lock()
+                .expect("Lock image jobs")
+                .tasks
+                .push_back(job);
+            self.available.notify_one();
+        }
+
+        fn load(&self, load: Load, priority: Priority) {
+            let mut jobs = self.jobs.lock().expect("Lock image jobs");
+
+            match priority {
+                Priority::Visible => jobs.visible.push_back(load),
+                Priority::Background => jobs.background.push_back(load),
+            }
+
+            drop(jobs);
+            self.available.notify_one();
+        }
+
+        fn prioritize(&self, id: image::Id) {
+            let mut jobs = self.jobs.lock().expect("Lock image jobs");
+
+            if let Some(index) = jobs
+                .background
+                .iter()
+                .position(|load| load.handle.id() == id)
+                && let Some(load) = jobs.background.remove(index)
+            {
+                jobs.visible.push_back(load);
+            }
+        }
+
+        fn cancel(&self, id: image::Id) {
+            let mut jobs = self.jobs.lock().expect("Lock image jobs");
+
+            jobs.visible.retain(|load| load.handle.id() != id);
+            jobs.background.retain(|load| load.handle.id() != id);
+        }
+
+        fn quit(&self) {
+            self.jobs.lock().expect("Lock image jobs").is_quitting = true;
+            self.available.notify_one();
+        }
+
+        fn pop(&self) -> Option<Job> {
+            let mut jobs = self.jobs.lock().expect("Lock image jobs");
+
+            loop {
+                if jobs.is_quitting {
+                    return None;
+                }
+
+                if let Some(job) = jobs.tasks.pop_front() {
+                    return Some(job);
+                }
+
+                if let Some(load) = jobs
+                    .visible
+                    .pop_front()
+                    .or_else(|| jobs.background.pop_front())
+                {
+                    return Some(Job::Load(load));
+                }
+
+                jobs = self.available.wait(jobs).expect("Wait for image jobs");
+            }
+        }
+    }
+
+    /// The most recently decoded images, kept in memory up to a size in bytes.
+    ///
+    /// Images that are trimmed from the atlas can be uploaded again without
+    /// decoding them; which is common when scrolling back and forth.
+    struct Decoded {
+        images: FxHashMap<image::Id, (u32, u32, Bytes)>,
+        recent: VecDeque<image::Id>,
+        size: usize,
+        max_size: usize,
+    }
+
+    impl Decoded {
+        fn new(max_size: usize) -> Self {
+            Self {
+                images: FxHashMap::default(),
+                recent: VecDeque::new(),
+                size: 0,
+                max_size,
+            }
+        }
+
+        fn get(&mut self, id: image::Id) -> Option<(u32, u32, Bytes)> {
+            let image = self.images.get(&id)?.clone();
+
+            if let Some(index) = self.recent.iter().position(|recent| *recent == id) {
+                let _ = self.recent.remove(index);
+            }
+
+            self.recent.push_back(id);
+
+            Some(image)
+        }
+
+        fn insert(&mut self, id: image::Id, width: u32, height: u32, rgba: Bytes) {
+            if rgba.len() > self.max_size || self.images.contains_key(&id) {
+                return;
+            }
+
+            self.size += rgba.len();
+            let _ = self.images.insert(id, (width, height, rgba));
+            self.recent.push_back(id);
+
+            while self.size > self.max_size {
+                let Some(oldest) = self.recent.pop_front() else {
+                    break;
+                };
+
+                if let Some((_, _, rgba)) = self.images.remove(&oldest) {
+                    self.size -= rgba.len();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "image"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_keeps_measurement_loads() {
+        let mut pending = Pending::new(Vec::new(), Priority::Background);
+
+        assert!(!pending.is_abandoned());
+        assert!(!pending.is_abandoned());
+        assert!(!pending.is_abandoned());
+    }
+
+    #[test]
+    fn it_abandons_loads_that_stopped_being_drawn() {
+        let mut pending = Pending::new(Vec::new(), Priority::Visible);
+        pending.draw();
+
+        assert!(!pending.is_abandoned());
+
+        pending.draw();
+
+        assert!(!pending.is_abandoned());
+        assert!(pending.is_abandoned());
+    }
 }
@@ -31,10 +31,16 @@ pub struct Pipeline {
     linear_sampler: wgpu::Sampler,
     texture_layout: wgpu::BindGroupLayout,
     constant_layout: wgpu::BindGroupLayout,
+    decoded_size: usize,
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, backend: wgpu::Backend) -> Self {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        backend: wgpu::Backend,
+        decoded_size: usize,
+    ) -> Self {
         let nearest_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
             address_mode_v: wgpu::AddressMode::ClampToEdge,
@@ -183,6 +189,7 @@ impl Pipeline {
             linear_sampler,
             texture_layout,
             constant_layout,
+            decoded_size,
         }
     }
 
@@ -193,6 +200,7 @@ impl Pipeline {
             self.backend,
             self.texture_layout.clone(),
             shell,
+            self.decoded_size,
         )
     }
 }
@@ -242,8 +250,14 @@ impl State {
                     bounds,
                     clip_bounds,
                 } => {
+                    let priority = if bounds.intersects(clip_bounds) {
+                        cache::Priority::Visible
+                    } else {
+                        cache::Priority::Background
+                    };
+
                     if let Some((atlas_entry, bind_group)) =
-                        cache.upload_raster(device, encoder, belt, &image.handle)
+                        cache.upload_raster(device, encoder, belt, &image.handle, priority)
                     {
                         match atlas.as_mut() {
                             None => {
@@ -358,6 +372,15 @@ impl State {
         }
     }
 
+    /// Returns the amount of buffer reallocations of the [`State`] since the
+    /// last call and resets it.
+    pub fn take_reallocations(&mut self) -> usize {
+        self.layers
+            .iter_mut()
+            .map(|layer| layer.instances.take_reallocations())
+            .sum()
+    }
+
     pub fn trim(&mut self) {
         for layer in &mut self.layers[..self.prepare_layer] {
             layer.clear();
@@ -25,6 +25,34 @@ pub struct Layer {
     pending_text: Vec<Text>,
 }
 
+/// A snapshot of the contents of a [`Layer`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    /// The clip bounds of the [`Layer`].
+    pub bounds: Rectangle,
+    /// The amount of quads in the [`Layer`].
+    pub quads: usize,
+    /// The groups of meshes in the [`Layer`].
+    pub meshes: Vec<Group>,
+    /// The amount of custom primitives in the [`Layer`].
+    pub primitives: usize,
+    /// The amount of images and vector graphics in the [`Layer`].
+    pub images: usize,
+    /// The groups of text in the [`Layer`].
+    pub text: Vec<Group>,
+}
+
+/// A group of primitives sharing the same transformation in a [`Snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group {
+    /// The transformation of the [`Group`], as a column-major matrix.
+    pub transformation: [f32; 16],
+    /// The amount of primitives in the [`Group`].
+    pub count: usize,
+}
+
 impl Layer {
     pub fn is_empty(&self) -> bool {
         self.quads.is_empty()
@@ -44,6 +72,13 @@ impl Layer {
     ) {
         let bounds = quad.bounds * transformation;
 
+        let shadow_bounds = (bounds + quad.shadow.offset * transformation.scale_factor())
+            .expand(quad.shadow.blur_radius * transformation.scale_factor());
+
+        if !self.bounds.intersects(&bounds.union(&shadow_bounds)) {
+            return;
+        }
+
         let quad = Quad {
             position: [bounds.x, bounds.y],
             size: [bounds.width, bounds.height],
@@ -75,6 +110,10 @@ impl Layer {
             transformation,
         };
 
+        if !self.is_visible(&paragraph) {
+            return;
+        }
+
         self.pending_text.push(paragraph);
     }
 
@@ -94,6 +133,10 @@ impl Layer {
             transformation,
         };
 
+        if !self.is_visible(&editor) {
+            return;
+        }
+
         self.pending_text.push(editor);
     }
 
@@ -105,6 +148,13 @@ impl Layer {
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
+        let clip_bounds = clip_bounds * transformation;
+
+        // Cached text is aligned around its position, so only its clip bounds are reliable
+        if !self.bounds.intersects(&clip_bounds) {
+            return;
+        }
+
         let text = Text::Cached {
             content: text.content,
             bounds: Rectangle::new(position, text.bounds) * transformation,
@@ -115,7 +165,7 @@ impl Layer {
             align_x: text.align_x,
             align_y: text.align_y,
             shaping: text.shaping,
-            clip_bounds: clip_bounds * transformation,
+            clip_bounds,
         };
 
         self.pending_text.push(text);
@@ -156,13 +206,19 @@ impl Layer {
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
+        let clip_bounds = clip_bounds * transformation;
+
+        if !self.bounds.intersects(&clip_bounds) {
+            return;
+        }
+
         let image = Image::Raster {
             image: core::Image {
                 border_radius: image.border_radius * transformation.scale_factor(),
                 ..image
             },
             bounds: bounds * transformation,
-            clip_bounds: clip_bounds * transformation,
+            clip_bounds,
         };
 
         self.images.push(image);
@@ -175,10 +231,16 @@ impl Layer {
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
+        let clip_bounds = clip_bounds * transformation;
+
+        if !self.bounds.intersects(&clip_bounds) {
+            return;
+        }
+
         let svg = Image::Vector {
             svg,
             bounds: bounds * transformation,
-            clip_bounds: clip_bounds * transformation,
+            clip_bounds,
         };
 
         self.images.push(svg);
@@ -249,6 +311,79 @@ impl Layer {
             .push(primitive::Instance::new(bounds, primitive));
     }
 
+    /// Returns a [`Snapshot`] of the current contents of the [`Layer`].
+    pub fn snapshot(&self) -> Snapshot {
+        let mut meshes: Vec<_> = self
+            .triangles
+            .iter()
+            .map(|item| match item {
+                triangle::Item::Group {
+                    transformation,
+                    meshes,
+                } => Group {
+                    transformation: (*transformation).into(),
+                    count: meshes.len(),
+                },
+                triangle::Item::Cached {
+                    transformation,
+                    cache,
+                } => Group {
+                    transformation: (*transformation).into(),
+                    count: cache.batch().len(),
+                },
+            })
+            .collect();
+
+        if !self.pending_meshes.is_empty() {
+            meshes.push(Group {
+                transformation: Transformation::IDENTITY.into(),
+                count: self.pending_meshes.len(),
+            });
+        }
+
+        let mut text: Vec<_> = self
+            .text
+            .iter()
+            .map(|item| match item {
+                text::Item::Group {
+                    transformation,
+                    text,
+                } => Group {
+                    transformation: (*transformation).into(),
+                    count: text.len(),
+                },
+                text::Item::Cached {
+                    transformation,
+                    cache,
+                } => Group {
+                    transformation: (*transformation).into(),
+                    count: cache.len(),
+                },
+            })
+            .collect();
+
+        if !self.pending_text.is_empty() {
+            text.push(Group {
+                transformation: Transformation::IDENTITY.into(),
+                count: self.pending_text.len(),
+            });
+        }
+
+        Snapshot {
+            bounds: self.bounds,
+            quads: self.quads.len(),
+            meshes,
+            primitives: self.primitives.len(),
+            images: self.images.len(),
+            text,
+        }
+    }
+
+    fn is_visible(&self, text: &Text) -> bool {
+        text.visible_bounds()
+            .is_some_and(|bounds| self.bounds.intersects(&bounds))
+    }
+
     fn flush_meshes(&mut self) {
         if !self.pending_meshes.is_empty() {
             self.triangles.push(triangle::Item::Group {
@@ -178,6 +178,15 @@ impl Renderer {
         submission
     }
 
+    /// Returns a [`layer::Snapshot`] of every layer in the current frame.
+    ///
+    /// The layers are merged when the frame is prepared; so, after
+    /// [`present`](Self::present), the snapshot describes exactly what
+    /// was drawn.
+    pub fn snapshot(&self) -> Vec<layer::Snapshot> {
+        self.layers.iter().map(Layer::snapshot).collect()
+    }
+
     /// Renders the current surface to an offscreen buffer.
     ///
     /// Returns RGBA bytes of the texture data.
@@ -296,17 +305,53 @@ impl Renderer {
 
         self.layers.merge();
 
-        for layer in self.layers.iter() {
-            let clip_bounds = layer.bounds * scale_factor;
+        let layers: Vec<_> = self
+            .layers
+            .iter()
+            .filter(|layer| {
+                physical_bounds
+                    .intersection(&(layer.bounds * scale_factor))
+                    .and_then(Rectangle::snap)
+                    .is_some()
+            })
+            .collect();
+
+        // With `rayon`, the CPU-side buffers of the meshes are packed in
+        // parallel before the serial uploads. Otherwise, the meshes are
+        // written straight to their buffers.
+        #[cfg(feature = "rayon")]
+        let packs: Vec<_> = {
+            use rayon::prelude::*;
+
+            layers
+                .par_iter()
+                .map(|layer| triangle::pack(&layer.triangles))
+                .collect()
+        };
 
-            if physical_bounds
-                .intersection(&clip_bounds)
-                .and_then(Rectangle::snap)
-                .is_none()
-            {
-                continue;
-            }
+        #[cfg(not(feature = "rayon"))]
+        let packs = std::iter::repeat_with(Vec::new);
+
+        let mesh_bounds: Vec<_> = layers
+            .iter()
+            .filter(|layer| !layer.triangles.is_empty())
+            .filter_map(|layer| {
+                physical_bounds
+                    .intersection(&(layer.bounds * scale_factor))
+                    .and_then(Rectangle::snap)
+            })
+            .collect();
+
+        self.triangle.prepare_regions(
+            &self.engine.triangle_pipeline,
+            &self.engine.device,
+            &mut self.staging_belt,
+            encoder,
+            &mesh_bounds,
+            viewport.physical_size(),
+        );
 
+        for (layer, packs) in layers.into_iter().zip(packs) {
             if !layer.quads.is_empty() {
                 let prepare_span = debug::prepare(debug::Primitive::Quad);
 
@@ -332,6 +377,7 @@ impl Renderer {
                     &mut self.staging_belt,
                     encoder,
                     &layer.triangles,
+                    &packs,
                     Transformation::scale(scale_factor),
                     viewport.physical_size(),
                 );
@@ -408,6 +454,59 @@ impl Renderer {
     ) {
         use std::mem::ManuallyDrop;
 
+        let scale_factor = viewport.scale_factor();
+        let physical_bounds =
+            Rectangle::<f32>::from(Rectangle::with_size(viewport.physical_size()));
+
+        let scale = Transformation::scale(scale_factor);
+
+        // With multisampling, the meshes of every layer are rendered into a
+        // single multisampled attachment and resolved once, before the render
+        // pass of the target; where they are drawn without ending it
+        let multisampled = if self.triangle.is_packed() {
+            self.triangle
+                .begin_multisampled(&self.engine.triangle_pipeline, encoder)
+        } else {
+            None
+        };
+
+        if let Some(mut render_pass) = multisampled {
+            let mut mesh_layer = 0;
+            let mut mesh_region = 0;
+
+            for layer in self.layers.iter() {
+                if layer.triangles.is_empty() {
+                    continue;
+                }
+
+                let Some(physical_bounds) =
+                    physical_bounds.intersection(&(layer.bounds * scale_factor))
+                else {
+                    continue;
+                };
+
+                if physical_bounds.snap().is_none() {
+                    continue;
+                }
+
+                let render_span = debug::render(debug::Primitive::Triangle);
+
+                mesh_layer += self.triangle.render_multisampled(
+                    &self.engine.triangle_pipeline,
+                    mesh_layer,
+                    mesh_region,
+                    &layer.triangles,
+                    physical_bounds,
+                    scale,
+                    &mut render_pass,
+                );
+
+                render_span.finish();
+
+                mesh_region += 1;
+            }
+        }
+
         let mut render_pass =
             ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("iced_wgpu render pass"),
@@ -440,17 +539,12 @@ impl Renderer {
 
         let mut quad_layer = 0;
         let mut mesh_layer = 0;
+        let mut mesh_region = 0;
         let mut text_layer = 0;
 
         #[cfg(any(feature = "svg", feature = "image"))]
         let mut image_layer = 0;
 
-        let scale_factor = viewport.scale_factor();
-        let physical_bounds =
-            Rectangle::<f32>::from(Rectangle::with_size(viewport.physical_size()));
-
-        let scale = Transformation::scale(scale_factor);
-
         for layer in self.layers.iter() {
             let Some(physical_bounds) =
                 physical_bounds.intersection(&(layer.bounds * scale_factor))
@@ -477,36 +571,72 @@ impl Renderer {
             }
 
             if !layer.triangles.is_empty() {
-                let _ = ManuallyDrop::into_inner(render_pass);
-
                 let render_span = debug::render(debug::Primitive::Triangle);
-                mesh_layer += self.triangle.render(
-                    &self.engine.triangle_pipeline,
-                    encoder,
-                    frame,
-                    mesh_layer,
-                    &layer.triangles,
-                    physical_bounds,
-                    scale,
-                );
-                render_span.finish();
 
-                render_pass =
-                    ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some("iced_wgpu render pass"),
-                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                            view: frame,
-                            depth_slice: None,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Load,
-                                store: wgpu::StoreOp::Store,
-                            },
-                        })],
-                        depth_stencil_attachment: None,
-                        timestamp_writes: None,
-                        occlusion_query_set: None,
-                    }));
+                if !self.triangle.is_multisampled() {
+                    mesh_layer += self.triangle.render(
+                        &self.engine.triangle_pipeline,
+                        mesh_layer,
+                        &layer.triangles,
+                        physical_bounds,
+                        scale,
+                        &mut render_pass,
+                    );
+                } else if self.triangle.is_packed() {
+                    self.triangle.resolve(
+                        &self.engine.triangle_pipeline,
+                        mesh_region,
+                        &mut render_pass,
+                    );
+
+                    mesh_region += 1;
+                } else {
+                    // The regions did not fit in the multisampled attachment;
+                    // so every layer is rendered and resolved on its own
+                    let _ = ManuallyDrop::into_inner(render_pass);
+
+                    if let Some(mut multisampled) = self
+                        .triangle
+                        .begin_multisampled(&self.engine.triangle_pipeline, encoder)
+                    {
+                        mesh_layer += self.triangle.render_multisampled(
+                            &self.engine.triangle_pipeline,
+                            mesh_layer,
+                            mesh_region,
+                            &layer.triangles,
+                            physical_bounds,
+                            scale,
+                            &mut multisampled,
+                        );
+                    }
+
+                    render_pass =
+                        ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                            label: Some("iced_wgpu render pass"),
+                            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                                view: frame,
+                                depth_slice: None,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            })],
+                            depth_stencil_attachment: None,
+                            timestamp_writes: None,
+                            occlusion_query_set: None,
+                        }));
+
+                    self.triangle.resolve(
+                        &self.engine.triangle_pipeline,
+                        mesh_region,
+                        &mut render_pass,
+                    );
+
+                    mesh_region += 1;
+                }
+
+                render_span.finish();
             }
 
             if !layer.primitives.is_empty() {
@@ -640,6 +770,20 @@ impl Renderer {
                 })
                 .count()
         });
+
+        debug::buffers_reallocated(self.take_reallocations());
+    }
+
+    /// Returns the amount of buffer reallocations of the [`Renderer`] since
+    /// the last call and resets it.
+    fn take_reallocations(&mut self) -> usize {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        let images = self.image.take_reallocations();
+
+        #[cfg(not(any(feature = "svg", feature = "image")))]
+        let images = 0;
+
+        self.quad.take_reallocations() + self.triangle.take_reallocations() + images
     }
 }
 
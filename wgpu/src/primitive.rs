@@ -1,4 +1,10 @@
 //! Draw custom primitives.
+mod cache;
+mod uniform;
+
+pub use cache::Cache;
+pub use uniform::{Globals, Uniform};
+
 use crate::core::{self, Rectangle};
 use crate::graphics::Viewport;
 use crate::graphics::futures::{MaybeSend, MaybeSync};
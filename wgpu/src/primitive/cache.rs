@@ -0,0 +1,78 @@
+use crate::core::widget;
+
+use rustc_hash::FxHashMap;
+
+/// Per-instance storage for the [`Pipeline`] of a custom [`Primitive`],
+/// keyed by widget [`Id`](widget::Id).
+///
+/// All the instances of a [`Primitive`] share the same [`Pipeline`]; a [`Cache`]
+/// lets each of them keep its own GPU resources (like a [`Uniform`] or some
+/// textures) across frames.
+///
+/// Entries that are not accessed between two calls to [`Cache::trim`] are
+/// dropped; so calling it from [`Pipeline::trim`] frees the resources of
+/// widgets that are no longer drawn.
+///
+/// [`Primitive`]: super::Primitive
+/// [`Pipeline`]: super::Pipeline
+/// [`Pipeline::trim`]: super::Pipeline::trim
+/// [`Uniform`]: super::Uniform
+#[derive(Debug)]
+pub struct Cache<T> {
+    entries: FxHashMap<widget::Id, Entry<T>>,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    alive: bool,
+}
+
+impl<T> Cache<T> {
+    /// Creates a new empty [`Cache`].
+    pub fn new() -> Self {
+        Self {
+            entries: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the value of the given [`Id`](widget::Id), creating it
+    /// with the provided closure if it does not exist yet.
+    pub fn get_or_insert_with(&mut self, id: &widget::Id, f: impl FnOnce() -> T) -> &mut T {
+        let entry = self.entries.entry(id.clone()).or_insert_with(|| Entry {
+            value: f(),
+            alive: true,
+        });
+
+        entry.alive = true;
+
+        &mut entry.value
+    }
+
+    /// Returns the value of the given [`Id`](widget::Id), if any.
+    pub fn get(&self, id: &widget::Id) -> Option<&T> {
+        self.entries.get(id).map(|entry| &entry.value)
+    }
+
+    /// Returns the amount of values in the [`Cache`].
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the [`Cache`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drops the values that have not been accessed since the last trim.
+    pub fn trim(&mut self) {
+        self.entries
+            .retain(|_, entry| std::mem::replace(&mut entry.alive, false));
+    }
+}
+
+impl<T> Default for Cache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
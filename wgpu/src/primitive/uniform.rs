@@ -0,0 +1,205 @@
+use crate::core::{Point, Rectangle};
+use crate::graphics::Viewport;
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// The built-in uniforms of a custom [`Primitive`].
+///
+/// They describe the surface a [`Primitive`] is drawn on and the state of the
+/// cursor and clock at the time it was produced; which are the uniforms almost
+/// every custom shader ends up needing.
+///
+/// The layout of [`Globals`] matches the WGSL struct in [`Globals::WGSL`].
+///
+/// [`Primitive`]: super::Primitive
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Globals {
+    /// The size of the bounds of the [`Primitive`].
+    ///
+    /// It is in logical pixels until the [`Globals`] are scaled with
+    /// [`Globals::scale`].
+    ///
+    /// [`Primitive`]: super::Primitive
+    pub resolution: [f32; 2],
+    /// The position of the cursor relative to the bounds of the [`Primitive`],
+    /// in the same units as the [`resolution`](Self::resolution).
+    ///
+    /// It is `[-1.0, -1.0]` when the cursor is not over the [`Primitive`].
+    ///
+    /// [`Primitive`]: super::Primitive
+    pub cursor: [f32; 2],
+    /// The elapsed time, in seconds.
+    pub time: f32,
+    /// The scale factor of the [`Viewport`].
+    pub scale_factor: f32,
+    _padding: [f32; 2],
+}
+
+impl Globals {
+    /// The WGSL declaration of the [`Globals`] struct.
+    ///
+    /// It can be prepended to the source of a shader module to bind
+    /// the [`Globals`] with a [`Uniform`]:
+    ///
+    /// ```wgsl
+    /// @group(0) @binding(0) var<uniform> globals: Globals;
+    /// ```
+    pub const WGSL: &'static str = "struct Globals {
+    resolution: vec2<f32>,
+    cursor: vec2<f32>,
+    time: f32,
+    scale_factor: f32,
+    _padding: vec2<f32>,
+}
+";
+
+    /// Creates new [`Globals`] for a [`Primitive`] with the given logical bounds.
+    ///
+    /// [`Primitive`]: super::Primitive
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            resolution: [bounds.width, bounds.height],
+            cursor: [-1.0, -1.0],
+            time: 0.0,
+            scale_factor: 1.0,
+            _padding: [0.0; 2],
+        }
+    }
+
+    /// Sets the logical position of the cursor, relative to the bounds
+    /// of the [`Primitive`].
+    ///
+    /// It should be set before calling [`Globals::scale`].
+    ///
+    /// [`Primitive`]: super::Primitive
+    pub fn cursor(mut self, cursor: Option<Point>) -> Self {
+        self.cursor = cursor.map_or([-1.0, -1.0], |cursor| {
+            [cursor.x * self.scale_factor, cursor.y * self.scale_factor]
+        });
+        self
+    }
+
+    /// Sets the elapsed time of the [`Globals`].
+    pub fn time(mut self, time: Duration) -> Self {
+        self.time = time.as_secs_f32();
+        self
+    }
+
+    /// Scales the [`Globals`] to the physical pixels of the given [`Viewport`].
+    ///
+    /// This is normally called in [`Primitive::prepare`], where the
+    /// [`Viewport`] is known.
+    ///
+    /// [`Primitive::prepare`]: super::Primitive::prepare
+    pub fn scale(mut self, viewport: &Viewport) -> Self {
+        let scale_factor = viewport.scale_factor() / self.scale_factor;
+
+        self.resolution = [
+            self.resolution[0] * scale_factor,
+            self.resolution[1] * scale_factor,
+        ];
+
+        if self.cursor != [-1.0, -1.0] {
+            self.cursor = [self.cursor[0] * scale_factor, self.cursor[1] * scale_factor];
+        }
+
+        self.scale_factor = viewport.scale_factor();
+        self
+    }
+}
+
+/// A uniform buffer holding a single value of type `T`.
+///
+/// A [`Uniform`] owns its [`wgpu::Buffer`] together with a bind group
+/// layout and a bind group containing it at binding `0`; so it can be
+/// plugged directly into a render pipeline.
+pub struct Uniform<T> {
+    buffer: wgpu::Buffer,
+    layout: wgpu::BindGroupLayout,
+    bind_group: wgpu::BindGroup,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T: bytemuck::Pod> Uniform<T> {
+    /// Creates a new [`Uniform`] visible to both the vertex and fragment stages.
+    pub fn new(device: &wgpu::Device, label: &'static str) -> Self {
+        Self::with_visibility(device, label, wgpu::ShaderStages::VERTEX_FRAGMENT)
+    }
+
+    /// Creates a new [`Uniform`] visible to the given [`wgpu::ShaderStages`].
+    pub fn with_visibility(
+        device: &wgpu::Device,
+        label: &'static str,
+        visibility: wgpu::ShaderStages,
+    ) -> Self {
+        // Structs in the uniform address space are aligned to 16 bytes
+        let size = wgpu::util::align_to(std::mem::size_of::<T>() as u64, 16);
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some(label),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(label),
+            layout: &layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        Self {
+            buffer,
+            layout,
+            bind_group,
+            value: PhantomData,
+        }
+    }
+
+    /// Uploads the given value to the [`Uniform`].
+    pub fn write(&self, queue: &wgpu::Queue, value: &T) {
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(value));
+    }
+
+    /// Returns the [`wgpu::Buffer`] of the [`Uniform`].
+    pub fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+
+    /// Returns the [`wgpu::BindGroupLayout`] of the [`Uniform`].
+    pub fn layout(&self) -> &wgpu::BindGroupLayout {
+        &self.layout
+    }
+
+    /// Returns the [`wgpu::BindGroup`] of the [`Uniform`].
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+impl<T> std::fmt::Debug for Uniform<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Uniform")
+            .field("buffer", &self.buffer)
+            .finish_non_exhaustive()
+    }
+}
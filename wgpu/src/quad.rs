@@ -126,6 +126,15 @@ impl State {
         }
     }
 
+    /// Returns the amount of buffer reallocations of the [`State`] since the
+    /// last call and resets it.
+    pub fn take_reallocations(&mut self) -> usize {
+        self.layers
+            .iter_mut()
+            .map(|layer| layer.solid.take_reallocations() + layer.gradient.take_reallocations())
+            .sum()
+    }
+
     pub fn trim(&mut self) {
         self.prepare_layer = 0;
     }
@@ -256,6 +265,11 @@ impl Batch {
         self.solids.is_empty() && self.gradients.is_empty()
     }
 
+    /// Returns the amount of quads of any type in [`Quads`].
+    pub fn len(&self) -> usize {
+        self.solids.len() + self.gradients.len()
+    }
+
     /// Adds a [`Quad`] with the provided `Background` type to the quad [`Layer`].
     pub fn add(&mut self, quad: Quad, background: &Background) {
         let kind = match background {
@@ -55,6 +55,10 @@ impl Layer {
 
         self.instance_count = instances.len();
     }
+
+    pub fn take_reallocations(&mut self) -> usize {
+        self.instances.take_reallocations()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +27,12 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The maximum size, in bytes, of the decoded images kept in memory
+    /// by each [`Renderer`](crate::Renderer).
+    ///
+    /// By default, it is 64 MiB.
+    pub image_cache_size: usize,
 }
 
 impl Default for Settings {
@@ -37,6 +43,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_cache_size: 64 * 1024 * 1024,
         }
     }
 }
@@ -52,6 +59,7 @@ impl From<graphics::Settings> for Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            image_cache_size: settings.image_cache_size,
             ..Settings::default()
         }
     }
@@ -4,7 +4,7 @@ use crate::core::{Rectangle, Size, Transformation};
 use crate::graphics::cache;
 use crate::graphics::color;
 use crate::graphics::text::cache::{self as text_cache, Cache as BufferCache};
-use crate::graphics::text::{Editor, Paragraph, font_system, to_color};
+use crate::graphics::text::{Editor, Paragraph, font_system, paragraph, to_color};
 
 use rustc_hash::FxHashMap;
 use std::collections::hash_map;
@@ -60,6 +60,10 @@ impl Cache {
         })
     }
 
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
     pub fn update(&mut self, text: Vec<Text>) {
         if self.text.is_empty() && text.is_empty() {
             return;
@@ -302,6 +306,7 @@ pub struct State {
     prepare_layer: usize,
     cache: BufferCache,
     storage: Storage,
+    trimmer: paragraph::Trimmer,
 }
 
 impl State {
@@ -424,6 +429,7 @@ impl State {
     pub fn trim(&mut self) {
         self.cache.trim();
         self.storage.trim();
+        self.trimmer.trim();
 
         self.prepare_layer = 0;
     }
@@ -27,6 +27,63 @@ pub enum Item {
     },
 }
 
+/// The vertices and indices of a group of meshes, packed contiguously and
+/// ready to be uploaded.
+///
+/// Packing does not touch the GPU; so the groups of a frame can be packed in
+/// parallel with `rayon` before any upload. Otherwise, the meshes are written
+/// straight to their buffers.
+#[derive(Debug, Default)]
+#[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+pub struct Packed {
+    indices: Vec<u32>,
+    solid_vertices: Vec<mesh::SolidVertex2D>,
+    gradient_vertices: Vec<mesh::GradientVertex2D>,
+}
+
+#[cfg(feature = "rayon")]
+impl Packed {
+    pub fn new(meshes: &[Mesh]) -> Self {
+        let count = mesh::attribute_count_of(meshes);
+
+        let mut packed = Self {
+            indices: Vec::with_capacity(count.indices),
+            solid_vertices: Vec::with_capacity(count.solid_vertices),
+            gradient_vertices: Vec::with_capacity(count.gradient_vertices),
+        };
+
+        for mesh in meshes {
+            packed.indices.extend_from_slice(mesh.indices());
+
+            match mesh {
+                Mesh::Solid { buffers, .. } => {
+                    packed.solid_vertices.extend_from_slice(&buffers.vertices);
+                }
+                Mesh::Gradient { buffers, .. } => {
+                    packed
+                        .gradient_vertices
+                        .extend_from_slice(&buffers.vertices);
+                }
+            }
+        }
+
+        packed
+    }
+}
+
+/// Packs the groups of meshes of a [`Batch`]; cached meshes are written
+/// straight to their buffers when they change.
+#[cfg(feature = "rayon")]
+pub fn pack(batch: &Batch) -> Vec<Packed> {
+    batch
+        .iter()
+        .filter_map(|item| match item {
+            Item::Group { meshes, .. } => Some(Packed::new(meshes)),
+            Item::Cached { .. } => None,
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 struct Upload {
     layer: Layer,
@@ -78,6 +135,7 @@ impl Storage {
                         solid,
                         gradient,
                         cache.batch(),
+                        None,
                         new_transformation,
                     );
 
@@ -96,6 +154,7 @@ impl Storage {
                     solid,
                     gradient,
                     cache.batch(),
+                    None,
                     new_transformation,
                 );
 
@@ -119,6 +178,13 @@ impl Storage {
         self.uploads
             .retain(|_id, upload| upload.batch.strong_count() > 0);
     }
+
+    fn take_reallocations(&mut self) -> usize {
+        self.uploads
+            .values_mut()
+            .map(|upload| upload.layer.take_reallocations())
+            .sum()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -132,6 +198,7 @@ pub struct State {
     msaa: Option<msaa::State>,
     layers: Vec<Layer>,
     prepare_layer: usize,
+    prepare_region: usize,
     storage: Storage,
 }
 
@@ -144,10 +211,29 @@ impl State {
                 .map(|pipeline| msaa::State::new(device, pipeline)),
             layers: Vec::new(),
             prepare_layer: 0,
+            prepare_region: 0,
             storage: Storage::new(),
         }
     }
 
+    /// Places the meshes of the layers with the given physical bounds in the
+    /// multisampled attachment, if any.
+    ///
+    /// It must be called before preparing the meshes of every layer.
+    pub fn prepare_regions(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        layers: &[Rectangle<u32>],
+        target_size: Size<u32>,
+    ) {
+        if let Some((state, pipeline)) = self.msaa.as_mut().zip(pipeline.msaa.as_ref()) {
+            state.prepare(device, encoder, belt, pipeline, layers, target_size);
+        }
+    }
+
     pub fn prepare(
         &mut self,
         pipeline: &Pipeline,
@@ -155,15 +241,20 @@ impl State {
         belt: &mut wgpu::util::StagingBelt,
         encoder: &mut wgpu::CommandEncoder,
         items: &[Item],
+        packs: &[Packed],
         scale: Transformation,
         target_size: Size<u32>,
     ) {
-        let projection =
-            if let Some((state, pipeline)) = self.msaa.as_mut().zip(pipeline.msaa.as_ref()) {
-                state.prepare(device, encoder, belt, pipeline, target_size) * scale
-            } else {
-                Transformation::orthographic(target_size.width, target_size.height) * scale
-            };
+        let projection = if let Some(state) = &self.msaa {
+            let projection = state.projection(self.prepare_region) * scale;
+            self.prepare_region += 1;
+
+            projection
+        } else {
+            Transformation::orthographic(target_size.width, target_size.height) * scale
+        };
+
+        let mut packs = packs.iter();
 
         for item in items {
             match item {
@@ -184,6 +275,7 @@ impl State {
                         &pipeline.solid,
                         &pipeline.gradient,
                         meshes,
+                        packs.next(),
                         projection * *transformation,
                     );
 
@@ -207,32 +299,132 @@ impl State {
         }
     }
 
-    pub fn render(
-        &mut self,
+    /// Returns whether the meshes must be rendered in a multisampled render
+    /// pass; instead of the render pass of the target.
+    pub fn is_multisampled(&self) -> bool {
+        self.msaa.is_some()
+    }
+
+    /// Returns whether the meshes of every layer can be rendered in a single
+    /// multisampled render pass before the render pass of the target.
+    ///
+    /// Otherwise, the meshes of every layer must be rendered in their own
+    /// multisampled render pass.
+    pub fn is_packed(&self) -> bool {
+        self.msaa.as_ref().is_some_and(msaa::State::is_packed)
+    }
+
+    /// Renders the meshes of the [`Batch`] directly in the given render pass.
+    ///
+    /// Returns the amount of prepared layers that were used.
+    pub fn render<'a>(
+        &'a self,
+        pipeline: &'a Pipeline,
+        start: usize,
+        batch: &Batch,
+        bounds: Rectangle,
+        screen_transformation: Transformation,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) -> usize {
+        let (layer_count, items) = self.items(start, batch, screen_transformation);
+
+        for (layer, meshes, transformation) in items {
+            layer.render(
+                &pipeline.solid,
+                &pipeline.gradient,
+                meshes,
+                bounds,
+                transformation,
+                render_pass,
+            );
+        }
+
+        layer_count
+    }
+
+    /// Begins a multisampled render pass for the meshes, if multisampling
+    /// is enabled.
+    ///
+    /// The multisampled attachment is cleared when the pass begins and
+    /// resolved when it ends.
+    pub fn begin_multisampled<'a>(
+        &self,
         pipeline: &Pipeline,
-        encoder: &mut wgpu::CommandEncoder,
-        target: &wgpu::TextureView,
+        encoder: &'a mut wgpu::CommandEncoder,
+    ) -> Option<wgpu::RenderPass<'a>> {
+        Some(pipeline.msaa.as_ref()?.render_pass(encoder))
+    }
+
+    /// Renders the meshes of the [`Batch`] into the given region of the
+    /// multisampled attachment.
+    ///
+    /// The result must be drawn to the target with [`State::resolve`].
+    ///
+    /// Returns the amount of prepared layers that were used.
+    pub fn render_multisampled<'a>(
+        &'a self,
+        pipeline: &'a Pipeline,
         start: usize,
+        region: usize,
         batch: &Batch,
         bounds: Rectangle,
         screen_transformation: Transformation,
+        render_pass: &mut wgpu::RenderPass<'a>,
     ) -> usize {
-        let mut layer_count = 0;
+        let Some(msaa) = &self.msaa else {
+            return 0;
+        };
+
+        let offset = msaa.offset(region);
+
+        self.render(
+            pipeline,
+            start,
+            batch,
+            bounds + offset,
+            Transformation::translate(offset.x, offset.y) * screen_transformation,
+            render_pass,
+        )
+    }
+
+    /// Draws the meshes of the given region, rendered and resolved with
+    /// [`State::render_multisampled`], in the render pass of the target.
+    pub fn resolve(
+        &self,
+        pipeline: &Pipeline,
+        region: usize,
+        render_pass: &mut wgpu::RenderPass<'_>,
+    ) {
+        if let Some((state, pipeline)) = self.msaa.as_ref().zip(pipeline.msaa.as_ref()) {
+            state.render(pipeline, region, render_pass);
+        }
+    }
+
+    fn items<'a>(
+        &'a self,
+        start: usize,
+        batch: &'a Batch,
+        screen_transformation: Transformation,
+    ) -> (
+        usize,
+        impl Iterator<Item = (&'a Layer, &'a [Mesh], Transformation)>,
+    ) {
+        let layer_count = batch
+            .iter()
+            .filter(|item| matches!(item, Item::Group { .. }))
+            .count();
 
-        let items = batch.iter().filter_map(|item| match item {
+        let mut layers = self.layers[start..].iter();
+
+        let items = batch.iter().filter_map(move |item| match item {
             Item::Group {
                 transformation,
                 meshes,
-            } => {
-                let layer = &self.layers[start + layer_count];
-                layer_count += 1;
-
-                Some((
-                    layer,
-                    meshes.as_slice(),
-                    screen_transformation * *transformation,
-                ))
-            }
+            } => Some((
+                layers.next()?,
+                meshes.as_slice(),
+                screen_transformation * *transformation,
+            )),
             Item::Cached {
                 transformation,
                 cache,
@@ -247,23 +439,28 @@ impl State {
             }
         });
 
-        render(
-            encoder,
-            target,
-            self.msaa.as_ref().zip(pipeline.msaa.as_ref()),
-            &pipeline.solid,
-            &pipeline.gradient,
-            bounds,
-            items,
-        );
+        (layer_count, items)
+    }
 
-        layer_count
+    /// Returns the amount of buffer reallocations of the [`State`] since the
+    /// last call and resets it.
+    pub fn take_reallocations(&mut self) -> usize {
+        self.layers
+            .iter_mut()
+            .map(Layer::take_reallocations)
+            .sum::<usize>()
+            + self.storage.take_reallocations()
+            + self
+                .msaa
+                .as_mut()
+                .map_or(0, msaa::State::take_reallocations)
     }
 
     pub fn trim(&mut self) {
         self.storage.trim();
 
         self.prepare_layer = 0;
+        self.prepare_region = 0;
     }
 }
 
@@ -281,53 +478,6 @@ impl Pipeline {
     }
 }
 
-fn render<'a>(
-    encoder: &mut wgpu::CommandEncoder,
-    target: &wgpu::TextureView,
-    mut msaa: Option<(&msaa::State, &msaa::Pipeline)>,
-    solid: &solid::Pipeline,
-    gradient: &gradient::Pipeline,
-    bounds: Rectangle,
-    group: impl Iterator<Item = (&'a Layer, &'a [Mesh], Transformation)>,
-) {
-    {
-        let mut render_pass = if let Some((_state, pipeline)) = &mut msaa {
-            pipeline.render_pass(encoder)
-        } else {
-            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("iced_wgpu.triangle.render_pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    depth_slice: None,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            })
-        };
-
-        for (layer, meshes, transformation) in group {
-            layer.render(
-                solid,
-                gradient,
-                meshes,
-                bounds,
-                transformation,
-                &mut render_pass,
-            );
-        }
-    }
-
-    if let Some((state, pipeline)) = msaa {
-        state.render(pipeline, encoder, target);
-    }
-}
-
 #[derive(Debug)]
 pub struct Layer {
     index_buffer: Buffer<u32>,
@@ -349,6 +499,14 @@ impl Layer {
         }
     }
 
+    fn take_reallocations(&mut self) -> usize {
+        self.index_buffer.take_reallocations()
+            + self.solid.vertices.take_reallocations()
+            + self.solid.uniforms.take_reallocations()
+            + self.gradient.vertices.take_reallocations()
+            + self.gradient.uniforms.take_reallocations()
+    }
+
     fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -357,6 +515,7 @@ impl Layer {
         solid: &solid::Pipeline,
         gradient: &gradient::Pipeline,
         meshes: &[Mesh],
+        packed: Option<&Packed>,
         transformation: Transformation,
     ) {
         // Count the total amount of vertices & indices we need to handle
@@ -386,11 +545,8 @@ impl Layer {
             );
         }
 
-        let mut solid_vertex_offset = 0;
-        let mut solid_uniform_offset = 0;
-        let mut gradient_vertex_offset = 0;
-        let mut gradient_uniform_offset = 0;
-        let mut index_offset = 0;
+        let mut solid_uniforms = Vec::with_capacity(count.solids);
+        let mut gradient_uniforms = Vec::with_capacity(count.gradients);
 
         for mesh in meshes {
             let clip_bounds = mesh.clip_bounds() * transformation;
@@ -408,6 +564,59 @@ impl Layer {
                     * Transformation::translate(snap_distance.x, snap_distance.y),
             );
 
+            match mesh {
+                Mesh::Solid { .. } => solid_uniforms.push(uniforms),
+                Mesh::Gradient { .. } => gradient_uniforms.push(uniforms),
+            }
+        }
+
+        if !solid_uniforms.is_empty() {
+            let _ = self
+                .solid
+                .uniforms
+                .write(device, encoder, belt, 0, &solid_uniforms);
+        }
+
+        if !gradient_uniforms.is_empty() {
+            let _ = self
+                .gradient
+                .uniforms
+                .write(device, encoder, belt, 0, &gradient_uniforms);
+        }
+
+        // Packed meshes are uploaded in a single write per buffer
+        if let Some(packed) = packed {
+            if !packed.indices.is_empty() {
+                let _ = self
+                    .index_buffer
+                    .write(device, encoder, belt, 0, &packed.indices);
+            }
+
+            if !packed.solid_vertices.is_empty() {
+                let _ = self
+                    .solid
+                    .vertices
+                    .write(device, encoder, belt, 0, &packed.solid_vertices);
+            }
+
+            if !packed.gradient_vertices.is_empty() {
+                let _ = self.gradient.vertices.write(
+                    device,
+                    encoder,
+                    belt,
+                    0,
+                    &packed.gradient_vertices,
+                );
+            }
+
+            return;
+        }
+
+        let mut solid_vertex_offset = 0;
+        let mut gradient_vertex_offset = 0;
+        let mut index_offset = 0;
+
+        for mesh in meshes {
             let indices = mesh.indices();
 
             index_offset += self
@@ -423,14 +632,6 @@ impl Layer {
                         solid_vertex_offset,
                         &buffers.vertices,
                     );
-
-                    solid_uniform_offset += self.solid.uniforms.write(
-                        device,
-                        encoder,
-                        belt,
-                        solid_uniform_offset,
-                        &[uniforms],
-                    );
                 }
                 Mesh::Gradient { buffers, .. } => {
                     gradient_vertex_offset += self.gradient.vertices.write(
@@ -440,14 +641,6 @@ impl Layer {
                         gradient_vertex_offset,
                         &buffers.vertices,
                     );
-
-                    gradient_uniform_offset += self.gradient.uniforms.write(
-                        device,
-                        encoder,
-                        belt,
-                        gradient_uniform_offset,
-                        &[uniforms],
-                    );
                 }
             }
         }
@@ -1,7 +1,7 @@
-use crate::core::{Size, Transformation};
+use crate::Buffer;
+use crate::core::{Point, Rectangle, Size, Transformation, Vector};
 use crate::graphics;
 
-use std::num::NonZeroU64;
 use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone)]
@@ -25,24 +25,12 @@ impl Pipeline {
 
         let constant_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("iced_wgpu::triangle:msaa uniforms layout"),
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::NonFiltering),
-                    count: None,
-                },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: wgpu::ShaderStages::VERTEX,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
-            ],
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::NonFiltering),
+                count: None,
+            }],
         });
 
         let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -66,9 +54,9 @@ impl Pipeline {
         });
 
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("iced_wgpu triangle blit_shader"),
+            label: Some("iced_wgpu triangle resolve shader"),
             source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(include_str!(
-                "../shader/blit.wgsl"
+                "../shader/triangle/resolve.wgsl"
             ))),
         });
 
@@ -78,7 +66,16 @@ impl Pipeline {
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: Some("vs_main"),
-                buffers: &[],
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<Blit>() as u64,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Source
+                        0 => Float32x4,
+                        // Target
+                        1 => Float32x4,
+                    ),
+                }],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             },
             fragment: Some(wgpu::FragmentState {
@@ -138,6 +135,8 @@ impl Pipeline {
         targets.as_ref().unwrap().clone()
     }
 
+    /// Begins a render pass that clears the multisampled attachment and
+    /// resolves it when it ends.
     pub fn render_pass<'a>(&self, encoder: &'a mut wgpu::CommandEncoder) -> wgpu::RenderPass<'a> {
         let targets = self.targets.read().expect("Read MSAA targets");
         let targets = targets.as_ref().unwrap();
@@ -226,120 +225,269 @@ impl Targets {
     }
 }
 
+/// A region of the resolved meshes to draw on the target.
 #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
-struct Ratio {
-    u: f32,
-    v: f32,
-    // Padding field for 16-byte alignment.
-    // See https://docs.rs/wgpu/latest/wgpu/struct.DownlevelFlags.html#associatedconstant.BUFFER_BINDINGS_NOT_16_BYTE_ALIGNED
-    _padding: [f32; 2],
+struct Blit {
+    /// The normalized bounds of the region in the resolve target.
+    source: [f32; 4],
+    /// The normalized bounds of the region in the target.
+    target: [f32; 4],
+}
+
+/// The placement of the meshes of a layer in the multisampled attachment.
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    bounds: Rectangle<u32>,
+    position: Point<u32>,
+}
+
+impl Region {
+    fn offset(&self) -> Vector {
+        Vector::new(
+            self.position.x as f32 - self.bounds.x as f32,
+            self.position.y as f32 - self.bounds.y as f32,
+        )
+    }
 }
 
 pub struct State {
-    ratio: wgpu::Buffer,
     constants: wgpu::BindGroup,
-    last_ratio: Option<Ratio>,
+    blits: Buffer<Blit>,
+    regions: Vec<Region>,
+    is_packed: bool,
+    size: Size<u32>,
 }
 
 impl State {
     pub fn new(device: &wgpu::Device, pipeline: &Pipeline) -> Self {
-        let ratio = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("iced_wgpu::triangle::msaa ratio"),
-            size: std::mem::size_of::<Ratio>() as u64,
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
-            mapped_at_creation: false,
-        });
-
         let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("iced_wgpu::triangle::msaa uniforms bind group"),
             layout: &pipeline.constant_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: ratio.as_entire_binding(),
-                },
-            ],
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
+            }],
         });
 
         Self {
-            ratio,
             constants,
-            last_ratio: None,
+            blits: Buffer::new(
+                device,
+                "iced_wgpu::triangle::msaa blits",
+                1,
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            ),
+            regions: Vec::new(),
+            is_packed: false,
+            size: Size::new(1, 1),
         }
     }
 
+    pub fn take_reallocations(&mut self) -> usize {
+        self.blits.take_reallocations()
+    }
+
+    /// Places the meshes of every layer with the given bounds in the
+    /// multisampled attachment.
+    ///
+    /// The regions are packed side by side when they fit in twice the size
+    /// of the target; so all the meshes of a frame can be rendered in a
+    /// single pass and resolved once. Otherwise, every region keeps its
+    /// position in the target and must be rendered and resolved on its own.
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         belt: &mut wgpu::util::StagingBelt,
         pipeline: &Pipeline,
-        region_size: Size<u32>,
-    ) -> Transformation {
-        let targets = pipeline.targets(device, region_size);
-
-        let ratio = Ratio {
-            u: region_size.width as f32 / targets.size.width as f32,
-            v: region_size.height as f32 / targets.size.height as f32,
-            _padding: [0.0; 2],
-        };
+        layers: &[Rectangle<u32>],
+        target_size: Size<u32>,
+    ) {
+        if layers.is_empty() {
+            self.regions.clear();
+            self.is_packed = false;
 
-        if Some(ratio) != self.last_ratio {
-            belt.write_buffer(
-                encoder,
-                &self.ratio,
-                0,
-                NonZeroU64::new(std::mem::size_of::<Ratio>() as u64).expect("non-empty ratio"),
-                device,
+            return;
+        }
+
+        let max_height = target_size
+            .height
+            .saturating_mul(2)
+            .min(device.limits().max_texture_dimension_2d);
+
+        let packed = pack(layers, target_size.width, max_height);
+        self.is_packed = packed.is_some();
+
+        let (positions, size) = packed.unwrap_or_else(|| {
+            (
+                layers
+                    .iter()
+                    .map(|bounds| Point::new(bounds.x, bounds.y))
+                    .collect(),
+                target_size,
             )
-            .copy_from_slice(bytemuck::bytes_of(&ratio));
+        });
 
-            self.last_ratio = Some(ratio);
-        }
+        self.size = pipeline.targets(device, size).size;
+
+        self.regions = layers
+            .iter()
+            .zip(positions)
+            .map(|(bounds, position)| Region {
+                bounds: *bounds,
+                position,
+            })
+            .collect();
+
+        let normalize = |x: u32, y: u32, width: u32, height: u32, size: Size<u32>| {
+            [
+                x as f32 / size.width as f32,
+                y as f32 / size.height as f32,
+                width as f32 / size.width as f32,
+                height as f32 / size.height as f32,
+            ]
+        };
+
+        let blits: Vec<_> = self
+            .regions
+            .iter()
+            .map(|region| Blit {
+                source: normalize(
+                    region.position.x,
+                    region.position.y,
+                    region.bounds.width,
+                    region.bounds.height,
+                    self.size,
+                ),
+                target: normalize(
+                    region.bounds.x,
+                    region.bounds.y,
+                    region.bounds.width,
+                    region.bounds.height,
+                    target_size,
+                ),
+            })
+            .collect();
+
+        let _ = self.blits.resize(device, blits.len());
+        let _ = self.blits.write(device, encoder, belt, 0, &blits);
+    }
+
+    /// Returns whether all the regions were packed in the multisampled
+    /// attachment.
+    pub fn is_packed(&self) -> bool {
+        self.is_packed
+    }
+
+    /// Returns the projection of the meshes of the given region.
+    pub fn projection(&self, region: usize) -> Transformation {
+        let offset = self.offset(region);
 
-        Transformation::orthographic(targets.size.width, targets.size.height)
+        Transformation::orthographic(self.size.width, self.size.height)
+            * Transformation::translate(offset.x, offset.y)
     }
 
+    /// Returns the translation from the target to the given region.
+    pub fn offset(&self, region: usize) -> Vector {
+        self.regions
+            .get(region)
+            .map(Region::offset)
+            .unwrap_or(Vector::ZERO)
+    }
+
+    /// Draws the resolved meshes of the given region on the target.
     pub fn render(
         &self,
         pipeline: &Pipeline,
-        encoder: &mut wgpu::CommandEncoder,
-        target: &wgpu::TextureView,
+        region: usize,
+        render_pass: &mut wgpu::RenderPass<'_>,
     ) {
-        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("iced_wgpu::triangle::msaa render pass"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: target,
-                depth_slice: None,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                },
-            })],
-            depth_stencil_attachment: None,
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
+        let Some(Region { bounds, .. }) = self.regions.get(region) else {
+            return;
+        };
+
+        let targets = pipeline.targets.read().expect("Read MSAA targets");
+
+        let Some(targets) = targets.as_ref() else {
+            return;
+        };
+
+        let instance = region as u32;
 
+        render_pass.set_scissor_rect(bounds.x, bounds.y, bounds.width, bounds.height);
         render_pass.set_pipeline(&pipeline.raw);
         render_pass.set_bind_group(0, &self.constants, &[]);
-        render_pass.set_bind_group(
-            1,
-            &pipeline
-                .targets
-                .read()
-                .expect("Read MSAA targets")
-                .as_ref()
-                .unwrap()
-                .bind_group,
-            &[],
+        render_pass.set_bind_group(1, &targets.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.blits.slice(..));
+        render_pass.draw(0..6, instance..instance + 1);
+    }
+}
+
+/// Packs rectangles in rows of the given width; left to right and top to
+/// bottom.
+///
+/// Returns the position of every rectangle and the size of the packed area,
+/// or `None` if they do not fit in the given height.
+fn pack(
+    rectangles: &[Rectangle<u32>],
+    width: u32,
+    max_height: u32,
+) -> Option<(Vec<Point<u32>>, Size<u32>)> {
+    let mut positions = Vec::with_capacity(rectangles.len());
+    let mut cursor = Point::new(0, 0);
+    let mut row_height = 0;
+
+    for rectangle in rectangles {
+        if rectangle.width > width {
+            return None;
+        }
+
+        if cursor.x + rectangle.width > width {
+            cursor = Point::new(0, cursor.y + row_height);
+            row_height = 0;
+        }
+
+        row_height = row_height.max(rectangle.height);
+
+        if cursor.y + row_height > max_height {
+            return None;
+        }
+
+        positions.push(cursor);
+        cursor.x += rectangle.width;
+    }
+
+    Some((positions, Size::new(width, (cursor.y + row_height).max(1))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_packs_regions_in_rows() {
+        let rectangle = |width, height| Rectangle {
+            x: 10,
+            y: 10,
+            width,
+            height,
+        };
+
+        let (positions, size) = pack(
+            &[rectangle(60, 20), rectangle(30, 10), rectangle(50, 30)],
+            100,
+            200,
+        )
+        .expect("Pack regions");
+
+        assert_eq!(
+            positions,
+            vec![Point::new(0, 0), Point::new(60, 0), Point::new(0, 20)]
         );
-        render_pass.draw(0..6, 0..1);
+        assert_eq!(size, Size::new(100, 50));
+
+        assert!(pack(&[rectangle(100, 150), rectangle(100, 60)], 100, 200).is_none());
+        assert!(pack(&[rectangle(120, 10)], 100, 200).is_none());
     }
 }
@@ -164,12 +164,13 @@ impl Compositor {
 
             match result {
                 Ok((device, queue)) => {
-                    let engine = Engine::new(
+                    let engine = Engine::with_image_cache_size(
                         &adapter,
                         device,
                         queue,
                         format,
                         settings.antialiasing,
+                        settings.image_cache_size,
                         shell,
                     );
 
@@ -0,0 +1,288 @@
+//! Keep the aspect ratio of some content regardless of the size of its parent.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{aspect_ratio, container};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     aspect_ratio(16.0 / 9.0, container("Trailer").style(container::dark)).into()
+//! }
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::{
+    self, Alignment, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A widget that sizes its contents following an aspect ratio.
+///
+/// An [`AspectRatio`] widget takes the largest size with its ratio that fits
+/// its limits. By default, it will try to fill the width of its parent.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{aspect_ratio, container};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     aspect_ratio(16.0 / 9.0, container("Trailer").style(container::dark)).into()
+/// }
+/// ```
+pub struct AspectRatio<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    ratio: f32,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> AspectRatio<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates an [`AspectRatio`] widget with the given ratio—its width
+    /// divided by its height—and content.
+    pub fn new(ratio: f32, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            ratio,
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`AspectRatio`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AspectRatio<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = fit(limits.max(), self.ratio).unwrap_or_else(|| {
+            // Unbounded in both axes; so we follow the width of the content
+            let node = self.content.as_widget_mut().layout(tree, renderer, &limits);
+            let width = node.size().width;
+
+            Size::new(width, width / self.ratio)
+        });
+
+        let node = self
+            .content
+            .as_widget_mut()
+            .layout(tree, renderer, &layout::Limits::new(Size::ZERO, size))
+            .align(Alignment::Center, Alignment::Center, size);
+
+        layout::Node::with_children(size, vec![node])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if let Some(clipped_viewport) = bounds.intersection(viewport) {
+            self.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                &clipped_viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AspectRatio<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(
+        aspect_ratio: AspectRatio<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(aspect_ratio)
+    }
+}
+
+/// Returns the largest [`Size`] with the given ratio that fits in `max`;
+/// unless `max` is unbounded in both axes.
+fn fit(max: Size, ratio: f32) -> Option<Size> {
+    let height = max.width / ratio;
+
+    if max.width.is_finite() && height <= max.height {
+        Some(Size::new(max.width, height))
+    } else if max.height.is_finite() {
+        Some(Size::new(max.height * ratio, max.height))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_fits_the_largest_size() {
+        assert_eq!(
+            fit(Size::new(320.0, f32::INFINITY), 16.0 / 9.0),
+            Some(Size::new(320.0, 180.0))
+        );
+
+        assert_eq!(
+            fit(Size::new(1000.0, 90.0), 16.0 / 9.0),
+            Some(Size::new(160.0, 90.0))
+        );
+
+        assert_eq!(
+            fit(Size::new(f32::INFINITY, 300.0), 2.0 / 3.0),
+            Some(Size::new(200.0, 300.0))
+        );
+
+        assert_eq!(fit(Size::INFINITE, 1.0), None);
+    }
+}
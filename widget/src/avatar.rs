@@ -0,0 +1,466 @@
+//! Avatars display a circular picture of a user, with an optional status badge.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::avatar;
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     avatar("Ada Lovelace")
+//!         .image("ada.png")
+//!         .status(avatar::Status::Online)
+//!         .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::Tree;
+use crate::core::{
+    Background, Color, ContentFit, Element, Layout, Length, Pixels, Point, Radians, Rectangle,
+    Size, Theme, Vector, Widget, color,
+};
+
+/// A circular picture of a user.
+///
+/// If the image is not loaded yet or fails to load, the [`Avatar`] falls
+/// back to the initials of the user name over a colored background.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::avatar;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     avatar("Ada Lovelace")
+///         .image("ada.png")
+///         .status(avatar::Status::Online)
+///         .into()
+/// }
+/// ```
+pub struct Avatar<'a, Handle = image::Handle, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    name: String,
+    initials: String,
+    image: Option<Handle>,
+    size: f32,
+    font: Option<Renderer::Font>,
+    status: Option<Status>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Handle, Theme, Renderer> Avatar<'a, Handle, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default size of an [`Avatar`].
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// Creates a new [`Avatar`] for the given user name.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        Self {
+            initials: initials(&name),
+            name,
+            image: None,
+            size: Self::DEFAULT_SIZE,
+            font: None,
+            status: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the image of the [`Avatar`].
+    ///
+    /// The image will be cropped to its centered square region and scaled
+    /// to cover the whole [`Avatar`].
+    pub fn image(mut self, handle: impl Into<Handle>) -> Self {
+        self.image = Some(handle.into());
+        self
+    }
+
+    /// Sets the diameter of the [`Avatar`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the [`Font`] of the initials of the [`Avatar`].
+    ///
+    /// [`Font`]: crate::core::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the initials of the [`Avatar`].
+    ///
+    /// By default, they are derived from the first and last words of the name.
+    pub fn initials(mut self, initials: impl Into<String>) -> Self {
+        self.initials = initials.into();
+        self
+    }
+
+    /// Sets the [`Status`] displayed by the badge of the [`Avatar`].
+    pub fn status(mut self, status: impl Into<Option<Status>>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Sets the style of the [`Avatar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Option<Status>) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Avatar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Handle, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Avatar<'_, Handle, Theme, Renderer>
+where
+    Handle: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer + image::Renderer<Handle = Handle>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let diameter = bounds.width.min(bounds.height);
+        let circle = Rectangle::new(
+            bounds.center() - Vector::new(diameter / 2.0, diameter / 2.0),
+            Size::new(diameter, diameter),
+        );
+
+        let style = theme.style(&self.class, self.status);
+        let background = style
+            .background
+            .unwrap_or_else(|| Background::Color(fallback_color(&self.name)));
+
+        let text_color = style.text_color.unwrap_or_else(|| match background {
+            Background::Color(color) if !Color::WHITE.is_readable_on(color) => Color::BLACK,
+            _ => Color::WHITE,
+        });
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: circle,
+                border: border::rounded(diameter / 2.0),
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+
+        if !self.initials.is_empty() {
+            renderer.fill_text(
+                text::Text {
+                    content: self.initials.clone(),
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    size: Pixels(diameter * 0.4),
+                    line_height: text::LineHeight::default(),
+                    bounds: circle.size(),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::default(),
+                    wrapping: text::Wrapping::None,
+                    hint_factor: None,
+                },
+                circle.center(),
+                text_color,
+                *viewport,
+            );
+        }
+
+        if let Some(handle) = &self.image
+            && let Some(size) = renderer.measure_image(handle)
+        {
+            let drawing_bounds = cover(Size::new(size.width as f32, size.height as f32), circle);
+
+            // The border radius applies to the clip bounds; that is, the circle
+            renderer.draw_image(
+                image::Image {
+                    handle: handle.clone(),
+                    border_radius: border::radius(diameter / 2.0),
+                    filter_method: image::FilterMethod::default(),
+                    rotation: Radians(0.0),
+                    opacity: 1.0,
+                    snap: true,
+                },
+                drawing_bounds,
+                circle,
+            );
+        }
+
+        if style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: circle,
+                    border: Border {
+                        radius: border::radius(diameter / 2.0),
+                        ..style.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if self.status.is_some() {
+            let badge_diameter = diameter * 0.3;
+            let offset = diameter / 2.0 * std::f32::consts::FRAC_1_SQRT_2;
+
+            let center = circle.center() + Vector::new(offset, offset);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        Point::new(
+                            center.x - badge_diameter / 2.0,
+                            center.y - badge_diameter / 2.0,
+                        ),
+                        Size::new(badge_diameter, badge_diameter),
+                    ),
+                    border: Border {
+                        radius: border::radius(badge_diameter / 2.0),
+                        ..style.badge_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.badge,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Handle, Theme, Renderer> From<Avatar<'a, Handle, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Handle: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + image::Renderer<Handle = Handle> + 'a,
+{
+    fn from(avatar: Avatar<'a, Handle, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(avatar)
+    }
+}
+
+/// The presence status of the user of an [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The user is online.
+    Online,
+    /// The user is online, but inactive.
+    Idle,
+    /// The user is online, but does not want to be disturbed.
+    Busy,
+    /// The user is offline.
+    Offline,
+}
+
+/// The appearance of an [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] behind the initials of the [`Avatar`].
+    ///
+    /// If `None`, a color will be derived from the user name.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the initials of the [`Avatar`].
+    ///
+    /// If `None`, a readable color will be chosen based on the background.
+    pub text_color: Option<Color>,
+    /// The [`Border`] around the [`Avatar`].
+    ///
+    /// Its radius is ignored.
+    pub border: Border,
+    /// The [`Background`] of the status badge.
+    pub badge: Background,
+    /// The [`Border`] of the status badge.
+    ///
+    /// Its radius is ignored.
+    pub badge_border: Border,
+}
+
+/// The theme catalog of an [`Avatar`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Option<Status>) -> Style;
+}
+
+/// A styling function for an [`Avatar`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Option<Status>) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Option<Status>) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Option<Status>) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of an [`Avatar`].
+pub fn default(theme: &Theme, status: Option<Status>) -> Style {
+    let palette = theme.extended_palette();
+
+    let badge = match status {
+        Some(Status::Online) => palette.success.base.color,
+        Some(Status::Idle) => palette.warning.base.color,
+        Some(Status::Busy) => palette.danger.base.color,
+        Some(Status::Offline) | None => palette.background.strong.color,
+    };
+
+    Style {
+        background: None,
+        text_color: None,
+        border: Border::default(),
+        badge: badge.into(),
+        badge_border: Border {
+            color: palette.background.base.color,
+            width: 2.0,
+            ..Border::default()
+        },
+    }
+}
+
+/// Returns the bounds of an image of the given size covering the given
+/// circle while keeping its aspect ratio.
+///
+/// The image is centered on the circle; so, once clipped, only its centered
+/// square region is visible.
+fn cover(image_size: Size, circle: Rectangle) -> Rectangle {
+    let size = ContentFit::Cover.fit(image_size, circle.size());
+
+    Rectangle::new(
+        circle.center() - Vector::new(size.width / 2.0, size.height / 2.0),
+        size,
+    )
+}
+
+/// Returns the initials of the given name; that is, the first letter
+/// of its first and last words in uppercase.
+fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.next_back().and_then(|word| word.chars().next());
+
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Derives a stable background [`Color`] for the given name.
+fn fallback_color(name: &str) -> Color {
+    const COLORS: [Color; 8] = [
+        color!(0xe5484d),
+        color!(0xf76b15),
+        color!(0xffc53d),
+        color!(0x30a46c),
+        color!(0x12a594),
+        color!(0x0090ff),
+        color!(0x6e56cf),
+        color!(0xd6409f),
+    ];
+
+    // FNV-1a, so the color is stable across runs and platforms
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+
+    COLORS[hash as usize % COLORS.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_crops_the_centered_square_of_an_image() {
+        let circle = Rectangle::new(Point::new(10.0, 10.0), Size::new(40.0, 40.0));
+
+        let landscape = cover(Size::new(200.0, 100.0), circle);
+
+        assert_eq!(
+            landscape,
+            Rectangle::new(Point::new(-10.0, 10.0), Size::new(80.0, 40.0))
+        );
+        assert_eq!(landscape.intersection(&circle), Some(circle));
+
+        let portrait = cover(Size::new(30.0, 90.0), circle);
+
+        assert_eq!(
+            portrait,
+            Rectangle::new(Point::new(10.0, -30.0), Size::new(40.0, 120.0))
+        );
+        assert_eq!(portrait.intersection(&circle), Some(circle));
+    }
+}
@@ -16,12 +16,14 @@
 //!     button("Press me!").on_press(Message::ButtonPressed).into()
 //! }
 //! ```
+use crate::core::animation::{self, Animation, Interpolable};
 use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
@@ -30,6 +32,7 @@ use crate::core::{
     Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Rectangle, Shadow,
     Shell, Size, Theme, Vector, Widget,
 };
+use crate::hold::{self, Hold};
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -75,12 +78,17 @@ where
 {
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<OnPress<'a, Message>>,
+    on_long_press: Option<Message>,
+    on_double_click: Option<Message>,
+    long_press: Duration,
+    repeat: Option<(Duration, Duration)>,
     width: Length,
     height: Length,
     padding: Padding,
     clip: bool,
     class: Theme::Class<'a>,
     status: Option<Status>,
+    transition: Option<Duration>,
 }
 
 enum OnPress<'a, Message> {
@@ -110,12 +118,17 @@ where
         Button {
             content,
             on_press: None,
+            on_long_press: None,
+            on_double_click: None,
+            long_press: hold::DEFAULT_LONG_PRESS,
+            repeat: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
             padding: DEFAULT_PADDING,
             clip: false,
             class: Theme::default(),
             status: None,
+            transition: None,
         }
     }
 
@@ -167,6 +180,48 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is held
+    /// down for a while; like a secondary action on touch screens.
+    ///
+    /// Releasing a long-pressed [`Button`] does not produce its
+    /// [`on_press`](Self::on_press) message.
+    ///
+    /// The [`Button`] must be enabled for it to be long-pressed.
+    pub fn on_long_press(mut self, on_long_press: Message) -> Self {
+        self.on_long_press = Some(on_long_press);
+        self
+    }
+
+    /// Sets the duration a [`Button`] needs to be held down to be
+    /// long-pressed.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub fn long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press = duration;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is double
+    /// clicked.
+    ///
+    /// The [`on_press`](Self::on_press) message is still produced for every
+    /// click.
+    pub fn on_double_click(mut self, on_double_click: Message) -> Self {
+        self.on_double_click = Some(on_double_click);
+        self
+    }
+
+    /// Makes the [`Button`] produce its [`on_press`](Self::on_press) message
+    /// repeatedly while it is held down; first after the given `delay`, and
+    /// then every `interval`.
+    ///
+    /// This is useful for buttons that step through values; like the
+    /// arrows of a number input.
+    pub fn repeat(mut self, delay: Duration, interval: Duration) -> Self {
+        self.repeat = Some((delay, interval));
+        self
+    }
+
     /// Sets whether the contents of the [`Button`] should be clipped on
     /// overflow.
     pub fn clip(mut self, clip: bool) -> Self {
@@ -174,6 +229,15 @@ where
         self
     }
 
+    /// Sets the duration of the style transitions of the [`Button`].
+    ///
+    /// When set, the [`Style`] of the [`Button`] will be interpolated over the given
+    /// duration every time its [`Status`] changes, instead of snapping.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -193,9 +257,22 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Default)]
 struct State {
     is_pressed: bool,
+    hold: Option<Hold>,
+    previous_click: Option<mouse::Click>,
+    transition: Option<(Animation<Status>, Instant)>,
+}
+
+impl<Message, Theme, Renderer> Button<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Theme: Catalog,
+{
+    fn long_press(&self) -> Option<Duration> {
+        self.on_long_press.as_ref().map(|_| self.long_press)
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -296,6 +373,32 @@ where
 
                         state.is_pressed = true;
 
+                        if self.on_long_press.is_some() || self.repeat.is_some() {
+                            let hold = Hold::new(Instant::now());
+
+                            if let Some(deadline) = hold.deadline(self.long_press(), self.repeat) {
+                                shell.request_redraw_at(deadline);
+                            }
+
+                            state.hold = Some(hold);
+                        }
+
+                        if let Some(on_double_click) = &self.on_double_click
+                            && let Some(position) = cursor.position()
+                        {
+                            let click = mouse::Click::new(
+                                position,
+                                mouse::Button::Left,
+                                state.previous_click,
+                            );
+
+                            if click.kind() == mouse::click::Kind::Double {
+                                shell.publish(on_double_click.clone());
+                            }
+
+                            state.previous_click = Some(click);
+                        }
+
                         shell.capture_event();
                     }
                 }
@@ -309,8 +412,9 @@ where
                         state.is_pressed = false;
 
                         let bounds = layout.bounds();
+                        let is_consumed = state.hold.take().is_some_and(|hold| hold.is_consumed());
 
-                        if cursor.is_over(bounds) {
+                        if cursor.is_over(bounds) && !is_consumed {
                             shell.publish(on_press.get());
                         }
 
@@ -322,6 +426,36 @@ where
                 let state = tree.state.downcast_mut::<State>();
 
                 state.is_pressed = false;
+                state.hold = None;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(hold) = &mut state.hold {
+                    if cursor.is_over(layout.bounds()) {
+                        let tick = hold.tick(*now, self.long_press(), self.repeat);
+
+                        if tick.is_long_press
+                            && let Some(on_long_press) = &self.on_long_press
+                        {
+                            shell.publish(on_long_press.clone());
+                        }
+
+                        if tick.is_repeat
+                            && let Some(on_press) = &self.on_press
+                        {
+                            shell.publish(on_press.get());
+                        }
+
+                        if let Some(next) = tick.next {
+                            shell.request_redraw_at(next);
+                        }
+                    } else {
+                        // Leaving the button while holding it cancels
+                        // the hold
+                        state.hold = None;
+                    }
+                }
             }
             _ => {}
         }
@@ -340,8 +474,26 @@ where
             Status::Active
         };
 
-        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
             self.status = Some(current_status);
+
+            if let Some(duration) = self.transition {
+                let state = tree.state.downcast_mut::<State>();
+
+                let (animation, last_redraw) = state.transition.get_or_insert_with(|| {
+                    (Animation::new(current_status).duration(duration), *now)
+                });
+
+                if animation.value() != current_status {
+                    animation.go_mut(current_status, *now);
+                }
+
+                *last_redraw = *now;
+
+                if animation.is_animating(*now) {
+                    shell.request_redraw();
+                }
+            }
         } else if self.status.is_some_and(|status| status != current_status) {
             shell.request_redraw();
         }
@@ -359,7 +511,14 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let style = theme.style(&self.class, self.status.unwrap_or(Status::Disabled));
+        let state = tree.state.downcast_ref::<State>();
+
+        let style = match &state.transition {
+            Some((animation, now)) if self.transition.is_some() => {
+                animation.interpolate_with(|status| theme.style(&self.class, status), *now)
+            }
+            _ => theme.style(&self.class, self.status.unwrap_or(Status::Disabled)),
+        };
 
         if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
             renderer.fill_quad(
@@ -462,6 +621,17 @@ pub enum Status {
     Disabled,
 }
 
+impl animation::Float for Status {
+    fn float_value(&self) -> f32 {
+        match self {
+            Status::Active => 0.0,
+            Status::Hovered => 1.0,
+            Status::Pressed => 2.0,
+            Status::Disabled => 3.0,
+        }
+    }
+}
+
 /// The style of a button.
 ///
 /// If not specified with [`Button::style`]
@@ -490,6 +660,18 @@ impl Style {
     }
 }
 
+impl Interpolable for Style {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Self {
+            background: animation::fade(self.background, other.background, ratio),
+            text_color: self.text_color.interpolated(other.text_color, ratio),
+            border: self.border.interpolated(other.border, ratio),
+            shadow: self.shadow.interpolated(other.shadow, ratio),
+            snap: if ratio < 0.5 { self.snap } else { other.snap },
+        }
+    }
+}
+
 impl Default for Style {
     fn default() -> Self {
         Self {
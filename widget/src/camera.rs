@@ -0,0 +1,637 @@
+//! Cameras display the live video of a capture device.
+//!
+//! A [`Feed`] captures the frames of a [`Device`] in a background thread
+//! while it is alive. Any amount of [`Camera`] widgets can display it, and
+//! its latest [`Frame`] can be captured at any time; for instance, to take
+//! a profile picture or to scan a QR code.
+//!
+//! Capturing is currently supported on Linux, through Video4Linux. A
+//! [`Camera`] looks for a new [`Frame`] once per frame interval of its
+//! [`Feed`], and it keeps drawing the same image until the sequence of the
+//! latest frame changes.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::camera::Feed;
+//! use iced::widget::{button, camera, column, image};
+//!
+//! struct State {
+//!     feed: Option<Feed>,
+//!     picture: Option<image::Handle>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     TakePicture,
+//! }
+//!
+//! fn new() -> State {
+//!     State {
+//!         feed: camera::devices()
+//!             .first()
+//!             .and_then(|device| Feed::open(device).ok()),
+//!         picture: None,
+//!     }
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::TakePicture => {
+//!             state.picture = state
+//!                 .feed
+//!                 .as_ref()
+//!                 .and_then(Feed::capture)
+//!                 .map(|frame| frame.handle().clone());
+//!         }
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let Some(feed) = &state.feed else {
+//!         return "No camera found".into();
+//!     };
+//!
+//!     column![
+//!         camera(feed).width(320).height(240),
+//!         button("Take picture").on_press(Message::TakePicture),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::core::border;
+use crate::core::image::{self, FilterMethod};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Duration;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Bytes, Clipboard, ContentFit, Element, Event, Layout, Length, Rectangle, Rotation, Shell, Size,
+    Widget,
+};
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+
+/// A frame that displays the live video of a [`Feed`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::camera;
+/// use iced::widget::camera::Feed;
+///
+/// struct State {
+///     feed: Feed,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     camera(&state.feed).into()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Camera<'a> {
+    feed: &'a Feed,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    border_radius: border::Radius,
+}
+
+impl<'a> Camera<'a> {
+    /// Creates a new [`Camera`] displaying the given [`Feed`].
+    pub fn new(feed: &'a Feed) -> Self {
+        Self {
+            feed,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Cover,
+            border_radius: border::Radius::default(),
+        }
+    }
+
+    /// Sets the width of the [`Camera`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Camera`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Camera`].
+    ///
+    /// Defaults to [`ContentFit::Cover`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the border radius of the [`Camera`].
+    pub fn border_radius(mut self, border_radius: impl Into<border::Radius>) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Camera<'_>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = self.feed.resolution();
+
+        layout::Node::new(limits.resolve(
+            self.width,
+            self.height,
+            Size::new(width as f32, height as f32),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(frame) = self.feed.capture()
+            && state.sequence != Some(frame.sequence)
+        {
+            state.sequence = Some(frame.sequence);
+            state.handle = Some(frame.handle);
+        }
+
+        // Frames arrive from another thread; so we check for a new one
+        // once per frame interval of the feed, and not on every redraw.
+        if self.feed.is_running() {
+            shell.request_redraw_at(*now + self.feed.interval());
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let Some(handle) = &state.handle else {
+            return;
+        };
+
+        crate::image::draw(
+            renderer,
+            layout,
+            handle,
+            None,
+            self.border_radius,
+            self.content_fit,
+            FilterMethod::Linear,
+            Rotation::default(),
+            1.0,
+            1.0,
+        );
+    }
+}
+
+/// The state of a [`Camera`].
+#[derive(Debug, Default)]
+struct State {
+    sequence: Option<u32>,
+    handle: Option<image::Handle>,
+}
+
+impl<'a, Message, Theme, Renderer> From<Camera<'a>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn from(camera: Camera<'a>) -> Self {
+        Element::new(camera)
+    }
+}
+
+/// A video capture device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Device {
+    path: PathBuf,
+    name: String,
+}
+
+impl Device {
+    /// Returns the name of the [`Device`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path of the [`Device`]; like `/dev/video0`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl std::fmt::Display for Device {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Returns the video capture devices of the system.
+///
+/// The result can be used as the options of a `pick_list` to let users
+/// choose their camera.
+pub fn devices() -> Vec<Device> {
+    platform::devices()
+}
+
+/// The live video of a [`Device`].
+///
+/// A [`Feed`] can be cheaply cloned; capturing stops once every clone is
+/// dropped.
+#[derive(Debug, Clone)]
+pub struct Feed {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    resolution: (u32, u32),
+    interval: Duration,
+    frame: Mutex<Option<Frame>>,
+    error: Mutex<Option<Error>>,
+    is_running: AtomicBool,
+}
+
+impl Feed {
+    /// The default resolution requested by [`Feed::open`].
+    pub const DEFAULT_RESOLUTION: (u32, u32) = (640, 480);
+
+    /// Starts capturing the video of the given [`Device`] at the default
+    /// resolution.
+    pub fn open(device: &Device) -> Result<Self, Error> {
+        Self::with_resolution(device, Self::DEFAULT_RESOLUTION)
+    }
+
+    /// Starts capturing the video of the given [`Device`] at the closest
+    /// resolution to the given one that it supports.
+    pub fn with_resolution(device: &Device, resolution: (u32, u32)) -> Result<Self, Error> {
+        platform::open(device, resolution)
+    }
+
+    /// Returns the resolution of the video of the [`Feed`].
+    pub fn resolution(&self) -> (u32, u32) {
+        self.shared.resolution
+    }
+
+    /// Returns the time between the frames of the [`Feed`].
+    pub fn interval(&self) -> Duration {
+        self.shared.interval
+    }
+
+    /// Returns true if the [`Feed`] is still capturing frames.
+    pub fn is_running(&self) -> bool {
+        self.shared.is_running.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Captures the latest [`Frame`] of the [`Feed`], if any.
+    pub fn capture(&self) -> Option<Frame> {
+        self.shared
+            .frame
+            .lock()
+            .ok()
+            .and_then(|frame| frame.clone())
+    }
+
+    /// Returns the [`Error`] that stopped the [`Feed`], if any.
+    pub fn error(&self) -> Option<Error> {
+        self.shared
+            .error
+            .lock()
+            .ok()
+            .and_then(|error| error.clone())
+    }
+}
+
+/// A captured frame of a [`Feed`].
+#[derive(Debug, Clone)]
+pub struct Frame {
+    sequence: u32,
+    width: u32,
+    height: u32,
+    pixels: Bytes,
+    handle: image::Handle,
+}
+
+impl Frame {
+    fn new(sequence: u32, width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        let pixels = Bytes::from(pixels);
+
+        Self {
+            sequence,
+            width,
+            height,
+            handle: image::Handle::from_rgba(width, height, pixels.clone()),
+            pixels,
+        }
+    }
+
+    /// Returns the sequence number of the [`Frame`], as counted by the
+    /// [`Device`].
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// Returns the width of the [`Frame`].
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Frame`].
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the pixels of the [`Frame`] in RGBA order.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns an image [`Handle`](image::Handle) of the [`Frame`].
+    pub fn handle(&self) -> &image::Handle {
+        &self.handle
+    }
+}
+
+/// An error capturing the video of a [`Device`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// Capturing video is not supported on this platform.
+    #[error("capturing video is not supported on this platform")]
+    Unsupported,
+
+    /// The [`Device`] does not produce frames in any supported format.
+    #[error("the device does not support any known pixel format (found {0})")]
+    UnsupportedFormat(String),
+
+    /// The [`Device`] could not be accessed.
+    #[error("the device could not be accessed: {0}")]
+    Io(Arc<io::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::{Device, Error, Feed, Frame, Shared};
+
+    use v4l::buffer::Type;
+    use v4l::io::mmap::Stream;
+    use v4l::io::traits::CaptureStream;
+    use v4l::video::Capture;
+
+    use std::io;
+    use std::sync::atomic::{self, AtomicBool};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// The amount of buffers the driver fills while we convert frames.
+    const BUFFERS: u32 = 4;
+
+    /// The time between frames of devices that do not report it.
+    const DEFAULT_INTERVAL: Duration = Duration::from_nanos(1_000_000_000 / 30);
+
+    pub fn devices() -> Vec<Device> {
+        v4l::context::enum_devices()
+            .into_iter()
+            .filter(|node| {
+                v4l::Device::with_path(node.path())
+                    .and_then(|device| device.query_caps())
+                    .is_ok_and(|caps| {
+                        caps.capabilities
+                            .contains(v4l::capability::Flags::VIDEO_CAPTURE)
+                    })
+            })
+            .map(|node| Device {
+                path: node.path().to_path_buf(),
+                name: node
+                    .name()
+                    .unwrap_or_else(|| node.path().display().to_string()),
+            })
+            .collect()
+    }
+
+    pub fn open(device: &Device, resolution: (u32, u32)) -> Result<Feed, Error> {
+        let capture = v4l::Device::with_path(&device.path)?;
+
+        let mut format = capture.format()?;
+        format.width = resolution.0;
+        format.height = resolution.1;
+        format.fourcc = v4l::FourCC::new(b"YUYV");
+
+        let format = capture.set_format(&format)?;
+
+        if format.fourcc != v4l::FourCC::new(b"YUYV") {
+            return Err(Error::UnsupportedFormat(format.fourcc.to_string()));
+        }
+
+        let (width, height) = (format.width, format.height);
+
+        // Rows may be padded by the driver
+        let stride = (format.stride as usize).max(width as usize * 2);
+
+        let interval = capture
+            .params()
+            .ok()
+            .map(|parameters| parameters.interval)
+            .filter(|interval| interval.numerator > 0 && interval.denominator > 0)
+            .map_or(DEFAULT_INTERVAL, |interval| {
+                Duration::from_secs(u64::from(interval.numerator)) / interval.denominator
+            });
+
+        let shared = Arc::new(Shared {
+            resolution: (width, height),
+            interval,
+            frame: Mutex::new(None),
+            error: Mutex::new(None),
+            is_running: AtomicBool::new(true),
+        });
+
+        let feed = Feed {
+            shared: shared.clone(),
+        };
+
+        // The thread only holds a weak reference; so it stops once
+        // every clone of the feed is dropped.
+        let shared = Arc::downgrade(&shared);
+
+        let _ = std::thread::Builder::new()
+            .name(format!("iced-camera-{}", device.name))
+            .spawn(move || {
+                let result: io::Result<()> = (|| {
+                    let mut stream = Stream::with_buffers(&capture, Type::VideoCapture, BUFFERS)?;
+
+                    loop {
+                        let (buffer, metadata) = stream.next()?;
+
+                        let Some(shared) = shared.upgrade() else {
+                            return Ok(());
+                        };
+
+                        let bytes_used = (metadata.bytesused as usize).min(buffer.len());
+
+                        let Some(pixels) =
+                            yuyv_to_rgba(&buffer[..bytes_used], width, height, stride)
+                        else {
+                            log::warn!("Skipping incomplete camera frame {}", metadata.sequence);
+                            continue;
+                        };
+
+                        let frame = Frame::new(metadata.sequence, width, height, pixels);
+
+                        if let Ok(mut latest) = shared.frame.lock() {
+                            *latest = Some(frame);
+                        }
+                    }
+                })();
+
+                if let Some(shared) = shared.upgrade() {
+                    if let Err(error) = result
+                        && let Ok(mut latest) = shared.error.lock()
+                    {
+                        *latest = Some(Error::from(error));
+                    }
+
+                    shared.is_running.store(false, atomic::Ordering::Relaxed);
+                }
+            })?;
+
+        Ok(feed)
+    }
+
+    /// Converts packed YUV 4:2:2 pixels, with rows of `stride` bytes, to
+    /// RGBA.
+    ///
+    /// Returns `None` if there are not enough pixels for the whole frame.
+    fn yuyv_to_rgba(yuyv: &[u8], width: u32, height: u32, stride: usize) -> Option<Vec<u8>> {
+        let row = width as usize * 2;
+
+        if height == 0 || yuyv.len() < stride * (height as usize - 1) + row {
+            return None;
+        }
+
+        let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+
+        for chunk in yuyv
+            .chunks(stride)
+            .take(height as usize)
+            .flat_map(|line| line[..row].chunks_exact(4))
+        {
+            let [y0, u, y1, v] = [chunk[0], chunk[1], chunk[2], chunk[3]];
+
+            for y in [y0, y1] {
+                let c = f32::from(y) - 16.0;
+                let d = f32::from(u) - 128.0;
+                let e = f32::from(v) - 128.0;
+
+                let r = 1.164 * c + 1.596 * e;
+                let g = 1.164 * c - 0.392 * d - 0.813 * e;
+                let b = 1.164 * c + 2.017 * d;
+
+                rgba.extend([
+                    r.clamp(0.0, 255.0) as u8,
+                    g.clamp(0.0, 255.0) as u8,
+                    b.clamp(0.0, 255.0) as u8,
+                    255,
+                ]);
+            }
+        }
+
+        Some(rgba)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn it_skips_the_padding_of_rows() {
+            // Two rows of two black pixels, padded to 8 bytes
+            let yuyv = [
+                16, 128, 16, 128, 0xAA, 0xAA, 0xAA, 0xAA, //
+                16, 128, 16, 128,
+            ];
+
+            let rgba = yuyv_to_rgba(&yuyv, 2, 2, 8).expect("Convert frame");
+
+            assert_eq!(rgba.len(), 2 * 2 * 4);
+            assert!(rgba.chunks_exact(4).all(|pixel| pixel == [0, 0, 0, 255]));
+
+            assert_eq!(yuyv_to_rgba(&yuyv[..10], 2, 2, 8), None);
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use super::{Device, Error, Feed};
+
+    pub fn devices() -> Vec<Device> {
+        Vec::new()
+    }
+
+    pub fn open(_device: &Device, _resolution: (u32, u32)) -> Result<Feed, Error> {
+        Err(Error::Unsupported)
+    }
+}
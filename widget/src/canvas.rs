@@ -56,8 +56,8 @@ pub use crate::Action;
 pub use crate::core::event::Event;
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
-    Fill, Gradient, Image, LineCap, LineDash, LineJoin, Path, Stroke, Style, Text, fill, gradient,
-    path, stroke,
+    Fill, Gradient, Image, LineCap, LineDash, LineJoin, Path, Recorder, Stroke, Style, Text, fill,
+    gradient, path, stroke,
 };
 
 use crate::core::event;
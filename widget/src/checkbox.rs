@@ -299,6 +299,8 @@ where
                             align_y: alignment::Vertical::Top,
                             shaping: self.text_shaping,
                             wrapping: self.text_wrapping,
+                            hyphenation: None,
+                            max_lines: None,
                         },
                     )
                 } else {
@@ -448,6 +450,7 @@ where
                 state.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
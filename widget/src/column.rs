@@ -9,6 +9,7 @@ use crate::core::{
     Clipboard, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size, Vector,
     Widget,
 };
+use crate::z_index;
 
 /// A container that distributes its contents vertically.
 ///
@@ -308,17 +309,16 @@ where
                 viewport
             };
 
-            for ((child, tree), layout) in self
-                .children
-                .iter()
-                .zip(&tree.children)
-                .zip(layout.children())
-                .filter(|(_, layout)| layout.bounds().intersects(viewport))
-            {
-                child
-                    .as_widget()
-                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
-            }
+            z_index::draw_children(
+                &self.children,
+                tree,
+                layout,
+                renderer,
+                theme,
+                style,
+                cursor,
+                viewport,
+            );
         }
     }
 
@@ -0,0 +1,670 @@
+//! Command palettes let users search and run the actions of an application
+//! with the keyboard.
+//!
+//! A command palette is an overlay with a search box and a list of
+//! [`Command`]s ranked by how well they fuzzy match the query. Recently run
+//! commands are ranked first.
+//!
+//! Use the arrow keys to move the selection, `Enter` to run the selected
+//! command and `Escape` to close the palette.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::command_palette::{self, Command};
+//! use iced::widget::{stack, text};
+//!
+//! struct State {
+//!     palette: command_palette::State,
+//!     commands: Vec<Command<Message>>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     OpenPalette,
+//!     Palette(command_palette::Action),
+//!     Save,
+//!     Quit,
+//! }
+//!
+//! impl State {
+//!     fn new() -> Self {
+//!         Self {
+//!             palette: command_palette::State::new(),
+//!             commands: vec![
+//!                 Command::new("Save", Message::Save).shortcut("Ctrl+S"),
+//!                 Command::new("Quit", Message::Quit).shortcut("Ctrl+Q"),
+//!             ],
+//!         }
+//!     }
+//!
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::OpenPalette => self.palette.open(),
+//!             Message::Palette(action) => {
+//!                 if let Some(message) = self.palette.perform(action, &self.commands) {
+//!                     self.update(message);
+//!                 }
+//!             }
+//!             Message::Save => { /* ... */ }
+//!             Message::Quit => { /* ... */ }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         let content = text("Press Ctrl+P to open the command palette");
+//!
+//!         if self.palette.is_open() {
+//!             stack![
+//!                 content,
+//!                 command_palette::view(&self.palette, &self.commands, Message::Palette),
+//!             ]
+//!             .into()
+//!         } else {
+//!             content.into()
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::keyboard::{self, key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::padding;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    self, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Theme, Vector,
+};
+use crate::{column, container, mouse_area, opaque, row, text, text_input};
+
+use std::cmp::Reverse;
+
+/// The maximum amount of commands displayed at once.
+const MAX_RESULTS: usize = 10;
+
+/// The maximum amount of recently run commands remembered.
+const MAX_RECENT: usize = 10;
+
+/// An action that can be run from a command palette.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Command<Message> {
+    /// The name of the [`Command`], used for searching.
+    pub name: String,
+    /// The keyboard shortcut of the [`Command`], if any.
+    pub shortcut: Option<String>,
+    /// The message produced when the [`Command`] is run.
+    pub message: Message,
+}
+
+impl<Message> Command<Message> {
+    /// Creates a new [`Command`] with the given name and message.
+    pub fn new(name: impl Into<String>, message: Message) -> Self {
+        Self {
+            name: name.into(),
+            shortcut: None,
+            message,
+        }
+    }
+
+    /// Sets the keyboard shortcut displayed next to the [`Command`].
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+}
+
+/// An interaction with a command palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// The query was changed.
+    Query(String),
+    /// The previous result was selected.
+    Previous,
+    /// The next result was selected.
+    Next,
+    /// The result at the given position was hovered.
+    Hover(usize),
+    /// The selected result was submitted.
+    Submit,
+    /// The result at the given position was clicked.
+    Select(usize),
+    /// The command palette was dismissed.
+    Close,
+}
+
+/// The state of a command palette.
+#[derive(Debug, Clone)]
+pub struct State {
+    id: widget::Id,
+    query: String,
+    selected: usize,
+    recent: Vec<String>,
+    is_open: bool,
+}
+
+impl State {
+    /// Creates a new closed [`State`].
+    pub fn new() -> Self {
+        Self {
+            id: widget::Id::unique(),
+            query: String::new(),
+            selected: 0,
+            recent: Vec::new(),
+            is_open: false,
+        }
+    }
+
+    /// Returns true if the command palette is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the command palette with an empty query.
+    pub fn open(&mut self) {
+        self.query.clear();
+        self.selected = 0;
+        self.is_open = true;
+    }
+
+    /// Closes the command palette.
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Opens the command palette if closed; closes it otherwise.
+    pub fn toggle(&mut self) {
+        if self.is_open {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Returns the current query of the command palette.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the names of the recently run commands, from most to
+    /// least recent.
+    pub fn recent(&self) -> impl Iterator<Item = &str> {
+        self.recent.iter().map(String::as_str)
+    }
+
+    /// Returns the indices of the given commands matching the current
+    /// query, from best to worst.
+    ///
+    /// Recently run commands are ranked first among similar matches.
+    pub fn results<Message>(&self, commands: &[Command<Message>]) -> Vec<usize> {
+        let mut results: Vec<(i32, usize)> = commands
+            .iter()
+            .enumerate()
+            .filter_map(|(index, command)| {
+                let score = score(&self.query, &command.name)?;
+
+                let recency = self
+                    .recent
+                    .iter()
+                    .position(|name| *name == command.name)
+                    .map_or(0, |rank| (MAX_RECENT - rank) as i32);
+
+                Some((score + recency, index))
+            })
+            .collect();
+
+        results.sort_by_key(|(score, index)| (Reverse(*score), *index));
+        results.truncate(MAX_RESULTS);
+
+        results.into_iter().map(|(_, index)| index).collect()
+    }
+
+    /// Applies an [`Action`] to the [`State`].
+    ///
+    /// Returns the message of the [`Command`] run, if any; in which case
+    /// the command palette is closed.
+    pub fn perform<Message: Clone>(
+        &mut self,
+        action: Action,
+        commands: &[Command<Message>],
+    ) -> Option<Message> {
+        let results = self.results(commands);
+
+        match action {
+            Action::Query(query) => {
+                self.query = query;
+                self.selected = 0;
+            }
+            Action::Previous if !results.is_empty() => {
+                self.selected = self
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(results.len() - 1)
+                    .min(results.len() - 1);
+            }
+            Action::Next if !results.is_empty() => {
+                self.selected = (self.selected + 1) % results.len();
+            }
+            Action::Hover(position) => {
+                self.selected = position;
+            }
+            Action::Submit => {
+                return self.run(commands, results.get(self.selected).copied()?);
+            }
+            Action::Select(position) => {
+                return self.run(commands, results.get(position).copied()?);
+            }
+            Action::Close => {
+                self.close();
+            }
+            Action::Previous | Action::Next => {}
+        }
+
+        None
+    }
+
+    fn run<Message: Clone>(
+        &mut self,
+        commands: &[Command<Message>],
+        index: usize,
+    ) -> Option<Message> {
+        let command = commands.get(index)?;
+
+        self.recent.retain(|name| *name != command.name);
+        self.recent.insert(0, command.name.clone());
+        self.recent.truncate(MAX_RECENT);
+
+        self.close();
+
+        Some(command.message.clone())
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Displays a command palette with the given [`State`] and [`Command`]s.
+///
+/// The command palette covers its whole area; so it is usually placed
+/// on top of the contents of a window with a [`Stack`](crate::Stack).
+pub fn view<'a, Message, Theme, Renderer>(
+    state: &'a State,
+    commands: &'a [Command<Message>],
+    on_action: impl Fn(Action) -> Message + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    let results = state.results(commands);
+
+    let items: Vec<Element<'a, Message, Theme, Renderer>> = if results.is_empty() {
+        vec![
+            container(text("No matching commands"))
+                .padding([6, 10])
+                .into(),
+        ]
+    } else {
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(position, index)| {
+                let command = &commands[index];
+
+                let shortcut = command.shortcut.as_deref().map(|shortcut| {
+                    container(text(shortcut).size(12))
+                        .padding([2, 6])
+                        .class(Theme::shortcut())
+                });
+
+                mouse_area(
+                    container(
+                        row![text(command.name.as_str()).width(Length::Fill), shortcut]
+                            .spacing(10)
+                            .align_y(alignment::Vertical::Center),
+                    )
+                    .width(Length::Fill)
+                    .padding([6, 10])
+                    .class(Theme::command(position == state.selected)),
+                )
+                .on_press(on_action(Action::Select(position)))
+                .on_enter(on_action(Action::Hover(position)))
+                .interaction(mouse::Interaction::Pointer)
+                .into()
+            })
+            .collect()
+    };
+
+    let on_previous = on_action(Action::Previous);
+    let on_next = on_action(Action::Next);
+    let on_submit = on_action(Action::Submit);
+    let on_close = on_action(Action::Close);
+
+    let input = text_input("Search commands...", &state.query)
+        .id(state.id.clone())
+        .on_input(move |query| on_action(Action::Query(query)))
+        .padding(10);
+
+    let palette = container(column![input, column(items).spacing(2)].spacing(8))
+        .padding(8)
+        .max_width(600)
+        .class(Theme::palette());
+
+    let backdrop = container(opaque(palette))
+        .center_x(Length::Fill)
+        .height(Length::Fill)
+        .padding(padding::top(80))
+        .class(Theme::backdrop());
+
+    Element::new(Keys {
+        content: opaque(mouse_area(backdrop).on_press(on_close.clone())),
+        id: state.id.clone(),
+        on_previous,
+        on_next,
+        on_submit,
+        on_close,
+    })
+}
+
+/// Handles the keyboard interactions of a command palette and focuses its
+/// search box when it appears.
+struct Keys<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    id: widget::Id,
+    on_previous: Message,
+    on_next: Message,
+    on_submit: Message,
+    on_close: Message,
+}
+
+#[derive(Default)]
+struct KeysState {
+    is_focused: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Keys<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<KeysState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(KeysState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<KeysState>();
+
+        if !state.is_focused {
+            state.is_focused = true;
+
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut operation::focusable::focus(self.id.clone()),
+            );
+
+            shell.request_redraw();
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modifiers,
+            ..
+        }) = event
+        {
+            let message = match named {
+                key::Named::ArrowUp => Some(&self.on_previous),
+                key::Named::ArrowDown => Some(&self.on_next),
+                key::Named::Tab if modifiers.shift() => Some(&self.on_previous),
+                key::Named::Tab => Some(&self.on_next),
+                key::Named::Enter => Some(&self.on_submit),
+                key::Named::Escape => Some(&self.on_close),
+                _ => None,
+            };
+
+            if let Some(message) = message {
+                shell.publish(message.clone());
+                shell.capture_event();
+                return;
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<core::overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Scores how well the query fuzzy matches the candidate; or `None` if
+/// it does not match at all.
+///
+/// Consecutive matches and matches at the start of words score higher.
+fn score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.chars().collect();
+
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous = None;
+
+    for c in query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+    {
+        let index = (position..candidate.len())
+            .find(|&i| candidate[i].to_lowercase().eq(std::iter::once(c)))?;
+
+        score += 1;
+
+        if previous.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+
+        if index == 0
+            || !candidate[index - 1].is_alphanumeric()
+            || (candidate[index].is_uppercase() && candidate[index - 1].is_lowercase())
+        {
+            score += 8;
+        }
+
+        score -= (index - position).min(5) as i32;
+
+        previous = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+/// The theme catalog of a command palette.
+pub trait Catalog: container::Catalog + text_input::Catalog + text::Catalog {
+    /// The styling class of the area behind the command palette.
+    fn backdrop<'a>() -> <Self as container::Catalog>::Class<'a>;
+
+    /// The styling class of the command palette itself.
+    fn palette<'a>() -> <Self as container::Catalog>::Class<'a>;
+
+    /// The styling class of a [`Command`]; selected or not.
+    fn command<'a>(is_selected: bool) -> <Self as container::Catalog>::Class<'a>;
+
+    /// The styling class of the shortcut of a [`Command`].
+    fn shortcut<'a>() -> <Self as container::Catalog>::Class<'a>;
+}
+
+impl Catalog for Theme {
+    fn backdrop<'a>() -> <Self as container::Catalog>::Class<'a> {
+        Box::new(|_theme| container::background(Color::BLACK.scale_alpha(0.3)))
+    }
+
+    fn palette<'a>() -> <Self as container::Catalog>::Class<'a> {
+        Box::new(container::bordered_box)
+    }
+
+    fn command<'a>(is_selected: bool) -> <Self as container::Catalog>::Class<'a> {
+        if is_selected {
+            Box::new(|theme: &Theme| {
+                let palette = theme.extended_palette();
+
+                container::Style {
+                    background: Some(palette.primary.weak.color.into()),
+                    text_color: Some(palette.primary.weak.text),
+                    border: border::rounded(4),
+                    ..container::Style::default()
+                }
+            })
+        } else {
+            Box::new(container::transparent)
+        }
+    }
+
+    fn shortcut<'a>() -> <Self as container::Catalog>::Class<'a> {
+        Box::new(container::rounded_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_ranks_recent_commands_first() {
+        let commands = vec![
+            Command::new("Open File", 0),
+            Command::new("Save File", 1),
+            Command::new("Close Window", 2),
+        ];
+
+        let mut state = State::new();
+
+        assert_eq!(state.results(&commands), vec![0, 1, 2]);
+        assert_eq!(state.perform(Action::Select(2), &commands), Some(2));
+        assert_eq!(state.results(&commands), vec![2, 0, 1]);
+
+        let _ = state.perform(Action::Query("sf".to_owned()), &commands);
+        assert_eq!(state.results(&commands), vec![1]);
+
+        let _ = state.perform(Action::Query("xyz".to_owned()), &commands);
+        assert!(state.results(&commands).is_empty());
+    }
+}
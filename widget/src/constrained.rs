@@ -0,0 +1,280 @@
+//! Constrain the size of some content between a minimum and a maximum.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::constrained;
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     constrained("Cover art")
+//!         .min_width(120)
+//!         .ideal_width(240)
+//!         .max_width(360)
+//!         .into()
+//! }
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A widget that constrains the size of its contents.
+///
+/// A [`Constrained`] widget never becomes smaller than its minimum size nor
+/// larger than its maximum size. When an ideal size is set, it takes that
+/// size if its parent allows it; otherwise, it follows the size of its
+/// contents.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::constrained;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     constrained("Cover art")
+///         .min_width(120)
+///         .ideal_width(240)
+///         .max_width(360)
+///         .into()
+/// }
+/// ```
+pub struct Constrained<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    min: Size,
+    max: Size,
+    ideal: Size<Option<f32>>,
+}
+
+impl<'a, Message, Theme, Renderer> Constrained<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a [`Constrained`] widget with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            min: Size::ZERO,
+            max: Size::INFINITE,
+            ideal: Size::new(None, None),
+        }
+    }
+
+    /// Sets the minimum width of the [`Constrained`] widget.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min.width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Constrained`] widget.
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min.height = min_height.into().0;
+        self
+    }
+
+    /// Sets the maximum width of the [`Constrained`] widget.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max.width = max_width.into().0;
+        self
+    }
+
+    /// Sets the maximum height of the [`Constrained`] widget.
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max.height = max_height.into().0;
+        self
+    }
+
+    /// Sets the ideal width of the [`Constrained`] widget.
+    pub fn ideal_width(mut self, ideal_width: impl Into<Pixels>) -> Self {
+        self.ideal.width = Some(ideal_width.into().0);
+        self
+    }
+
+    /// Sets the ideal height of the [`Constrained`] widget.
+    pub fn ideal_height(mut self, ideal_height: impl Into<Pixels>) -> Self {
+        self.ideal.height = Some(ideal_height.into().0);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Constrained<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        let size = self.content.as_widget().size();
+
+        Size {
+            width: self.ideal.width.map(Length::Fixed).unwrap_or(size.width),
+            height: self.ideal.height.map(Length::Fixed).unwrap_or(size.height),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size();
+
+        let limits = limits
+            .max_width(self.max.width)
+            .max_height(self.max.height)
+            .min_width(self.min.width)
+            .min_height(self.min.height)
+            .width(size.width)
+            .height(size.height);
+
+        let node = self.content.as_widget_mut().layout(tree, renderer, &limits);
+        let size = limits.resolve(size.width, size.height, node.size());
+
+        layout::Node::with_children(size, vec![node])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if let Some(clipped_viewport) = bounds.intersection(viewport) {
+            self.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                &clipped_viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Constrained<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(
+        constrained: Constrained<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(constrained)
+    }
+}
@@ -0,0 +1,1242 @@
+//! Context menus display a list of actions for some content when it is
+//! right-clicked or long-pressed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::context_menu::Item;
+//! use iced::widget::{context_menu, text};
+//!
+//! struct State {
+//!    is_favorite: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Play,
+//!     ToggleFavorite,
+//!     MoveTo(String),
+//!     Uninstall,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     context_menu(text("Right-click me!"), move || {
+//!         vec![
+//!             Item::new("Play", Message::Play).shortcut("Enter"),
+//!             Item::new(
+//!                 if state.is_favorite { "Unfavorite" } else { "Favorite" },
+//!                 Message::ToggleFavorite,
+//!             ),
+//!             Item::submenu(
+//!                 "Move to",
+//!                 ["Action", "Puzzle", "Racing"]
+//!                     .into_iter()
+//!                     .map(|shelf| Item::new(shelf, Message::MoveTo(shelf.to_owned()))),
+//!             ),
+//!             Item::separator(),
+//!             Item::new("Uninstall", Message::Uninstall),
+//!         ]
+//!     })
+//!     .into()
+//! }
+//! ```
+//!
+//! A [`ContextMenu`] can also be opened with the menu key (or `Shift+F10`)
+//! while the cursor is over its content or while it is focused with the
+//! [`focus_next`] and [`focus_previous`] operations. While open, the arrow
+//! keys navigate the menu and its submenus, `Enter` runs the highlighted
+//! item, and typing the first letters of an item highlights it; which makes
+//! it usable with a controller or a remote.
+//!
+//! [`focus_next`]: crate::core::widget::operation::focusable::focus_next
+//! [`focus_previous`]: crate::core::widget::operation::focusable::focus_previous
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+use crate::popover::{Placement, Positioning};
+
+/// The distance a finger may travel during a long press before it is
+/// no longer considered one.
+const LONG_PRESS_TOLERANCE: f32 = 10.0;
+
+/// The time after which typed characters start a new search.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The padding between the border of a menu and its items.
+const MENU_PADDING: f32 = 4.0;
+
+/// The height of a separator item.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// A widget that displays a menu of actions for its content when it is
+/// right-clicked or long-pressed.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::context_menu::Item;
+/// use iced::widget::{context_menu, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     context_menu(text("Right-click me!"), || {
+///         vec![
+///             Item::new("Copy", Message::Copy),
+///             Item::new("Paste", Message::Paste),
+///         ]
+///     })
+///     .into()
+/// }
+/// ```
+pub struct ContextMenu<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    content: Element<'a, Message, Theme, Renderer>,
+    menu: Box<dyn Fn() -> Vec<Item<Message>> + 'a>,
+    items: Vec<Item<Message>>,
+    width: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    long_press: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default minimum width of a menu.
+    const DEFAULT_WIDTH: f32 = 160.0;
+
+    /// The default padding of the items of a menu.
+    const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// The default duration of a long press that opens a [`ContextMenu`].
+    const DEFAULT_LONG_PRESS: Duration = Duration::from_millis(500);
+
+    /// Creates a new [`ContextMenu`] for the given content.
+    ///
+    /// The menu builder is only called while the menu is open.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        menu: impl Fn() -> Vec<Item<Message>> + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            content: content.into(),
+            menu: Box::new(menu),
+            items: Vec::new(),
+            width: Self::DEFAULT_WIDTH,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            long_press: Self::DEFAULT_LONG_PRESS,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`ContextMenu`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the minimum width of the menus of the [`ContextMenu`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the items of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the items of the [`ContextMenu`].
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the items of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the duration a finger must be held on the content to open the
+    /// [`ContextMenu`] on touch screens.
+    pub fn long_press(mut self, duration: Duration) -> Self {
+        self.long_press = duration;
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let now = Instant::now();
+
+        state.clear_closed();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.open(position, None, shell);
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if bounds.contains(*position) && state.menu.is_none() {
+                    state.press = Some(Press {
+                        at: now,
+                        finger: *id,
+                        origin: *position,
+                    });
+
+                    shell.request_redraw_at(now + self.long_press);
+                }
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(press) = state.press
+                    && press.finger == *id
+                    && position.distance(press.origin) > LONG_PRESS_TOLERANCE
+                {
+                    state.press = None;
+                }
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if state.press.is_some_and(|press| press.finger == *id) {
+                    state.press = None;
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                if let Some(press) = state.press {
+                    if press.at.elapsed() >= self.long_press {
+                        state.press = None;
+                        state.open(press.origin, None, shell);
+                    } else {
+                        shell.request_redraw_at(now + self.long_press - press.at.elapsed());
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                let is_menu_key = matches!(key, keyboard::Key::Named(key::Named::ContextMenu))
+                    || (modifiers.shift() && matches!(key, keyboard::Key::Named(key::Named::F10)));
+
+                if is_menu_key
+                    && state.menu.is_none()
+                    && (state.is_focused || cursor.is_over(bounds))
+                {
+                    let origin = cursor.position_over(bounds).unwrap_or(bounds.center());
+                    let highlighted = next_selectable(&(self.menu)(), None, 1);
+
+                    state.open(origin, highlighted, shell);
+                    shell.capture_event();
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        state.clear_closed();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let menu = if let Some(menu) = &mut state.menu {
+            self.items = (self.menu)();
+
+            Some(overlay::Element::new(Box::new(Overlay {
+                menu,
+                translation,
+                items: &self.items,
+                width: self.width,
+                padding: self.padding,
+                text_size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                text_line_height: self.text_line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                class: &self.class,
+            })))
+        } else {
+            None
+        };
+
+        if content.is_some() || menu.is_some() {
+            Some(overlay::Group::with_children(content.into_iter().chain(menu).collect()).overlay())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(context_menu)
+    }
+}
+
+/// An entry of a [`ContextMenu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item<Message> {
+    label: String,
+    shortcut: Option<String>,
+    kind: Kind<Message>,
+    is_enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind<Message> {
+    Action(Message),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] that produces the given message when chosen.
+    pub fn new(label: impl Into<String>, message: Message) -> Self {
+        Self {
+            label: label.into(),
+            shortcut: None,
+            kind: Kind::Action(message),
+            is_enabled: true,
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    pub fn submenu(label: impl Into<String>, items: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            label: label.into(),
+            shortcut: None,
+            kind: Kind::Submenu(items.into_iter().collect()),
+            is_enabled: true,
+        }
+    }
+
+    /// Creates a new [`Item`] that separates groups of items.
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            shortcut: None,
+            kind: Kind::Separator,
+            is_enabled: false,
+        }
+    }
+
+    /// Sets the keyboard shortcut displayed next to the [`Item`].
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets whether the [`Item`] can be chosen.
+    ///
+    /// Disabled items are displayed, but cannot be highlighted.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.is_enabled && !matches!(self.kind, Kind::Separator)
+    }
+
+    fn submenu_items(&self) -> Option<&[Self]> {
+        match &self.kind {
+            Kind::Submenu(items) => Some(items),
+            Kind::Action(_) | Kind::Separator => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    is_focused: bool,
+    menu: Option<Menu>,
+    press: Option<Press>,
+}
+
+impl State {
+    fn open<Message>(
+        &mut self,
+        origin: Point,
+        highlighted: Option<usize>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        self.menu = Some(Menu {
+            origin,
+            levels: vec![highlighted],
+            query: String::new(),
+            last_typed: None,
+            is_closed: false,
+        });
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn clear_closed(&mut self) {
+        if self.menu.as_ref().is_some_and(|menu| menu.is_closed) {
+            self.menu = None;
+        }
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    at: Instant,
+    finger: touch::Finger,
+    origin: Point,
+}
+
+/// An open menu and its open submenus.
+#[derive(Debug, Clone)]
+struct Menu {
+    origin: Point,
+    /// The highlighted item of each open menu; each one after the first is
+    /// the submenu of the highlighted item of the previous one.
+    levels: Vec<Option<usize>>,
+    query: String,
+    last_typed: Option<Instant>,
+    is_closed: bool,
+}
+
+impl Menu {
+    fn highlight(&mut self, depth: usize, index: Option<usize>) {
+        self.levels.truncate(depth + 1);
+        self.levels[depth] = index;
+    }
+}
+
+/// Returns the items of the open menu at the given depth.
+fn items_at<'a, Message>(
+    items: &'a [Item<Message>],
+    levels: &[Option<usize>],
+    depth: usize,
+) -> Option<&'a [Item<Message>]> {
+    levels[..depth]
+        .iter()
+        .try_fold(items, |items, highlighted| {
+            items.get((*highlighted)?)?.submenu_items()
+        })
+}
+
+/// Returns the closest selectable item in the given direction, wrapping
+/// around the ends.
+fn next_selectable<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    direction: isize,
+) -> Option<usize> {
+    let count = items.len() as isize;
+
+    if count == 0 {
+        return None;
+    }
+
+    let start = current.map_or(if direction > 0 { -1 } else { count }, |index| {
+        index as isize
+    });
+
+    (1..=count)
+        .map(|step| (start + step * direction).rem_euclid(count) as usize)
+        .find(|&index| items[index].is_selectable())
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menu: &'b mut Menu,
+    translation: Vector,
+    items: &'b [Item<Message>],
+    width: f32,
+    padding: Padding,
+    text_size: Pixels,
+    text_line_height: text::LineHeight,
+    font: Renderer::Font,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn item_height(&self, item: &Item<Message>) -> f32 {
+        match item.kind {
+            Kind::Separator => SEPARATOR_HEIGHT,
+            Kind::Action(_) | Kind::Submenu(_) => {
+                self.text_line_height.to_absolute(self.text_size).0 + self.padding.vertical()
+            }
+        }
+    }
+
+    fn measure(&self, renderer: &Renderer, content: &str) -> f32 {
+        Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size,
+            line_height: self.text_line_height,
+            font: self.font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        })
+        .min_width()
+    }
+
+    fn layout_menu(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+        anchor: Rectangle,
+        placement: Placement,
+        viewport: Rectangle,
+    ) -> layout::Node {
+        let accessory = self.text_size.0 * 2.0;
+
+        let width = items
+            .iter()
+            .filter(|item| !matches!(item.kind, Kind::Separator))
+            .map(|item| {
+                let shortcut = item
+                    .shortcut
+                    .as_deref()
+                    .map_or(0.0, |shortcut| self.measure(renderer, shortcut));
+
+                self.measure(renderer, &item.label) + shortcut + accessory
+            })
+            .fold(self.width, f32::max)
+            + self.padding.horizontal();
+
+        let mut y = MENU_PADDING;
+
+        let children = items
+            .iter()
+            .map(|item| {
+                let height = self.item_height(item);
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(MENU_PADDING, y));
+
+                y += height;
+                node
+            })
+            .collect();
+
+        let size = Size::new(width + MENU_PADDING * 2.0, y + MENU_PADDING);
+
+        let placed = Positioning {
+            placement,
+            align: Alignment::Start,
+            gap: 0.0,
+            flip: true,
+            shift: true,
+        }
+        .place(anchor, size, viewport);
+
+        layout::Node::with_children(size, children).move_to(placed.bounds.position())
+    }
+
+    fn hovered(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, usize)> {
+        let position = cursor.position()?;
+
+        layout
+            .children()
+            .enumerate()
+            .rev()
+            .find_map(|(depth, menu)| {
+                if !menu.bounds().contains(position) {
+                    return None;
+                }
+
+                let index = menu
+                    .children()
+                    .position(|item| item.bounds().contains(position));
+
+                Some((depth, index?))
+            })
+    }
+
+    fn choose(&mut self, depth: usize, index: usize, shell: &mut Shell<'_, Message>) {
+        let Some(item) = items_at(self.items, &self.menu.levels, depth)
+            .and_then(|items| items.get(index))
+            .filter(|item| item.is_selectable())
+        else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Action(message) => {
+                shell.publish(message.clone());
+                self.close(shell);
+            }
+            Kind::Submenu(items) => {
+                let highlighted = next_selectable(items, None, 1);
+
+                self.menu.highlight(depth, Some(index));
+                self.menu.levels.push(highlighted);
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+            Kind::Separator => {}
+        }
+    }
+
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        self.menu.is_closed = true;
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn type_ahead(&mut self, text: &str, shell: &mut Shell<'_, Message>) {
+        let now = Instant::now();
+
+        if self
+            .menu
+            .last_typed
+            .is_none_or(|last_typed| now.duration_since(last_typed) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.menu.query.clear();
+        }
+
+        self.menu.query.push_str(&text.to_lowercase());
+        self.menu.last_typed = Some(now);
+
+        let depth = self.menu.levels.len() - 1;
+
+        let Some(items) = items_at(self.items, &self.menu.levels, depth) else {
+            return;
+        };
+
+        let current = self.menu.levels[depth];
+
+        // A repeated single character cycles through the matching items
+        let start = if self.menu.query.chars().count() == 1 {
+            current.map_or(0, |index| index + 1)
+        } else {
+            current.unwrap_or(0)
+        };
+
+        let matched = (0..items.len())
+            .map(|offset| (start + offset) % items.len())
+            .find(|&index| {
+                items[index].is_selectable()
+                    && items[index]
+                        .label
+                        .to_lowercase()
+                        .starts_with(&self.menu.query)
+            });
+
+        if let Some(index) = matched {
+            self.menu.highlight(depth, Some(index));
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        if self.menu.is_closed {
+            return layout::Node::new(Size::ZERO);
+        }
+
+        let viewport = Rectangle::with_size(bounds);
+
+        let mut menus: Vec<layout::Node> = Vec::with_capacity(self.menu.levels.len());
+
+        for depth in 0..self.menu.levels.len() {
+            let Some(items) = items_at(self.items, &self.menu.levels, depth) else {
+                self.menu.levels.truncate(depth);
+                break;
+            };
+
+            let node = match menus.last() {
+                None => self.layout_menu(
+                    renderer,
+                    items,
+                    Rectangle::new(self.menu.origin + self.translation, Size::ZERO),
+                    Placement::Bottom,
+                    viewport,
+                ),
+                Some(parent) => {
+                    let index = self.menu.levels[depth - 1].unwrap_or_default();
+                    let item = parent.children()[index].bounds();
+                    let offset = Vector::new(parent.bounds().x, parent.bounds().y);
+
+                    self.layout_menu(renderer, items, item + offset, Placement::Right, viewport)
+                }
+            };
+
+            menus.push(node);
+        }
+
+        layout::Node::with_children(bounds, menus)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.menu.is_closed {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((depth, index)) = self.hovered(layout, cursor)
+                    && let Some(item) = items_at(self.items, &self.menu.levels, depth)
+                        .and_then(|items| items.get(index))
+                {
+                    if self.menu.levels.get(depth + 1).is_some()
+                        && self.menu.levels[depth] == Some(index)
+                    {
+                        return;
+                    }
+
+                    if item.is_selectable() {
+                        if item.submenu_items().is_some() {
+                            self.choose(depth, index, shell);
+                            self.menu.levels[depth + 1] = None;
+                        } else if self.menu.levels[depth] != Some(index)
+                            || self.menu.levels.len() > depth + 1
+                        {
+                            self.menu.highlight(depth, Some(index));
+                            shell.invalidate_layout();
+                            shell.request_redraw();
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let cursor = match event {
+                    Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                        mouse::Cursor::Available(*position)
+                    }
+                    _ => cursor,
+                };
+
+                if let Some((depth, index)) = self.hovered(layout, cursor) {
+                    self.choose(depth, index, shell);
+                } else if !layout.children().any(|menu| cursor.is_over(menu.bounds())) {
+                    self.close(shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if !layout.children().any(|menu| cursor.is_over(menu.bounds())) {
+                    self.close(shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) => {
+                let depth = self.menu.levels.len() - 1;
+                let current = self.menu.levels[depth];
+                let items = items_at(self.items, &self.menu.levels, depth).unwrap_or(&[]);
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        self.menu.levels[depth] = next_selectable(items, current, 1);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        self.menu.levels[depth] = next_selectable(items, current, -1);
+                    }
+                    keyboard::Key::Named(key::Named::Home) => {
+                        self.menu.levels[depth] = next_selectable(items, None, 1);
+                    }
+                    keyboard::Key::Named(key::Named::End) => {
+                        self.menu.levels[depth] = next_selectable(items, None, -1);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        if let Some(index) = current
+                            && items
+                                .get(index)
+                                .is_some_and(|item| item.submenu_items().is_some())
+                        {
+                            self.choose(depth, index, shell);
+                        }
+                    }
+                    keyboard::Key::Named(
+                        key::Named::Enter | key::Named::Space | key::Named::Select,
+                    ) => {
+                        if let Some(index) = current {
+                            self.choose(depth, index, shell);
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) if depth > 0 => {
+                        let _ = self.menu.levels.pop();
+                    }
+                    keyboard::Key::Named(key::Named::Escape | key::Named::ContextMenu) => {
+                        if depth > 0 {
+                            let _ = self.menu.levels.pop();
+                        } else {
+                            self.close(shell);
+                        }
+                    }
+                    _ => {
+                        if let Some(text) = text
+                            && !text.chars().any(char::is_control)
+                        {
+                            self.type_ahead(text, shell);
+                        }
+                    }
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.hovered(layout, cursor) {
+            Some((depth, index))
+                if items_at(self.items, &self.menu.levels, depth)
+                    .and_then(|items| items.get(index))
+                    .is_some_and(Item::is_selectable) =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let line_height = self.text_line_height.to_absolute(self.text_size).0;
+        let hint_factor = renderer.scale_factor();
+
+        for (depth, menu) in layout.children().enumerate() {
+            let Some(items) = items_at(self.items, &self.menu.levels, depth) else {
+                break;
+            };
+
+            let highlighted = self.menu.levels[depth];
+            let bounds = menu.bounds();
+
+            renderer.with_layer(bounds.expand(style.shadow.blur_radius), |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                for (index, (item, layout)) in items.iter().zip(menu.children()).enumerate() {
+                    let bounds = layout.bounds();
+
+                    if let Kind::Separator = item.kind {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    y: bounds.center_y().round(),
+                                    height: 1.0,
+                                    ..bounds
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.separator,
+                        );
+
+                        continue;
+                    }
+
+                    let is_highlighted = highlighted == Some(index);
+
+                    if is_highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border: border::rounded(style.border.radius),
+                                ..renderer::Quad::default()
+                            },
+                            style.highlighted_background,
+                        );
+                    }
+
+                    let color = if !item.is_enabled {
+                        style.disabled_text_color
+                    } else if is_highlighted {
+                        style.highlighted_text_color
+                    } else {
+                        style.text_color
+                    };
+
+                    let text = |content: String, align_x| Text {
+                        content,
+                        bounds: Size::new(bounds.width - self.padding.horizontal(), line_height),
+                        size: self.text_size,
+                        line_height: self.text_line_height,
+                        font: self.font,
+                        align_x,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                        hint_factor,
+                    };
+
+                    renderer.fill_text(
+                        text(item.label.clone(), text::Alignment::Left),
+                        Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                        color,
+                        bounds,
+                    );
+
+                    let accessory = match &item.kind {
+                        Kind::Submenu(_) => Some((String::from("›"), color)),
+                        Kind::Action(_) | Kind::Separator => item
+                            .shortcut
+                            .clone()
+                            .map(|shortcut| (shortcut, color.scale_alpha(0.6))),
+                    };
+
+                    if let Some((content, color)) = accessory {
+                        renderer.fill_text(
+                            text(content, text::Alignment::Right),
+                            Point::new(
+                                bounds.x + bounds.width - self.padding.right,
+                                bounds.center_y(),
+                            ),
+                            color,
+                            bounds,
+                        );
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// The appearance of a [`ContextMenu`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the menus.
+    pub background: Background,
+    /// The [`Border`] of the menus.
+    pub border: Border,
+    /// The [`Shadow`] of the menus.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the items.
+    pub text_color: Color,
+    /// The text [`Color`] of the highlighted item.
+    pub highlighted_text_color: Color,
+    /// The [`Background`] of the highlighted item.
+    pub highlighted_background: Background,
+    /// The text [`Color`] of disabled items.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of separators.
+    pub separator: Background,
+}
+
+/// The theme catalog of a [`ContextMenu`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ContextMenu`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ContextMenu`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        highlighted_text_color: palette.primary.strong.text,
+        highlighted_background: palette.primary.strong.color.into(),
+        disabled_text_color: palette.background.strong.color,
+        separator: palette.background.strong.color.into(),
+    }
+}
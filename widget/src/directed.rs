@@ -0,0 +1,212 @@
+//! Override the layout direction of any widget.
+//!
+//! The [`Directed`] widget lays out its content with the given [`Direction`],
+//! regardless of the [`Direction`] of the runtime. This is useful for content
+//! that must never be mirrored, like media controls or numeric keypads.
+use crate::core::layout::{self, Direction, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size, Vector, Widget};
+
+/// A widget that lays out its content with a specific [`Direction`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::layout::Direction;
+/// use iced::widget::{button, directed, row};
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     Previous,
+///     Next,
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     // Media controls keep their order in right-to-left locales
+///     directed(
+///         Direction::LeftToRight,
+///         row![
+///             button("⏮").on_press(Message::Previous),
+///             button("⏭").on_press(Message::Next),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Directed<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    direction: Direction,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Directed<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Directed`] widget with the given [`Direction`] and content.
+    pub fn new(
+        direction: Direction,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            direction,
+            content: content.into(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Directed<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.direction.scope(|| {
+            self.content
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, limits)
+        })
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Directed<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(directed: Directed<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(directed)
+    }
+}
+
+/// Creates a new [`Directed`] widget that lays out its content with the given
+/// [`Direction`].
+pub fn directed<'a, Message, Theme, Renderer>(
+    direction: Direction,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Directed<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    Directed::new(direction, content)
+}
@@ -0,0 +1,321 @@
+//! Group focusable widgets for keyboard navigation.
+//!
+//! A [`FocusGroup`] marks a subtree of focusable widgets. With an [`Id`],
+//! keyboard navigation can be confined to it with the [`focus_next_in`] and
+//! [`focus_previous_in`] operations; and with a [`tab_index`], its widgets
+//! can be moved around in the order of the [`focus_next`] and
+//! [`focus_previous`] operations.
+//!
+//! A [`FocusGroup`] also notifies when the focus enters or leaves it; which
+//! is useful to expand or highlight a section of a form while it is being
+//! navigated.
+//!
+//! [`Id`]: crate::core::widget::Id
+//! [`tab_index`]: FocusGroup::tab_index
+//! [`focus_next_in`]: crate::core::widget::operation::focusable::focus_next_in
+//! [`focus_previous_in`]: crate::core::widget::operation::focusable::focus_previous_in
+//! [`focus_next`]: crate::core::widget::operation::focusable::focus_next
+//! [`focus_previous`]: crate::core::widget::operation::focusable::focus_previous
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{Focusable, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Widget};
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
+};
+
+/// A widget that groups the focusable widgets of its contents.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, focus_group, text_input};
+///
+/// struct State {
+///     street: String,
+///     city: String,
+///     is_editing_address: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     StreetChanged(String),
+///     CityChanged(String),
+///     AddressFocused,
+///     AddressUnfocused,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     focus_group(column![
+///         text_input("Street", &state.street).on_input(Message::StreetChanged),
+///         text_input("City", &state.city).on_input(Message::CityChanged),
+///     ])
+///     .id("address")
+///     .on_focus_enter(Message::AddressFocused)
+///     .on_focus_leave(Message::AddressUnfocused)
+///     .into()
+/// }
+/// ```
+pub struct FocusGroup<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    id: Option<Id>,
+    content: Element<'a, Message, Theme, Renderer>,
+    tab_index: Option<i32>,
+    on_focus_enter: Option<Message>,
+    on_focus_leave: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> FocusGroup<'a, Message, Theme, Renderer> {
+    /// Creates a new [`FocusGroup`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            id: None,
+            content: content.into(),
+            tab_index: None,
+            on_focus_enter: None,
+            on_focus_leave: None,
+        }
+    }
+
+    /// Sets the [`Id`] of the [`FocusGroup`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the tab index of the focusable widgets of the [`FocusGroup`].
+    ///
+    /// Focusable widgets are navigated in ascending order of their tab
+    /// index; and in tree order for equal indices. Widgets have a tab index
+    /// of `0` by default.
+    ///
+    /// Widgets with a negative tab index are skipped by keyboard navigation,
+    /// but they can still be focused directly.
+    pub fn tab_index(mut self, index: i32) -> Self {
+        self.tab_index = Some(index);
+        self
+    }
+
+    /// Sets the message that will be produced when the focus enters the
+    /// [`FocusGroup`]; that is, when any of its widgets is focused while
+    /// none was before.
+    pub fn on_focus_enter(mut self, message: Message) -> Self {
+        self.on_focus_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the focus leaves the
+    /// [`FocusGroup`]; that is, when none of its widgets is focused anymore.
+    pub fn on_focus_leave(mut self, message: Message) -> Self {
+        self.on_focus_leave = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`FocusGroup`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusGroup<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(self.id.as_ref(), layout.bounds());
+
+        if let Some(index) = self.tab_index {
+            operation.tab_index(index);
+        }
+
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if self.on_focus_enter.is_none() && self.on_focus_leave.is_none() {
+            return;
+        }
+
+        let mut operation = HasFocus(false);
+
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut operation,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_focused != operation.0 {
+            state.is_focused = operation.0;
+
+            let message = if state.is_focused {
+                self.on_focus_enter.as_ref()
+            } else {
+                self.on_focus_leave.as_ref()
+            };
+
+            if let Some(message) = message {
+                shell.publish(message.clone());
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FocusGroup<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(focus_group: FocusGroup<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(focus_group)
+    }
+}
+
+/// Finds out whether any focusable widget is focused.
+struct HasFocus(bool);
+
+impl Operation for HasFocus {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if !self.0 {
+            operate(self);
+        }
+    }
+
+    fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+        self.0 = self.0 || state.is_focused();
+    }
+}
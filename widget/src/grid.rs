@@ -1,4 +1,9 @@
 //! Distribute content on a grid.
+//!
+//! By default, a [`Grid`] places its children on cells of the same size.
+//! Alternatively, explicit templates of [`Track`] sizes can be given for its
+//! columns and rows; in which case, children can span multiple cells and are
+//! placed automatically following the [`Flow`] of the [`Grid`].
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
@@ -7,14 +12,39 @@ use crate::core::widget::{Operation, Tree};
 use crate::core::{
     Clipboard, Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
 };
+use crate::z_index;
 
 /// A container that distributes its contents on a responsive grid.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::grid::{self, Cell, Grid};
+/// use iced::widget::text;
+///
+/// enum Message {}
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     Grid::new()
+///         .template_columns([grid::fixed(200), grid::fraction(1.0), grid::fraction(1.0)])
+///         .spacing(10)
+///         .push_cell(text("Sidebar"), Cell::new().at(0, 0).row_span(2))
+///         .push_cell(text("Header"), Cell::new().column_span(2))
+///         .push(text("Chart"))
+///         .push(text("Stats"))
+///         .into()
+/// }
+/// ```
 pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
-    spacing: f32,
+    column_spacing: f32,
+    row_spacing: f32,
     columns: Constraint,
+    template: Option<Template>,
     width: Option<Pixels>,
     height: Sizing,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    cells: Vec<Cell>,
 }
 
 enum Constraint {
@@ -48,17 +78,35 @@ where
     /// Creates a [`Grid`] from an already allocated [`Vec`].
     pub fn from_vec(children: Vec<Element<'a, Message, Theme, Renderer>>) -> Self {
         Self {
-            spacing: 0.0,
+            column_spacing: 0.0,
+            row_spacing: 0.0,
             columns: Constraint::Amount(3),
+            template: None,
             width: None,
             height: Sizing::AspectRatio(1.0),
+            cells: vec![Cell::new(); children.len()],
             children,
         }
     }
 
     /// Sets the spacing _between_ cells in the [`Grid`].
     pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
-        self.spacing = amount.into().0;
+        let amount = amount.into().0;
+
+        self.column_spacing = amount;
+        self.row_spacing = amount;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
         self
     }
 
@@ -93,12 +141,74 @@ where
         self
     }
 
+    /// Sets the sizes of the explicit columns of the [`Grid`].
+    ///
+    /// Once any template is set, the children of the [`Grid`] are placed on
+    /// its tracks following its [`Flow`]; and the [`columns`] and [`fluid`]
+    /// strategies are ignored.
+    ///
+    /// [`columns`]: Self::columns
+    /// [`fluid`]: Self::fluid
+    pub fn template_columns(mut self, columns: impl IntoIterator<Item = impl Into<Track>>) -> Self {
+        self.template_mut().columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the sizes of the explicit rows of the [`Grid`].
+    ///
+    /// See [`template_columns`](Self::template_columns) for more details.
+    pub fn template_rows(mut self, rows: impl IntoIterator<Item = impl Into<Track>>) -> Self {
+        self.template_mut().rows = rows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the size of the columns created implicitly to fit the children
+    /// of the [`Grid`] outside of its explicit columns.
+    ///
+    /// By default, implicit columns are [`Track::Auto`].
+    pub fn auto_columns(mut self, track: impl Into<Track>) -> Self {
+        self.template_mut().auto_columns = track.into();
+        self
+    }
+
+    /// Sets the size of the rows created implicitly to fit the children
+    /// of the [`Grid`] outside of its explicit rows.
+    ///
+    /// By default, implicit rows are [`Track::Auto`].
+    pub fn auto_rows(mut self, track: impl Into<Track>) -> Self {
+        self.template_mut().auto_rows = track.into();
+        self
+    }
+
+    /// Sets the [`Flow`] used to place the children of the [`Grid`] that
+    /// have no explicit position.
+    pub fn flow(mut self, flow: Flow) -> Self {
+        self.template_mut().flow = flow;
+        self
+    }
+
     /// Adds an [`Element`] to the [`Grid`].
-    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_cell(child, Cell::new())
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] with the given [`Cell`] placement.
+    ///
+    /// Placements are only honored when the [`Grid`] has a template.
+    pub fn push_cell(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        cell: Cell,
+    ) -> Self {
         self.children.push(child.into());
+        self.cells.push(cell);
         self
     }
 
+    fn template_mut(&mut self) -> &mut Template {
+        self.template.get_or_insert_with(Template::default)
+    }
+
     /// Adds an element to the [`Grid`], if `Some`.
     pub fn push_maybe(
         self,
@@ -120,6 +230,153 @@ where
     }
 }
 
+impl<Message, Theme, Renderer> Grid<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout_template(
+        &mut self,
+        template: &Template,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size();
+        let limits = limits.width(size.width).height(size.height);
+        let available = limits.max();
+
+        let (areas, columns, rows) = place(&self.cells, template);
+
+        let column_tracks: Vec<Track> = (0..columns)
+            .map(|i| {
+                template
+                    .columns
+                    .get(i)
+                    .copied()
+                    .unwrap_or(template.auto_columns)
+            })
+            .collect();
+
+        let row_tracks: Vec<Track> = (0..rows)
+            .map(|i| template.rows.get(i).copied().unwrap_or(template.auto_rows))
+            .collect();
+
+        let loose = layout::Limits::new(Size::ZERO, available);
+
+        // Widths of the content of every child; needed by `Auto` columns
+        let widths: Vec<f32> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(&areas)
+            .map(|((child, tree), area)| {
+                let is_auto = column_tracks[area.column..area.column + area.column_span]
+                    .iter()
+                    .any(|track| track.is_auto(available.width.is_finite()));
+
+                if is_auto {
+                    child
+                        .as_widget_mut()
+                        .layout(tree, renderer, &loose)
+                        .size()
+                        .width
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        let column_sizes = resolve(
+            &column_tracks,
+            available.width,
+            self.column_spacing,
+            areas
+                .iter()
+                .zip(&widths)
+                .map(|(area, width)| (area.column, area.column_span, *width)),
+        );
+
+        let column_offsets = offsets(&column_sizes, self.column_spacing);
+
+        // Heights of the content of every child, given the width of its cells
+        let heights: Vec<f32> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(&areas)
+            .map(|((child, tree), area)| {
+                let width = span(
+                    &column_sizes,
+                    area.column,
+                    area.column_span,
+                    self.column_spacing,
+                );
+
+                child
+                    .as_widget_mut()
+                    .layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(Size::ZERO, Size::new(width, available.height)),
+                    )
+                    .size()
+                    .height
+            })
+            .collect();
+
+        let is_height_bounded = size.height != Length::Shrink && available.height.is_finite();
+
+        let row_sizes = resolve(
+            &row_tracks,
+            if is_height_bounded {
+                available.height
+            } else {
+                f32::INFINITY
+            },
+            self.row_spacing,
+            areas
+                .iter()
+                .zip(&heights)
+                .map(|(area, height)| (area.row, area.row_span, *height)),
+        );
+
+        let row_offsets = offsets(&row_sizes, self.row_spacing);
+
+        let nodes = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(&areas)
+            .map(|((child, tree), area)| {
+                let cell = Size::new(
+                    span(
+                        &column_sizes,
+                        area.column,
+                        area.column_span,
+                        self.column_spacing,
+                    ),
+                    span(&row_sizes, area.row, area.row_span, self.row_spacing),
+                );
+
+                child
+                    .as_widget_mut()
+                    .layout(tree, renderer, &layout::Limits::new(Size::ZERO, cell))
+                    .move_to((column_offsets[area.column], row_offsets[area.row]))
+            })
+            .collect();
+
+        let content = Size::new(
+            span(&column_sizes, 0, columns, self.column_spacing),
+            span(&row_sizes, 0, rows, self.row_spacing),
+        );
+
+        layout::Direction::current().orient(layout::Node::with_children(
+            limits.resolve(size.width, size.height, content),
+            nodes,
+        ))
+    }
+}
+
 impl<Message, Renderer> Default for Grid<'_, Message, Renderer>
 where
     Renderer: crate::core::Renderer,
@@ -169,15 +426,21 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        if let Some(template) = &self.template {
+            let template = template.clone();
+
+            return self.layout_template(&template, tree, renderer, limits);
+        }
+
         let size = self.size();
         let limits = limits.width(size.width).height(size.height);
         let available = limits.max();
 
         let cells_per_row = match self.columns {
             // width = n * (cell + spacing) - spacing, given n > 0
-            Constraint::MaxWidth(pixels) => {
-                ((available.width + self.spacing) / (pixels.0 + self.spacing)).ceil() as usize
-            }
+            Constraint::MaxWidth(pixels) => ((available.width + self.column_spacing)
+                / (pixels.0 + self.column_spacing))
+                .ceil() as usize,
             Constraint::Amount(amount) => amount,
         };
 
@@ -185,8 +448,8 @@ where
             return layout::Node::new(limits.resolve(size.width, size.height, Size::ZERO));
         }
 
-        let cell_width =
-            (available.width - self.spacing * (cells_per_row - 1) as f32) / cells_per_row as f32;
+        let cell_width = (available.width - self.column_spacing * (cells_per_row - 1) as f32)
+            / cells_per_row as f32;
 
         let cell_height = match self.height {
             Sizing::AspectRatio(ratio) => Some(cell_width / ratio),
@@ -194,7 +457,8 @@ where
             Sizing::EvenlyDistribute(_) => {
                 let total_rows = self.children.len().div_ceil(cells_per_row);
                 Some(
-                    (available.height - self.spacing * (total_rows - 1) as f32) / total_rows as f32,
+                    (available.height - self.row_spacing * (total_rows - 1) as f32)
+                        / total_rows as f32,
                 )
             }
         };
@@ -217,11 +481,11 @@ where
 
             let size = node.size();
 
-            x += size.width + self.spacing;
+            x += size.width + self.column_spacing;
             row_height = row_height.max(size.height);
 
             if (i + 1) % cells_per_row == 0 {
-                y += cell_height.unwrap_or(row_height) + self.spacing;
+                y += cell_height.unwrap_or(row_height) + self.row_spacing;
                 x = 0.0;
                 row_height = 0.0;
             }
@@ -230,12 +494,15 @@ where
         }
 
         if x == 0.0 {
-            y -= self.spacing;
+            y -= self.row_spacing;
         } else {
             y += cell_height.unwrap_or(row_height);
         }
 
-        layout::Node::with_children(Size::new(available.width, y), nodes)
+        layout::Direction::current().orient(layout::Node::with_children(
+            Size::new(available.width, y),
+            nodes,
+        ))
     }
 
     fn operate(
@@ -314,17 +581,16 @@ where
         viewport: &Rectangle,
     ) {
         if let Some(viewport) = layout.bounds().intersection(viewport) {
-            for ((child, tree), layout) in self
-                .children
-                .iter()
-                .zip(&tree.children)
-                .zip(layout.children())
-                .filter(|(_, layout)| layout.bounds().intersects(&viewport))
-            {
-                child
-                    .as_widget()
-                    .draw(tree, renderer, theme, style, layout, cursor, &viewport);
-            }
+            z_index::draw_children(
+                &self.children,
+                tree,
+                layout,
+                renderer,
+                theme,
+                style,
+                cursor,
+                &viewport,
+            );
         }
     }
 
@@ -390,3 +656,382 @@ impl From<Length> for Sizing {
 pub fn aspect_ratio(width: impl Into<Pixels>, height: impl Into<Pixels>) -> Sizing {
     Sizing::AspectRatio(width.into().0 / height.into().0)
 }
+
+/// The size of a column or a row of a [`Grid`] with a template.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Track {
+    /// The track has a fixed amount of pixels.
+    Fixed(f32),
+
+    /// The track takes the given fraction of the space left by the other
+    /// tracks.
+    ///
+    /// Without a bounded space to share, the track fits its content instead.
+    Fraction(f32),
+
+    /// The track fits its content.
+    #[default]
+    Auto,
+}
+
+impl Track {
+    fn is_auto(self, is_bounded: bool) -> bool {
+        match self {
+            Track::Fixed(_) => false,
+            Track::Fraction(_) => !is_bounded,
+            Track::Auto => true,
+        }
+    }
+}
+
+impl From<f32> for Track {
+    fn from(pixels: f32) -> Self {
+        Self::Fixed(pixels)
+    }
+}
+
+impl From<Pixels> for Track {
+    fn from(pixels: Pixels) -> Self {
+        Self::Fixed(pixels.0)
+    }
+}
+
+impl From<Length> for Track {
+    fn from(length: Length) -> Self {
+        match length {
+            Length::Fill => Self::Fraction(1.0),
+            Length::FillPortion(portion) => Self::Fraction(f32::from(portion)),
+            Length::Shrink => Self::Auto,
+            Length::Fixed(pixels) => Self::Fixed(pixels),
+        }
+    }
+}
+
+/// Creates a [`Track`] with a fixed amount of pixels.
+pub fn fixed(pixels: impl Into<Pixels>) -> Track {
+    Track::Fixed(pixels.into().0)
+}
+
+/// Creates a [`Track`] that takes the given fraction of the space left.
+pub fn fraction(fraction: f32) -> Track {
+    Track::Fraction(fraction)
+}
+
+/// Creates a [`Track`] that fits its content.
+pub fn auto() -> Track {
+    Track::Auto
+}
+
+/// The direction in which the children of a [`Grid`] are placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Flow {
+    /// Children fill the columns of a row before moving to the next row.
+    #[default]
+    Row,
+
+    /// Children fill the rows of a column before moving to the next column.
+    Column,
+}
+
+/// The placement of a child of a [`Grid`] with a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    position: Option<(usize, usize)>,
+    column_span: usize,
+    row_span: usize,
+}
+
+impl Cell {
+    /// Creates a [`Cell`] that spans a single column and row and is
+    /// placed automatically.
+    pub fn new() -> Self {
+        Self {
+            position: None,
+            column_span: 1,
+            row_span: 1,
+        }
+    }
+
+    /// Places the [`Cell`] at the given row and column, starting at `0`.
+    pub fn at(mut self, row: usize, column: usize) -> Self {
+        self.position = Some((row, column));
+        self
+    }
+
+    /// Sets the amount of columns spanned by the [`Cell`].
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets the amount of rows spanned by the [`Cell`].
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.row_span = rows.max(1);
+        self
+    }
+
+    /// Sets the amount of columns and rows spanned by the [`Cell`].
+    pub fn span(self, columns: usize, rows: usize) -> Self {
+        self.column_span(columns).row_span(rows)
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Template {
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+    auto_columns: Track,
+    auto_rows: Track,
+    flow: Flow,
+}
+
+/// The cells covered by a child of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Area {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+/// Places the given cells on a template; returning their areas and the
+/// total amount of columns and rows.
+fn place(cells: &[Cell], template: &Template) -> (Vec<Area>, usize, usize) {
+    // Placement works on the axes of the flow; the minor axis has a fixed
+    // amount of tracks and the major axis grows as needed.
+    let flip = |(a, b): (usize, usize)| match template.flow {
+        Flow::Row => (a, b),
+        Flow::Column => (b, a),
+    };
+
+    let (explicit_major, explicit_minor) = flip((template.rows.len(), template.columns.len()));
+
+    let minor = cells
+        .iter()
+        .filter_map(|cell| {
+            let (_, minor) = flip(cell.position?);
+            let (_, minor_span) = flip((cell.row_span, cell.column_span));
+
+            Some(minor + minor_span)
+        })
+        .fold(explicit_minor.max(1), usize::max);
+
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+
+    let mut areas: Vec<Option<(usize, usize, usize, usize)>> = vec![None; cells.len()];
+
+    for (cell, area) in cells.iter().zip(&mut areas) {
+        if let Some(position) = cell.position {
+            let (major, minor_start) = flip(position);
+            let (major_span, minor_span) = flip((cell.row_span, cell.column_span));
+
+            occupy(
+                &mut occupied,
+                minor,
+                (major, minor_start),
+                (major_span, minor_span),
+            );
+            *area = Some((major, minor_start, major_span, minor_span));
+        }
+    }
+
+    let mut cursor = (0, 0);
+
+    for (cell, area) in cells.iter().zip(&mut areas) {
+        if area.is_some() {
+            continue;
+        }
+
+        let (major_span, minor_span) = flip((cell.row_span, cell.column_span));
+        let minor_span = minor_span.min(minor);
+
+        loop {
+            let (major, minor_start) = cursor;
+
+            if minor_start + minor_span > minor {
+                cursor = (major + 1, 0);
+                continue;
+            }
+
+            let is_free = (major..major + major_span).all(|line| {
+                occupied.get(line).is_none_or(|line| {
+                    line[minor_start..minor_start + minor_span]
+                        .iter()
+                        .all(|slot| !slot)
+                })
+            });
+
+            if is_free {
+                occupy(
+                    &mut occupied,
+                    minor,
+                    (major, minor_start),
+                    (major_span, minor_span),
+                );
+                *area = Some((major, minor_start, major_span, minor_span));
+                cursor = (major, minor_start + minor_span);
+                break;
+            }
+
+            cursor = (major, minor_start + 1);
+        }
+    }
+
+    let major = occupied.len().max(explicit_major);
+
+    let areas = areas
+        .into_iter()
+        .map(|area| {
+            let (major, minor, major_span, minor_span) = area.unwrap_or((0, 0, 1, 1));
+            let (row, column) = flip((major, minor));
+            let (row_span, column_span) = flip((major_span, minor_span));
+
+            Area {
+                column,
+                row,
+                column_span,
+                row_span,
+            }
+        })
+        .collect();
+
+    let (rows, columns) = flip((major, minor));
+
+    (areas, columns, rows)
+}
+
+/// Marks the given slots of the placement lines as occupied.
+fn occupy(
+    occupied: &mut Vec<Vec<bool>>,
+    minor: usize,
+    (major, minor_start): (usize, usize),
+    (major_span, minor_span): (usize, usize),
+) {
+    if occupied.len() < major + major_span {
+        occupied.resize(major + major_span, vec![false; minor]);
+    }
+
+    for line in &mut occupied[major..major + major_span] {
+        for slot in &mut line[minor_start..minor_start + minor_span] {
+            *slot = true;
+        }
+    }
+}
+
+/// Resolves the sizes of the given tracks in the available space; given
+/// the start, span, and content size of every child.
+fn resolve(
+    tracks: &[Track],
+    available: f32,
+    spacing: f32,
+    contents: impl Iterator<Item = (usize, usize, f32)> + Clone,
+) -> Vec<f32> {
+    let is_bounded = available.is_finite();
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(pixels) => *pixels,
+            Track::Fraction(_) | Track::Auto => 0.0,
+        })
+        .collect();
+
+    // Single tracks fit their content first
+    for (start, span, size) in contents.clone() {
+        if span == 1 && tracks[start].is_auto(is_bounded) {
+            sizes[start] = sizes[start].max(size);
+        }
+    }
+
+    // Then, spanning content grows the last fitting track it spans
+    for (start, span, size) in contents {
+        if span > 1 {
+            let missing = size - self::span(&sizes, start, span, spacing);
+
+            if let Some(track) = (start..start + span)
+                .rev()
+                .find(|&i| tracks[i].is_auto(is_bounded))
+                && missing > 0.0
+            {
+                sizes[track] += missing;
+            }
+        }
+    }
+
+    if is_bounded {
+        let total: f32 = tracks
+            .iter()
+            .map(|track| match track {
+                Track::Fraction(fraction) => *fraction,
+                _ => 0.0,
+            })
+            .sum();
+
+        let left = available - self::span(&sizes, 0, sizes.len(), spacing);
+
+        if total > 0.0 && left > 0.0 {
+            for (size, track) in sizes.iter_mut().zip(tracks) {
+                if let Track::Fraction(fraction) = track {
+                    *size = left * fraction / total;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the size spanned by the given tracks, including the spacing
+/// between them.
+fn span(sizes: &[f32], start: usize, span: usize, spacing: f32) -> f32 {
+    let sizes = &sizes[start..(start + span).min(sizes.len())];
+
+    sizes.iter().sum::<f32>() + spacing * sizes.len().saturating_sub(1) as f32
+}
+
+/// Returns the starting offset of every track.
+fn offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let start = *offset;
+            *offset += size + spacing;
+
+            Some(start)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_places_spanning_cells() {
+        let template = Template {
+            columns: vec![Track::Auto; 3],
+            ..Template::default()
+        };
+
+        let cells = [
+            Cell::new().at(0, 0).row_span(2),
+            Cell::new().column_span(2),
+            Cell::new(),
+            Cell::new(),
+            Cell::new().column_span(3),
+        ];
+
+        let (areas, columns, rows) = place(&cells, &template);
+
+        let positions: Vec<_> = areas.iter().map(|area| (area.row, area.column)).collect();
+
+        assert_eq!(positions, [(0, 0), (0, 1), (1, 1), (1, 2), (2, 0)]);
+        assert_eq!((columns, rows), (3, 3));
+    }
+}
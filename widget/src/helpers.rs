@@ -3,6 +3,7 @@ use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::{self, ContextMenu};
 use crate::core;
 use crate::core::theme;
 use crate::core::widget::operation::{self, Operation};
@@ -10,20 +11,33 @@ use crate::core::window;
 use crate::core::{Element, Length, Size, Widget};
 use crate::float::{self, Float};
 use crate::keyed;
+use crate::level_meter::{self, LevelMeter};
 use crate::overlay;
 use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
+use crate::popover::Popover;
+use crate::portal::{self, Portal};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::rating::{self, Rating};
+use crate::responsive::{Breakpoint, Breakpoints};
 use crate::scrollable::{self, Scrollable};
+use crate::segmented::{self, Segmented};
 use crate::slider::{self, Slider};
+use crate::spotlight::{self, Spotlight};
+use crate::stepper::{self, Stepper};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::toggle::{self, Toggle};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer};
+use crate::waveform::{self, Waveform};
+use crate::{
+    AspectRatio, Column, Constrained, FocusGroup, Grid, Masonry, MouseArea, Pin, Responsive, Row,
+    Sensor, Space, Stack, Sticky, Themer, ZIndex,
+};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -98,6 +112,47 @@ macro_rules! row {
     );
 }
 
+/// Creates a [`Row`] that wraps its contents with the given children.
+///
+/// Children are laid out in lines, moving to the next line when the
+/// width of the [`Row`] is exhausted.
+///
+/// [`Row`]: crate::Row
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, wrap};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     wrap![
+///         button("Action"),
+///         button("Adventure"),
+///         button("Puzzle"),
+///         button("Racing"),
+///     ]
+///     .spacing(5)
+///     .vertical_spacing(10)
+///     .into()
+/// }
+/// ```
+#[macro_export]
+macro_rules! wrap {
+    () => (
+        $crate::Row::new().wrap()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Row::with_children([$($crate::core::Element::from($x)),+]).wrap()
+    );
+}
+
 /// Creates a [`Stack`] with the given children.
 ///
 /// [`Stack`]: crate::Stack
@@ -124,6 +179,19 @@ macro_rules! grid {
     );
 }
 
+/// Creates a [`Masonry`] with the given children.
+///
+/// [`Masonry`]: crate::Masonry
+#[macro_export]
+macro_rules! masonry {
+    () => (
+        $crate::Masonry::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Masonry::with_children([$($crate::core::Element::from($x)),+])
+    );
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// [`Text`]: core::widget::Text
@@ -456,6 +524,49 @@ where
     Pin::new(content)
 }
 
+/// Creates a new [`AspectRatio`] widget with the given ratio and content.
+///
+/// An [`AspectRatio`] widget sizes its contents following the given ratio—its
+/// width divided by its height—regardless of the size of its parent.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{aspect_ratio, container};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     aspect_ratio(16.0 / 9.0, container("Trailer").style(container::dark)).into()
+/// }
+/// ```
+pub fn aspect_ratio<'a, Message, Theme, Renderer>(
+    ratio: f32,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> AspectRatio<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    AspectRatio::new(ratio, content)
+}
+
+/// Creates a new [`Constrained`] widget with the given content.
+///
+/// A [`Constrained`] widget keeps the size of its contents between a minimum
+/// and a maximum; preferring an ideal size, if any.
+pub fn constrained<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Constrained<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Constrained::new(content)
+}
+
 /// Creates a new [`Column`] with the given children.
 ///
 /// Columns distribute their children vertically.
@@ -543,6 +654,19 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new [`Row`] that wraps its contents from an iterator.
+///
+/// Children are laid out in lines, moving to the next line when the
+/// width of the [`Row`] is exhausted.
+pub fn wrap<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> crate::row::Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Row::with_children(children).wrap()
+}
+
 /// Creates a new [`Grid`] from an iterator.
 pub fn grid<'a, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
@@ -553,6 +677,16 @@ where
     Grid::with_children(children)
 }
 
+/// Creates a new [`Masonry`] from an iterator.
+pub fn masonry<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Masonry::with_children(children)
+}
+
 /// Creates a new [`Stack`] with the given children.
 ///
 /// [`Stack`]: crate::Stack
@@ -565,6 +699,33 @@ where
     Stack::with_children(children)
 }
 
+/// Creates a new [`Sticky`] widget with the given content.
+///
+/// A [`Sticky`] widget keeps its contents within the viewport of the nearest
+/// scrollable while its original position is scrolled past.
+pub fn sticky<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Sticky::new(content)
+}
+
+/// Creates a new [`ZIndex`] widget with the given index and content.
+///
+/// Children with a higher z-index are drawn on top of their siblings in a
+/// [`Column`], [`Row`], [`Grid`], or [`Masonry`].
+pub fn z_index<'a, Message, Theme, Renderer>(
+    index: i32,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> ZIndex<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    ZIndex::new(index, content)
+}
+
 /// Wraps the given widget and captures any mouse button presses inside the bounds of
 /// the widget—effectively making it _opaque_.
 ///
@@ -1094,6 +1255,113 @@ where
     Tooltip::new(content, tooltip, position)
 }
 
+/// Creates a new [`Popover`] displaying the given content next to the given
+/// anchor.
+///
+/// Popovers flip and shift their content to keep it within the viewport.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, container, popover};
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     Close,
+/// }
+///
+/// fn view(is_open: bool) -> Element<'static, Message> {
+///     popover(button("Anchor"), "This is the popover contents!")
+///         .open(is_open)
+///         .on_dismiss(Message::Close)
+///         .style(container::rounded_box)
+///         .into()
+/// }
+/// ```
+pub fn popover<'a, Message, Theme, Renderer>(
+    anchor: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: container::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Popover::new(anchor, content)
+}
+
+/// Creates a new [`Spotlight`] highlighting the widget with the given [`Id`]
+/// among the given content; dimming everything else.
+///
+/// [`Id`]: core::widget::Id
+pub fn spotlight<'a, Message, Theme, Renderer>(
+    target: impl Into<core::widget::Id>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Spotlight<'a, Message, Theme, Renderer>
+where
+    Theme: spotlight::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Spotlight::new(target, content)
+}
+
+/// Creates a new [`ContextMenu`] for the given content with the menu built
+/// by the given closure.
+///
+/// The menu opens when the content is right-clicked or long-pressed.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::context_menu::Item;
+/// use iced::widget::{context_menu, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rename,
+///     Delete,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     context_menu(text("save.dat"), || {
+///         vec![
+///             Item::new("Rename", Message::Rename).shortcut("F2"),
+///             Item::separator(),
+///             Item::new("Delete", Message::Delete),
+///         ]
+///     })
+///     .into()
+/// }
+/// ```
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    menu: impl Fn() -> Vec<context_menu::Item<Message>> + 'a,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: context_menu::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, menu)
+}
+
+/// Creates a new [`Portal`] rendering the given content in the given
+/// [`portal::Target`].
+///
+/// Portals keep the space of their contents in place, but draw them in an
+/// overlay layer; escaping the clipping of their parents.
+pub fn portal<'a, Message, Theme, Renderer>(
+    target: impl Into<portal::Target>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Portal<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Portal::new(target, content)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// # Example
@@ -1376,6 +1644,181 @@ where
     Toggler::new(is_checked)
 }
 
+/// Creates a new [`Toggle`].
+///
+/// Toggles let users switch a setting on or off with an animated switch.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::toggle;
+///
+/// struct State {
+///    is_enabled: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(bool),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     toggle(state.is_enabled).on_toggle(Message::Toggled).into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Toggled(is_enabled) => {
+///             state.is_enabled = is_enabled;
+///         }
+///     }
+/// }
+/// ```
+pub fn toggle<'a, Message, Theme>(is_toggled: bool) -> Toggle<'a, Message, Theme>
+where
+    Theme: toggle::Catalog + 'a,
+{
+    Toggle::new(is_toggled)
+}
+
+/// Creates a new [`Segmented`] control.
+///
+/// Segmented controls let users pick one option out of a few, displayed as a
+/// group of joined buttons.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::segmented;
+///
+/// struct State {
+///    alignment: &'static str,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AlignmentSelected(&'static str),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     segmented(
+///         ["Left", "Center", "Right"],
+///         Some(state.alignment),
+///         Message::AlignmentSelected,
+///     )
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::AlignmentSelected(alignment) => {
+///             state.alignment = alignment;
+///         }
+///     }
+/// }
+/// ```
+pub fn segmented<'a, T, Message, Theme, Renderer>(
+    options: impl IntoIterator<Item = T>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> Segmented<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: segmented::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Segmented::new(options, selected, on_select)
+}
+
+/// Creates a new [`Rating`] with the given score.
+///
+/// Ratings let users score something with a row of stars or hearts.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::rating;
+///
+/// struct State {
+///    score: f32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rated(f32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     rating(state.score).half_steps(true).on_rate(Message::Rated).into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Rated(score) => {
+///             state.score = score;
+///         }
+///     }
+/// }
+/// ```
+pub fn rating<'a, Message, Theme>(value: f32) -> Rating<'a, Message, Theme>
+where
+    Theme: rating::Catalog + 'a,
+{
+    Rating::new(value)
+}
+
+/// Creates a new [`Stepper`] with the given step labels and the index of the
+/// current step.
+///
+/// Steppers display the progress of a flow split in multiple steps.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::stepper;
+///
+/// struct State {
+///    step: usize,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     StepSelected(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     stepper(["Account", "Profile", "Done"], state.step)
+///         .on_select(Message::StepSelected)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::StepSelected(step) => {
+///             state.step = step;
+///         }
+///     }
+/// }
+/// ```
+pub fn stepper<'a, Message, Theme, Renderer>(
+    labels: impl IntoIterator<Item = impl Into<String>>,
+    current: usize,
+) -> Stepper<'a, Message, Theme, Renderer>
+where
+    Theme: stepper::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Stepper::new(labels, current)
+}
+
 /// Creates a new [`TextInput`].
 ///
 /// Text inputs display fields that can be filled with text.
@@ -1558,6 +2001,52 @@ where
     VerticalSlider::new(range, value, on_change)
 }
 
+/// Creates a new range [`Slider`](slider::Range) with two handles.
+///
+/// Range sliders let users set a start and an end value by moving two
+/// indicators.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::range_slider;
+///
+/// struct State {
+///    range: (f32, f32),
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangeChanged((f32, f32)),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     range_slider(0.0..=100.0, state.range, Message::RangeChanged).into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::RangeChanged(range) => {
+///             state.range = range;
+///         }
+///     }
+/// }
+/// ```
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> slider::Range<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: slider::Catalog + 'a,
+{
+    slider::Range::new(range, values, on_change)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// Pick lists display a dropdown list of selectable options.
@@ -1752,6 +2241,99 @@ where
     ProgressBar::new(range, value)
 }
 
+/// Creates a new [`Waveform`] of the given [`Samples`].
+///
+/// Waveforms visualize the most recent samples of an audio signal.
+///
+/// [`Samples`]: crate::waveform::Samples
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::waveform;
+/// use iced::widget::waveform::Samples;
+///
+/// struct State {
+///    samples: Samples,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     waveform(&state.samples).into()
+/// }
+/// ```
+pub fn waveform<'a, Theme>(samples: &'a waveform::Samples) -> Waveform<'a, Theme>
+where
+    Theme: waveform::Catalog + 'a,
+{
+    Waveform::new(samples)
+}
+
+/// Creates a new [`LevelMeter`] with the given level, in the `[0.0, 1.0]`
+/// range.
+///
+/// Level meters display the loudness of an audio signal.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::level_meter;
+///
+/// struct State {
+///    level: f32,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     level_meter(state.level).into()
+/// }
+/// ```
+pub fn level_meter<'a, Theme>(level: f32) -> LevelMeter<'a, Theme>
+where
+    Theme: level_meter::Catalog + 'a,
+{
+    LevelMeter::new(level)
+}
+
+/// Creates a new [`PerfHud`].
+///
+/// Performance HUDs display live frame timings and pipeline costs of an application.
+///
+/// [`PerfHud`]: crate::PerfHud
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{perf_hud, stack};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view<'a>(game: Element<'a, Message>) -> Element<'a, Message> {
+///     stack![game, perf_hud()].into()
+/// }
+/// ```
+#[cfg(feature = "perf_hud")]
+pub fn perf_hud<'a, Theme>() -> crate::PerfHud<'a, Theme>
+where
+    Theme: crate::perf_hud::Catalog + 'a,
+{
+    crate::PerfHud::new()
+}
+
 /// Creates a new [`Image`].
 ///
 /// Images display raster graphics in different formats (PNG, JPG, etc.).
@@ -1779,6 +2361,39 @@ pub fn image<Handle>(handle: impl Into<Handle>) -> crate::Image<Handle> {
     crate::Image::new(handle.into())
 }
 
+/// Creates a new [`Avatar`] for the given user name.
+///
+/// Avatars display a circular picture of a user, falling back to their
+/// initials, with an optional status badge.
+///
+/// [`Avatar`]: crate::Avatar
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::avatar;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     avatar("Ada Lovelace").image("ada.png").into()
+/// }
+/// ```
+#[cfg(feature = "image")]
+pub fn avatar<'a, Theme, Renderer>(
+    name: impl Into<String>,
+) -> crate::Avatar<'a, crate::core::image::Handle, Theme, Renderer>
+where
+    Theme: crate::avatar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    crate::Avatar::new(name)
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// Svg widgets display vector graphics in your application.
@@ -1954,6 +2569,15 @@ where
     crate::QRCode::new(data)
 }
 
+/// Creates a new [`Camera`] that displays the given [`Feed`].
+///
+/// [`Camera`]: crate::Camera
+/// [`Feed`]: crate::camera::Feed
+#[cfg(feature = "camera")]
+pub fn camera(feed: &crate::camera::Feed) -> crate::Camera<'_> {
+    crate::Camera::new(feed)
+}
+
 /// Creates a new [`Shader`].
 ///
 /// [`Shader`]: crate::Shader
@@ -1965,6 +2589,19 @@ where
     crate::Shader::new(program)
 }
 
+/// Creates a new [`FocusGroup`] with the given content.
+///
+/// A [`FocusGroup`] confines keyboard navigation and notifies when the focus
+/// enters or leaves its contents.
+pub fn focus_group<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FocusGroup<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    FocusGroup::new(content)
+}
+
 /// Creates a new [`MouseArea`].
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -2060,3 +2697,45 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`Responsive`] widget with the given [`Breakpoints`] and a
+/// closure that produces its contents for the active [`Breakpoint`].
+///
+/// The [`Breakpoint`] is computed from the width available to the widget
+/// itself; so individual panels can adapt to their own width, not just the
+/// width of the window.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::responsive::{Breakpoint, Breakpoints};
+/// use iced::widget::{column, responsive_breakpoints, row, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     responsive_breakpoints(Breakpoints::default(), |breakpoint| {
+///         match breakpoint {
+///             Breakpoint::Compact => column![text("Sidebar"), text("Content")].into(),
+///             Breakpoint::Medium | Breakpoint::Expanded => {
+///                 row![text("Sidebar"), text("Content")].into()
+///             }
+///         }
+///     })
+///     .into()
+/// }
+/// ```
+pub fn responsive_breakpoints<'a, Message, Theme, Renderer>(
+    breakpoints: Breakpoints,
+    f: impl Fn(Breakpoint) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Responsive<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Responsive::with_breakpoints(breakpoints, f)
+}
@@ -0,0 +1,143 @@
+//! Recognize long presses and press-and-hold repeats.
+use crate::core::time::{Duration, Instant};
+
+/// The default duration of a long press.
+pub const DEFAULT_LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// A press being held down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hold {
+    at: Instant,
+    is_long: bool,
+    next_repeat: Option<Instant>,
+}
+
+/// What happened to a [`Hold`] at some point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tick {
+    /// Whether the [`Hold`] just became a long press.
+    pub is_long_press: bool,
+    /// Whether the press should be repeated.
+    pub is_repeat: bool,
+    /// The next time the [`Hold`] needs to be ticked; if any.
+    pub next: Option<Instant>,
+}
+
+impl Hold {
+    /// Starts holding a press at the given time.
+    pub fn new(at: Instant) -> Self {
+        Self {
+            at,
+            is_long: false,
+            next_repeat: None,
+        }
+    }
+
+    /// Returns true if the [`Hold`] has produced a long press or a repeat;
+    /// in which case releasing it should not count as a click.
+    pub fn is_consumed(&self) -> bool {
+        self.is_long || self.next_repeat.is_some()
+    }
+
+    /// Returns the first time the [`Hold`] needs to be ticked; if any.
+    pub fn deadline(
+        &self,
+        long_press: Option<Duration>,
+        repeat: Option<(Duration, Duration)>,
+    ) -> Option<Instant> {
+        let long_press = long_press
+            .filter(|_| !self.is_long)
+            .map(|duration| self.at + duration);
+
+        let repeat = repeat.map(|(delay, _)| self.next_repeat.unwrap_or(self.at + delay));
+
+        long_press.into_iter().chain(repeat).min()
+    }
+
+    /// Advances the [`Hold`] to the given time.
+    ///
+    /// The long press is recognized after the `long_press` duration and the
+    /// press is repeated every `interval` after an initial `delay`.
+    pub fn tick(
+        &mut self,
+        now: Instant,
+        long_press: Option<Duration>,
+        repeat: Option<(Duration, Duration)>,
+    ) -> Tick {
+        let is_long_press =
+            long_press.is_some_and(|duration| !self.is_long && now >= self.at + duration);
+
+        if is_long_press {
+            self.is_long = true;
+        }
+
+        let mut is_repeat = false;
+
+        if let Some((delay, interval)) = repeat {
+            let next = self.next_repeat.unwrap_or(self.at + delay);
+
+            if now >= next {
+                is_repeat = true;
+
+                // Skip missed repeats instead of bursting them
+                self.next_repeat = Some((next + interval).max(now));
+            }
+        }
+
+        Tick {
+            is_long_press,
+            is_repeat,
+            next: self.deadline(long_press, repeat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_recognizes_a_long_press_once() {
+        let start = Instant::now();
+        let mut hold = Hold::new(start);
+        let long_press = Some(DEFAULT_LONG_PRESS);
+
+        assert!(!hold.tick(start, long_press, None).is_long_press);
+        assert!(!hold.is_consumed());
+
+        let tick = hold.tick(start + DEFAULT_LONG_PRESS, long_press, None);
+
+        assert!(tick.is_long_press);
+        assert_eq!(tick.next, None);
+        assert!(hold.is_consumed());
+
+        assert!(
+            !hold
+                .tick(start + DEFAULT_LONG_PRESS * 2, long_press, None)
+                .is_long_press
+        );
+    }
+
+    #[test]
+    fn it_repeats_after_a_delay() {
+        let start = Instant::now();
+        let mut hold = Hold::new(start);
+
+        let delay = Duration::from_millis(400);
+        let interval = Duration::from_millis(100);
+        let repeat = Some((delay, interval));
+
+        let tick = hold.tick(start, None, repeat);
+
+        assert!(!tick.is_repeat);
+        assert_eq!(tick.next, Some(start + delay));
+
+        let tick = hold.tick(start + delay, None, repeat);
+
+        assert!(tick.is_repeat);
+        assert_eq!(tick.next, Some(start + delay + interval));
+
+        assert!(!hold.tick(start + delay, None, repeat).is_repeat);
+        assert!(hold.tick(start + delay + interval, None, repeat).is_repeat);
+    }
+}
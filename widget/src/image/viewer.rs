@@ -1,16 +1,25 @@
-//! Zoom and pan on an image.
+//! Zoom, pan, and rotate an image.
 use crate::core::border;
 use crate::core::image::{self, FilterMethod};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    Clipboard, ContentFit, Element, Event, Image, Layout, Length, Pixels, Point, Radians,
-    Rectangle, Shell, Size, Vector, Widget,
+    Clipboard, ContentFit, Element, Event, Image, Layout, Length, Pixels, Point, Rectangle,
+    Rotation, Shell, Size, Vector, Widget,
 };
 
-/// A frame that displays an image with the ability to zoom in/out and pan.
+/// A frame that displays an image with the ability to zoom in/out, pan, and rotate.
+///
+/// The image can be zoomed with the mouse wheel or by pinching with two fingers,
+/// and panned by dragging it with the mouse or a single finger.
+///
+/// Double clicking (or double tapping) the image toggles between fitting it and
+/// filling the [`Viewer`] with it.
 pub struct Viewer<Handle> {
     padding: f32,
     width: Length,
@@ -21,6 +30,8 @@ pub struct Viewer<Handle> {
     handle: Handle,
     filter_method: FilterMethod,
     content_fit: ContentFit,
+    rotation: Rotation,
+    snap_back: bool,
 }
 
 impl<Handle> Viewer<Handle> {
@@ -36,6 +47,8 @@ impl<Handle> Viewer<Handle> {
             scale_step: 0.10,
             filter_method: FilterMethod::default(),
             content_fit: ContentFit::default(),
+            rotation: Rotation::default(),
+            snap_back: true,
         }
     }
 
@@ -93,6 +106,145 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Applies the given [`Rotation`] to the image of the [`Viewer`].
+    ///
+    /// A [`Rotation::Solid`] will fit the rotated image inside the [`Viewer`],
+    /// while a [`Rotation::Floating`] will fit the image before rotating it.
+    pub fn rotation(mut self, rotation: impl Into<Rotation>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets whether the image of the [`Viewer`] can be dragged past its bounds,
+    /// animating back into place once released.
+    ///
+    /// When disabled, the image will be strictly clamped to its bounds while dragging.
+    ///
+    /// Default is `true`
+    pub fn snap_back(mut self, snap_back: bool) -> Self {
+        self.snap_back = snap_back;
+        self
+    }
+
+    fn sizes<Renderer>(&self, renderer: &Renderer, scale: f32, bounds: Size) -> (Size, Size)
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        image_sizes(
+            renderer,
+            &self.handle,
+            scale,
+            bounds,
+            self.content_fit,
+            self.rotation,
+        )
+    }
+
+    fn zoom<Renderer>(
+        &self,
+        state: &mut State,
+        scale: f32,
+        origin: Point,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let previous_scale = state.scale;
+        state.scale = scale.clamp(self.min_scale, self.max_scale);
+
+        let (_, visual_size) = self.sizes(renderer, state.scale, bounds.size());
+
+        let factor = state.scale / previous_scale - 1.0;
+        let cursor_to_center = origin - bounds.center();
+        let adjustment = cursor_to_center * factor + state.current_offset * factor;
+
+        state.current_offset = clamp_offset(
+            state.current_offset + adjustment,
+            bounds.size(),
+            visual_size,
+        );
+    }
+
+    fn drag<Renderer>(
+        &self,
+        state: &mut State,
+        position: Point,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> bool
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let Some(origin) = state.cursor_grabbed_at else {
+            return false;
+        };
+
+        let (_, visual_size) = self.sizes(renderer, state.scale, bounds.size());
+        let max_offset = max_offset(bounds.size(), visual_size);
+        let offset = state.starting_offset - (position - origin);
+
+        state.current_offset = if self.snap_back {
+            Vector::new(
+                rubber_band(offset.x, max_offset.x, bounds.width),
+                rubber_band(offset.y, max_offset.y, bounds.height),
+            )
+        } else {
+            clamp_offset(offset, bounds.size(), visual_size)
+        };
+
+        true
+    }
+
+    fn release<Renderer>(&self, state: &mut State, bounds: Rectangle, renderer: &Renderer)
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        state.cursor_grabbed_at = None;
+
+        let (_, visual_size) = self.sizes(renderer, state.scale, bounds.size());
+        let target = clamp_offset(state.current_offset, bounds.size(), visual_size);
+
+        if target != state.current_offset {
+            state.transition = Some(Transition::new(state, state.scale, target));
+        }
+    }
+
+    fn toggle_zoom<Renderer>(
+        &self,
+        state: &mut State,
+        origin: Point,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let mut target = *state;
+
+        if (state.scale - 1.0).abs() > f32::EPSILON {
+            target.scale = 1.0;
+            target.current_offset = Vector::ZERO;
+        } else {
+            let (_, fit_size) = self.sizes(renderer, 1.0, bounds.size());
+
+            let fill_scale = if fit_size.width > 0.0 && fit_size.height > 0.0 {
+                (bounds.width / fit_size.width).max(bounds.height / fit_size.height)
+            } else {
+                1.0
+            };
+
+            let scale = if fill_scale > 1.0 + f32::EPSILON {
+                fill_scale
+            } else {
+                DEFAULT_ZOOM
+            };
+
+            self.zoom(&mut target, scale, origin, bounds, renderer);
+        }
+
+        state.transition = Some(Transition::new(state, target.scale, target.current_offset));
+    }
 }
 
 impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer> for Viewer<Handle>
@@ -126,11 +278,14 @@ where
 
         let image_size = Size::new(image_size.width as f32, image_size.height as f32);
 
+        // The rotated size of the image
+        let rotated_size = self.rotation.apply(image_size);
+
         // The size to be available to the widget prior to `Shrink`ing
-        let raw_size = limits.resolve(self.width, self.height, image_size);
+        let raw_size = limits.resolve(self.width, self.height, rotated_size);
 
         // The uncropped size of the image when fit to the bounds above
-        let full_size = self.content_fit.fit(image_size, raw_size);
+        let full_size = self.content_fit.fit(rotated_size, raw_size);
 
         // Shrink the widget to fit the resized image, if requested
         let final_size = Size {
@@ -159,6 +314,7 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
@@ -168,46 +324,18 @@ where
 
                 match *delta {
                     mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                        let state = tree.state.downcast_mut::<State>();
-                        let previous_scale = state.scale;
-
-                        if y < 0.0 && previous_scale > self.min_scale
-                            || y > 0.0 && previous_scale < self.max_scale
+                        if y < 0.0 && state.scale > self.min_scale
+                            || y > 0.0 && state.scale < self.max_scale
                         {
-                            state.scale = (if y > 0.0 {
+                            let scale = if y > 0.0 {
                                 state.scale * (1.0 + self.scale_step)
                             } else {
                                 state.scale / (1.0 + self.scale_step)
-                            })
-                            .clamp(self.min_scale, self.max_scale);
-
-                            let scaled_size = scaled_image_size(
-                                renderer,
-                                &self.handle,
-                                state,
-                                bounds.size(),
-                                self.content_fit,
-                            );
-
-                            let factor = state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center = cursor_position - bounds.center();
-
-                            let adjustment =
-                                cursor_to_center * factor + state.current_offset * factor;
-
-                            state.current_offset = Vector::new(
-                                if scaled_size.width > bounds.width {
-                                    state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if scaled_size.height > bounds.height {
-                                    state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
-                            );
+                            };
+
+                            state.transition = None;
+
+                            self.zoom(state, scale, cursor_position, bounds, renderer);
                         }
                     }
                 }
@@ -220,52 +348,153 @@ where
                     return;
                 };
 
-                let state = tree.state.downcast_mut::<State>();
+                let click =
+                    mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
+
+                state.last_click = Some(click);
+                state.transition = None;
 
-                state.cursor_grabbed_at = Some(cursor_position);
-                state.starting_offset = state.current_offset;
+                if click.kind() == mouse::click::Kind::Double {
+                    self.toggle_zoom(state, cursor_position, bounds, renderer);
+                    shell.request_redraw();
+                } else {
+                    state.cursor_grabbed_at = Some(cursor_position);
+                    state.starting_offset = state.current_offset;
+                }
 
                 shell.capture_event();
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let state = tree.state.downcast_mut::<State>();
-
-                state.cursor_grabbed_at = None;
+                if state.is_cursor_grabbed() {
+                    self.release(state, bounds, renderer);
+                    shell.request_redraw();
+                }
             }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
-                let state = tree.state.downcast_mut::<State>();
+                if self.drag(state, *position, bounds, renderer) {
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if !bounds.contains(*position) {
+                    return;
+                }
 
-                if let Some(origin) = state.cursor_grabbed_at {
-                    let scaled_size = scaled_image_size(
-                        renderer,
-                        &self.handle,
-                        state,
-                        bounds.size(),
-                        self.content_fit,
-                    );
-                    let hidden_width = (scaled_size.width - bounds.width / 2.0).max(0.0).round();
+                let Some(slot) = state.fingers.iter_mut().find(|finger| finger.is_none()) else {
+                    return;
+                };
 
-                    let hidden_height = (scaled_size.height - bounds.height / 2.0).max(0.0).round();
+                *slot = Some((*id, *position));
+                state.transition = None;
+
+                if let [Some((_, a)), Some((_, b))] = state.fingers {
+                    state.cursor_grabbed_at = None;
+                    state.pinch = Some(Pinch {
+                        distance: a.distance(b),
+                        midpoint: midpoint(a, b),
+                        scale: state.scale,
+                        offset: state.current_offset,
+                    });
+                } else {
+                    let click = mouse::Click::new(*position, mouse::Button::Left, state.last_click);
+
+                    state.last_click = Some(click);
+
+                    if click.kind() == mouse::click::Kind::Double {
+                        self.toggle_zoom(state, *position, bounds, renderer);
+                        shell.request_redraw();
+                    } else {
+                        state.cursor_grabbed_at = Some(*position);
+                        state.starting_offset = state.current_offset;
+                    }
+                }
 
-                    let delta = *position - origin;
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let Some(slot) = state
+                    .fingers
+                    .iter_mut()
+                    .flatten()
+                    .find(|(finger, _)| finger == id)
+                else {
+                    return;
+                };
 
-                    let x = if bounds.width < scaled_size.width {
-                        (state.starting_offset.x - delta.x).clamp(-hidden_width, hidden_width)
-                    } else {
-                        0.0
-                    };
+                slot.1 = *position;
 
-                    let y = if bounds.height < scaled_size.height {
-                        (state.starting_offset.y - delta.y).clamp(-hidden_height, hidden_height)
-                    } else {
-                        0.0
-                    };
+                if let (Some(pinch), [Some((_, a)), Some((_, b))]) = (state.pinch, state.fingers) {
+                    let distance = a.distance(b);
+
+                    if pinch.distance > 0.0 {
+                        state.scale = pinch.scale;
+                        state.current_offset = pinch.offset;
+
+                        self.zoom(
+                            state,
+                            pinch.scale * distance / pinch.distance,
+                            pinch.midpoint,
+                            bounds,
+                            renderer,
+                        );
+
+                        let (_, visual_size) = self.sizes(renderer, state.scale, bounds.size());
+                        let pan = midpoint(a, b) - pinch.midpoint;
+
+                        state.current_offset =
+                            clamp_offset(state.current_offset - pan, bounds.size(), visual_size);
+                    }
 
-                    state.current_offset = Vector::new(x, y);
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else if self.drag(state, *position, bounds, renderer) {
                     shell.request_redraw();
                     shell.capture_event();
                 }
             }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                let Some(slot) = state
+                    .fingers
+                    .iter_mut()
+                    .find(|finger| finger.is_some_and(|(finger, _)| finger == *id))
+                else {
+                    return;
+                };
+
+                *slot = None;
+
+                if state.pinch.take().is_some() {
+                    if let Some((_, position)) = state.fingers.iter().flatten().next() {
+                        state.cursor_grabbed_at = Some(*position);
+                        state.starting_offset = state.current_offset;
+                    }
+                } else if state.is_cursor_grabbed() {
+                    self.release(state, bounds, renderer);
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(transition) = &mut state.transition {
+                    let started_at = *transition.started_at.get_or_insert(*now);
+                    let progress = ((*now - started_at).as_secs_f32()
+                        / TRANSITION_DURATION.as_secs_f32())
+                    .min(1.0);
+
+                    let (scale, offset) = transition.interpolate(progress);
+
+                    state.scale = scale;
+                    state.current_offset = offset;
+
+                    if progress < 1.0 {
+                        shell.request_redraw();
+                    } else {
+                        state.transition = None;
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -304,27 +533,28 @@ where
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
 
-        let final_size = scaled_image_size(
-            renderer,
-            &self.handle,
-            state,
-            bounds.size(),
-            self.content_fit,
-        );
+        let (image_size, visual_size) = self.sizes(renderer, state.scale, bounds.size());
 
         let translation = {
-            let diff_w = bounds.width - final_size.width;
-            let diff_h = bounds.height - final_size.height;
+            let diff_w = bounds.width - visual_size.width;
+            let diff_h = bounds.height - visual_size.height;
 
             let image_top_left = match self.content_fit {
                 ContentFit::None => Vector::new(diff_w.max(0.0) / 2.0, diff_h.max(0.0) / 2.0),
                 _ => Vector::new(diff_w / 2.0, diff_h / 2.0),
             };
 
-            image_top_left - state.offset(bounds, final_size)
+            image_top_left - state.offset(bounds, visual_size)
         };
 
-        let drawing_bounds = Rectangle::new(bounds.position(), final_size);
+        let drawing_bounds = Rectangle::new(
+            bounds.position()
+                + Vector::new(
+                    (visual_size.width - image_size.width) / 2.0,
+                    (visual_size.height - image_size.height) / 2.0,
+                ),
+            image_size,
+        );
 
         let render = |renderer: &mut Renderer| {
             renderer.with_translation(translation, |renderer| {
@@ -333,7 +563,7 @@ where
                         handle: self.handle.clone(),
                         border_radius: border::Radius::default(),
                         filter_method: self.filter_method,
-                        rotation: Radians(0.0),
+                        rotation: self.rotation.radians(),
                         opacity: 1.0,
                         snap: true,
                     },
@@ -354,6 +584,10 @@ pub struct State {
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    last_click: Option<mouse::Click>,
+    fingers: [Option<(touch::Finger, Point)>; 2],
+    pinch: Option<Pinch>,
+    transition: Option<Transition>,
 }
 
 impl Default for State {
@@ -363,6 +597,10 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            last_click: None,
+            fingers: [None; 2],
+            pinch: None,
+            transition: None,
         }
     }
 }
@@ -375,23 +613,70 @@ impl State {
 
     /// Returns the current offset of the [`State`], given the bounds
     /// of the [`Viewer`] and its image.
+    ///
+    /// The offset is only allowed to exceed the bounds while the image
+    /// is being manipulated or animated.
     fn offset(&self, bounds: Rectangle, image_size: Size) -> Vector {
-        let hidden_width = (image_size.width - bounds.width / 2.0).max(0.0).round();
-
-        let hidden_height = (image_size.height - bounds.height / 2.0).max(0.0).round();
+        if self.is_cursor_grabbed() || self.pinch.is_some() || self.transition.is_some() {
+            return self.current_offset;
+        }
 
-        Vector::new(
-            self.current_offset.x.clamp(-hidden_width, hidden_width),
-            self.current_offset.y.clamp(-hidden_height, hidden_height),
-        )
+        clamp_offset(self.current_offset, bounds.size(), image_size)
     }
 
     /// Returns if the cursor is currently grabbed by the [`Viewer`].
     pub fn is_cursor_grabbed(&self) -> bool {
         self.cursor_grabbed_at.is_some()
     }
+
+    /// Returns the current scale of the image of the [`Viewer`].
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pinch {
+    distance: f32,
+    midpoint: Point,
+    scale: f32,
+    offset: Vector,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    from_scale: f32,
+    to_scale: f32,
+    from_offset: Vector,
+    to_offset: Vector,
+    started_at: Option<Instant>,
+}
+
+impl Transition {
+    fn new(state: &State, scale: f32, offset: Vector) -> Self {
+        Self {
+            from_scale: state.scale,
+            to_scale: scale,
+            from_offset: state.current_offset,
+            to_offset: offset,
+            started_at: None,
+        }
+    }
+
+    fn interpolate(&self, progress: f32) -> (f32, Vector) {
+        // Ease out cubic
+        let t = 1.0 - (1.0 - progress).powi(3);
+
+        (
+            self.from_scale + (self.to_scale - self.from_scale) * t,
+            self.from_offset + (self.to_offset - self.from_offset) * t,
+        )
+    }
+}
+
+const DEFAULT_ZOOM: f32 = 2.0;
+const TRANSITION_DURATION: Duration = Duration::from_millis(200);
+
 impl<'a, Message, Theme, Renderer, Handle> From<Viewer<Handle>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -414,17 +699,112 @@ pub fn scaled_image_size<Renderer>(
     bounds: Size,
     content_fit: ContentFit,
 ) -> Size
+where
+    Renderer: image::Renderer,
+{
+    let (image_size, _) = image_sizes(
+        renderer,
+        handle,
+        state.scale,
+        bounds,
+        content_fit,
+        Rotation::default(),
+    );
+
+    image_size
+}
+
+/// Returns the scaled size of the image and the size of its rotated
+/// bounding box, in that order.
+fn image_sizes<Renderer>(
+    renderer: &Renderer,
+    handle: &<Renderer as image::Renderer>::Handle,
+    scale: f32,
+    bounds: Size,
+    content_fit: ContentFit,
+    rotation: Rotation,
+) -> (Size, Size)
 where
     Renderer: image::Renderer,
 {
     let Size { width, height } = renderer.measure_image(handle).unwrap_or_default();
 
     let image_size = Size::new(width as f32, height as f32);
+    let rotated_size = rotation.apply(image_size);
+
+    if rotated_size.width <= 0.0 || rotated_size.height <= 0.0 {
+        return (Size::ZERO, Size::ZERO);
+    }
+
+    let adjusted_fit = content_fit.fit(rotated_size, bounds);
 
-    let adjusted_fit = content_fit.fit(image_size, bounds);
+    let fit_scale = Vector::new(
+        adjusted_fit.width / rotated_size.width,
+        adjusted_fit.height / rotated_size.height,
+    );
 
-    Size::new(
-        adjusted_fit.width * state.scale,
-        adjusted_fit.height * state.scale,
+    let final_size = image_size * fit_scale * scale;
+
+    (final_size, final_size.rotate(rotation.radians()))
+}
+
+/// Returns the maximum offset of a centered image of the given size, so its
+/// edges never move past the edges of the given bounds.
+fn max_offset(bounds: Size, image_size: Size) -> Vector {
+    Vector::new(
+        ((image_size.width - bounds.width) / 2.0).max(0.0).round(),
+        ((image_size.height - bounds.height) / 2.0).max(0.0).round(),
     )
 }
+
+fn clamp_offset(offset: Vector, bounds: Size, image_size: Size) -> Vector {
+    let max_offset = max_offset(bounds, image_size);
+
+    Vector::new(
+        offset.x.clamp(-max_offset.x, max_offset.x),
+        offset.y.clamp(-max_offset.y, max_offset.y),
+    )
+}
+
+/// Applies increasing resistance to the given value as it exceeds the limit,
+/// never exceeding the given dimension past the limit.
+fn rubber_band(value: f32, limit: f32, dimension: f32) -> f32 {
+    const COEFFICIENT: f32 = 0.55;
+
+    let excess = value.abs() - limit;
+
+    if excess <= 0.0 {
+        return value;
+    }
+
+    let resisted = (1.0 - 1.0 / (excess * COEFFICIENT / dimension + 1.0)) * dimension;
+
+    value.signum() * (limit + resisted)
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_pans_until_the_edges_of_the_image() {
+        let bounds = Size::new(400.0, 300.0);
+        let image_size = Size::new(1000.0, 200.0);
+
+        assert_eq!(max_offset(bounds, image_size), Vector::new(300.0, 0.0));
+
+        assert_eq!(
+            clamp_offset(Vector::new(-500.0, 50.0), bounds, image_size),
+            Vector::new(-300.0, 0.0)
+        );
+
+        assert_eq!(
+            clamp_offset(Vector::new(120.0, 0.0), bounds, image_size),
+            Vector::new(120.0, 0.0)
+        );
+    }
+}
@@ -8,6 +8,9 @@ pub use crate::lazy::{Component, Lazy};
 
 /// Creates a new [`Lazy`] widget with the given data `Dependency` and a
 /// closure that can turn this data into a widget tree.
+///
+/// While the `Dependency` hashes the same, the closure is not called again
+/// and the retained subtree is not diffed.
 #[cfg(feature = "lazy")]
 pub fn lazy<'a, Message, Theme, Renderer, Dependency, View>(
     dependency: Dependency,
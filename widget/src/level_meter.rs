@@ -0,0 +1,408 @@
+//! Level meters display the loudness of an audio signal.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::level_meter;
+//! use iced::widget::waveform::Samples;
+//!
+//! struct State {
+//!    samples: Samples,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     level_meter(state.samples.rms())
+//!         .peak(state.samples.peak())
+//!         .into()
+//! }
+//! ```
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{
+    self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme, Transformation,
+    Vector, Widget,
+};
+use crate::graphics::mesh::{self, Mesh};
+use crate::waveform::push_quad;
+
+/// A meter that displays the level of an audio signal.
+///
+/// The meter is split into three zones; each with its own [`Color`]. A level
+/// above [`LevelMeter::MEDIUM`] is drawn with the medium color and a level
+/// above [`LevelMeter::HIGH`] with the high color.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::level_meter;
+///
+/// struct State {
+///    level: f32,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     level_meter(state.level).segments(12).vertical().into()
+/// }
+/// ```
+pub struct LevelMeter<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    level: f32,
+    peak: Option<f32>,
+    length: Length,
+    girth: Length,
+    is_vertical: bool,
+    segments: u16,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> LevelMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default girth of a [`LevelMeter`].
+    pub const DEFAULT_GIRTH: f32 = 8.0;
+
+    /// The level where the medium zone of a [`LevelMeter`] starts.
+    pub const MEDIUM: f32 = 0.6;
+
+    /// The level where the high zone of a [`LevelMeter`] starts.
+    pub const HIGH: f32 = 0.85;
+
+    /// Creates a new [`LevelMeter`] with the given level, in the
+    /// `[0.0, 1.0]` range.
+    pub fn new(level: f32) -> Self {
+        Self {
+            level: level.clamp(0.0, 1.0),
+            peak: None,
+            length: Length::Fill,
+            girth: Length::from(Self::DEFAULT_GIRTH),
+            is_vertical: false,
+            segments: 0,
+            spacing: 2.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the peak level of the [`LevelMeter`], in the `[0.0, 1.0]` range.
+    ///
+    /// The peak is drawn as a thin line; commonly held for a while by the
+    /// application after the level drops.
+    pub fn peak(mut self, peak: f32) -> Self {
+        self.peak = Some(peak.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Splits the [`LevelMeter`] into the given amount of segments; like
+    /// the LEDs of a hardware meter.
+    ///
+    /// By default, the level is drawn as a continuous bar.
+    pub fn segments(mut self, segments: u16) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Sets the spacing between the segments of the [`LevelMeter`].
+    pub fn spacing(mut self, spacing: impl Into<core::Pixels>) -> Self {
+        self.spacing = spacing.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the length of the [`LevelMeter`].
+    pub fn length(mut self, length: impl Into<Length>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    /// Sets the girth of the [`LevelMeter`].
+    pub fn girth(mut self, girth: impl Into<Length>) -> Self {
+        self.girth = girth.into();
+        self
+    }
+
+    /// Turns the [`LevelMeter`] into a vertical [`LevelMeter`] that fills
+    /// from the bottom.
+    ///
+    /// By default, a [`LevelMeter`] is horizontal.
+    pub fn vertical(mut self) -> Self {
+        self.is_vertical = true;
+        self
+    }
+
+    /// Sets the style of the [`LevelMeter`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LevelMeter`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn width(&self) -> Length {
+        if self.is_vertical {
+            self.girth
+        } else {
+            self.length
+        }
+    }
+
+    fn height(&self) -> Length {
+        if self.is_vertical {
+            self.length
+        } else {
+            self.girth
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for LevelMeter<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer + mesh::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width(), self.height())
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let length = if self.is_vertical {
+            bounds.height
+        } else {
+            bounds.width
+        };
+
+        // Returns the local bounds of the segment of the meter between the
+        // given distances from its start.
+        let segment = |start: f32, end: f32| {
+            if self.is_vertical {
+                Rectangle {
+                    x: 0.0,
+                    y: bounds.height - end,
+                    width: bounds.width,
+                    height: end - start,
+                }
+            } else {
+                Rectangle {
+                    x: start,
+                    y: 0.0,
+                    width: end - start,
+                    height: bounds.height,
+                }
+            }
+        };
+
+        let color = |level: f32| {
+            if level >= Self::HIGH {
+                style.high
+            } else if level >= Self::MEDIUM {
+                style.medium
+            } else {
+                style.low
+            }
+        };
+
+        let mut buffers = mesh::Indexed {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        if self.segments == 0 {
+            let zones = [
+                (0.0, Self::MEDIUM),
+                (Self::MEDIUM, Self::HIGH),
+                (Self::HIGH, 1.0),
+            ];
+
+            for (start, end) in zones {
+                let end = end.min(self.level);
+
+                if end > start {
+                    push_quad(
+                        &mut buffers,
+                        segment(start * length, end * length),
+                        color(start),
+                    );
+                }
+            }
+        } else {
+            let segments = f32::from(self.segments);
+            let size = (length - self.spacing * (segments - 1.0)) / segments;
+
+            for index in 0..self.segments {
+                let level = f32::from(index) / segments;
+
+                if level >= self.level || size <= 0.0 {
+                    break;
+                }
+
+                let start = f32::from(index) * (size + self.spacing);
+
+                push_quad(&mut buffers, segment(start, start + size), color(level));
+            }
+        }
+
+        if let Some(peak) = self.peak.filter(|peak| *peak > 0.0) {
+            let end = (peak * length).max(PEAK_WIDTH);
+
+            push_quad(&mut buffers, segment(end - PEAK_WIDTH, end), style.peak);
+        }
+
+        if buffers.indices.is_empty() {
+            return;
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_mesh(Mesh::Solid {
+                buffers,
+                transformation: Transformation::IDENTITY,
+                clip_bounds: Rectangle::with_size(bounds.size()),
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LevelMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + core::Renderer + mesh::Renderer,
+{
+    fn from(level_meter: LevelMeter<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(level_meter)
+    }
+}
+
+/// The width of the line of the peak level of a [`LevelMeter`].
+const PEAK_WIDTH: f32 = 2.0;
+
+/// The appearance of a level meter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the level meter.
+    pub background: Background,
+    /// The [`Border`] of the level meter.
+    pub border: Border,
+    /// The [`Color`] of the low zone of the level meter.
+    pub low: Color,
+    /// The [`Color`] of the medium zone of the level meter.
+    pub medium: Color,
+    /// The [`Color`] of the high zone of the level meter.
+    pub high: Color,
+    /// The [`Color`] of the peak level of the level meter.
+    pub peak: Color,
+}
+
+/// The theme catalog of a [`LevelMeter`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LevelMeter`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LevelMeter`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.strong.color.into(),
+        border: border::rounded(2),
+        low: palette.success.base.color,
+        medium: palette.warning.base.color,
+        high: palette.danger.base.color,
+        peak: palette.background.base.text,
+    }
+}
+
+/// A [`LevelMeter`] with a single color; for levels that cannot be too
+/// loud, like the volume of a device.
+pub fn primary(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+    let color = palette.primary.base.color;
+
+    Style {
+        low: color,
+        medium: color,
+        high: color,
+        ..default(theme)
+    }
+}
@@ -10,39 +10,58 @@ pub use iced_renderer::graphics;
 pub use core::widget::Id;
 
 mod action;
+mod aspect_ratio;
 mod column;
+mod constrained;
+mod directed;
+mod hold;
 mod mouse_area;
 mod opacity;
 mod pin;
-mod responsive;
 mod stack;
+mod sticky;
 mod themer;
 
 pub mod button;
 pub mod checkbox;
 pub mod combo_box;
+pub mod command_palette;
 pub mod container;
+pub mod context_menu;
 pub mod float;
+pub mod focus_group;
 pub mod grid;
 pub mod keyed;
+pub mod level_meter;
+pub mod masonry;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod popover;
+pub mod portal;
 pub mod progress_bar;
 pub mod radio;
+pub mod rating;
+pub mod responsive;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod segmented;
 pub mod sensor;
 pub mod slider;
 pub mod space;
+pub mod spotlight;
+pub mod stepper;
 pub mod table;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod toggle;
 pub mod toggler;
 pub mod tooltip;
 pub mod vertical_slider;
+pub mod waveform;
+pub mod z_index;
 
 mod helpers;
 
@@ -54,6 +73,8 @@ mod lazy;
 #[cfg(feature = "lazy")]
 pub use crate::lazy::helpers::*;
 
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -63,12 +84,24 @@ pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
+pub use constrained::Constrained;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use directed::{Directed, directed};
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
+pub use focus_group::FocusGroup;
+#[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
+pub use level_meter::LevelMeter;
+#[doc(no_inline)]
+pub use masonry::Masonry;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use opacity::{Opacity, opacity};
@@ -79,10 +112,16 @@ pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use pin::Pin;
 #[doc(no_inline)]
+pub use popover::Popover;
+#[doc(no_inline)]
+pub use portal::Portal;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rating::Rating;
+#[doc(no_inline)]
 pub use responsive::Responsive;
 #[doc(no_inline)]
 pub use row::Row;
@@ -91,14 +130,22 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use segmented::Segmented;
+#[doc(no_inline)]
 pub use sensor::Sensor;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spotlight::Spotlight;
+#[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use stepper::Stepper;
+#[doc(no_inline)]
+pub use sticky::Sticky;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -107,11 +154,17 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use toggle::Toggle;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use waveform::Waveform;
+#[doc(no_inline)]
+pub use z_index::ZIndex;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -134,6 +187,20 @@ pub mod image;
 #[doc(no_inline)]
 pub use image::Image;
 
+#[cfg(feature = "image")]
+pub mod avatar;
+
+#[cfg(feature = "image")]
+#[doc(no_inline)]
+pub use avatar::Avatar;
+
+#[cfg(feature = "camera")]
+pub mod camera;
+
+#[cfg(feature = "camera")]
+#[doc(no_inline)]
+pub use camera::Camera;
+
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
@@ -151,6 +218,13 @@ pub use qr_code::QRCode;
 #[cfg(feature = "markdown")]
 pub mod markdown;
 
+#[cfg(feature = "perf_hud")]
+pub mod perf_hud;
+
+#[cfg(feature = "perf_hud")]
+#[doc(no_inline)]
+pub use perf_hud::PerfHud;
+
 pub use crate::core::theme::{self, Theme};
 pub use action::Action;
 pub use renderer::Renderer;
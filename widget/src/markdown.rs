@@ -924,16 +924,43 @@ fn parse_with<'a>(
                 return None;
             }
 
-            let span = Span::Standard {
-                text: text.into_string(),
-                strong,
-                emphasis,
-                strikethrough,
-                link: link.clone(),
-                code: false,
+            if link.is_some() {
+                spans.push(Span::Standard {
+                    text: text.into_string(),
+                    strong,
+                    emphasis,
+                    strikethrough,
+                    link: link.clone(),
+                    code: false,
+                });
+
+                return None;
+            }
+
+            // Bare URLs and email addresses become links
+            let mut push = |range: Range<usize>, link: Option<Uri>| {
+                if !range.is_empty() {
+                    spans.push(Span::Standard {
+                        text: text[range].to_owned(),
+                        strong,
+                        emphasis,
+                        strikethrough,
+                        link,
+                        code: false,
+                    });
+                }
             };
 
-            spans.push(span);
+            let mut last = 0;
+
+            for detected in core::text::link::find(&text) {
+                push(last..detected.range.start, None);
+                push(detected.range.clone(), Some(detected.url(&text)));
+
+                last = detected.range.end;
+            }
+
+            push(last..text.len(), None);
 
             None
         }
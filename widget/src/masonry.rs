@@ -0,0 +1,608 @@
+//! Pack children of different heights into balanced columns.
+//!
+//! A [`Masonry`] places each of its children in its shortest column; keeping
+//! the heights of the columns as close as possible. This is ideal for media
+//! galleries where items have different aspect ratios.
+//!
+//! When there are too many items to lay out at once, a [`Masonry`] can be
+//! [`virtualized`](Masonry::virtualized): the height of every item is known
+//! in advance, but only the items in view need to be provided as children.
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::z_index;
+
+use std::ops::Range;
+
+/// A container that packs its children into columns of balanced heights.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{container, masonry, text};
+///
+/// struct Game {
+///     name: String,
+///     cover_height: f32,
+/// }
+///
+/// enum Message {}
+///
+/// fn view(games: &[Game]) -> Element<'_, Message> {
+///     masonry(games.iter().map(|game| {
+///         container(text(&game.name))
+///             .height(game.cover_height)
+///             .into()
+///     }))
+///     .fluid(240)
+///     .spacing(10)
+///     .into()
+/// }
+/// ```
+pub struct Masonry<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    spacing: f32,
+    columns: Constraint,
+    width: Length,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    items: Option<Items<'a>>,
+    on_visible: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
+    anticipate: Pixels,
+}
+
+enum Constraint {
+    MaxWidth(Pixels),
+    Amount(usize),
+}
+
+struct Items<'a> {
+    count: usize,
+    first: usize,
+    height: Box<dyn Fn(usize, f32) -> f32 + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Masonry`].
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /// Creates a [`Masonry`] with the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_vec(Vec::with_capacity(capacity))
+    }
+
+    /// Creates a [`Masonry`] with the given elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let iterator = children.into_iter();
+
+        Self::with_capacity(iterator.size_hint().0).extend(iterator)
+    }
+
+    /// Creates a [`Masonry`] from an already allocated [`Vec`].
+    pub fn from_vec(children: Vec<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            spacing: 0.0,
+            columns: Constraint::Amount(3),
+            width: Length::Fill,
+            children,
+            items: None,
+            on_visible: None,
+            anticipate: Pixels::ZERO,
+        }
+    }
+
+    /// Creates a virtualized [`Masonry`] of `count` items, where the given
+    /// closure produces the height of an item given its index and the width
+    /// of the columns.
+    ///
+    /// Every item is placed using its height, but only the items pushed as
+    /// children—starting at the index set with [`first`]—are laid out and
+    /// drawn. Use [`on_visible`] to know which items should be provided.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # mod iced { pub mod widget { pub use iced_widget::*; } }
+    /// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+    /// use iced::widget::{container, scrollable, text, Masonry};
+    /// use std::ops::Range;
+    ///
+    /// struct State {
+    ///     games: Vec<(String, f32)>,
+    ///     visible: Range<usize>,
+    /// }
+    ///
+    /// enum Message {
+    ///     VisibleChanged(Range<usize>),
+    /// }
+    ///
+    /// fn view(state: &State) -> Element<'_, Message> {
+    ///     let visible = state.visible.start..state.visible.end.min(state.games.len());
+    ///
+    ///     scrollable(
+    ///         Masonry::virtualized(state.games.len(), |i, width| {
+    ///             width * state.games[i].1
+    ///         })
+    ///         .first(visible.start)
+    ///         .extend(state.games[visible].iter().map(|(name, _)| container(text(name)).into()))
+    ///         .on_visible(Message::VisibleChanged)
+    ///         .anticipate(300),
+    ///     )
+    ///     .into()
+    /// }
+    /// ```
+    ///
+    /// [`first`]: Self::first
+    /// [`on_visible`]: Self::on_visible
+    pub fn virtualized(count: usize, height: impl Fn(usize, f32) -> f32 + 'a) -> Self {
+        Self {
+            items: Some(Items {
+                count,
+                first: 0,
+                height: Box::new(height),
+            }),
+            ..Self::new()
+        }
+    }
+
+    /// Sets the index of the item of the first child of a
+    /// [`virtualized`](Self::virtualized) [`Masonry`].
+    pub fn first(mut self, index: usize) -> Self {
+        if let Some(items) = &mut self.items {
+            items.first = index;
+        }
+
+        self
+    }
+
+    /// Sets the spacing _between_ the children of the [`Masonry`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Masonry`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the amount of columns in the [`Masonry`].
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Constraint::Amount(columns);
+        self
+    }
+
+    /// Makes the amount of columns dynamic in the [`Masonry`], never
+    /// exceeding the provided `max_width`.
+    pub fn fluid(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.columns = Constraint::MaxWidth(max_width.into());
+        self
+    }
+
+    /// Sets the message to be produced when the range of items in view of
+    /// the [`Masonry`] changes.
+    pub fn on_visible(mut self, on_visible: impl Fn(Range<usize>) -> Message + 'a) -> Self {
+        self.on_visible = Some(Box::new(on_visible));
+        self
+    }
+
+    /// Sets the distance in [`Pixels`] around the viewport where items are
+    /// considered in view by [`on_visible`](Self::on_visible).
+    ///
+    /// This can be used to provide the items of a virtualized [`Masonry`]
+    /// before the user can notice them popping in.
+    pub fn anticipate(mut self, distance: impl Into<Pixels>) -> Self {
+        self.anticipate = distance.into();
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Masonry`].
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Adds an element to the [`Masonry`], if `Some`.
+    pub fn push_maybe(
+        self,
+        child: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
+    ) -> Self {
+        if let Some(child) = child {
+            self.push(child)
+        } else {
+            self
+        }
+    }
+
+    /// Extends the [`Masonry`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+}
+
+impl<Message, Renderer> Default for Masonry<'_, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer: crate::core::Renderer>
+    FromIterator<Element<'a, Message, Theme, Renderer>> for Masonry<'a, Message, Theme, Renderer>
+{
+    fn from_iter<T: IntoIterator<Item = Element<'a, Message, Theme, Renderer>>>(iter: T) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    extents: Vec<(f32, f32)>,
+    visible: Option<Range<usize>>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Masonry<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let available = limits.max();
+
+        let amount = match self.columns {
+            // width = n * (column + spacing) - spacing, given n > 0
+            Constraint::MaxWidth(pixels) => {
+                ((available.width + self.spacing) / (pixels.0 + self.spacing)).ceil() as usize
+            }
+            Constraint::Amount(amount) => amount,
+        }
+        .max(1);
+
+        let column_width =
+            ((available.width - self.spacing * (amount - 1) as f32) / amount as f32).max(0.0);
+
+        let mut columns = Columns::new(amount, self.spacing);
+        let mut extents = Vec::new();
+        let mut nodes = Vec::with_capacity(self.children.len());
+
+        let position = |(column, y): (usize, f32)| {
+            Vector::new(column as f32 * (column_width + self.spacing), y)
+        };
+
+        if let Some(items) = &self.items {
+            let provided = items.first..items.first + self.children.len();
+            extents.reserve(items.count);
+
+            for i in 0..items.count {
+                let height = (items.height)(i, column_width).max(0.0);
+                let placement = columns.place(height);
+
+                extents.push((placement.1, placement.1 + height));
+
+                if provided.contains(&i) {
+                    let child = i - items.first;
+                    let size = Size::new(column_width, height);
+
+                    let node = self.children[child]
+                        .as_widget_mut()
+                        .layout(
+                            &mut tree.children[child],
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, size),
+                        )
+                        .translate(position(placement));
+
+                    nodes.push(node);
+                }
+            }
+
+            // Children beyond the amount of items are never displayed
+            for child in nodes.len()..self.children.len() {
+                let node = self.children[child].as_widget_mut().layout(
+                    &mut tree.children[child],
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::ZERO),
+                );
+
+                nodes.push(node);
+            }
+        } else {
+            let child_limits = layout::Limits::new(
+                Size::new(column_width, 0.0),
+                Size::new(column_width, f32::INFINITY),
+            );
+
+            for (child, tree) in self.children.iter_mut().zip(&mut tree.children) {
+                let node = child.as_widget_mut().layout(tree, renderer, &child_limits);
+                let height = node.size().height;
+                let placement = columns.place(height);
+
+                extents.push((placement.1, placement.1 + height));
+                nodes.push(node.translate(position(placement)));
+            }
+        }
+
+        tree.state.downcast_mut::<State>().extents = extents;
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(available.width, columns.height()),
+        );
+
+        layout::Direction::current().orient(layout::Node::with_children(size, nodes))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event
+            && let Some(on_visible) = &self.on_visible
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let bounds = layout.bounds();
+
+            let top = viewport.y - bounds.y - self.anticipate.0;
+            let bottom = viewport.y + viewport.height - bounds.y + self.anticipate.0;
+
+            let visible = visible(&state.extents, top, bottom);
+
+            if state.visible.as_ref() != Some(&visible) {
+                state.visible = Some(visible.clone());
+                shell.publish(on_visible(visible));
+            }
+        }
+
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(viewport) = layout.bounds().intersection(viewport) {
+            z_index::draw_children(
+                &self.children,
+                tree,
+                layout,
+                renderer,
+                theme,
+                style,
+                cursor,
+                &viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Masonry<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(masonry: Masonry<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(masonry)
+    }
+}
+
+/// The bottoms of the columns of a [`Masonry`] being packed.
+struct Columns {
+    bottoms: Vec<f32>,
+    spacing: f32,
+}
+
+impl Columns {
+    fn new(amount: usize, spacing: f32) -> Self {
+        Self {
+            bottoms: vec![0.0; amount],
+            spacing,
+        }
+    }
+
+    /// Places an item of the given height in the shortest column; returning
+    /// the index of the column and the vertical offset of the item.
+    fn place(&mut self, height: f32) -> (usize, f32) {
+        let (column, y) = self
+            .bottoms
+            .iter()
+            .copied()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap_or((0, 0.0));
+
+        self.bottoms[column] = y + height + self.spacing;
+
+        (column, y)
+    }
+
+    fn height(&self) -> f32 {
+        self.bottoms
+            .iter()
+            .copied()
+            .fold(0.0, f32::max)
+            .max(self.spacing)
+            - self.spacing
+    }
+}
+
+/// Returns the range of the items with vertical extents between `top`
+/// and `bottom`.
+fn visible(extents: &[(f32, f32)], top: f32, bottom: f32) -> Range<usize> {
+    let mut visible = extents
+        .iter()
+        .enumerate()
+        .filter(|(_, (start, end))| *start < bottom && *end > top)
+        .map(|(i, _)| i);
+
+    let Some(first) = visible.next() else {
+        return 0..0;
+    };
+
+    first..visible.next_back().unwrap_or(first) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_packs_items_in_the_shortest_column() {
+        let mut columns = Columns::new(3, 10.0);
+
+        let placements: Vec<_> = [100.0, 50.0, 80.0, 30.0, 40.0, 20.0]
+            .into_iter()
+            .map(|height| columns.place(height))
+            .collect();
+
+        assert_eq!(
+            placements,
+            [
+                (0, 0.0),
+                (1, 0.0),
+                (2, 0.0),
+                (1, 60.0),
+                (2, 90.0),
+                (1, 100.0),
+            ]
+        );
+        assert_eq!(columns.height(), 130.0);
+
+        let extents = [(0.0, 100.0), (0.0, 50.0), (200.0, 300.0), (400.0, 500.0)];
+
+        assert_eq!(visible(&extents, 150.0, 250.0), 2..3);
+        assert_eq!(visible(&extents, 0.0, 250.0), 0..3);
+        assert_eq!(visible(&extents, 600.0, 700.0), 0..0);
+    }
+}
@@ -3,11 +3,16 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::{Operation, Tree, tree};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
 };
+use crate::hold::{self, Hold};
+
+const DEFAULT_HOVER_DELAY: Duration = Duration::from_millis(200);
 
 /// Emit messages on mouse events.
 pub struct MouseArea<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
@@ -15,6 +20,9 @@ pub struct MouseArea<'a, Message, Theme = crate::Theme, Renderer = crate::Render
     on_press: Option<Message>,
     on_release: Option<Message>,
     on_double_click: Option<Message>,
+    on_long_press: Option<Message>,
+    long_press: Duration,
+    repeat: Option<(Duration, Duration)>,
     on_right_press: Option<Message>,
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
@@ -23,6 +31,9 @@ pub struct MouseArea<'a, Message, Theme = crate::Theme, Renderer = crate::Render
     on_enter: Option<Message>,
     on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_exit: Option<Message>,
+    on_hover_enter: Option<Message>,
+    on_hover_exit: Option<Message>,
+    hover_delay: Duration,
     interaction: Option<mouse::Interaction>,
 }
 
@@ -57,6 +68,39 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when the left button is held down for a while.
+    ///
+    /// The [`on_press`] message is still emitted when the press starts.
+    ///
+    /// [`on_press`]: Self::on_press
+    #[must_use]
+    pub fn on_long_press(mut self, message: Message) -> Self {
+        self.on_long_press = Some(message);
+        self
+    }
+
+    /// Sets the duration the left button needs to be held down to emit
+    /// the [`on_long_press`] message.
+    ///
+    /// Defaults to 500 milliseconds.
+    ///
+    /// [`on_long_press`]: Self::on_long_press
+    #[must_use]
+    pub fn long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press = duration;
+        self
+    }
+
+    /// Emits the [`on_press`] message repeatedly while the left button is
+    /// held down; first after the given `delay`, and then every `interval`.
+    ///
+    /// [`on_press`]: Self::on_press
+    #[must_use]
+    pub fn repeat(mut self, delay: Duration, interval: Duration) -> Self {
+        self.repeat = Some((delay, interval));
+        self
+    }
+
     /// The message to emit on a right button press.
     #[must_use]
     pub fn on_right_press(mut self, message: Message) -> Self {
@@ -113,6 +157,45 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when the mouse rests over the area; that is,
+    /// when it stays in the area for the [`hover_delay`].
+    ///
+    /// Unlike [`on_enter`], merely crossing the area does not emit it;
+    /// which is useful to open flyouts and tooltips without flickering.
+    ///
+    /// [`hover_delay`]: Self::hover_delay
+    /// [`on_enter`]: Self::on_enter
+    #[must_use]
+    pub fn on_hover_enter(mut self, message: Message) -> Self {
+        self.on_hover_enter = Some(message);
+        self
+    }
+
+    /// The message to emit when the mouse leaves the area after an
+    /// [`on_hover_enter`]; once it stays out of the area for the
+    /// [`hover_delay`].
+    ///
+    /// [`on_hover_enter`]: Self::on_hover_enter
+    /// [`hover_delay`]: Self::hover_delay
+    #[must_use]
+    pub fn on_hover_exit(mut self, message: Message) -> Self {
+        self.on_hover_exit = Some(message);
+        self
+    }
+
+    /// Sets the time the mouse needs to stay in or out of the area to emit
+    /// the [`on_hover_enter`] and [`on_hover_exit`] messages.
+    ///
+    /// Defaults to 200 milliseconds.
+    ///
+    /// [`on_hover_enter`]: Self::on_hover_enter
+    /// [`on_hover_exit`]: Self::on_hover_exit
+    #[must_use]
+    pub fn hover_delay(mut self, delay: Duration) -> Self {
+        self.hover_delay = delay;
+        self
+    }
+
     /// The [`mouse::Interaction`] to use when hovering the area.
     #[must_use]
     pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
@@ -128,6 +211,9 @@ struct State {
     bounds: Rectangle,
     cursor_position: Option<Point>,
     previous_click: Option<mouse::Click>,
+    hold: Option<Hold>,
+    is_hover_intended: bool,
+    hover_deadline: Option<Instant>,
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
@@ -138,6 +224,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_press: None,
             on_release: None,
             on_double_click: None,
+            on_long_press: None,
+            long_press: hold::DEFAULT_LONG_PRESS,
+            repeat: None,
             on_right_press: None,
             on_right_release: None,
             on_middle_press: None,
@@ -146,6 +235,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_enter: None,
             on_move: None,
             on_exit: None,
+            on_hover_enter: None,
+            on_hover_exit: None,
+            hover_delay: DEFAULT_HOVER_DELAY,
             interaction: None,
         }
     }
@@ -346,6 +438,74 @@ fn update<Message: Clone, Theme, Renderer>(
             }
             _ => {}
         }
+
+        if widget.on_hover_enter.is_some() || widget.on_hover_exit.is_some() {
+            // The hover intent changes only once the mouse stays in (or out of)
+            // the area for a while
+            if state.is_hovered != state.is_hover_intended {
+                if state.hover_deadline.is_none() {
+                    let deadline = Instant::now() + widget.hover_delay;
+
+                    state.hover_deadline = Some(deadline);
+                    shell.request_redraw_at(deadline);
+                }
+            } else {
+                state.hover_deadline = None;
+            }
+        }
+    }
+
+    match event {
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            if let Some(deadline) = state.hover_deadline {
+                if *now >= deadline {
+                    state.hover_deadline = None;
+                    state.is_hover_intended = state.is_hovered;
+
+                    let message = if state.is_hover_intended {
+                        widget.on_hover_enter.as_ref()
+                    } else {
+                        widget.on_hover_exit.as_ref()
+                    };
+
+                    if let Some(message) = message {
+                        shell.publish(message.clone());
+                    }
+                } else {
+                    shell.request_redraw_at(deadline);
+                }
+            }
+
+            if let Some(hold) = &mut state.hold {
+                if state.is_hovered {
+                    let long_press = widget.on_long_press.as_ref().map(|_| widget.long_press);
+                    let tick = hold.tick(*now, long_press, widget.repeat);
+
+                    if tick.is_long_press
+                        && let Some(message) = widget.on_long_press.as_ref()
+                    {
+                        shell.publish(message.clone());
+                    }
+
+                    if tick.is_repeat
+                        && let Some(message) = widget.on_press.as_ref()
+                    {
+                        shell.publish(message.clone());
+                    }
+
+                    if let Some(next) = tick.next {
+                        shell.request_redraw_at(next);
+                    }
+                } else {
+                    state.hold = None;
+                }
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+            state.hold = None;
+        }
+        _ => {}
     }
 
     if !cursor.is_over(layout.bounds()) {
@@ -360,6 +520,18 @@ fn update<Message: Clone, Theme, Renderer>(
                 shell.capture_event();
             }
 
+            if widget.on_long_press.is_some() || widget.repeat.is_some() {
+                let long_press = widget.on_long_press.as_ref().map(|_| widget.long_press);
+                let hold = Hold::new(Instant::now());
+
+                if let Some(deadline) = hold.deadline(long_press, widget.repeat) {
+                    shell.request_redraw_at(deadline);
+                }
+
+                state.hold = Some(hold);
+                shell.capture_event();
+            }
+
             if let Some(position) = cursor_position
                 && let Some(message) = widget.on_double_click.as_ref()
             {
@@ -12,6 +12,7 @@
 //! * Tracking of the last active pane
 //! * Mouse-based resizing
 //! * Drag and drop to reorganize panes
+//! * Floating panes that can be moved, resized, and docked back
 //! * Hotkey support
 //! * Configurable modifier keys
 //! * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -94,6 +95,7 @@ use crate::core::{
 
 const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
+const FLOATING_HANDLE_SIZE: f32 = 8.0;
 
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
@@ -110,6 +112,7 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Floating panes that can be moved, resized, and docked back
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -162,6 +165,7 @@ where
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_float: Option<Box<dyn Fn(FloatEvent) -> Message + 'a>>,
     class: <Theme as Catalog>::Class<'a>,
     last_mouse_interaction: Option<mouse::Interaction>,
 }
@@ -200,6 +204,7 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
+            on_float: None,
             class: <Theme as Catalog>::default(),
             last_mouse_interaction: None,
         }
@@ -270,6 +275,24 @@ where
         self
     }
 
+    /// Enables the move and resize interactions of the floating panes of the
+    /// [`PaneGrid`], which will use the provided function to produce messages.
+    ///
+    /// Floating panes can be moved by dragging their [`TitleBar`] and resized by
+    /// dragging their right and bottom edges. Clicking a floating pane will also
+    /// produce a [`FloatEvent`], so it can be brought to the front.
+    ///
+    /// If drag and drop is enabled with [`on_drag`](Self::on_drag), a floating pane
+    /// dropped on an edge of the [`PaneGrid`] will produce a [`DragEvent::Dropped`],
+    /// so it can be docked back.
+    pub fn on_float<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(FloatEvent) -> Message,
+    {
+        self.on_float = Some(Box::new(f));
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -306,6 +329,28 @@ where
             return Some(mouse::Interaction::Grabbing);
         }
 
+        if let Some((_, _, _, handle)) = action.picked_floating() {
+            return Some(match handle {
+                Some(handle) => handle_interaction(handle),
+                None => mouse::Interaction::Grabbing,
+            });
+        }
+
+        if let Some(cursor_position) = cursor.position()
+            && let Some((_, bounds)) = self
+                .floating_regions(layout)
+                .into_iter()
+                .rev()
+                .find(|(_, bounds)| bounds.contains(cursor_position))
+        {
+            return self
+                .on_float
+                .is_some()
+                .then(|| floating_handle(bounds, cursor_position))
+                .flatten()
+                .map(handle_interaction);
+        }
+
         let resize_leeway = self.on_resize.as_ref().map(|(leeway, _)| *leeway);
         let node = self.internal.layout();
 
@@ -335,6 +380,45 @@ where
     }
 }
 
+impl<Message, Theme, Renderer> PaneGrid<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    /// Returns the floating panes with their bounds; from back to front.
+    fn floating_regions(&self, layout: Layout<'_>) -> Vec<(Pane, Rectangle)> {
+        self.internal
+            .floating()
+            .iter()
+            .filter_map(|pane| {
+                let index = self.panes.iter().position(|p| p == pane)?;
+
+                Some((*pane, layout.children().nth(index)?.bounds()))
+            })
+            .collect()
+    }
+}
+
+/// Returns the [`mouse::Cursor`] of a [`Pane`], levitating it if the
+/// pane is covered by a floating one.
+fn floating_cursor(
+    pane: Pane,
+    floating: &[(Pane, Rectangle)],
+    cursor: mouse::Cursor,
+) -> mouse::Cursor {
+    let above = match floating.iter().position(|(p, _)| *p == pane) {
+        Some(index) => &floating[index + 1..],
+        None => floating,
+    };
+
+    match cursor.position() {
+        Some(position) if above.iter().any(|(_, bounds)| bounds.contains(position)) => {
+            cursor.levitate()
+        }
+        _ => cursor,
+    }
+}
+
 #[derive(Default)]
 struct Memory {
     action: state::Action,
@@ -424,7 +508,11 @@ where
                     return Some(layout::Node::new(Size::ZERO));
                 }
 
-                let region = regions.get(pane)?;
+                let region = match self.internal.floating_bounds(*pane) {
+                    Some(floating) => clamp_floating(floating, bounds, self.min_size),
+                    None => *regions.get(pane)?,
+                };
+
                 let size = Size::new(region.width, region.height);
 
                 let node = content.layout(tree, renderer, &layout::Limits::new(size, size));
@@ -482,6 +570,7 @@ where
         };
 
         let picked_pane = action.picked_pane().map(|(pane, _)| pane);
+        let floating = self.floating_regions(layout);
 
         for (((pane, content), tree), layout) in self
             .panes
@@ -497,6 +586,7 @@ where
             })
         {
             let is_picked = picked_pane == Some(pane);
+            let cursor = floating_cursor(pane, &floating, cursor);
 
             content.update(
                 tree, event, layout, cursor, renderer, clipboard, shell, viewport, is_picked,
@@ -511,6 +601,47 @@ where
                 if let Some(cursor_position) = cursor.position_over(bounds) {
                     shell.capture_event();
 
+                    let floating_pane = floating
+                        .iter()
+                        .rev()
+                        .find(|(_, bounds)| bounds.contains(cursor_position));
+
+                    if let Some((pane, pane_bounds)) = floating_pane.copied() {
+                        if let Some(on_click) = &self.on_click {
+                            shell.publish(on_click(pane));
+                        }
+
+                        if let Some(on_float) = &self.on_float {
+                            let handle = floating_handle(pane_bounds, cursor_position);
+
+                            let is_draggable = self
+                                .panes
+                                .iter()
+                                .zip(&self.contents)
+                                .zip(layout.children())
+                                .find(|((p, _), _)| **p == pane)
+                                .is_some_and(|((_, content), layout)| {
+                                    content.can_be_dragged_at(layout, cursor_position)
+                                });
+
+                            if handle.is_some() || is_draggable {
+                                *action = state::Action::Floating {
+                                    pane,
+                                    origin: cursor_position,
+                                    bounds: pane_bounds - Vector::new(bounds.x, bounds.y),
+                                    handle,
+                                };
+                            }
+
+                            shell.publish(on_float(FloatEvent {
+                                pane,
+                                bounds: pane_bounds - Vector::new(bounds.x, bounds.y),
+                            }));
+                        }
+
+                        return;
+                    }
+
                     match &self.on_resize {
                         Some((leeway, _)) => {
                             let relative_cursor = Point::new(
@@ -570,6 +701,11 @@ where
                                 pane,
                                 target: Target::Edge(edge),
                             }
+                        } else if floating
+                            .iter()
+                            .any(|(_, bounds)| bounds.contains(cursor_position))
+                        {
+                            DragEvent::Canceled { pane }
                         } else {
                             let dropped_region = self
                                 .panes
@@ -599,10 +735,53 @@ where
                     }
                 }
 
+                if let Some((pane, origin, _, None)) = action.picked_floating()
+                    && let Some(on_drag) = on_drag
+                    && let Some(cursor_position) = cursor.position()
+                    && cursor_position.distance(origin) > DRAG_DEADBAND_DISTANCE
+                    && let Some(edge) = in_edge(layout, cursor_position)
+                {
+                    shell.publish(on_drag(DragEvent::Dropped {
+                        pane,
+                        target: Target::Edge(edge),
+                    }));
+                }
+
                 *action = state::Action::Idle;
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((pane, origin, pane_bounds, handle)) = action.picked_floating()
+                    && let Some(on_float) = &self.on_float
+                    && let Some(cursor_position) = cursor.position()
+                {
+                    let delta = cursor_position - origin;
+
+                    let pane_bounds = match handle {
+                        None => pane_bounds + delta,
+                        Some(handle) => {
+                            let (width, height) = match handle {
+                                state::Handle::Right => (delta.x, 0.0),
+                                state::Handle::Bottom => (0.0, delta.y),
+                                state::Handle::BottomRight => (delta.x, delta.y),
+                            };
+
+                            Rectangle {
+                                width: pane_bounds.width + width,
+                                height: pane_bounds.height + height,
+                                ..pane_bounds
+                            }
+                        }
+                    };
+
+                    shell.publish(on_float(FloatEvent {
+                        pane,
+                        bounds: clamp_floating(pane_bounds, layout.bounds().size(), self.min_size),
+                    }));
+
+                    shell.capture_event();
+                }
+
                 if let Some((_, on_resize)) = &self.on_resize {
                     if let Some((split, _)) = action.picked_split() {
                         let bounds = layout.bounds();
@@ -681,6 +860,8 @@ where
             return grid_interaction;
         }
 
+        let floating = self.floating_regions(layout);
+
         self.panes
             .iter()
             .copied()
@@ -692,11 +873,11 @@ where
                     .maximized()
                     .is_none_or(|maximized| *pane == maximized)
             })
-            .map(|(((_, content), tree), layout)| {
+            .map(|(((pane, content), tree), layout)| {
                 content.mouse_interaction(
                     tree,
                     layout,
-                    cursor,
+                    floating_cursor(pane, &floating, cursor),
                     viewport,
                     renderer,
                     self.drag_enabled(),
@@ -767,7 +948,18 @@ where
 
         let mut render_picked_pane = None;
 
-        let pane_in_edge = if picked_pane.is_some() {
+        let moving_floating = action
+            .picked_floating()
+            .filter(|(_, origin, _, handle)| {
+                handle.is_none()
+                    && self.on_drag.is_some()
+                    && cursor
+                        .position()
+                        .is_some_and(|position| position.distance(*origin) > DRAG_DEADBAND_DISTANCE)
+            })
+            .is_some();
+
+        let pane_in_edge = if picked_pane.is_some() || moving_floating {
             cursor
                 .position()
                 .and_then(|cursor_position| in_edge(layout, cursor_position))
@@ -776,6 +968,7 @@ where
         };
 
         let style = Catalog::style(theme, &self.class);
+        let floating = self.floating_regions(layout);
 
         for (((id, content), tree), pane_layout) in self
             .panes
@@ -788,8 +981,11 @@ where
                 self.internal
                     .maximized()
                     .is_none_or(|maximized| maximized == *pane)
+                    && !self.internal.floating().contains(pane)
             })
         {
+            let pane_cursor = floating_cursor(id, &floating, pane_cursor);
+
             match picked_pane {
                 Some((dragging, origin)) if id == dragging => {
                     render_picked_pane = Some(((content, tree), origin, pane_layout));
@@ -837,6 +1033,36 @@ where
             }
         }
 
+        // Render floating panes from back to front
+        for (pane, bounds) in &floating {
+            let Some(((content, tree), pane_layout)) = self
+                .panes
+                .iter()
+                .zip(&self.contents)
+                .zip(&tree.children)
+                .zip(layout.children())
+                .find_map(|(((id, content), tree), layout)| {
+                    (id == pane).then_some(((content, tree), layout))
+                })
+            else {
+                continue;
+            };
+
+            let pane_cursor = floating_cursor(*pane, &floating, pane_cursor);
+
+            renderer.with_layer(*bounds, |renderer| {
+                content.draw(
+                    tree,
+                    renderer,
+                    theme,
+                    defaults,
+                    pane_layout,
+                    pane_cursor,
+                    viewport,
+                );
+            });
+        }
+
         if let Some(edge) = pane_in_edge {
             let bounds = edge_bounds(layout, edge);
 
@@ -1157,9 +1383,52 @@ pub struct ResizeEvent {
     pub ratio: f32,
 }
 
+/// An event produced when a floating [`Pane`] of a [`PaneGrid`] is picked,
+/// moved, or resized.
+#[derive(Debug, Clone, Copy)]
+pub struct FloatEvent {
+    /// The floating [`Pane`].
+    pub pane: Pane,
+
+    /// The new bounds of the [`Pane`], relative to the [`PaneGrid`].
+    pub bounds: Rectangle,
+}
+
 /*
  * Helpers
  */
+fn floating_handle(bounds: Rectangle, cursor_position: Point) -> Option<state::Handle> {
+    let right = cursor_position.x >= bounds.x + bounds.width - FLOATING_HANDLE_SIZE;
+    let bottom = cursor_position.y >= bounds.y + bounds.height - FLOATING_HANDLE_SIZE;
+
+    match (right, bottom) {
+        (true, true) => Some(state::Handle::BottomRight),
+        (true, false) => Some(state::Handle::Right),
+        (false, true) => Some(state::Handle::Bottom),
+        (false, false) => None,
+    }
+}
+
+fn handle_interaction(handle: state::Handle) -> mouse::Interaction {
+    match handle {
+        state::Handle::Right => mouse::Interaction::ResizingHorizontally,
+        state::Handle::Bottom => mouse::Interaction::ResizingVertically,
+        state::Handle::BottomRight => mouse::Interaction::ResizingDiagonallyDown,
+    }
+}
+
+fn clamp_floating(bounds: Rectangle, grid: Size, min_size: f32) -> Rectangle {
+    let width = bounds.width.max(min_size).min(grid.width);
+    let height = bounds.height.max(min_size).min(grid.height);
+
+    Rectangle {
+        x: bounds.x.clamp(0.0, grid.width - width),
+        y: bounds.y.clamp(0.0, grid.height - height),
+        width,
+        height,
+    }
+}
+
 fn hovered_split<'a>(
     mut splits: impl Iterator<Item = (&'a Split, &'a (Axis, Rectangle, f32))>,
     spacing: f32,
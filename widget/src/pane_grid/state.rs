@@ -1,7 +1,7 @@
 //! The state of a [`PaneGrid`].
 //!
 //! [`PaneGrid`]: super::PaneGrid
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::pane_grid::{Axis, Configuration, Direction, Edge, Node, Pane, Region, Split, Target};
 
 use std::borrow::Cow;
@@ -151,6 +151,8 @@ impl<T> State<T> {
     }
 
     /// Drops the given [`Pane`] into the provided [`Target`].
+    ///
+    /// If the [`Pane`] is floating, it will be docked back into the grid.
     pub fn drop(&mut self, pane: Pane, target: Target) {
         match target {
             Target::Edge(edge) => self.move_to_edge(pane, edge),
@@ -199,6 +201,10 @@ impl<T> State<T> {
     }
 
     fn split_and_swap(&mut self, axis: Axis, target: Pane, pane: Pane, swap: bool) {
+        if self.is_floating(target) {
+            return;
+        }
+
         if let Some((state, _)) = self.close(pane)
             && let Some((new_pane, _)) = self.split(axis, target, state)
         {
@@ -267,6 +273,27 @@ impl<T> State<T> {
                 }
             }
         });
+
+        if self.is_floating(a) || self.is_floating(b) {
+            for pane in &mut self.internal.floating {
+                if *pane == a {
+                    *pane = b;
+                } else if *pane == b {
+                    *pane = a;
+                }
+            }
+
+            let bounds_a = self.internal.floating_bounds.remove(&a);
+            let bounds_b = self.internal.floating_bounds.remove(&b);
+
+            if let Some(bounds) = bounds_a {
+                let _ = self.internal.floating_bounds.insert(b, bounds);
+            }
+
+            if let Some(bounds) = bounds_b {
+                let _ = self.internal.floating_bounds.insert(a, bounds);
+            }
+        }
     }
 
     /// Resizes two panes by setting the position of the provided [`Split`].
@@ -285,12 +312,23 @@ impl<T> State<T> {
 
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
+    ///
+    /// The closest sibling of a floating [`Pane`] is the [`Pane`] right below it.
     pub fn close(&mut self, pane: Pane) -> Option<(T, Pane)> {
         if self.internal.maximized == Some(pane) {
             let _ = self.internal.maximized.take();
         }
 
-        if let Some(sibling) = self.internal.layout.remove(pane) {
+        if let Some(index) = self.internal.floating.iter().position(|p| *p == pane) {
+            let _ = self.internal.floating.remove(index);
+
+            let below = index
+                .checked_sub(1)
+                .map(|index| self.internal.floating[index])
+                .unwrap_or_else(|| self.internal.layout.first_pane());
+
+            self.panes.remove(&pane).map(|state| (state, below))
+        } else if let Some(sibling) = self.internal.layout.remove(pane) {
             self.panes.remove(&pane).map(|state| (state, sibling))
         } else {
             None
@@ -319,6 +357,60 @@ impl<T> State<T> {
     pub fn maximized(&self) -> Option<Pane> {
         self.internal.maximized
     }
+
+    /// Pops the given [`Pane`] out of the grid, making it float on top of
+    /// the rest of panes with the given bounds—relative to the [`PaneGrid`].
+    ///
+    /// If the [`Pane`] is already floating, its bounds are updated and it is
+    /// brought to the front.
+    ///
+    /// The last docked [`Pane`] of the grid cannot float.
+    ///
+    /// Floating panes can be docked back into the grid with [`Self::drop()`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub fn float(&mut self, pane: Pane, bounds: Rectangle) {
+        if !self.panes.contains_key(&pane) {
+            return;
+        }
+
+        if let Some(index) = self.internal.floating.iter().position(|p| *p == pane) {
+            let _ = self.internal.floating.remove(index);
+        } else if self.internal.layout.remove(pane).is_some() {
+            if self.internal.maximized == Some(pane) {
+                let _ = self.internal.maximized.take();
+            }
+        } else {
+            return;
+        }
+
+        self.internal.floating.push(pane);
+
+        let _ = self.internal.floating_bounds.insert(pane, bounds);
+    }
+
+    /// Returns `true` if the given [`Pane`] is floating.
+    pub fn is_floating(&self, pane: Pane) -> bool {
+        self.internal.floating.contains(&pane)
+    }
+
+    /// Returns an iterator over all the floating panes of the [`State`],
+    /// alongside their bounds; from back to front.
+    pub fn floating(&self) -> impl Iterator<Item = (Pane, Rectangle)> + '_ {
+        self.internal
+            .floating
+            .iter()
+            .filter_map(|pane| Some((*pane, self.floating_bounds(*pane)?)))
+    }
+
+    /// Returns the last known floating bounds of the given [`Pane`], if it
+    /// has ever floated.
+    ///
+    /// The bounds are kept after docking a [`Pane`], so they can be persisted
+    /// and reused when popping it out again.
+    pub fn floating_bounds(&self, pane: Pane) -> Option<Rectangle> {
+        self.internal.floating_bounds.get(&pane).copied()
+    }
 }
 
 /// The internal state of a [`PaneGrid`].
@@ -329,6 +421,8 @@ pub struct Internal {
     layout: Node,
     last_id: usize,
     maximized: Option<Pane>,
+    floating: Vec<Pane>,
+    floating_bounds: BTreeMap<Pane, Rectangle>,
 }
 
 impl Internal {
@@ -378,6 +472,8 @@ impl Internal {
             layout,
             last_id,
             maximized: None,
+            floating: Vec::new(),
+            floating_bounds: BTreeMap::new(),
         }
     }
 
@@ -391,6 +487,22 @@ impl Internal {
     pub(super) fn maximized(&self) -> Option<Pane> {
         self.maximized
     }
+
+    pub(super) fn floating(&self) -> &[Pane] {
+        if self.maximized.is_some() {
+            &[]
+        } else {
+            &self.floating
+        }
+    }
+
+    pub(super) fn floating_bounds(&self, pane: Pane) -> Option<Rectangle> {
+        if self.maximized.is_some() || !self.floating.contains(&pane) {
+            return None;
+        }
+
+        self.floating_bounds.get(&pane).copied()
+    }
 }
 
 /// The current action of a [`PaneGrid`].
@@ -421,6 +533,30 @@ pub enum Action {
         /// The [`Axis`] of the [`Split`].
         axis: Axis,
     },
+    /// A floating [`Pane`] in the [`PaneGrid`] is being moved or resized.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    Floating {
+        /// The floating [`Pane`].
+        pane: Pane,
+        /// The starting [`Point`] of the interaction.
+        origin: Point,
+        /// The bounds of the [`Pane`] when the interaction started.
+        bounds: Rectangle,
+        /// The [`Handle`] being dragged, or `None` if the [`Pane`] is being moved.
+        handle: Option<Handle>,
+    },
+}
+
+/// A resize handle of a floating [`Pane`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handle {
+    /// The right edge.
+    Right,
+    /// The bottom edge.
+    Bottom,
+    /// The bottom right corner.
+    BottomRight,
 }
 
 impl Action {
@@ -432,6 +568,19 @@ impl Action {
         }
     }
 
+    /// Returns the current floating [`Pane`] that is being moved or resized, if any.
+    pub fn picked_floating(&self) -> Option<(Pane, Point, Rectangle, Option<Handle>)> {
+        match *self {
+            Action::Floating {
+                pane,
+                origin,
+                bounds,
+                handle,
+            } => Some((pane, origin, bounds, handle)),
+            _ => None,
+        }
+    }
+
     /// Returns the current [`Split`] that is being dragged, if any.
     pub fn picked_split(&self) -> Option<(Split, Axis)> {
         match *self {
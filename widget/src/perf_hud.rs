@@ -0,0 +1,496 @@
+//! Performance HUDs display live frame timings and pipeline costs of an application.
+//!
+//! The statistics are collected by `iced_debug`, which is only enabled
+//! when the `debug` feature of `iced` is active. Otherwise, a [`PerfHud`]
+//! will not have any data to display.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{perf_hud, stack};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view<'a>(game: Element<'a, Message>) -> Element<'a, Message> {
+//!     stack![game, perf_hud()].into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::widget::Tree;
+use crate::core::widget::tree;
+use crate::core::window;
+use crate::core::{
+    Background, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Theme, Widget,
+};
+
+use iced_debug as debug;
+
+/// A heads-up display of the performance statistics of an application.
+///
+/// It shows the current frame rate, a graph of the most recent frame times
+/// against a frame budget, and the average cost of every stage of the
+/// runtime and every rendering pipeline.
+///
+/// A [`PerfHud`] has a fixed size by default, so it can be layered on top
+/// of any other content—like an embedded game—with a [`Stack`].
+///
+/// [`Stack`]: crate::Stack
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{perf_hud, stack};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view<'a>(game: Element<'a, Message>) -> Element<'a, Message> {
+///     stack![game, perf_hud()].into()
+/// }
+/// ```
+pub struct PerfHud<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    width: Length,
+    height: Length,
+    budget: Duration,
+    refresh_interval: Duration,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> PerfHud<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default width of a [`PerfHud`].
+    pub const DEFAULT_WIDTH: f32 = 300.0;
+
+    /// The default height of a [`PerfHud`].
+    pub const DEFAULT_HEIGHT: f32 = 200.0;
+
+    /// The default frame budget of a [`PerfHud`]; 60 frames per second.
+    pub const DEFAULT_BUDGET: Duration = Duration::from_micros(16_667);
+
+    /// Creates a new [`PerfHud`].
+    pub fn new() -> Self {
+        Self {
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            budget: Self::DEFAULT_BUDGET,
+            refresh_interval: milliseconds(250),
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`PerfHud`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`PerfHud`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the frame budget of the [`PerfHud`].
+    ///
+    /// Frames taking longer than the budget will be highlighted in the graph.
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Sets how often the [`PerfHud`] fetches new statistics.
+    ///
+    /// By default, it refreshes 4 times per second.
+    pub fn refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Sets the text size of the [`PerfHud`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the [`PerfHud`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`PerfHud`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Theme> Default for PerfHud<'_, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    stats: debug::Stats,
+    last_refresh: Option<Instant>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for PerfHud<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn crate::core::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let is_stale = state
+            .last_refresh
+            .is_none_or(|last_refresh| *now >= last_refresh + self.refresh_interval);
+
+        if is_stale {
+            state.stats = debug::stats();
+            state.last_refresh = Some(*now);
+        }
+
+        if let Some(last_refresh) = state.last_refresh {
+            shell.request_redraw_at(last_refresh + self.refresh_interval);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        const PADDING: f32 = 8.0;
+        const GRAPH_HEIGHT: f32 = 48.0;
+
+        let state = tree.state.downcast_ref::<State>();
+        let stats = &state.stats;
+
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = text::LineHeight::default().to_absolute(text_size).0;
+
+        let font = renderer.default_font();
+        let hint_factor = renderer.scale_factor();
+
+        let label = |renderer: &mut Renderer, content: String, position: Point, color: Color| {
+            renderer.fill_text(
+                text::Text {
+                    content,
+                    bounds: Size::new(bounds.width - PADDING * 2.0, line_height),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Left,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                    hint_factor,
+                },
+                position,
+                color,
+                clip_bounds,
+            );
+        };
+
+        let content = Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: bounds.width - PADDING * 2.0,
+            height: bounds.height - PADDING * 2.0,
+        };
+
+        if stats.frames.is_empty() {
+            label(
+                renderer,
+                String::from("No statistics available.\nIs the `debug` feature enabled?"),
+                content.position(),
+                style.text_color,
+            );
+
+            return;
+        }
+
+        let frame_time = stats.frames.iter().sum::<Duration>() / stats.frames.len() as u32;
+
+        label(
+            renderer,
+            format!(
+                "{fps:.0} FPS  {frame_time:.1} ms  {layers} layers  {reallocations} reallocs",
+                fps = stats.fps(),
+                frame_time = as_millis(frame_time),
+                layers = stats.layers,
+                reallocations = stats.reallocations,
+            ),
+            content.position(),
+            if frame_time > self.budget {
+                style.over_budget
+            } else {
+                style.text_color
+            },
+        );
+
+        let graph = Rectangle {
+            y: content.y + line_height + PADDING / 2.0,
+            height: GRAPH_HEIGHT,
+            ..content
+        };
+
+        // The graph fits twice the budget, so the budget line stays centered
+        let scale = graph.height / (self.budget.as_secs_f32() * 2.0);
+        let bar_width = graph.width / stats.frames.len().max(1) as f32;
+
+        for (i, frame) in stats.frames.iter().enumerate() {
+            let height = (frame.as_secs_f32() * scale).min(graph.height);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: graph.x + i as f32 * bar_width,
+                        y: graph.y + graph.height - height,
+                        width: bar_width,
+                        height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                if *frame > self.budget {
+                    style.over_budget
+                } else {
+                    style.graph
+                },
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: graph.center_y(),
+                    height: 1.0,
+                    ..graph
+                },
+                ..renderer::Quad::default()
+            },
+            style.text_color.scale_alpha(0.5),
+        );
+
+        let column_width = content.width / 2.0;
+        let top = graph.y + graph.height + PADDING / 2.0;
+
+        let stages = [
+            ("Update", debug::Stage::Update),
+            ("View", debug::Stage::View),
+            ("Layout", debug::Stage::Layout),
+            ("Interact", debug::Stage::Interact),
+            ("Draw", debug::Stage::Draw),
+            ("Present", debug::Stage::Present),
+        ];
+
+        for (i, (name, stage)) in stages.into_iter().enumerate() {
+            let Some(duration) = stats.stage(stage) else {
+                continue;
+            };
+
+            label(
+                renderer,
+                format!("{name} {:.2} ms", as_millis(duration)),
+                Point::new(content.x, top + i as f32 * line_height),
+                style.text_color,
+            );
+        }
+
+        let pipelines = [
+            ("Quads", debug::Primitive::Quad),
+            ("Triangles", debug::Primitive::Triangle),
+            ("Shaders", debug::Primitive::Shader),
+            ("Images", debug::Primitive::Image),
+            ("Text", debug::Primitive::Text),
+        ];
+
+        for (i, (name, primitive)) in pipelines.into_iter().enumerate() {
+            let prepare = stats.stage(debug::Stage::Prepare(primitive));
+            let render = stats.stage(debug::Stage::Render(primitive));
+
+            if prepare.is_none() && render.is_none() {
+                continue;
+            }
+
+            label(
+                renderer,
+                format!(
+                    "{name} {:.2} / {:.2} ms",
+                    prepare.map(as_millis).unwrap_or_default(),
+                    render.map(as_millis).unwrap_or_default(),
+                ),
+                Point::new(content.x + column_width, top + i as f32 * line_height),
+                style.text_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PerfHud<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(perf_hud: PerfHud<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(perf_hud)
+    }
+}
+
+fn as_millis(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+/// The appearance of a performance HUD.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the performance HUD.
+    pub background: Background,
+    /// The [`Border`] of the performance HUD.
+    pub border: Border,
+    /// The [`Color`] of the text of the performance HUD.
+    pub text_color: Color,
+    /// The [`Color`] of the frames within budget in the graph.
+    pub graph: Color,
+    /// The [`Color`] of the frames over budget in the graph.
+    pub over_budget: Color,
+}
+
+/// The theme catalog of a [`PerfHud`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`PerfHud`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`PerfHud`].
+///
+/// It is translucent, so the content below stays visible.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.scale_alpha(0.85).into(),
+        border: border::rounded(4),
+        text_color: palette.background.base.text,
+        graph: palette.success.base.color,
+        over_budget: palette.danger.base.color,
+    }
+}
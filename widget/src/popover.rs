@@ -0,0 +1,764 @@
+//! Display floating content next to an anchor widget.
+//!
+//! A [`Popover`] positions its content on a side of its anchor; flipping it
+//! to the opposite side and shifting it to stay within the viewport when
+//! there is not enough space. The same [`Positioning`] logic can be used by
+//! any other overlay.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{button, column, container, popover, text};
+//! use iced::widget::popover::Placement;
+//!
+//! struct State {
+//!     is_menu_open: bool,
+//! }
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     ToggleMenu,
+//!     CloseMenu,
+//!     Uninstall,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     popover(
+//!         button("More").on_press(Message::ToggleMenu),
+//!         column![
+//!             text("Game options"),
+//!             button("Uninstall").on_press(Message::Uninstall),
+//!         ],
+//!     )
+//!     .open(state.is_menu_open)
+//!     .placement(Placement::Bottom)
+//!     .arrow(8)
+//!     .on_dismiss(Message::CloseMenu)
+//!     .style(container::bordered_box)
+//!     .into()
+//! }
+//! ```
+use crate::container;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    self, Alignment, Background, Clipboard, Element, Event, Length, Padding, Pixels, Rectangle,
+    Shell, Size, Vector,
+};
+
+/// A widget that displays some content next to an anchor widget.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, container, popover, text};
+/// use iced::widget::popover::Placement;
+///
+/// struct State {
+///     is_open: bool,
+/// }
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     Toggle,
+///     Close,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     popover(button("Info").on_press(Message::Toggle), text("Last played yesterday"))
+///         .open(state.is_open)
+///         .placement(Placement::Right)
+///         .on_dismiss(Message::Close)
+///         .style(container::rounded_box)
+///         .into()
+/// }
+/// ```
+pub struct Popover<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: core::Renderer,
+{
+    anchor: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    positioning: Positioning,
+    padding: Padding,
+    arrow: f32,
+    on_dismiss: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: core::Renderer,
+{
+    /// The default padding of a [`Popover`].
+    const DEFAULT_PADDING: f32 = 5.0;
+
+    /// Creates a new [`Popover`] displaying the given content next to the
+    /// given anchor.
+    pub fn new(
+        anchor: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            anchor: anchor.into(),
+            content: content.into(),
+            is_open: true,
+            positioning: Positioning::default(),
+            padding: Padding::new(Self::DEFAULT_PADDING),
+            arrow: 0.0,
+            on_dismiss: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the content of the [`Popover`] is displayed.
+    ///
+    /// By default, it is.
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+
+    /// Sets the preferred [`Placement`] of the content of the [`Popover`].
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.positioning.placement = placement;
+        self
+    }
+
+    /// Sets the alignment of the content of the [`Popover`] along the side
+    /// of its anchor.
+    pub fn align(mut self, align: impl Into<Alignment>) -> Self {
+        self.positioning.align = align.into();
+        self
+    }
+
+    /// Sets the gap between the anchor and the content of the [`Popover`].
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.positioning.gap = gap.into().0;
+        self
+    }
+
+    /// Sets whether the content of the [`Popover`] can be flipped to the
+    /// opposite side of its anchor when it does not fit.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.positioning.flip = flip;
+        self
+    }
+
+    /// Sets whether the content of the [`Popover`] can be shifted to stay
+    /// within the viewport.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.positioning.shift = shift;
+        self
+    }
+
+    /// Sets the padding of the [`Popover`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the size of the arrow pointing from the [`Popover`] to its
+    /// anchor.
+    ///
+    /// By default, there is no arrow.
+    pub fn arrow(mut self, size: impl Into<Pixels>) -> Self {
+        self.arrow = size.into().0;
+        self
+    }
+
+    /// Sets the message produced when the [`Popover`] is open and a mouse
+    /// button is pressed outside of it or `Escape` is pressed.
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Popover`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> container::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as container::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Popover`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Popover<'_, Message, Theme, Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.anchor),
+            widget::Tree::new(&self.content),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[self.anchor.as_widget(), self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.anchor.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.anchor.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.anchor
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.anchor
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.anchor.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.anchor.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.anchor.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = tree.children.iter_mut();
+
+        let anchor = self.anchor.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let popover = self.is_open.then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                anchor: layout.bounds() + translation,
+                content: &mut self.content,
+                tree: children.next().unwrap(),
+                positioning: Positioning {
+                    gap: self.positioning.gap + self.arrow,
+                    ..self.positioning
+                },
+                padding: self.padding,
+                arrow: self.arrow,
+                on_dismiss: &mut self.on_dismiss,
+                class: &self.class,
+                placed: None,
+            }))
+        });
+
+        if anchor.is_some() || popover.is_some() {
+            Some(
+                overlay::Group::with_children(anchor.into_iter().chain(popover).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Popover<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: container::Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(popover: Popover<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(popover)
+    }
+}
+
+/// The side of an anchor where some floating content is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// On the left of the anchor.
+    Left,
+    /// On the right of the anchor.
+    Right,
+}
+
+impl Placement {
+    /// Returns the opposite [`Placement`].
+    pub fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Placement::Top | Placement::Bottom)
+    }
+}
+
+/// The logic used to position floating content next to an anchor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Positioning {
+    /// The preferred [`Placement`] of the content.
+    pub placement: Placement,
+    /// The alignment of the content along the side of the anchor.
+    pub align: Alignment,
+    /// The gap between the anchor and the content.
+    pub gap: f32,
+    /// Whether the content is flipped to the opposite side of the anchor
+    /// when it does not fit in the viewport, but would fit there.
+    pub flip: bool,
+    /// Whether the content is shifted to stay within the viewport.
+    pub shift: bool,
+}
+
+impl Default for Positioning {
+    fn default() -> Self {
+        Self {
+            placement: Placement::default(),
+            align: Alignment::Center,
+            gap: 0.0,
+            flip: true,
+            shift: true,
+        }
+    }
+}
+
+impl Positioning {
+    /// Positions content of the given [`Size`] next to the given anchor
+    /// bounds, within the given viewport.
+    pub fn place(&self, anchor: Rectangle, size: Size, viewport: Rectangle) -> Placed {
+        let mut placement = self.placement;
+        let mut bounds = self.bounds(placement, anchor, size);
+
+        if self.flip && !fits(placement, bounds, viewport) {
+            let opposite = placement.opposite();
+            let flipped = self.bounds(opposite, anchor, size);
+
+            if fits(opposite, flipped, viewport) {
+                placement = opposite;
+                bounds = flipped;
+            }
+        }
+
+        if self.shift {
+            bounds.x = bounds
+                .x
+                .min(viewport.x + viewport.width - bounds.width)
+                .max(viewport.x);
+
+            bounds.y = bounds
+                .y
+                .min(viewport.y + viewport.height - bounds.height)
+                .max(viewport.y);
+        }
+
+        let arrow = if placement.is_vertical() {
+            (anchor.center_x() - bounds.x).clamp(0.0, bounds.width)
+        } else {
+            (anchor.center_y() - bounds.y).clamp(0.0, bounds.height)
+        };
+
+        Placed {
+            bounds,
+            placement,
+            arrow,
+        }
+    }
+
+    fn bounds(&self, placement: Placement, anchor: Rectangle, size: Size) -> Rectangle {
+        let align = |start: f32, anchor_length: f32, length: f32| match self.align {
+            Alignment::Start => start,
+            Alignment::Center => start + (anchor_length - length) / 2.0,
+            Alignment::End => start + anchor_length - length,
+        };
+
+        let (x, y) = match placement {
+            Placement::Top => (
+                align(anchor.x, anchor.width, size.width),
+                anchor.y - self.gap - size.height,
+            ),
+            Placement::Bottom => (
+                align(anchor.x, anchor.width, size.width),
+                anchor.y + anchor.height + self.gap,
+            ),
+            Placement::Left => (
+                anchor.x - self.gap - size.width,
+                align(anchor.y, anchor.height, size.height),
+            ),
+            Placement::Right => (
+                anchor.x + anchor.width + self.gap,
+                align(anchor.y, anchor.height, size.height),
+            ),
+        };
+
+        Rectangle::new([x, y].into(), size)
+    }
+}
+
+fn fits(placement: Placement, bounds: Rectangle, viewport: Rectangle) -> bool {
+    match placement {
+        Placement::Top => bounds.y >= viewport.y,
+        Placement::Bottom => bounds.y + bounds.height <= viewport.y + viewport.height,
+        Placement::Left => bounds.x >= viewport.x,
+        Placement::Right => bounds.x + bounds.width <= viewport.x + viewport.width,
+    }
+}
+
+/// Some floating content positioned by a [`Positioning`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placed {
+    /// The bounds of the content.
+    pub bounds: Rectangle,
+    /// The final [`Placement`] of the content; after flipping.
+    pub placement: Placement,
+    /// The offset along the side of the content facing the anchor that is
+    /// the closest to the center of the anchor; where an arrow should point
+    /// from.
+    pub arrow: f32,
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: container::Catalog,
+{
+    anchor: Rectangle,
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut widget::Tree,
+    positioning: Positioning,
+    padding: Padding,
+    arrow: f32,
+    on_dismiss: &'b mut Option<Message>,
+    class: &'b Theme::Class<'a>,
+    placed: Option<Placed>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let viewport = Rectangle::with_size(bounds);
+
+        let node = self.content.as_widget_mut().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds).shrink(self.padding),
+        );
+
+        let size = node.size().expand(self.padding);
+        let placed = self.positioning.place(self.anchor, size, viewport);
+
+        self.placed = Some(placed);
+
+        layout::Node::with_children(
+            size,
+            vec![node.translate(Vector::new(self.padding.left, self.padding.top))],
+        )
+        .move_to(placed.bounds.position())
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_dismissed = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                !cursor.is_over(layout.bounds()) && !cursor.is_over(self.anchor)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => true,
+            _ => false,
+        };
+
+        if is_dismissed && let Some(on_dismiss) = self.on_dismiss.take() {
+            shell.publish(on_dismiss);
+        }
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(self.class);
+
+        container::draw_background(renderer, &style, bounds);
+
+        if self.arrow > 0.0
+            && let Some(placed) = self.placed
+        {
+            let color = match style.background {
+                Some(Background::Color(color)) => color,
+                _ => style.border.color,
+            };
+
+            draw_arrow(renderer, bounds, placed, self.arrow, color);
+        }
+
+        let defaults = renderer::Style {
+            text_color: style.text_color.unwrap_or(inherited_style.text_color),
+        };
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &defaults,
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            layout.children().next().unwrap(),
+            renderer,
+            &layout.bounds(),
+            Vector::ZERO,
+        )
+    }
+}
+
+/// Draws an arrow pointing from the given bounds to its anchor with rows of
+/// quads; one per pixel.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    placed: Placed,
+    size: f32,
+    color: core::Color,
+) where
+    Renderer: core::Renderer,
+{
+    let rows = size.ceil() as usize;
+
+    for row in 0..rows {
+        let distance = row as f32;
+        let half = size - distance;
+
+        let quad = match placed.placement {
+            Placement::Top => Rectangle {
+                x: bounds.x + placed.arrow - half,
+                y: bounds.y + bounds.height + distance,
+                width: half * 2.0,
+                height: 1.0,
+            },
+            Placement::Bottom => Rectangle {
+                x: bounds.x + placed.arrow - half,
+                y: bounds.y - distance - 1.0,
+                width: half * 2.0,
+                height: 1.0,
+            },
+            Placement::Left => Rectangle {
+                x: bounds.x + bounds.width + distance,
+                y: bounds.y + placed.arrow - half,
+                width: 1.0,
+                height: half * 2.0,
+            },
+            Placement::Right => Rectangle {
+                x: bounds.x - distance - 1.0,
+                y: bounds.y + placed.arrow - half,
+                width: 1.0,
+                height: half * 2.0,
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: quad,
+                snap: true,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_flips_and_shifts_to_fit() {
+        let viewport = Rectangle::new([0.0, 0.0].into(), Size::new(800.0, 600.0));
+        let size = Size::new(200.0, 100.0);
+
+        let positioning = Positioning {
+            placement: Placement::Top,
+            gap: 10.0,
+            ..Positioning::default()
+        };
+
+        let placed = positioning.place(
+            Rectangle::new([300.0, 300.0].into(), Size::new(100.0, 40.0)),
+            size,
+            viewport,
+        );
+
+        assert_eq!(placed.placement, Placement::Top);
+        assert_eq!(placed.bounds, Rectangle::new([250.0, 190.0].into(), size));
+        assert_eq!(placed.arrow, 100.0);
+
+        let placed = positioning.place(
+            Rectangle::new([0.0, 20.0].into(), Size::new(40.0, 40.0)),
+            size,
+            viewport,
+        );
+
+        assert_eq!(placed.placement, Placement::Bottom);
+        assert_eq!(placed.bounds, Rectangle::new([0.0, 70.0].into(), size));
+        assert_eq!(placed.arrow, 20.0);
+    }
+}
@@ -0,0 +1,372 @@
+//! Render a widget in an overlay layer, out of its place in the tree.
+//!
+//! A [`Portal`] keeps the space of its contents in its parent, but draws them
+//! in an overlay [`Layer`] instead; escaping the clipping of any scrollable
+//! or container above it. Contents in a [`Layer`] keep receiving events as if
+//! they were in place.
+//!
+//! Contents can also follow the mouse cursor; useful for drag previews.
+//!
+//! A [`Portal`] can only target the layers of its own window.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, container, portal, scrollable, stack, text};
+//! use iced::widget::portal::{Layer, Target};
+//!
+//! struct State {
+//!     dragging: Option<String>,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let list = scrollable(column![
+//!         portal(Layer::OVERLAY, text("I am never clipped!")),
+//!         text("..."),
+//!     ]);
+//!
+//!     match &state.dragging {
+//!         Some(title) => stack![
+//!             list,
+//!             portal(
+//!                 Target::Cursor(Layer::TOP),
+//!                 container(text(title.as_str())).padding(10),
+//!             ),
+//!         ]
+//!         .into(),
+//!         None => list.into(),
+//!     }
+//! }
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{self, Tree, Widget};
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Transformation, Vector,
+};
+
+/// A widget that renders its contents in an overlay layer.
+pub struct Portal<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    target: Target,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Portal<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Portal`] rendering the given content in the given
+    /// [`Target`].
+    pub fn new(
+        target: impl Into<Target>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            content: content.into(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Portal<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        _tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let Target::Cursor(_) = self.target
+            && let Event::Mouse(mouse::Event::CursorMoved { .. }) = event
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        Some(overlay::Element::new(Box::new(Overlay {
+            content: &mut self.content,
+            tree: &mut tree.children[0],
+            layout,
+            target: self.target,
+            translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Portal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(portal: Portal<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(portal)
+    }
+}
+
+/// The place where the contents of a [`Portal`] are rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Target {
+    /// The given overlay [`Layer`], in place.
+    Layer(Layer),
+    /// The given overlay [`Layer`], with the top-left corner of the contents
+    /// following the mouse cursor.
+    ///
+    /// The contents do not receive any events.
+    Cursor(Layer),
+}
+
+impl From<Layer> for Target {
+    fn from(layer: Layer) -> Self {
+        Self::Layer(layer)
+    }
+}
+
+/// An overlay layer.
+///
+/// Layers with a higher index are rendered on top of layers with a lower
+/// index.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Layer(f32);
+
+impl Layer {
+    /// The layer of regular overlays; like menus and tooltips.
+    pub const OVERLAY: Self = Self(1.0);
+
+    /// The topmost layer; above every other overlay.
+    pub const TOP: Self = Self(f32::MAX);
+
+    /// Creates a new [`Layer`] with the given index.
+    pub const fn new(index: f32) -> Self {
+        Self(index)
+    }
+
+    /// Returns the index of the [`Layer`].
+    pub const fn index(self) -> f32 {
+        self.0
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    content: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    layout: Layout<'a>,
+    target: Target,
+    translation: Vector,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer> {
+    fn transformation(&self, cursor: mouse::Cursor) -> Option<Transformation> {
+        let translation = match self.target {
+            Target::Layer(_) => self.translation,
+            Target::Cursor(_) => cursor.position()? - self.layout.bounds().position(),
+        };
+
+        Some(Transformation::translate(translation.x, translation.y))
+    }
+}
+
+impl<Message, Theme, Renderer> core::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let content = self.layout.bounds() + self.translation;
+
+        layout::Node::with_children(
+            bounds,
+            vec![layout::Node::new(content.size()).move_to(content.position())],
+        )
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Target::Layer(_) = self.target else {
+            return;
+        };
+
+        let inverse = Transformation::translate(-self.translation.x, -self.translation.y);
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            self.layout,
+            cursor * inverse,
+            renderer,
+            clipboard,
+            shell,
+            &(layout.bounds() * inverse),
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let viewport = layout.bounds();
+
+        let Some(transformation) = self.transformation(cursor) else {
+            return;
+        };
+
+        let inverse = transformation.inverse();
+
+        let cursor = match self.target {
+            Target::Layer(_) => cursor * inverse,
+            Target::Cursor(_) => mouse::Cursor::Unavailable,
+        };
+
+        renderer.with_layer(viewport, |renderer| {
+            renderer.with_transformation(transformation, |renderer| {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    self.layout,
+                    cursor,
+                    &(viewport * inverse),
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Target::Layer(_) = self.target else {
+            return mouse::Interaction::None;
+        };
+
+        let viewport = layout.bounds();
+
+        if !layout
+            .children()
+            .next()
+            .is_some_and(|content| cursor.is_over(content.bounds()))
+        {
+            return mouse::Interaction::None;
+        }
+
+        let inverse = Transformation::translate(-self.translation.x, -self.translation.y);
+
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            self.layout,
+            cursor * inverse,
+            &(viewport * inverse),
+            renderer,
+        )
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let Target::Layer(_) = self.target else {
+            return None;
+        };
+
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            self.layout,
+            renderer,
+            &layout.bounds(),
+            self.translation,
+        )
+    }
+
+    fn index(&self) -> f32 {
+        match self.target {
+            Target::Layer(layer) | Target::Cursor(layer) => layer.index(),
+        }
+    }
+}
@@ -19,15 +19,30 @@
 //!     progress_bar(0.0..=100.0, state.progress).into()
 //! }
 //! ```
+//!
+//! A [`ProgressBar`] can also display a buffered value, animated stripes,
+//! or an indeterminate animation when the progress is unknown. It can be
+//! turned into a [`Circular`] progress indicator sharing the same styling.
+#[cfg(feature = "canvas")]
+mod circular;
+
+#[cfg(feature = "canvas")]
+pub use circular::Circular;
+
 use crate::core::border::{self, Border};
+use crate::core::gradient;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme, Widget,
+    self, Background, Clipboard, Color, Element, Event, Layout, Length, Radians, Rectangle, Shell,
+    Size, Theme, Widget,
 };
 
+use std::f32::consts::FRAC_PI_2;
 use std::ops::RangeInclusive;
 
 /// A bar that displays progress.
@@ -57,9 +72,13 @@ where
 {
     range: RangeInclusive<f32>,
     value: f32,
+    buffer: Option<f32>,
     length: Length,
     girth: Length,
     is_vertical: bool,
+    is_indeterminate: bool,
+    is_striped: bool,
+    cycle_duration: Duration,
     class: Theme::Class<'a>,
 }
 
@@ -70,6 +89,9 @@ where
     /// The default girth of a [`ProgressBar`].
     pub const DEFAULT_GIRTH: f32 = 30.0;
 
+    /// The default duration of an animation cycle of a [`ProgressBar`].
+    pub const DEFAULT_CYCLE_DURATION: Duration = Duration::from_millis(1500);
+
     /// Creates a new [`ProgressBar`].
     ///
     /// It expects:
@@ -79,13 +101,48 @@ where
         ProgressBar {
             value: value.clamp(*range.start(), *range.end()),
             range,
+            buffer: None,
             length: Length::Fill,
             girth: Length::from(Self::DEFAULT_GIRTH),
             is_vertical: false,
+            is_indeterminate: false,
+            is_striped: false,
+            cycle_duration: Self::DEFAULT_CYCLE_DURATION,
             class: Theme::default(),
         }
     }
 
+    /// Sets the buffered value of the [`ProgressBar`]; like the amount of
+    /// data loaded ahead of the current playback position.
+    ///
+    /// The buffered segment is drawn behind the bar.
+    pub fn buffer(mut self, buffer: f32) -> Self {
+        self.buffer = Some(buffer.clamp(*self.range.start(), *self.range.end()));
+        self
+    }
+
+    /// Turns the [`ProgressBar`] into an indeterminate [`ProgressBar`].
+    ///
+    /// An indeterminate [`ProgressBar`] ignores its value and continuously
+    /// animates a segment across its track.
+    pub fn indeterminate(mut self) -> Self {
+        self.is_indeterminate = true;
+        self
+    }
+
+    /// Sets whether the bar of the [`ProgressBar`] is filled with animated
+    /// stripes.
+    pub fn striped(mut self, is_striped: bool) -> Self {
+        self.is_striped = is_striped;
+        self
+    }
+
+    /// Sets the duration of an animation cycle of the [`ProgressBar`].
+    pub fn cycle_duration(mut self, duration: Duration) -> Self {
+        self.cycle_duration = duration;
+        self
+    }
+
     /// Sets the width of the [`ProgressBar`].
     pub fn length(mut self, length: impl Into<Length>) -> Self {
         self.length = length.into();
@@ -124,6 +181,17 @@ where
         self
     }
 
+    /// Turns the [`ProgressBar`] into a [`Circular`] progress indicator with
+    /// the same range, values, animation, and style.
+    #[cfg(feature = "canvas")]
+    pub fn circular(self) -> Circular<'a, Theme> {
+        Circular::from_bar(self)
+    }
+
+    fn is_animated(&self) -> bool {
+        self.is_indeterminate || self.is_striped
+    }
+
     fn width(&self) -> Length {
         if self.is_vertical {
             self.girth
@@ -146,6 +214,14 @@ where
     Theme: Catalog,
     Renderer: core::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width(),
@@ -162,9 +238,29 @@ where
         layout::atomic(limits, self.width(), self.height())
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.is_animated()
+            && let Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            tree.state.downcast_mut::<State>().now = *now;
+
+            shell.request_redraw();
+        }
+    }
+
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -173,7 +269,8 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let (range_start, range_end) = self.range.clone().into_inner();
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
 
         let length = if self.is_vertical {
             bounds.height
@@ -181,48 +278,84 @@ where
             bounds.width
         };
 
-        let active_progress_length = if range_start >= range_end {
-            0.0
-        } else {
-            length * (self.value - range_start) / (range_end - range_start)
+        // Returns the bounds of the segment of the track between the given
+        // distances from its start; clipped to the track.
+        let segment = |start: f32, end: f32| {
+            let start = start.clamp(0.0, length);
+            let end = end.clamp(0.0, length);
+
+            (end > start).then(|| {
+                if self.is_vertical {
+                    Rectangle {
+                        y: bounds.y + bounds.height - end,
+                        height: end - start,
+                        ..bounds
+                    }
+                } else {
+                    Rectangle {
+                        x: bounds.x + start,
+                        width: end - start,
+                        ..bounds
+                    }
+                }
+            })
         };
 
-        let style = theme.style(&self.class);
+        let bar_border = Border {
+            color: Color::TRANSPARENT,
+            ..style.border
+        };
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle { ..bounds },
+                bounds,
                 border: style.border,
                 ..renderer::Quad::default()
             },
             style.background,
         );
 
-        if active_progress_length > 0.0 {
-            let bounds = if self.is_vertical {
-                Rectangle {
-                    y: bounds.y + bounds.height - active_progress_length,
-                    height: active_progress_length,
-                    ..bounds
-                }
-            } else {
-                Rectangle {
-                    width: active_progress_length,
-                    ..bounds
-                }
-            };
+        let progress = state.progress(self.cycle_duration);
 
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: Border {
-                        color: Color::TRANSPARENT,
-                        ..style.border
+        let bar = if self.is_indeterminate {
+            let (start, end) = indeterminate_segment(progress);
+
+            segment(start * length, end * length)
+        } else {
+            let distance = |value| distance(&self.range, value) * length;
+
+            if let Some(buffer) = self
+                .buffer
+                .and_then(|buffer| segment(0.0, distance(buffer)))
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: buffer,
+                        border: bar_border,
+                        ..renderer::Quad::default()
                     },
-                    ..renderer::Quad::default()
-                },
-                style.bar,
-            );
+                    style.buffer,
+                );
+            }
+
+            segment(0.0, distance(self.value))
+        };
+
+        let Some(bar) = bar else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar,
+                border: bar_border,
+                ..renderer::Quad::default()
+            },
+            style.bar,
+        );
+
+        if self.is_striped && style.stripe.a > 0.0 {
+            draw_stripes(renderer, bar, self.is_vertical, progress, style.stripe);
         }
     }
 }
@@ -246,10 +379,27 @@ pub struct Style {
     pub background: Background,
     /// The [`Background`] of the bar of the progress bar.
     pub bar: Background,
+    /// The [`Background`] of the buffered segment of the progress bar.
+    pub buffer: Background,
+    /// The [`Color`] of the stripes drawn over the bar of a striped progress
+    /// bar.
+    pub stripe: Color,
     /// The [`Border`] of the progress bar.
     pub border: Border,
 }
 
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            bar: Color::BLACK.into(),
+            buffer: Color::BLACK.scale_alpha(0.5).into(),
+            stripe: Color::WHITE.scale_alpha(0.2),
+            border: Border::default(),
+        }
+    }
+}
+
 /// The theme catalog of a [`ProgressBar`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
@@ -317,10 +467,152 @@ pub fn danger(theme: &Theme) -> Style {
     styled(palette.background.strong.color, palette.danger.base.color)
 }
 
-fn styled(background: impl Into<Background>, bar: impl Into<Background>) -> Style {
+fn styled(background: Color, bar: Color) -> Style {
     Style {
         background: background.into(),
         bar: bar.into(),
+        buffer: Color { a: 0.5, ..bar }.into(),
+        stripe: Color::WHITE.scale_alpha(0.2),
         border: border::rounded(2),
     }
 }
+
+/// The animation state of a progress indicator.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    started_at: Instant,
+    now: Instant,
+}
+
+impl State {
+    fn new() -> Self {
+        let now = Instant::now();
+
+        Self {
+            started_at: now,
+            now,
+        }
+    }
+
+    /// Returns the progress of the current animation cycle, in `[0, 1)`.
+    fn progress(&self, cycle_duration: Duration) -> f32 {
+        if cycle_duration.is_zero() {
+            return 0.0;
+        }
+
+        let elapsed = self.now.saturating_duration_since(self.started_at);
+
+        (elapsed.as_secs_f32() / cycle_duration.as_secs_f32()).fract()
+    }
+}
+
+/// Returns the relative distance of the given value within the range.
+fn distance(range: &RangeInclusive<f32>, value: f32) -> f32 {
+    let (start, end) = range.clone().into_inner();
+
+    if start >= end {
+        0.0
+    } else {
+        (value - start) / (end - start)
+    }
+}
+
+/// Returns the relative start and end of the segment of an indeterminate
+/// progress indicator at the given progress of its animation cycle.
+///
+/// The segment enters the track growing and leaves it shrinking.
+fn indeterminate_segment(progress: f32) -> (f32, f32) {
+    let ease = |t: f32| t * t * (3.0 - 2.0 * t);
+
+    let end = ease((progress * 1.5).min(1.0));
+    let start = ease((progress * 1.5 - 0.5).max(0.0));
+
+    (start, end)
+}
+
+/// Draws animated stripes over the given bar; each stripe being a tile with a
+/// hard-stopped linear gradient.
+fn draw_stripes<Renderer>(
+    renderer: &mut Renderer,
+    bar: Rectangle,
+    is_vertical: bool,
+    progress: f32,
+    color: Color,
+) where
+    Renderer: core::Renderer,
+{
+    let (girth, length) = if is_vertical {
+        (bar.width, bar.height)
+    } else {
+        (bar.height, bar.width)
+    };
+
+    if girth <= 0.0 {
+        return;
+    }
+
+    let gradient = gradient::Linear::new(Radians(if is_vertical { 0.0 } else { FRAC_PI_2 }))
+        .add_stop(0.0, color)
+        .add_stop(0.5, color)
+        .add_stop(0.5001, Color::TRANSPARENT)
+        .add_stop(1.0, Color::TRANSPARENT);
+
+    let offset = progress * girth;
+    let tiles = (length / girth).ceil() as usize + 1;
+
+    renderer.with_layer(bar, |renderer| {
+        for tile in 0..tiles {
+            let distance = tile as f32 * girth - girth + offset;
+
+            let bounds = if is_vertical {
+                Rectangle {
+                    y: bar.y + bar.height - distance - girth,
+                    height: girth,
+                    ..bar
+                }
+            } else {
+                Rectangle {
+                    x: bar.x + distance,
+                    width: girth,
+                    ..bar
+                }
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                gradient,
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_grows_and_shrinks_the_indeterminate_segment() {
+        assert_eq!(indeterminate_segment(0.0), (0.0, 0.0));
+
+        let (start, end) = indeterminate_segment(1.0 / 3.0);
+        assert_eq!(start, 0.0);
+        assert_eq!(end, 0.5);
+
+        let (start, end) = indeterminate_segment(2.0 / 3.0);
+        assert!(start > 0.0);
+        assert_eq!(end, 1.0);
+
+        let (start, end) = indeterminate_segment(0.999_999);
+        assert!(start > 0.99);
+        assert_eq!(end, 1.0);
+    }
+
+    #[test]
+    fn it_measures_distances_within_a_range() {
+        assert_eq!(distance(&(0.0..=100.0), 25.0), 0.25);
+        assert_eq!(distance(&(10.0..=10.0), 10.0), 0.0);
+    }
+}
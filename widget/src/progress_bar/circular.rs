@@ -0,0 +1,285 @@
+//! Display progress around a circle.
+use crate::canvas;
+use crate::core::gradient;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Duration;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Element, Event, Layout, Length, Pixels, Radians, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::graphics::geometry;
+
+use super::{Catalog, ProgressBar, State, Style, StyleFn, distance, indeterminate_segment};
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::ops::RangeInclusive;
+
+/// A circular progress indicator.
+///
+/// It is styled with the same [`Catalog`] as a [`ProgressBar`]; the
+/// background of the [`Style`] is used for the track.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::{progress_bar, row};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(progress: f32) -> Element<'static, Message> {
+///     row![
+///         progress_bar(0.0..=100.0, progress).circular(),
+///         progress_bar(0.0..=1.0, 0.0).indeterminate().circular().size(24),
+///     ]
+///     .into()
+/// }
+/// ```
+pub struct Circular<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    buffer: Option<f32>,
+    size: f32,
+    thickness: f32,
+    is_indeterminate: bool,
+    cycle_duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Circular<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`Circular`] progress indicator.
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default thickness of the track of a [`Circular`] progress
+    /// indicator.
+    pub const DEFAULT_THICKNESS: f32 = 4.0;
+
+    /// Creates a new [`Circular`] progress indicator.
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Circular`] progress indicator
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self::from_bar(ProgressBar::new(range, value))
+    }
+
+    pub(super) fn from_bar(bar: ProgressBar<'a, Theme>) -> Self {
+        Self {
+            range: bar.range,
+            value: bar.value,
+            buffer: bar.buffer,
+            size: Self::DEFAULT_SIZE,
+            thickness: Self::DEFAULT_THICKNESS,
+            is_indeterminate: bar.is_indeterminate,
+            cycle_duration: bar.cycle_duration,
+            class: bar.class,
+        }
+    }
+
+    /// Sets the diameter of the [`Circular`] progress indicator.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the thickness of the track of the [`Circular`] progress
+    /// indicator.
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Sets the buffered value of the [`Circular`] progress indicator.
+    pub fn buffer(mut self, buffer: f32) -> Self {
+        self.buffer = Some(buffer.clamp(*self.range.start(), *self.range.end()));
+        self
+    }
+
+    /// Turns the [`Circular`] progress indicator into an indeterminate one;
+    /// continuously spinning a segment around its track.
+    pub fn indeterminate(mut self) -> Self {
+        self.is_indeterminate = true;
+        self
+    }
+
+    /// Sets the duration of an animation cycle of the [`Circular`] progress
+    /// indicator.
+    pub fn cycle_duration(mut self, duration: Duration) -> Self {
+        self.cycle_duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Circular`] progress indicator.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Circular`] progress indicator.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Circular<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.is_indeterminate
+            && let Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            tree.state.downcast_mut::<State>().now = *now;
+
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+
+        let mut frame = geometry::Frame::new(renderer, bounds.size());
+
+        let center = frame.center();
+        let radius = (frame.width().min(frame.height()) - self.thickness).max(0.0) / 2.0;
+        let track = Rectangle::with_size(frame.size());
+
+        let mut stroke_arc = |start: f32, end: f32, background: Background| {
+            if end <= start {
+                return;
+            }
+
+            let path = canvas::Path::new(|builder| {
+                builder.arc(canvas::path::Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start * TAU - FRAC_PI_2),
+                    end_angle: Radians(end * TAU - FRAC_PI_2),
+                });
+            });
+
+            frame.stroke(
+                &path,
+                canvas::Stroke {
+                    style: stroke_style(background, track),
+                    width: self.thickness,
+                    line_cap: canvas::LineCap::Round,
+                    ..canvas::Stroke::default()
+                },
+            );
+        };
+
+        stroke_arc(0.0, 1.0, style.background);
+
+        if self.is_indeterminate {
+            let progress = state.progress(self.cycle_duration);
+            let (start, end) = indeterminate_segment(progress);
+
+            stroke_arc(progress + start, progress + end, style.bar);
+        } else {
+            if let Some(buffer) = self.buffer {
+                stroke_arc(0.0, distance(&self.range, buffer), style.buffer);
+            }
+
+            stroke_arc(0.0, distance(&self.range, self.value), style.bar);
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(geometry);
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Circular<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + geometry::Renderer,
+{
+    fn from(circular: Circular<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(circular)
+    }
+}
+
+/// Converts a [`Background`] into a stroke style; placing gradients within
+/// the given bounds.
+fn stroke_style(background: Background, bounds: Rectangle) -> canvas::Style {
+    match background {
+        Background::Color(color) => canvas::Style::Solid(color),
+        Background::Gradient(gradient::Gradient::Linear(linear)) => {
+            let (start, end) = linear.angle.to_distance(&bounds);
+
+            canvas::Style::Gradient(
+                canvas::gradient::Linear::new(start, end)
+                    .add_stops(linear.stops.into_iter().flatten())
+                    .into(),
+            )
+        }
+    }
+}
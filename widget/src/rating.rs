@@ -0,0 +1,462 @@
+//! Ratings let users score something with a row of stars or hearts.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::rating;
+//!
+//! struct State {
+//!    score: f32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Rated(f32),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     rating(state.score).half_steps(true).on_rate(Message::Rated).into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Rated(score) => {
+//!             state.score = score;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Theme,
+    Widget,
+};
+
+/// A row of symbols that displays and selects a score.
+///
+/// While hovered, a [`Rating`] previews the score that would be selected.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::rating::{self, Symbol};
+///
+/// fn view<'a, Message: 'a>(score: f32) -> Element<'a, Message> {
+///     // A read-only rating out of 10 hearts
+///     rating::Rating::new(score).max(10).symbol(Symbol::Heart).into()
+/// }
+/// ```
+pub struct Rating<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    value: f32,
+    max: usize,
+    symbol: Symbol,
+    is_half_steps: bool,
+    on_rate: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    size: f32,
+    spacing: f32,
+    class: Theme::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of the symbols of a [`Rating`].
+    pub const DEFAULT_SIZE: f32 = 20.0;
+
+    /// The default amount of symbols of a [`Rating`].
+    pub const DEFAULT_MAX: usize = 5;
+
+    /// Creates a new [`Rating`] with the given score.
+    pub fn new(value: f32) -> Self {
+        Rating {
+            value,
+            max: Self::DEFAULT_MAX,
+            symbol: Symbol::Star,
+            is_half_steps: false,
+            on_rate: None,
+            size: Self::DEFAULT_SIZE,
+            spacing: 4.0,
+            class: Theme::default(),
+            last_status: None,
+        }
+    }
+
+    /// Sets the maximum score of the [`Rating`]; that is, the amount of
+    /// symbols displayed.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the [`Symbol`] of the [`Rating`].
+    pub fn symbol(mut self, symbol: Symbol) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets whether the [`Rating`] can select halves of a symbol.
+    pub fn half_steps(mut self, is_half_steps: bool) -> Self {
+        self.is_half_steps = is_half_steps;
+        self
+    }
+
+    /// Sets the message that should be produced when a user selects a
+    /// score.
+    ///
+    /// If this method is not called, the [`Rating`] will be read-only.
+    pub fn on_rate(mut self, on_rate: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_rate = Some(Box::new(on_rate));
+        self
+    }
+
+    /// Sets the message that should be produced when a user selects a
+    /// score, if `Some`.
+    ///
+    /// If `None`, the [`Rating`] will be read-only.
+    pub fn on_rate_maybe(mut self, on_rate: Option<impl Fn(f32) -> Message + 'a>) -> Self {
+        self.on_rate = on_rate.map(|on_rate| Box::new(on_rate) as _);
+        self
+    }
+
+    /// Sets the size of the symbols of the [`Rating`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the symbols of the [`Rating`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Rating`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Rating`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the score under the given cursor position.
+    fn locate(&self, bounds: Rectangle, cursor_position: Point) -> f32 {
+        let slot = self.size + self.spacing;
+        let x = (cursor_position.x - bounds.x).max(0.0);
+
+        let index = (x / slot).floor();
+        let within = ((x - index * slot) / self.size).min(1.0);
+
+        let value = if self.is_half_steps && within <= 0.5 {
+            index + 0.5
+        } else {
+            index + 1.0
+        };
+
+        value.min(self.max as f32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct State {
+    preview: Option<f32>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Rating<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = self.max as f32 * self.size + self.max.saturating_sub(1) as f32 * self.spacing;
+
+        layout::atomic(limits, width, self.size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let Some(on_rate) = &self.on_rate else {
+            state.preview = None;
+            return;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    shell.publish(on_rate(self.locate(bounds, cursor_position)));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::CursorLeft)
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let preview = cursor
+                    .position_over(bounds)
+                    .map(|cursor_position| self.locate(bounds, cursor_position));
+
+                if preview != state.preview {
+                    state.preview = preview;
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+
+        let current_status = if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(current_status);
+        } else if self
+            .last_status
+            .is_some_and(|status| status != current_status)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_rate.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if self.on_rate.is_none() {
+            Status::Disabled
+        } else {
+            self.last_status.unwrap_or(Status::Active)
+        };
+
+        let style = theme.style(&self.class, status);
+
+        let (value, filled) = match state.preview {
+            Some(preview) => (preview, style.preview),
+            None => (self.value, style.filled),
+        };
+
+        let symbol = Text {
+            content: self.symbol.to_char().to_string(),
+            bounds: Size::new(self.size, self.size),
+            size: Pixels(self.size),
+            line_height: text::LineHeight::Absolute(Pixels(self.size)),
+            font: renderer.default_font(),
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        };
+
+        for i in 0..self.max {
+            let slot = Rectangle {
+                x: bounds.x + i as f32 * (self.size + self.spacing),
+                y: bounds.y,
+                width: self.size,
+                height: self.size,
+            };
+
+            let fill = (value - i as f32).clamp(0.0, 1.0);
+
+            if fill < 1.0 {
+                renderer.fill_text(symbol.clone(), slot.center(), style.empty, *viewport);
+            }
+
+            if fill > 0.0 {
+                let clip = Rectangle {
+                    width: slot.width * fill,
+                    ..slot
+                };
+
+                renderer.with_layer(clip, |renderer| {
+                    renderer.fill_text(symbol.clone(), slot.center(), filled, clip);
+                });
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Rating<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(rating: Rating<'a, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(rating)
+    }
+}
+
+/// The symbol displayed by a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Symbol {
+    /// A star (★).
+    #[default]
+    Star,
+    /// A heart (♥).
+    Heart,
+    /// A custom character.
+    Custom(char),
+}
+
+impl Symbol {
+    fn to_char(self) -> char {
+        match self {
+            Symbol::Star => '★',
+            Symbol::Heart => '♥',
+            Symbol::Custom(character) => character,
+        }
+    }
+}
+
+/// The possible status of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Rating`] can be interacted with.
+    Active,
+    /// The [`Rating`] is being hovered.
+    Hovered,
+    /// The [`Rating`] is read-only.
+    Disabled,
+}
+
+/// The appearance of a rating.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the filled part of the symbols.
+    pub filled: Color,
+    /// The [`Color`] of the filled part of the symbols while previewing a
+    /// score.
+    pub preview: Color,
+    /// The [`Color`] of the empty part of the symbols.
+    pub empty: Color,
+}
+
+/// The theme catalog of a [`Rating`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Rating`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Rating`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let filled = match status {
+        Status::Active | Status::Hovered => palette.warning.base.color,
+        Status::Disabled => palette.warning.strong.color,
+    };
+
+    Style {
+        filled,
+        preview: palette.warning.weak.color,
+        empty: palette.background.strong.color,
+    }
+}
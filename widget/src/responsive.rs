@@ -1,3 +1,4 @@
+//! Adapt contents to the space available.
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
@@ -39,6 +40,20 @@ where
         }
     }
 
+    /// Creates a new [`Responsive`] widget with a closure that produces its
+    /// contents for the active [`Breakpoint`].
+    ///
+    /// The [`Breakpoint`] is computed from the maximum available width for
+    /// the [`Responsive`] during layout; so any panel can adapt to its own
+    /// width. When used at the root of a window, it will match the width
+    /// of the window.
+    pub fn with_breakpoints(
+        breakpoints: Breakpoints,
+        view: impl Fn(Breakpoint) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self::new(move |size| view(breakpoints.classify(size.width)))
+    }
+
     /// Sets the width of the [`Responsive`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -195,3 +210,61 @@ where
         Self::new(responsive)
     }
 }
+
+/// A named size class of a [`Responsive`] widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// The available width is below the [`Breakpoints::medium`] threshold.
+    ///
+    /// Typical of phones in portrait and narrow side panels.
+    Compact,
+    /// The available width is between the [`Breakpoints::medium`] and the
+    /// [`Breakpoints::expanded`] thresholds.
+    Medium,
+    /// The available width is at least the [`Breakpoints::expanded`] threshold.
+    Expanded,
+}
+
+/// The width thresholds used to classify the available space of a
+/// [`Responsive`] widget into a [`Breakpoint`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    /// The minimum width of the [`Breakpoint::Medium`] class.
+    pub medium: f32,
+    /// The minimum width of the [`Breakpoint::Expanded`] class.
+    pub expanded: f32,
+}
+
+impl Breakpoints {
+    /// The default [`Breakpoints`]: 600 logical pixels for
+    /// [`Breakpoint::Medium`] and 840 for [`Breakpoint::Expanded`].
+    pub const DEFAULT: Self = Self {
+        medium: 600.0,
+        expanded: 840.0,
+    };
+
+    /// Creates new [`Breakpoints`] with the given thresholds.
+    pub const fn new(medium: f32, expanded: f32) -> Self {
+        Self { medium, expanded }
+    }
+
+    /// Returns the [`Breakpoint`] of the given width.
+    ///
+    /// This can be used to classify the size of a window directly; for
+    /// instance, when listening to resize events.
+    pub fn classify(&self, width: f32) -> Breakpoint {
+        if width >= self.expanded {
+            Breakpoint::Expanded
+        } else if width >= self.medium {
+            Breakpoint::Medium
+        } else {
+            Breakpoint::Compact
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
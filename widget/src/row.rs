@@ -8,6 +8,7 @@ use crate::core::widget::{Operation, Tree};
 use crate::core::{
     Clipboard, Element, Event, Length, Padding, Pixels, Rectangle, Shell, Size, Vector, Widget,
 };
+use crate::z_index;
 
 /// A container that distributes its contents horizontally.
 ///
@@ -297,17 +298,16 @@ where
                 viewport
             };
 
-            for ((child, tree), layout) in self
-                .children
-                .iter()
-                .zip(&tree.children)
-                .zip(layout.children())
-                .filter(|(_, layout)| layout.bounds().intersects(viewport))
-            {
-                child
-                    .as_widget()
-                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
-            }
+            z_index::draw_children(
+                &self.children,
+                tree,
+                layout,
+                renderer,
+                theme,
+                style,
+                cursor,
+                viewport,
+            );
         }
     }
 
@@ -354,18 +354,68 @@ pub struct Wrapping<'a, Message, Theme = crate::Theme, Renderer = crate::Rendere
     align_x: alignment::Horizontal,
 }
 
-impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
+impl<'a, Message, Theme, Renderer> Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Sets the horizontal spacing _between_ elements of a line.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row = self.row.spacing(amount);
+        self
+    }
+
     /// Sets the vertical spacing _between_ lines.
+    ///
+    /// By default, it is the same as the horizontal [`spacing`](Self::spacing).
     pub fn vertical_spacing(mut self, amount: impl Into<Pixels>) -> Self {
         self.vertical_spacing = Some(amount.into().0);
         self
     }
 
-    /// Sets the horizontal alignment of the wrapping [`Row`].
+    /// Sets the [`Padding`] of the wrapping [`Row`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.row = self.row.padding(padding);
+        self
+    }
+
+    /// Sets the width of the wrapping [`Row`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.row = self.row.width(width);
+        self
+    }
+
+    /// Sets the height of the wrapping [`Row`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.row = self.row.height(height);
+        self
+    }
+
+    /// Sets the horizontal alignment of every line of the wrapping [`Row`].
     pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
         self.align_x = align_x.into();
         self
     }
+
+    /// Sets the vertical alignment of the elements within each line of the
+    /// wrapping [`Row`].
+    pub fn align_y(mut self, align_y: impl Into<alignment::Vertical>) -> Self {
+        self.row = self.row.align_y(align_y);
+        self
+    }
+
+    /// Adds an [`Element`] to the wrapping [`Row`].
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.row = self.row.push(child);
+        self
+    }
+
+    /// Extends the wrapping [`Row`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -402,6 +452,7 @@ where
         let max_width = limits.max().width;
 
         let mut children: Vec<layout::Node> = Vec::new();
+        let mut lines: Vec<(std::ops::Range<usize>, f32)> = Vec::new();
         let mut intrinsic_size = Size::ZERO;
         let mut row_start = 0;
         let mut row_height = 0.0;
@@ -435,6 +486,7 @@ where
 
             if x != 0.0 && x + child_size.width > max_width {
                 intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+                lines.push((row_start..i, x - spacing));
 
                 align_y(row_start..i, row_height, &mut children);
 
@@ -453,11 +505,14 @@ where
 
         if x != 0.0 {
             intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+            lines.push((row_start..children.len(), x - spacing));
         }
 
         intrinsic_size.height = y + row_height;
         align_y(row_start..children.len(), row_height, &mut children);
 
+        let size = limits.resolve(self.row.width, self.row.height, intrinsic_size);
+
         let align_factor = match self.align_x {
             alignment::Horizontal::Left => 0.0,
             alignment::Horizontal::Center => 2.0,
@@ -465,34 +520,19 @@ where
         };
 
         if align_factor != 0.0 {
-            let total_width = intrinsic_size.width;
-
-            let mut row_start = 0;
-
-            for i in 0..children.len() {
-                let bounds = children[i].bounds();
-                let row_width = bounds.x + bounds.width;
+            for (line, line_width) in lines {
+                let translation = Vector::new((size.width - line_width) / align_factor, 0.0);
 
-                let next_x = children
-                    .get(i + 1)
-                    .map(|node| node.bounds().x)
-                    .unwrap_or_default();
-
-                if next_x == 0.0 {
-                    let translation = Vector::new((total_width - row_width) / align_factor, 0.0);
-
-                    for node in &mut children[row_start..=i] {
-                        node.translate_mut(translation);
-                    }
-
-                    row_start = i + 1;
+                for node in &mut children[line] {
+                    node.translate_mut(translation);
                 }
             }
         }
 
-        let size = limits.resolve(self.row.width, self.row.height, intrinsic_size);
-
-        layout::Node::with_children(size.expand(self.row.padding), children)
+        layout::Direction::current().orient(layout::Node::with_children(
+            size.expand(self.row.padding),
+            children,
+        ))
     }
 
     fn operate(
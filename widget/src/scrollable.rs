@@ -39,7 +39,16 @@ use crate::core::{
     Pixels, Point, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
 };
 
-pub use operation::scrollable::{AbsoluteOffset, RelativeOffset};
+pub use operation::scrollable::{AbsoluteOffset, Motion, RelativeOffset};
+
+/// The duration of the fade of auto-hiding scrollbars.
+const FADE_DURATION: Duration = Duration::from_millis(150);
+
+/// The distance a touch must travel before its gesture is locked to an axis.
+const GESTURE_THRESHOLD: f32 = 8.0;
+
+/// The duration of the smooth scroll of a notch of the mouse wheel.
+const WHEEL_DURATION: Duration = Duration::from_millis(120);
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -73,6 +82,8 @@ where
     height: Length,
     direction: Direction,
     auto_scroll: bool,
+    gestures: Gestures,
+    wheel: Wheel,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     class: Theme::Class<'a>,
@@ -100,6 +111,8 @@ where
             height: Length::Shrink,
             direction: direction.into(),
             auto_scroll: false,
+            gestures: Gestures::default(),
+            wheel: Wheel::default(),
             content: content.into(),
             on_scroll: None,
             class: Theme::default(),
@@ -228,6 +241,22 @@ where
         self
     }
 
+    /// Sets how the [`Scrollable`] arbitrates scroll gestures with the
+    /// scrollables containing it.
+    ///
+    /// By default, it claims the gestures along its scrolling axes; see
+    /// [`Gestures::Dominant`].
+    pub fn gestures(mut self, gestures: Gestures) -> Self {
+        self.gestures = gestures;
+        self
+    }
+
+    /// Sets the [`Wheel`] settings of the [`Scrollable`].
+    pub fn wheel(mut self, wheel: Wheel) -> Self {
+        self.wheel = wheel;
+        self
+    }
+
     /// Sets the style of this [`Scrollable`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -282,6 +311,13 @@ impl Direction {
         }
     }
 
+    /// Returns the longest auto-hide timeout of the scrollbars, if any.
+    fn auto_hide(&self) -> Option<Duration> {
+        self.horizontal()
+            .and_then(|scrollbar| scrollbar.auto_hide)
+            .max(self.vertical().and_then(|scrollbar| scrollbar.auto_hide))
+    }
+
     fn align(&self, delta: Vector) -> Vector {
         let horizontal_alignment = self.horizontal().map(|p| p.alignment).unwrap_or_default();
 
@@ -313,6 +349,9 @@ pub struct Scrollbar {
     scroller_width: f32,
     alignment: Anchor,
     spacing: Option<f32>,
+    min_scroller_length: f32,
+    hover_width: Option<f32>,
+    auto_hide: Option<Duration>,
 }
 
 impl Default for Scrollbar {
@@ -323,6 +362,9 @@ impl Default for Scrollbar {
             scroller_width: 10.0,
             alignment: Anchor::Start,
             spacing: None,
+            min_scroller_length: 2.0,
+            hover_width: None,
+            auto_hide: None,
         }
     }
 }
@@ -372,6 +414,176 @@ impl Scrollbar {
         self.spacing = Some(spacing.into().0);
         self
     }
+
+    /// Sets the minimum length of the scroller of the [`Scrollbar`].
+    ///
+    /// The scroller shrinks as the contents grow, but never below this
+    /// length; so it stays easy to grab. Defaults to 2 pixels.
+    pub fn min_scroller_length(mut self, length: impl Into<Pixels>) -> Self {
+        self.min_scroller_length = length.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the width of the [`Scrollbar`] and its scroller while the
+    /// [`Scrollbar`] is hovered or dragged.
+    ///
+    /// Combined with a small [`width`](Self::width), this produces thin
+    /// scrollbars that expand over the contents when the pointer
+    /// approaches them.
+    pub fn hover_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.hover_width = Some(width.into().0.max(0.0));
+        self
+    }
+
+    /// Makes the [`Scrollbar`] hide itself after the given duration
+    /// without activity.
+    ///
+    /// The [`Scrollbar`] fades in when the [`Scrollable`] is scrolled or
+    /// the [`Scrollbar`] is hovered; and it fades out once the timeout
+    /// elapses.
+    pub fn auto_hide(mut self, timeout: Duration) -> Self {
+        self.auto_hide = Some(timeout);
+        self
+    }
+
+    /// Returns the [`Scrollbar`] with its hover width applied, if expanded.
+    fn expand(self, is_expanded: bool) -> Self {
+        match self.hover_width {
+            Some(width) if is_expanded => Self {
+                width,
+                scroller_width: width,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// Returns the opacity of the [`Scrollbar`], given whether it is hovered
+    /// or dragged.
+    fn opacity(&self, state: &State, is_engaged: bool) -> f32 {
+        match self.auto_hide {
+            None => 1.0,
+            Some(_) if is_engaged => 1.0,
+            Some(timeout) => state
+                .visibility
+                .map_or(0.0, |visibility| visibility.opacity(timeout)),
+        }
+    }
+}
+
+/// The mouse wheel settings of a [`Scrollable`].
+///
+/// Notched mouse wheels report their movement in lines; while touchpads and
+/// high-resolution wheels report it in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wheel {
+    speed: f32,
+    line_height: f32,
+    is_smooth: bool,
+    shift_horizontal: bool,
+}
+
+impl Default for Wheel {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            line_height: 60.0,
+            is_smooth: false,
+            shift_horizontal: true,
+        }
+    }
+}
+
+impl Wheel {
+    /// Creates new [`Wheel`] settings for use in a [`Scrollable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the speed multiplier of the [`Wheel`]; applied to both line and
+    /// pixel movements.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the distance scrolled per line of a notched [`Wheel`].
+    ///
+    /// Defaults to 60 pixels.
+    pub fn line_height(mut self, line_height: impl Into<Pixels>) -> Self {
+        self.line_height = line_height.into().0;
+        self
+    }
+
+    /// Sets whether the lines of a notched [`Wheel`] are scrolled smoothly,
+    /// instead of jumping to their destination.
+    ///
+    /// Pixel movements are always applied as they arrive.
+    pub fn smooth(mut self, is_smooth: bool) -> Self {
+        self.is_smooth = is_smooth;
+        self
+    }
+
+    /// Sets whether holding Shift while using a notched [`Wheel`] scrolls
+    /// horizontally.
+    ///
+    /// Enabled by default.
+    pub fn shift_horizontal(mut self, shift_horizontal: bool) -> Self {
+        self.shift_horizontal = shift_horizontal;
+        self
+    }
+}
+
+/// How a [`Scrollable`] arbitrates scroll gestures with the scrollables
+/// containing it.
+///
+/// Scroll gestures reach the innermost [`Scrollable`] first; which either
+/// claims them by scrolling and capturing them, or passes them along to its
+/// ancestors. Once claimed, a gesture stays with the same [`Scrollable`] until
+/// it ends; even if it changes direction.
+///
+/// Custom widgets can take part in the arbitration the same way: capturing a
+/// scroll event claims it, while leaving it uncaptured passes it along.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Gestures {
+    /// Claims the gestures whose dominant axis is one the [`Scrollable`] can
+    /// scroll along; and passes the rest.
+    ///
+    /// For instance, a horizontal carousel inside a vertical [`Scrollable`]
+    /// claims horizontal swipes; while vertical ones scroll the page.
+    #[default]
+    Dominant,
+    /// Claims every gesture over the [`Scrollable`]; even if it cannot
+    /// scroll along their axis.
+    Claim,
+    /// Passes every gesture to the scrollables containing it.
+    ///
+    /// The [`Scrollable`] can still be scrolled with its scrollbars or
+    /// programmatically.
+    Pass,
+}
+
+impl Gestures {
+    /// Returns whether a gesture with the given delta should be claimed.
+    fn claims(
+        self,
+        direction: Direction,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        match self {
+            Gestures::Claim => true,
+            Gestures::Pass => false,
+            Gestures::Dominant => {
+                if delta.x.abs() > delta.y.abs() {
+                    direction.horizontal().is_some() && content_bounds.width > bounds.width
+                } else {
+                    direction.vertical().is_some() && content_bounds.height > bounds.height
+                }
+            }
+        }
+    }
 }
 
 /// The anchor of the scroller of the [`Scrollable`] relative to its [`Viewport`]
@@ -420,44 +632,60 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let mut layout = |right_padding, bottom_padding| {
-            layout::padded(
-                limits,
-                self.width,
-                self.height,
-                Padding {
-                    right: right_padding,
-                    bottom: bottom_padding,
-                    ..Padding::ZERO
-                },
-                |limits| {
-                    let is_horizontal = self.direction.horizontal().is_some();
-                    let is_vertical = self.direction.vertical().is_some();
-
-                    let child_limits = layout::Limits::with_compression(
-                        limits.min(),
-                        Size::new(
-                            if is_horizontal {
-                                f32::INFINITY
-                            } else {
-                                limits.max().width
-                            },
-                            if is_vertical {
-                                f32::INFINITY
-                            } else {
-                                limits.max().height
-                            },
-                        ),
-                        Size::new(is_horizontal, is_vertical),
-                    );
+        let direction = layout::Direction::current();
+        tree.state.downcast_mut::<State>().is_right_to_left = direction.is_right_to_left();
+
+        // The content is never mirrored inside the viewport of the scrollable,
+        // since it may overflow it; the scrollbar moves to the left instead
+        let mut layout = |scrollbar_padding, bottom_padding| {
+            let (left_padding, right_padding) = if direction.is_right_to_left() {
+                (scrollbar_padding, 0.0)
+            } else {
+                (0.0, scrollbar_padding)
+            };
 
-                    self.content.as_widget_mut().layout(
-                        &mut tree.children[0],
-                        renderer,
-                        &child_limits,
-                    )
-                },
-            )
+            layout::Direction::LeftToRight.scope(|| {
+                layout::padded(
+                    limits,
+                    self.width,
+                    self.height,
+                    Padding {
+                        left: left_padding,
+                        right: right_padding,
+                        bottom: bottom_padding,
+                        ..Padding::ZERO
+                    },
+                    |limits| {
+                        let is_horizontal = self.direction.horizontal().is_some();
+                        let is_vertical = self.direction.vertical().is_some();
+
+                        let child_limits = layout::Limits::with_compression(
+                            limits.min(),
+                            Size::new(
+                                if is_horizontal {
+                                    f32::INFINITY
+                                } else {
+                                    limits.max().width
+                                },
+                                if is_vertical {
+                                    f32::INFINITY
+                                } else {
+                                    limits.max().height
+                                },
+                            ),
+                            Size::new(is_horizontal, is_vertical),
+                        );
+
+                        direction.scope(|| {
+                            self.content.as_widget_mut().layout(
+                                &mut tree.children[0],
+                                renderer,
+                                &child_limits,
+                            )
+                        })
+                    },
+                )
+            })
         };
 
         match self.direction {
@@ -574,6 +802,16 @@ where
 
         let last_offsets = (state.offset_x, state.offset_y);
 
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && state.smooth.is_some()
+        {
+            if state.advance(*now, bounds, content_bounds) {
+                shell.request_redraw();
+            }
+
+            let _ = notify_viewport(state, &self.on_scroll, bounds, content_bounds, shell);
+        }
+
         if let Some(last_scrolled) = state.last_scrolled {
             let clear_transaction = match event {
                 Event::Mouse(
@@ -792,7 +1030,7 @@ where
                         return;
                     }
 
-                    let delta = match *delta {
+                    let (delta, is_notched) = match *delta {
                         mouse::ScrollDelta::Lines { x, y } => {
                             let is_shift_pressed = state.keyboard_modifiers.shift();
 
@@ -803,26 +1041,69 @@ where
                                 (x, y)
                             };
 
-                            let movement = if !is_shift_pressed {
-                                Vector::new(x, y)
-                            } else {
+                            let movement = if is_shift_pressed && self.wheel.shift_horizontal {
                                 Vector::new(y, x)
+                            } else {
+                                Vector::new(x, y)
                             };
 
-                            // TODO: Configurable speed/friction (?)
-                            -movement * 60.0
+                            (-movement * self.wheel.line_height, true)
                         }
-                        mouse::ScrollDelta::Pixels { x, y } => -Vector::new(x, y),
+                        mouse::ScrollDelta::Pixels { x, y } => (-Vector::new(x, y), false),
                     };
 
+                    let delta = delta * self.wheel.speed;
+
+                    let in_transaction = state.last_scrolled.is_some();
+
+                    // Gestures locked by a transaction are kept until it ends
+                    if !in_transaction
+                        && !self
+                            .gestures
+                            .claims(self.direction, delta, bounds, content_bounds)
+                    {
+                        return;
+                    }
+
+                    if is_notched && self.wheel.is_smooth {
+                        let from = state.destination(bounds, content_bounds);
+                        let delta = self.direction.align(delta);
+
+                        let to = Vector::new(
+                            (from.x + delta.x)
+                                .clamp(0.0, (content_bounds.width - bounds.width).max(0.0)),
+                            (from.y + delta.y)
+                                .clamp(0.0, (content_bounds.height - bounds.height).max(0.0)),
+                        );
+
+                        let has_scrolled = to != from;
+
+                        if has_scrolled {
+                            state.animate(
+                                Some(Offset::Absolute(to.x)),
+                                Some(Offset::Absolute(to.y)),
+                                Motion::new(WHEEL_DURATION),
+                                bounds,
+                                content_bounds,
+                            );
+
+                            state.last_scrolled = Some(Instant::now());
+                            shell.request_redraw();
+                        }
+
+                        if has_scrolled || in_transaction || self.gestures == Gestures::Claim {
+                            shell.capture_event();
+                        }
+
+                        return;
+                    }
+
                     state.scroll(self.direction.align(delta), bounds, content_bounds);
 
                     let has_scrolled =
                         notify_scroll(state, &self.on_scroll, bounds, content_bounds, shell);
 
-                    let in_transaction = state.last_scrolled.is_some();
-
-                    if has_scrolled || in_transaction {
+                    if has_scrolled || in_transaction || self.gestures == Gestures::Claim {
                         shell.capture_event();
                     }
                 }
@@ -844,7 +1125,7 @@ where
                     shell.request_redraw();
                 }
                 Event::Touch(event)
-                    if matches!(state.interaction, Interaction::TouchScrolling(_))
+                    if matches!(state.interaction, Interaction::TouchScrolling { .. })
                         || (!mouse_over_y_scrollbar && !mouse_over_x_scrollbar) =>
                 {
                     match event {
@@ -853,11 +1134,20 @@ where
                                 return;
                             };
 
-                            state.interaction = Interaction::TouchScrolling(position);
+                            // The touch is not captured until its gesture is claimed;
+                            // so the scrollables containing this one can track it too
+                            state.interaction = Interaction::TouchScrolling {
+                                origin: position,
+                                last: position,
+                                is_locked: false,
+                            };
                         }
                         touch::Event::FingerMoved { .. } => {
-                            let Interaction::TouchScrolling(scroll_box_touched_at) =
-                                state.interaction
+                            let Interaction::TouchScrolling {
+                                origin,
+                                last,
+                                is_locked,
+                            } = state.interaction
                             else {
                                 return;
                             };
@@ -866,16 +1156,34 @@ where
                                 return;
                             };
 
-                            let delta = Vector::new(
-                                scroll_box_touched_at.x - cursor_position.x,
-                                scroll_box_touched_at.y - cursor_position.y,
-                            );
+                            if !is_locked {
+                                let movement = origin - cursor_position;
+
+                                if movement.x.abs().max(movement.y.abs()) < GESTURE_THRESHOLD {
+                                    return;
+                                }
+
+                                if !self.gestures.claims(
+                                    self.direction,
+                                    movement,
+                                    bounds,
+                                    content_bounds,
+                                ) {
+                                    state.interaction = Interaction::None;
+                                    return;
+                                }
+                            }
+
+                            let delta = last - cursor_position;
 
                             state.scroll(self.direction.align(delta), bounds, content_bounds);
 
-                            state.interaction = Interaction::TouchScrolling(cursor_position);
+                            state.interaction = Interaction::TouchScrolling {
+                                origin,
+                                last: cursor_position,
+                                is_locked: true,
+                            };
 
-                            // TODO: bubble up touch movements if not consumed.
                             let _ = notify_scroll(
                                 state,
                                 &self.on_scroll,
@@ -883,11 +1191,11 @@ where
                                 content_bounds,
                                 shell,
                             );
+
+                            shell.capture_event();
                         }
                         _ => {}
                     }
-
-                    shell.capture_event();
                 }
                 Event::Mouse(mouse::Event::CursorMoved { position }) => {
                     if let Interaction::AutoScrolling {
@@ -913,6 +1221,21 @@ where
                 Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                     state.keyboard_modifiers = *modifiers;
                 }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key),
+                    ..
+                }) if cursor_over_scrollable.is_some() => {
+                    let Some((x, y)) =
+                        state.key_targets(*key, self.direction, bounds, content_bounds)
+                    else {
+                        return;
+                    };
+
+                    state.animate(x, y, Motion::default(), bounds, content_bounds);
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
                 Event::Window(window::Event::RedrawRequested(now)) => {
                     if let Interaction::AutoScrolling {
                         origin,
@@ -993,6 +1316,54 @@ where
 
         update();
 
+        // Hovering the expanded area of a scrollbar expands it
+        let (is_y_scrollbar_hovered, is_x_scrollbar_hovered) = Scrollbars::new(
+            &State {
+                is_x_scrollbar_hovered: true,
+                is_y_scrollbar_hovered: true,
+                ..*state
+            },
+            self.direction,
+            bounds,
+            content_bounds,
+        )
+        .is_mouse_over(cursor);
+
+        let is_hover_changed = (is_x_scrollbar_hovered, is_y_scrollbar_hovered)
+            != (state.is_x_scrollbar_hovered, state.is_y_scrollbar_hovered);
+
+        state.is_x_scrollbar_hovered = is_x_scrollbar_hovered;
+        state.is_y_scrollbar_hovered = is_y_scrollbar_hovered;
+
+        if let Some(timeout) = self.direction.auto_hide() {
+            let is_redraw = matches!(event, Event::Window(window::Event::RedrawRequested(_)));
+
+            if !is_redraw
+                && (is_hover_changed
+                    || is_x_scrollbar_hovered
+                    || is_y_scrollbar_hovered
+                    || state.scrollers_grabbed()
+                    || last_offsets != (state.offset_x, state.offset_y))
+            {
+                state.show(Instant::now(), timeout);
+                shell.request_redraw();
+            }
+
+            if let Event::Window(window::Event::RedrawRequested(now)) = event
+                && let Some(visibility) = &mut state.visibility
+            {
+                visibility.now = *now;
+
+                if let Some(next_frame) = visibility.next_frame(timeout) {
+                    shell.request_redraw_at(next_frame);
+                }
+            }
+        }
+
+        if is_hover_changed {
+            shell.request_redraw();
+        }
+
         let status = if state.scrollers_grabbed() {
             Status::Dragged {
                 is_horizontal_scrollbar_dragged: state.x_scroller_grabbed_at().is_some(),
@@ -1097,43 +1468,50 @@ where
                 );
             });
 
-            let draw_scrollbar =
-                |renderer: &mut Renderer, style: Rail, scrollbar: &internals::Scrollbar| {
-                    if scrollbar.bounds.width > 0.0
-                        && scrollbar.bounds.height > 0.0
-                        && (style.background.is_some()
-                            || (style.border.color != Color::TRANSPARENT
-                                && style.border.width > 0.0))
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: scrollbar.bounds,
-                                border: style.border,
-                                ..renderer::Quad::default()
-                            },
-                            style
-                                .background
-                                .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        );
-                    }
+            let draw_scrollbar = |renderer: &mut Renderer,
+                                  style: Rail,
+                                  scrollbar: &internals::Scrollbar,
+                                  opacity: f32| {
+                if opacity <= 0.0 {
+                    return;
+                }
 
-                    if let Some(scroller) = scrollbar.scroller
-                        && scroller.bounds.width > 0.0
-                        && scroller.bounds.height > 0.0
-                        && (style.scroller.background != Background::Color(Color::TRANSPARENT)
-                            || (style.scroller.border.color != Color::TRANSPARENT
-                                && style.scroller.border.width > 0.0))
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: scroller.bounds,
-                                border: style.scroller.border,
-                                ..renderer::Quad::default()
-                            },
-                            style.scroller.background,
-                        );
-                    }
-                };
+                let style = style.scale_alpha(opacity);
+
+                if scrollbar.bounds.width > 0.0
+                    && scrollbar.bounds.height > 0.0
+                    && (style.background.is_some()
+                        || (style.border.color != Color::TRANSPARENT && style.border.width > 0.0))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: scrollbar.bounds,
+                            border: style.border,
+                            ..renderer::Quad::default()
+                        },
+                        style
+                            .background
+                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                    );
+                }
+
+                if let Some(scroller) = scrollbar.scroller
+                    && scroller.bounds.width > 0.0
+                    && scroller.bounds.height > 0.0
+                    && (style.scroller.background != Background::Color(Color::TRANSPARENT)
+                        || (style.scroller.border.color != Color::TRANSPARENT
+                            && style.scroller.border.width > 0.0))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: scroller.bounds,
+                            border: style.scroller.border,
+                            ..renderer::Quad::default()
+                        },
+                        style.scroller.background,
+                    );
+                }
+            };
 
             renderer.with_layer(
                 Rectangle {
@@ -1142,12 +1520,26 @@ where
                     ..visible_bounds
                 },
                 |renderer| {
-                    if let Some(scrollbar) = scrollbars.y {
-                        draw_scrollbar(renderer, style.vertical_rail, &scrollbar);
+                    if let Some(scrollbar) = scrollbars.y
+                        && let Some(vertical) = self.direction.vertical()
+                    {
+                        let opacity = vertical.opacity(
+                            state,
+                            mouse_over_y_scrollbar || state.y_scroller_grabbed_at().is_some(),
+                        );
+
+                        draw_scrollbar(renderer, style.vertical_rail, &scrollbar, opacity);
                     }
 
-                    if let Some(scrollbar) = scrollbars.x {
-                        draw_scrollbar(renderer, style.horizontal_rail, &scrollbar);
+                    if let Some(scrollbar) = scrollbars.x
+                        && let Some(horizontal) = self.direction.horizontal()
+                    {
+                        let opacity = horizontal.opacity(
+                            state,
+                            mouse_over_x_scrollbar || state.x_scroller_grabbed_at().is_some(),
+                        );
+
+                        draw_scrollbar(renderer, style.horizontal_rail, &scrollbar, opacity);
                     }
 
                     if let (Some(x), Some(y)) = (scrollbars.x, scrollbars.y) {
@@ -1508,6 +1900,52 @@ struct State {
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
     is_scrollbar_visible: bool,
+    is_right_to_left: bool,
+    is_x_scrollbar_hovered: bool,
+    is_y_scrollbar_hovered: bool,
+    visibility: Option<Visibility>,
+    smooth: Option<Smooth>,
+}
+
+/// The visibility of auto-hiding scrollbars.
+#[derive(Debug, Clone, Copy)]
+struct Visibility {
+    shown_at: Instant,
+    active_at: Instant,
+    now: Instant,
+}
+
+impl Visibility {
+    fn opacity(&self, timeout: Duration) -> f32 {
+        let fade_in = self
+            .now
+            .saturating_duration_since(self.shown_at)
+            .as_secs_f32()
+            / FADE_DURATION.as_secs_f32();
+
+        let fade_out = self
+            .now
+            .saturating_duration_since(self.active_at + timeout)
+            .as_secs_f32()
+            / FADE_DURATION.as_secs_f32();
+
+        fade_in.min(1.0 - fade_out).clamp(0.0, 1.0)
+    }
+
+    /// Returns when the next frame of the fade is due, if still fading.
+    fn next_frame(&self, timeout: Duration) -> Option<Instant> {
+        let fade_out = self.active_at + timeout;
+
+        if self.now < self.shown_at + FADE_DURATION
+            || (self.now >= fade_out && self.now < fade_out + FADE_DURATION)
+        {
+            Some(self.now)
+        } else if self.now < fade_out {
+            Some(fade_out)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1515,7 +1953,11 @@ enum Interaction {
     None,
     YScrollerGrabbed(f32),
     XScrollerGrabbed(f32),
-    TouchScrolling(Point),
+    TouchScrolling {
+        origin: Point,
+        last: Point,
+        is_locked: bool,
+    },
     AutoScrolling {
         origin: Point,
         current: Point,
@@ -1533,6 +1975,11 @@ impl Default for State {
             last_notified: None,
             last_scrolled: None,
             is_scrollbar_visible: true,
+            is_right_to_left: false,
+            is_x_scrollbar_hovered: false,
+            is_y_scrollbar_hovered: false,
+            visibility: None,
+            smooth: None,
         }
     }
 }
@@ -1549,6 +1996,48 @@ impl operation::Scrollable for State {
     fn scroll_by(&mut self, offset: AbsoluteOffset, bounds: Rectangle, content_bounds: Rectangle) {
         State::scroll_by(self, offset, bounds, content_bounds);
     }
+
+    fn snap_to_animated(
+        &mut self,
+        offset: RelativeOffset<Option<f32>>,
+        motion: Motion,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.animate(
+            offset.x.map(|x| Offset::Relative(x.clamp(0.0, 1.0))),
+            offset.y.map(|y| Offset::Relative(y.clamp(0.0, 1.0))),
+            motion,
+            bounds,
+            content_bounds,
+        );
+    }
+
+    fn scroll_to_animated(
+        &mut self,
+        offset: AbsoluteOffset<Option<f32>>,
+        motion: Motion,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.animate(
+            offset.x.map(|x| Offset::Absolute(x.max(0.0))),
+            offset.y.map(|y| Offset::Absolute(y.max(0.0))),
+            motion,
+            bounds,
+            content_bounds,
+        );
+    }
+}
+
+/// A smooth scroll in progress.
+#[derive(Debug, Clone, Copy)]
+struct Smooth {
+    from: Vector,
+    x: Option<Offset>,
+    y: Option<Offset>,
+    motion: Motion,
+    started_at: Option<Instant>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1638,6 +2127,8 @@ impl State {
     }
 
     fn scroll(&mut self, delta: Vector<f32>, bounds: Rectangle, content_bounds: Rectangle) {
+        self.smooth = None;
+
         if bounds.height < content_bounds.height {
             self.offset_y = Offset::Absolute(
                 (self.offset_y.absolute(bounds.height, content_bounds.height) + delta.y)
@@ -1654,16 +2145,20 @@ impl State {
     }
 
     fn scroll_y_to(&mut self, percentage: f32, bounds: Rectangle, content_bounds: Rectangle) {
+        self.smooth = None;
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
 
     fn scroll_x_to(&mut self, percentage: f32, bounds: Rectangle, content_bounds: Rectangle) {
+        self.smooth = None;
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
 
     fn snap_to(&mut self, offset: RelativeOffset<Option<f32>>) {
+        self.smooth = None;
+
         if let Some(x) = offset.x {
             self.offset_x = Offset::Relative(x.clamp(0.0, 1.0));
         }
@@ -1674,6 +2169,8 @@ impl State {
     }
 
     fn scroll_to(&mut self, offset: AbsoluteOffset<Option<f32>>) {
+        self.smooth = None;
+
         if let Some(x) = offset.x {
             self.offset_x = Offset::Absolute(x.max(0.0));
         }
@@ -1688,6 +2185,143 @@ impl State {
         self.scroll(Vector::new(offset.x, offset.y), bounds, content_bounds);
     }
 
+    /// Returns the absolute offsets where the [`State`] will come to rest; that is,
+    /// the destination of the smooth scroll in progress or the current offsets.
+    fn destination(&self, bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+        let (x, y) = self
+            .smooth
+            .map_or((None, None), |smooth| (smooth.x, smooth.y));
+
+        Vector::new(
+            x.unwrap_or(self.offset_x)
+                .absolute(bounds.width, content_bounds.width),
+            y.unwrap_or(self.offset_y)
+                .absolute(bounds.height, content_bounds.height),
+        )
+    }
+
+    /// Starts a smooth scroll from the current scroll position to the given offsets.
+    fn animate(
+        &mut self,
+        x: Option<Offset>,
+        y: Option<Offset>,
+        motion: Motion,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.smooth = Some(Smooth {
+            from: Vector::new(
+                self.offset_x.absolute(bounds.width, content_bounds.width),
+                self.offset_y.absolute(bounds.height, content_bounds.height),
+            ),
+            x,
+            y,
+            motion,
+            started_at: None,
+        });
+    }
+
+    /// Advances the smooth scroll in progress to the given [`Instant`].
+    ///
+    /// Returns `true` if the smooth scroll is still in progress.
+    fn advance(&mut self, now: Instant, bounds: Rectangle, content_bounds: Rectangle) -> bool {
+        let Some(mut smooth) = self.smooth else {
+            return false;
+        };
+
+        let started_at = *smooth.started_at.get_or_insert(now);
+        let progress = smooth
+            .motion
+            .progress(now.saturating_duration_since(started_at));
+
+        if progress >= 1.0 {
+            self.offset_x = smooth.x.unwrap_or(self.offset_x);
+            self.offset_y = smooth.y.unwrap_or(self.offset_y);
+            self.smooth = None;
+
+            return false;
+        }
+
+        // The destination is resolved every frame, so it follows the contents if they change
+        let step = |from: f32, to: Offset, viewport: f32, content: f32| {
+            Offset::Absolute(from + (to.absolute(viewport, content) - from) * progress)
+        };
+
+        if let Some(x) = smooth.x {
+            self.offset_x = step(smooth.from.x, x, bounds.width, content_bounds.width);
+        }
+
+        if let Some(y) = smooth.y {
+            self.offset_y = step(smooth.from.y, y, bounds.height, content_bounds.height);
+        }
+
+        self.smooth = Some(smooth);
+
+        true
+    }
+
+    /// Returns the offsets that the given key scrolls to, if any.
+    ///
+    /// Paging continues from the destination of the smooth scroll in progress;
+    /// so repeated key presses accumulate.
+    fn key_targets(
+        &self,
+        key: keyboard::key::Named,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<(Option<Offset>, Option<Offset>)> {
+        use keyboard::key::Named;
+
+        let (scrollbar, offset, smooth, viewport, content) =
+            if let Some(vertical) = direction.vertical() {
+                (
+                    vertical,
+                    self.offset_y,
+                    self.smooth.and_then(|smooth| smooth.y),
+                    bounds.height,
+                    content_bounds.height,
+                )
+            } else {
+                (
+                    direction.horizontal()?,
+                    self.offset_x,
+                    self.smooth.and_then(|smooth| smooth.x),
+                    bounds.width,
+                    content_bounds.width,
+                )
+            };
+
+        if content <= viewport {
+            return None;
+        }
+
+        let (start, end, sign) = match scrollbar.alignment {
+            Anchor::Start => (0.0, 1.0, 1.0),
+            Anchor::End => (1.0, 0.0, -1.0),
+        };
+
+        let page = |delta: f32| {
+            let current = smooth.unwrap_or(offset).absolute(viewport, content);
+
+            Offset::Absolute((current + delta * sign).clamp(0.0, content - viewport))
+        };
+
+        let target = match key {
+            Named::PageUp => page(-viewport),
+            Named::PageDown => page(viewport),
+            Named::Home => Offset::Relative(start),
+            Named::End => Offset::Relative(end),
+            _ => return None,
+        };
+
+        Some(if direction.vertical().is_some() {
+            (None, Some(target))
+        } else {
+            (Some(target), None)
+        })
+    }
+
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
@@ -1723,6 +2357,22 @@ impl State {
         )
     }
 
+    /// Shows the auto-hiding scrollbars, restarting their timeout.
+    fn show(&mut self, now: Instant, timeout: Duration) {
+        match &mut self.visibility {
+            Some(visibility) if Visibility { now, ..*visibility }.opacity(timeout) > 0.0 => {
+                visibility.active_at = now;
+            }
+            _ => {
+                self.visibility = Some(Visibility {
+                    shown_at: now,
+                    active_at: now,
+                    now,
+                });
+            }
+        }
+    }
+
     fn scrollers_grabbed(&self) -> bool {
         matches!(
             self.interaction,
@@ -1766,19 +2416,28 @@ impl Scrollbars {
 
         let show_scrollbar_x = direction
             .horizontal()
-            .filter(|_scrollbar| content_bounds.width > bounds.width);
+            .filter(|_scrollbar| content_bounds.width > bounds.width)
+            .map(|scrollbar| {
+                scrollbar
+                    .expand(state.is_x_scrollbar_hovered || state.x_scroller_grabbed_at().is_some())
+            });
 
         let show_scrollbar_y = direction
             .vertical()
-            .filter(|_scrollbar| content_bounds.height > bounds.height);
+            .filter(|_scrollbar| content_bounds.height > bounds.height)
+            .map(|scrollbar| {
+                scrollbar
+                    .expand(state.is_y_scrollbar_hovered || state.y_scroller_grabbed_at().is_some())
+            });
 
         let y_scrollbar = if let Some(vertical) = show_scrollbar_y {
             let Scrollbar {
                 width,
                 margin,
                 scroller_width,
+                min_scroller_length,
                 ..
-            } = *vertical;
+            } = vertical;
 
             // Adjust the height of the vertical scrollbar if the horizontal scrollbar
             // is present
@@ -1809,9 +2468,11 @@ impl Scrollbars {
                 None
             } else {
                 // min height for easier grabbing with super tall content
-                let scroller_height = (scrollbar_bounds.height * ratio).max(2.0);
-                let scroller_offset =
-                    translation.y * ratio * scrollbar_bounds.height / bounds.height;
+                let scroller_height = (scrollbar_bounds.height * ratio)
+                    .max(min_scroller_length)
+                    .min(scrollbar_bounds.height);
+                let scroller_offset = translation.y / (content_bounds.height - bounds.height)
+                    * (scrollbar_bounds.height - scroller_height);
 
                 let scroller_bounds = Rectangle {
                     x: bounds.x + bounds.width - total_scrollbar_width / 2.0 - scroller_width / 2.0,
@@ -1825,12 +2486,18 @@ impl Scrollbars {
                 })
             };
 
-            Some(internals::Scrollbar {
+            let scrollbar = internals::Scrollbar {
                 total_bounds: total_scrollbar_bounds,
                 bounds: scrollbar_bounds,
                 scroller,
                 alignment: vertical.alignment,
                 disabled: content_bounds.height <= bounds.height,
+            };
+
+            Some(if state.is_right_to_left {
+                scrollbar.mirror(bounds)
+            } else {
+                scrollbar
             })
         } else {
             None
@@ -1841,8 +2508,9 @@ impl Scrollbars {
                 width,
                 margin,
                 scroller_width,
+                min_scroller_length,
                 ..
-            } = *horizontal;
+            } = horizontal;
 
             // Need to adjust the width of the horizontal scrollbar if the vertical scrollbar
             // is present
@@ -1851,9 +2519,16 @@ impl Scrollbars {
 
             let total_scrollbar_height = width.max(scroller_width) + 2.0 * margin;
 
+            // The vertical scrollbar is on the left side when right-to-left
+            let x = if state.is_right_to_left {
+                bounds.x + scrollbar_y_width
+            } else {
+                bounds.x
+            };
+
             // Total bounds of the scrollbar + margin + scroller width
             let total_scrollbar_bounds = Rectangle {
-                x: bounds.x,
+                x,
                 y: bounds.y + bounds.height - total_scrollbar_height,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: total_scrollbar_height,
@@ -1861,7 +2536,7 @@ impl Scrollbars {
 
             // Bounds of just the scrollbar
             let scrollbar_bounds = Rectangle {
-                x: bounds.x,
+                x,
                 y: bounds.y + bounds.height - total_scrollbar_height / 2.0 - width / 2.0,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: width,
@@ -1873,8 +2548,11 @@ impl Scrollbars {
                 None
             } else {
                 // min width for easier grabbing with extra wide content
-                let scroller_length = (scrollbar_bounds.width * ratio).max(2.0);
-                let scroller_offset = translation.x * ratio * scrollbar_bounds.width / bounds.width;
+                let scroller_length = (scrollbar_bounds.width * ratio)
+                    .max(min_scroller_length)
+                    .min(scrollbar_bounds.width);
+                let scroller_offset = translation.x / (content_bounds.width - bounds.width)
+                    * (scrollbar_bounds.width - scroller_length);
 
                 let scroller_bounds = Rectangle {
                     x: (scrollbar_bounds.x + scroller_offset).max(0.0),
@@ -1982,6 +2660,23 @@ pub(super) mod internals {
     }
 
     impl Scrollbar {
+        /// Mirrors the [`Scrollbar`] horizontally inside the given bounds.
+        pub fn mirror(mut self, bounds: Rectangle) -> Self {
+            let mirror = |rectangle: Rectangle| Rectangle {
+                x: 2.0 * bounds.x + bounds.width - rectangle.x - rectangle.width,
+                ..rectangle
+            };
+
+            self.total_bounds = mirror(self.total_bounds);
+            self.bounds = mirror(self.bounds);
+
+            if let Some(scroller) = &mut self.scroller {
+                scroller.bounds = mirror(scroller.bounds);
+            }
+
+            self
+        }
+
         /// Returns whether the mouse is over the scrollbar or not.
         pub fn is_mouse_over(&self, cursor_position: Point) -> bool {
             self.total_bounds.contains(cursor_position)
@@ -2088,6 +2783,28 @@ pub struct Rail {
     pub scroller: Scroller,
 }
 
+impl Rail {
+    /// Scales the alpha channel of the [`Rail`] by the given factor.
+    pub fn scale_alpha(self, factor: f32) -> Self {
+        Self {
+            background: self
+                .background
+                .map(|background| background.scale_alpha(factor)),
+            border: Border {
+                color: self.border.color.scale_alpha(factor),
+                ..self.border
+            },
+            scroller: Scroller {
+                background: self.scroller.background.scale_alpha(factor),
+                border: Border {
+                    color: self.scroller.border.color.scale_alpha(factor),
+                    ..self.scroller.border
+                },
+            },
+        }
+    }
+}
+
 /// The appearance of the scroller of a scrollable.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Scroller {
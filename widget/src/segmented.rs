@@ -0,0 +1,639 @@
+//! Segmented controls let users pick one option out of a few, displayed as a
+//! group of joined buttons.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::segmented;
+//!
+//! struct State {
+//!    view: View,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum View {
+//!     List,
+//!     Grid,
+//!     Gallery,
+//! }
+//!
+//! impl std::fmt::Display for View {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         f.write_str(match self {
+//!             View::List => "List",
+//!             View::Grid => "Grid",
+//!             View::Gallery => "Gallery",
+//!         })
+//!     }
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ViewSelected(View),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     segmented(
+//!         [View::List, View::Grid, View::Gallery],
+//!         Some(state.view),
+//!         Message::ViewSelected,
+//!     )
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::ViewSelected(view) => {
+//!             state.view = view;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! A [`Segmented`] control can be focused with the [`focus_next`] and
+//! [`focus_previous`] operations. While focused, the arrow keys change its
+//! selection; which makes it usable with a controller or a remote.
+//!
+//! [`focus_next`]: crate::core::widget::operation::focusable::focus_next
+//! [`focus_previous`]: crate::core::widget::operation::focusable::focus_previous
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Widget,
+};
+
+/// A group of joined buttons that selects one option out of a few.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::segmented;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QualitySelected(&'static str),
+/// }
+///
+/// fn view(quality: &'static str) -> Element<'static, Message> {
+///     segmented(["Low", "Medium", "High"], Some(quality), Message::QualitySelected)
+///         .width(300)
+///         .into()
+/// }
+/// ```
+pub struct Segmented<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    options: Vec<T>,
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    last_interaction: Option<Interaction>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Segmented<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of the segments of a [`Segmented`] control.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 12.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`Segmented`] control.
+    ///
+    /// It expects:
+    ///   * the options of the [`Segmented`] control, in order
+    ///   * the currently selected option, if any
+    ///   * a function that will be called when an option is selected. It
+    ///     receives the selected option and must produce a `Message`.
+    pub fn new(
+        options: impl IntoIterator<Item = T>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            options: options.into_iter().collect(),
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::default(),
+            font: None,
+            class: Theme::default(),
+            last_interaction: None,
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`Segmented`] control.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`Segmented`] control.
+    ///
+    /// The width is always split evenly between the segments.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each segment of the [`Segmented`] control.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Segmented`] control.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Segmented`] control.
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`Segmented`] control.
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`Segmented`] control.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Segmented`] control.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Segmented`] control.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.options.iter().position(|option| option == selected)
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Segmented<'_, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.text_line_height.to_absolute(text_size));
+
+        state
+            .labels
+            .resize_with(self.options.len(), Default::default);
+
+        for (option, paragraph) in self.options.iter().zip(state.labels.iter_mut()) {
+            let label = option.to_string();
+
+            let _ = paragraph.update(Text {
+                content: &label,
+                bounds: Size::new(f32::INFINITY, line_height),
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: text::Wrapping::None,
+                hint_factor: renderer.scale_factor(),
+            });
+        }
+
+        let label_width = state.labels.iter().fold(0.0, |width, paragraph| {
+            f32::max(width, paragraph.min_width())
+        });
+
+        let count = self.options.len().max(1) as f32;
+
+        let size = limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(
+                (label_width + self.padding.x()) * count,
+                line_height + self.padding.y(),
+            ),
+        );
+
+        let segment = Size::new(size.width / count, size.height);
+
+        layout::Node::with_children(
+            size,
+            (0..self.options.len())
+                .map(|i| {
+                    layout::Node::new(segment).move_to(Point::new(i as f32 * segment.width, 0.0))
+                })
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let hovered = layout
+            .children()
+            .position(|segment| cursor.is_over(segment.bounds()));
+
+        let selection = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                if hovered.is_some() {
+                    shell.capture_event();
+                }
+
+                hovered
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if state.is_focused && !self.options.is_empty() => {
+                let last = self.options.len() - 1;
+                let current = self.selected_index();
+
+                let index = match named {
+                    key::Named::ArrowLeft | key::Named::ArrowUp => {
+                        Some(current.map_or(last, |index| index.saturating_sub(1)))
+                    }
+                    key::Named::ArrowRight | key::Named::ArrowDown => {
+                        Some(current.map_or(0, |index| (index + 1).min(last)))
+                    }
+                    key::Named::Home => Some(0),
+                    key::Named::End => Some(last),
+                    _ => None,
+                };
+
+                if index.is_some() {
+                    shell.capture_event();
+                }
+
+                index
+            }
+            _ => None,
+        };
+
+        if let Some(index) = selection
+            && self.selected_index() != Some(index)
+            && let Some(option) = self.options.get(index)
+        {
+            shell.publish((self.on_select)(option.clone()));
+        }
+
+        let current_interaction = Interaction {
+            hovered,
+            is_focused: state.is_focused,
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_interaction = Some(current_interaction);
+        } else if self
+            .last_interaction
+            .is_some_and(|interaction| interaction != current_interaction)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let interaction = self.last_interaction.unwrap_or_default();
+        let selected = self.selected_index();
+        let last = self.options.len().saturating_sub(1);
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (i, (option, segment)) in self.options.iter().zip(layout.children()).enumerate() {
+            let bounds = segment.bounds();
+            let is_selected = selected == Some(i);
+
+            let status = if interaction.is_focused && is_selected {
+                Status::Focused { is_selected }
+            } else if interaction.hovered == Some(i) {
+                Status::Hovered { is_selected }
+            } else {
+                Status::Active { is_selected }
+            };
+
+            let style = theme.style(&self.class, status);
+
+            // Only the outer corners of the group are rounded
+            let radius = style.border.radius;
+            let radius = border::Radius {
+                top_left: if i == 0 { radius.top_left } else { 0.0 },
+                bottom_left: if i == 0 { radius.bottom_left } else { 0.0 },
+                top_right: if i == last { radius.top_right } else { 0.0 },
+                bottom_right: if i == last { radius.bottom_right } else { 0.0 },
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius,
+                        ..style.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: option.to_string(),
+                    bounds: bounds.size(),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                bounds.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Segmented<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        segmented: Segmented<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(segmented)
+    }
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    is_focused: bool,
+    labels: Vec<paragraph::Plain<P>>,
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            is_focused: false,
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Interaction {
+    hovered: Option<usize>,
+    is_focused: bool,
+}
+
+/// The possible status of a segment of a [`Segmented`] control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The segment can be interacted with.
+    Active {
+        /// Indicates whether the segment is selected.
+        is_selected: bool,
+    },
+    /// The segment is being hovered.
+    Hovered {
+        /// Indicates whether the segment is selected.
+        is_selected: bool,
+    },
+    /// The segment is selected and the [`Segmented`] control is focused.
+    Focused {
+        /// Indicates whether the segment is selected.
+        is_selected: bool,
+    },
+}
+
+/// The appearance of a segment of a [`Segmented`] control.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the segment.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the segment.
+    pub text_color: Color,
+    /// The [`Border`] of the segment.
+    ///
+    /// Only the outer corners of the [`Segmented`] control are rounded.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`Segmented`] control.
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Segmented`] control.
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Segmented`] control.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let (background, text_color) = match status {
+        Status::Active { is_selected: true } => {
+            (palette.primary.base.color, palette.primary.base.text)
+        }
+        Status::Hovered { is_selected: true } | Status::Focused { .. } => {
+            (palette.primary.strong.color, palette.primary.strong.text)
+        }
+        Status::Active { is_selected: false } => {
+            (palette.background.weak.color, palette.background.weak.text)
+        }
+        Status::Hovered { is_selected: false } => (
+            palette.background.strong.color,
+            palette.background.strong.text,
+        ),
+    };
+
+    let border_color = match status {
+        Status::Focused { .. } => palette.primary.weak.color,
+        _ => palette.background.strong.color,
+    };
+
+    Style {
+        background: Some(background.into()),
+        text_color,
+        border: Border {
+            color: border_color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+    }
+}
@@ -7,8 +7,10 @@ use crate::core::event;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size};
 use crate::renderer::wgpu::primitive;
 
@@ -16,7 +18,7 @@ use std::marker::PhantomData;
 
 pub use crate::Action;
 pub use crate::graphics::Viewport;
-pub use primitive::{Pipeline, Primitive, Storage};
+pub use primitive::{Cache, Globals, Pipeline, Primitive, Storage, Uniform};
 
 /// A widget which can render custom shaders with Iced's `wgpu` backend.
 ///
@@ -59,12 +61,15 @@ where
     Renderer: primitive::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        struct Tag<T>(T);
-        tree::Tag::of::<Tag<P::State>>()
+        tree::Tag::of::<State<P::State>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(P::State::default())
+        tree::State::new(State {
+            program: P::State::default(),
+            started_at: None,
+            now: None,
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -96,9 +101,17 @@ where
     ) {
         let bounds = layout.bounds();
 
-        let state = tree.state.downcast_mut::<P::State>();
+        let state = tree.state.downcast_mut::<State<P::State>>();
 
-        if let Some(action) = self.program.update(state, event, bounds, cursor) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let _ = state.started_at.get_or_insert(*now);
+            state.now = Some(*now);
+        }
+
+        if let Some(action) = self
+            .program
+            .update(&mut state.program, event, bounds, cursor)
+        {
             let (message, redraw_request, event_status) = action.into_inner();
 
             shell.request_redraw_at(redraw_request);
@@ -122,9 +135,10 @@ where
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
 
-        self.program.mouse_interaction(state, bounds, cursor)
+        self.program
+            .mouse_interaction(&state.program, bounds, cursor)
     }
 
     fn draw(
@@ -138,9 +152,32 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
+
+        let globals = Globals::new(bounds)
+            .cursor(cursor_position.position_in(bounds))
+            .time(state.elapsed());
+
+        renderer.draw_primitive(
+            bounds,
+            self.program
+                .draw_with_globals(&state.program, cursor_position, bounds, globals),
+        );
+    }
+}
+
+struct State<T> {
+    program: T,
+    started_at: Option<Instant>,
+    now: Option<Instant>,
+}
 
-        renderer.draw_primitive(bounds, self.program.draw(state, cursor_position, bounds));
+impl<T> State<T> {
+    fn elapsed(&self) -> Duration {
+        match (self.started_at, self.now) {
+            (Some(started_at), Some(now)) => now.saturating_duration_since(started_at),
+            _ => Duration::ZERO,
+        }
     }
 }
 
@@ -182,6 +219,16 @@ where
         T::draw(self, state, cursor, bounds)
     }
 
+    fn draw_with_globals(
+        &self,
+        state: &Self::State,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        globals: Globals,
+    ) -> Self::Primitive {
+        T::draw_with_globals(self, state, cursor, bounds, globals)
+    }
+
     fn mouse_interaction(
         &self,
         state: &Self::State,
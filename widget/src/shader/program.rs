@@ -1,7 +1,7 @@
 use crate::core::Rectangle;
 use crate::core::mouse;
 use crate::renderer::wgpu::Primitive;
-use crate::shader::{self, Action};
+use crate::shader::{self, Action, Globals};
 
 /// The state and logic of a [`Shader`] widget.
 ///
@@ -43,6 +43,32 @@ pub trait Program<Message> {
         bounds: Rectangle,
     ) -> Self::Primitive;
 
+    /// Draws the [`Primitive`] with the built-in [`Globals`] of the [`Shader`].
+    ///
+    /// The [`Globals`] contain the size of the [`Shader`], the position of the
+    /// cursor relative to it, and the time elapsed since its first redraw.
+    /// They can be stored in the [`Primitive`], scaled with [`Globals::scale`]
+    /// during [`Primitive::prepare`], and uploaded with a [`Uniform`].
+    ///
+    /// The elapsed time only advances when the [`Shader`] is redrawn; request a
+    /// redraw in [`update`](Self::update) to animate continuously.
+    ///
+    /// By default, it ignores the [`Globals`] and calls [`draw`](Self::draw).
+    ///
+    /// [`Primitive`]: Self::Primitive
+    /// [`Primitive::prepare`]: shader::Primitive::prepare
+    /// [`Uniform`]: shader::Uniform
+    /// [`Shader`]: crate::Shader
+    fn draw_with_globals(
+        &self,
+        state: &Self::State,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        _globals: Globals,
+    ) -> Self::Primitive {
+        self.draw(state, cursor, bounds)
+    }
+
     /// Returns the current mouse interaction of the [`Program`].
     ///
     /// The interaction returned will be in effect even if the cursor position is out of
@@ -28,12 +28,21 @@
 //!     }
 //! }
 //! ```
+//!
+//! A [`Slider`] can display [`Ticks`] along its rail and snap to them, and a
+//! [`Range`] slider selects a pair of values with two handles.
+mod range;
+
+pub use range::Range;
+
+use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::keyboard;
 use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -93,6 +102,7 @@ where
     on_release: Option<Message>,
     width: Length,
     height: f32,
+    ticks: Option<Ticks<T>>,
     class: Theme::Class<'a>,
     status: Option<Status>,
 }
@@ -140,6 +150,7 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            ticks: None,
             class: Theme::default(),
             status: None,
         }
@@ -190,6 +201,14 @@ where
         self
     }
 
+    /// Sets the [`Ticks`] of the [`Slider`].
+    ///
+    /// The labels of the [`Ticks`] are displayed below the rail.
+    pub fn ticks(mut self, ticks: Ticks<T>) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -214,7 +233,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: core::text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -234,10 +253,16 @@ where
     fn layout(
         &mut self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        let labels = self
+            .ticks
+            .as_ref()
+            .map(|ticks| ticks.label_space(renderer, false))
+            .unwrap_or_default();
+
+        layout::atomic(limits, self.width, self.height + labels)
     }
 
     fn update(
@@ -279,11 +304,25 @@ where
                     let steps = (percent * (end - start) / step).round();
                     let value = steps * step + start;
 
-                    T::from_f64(value.min(end))
+                    let value = self
+                        .ticks
+                        .as_ref()
+                        .and_then(|ticks| ticks.nearest(start + percent * (end - start)))
+                        .unwrap_or(value);
+
+                    T::from_f64(value.max(start).min(end))
                 }
             };
 
             let increment = |value: T| -> Option<T> {
+                if let Some(tick) = self
+                    .ticks
+                    .as_ref()
+                    .and_then(|ticks| ticks.next(value.into()))
+                {
+                    return T::from_f64(tick.min((*self.range.end()).into()));
+                }
+
                 let step = if state.keyboard_modifiers.shift() {
                     self.shift_step.unwrap_or(self.step)
                 } else {
@@ -302,6 +341,14 @@ where
             };
 
             let decrement = |value: T| -> Option<T> {
+                if let Some(tick) = self
+                    .ticks
+                    .as_ref()
+                    .and_then(|ticks| ticks.previous(value.into()))
+                {
+                    return T::from_f64(tick.max((*self.range.start()).into()));
+                }
+
                 let step = if state.keyboard_modifiers.shift() {
                     self.shift_step.unwrap_or(self.step)
                 } else {
@@ -422,10 +469,10 @@ where
         _tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        defaults: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
 
@@ -436,7 +483,7 @@ where
             HandleShape::Rectangle {
                 width,
                 border_radius,
-            } => (f32::from(width), bounds.height, border_radius),
+            } => (f32::from(width), self.height, border_radius),
         };
 
         let value = self.value.into() as f32;
@@ -452,7 +499,7 @@ where
             (bounds.width - handle_width) * (value - range_start) / (range_end - range_start)
         };
 
-        let rail_y = bounds.y + bounds.height / 2.0;
+        let rail_y = bounds.y + self.height / 2.0;
 
         renderer.fill_quad(
             renderer::Quad {
@@ -482,6 +529,27 @@ where
             style.rail.backgrounds.1,
         );
 
+        if let Some(ticks) = &self.ticks {
+            ticks.draw(
+                renderer,
+                |value| {
+                    let offset = if range_start >= range_end {
+                        0.0
+                    } else {
+                        (bounds.width - handle_width) * (value as f32 - range_start)
+                            / (range_end - range_start)
+                    };
+
+                    Point::new(bounds.x + offset + handle_width / 2.0, rail_y)
+                },
+                false,
+                bounds.y + self.height,
+                defaults.text_color.scale_alpha(0.5),
+                defaults.text_color,
+                viewport,
+            );
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -537,7 +605,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: core::text::Renderer + 'a,
 {
     fn from(slider: Slider<'a, T, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
         Element::new(slider)
@@ -550,6 +618,284 @@ struct State {
     keyboard_modifiers: keyboard::Modifiers,
 }
 
+/// A set of tick marks along the rail of a slider.
+///
+/// Tick marks can have a label, and the value of the slider can snap to
+/// them.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::slider;
+/// use iced::widget::slider::Ticks;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QualityChanged(u8),
+/// }
+///
+/// fn view(quality: u8) -> Element<'static, Message> {
+///     slider(0..=2, quality, Message::QualityChanged)
+///         .ticks(
+///             Ticks::new()
+///                 .label(0, "Low")
+///                 .label(1, "Medium")
+///                 .label(2, "High")
+///                 .snap(true),
+///         )
+///         .into()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ticks<T> {
+    marks: Vec<(T, Option<String>)>,
+    snap: bool,
+    text_size: Option<Pixels>,
+}
+
+impl<T> Ticks<T> {
+    /// The length of a tick mark, across the rail.
+    const LENGTH: f32 = 8.0;
+
+    /// The thickness of a tick mark.
+    const THICKNESS: f32 = 2.0;
+
+    /// The spacing between the labels and the rail.
+    const SPACING: f32 = 4.0;
+
+    /// Creates an empty set of [`Ticks`].
+    pub fn new() -> Self {
+        Self {
+            marks: Vec::new(),
+            snap: false,
+            text_size: None,
+        }
+    }
+
+    /// Creates a set of [`Ticks`] every given step within the range.
+    pub fn every(range: RangeInclusive<T>, step: T) -> Self
+    where
+        T: Copy + Into<f64> + num_traits::FromPrimitive,
+    {
+        let (start, end) = ((*range.start()).into(), (*range.end()).into());
+        let step = step.into();
+
+        if step <= 0.0 {
+            return Self::new();
+        }
+
+        let count = ((end - start) / step).floor() as usize;
+
+        (0..=count)
+            .filter_map(|i| T::from_f64(start + step * i as f64))
+            .collect()
+    }
+
+    /// Adds a tick mark at the given value.
+    pub fn mark(mut self, value: T) -> Self {
+        self.marks.push((value, None));
+        self
+    }
+
+    /// Adds a tick mark with the given label at the given value.
+    pub fn label(mut self, value: T, label: impl Into<String>) -> Self {
+        self.marks.push((value, Some(label.into())));
+        self
+    }
+
+    /// Sets whether the value of the slider snaps to the nearest tick mark.
+    ///
+    /// By default, it does not.
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Ticks`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    fn labels(&self) -> impl Iterator<Item = (&T, &str)> {
+        self.marks
+            .iter()
+            .filter_map(|(value, label)| Some((value, label.as_deref()?)))
+    }
+}
+
+impl<T> Ticks<T>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the tick mark closest to the given value, if snapping.
+    pub(crate) fn nearest(&self, value: f64) -> Option<f64> {
+        if !self.snap {
+            return None;
+        }
+
+        self.marks
+            .iter()
+            .map(|(tick, _)| (*tick).into())
+            .min_by(|a: &f64, b: &f64| (a - value).abs().total_cmp(&(b - value).abs()))
+    }
+
+    /// Returns the closest tick mark after the given value, if snapping.
+    pub(crate) fn next(&self, value: f64) -> Option<f64> {
+        if !self.snap {
+            return None;
+        }
+
+        self.marks
+            .iter()
+            .map(|(tick, _)| (*tick).into())
+            .filter(|tick| *tick > value + f64::EPSILON)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Returns the closest tick mark before the given value, if snapping.
+    pub(crate) fn previous(&self, value: f64) -> Option<f64> {
+        if !self.snap {
+            return None;
+        }
+
+        self.marks
+            .iter()
+            .map(|(tick, _)| (*tick).into())
+            .filter(|tick| *tick < value - f64::EPSILON)
+            .max_by(f64::total_cmp)
+    }
+
+    /// Returns the space needed by the labels of the [`Ticks`] across the
+    /// rail of a slider.
+    pub(crate) fn label_space<Renderer>(&self, renderer: &Renderer, is_vertical: bool) -> f32
+    where
+        Renderer: core::text::Renderer,
+    {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        if self.labels().next().is_none() {
+            0.0
+        } else if is_vertical {
+            let width = self
+                .labels()
+                .map(|(_, label)| {
+                    Renderer::Paragraph::with_text(text::Text {
+                        content: label,
+                        bounds: Size::INFINITE,
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font: renderer.default_font(),
+                        align_x: text::Alignment::Left,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::default(),
+                        wrapping: text::Wrapping::None,
+                        hint_factor: None,
+                    })
+                    .min_width()
+                })
+                .fold(0.0, f32::max);
+
+            Self::SPACING + width
+        } else {
+            Self::SPACING + text::LineHeight::default().to_absolute(text_size).0
+        }
+    }
+
+    /// Draws the [`Ticks`] along a rail.
+    ///
+    /// The position of a value on the rail is given by `locate`; labels start
+    /// at the `labels` coordinate across the rail.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        locate: impl Fn(f64) -> Point,
+        is_vertical: bool,
+        labels: f32,
+        color: Color,
+        text_color: Color,
+        viewport: &Rectangle,
+    ) where
+        Renderer: core::text::Renderer,
+    {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (value, label) in &self.marks {
+            let position = locate((*value).into());
+
+            let (width, height) = if is_vertical {
+                (Self::LENGTH, Self::THICKNESS)
+            } else {
+                (Self::THICKNESS, Self::LENGTH)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: position.x - width / 2.0,
+                        y: position.y - height / 2.0,
+                        width,
+                        height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+
+            let Some(label) = label else {
+                continue;
+            };
+
+            let (position, align_x, align_y) = if is_vertical {
+                (
+                    Point::new(labels + Self::SPACING, position.y),
+                    text::Alignment::Left,
+                    alignment::Vertical::Center,
+                )
+            } else {
+                (
+                    Point::new(position.x, labels + Self::SPACING),
+                    text::Alignment::Center,
+                    alignment::Vertical::Top,
+                )
+            };
+
+            renderer.fill_text(
+                text::Text {
+                    content: label.clone(),
+                    bounds: Size::INFINITE,
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    align_x,
+                    align_y,
+                    shaping: text::Shaping::default(),
+                    wrapping: text::Wrapping::None,
+                    hint_factor: None,
+                },
+                position,
+                text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<T> Default for Ticks<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Ticks<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::mark)
+    }
+}
+
 /// The possible status of a [`Slider`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -0,0 +1,645 @@
+//! Select a range of values with two handles.
+use crate::core::border::Border;
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+
+use super::{Catalog, HandleShape, Status, Style, StyleFn, Ticks};
+
+use std::ops::RangeInclusive;
+
+/// A bar with two handles that selects a range of values from a range of
+/// values.
+///
+/// A [`Range`] slider is horizontal by default and will try to fill the
+/// space of its container along its rail. It is styled with the same
+/// [`Catalog`] as a [`Slider`](super::Slider); the first background of the
+/// rail is used between the handles.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::range_slider;
+///
+/// struct State {
+///    price: (u32, u32),
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PriceChanged((u32, u32)),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     range_slider(0..=200, state.price, Message::PriceChanged)
+///         .step(5u32)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::PriceChanged(price) => {
+///             state.price = price;
+///         }
+///     }
+/// }
+/// ```
+pub struct Range<'a, T, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    values: (T, T),
+    step: T,
+    shift_step: Option<T>,
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    on_release: Option<Message>,
+    length: Length,
+    girth: f32,
+    is_vertical: bool,
+    ticks: Option<Ticks<T>>,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+impl<'a, T, Message, Theme> Range<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// The default girth of a [`Range`] slider.
+    pub const DEFAULT_GIRTH: f32 = 16.0;
+
+    /// Creates a new [`Range`] slider.
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current start and end values of the [`Range`] slider
+    ///   * a function that will be called when any of the handles is dragged.
+    ///     It receives the new start and end values and must produce a
+    ///     `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, values: (T, T), on_change: F) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(values.0), clamp(values.1));
+
+        let values = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        Range {
+            range,
+            values,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            length: Length::Fill,
+            girth: Self::DEFAULT_GIRTH,
+            is_vertical: false,
+            ticks: None,
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the release message of the [`Range`] slider.
+    /// This is called when the mouse is released from any of the handles.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the length of the rail of the [`Range`] slider.
+    pub fn length(mut self, length: impl Into<Length>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    /// Sets the girth of the rail of the [`Range`] slider, not including the
+    /// labels of its [`Ticks`].
+    pub fn girth(mut self, girth: impl Into<Pixels>) -> Self {
+        self.girth = girth.into().0;
+        self
+    }
+
+    /// Makes the [`Range`] slider vertical, with the start of the range at
+    /// the bottom.
+    pub fn vertical(mut self) -> Self {
+        self.is_vertical = true;
+        self
+    }
+
+    /// Sets the step size of the [`Range`] slider.
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`Range`] slider.
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the [`Ticks`] of the [`Range`] slider.
+    ///
+    /// The labels of the [`Ticks`] are displayed below the rail, or to its
+    /// right if the [`Range`] slider is vertical.
+    pub fn ticks(mut self, ticks: Ticks<T>) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    /// Sets the style of the [`Range`] slider.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Range`] slider.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<T, Message, Theme> Range<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64>,
+    Theme: Catalog,
+{
+    fn step(&self, modifiers: keyboard::Modifiers) -> f64 {
+        if modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Range<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        if self.is_vertical {
+            Size {
+                width: Length::Shrink,
+                height: self.length,
+            }
+        } else {
+            Size {
+                width: self.length,
+                height: Length::Shrink,
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let labels = self
+            .ticks
+            .as_ref()
+            .map(|ticks| ticks.label_space(renderer, self.is_vertical))
+            .unwrap_or_default();
+
+        if self.is_vertical {
+            layout::atomic(limits, self.girth + labels, self.length)
+        } else {
+            layout::atomic(limits, self.length, self.girth + labels)
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let step = self.step(state.keyboard_modifiers);
+        let is_vertical = self.is_vertical;
+
+        let locate = |cursor_position: Point| -> f64 {
+            let percent = percent(bounds, cursor_position, is_vertical);
+
+            let steps = (percent * (end - start) / step).round();
+            let value = steps * step + start;
+
+            self.ticks
+                .as_ref()
+                .and_then(|ticks| ticks.nearest(start + percent * (end - start)))
+                .unwrap_or(value)
+                .max(start)
+                .min(end)
+        };
+
+        let increment = |value: f64| -> f64 {
+            if let Some(tick) = self.ticks.as_ref().and_then(|ticks| ticks.next(value)) {
+                return tick.min(end);
+            }
+
+            let steps = (value / step).round();
+
+            (step * (steps + 1.0)).min(end)
+        };
+
+        let decrement = |value: f64| -> f64 {
+            if let Some(tick) = self.ticks.as_ref().and_then(|ticks| ticks.previous(value)) {
+                return tick.max(start);
+            }
+
+            let steps = (value / step).round();
+
+            (step * (steps - 1.0)).max(start)
+        };
+
+        let (low, high) = (self.values.0.into(), self.values.1.into());
+        let current_value = state.last_thumb.value((low, high));
+
+        let change = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                cursor.position_over(bounds).map(|cursor_position| {
+                    let value = locate(cursor_position);
+
+                    let thumb = if (value - low).abs() < (value - high).abs()
+                        || ((value - low).abs() == (value - high).abs() && value < high)
+                    {
+                        Thumb::Start
+                    } else {
+                        Thumb::End
+                    };
+
+                    state.dragging = Some(thumb);
+                    state.last_thumb = thumb;
+
+                    shell.capture_event();
+
+                    (thumb, value)
+                })
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.take().is_some()
+                    && let Some(on_release) = self.on_release.clone()
+                {
+                    shell.publish(on_release);
+                }
+
+                None
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => state.dragging.and_then(|thumb| {
+                shell.capture_event();
+
+                cursor
+                    .land()
+                    .position()
+                    .map(|cursor_position| (thumb, locate(cursor_position)))
+            }),
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.keyboard_modifiers.control() && cursor.is_over(bounds) =>
+            {
+                let delta = match *delta {
+                    mouse::ScrollDelta::Lines { x: _, y } => y,
+                    mouse::ScrollDelta::Pixels { x: _, y } => y,
+                };
+
+                shell.capture_event();
+
+                if delta < 0.0 {
+                    Some((state.last_thumb, decrement(current_value)))
+                } else {
+                    Some((state.last_thumb, increment(current_value)))
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if cursor.is_over(bounds) => {
+                match key {
+                    Key::Named(key::Named::ArrowUp) => {
+                        shell.capture_event();
+
+                        Some((state.last_thumb, increment(current_value)))
+                    }
+                    Key::Named(key::Named::ArrowDown) => {
+                        shell.capture_event();
+
+                        Some((state.last_thumb, decrement(current_value)))
+                    }
+                    _ => None,
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+
+                None
+            }
+            _ => None,
+        };
+
+        if let Some((thumb, value)) = change {
+            let value = match thumb {
+                Thumb::Start => value.min(high),
+                Thumb::End => value.max(low),
+            };
+
+            if let Some(value) = T::from_f64(value)
+                && (value.into() - thumb.value((low, high))).abs() > f64::EPSILON
+            {
+                let values = match thumb {
+                    Thumb::Start => (value, self.values.1),
+                    Thumb::End => (self.values.0, value),
+                };
+
+                shell.publish((self.on_change)(values));
+
+                self.values = values;
+            }
+        }
+
+        let current_status = if state.dragging.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.status = Some(current_status);
+        } else if self.status.is_some_and(|status| status != current_status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let style = theme.style(&self.class, self.status.unwrap_or(Status::Active));
+
+        let (handle_length, handle_girth, handle_border_radius) = match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius.into()),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), self.girth, border_radius),
+        };
+
+        let (range_start, range_end) = {
+            let (start, end) = self.range.clone().into_inner();
+
+            (start.into() as f32, end.into() as f32)
+        };
+
+        let length = if self.is_vertical {
+            bounds.height
+        } else {
+            bounds.width
+        };
+
+        let offset = |value: f64| {
+            if range_start >= range_end {
+                0.0
+            } else {
+                (length - handle_length) * (value as f32 - range_start) / (range_end - range_start)
+            }
+        };
+
+        let rail = if self.is_vertical {
+            bounds.x + self.girth / 2.0
+        } else {
+            bounds.y + self.girth / 2.0
+        };
+
+        // Builds a rectangle from a position and a length along the rail,
+        // and a girth centered across it.
+        let rectangle = |along: f32, length: f32, girth: f32| {
+            if self.is_vertical {
+                Rectangle {
+                    x: rail - girth / 2.0,
+                    y: bounds.y + bounds.height - along - length,
+                    width: girth,
+                    height: length,
+                }
+            } else {
+                Rectangle {
+                    x: bounds.x + along,
+                    y: rail - girth / 2.0,
+                    width: length,
+                    height: girth,
+                }
+            }
+        };
+
+        let low = offset(self.values.0.into());
+        let high = offset(self.values.1.into());
+
+        for (along, length, background) in [
+            (0.0, low + handle_length / 2.0, style.rail.backgrounds.1),
+            (
+                low + handle_length / 2.0,
+                high - low,
+                style.rail.backgrounds.0,
+            ),
+            (
+                high + handle_length / 2.0,
+                length - high - handle_length / 2.0,
+                style.rail.backgrounds.1,
+            ),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rectangle(along, length, style.rail.width),
+                    border: style.rail.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if let Some(ticks) = &self.ticks {
+            ticks.draw(
+                renderer,
+                |value| {
+                    let along = offset(value) + handle_length / 2.0;
+
+                    if self.is_vertical {
+                        Point::new(rail, bounds.y + bounds.height - along)
+                    } else {
+                        Point::new(bounds.x + along, rail)
+                    }
+                },
+                self.is_vertical,
+                if self.is_vertical {
+                    bounds.x + self.girth
+                } else {
+                    bounds.y + self.girth
+                },
+                defaults.text_color.scale_alpha(0.5),
+                defaults.text_color,
+                viewport,
+            );
+        }
+
+        for along in [low, high] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rectangle(along, handle_length, handle_girth),
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging.is_some() {
+            // FIXME: Fall back to `Pointer` on Windows
+            // See https://github.com/rust-windowing/winit/issues/1043
+            if cfg!(target_os = "windows") {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Grabbing
+            }
+        } else if cursor.is_over(layout.bounds()) {
+            if cfg!(target_os = "windows") {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Grab
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Range<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    fn from(range: Range<'a, T, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(range)
+    }
+}
+
+/// Returns the position of the cursor along the rail, as a fraction of the
+/// rail.
+fn percent(bounds: Rectangle, cursor_position: Point, is_vertical: bool) -> f64 {
+    let percent = if is_vertical {
+        1.0 - f64::from(cursor_position.y - bounds.y) / f64::from(bounds.height)
+    } else {
+        f64::from(cursor_position.x - bounds.x) / f64::from(bounds.width)
+    };
+
+    percent.clamp(0.0, 1.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    dragging: Option<Thumb>,
+    last_thumb: Thumb,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+/// One of the two handles of a [`Range`] slider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Thumb {
+    #[default]
+    Start,
+    End,
+}
+
+impl Thumb {
+    fn value<T>(self, (start, end): (T, T)) -> T {
+        match self {
+            Thumb::Start => start,
+            Thumb::End => end,
+        }
+    }
+}
@@ -0,0 +1,623 @@
+//! Highlight a widget and dim everything else; like the coach marks of an
+//! onboarding tour.
+//!
+//! A [`Spotlight`] finds its target by [`Id`] among its contents and punches
+//! a hole with rounded corners in a backdrop covering the window. A hint can
+//! be displayed next to the target; which makes it easy to step through a
+//! sequence of them.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{button, column, container, row, spotlight, text};
+//!
+//! const TOUR: &[(&str, &str)] = &[
+//!     ("library", "Your games live here"),
+//!     ("store", "Find new games in the store"),
+//! ];
+//!
+//! struct State {
+//!     step: Option<usize>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Next,
+//!     Skip,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let content = row![
+//!         container("Library").id("library"),
+//!         container("Store").id("store"),
+//!     ]
+//!     .spacing(10);
+//!
+//!     let Some((target, hint)) = state.step.and_then(|step| TOUR.get(step)) else {
+//!         return content.into();
+//!     };
+//!
+//!     spotlight(*target, content)
+//!         .hint(
+//!             container(column![text(*hint), button("Next").on_press(Message::Next)])
+//!                 .padding(10)
+//!                 .style(container::rounded_box),
+//!         )
+//!         .on_dismiss(Message::Skip)
+//!         .into()
+//! }
+//! ```
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation, Outcome};
+use crate::core::widget::{self, Id, Widget};
+use crate::core::{
+    self, Clipboard, Color, Element, Event, Length, Pixels, Rectangle, Shell, Size, Theme, Vector,
+};
+use crate::popover::{Placement, Positioning};
+
+/// A widget that highlights one of its descendants and dims everything
+/// else.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, column, container, spotlight, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Play,
+///     Dismiss,
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     spotlight(
+///         "play",
+///         column![
+///             text("Celeste"),
+///             container(button("Play").on_press(Message::Play)).id("play"),
+///         ],
+///     )
+///     .hint(text("Press to start playing"))
+///     .on_dismiss(Message::Dismiss)
+///     .into()
+/// }
+/// ```
+pub struct Spotlight<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    target: Id,
+    content: Element<'a, Message, Theme, Renderer>,
+    hint: Option<Element<'a, Message, Theme, Renderer>>,
+    padding: f32,
+    positioning: Positioning,
+    on_dismiss: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Spotlight<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The default padding around the target of a [`Spotlight`].
+    pub const DEFAULT_PADDING: f32 = 8.0;
+
+    /// The default gap between the target and the hint of a [`Spotlight`].
+    pub const DEFAULT_GAP: f32 = 12.0;
+
+    /// Creates a new [`Spotlight`] highlighting the widget with the given
+    /// [`Id`] among the given content.
+    pub fn new(
+        target: impl Into<Id>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            content: content.into(),
+            hint: None,
+            padding: Self::DEFAULT_PADDING,
+            positioning: Positioning {
+                gap: Self::DEFAULT_GAP,
+                ..Positioning::default()
+            },
+            on_dismiss: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the hint displayed next to the target of the [`Spotlight`].
+    pub fn hint(mut self, hint: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Sets the preferred [`Placement`] of the hint of the [`Spotlight`].
+    ///
+    /// The hint is flipped and shifted to stay within the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.positioning.placement = placement;
+        self
+    }
+
+    /// Sets the gap between the target and the hint of the [`Spotlight`].
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.positioning.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the space between the target and the edges of the hole in the
+    /// backdrop of the [`Spotlight`].
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    /// Sets the message produced when the backdrop of the [`Spotlight`] is
+    /// pressed or `Escape` is pressed.
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Spotlight`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Spotlight`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Spotlight<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<widget::Tree> {
+        let mut children = vec![widget::Tree::new(&self.content)];
+        children.extend(self.hint.as_ref().map(widget::Tree::new));
+        children
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        match &self.hint {
+            Some(hint) => tree.diff_children(&[self.content.as_widget(), hint.as_widget()]),
+            None => tree.diff_children(&[self.content.as_widget()]),
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = tree.children.iter_mut();
+        let content_tree = children.next().unwrap();
+
+        // The target is found with the same operation that queries the
+        // bounds of a widget at runtime
+        let mut find = operation::bounds::find(self.target.clone());
+
+        self.content.as_widget_mut().operate(
+            content_tree,
+            layout,
+            renderer,
+            &mut operation::black_box(&mut find),
+        );
+
+        let target = match find.finish() {
+            Outcome::Some(bounds) => Some(bounds.bounds + translation),
+            _ => None,
+        };
+
+        let content = self.content.as_widget_mut().overlay(
+            content_tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let spotlight = target.map(|target| {
+            overlay::Element::new(Box::new(Overlay {
+                target: target.expand(self.padding),
+                hint: self.hint.as_mut().zip(children.next()),
+                positioning: self.positioning,
+                on_dismiss: &mut self.on_dismiss,
+                class: &self.class,
+            }))
+        });
+
+        if content.is_some() || spotlight.is_some() {
+            Some(
+                overlay::Group::with_children(content.into_iter().chain(spotlight).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spotlight<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(spotlight: Spotlight<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(spotlight)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    target: Rectangle,
+    hint: Option<(
+        &'b mut Element<'a, Message, Theme, Renderer>,
+        &'b mut widget::Tree,
+    )>,
+    positioning: Positioning,
+    on_dismiss: &'b mut Option<Message>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let viewport = Rectangle::with_size(bounds);
+
+        let children = self
+            .hint
+            .as_mut()
+            .map(|(hint, tree)| {
+                let node = hint.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, bounds),
+                );
+
+                let placed = self.positioning.place(self.target, node.size(), viewport);
+
+                node.move_to(placed.bounds.position())
+            })
+            .into_iter()
+            .collect();
+
+        layout::Node::with_children(bounds, children)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(((hint, tree), layout)) = self.hint.as_mut().zip(layout.children().next()) {
+            hint.as_widget_mut().update(
+                tree,
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &layout.bounds(),
+            );
+
+            if cursor.is_over(layout.bounds()) {
+                return;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(self.target) =>
+            {
+                if let Some(on_dismiss) = self.on_dismiss.take() {
+                    shell.publish(on_dismiss);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                if let Some(on_dismiss) = self.on_dismiss.take() {
+                    shell.publish(on_dismiss);
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let Some(((hint, tree), layout)) = self.hint.as_ref().zip(layout.children().next())
+            && cursor.is_over(layout.bounds())
+        {
+            return hint
+                .as_widget()
+                .mouse_interaction(tree, layout, cursor, &layout.bounds(), renderer)
+                .max(mouse::Interaction::Idle);
+        }
+
+        // The target stays interactive through the hole in the backdrop
+        if cursor.is_over(self.target) {
+            mouse::Interaction::None
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class);
+
+        draw_backdrop(renderer, bounds, self.target, appearance);
+
+        if let Some(((hint, tree), layout)) = self.hint.as_ref().zip(layout.children().next()) {
+            hint.as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, &bounds);
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let ((hint, tree), layout) = self.hint.as_mut().zip(layout.children().next())?;
+
+        hint.as_widget_mut()
+            .overlay(tree, layout, renderer, &layout.bounds(), Vector::ZERO)
+    }
+}
+
+/// Draws a backdrop covering the given viewport with a hole over the given
+/// target.
+///
+/// The hole is the inside of the border of a quad large enough to cover the
+/// viewport from anywhere in it; so its rounded corners are antialiased like
+/// any other quad.
+fn draw_backdrop<Renderer>(
+    renderer: &mut Renderer,
+    viewport: Rectangle,
+    target: Rectangle,
+    style: Style,
+) where
+    Renderer: core::Renderer,
+{
+    let width = (viewport.width + viewport.height) * 2.0;
+    let radius = style.border.radius;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: target.expand(width),
+            border: Border {
+                color: style.backdrop,
+                width,
+                radius: border::Radius {
+                    top_left: radius.top_left + width,
+                    top_right: radius.top_right + width,
+                    bottom_right: radius.bottom_right + width,
+                    bottom_left: radius.bottom_left + width,
+                },
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+
+    if style.border.width > 0.0 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: target,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+/// The appearance of a [`Spotlight`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the backdrop dimming everything but the target.
+    pub backdrop: Color,
+    /// The [`Border`] drawn around the target.
+    ///
+    /// Its radius rounds the corners of the hole in the backdrop.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`Spotlight`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Spotlight`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Spotlight`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.6),
+        border: Border {
+            color: palette.primary.base.color,
+            width: 2.0,
+            radius: 8.0.into(),
+        },
+    }
+}
+
+/// A [`Spotlight`] style without a border around the target.
+pub fn plain(_theme: &Theme) -> Style {
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.6),
+        border: border::rounded(8),
+    }
+}
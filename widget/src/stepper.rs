@@ -0,0 +1,579 @@
+//! Steppers display the progress of a flow split in multiple steps.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::stepper;
+//!
+//! struct State {
+//!    step: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     StepSelected(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     stepper(["Account", "Profile", "Done"], state.step)
+//!         .on_select(Message::StepSelected)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::StepSelected(step) => {
+//!             state.step = step;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::Tree;
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Widget,
+};
+
+/// A row of numbered markers joined by connectors that displays the
+/// progress of a flow split in multiple steps.
+///
+/// Steps before the current one are completed; and, if enabled, any
+/// step can be selected by clicking its marker or label.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::stepper;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     StepSelected(usize),
+/// }
+///
+/// fn view(step: usize) -> Element<'static, Message> {
+///     // Only allow going back to completed steps
+///     stepper(["Cart", "Shipping", "Payment", "Review"], step)
+///         .on_select(move |selected| Message::StepSelected(selected.min(step)))
+///         .marker_size(32)
+///         .into()
+/// }
+/// ```
+pub struct Stepper<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    labels: Vec<String>,
+    current: usize,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    marker_size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    last_hovered: Option<Option<usize>>,
+}
+
+impl<'a, Message, Theme, Renderer> Stepper<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default size of the markers of a [`Stepper`].
+    pub const DEFAULT_MARKER_SIZE: f32 = 24.0;
+
+    /// Creates a new [`Stepper`] with the given step labels and the index of
+    /// the current step.
+    ///
+    /// An index past the last step marks every step as completed.
+    pub fn new(labels: impl IntoIterator<Item = impl Into<String>>, current: usize) -> Self {
+        Stepper {
+            labels: labels.into_iter().map(Into::into).collect(),
+            current,
+            on_select: None,
+            width: Length::Fill,
+            marker_size: Self::DEFAULT_MARKER_SIZE,
+            spacing: 6.0,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+            last_hovered: None,
+        }
+    }
+
+    /// Sets the message that should be produced when a user selects a step.
+    ///
+    /// If this method is not called, the steps of the [`Stepper`] will not
+    /// be clickable.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when a user selects a step,
+    /// if `Some`.
+    pub fn on_select_maybe(mut self, on_select: Option<impl Fn(usize) -> Message + 'a>) -> Self {
+        self.on_select = on_select.map(|on_select| Box::new(on_select) as _);
+        self
+    }
+
+    /// Sets the width of the [`Stepper`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the size of the markers of the [`Stepper`].
+    pub fn marker_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.marker_size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the markers and the labels of the [`Stepper`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Stepper`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the [`Stepper`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Stepper`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Stepper`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn step(&self, index: usize) -> Step {
+        if index < self.current {
+            Step::Completed
+        } else if index == self.current {
+            Step::Current
+        } else {
+            Step::Upcoming
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Stepper<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let labels = if self.labels.iter().any(|label| !label.is_empty()) {
+            self.spacing + text::LineHeight::default().to_absolute(text_size).0
+        } else {
+            0.0
+        };
+
+        let count = self.labels.len();
+        let size = limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(
+                count as f32 * self.marker_size * 3.0,
+                self.marker_size + labels,
+            ),
+        );
+
+        let slot = size.width / count.max(1) as f32;
+
+        layout::Node::with_children(
+            size,
+            (0..count)
+                .map(|i| {
+                    layout::Node::new(Size::new(slot, size.height))
+                        .move_to(Point::new(i as f32 * slot, 0.0))
+                })
+                .collect(),
+        )
+    }
+
+    fn update(
+        &mut self,
+        _tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let hovered = layout
+            .children()
+            .position(|step| cursor.is_over(step.bounds()));
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) && let Some(index) = hovered
+        {
+            shell.publish(on_select(index));
+            shell.capture_event();
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_hovered = Some(hovered);
+        } else if self
+            .last_hovered
+            .is_some_and(|last_hovered| last_hovered != hovered)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_select.is_some() && layout.children().any(|step| cursor.is_over(step.bounds())) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let hovered = self.last_hovered.flatten();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let status = |index: usize| {
+            let step = self.step(index);
+
+            if self.on_select.is_none() {
+                Status::Disabled { step }
+            } else if hovered == Some(index) {
+                Status::Hovered { step }
+            } else {
+                Status::Active { step }
+            }
+        };
+
+        let markers: Vec<Rectangle> = layout
+            .children()
+            .map(|slot| {
+                let bounds = slot.bounds();
+
+                Rectangle {
+                    x: bounds.center_x() - self.marker_size / 2.0,
+                    y: bounds.y,
+                    width: self.marker_size,
+                    height: self.marker_size,
+                }
+            })
+            .collect();
+
+        // Connectors are drawn first, so markers can overlap them
+        for (i, pair) in markers.windows(2).enumerate() {
+            let style = theme.style(&self.class, status(i + 1));
+
+            let (from, to) = (pair[0], pair[1]);
+            let gap = self.marker_size / 4.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: from.x + from.width + gap,
+                        y: from.center_y() - style.connector_width / 2.0,
+                        width: (to.x - from.x - from.width - 2.0 * gap).max(0.0),
+                        height: style.connector_width,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.connector,
+            );
+        }
+
+        for (i, ((label, slot), marker)) in self
+            .labels
+            .iter()
+            .zip(layout.children())
+            .zip(&markers)
+            .enumerate()
+        {
+            let style = theme.style(&self.class, status(i));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: *marker,
+                    border: Border {
+                        radius: border::Radius::new(self.marker_size / 2.0),
+                        ..style.marker_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.marker,
+            );
+
+            let content = match self.step(i) {
+                Step::Completed => String::from("✓"),
+                Step::Current | Step::Upcoming => (i + 1).to_string(),
+            };
+
+            renderer.fill_text(
+                Text {
+                    content,
+                    bounds: marker.size(),
+                    size: Pixels(self.marker_size / 2.0),
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                marker.center(),
+                style.marker_text,
+                *viewport,
+            );
+
+            if label.is_empty() {
+                continue;
+            }
+
+            let bounds = slot.bounds();
+
+            renderer.fill_text(
+                Text {
+                    content: label.clone(),
+                    bounds: Size::new(bounds.width, bounds.height - self.marker_size),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::default(),
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                Point::new(
+                    bounds.center_x(),
+                    bounds.y + self.marker_size + self.spacing,
+                ),
+                style.label,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Stepper<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        stepper: Stepper<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(stepper)
+    }
+}
+
+/// The progress of a step of a [`Stepper`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The step comes before the current one.
+    Completed,
+    /// The step is the current one.
+    Current,
+    /// The step comes after the current one.
+    Upcoming,
+}
+
+/// The possible status of a step of a [`Stepper`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The step can be interacted with.
+    Active {
+        /// The progress of the step.
+        step: Step,
+    },
+    /// The step is being hovered.
+    Hovered {
+        /// The progress of the step.
+        step: Step,
+    },
+    /// The step cannot be selected.
+    Disabled {
+        /// The progress of the step.
+        step: Step,
+    },
+}
+
+/// The appearance of a step of a stepper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the marker of the step.
+    pub marker: Background,
+    /// The [`Border`] of the marker of the step.
+    ///
+    /// Its radius is ignored; markers are always round.
+    pub marker_border: Border,
+    /// The [`Color`] of the number or check mark inside the marker.
+    pub marker_text: Color,
+    /// The [`Color`] of the label of the step.
+    pub label: Color,
+    /// The [`Background`] of the connector leading to the step.
+    pub connector: Background,
+    /// The width of the connector leading to the step.
+    pub connector_width: f32,
+}
+
+/// The theme catalog of a [`Stepper`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Stepper`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Stepper`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let (step, is_hovered) = match status {
+        Status::Active { step } | Status::Disabled { step } => (step, false),
+        Status::Hovered { step } => (step, true),
+    };
+
+    let primary = if is_hovered {
+        palette.primary.strong
+    } else {
+        palette.primary.base
+    };
+
+    let (marker, marker_border, marker_text, label, connector) = match step {
+        Step::Completed => (
+            primary.color,
+            Color::TRANSPARENT,
+            primary.text,
+            palette.background.base.text,
+            palette.primary.base.color,
+        ),
+        Step::Current => (
+            palette.background.base.color,
+            primary.color,
+            primary.color,
+            palette.background.base.text,
+            palette.primary.base.color,
+        ),
+        Step::Upcoming => (
+            if is_hovered {
+                palette.background.strong.color
+            } else {
+                palette.background.weak.color
+            },
+            Color::TRANSPARENT,
+            palette.background.weak.text,
+            palette.background.strong.color,
+            palette.background.strong.color,
+        ),
+    };
+
+    Style {
+        marker: marker.into(),
+        marker_border: Border {
+            color: marker_border,
+            width: 2.0,
+            radius: border::Radius::default(),
+        },
+        marker_text,
+        label,
+        connector: connector.into(),
+        connector_width: 2.0,
+    }
+}
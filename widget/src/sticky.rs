@@ -0,0 +1,447 @@
+//! Keep a widget in view while its original position is scrolled past.
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size,
+    Transformation, Vector, Widget,
+};
+
+/// A widget that keeps its contents within the viewport of the nearest
+/// scrollable while its original position is scrolled past.
+///
+/// Each edge of a [`Sticky`] widget can be given a distance to the same edge
+/// of the viewport; its contents will never cross it. By default, a [`Sticky`]
+/// widget sticks to the top edge of the viewport.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, column, scrollable, sticky, text};
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     Install,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     scrollable(column![
+///         sticky(text("Achievements")),
+///         text("..."),
+///         sticky(button("Install").on_press(Message::Install)).bottom(20),
+///     ])
+///     .into()
+/// }
+/// ```
+pub struct Sticky<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    edges: Edges,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Edges {
+    top: Option<f32>,
+    bottom: Option<f32>,
+    left: Option<f32>,
+    right: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a [`Sticky`] widget with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            edges: Edges::default(),
+        }
+    }
+
+    /// Makes the [`Sticky`] widget stick at the given distance from the top
+    /// edge of the viewport.
+    pub fn top(mut self, distance: impl Into<Pixels>) -> Self {
+        self.edges.top = Some(distance.into().0);
+        self
+    }
+
+    /// Makes the [`Sticky`] widget stick at the given distance from the
+    /// bottom edge of the viewport.
+    pub fn bottom(mut self, distance: impl Into<Pixels>) -> Self {
+        self.edges.bottom = Some(distance.into().0);
+        self
+    }
+
+    /// Makes the [`Sticky`] widget stick at the given distance from the left
+    /// edge of the viewport.
+    pub fn left(mut self, distance: impl Into<Pixels>) -> Self {
+        self.edges.left = Some(distance.into().0);
+        self
+    }
+
+    /// Makes the [`Sticky`] widget stick at the given distance from the
+    /// right edge of the viewport.
+    pub fn right(mut self, distance: impl Into<Pixels>) -> Self {
+        self.edges.right = Some(distance.into().0);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Sticky<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn is_stuck(&self, bounds: Rectangle, viewport: &Rectangle) -> bool {
+        self.edges.offset(bounds, viewport) != Vector::ZERO
+    }
+}
+
+impl Edges {
+    /// Returns the translation that keeps the given bounds within the
+    /// edges of the viewport.
+    fn offset(self, bounds: Rectangle, viewport: &Rectangle) -> Vector {
+        let Edges {
+            top,
+            bottom,
+            left,
+            right,
+        } = self;
+
+        let is_default = top.is_none() && bottom.is_none() && left.is_none() && right.is_none();
+        let top = if is_default { Some(0.0) } else { top };
+
+        // When both edges are crossed, the top and left edges win
+        let y = stick(
+            bounds.y,
+            bounds.height,
+            viewport.y,
+            viewport.height,
+            top,
+            bottom,
+        );
+
+        let x = stick(
+            bounds.x,
+            bounds.width,
+            viewport.x,
+            viewport.width,
+            left,
+            right,
+        );
+
+        Vector::new(x, y)
+    }
+}
+
+fn stick(
+    position: f32,
+    length: f32,
+    start: f32,
+    extent: f32,
+    near: Option<f32>,
+    far: Option<f32>,
+) -> f32 {
+    let mut offset = 0.0;
+
+    if let Some(far) = far {
+        offset = (start + extent - far - (position + length)).min(0.0);
+    }
+
+    if let Some(near) = near {
+        offset = offset.max(start + near - position);
+    }
+
+    offset
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sticky<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if self.is_stuck(layout.bounds(), viewport) {
+            return;
+        }
+
+        self.content.as_widget_mut().update(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_stuck(layout.bounds(), viewport) {
+            return mouse::Interaction::None;
+        }
+
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.is_stuck(layout.bounds(), viewport) {
+            return;
+        }
+
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = self.edges.offset(layout.bounds() + translation, viewport);
+
+        // Stuck contents are displayed as an overlay, since parents skip
+        // drawing children out of view
+        if offset == Vector::ZERO {
+            return self.content.as_widget_mut().overlay(
+                tree,
+                layout,
+                renderer,
+                viewport,
+                translation,
+            );
+        }
+
+        let translation = translation + offset;
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            content: &mut self.content,
+            tree,
+            layout,
+            viewport: *viewport,
+            transformation: Transformation::translate(translation.x, translation.y),
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sticky<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(sticky: Sticky<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(sticky)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    content: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut widget::Tree,
+    layout: Layout<'a>,
+    viewport: Rectangle,
+    transformation: Transformation,
+}
+
+impl<Message, Theme, Renderer> core::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> layout::Node {
+        let bounds = self.layout.bounds() * self.transformation;
+
+        layout::Node::new(bounds.size()).move_to(bounds.position())
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let inverse = self.transformation.inverse();
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            self.layout,
+            cursor * inverse,
+            renderer,
+            clipboard,
+            shell,
+            &(self.viewport * inverse),
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let inverse = self.transformation.inverse();
+
+        renderer.with_layer(self.viewport, |renderer| {
+            renderer.with_transformation(self.transformation, |renderer| {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    self.layout,
+                    cursor * inverse,
+                    &(self.viewport * inverse),
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::None;
+        }
+
+        let inverse = self.transformation.inverse();
+
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            self.layout,
+            cursor * inverse,
+            &(self.viewport * inverse),
+            renderer,
+        )
+    }
+
+    fn overlay<'a>(
+        &'a mut self,
+        _layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'a, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            self.layout,
+            renderer,
+            &(self.viewport * self.transformation.inverse()),
+            self.transformation.translation(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_sticks_to_the_edges_of_the_viewport() {
+        let viewport = Rectangle::new([0.0, 500.0].into(), Size::new(400.0, 300.0));
+        let header = Rectangle::new([0.0, 100.0].into(), Size::new(400.0, 40.0));
+        let fab = Rectangle::new([300.0, 2000.0].into(), Size::new(60.0, 60.0));
+
+        assert_eq!(
+            Edges::default().offset(header, &viewport),
+            Vector::new(0.0, 400.0)
+        );
+
+        assert_eq!(
+            Edges::default().offset(header + Vector::new(0.0, 600.0), &viewport),
+            Vector::ZERO
+        );
+
+        let bottom = Edges {
+            bottom: Some(20.0),
+            ..Edges::default()
+        };
+
+        assert_eq!(bottom.offset(fab, &viewport), Vector::new(0.0, -1280.0));
+        assert_eq!(bottom.offset(header, &viewport), Vector::ZERO);
+    }
+}
@@ -1,10 +1,18 @@
 //! Draw and interact with text.
+pub mod spell;
+
 mod rich;
 
-pub use crate::core::text::{Fragment, Highlighter, IntoFragment, Span};
+pub use crate::core::text::{Ellipsis, Fragment, Highlighter, IntoFragment, Span, link};
 pub use crate::core::widget::text::*;
 pub use rich::Rich;
 
+use crate::core::{self, Font, Pixels, Size};
+
+use std::borrow::Cow;
+
+type Paragraph = <crate::Renderer as core::text::Renderer>::Paragraph;
+
 /// A bunch of text.
 ///
 /// # Example
@@ -28,3 +36,62 @@ pub use rich::Rich;
 /// ```
 pub type Text<'a, Theme = crate::Theme, Renderer = crate::Renderer> =
     crate::core::widget::Text<'a, Theme, Renderer>;
+
+/// Measures some text with the given font and size, wrapped at the given
+/// maximum width, without building any widget.
+///
+/// The text is shaped by the same engine the default renderer uses, so the
+/// measured [`Size`] matches the one a [`Text`] widget would have.
+///
+/// # Example
+/// ```no_run
+/// use iced_widget::core::Font;
+/// use iced_widget::text;
+///
+/// let size = text::measure("Hello, this is iced!", Font::DEFAULT, 16, f32::INFINITY);
+///
+/// assert!(size.width > 0.0);
+/// ```
+pub fn measure(content: &str, font: Font, size: impl Into<Pixels>, max_width: f32) -> Size {
+    core::text::measure::<Paragraph>(plain(content, font, size.into(), max_width))
+}
+
+/// Truncates some text to a single line that fits the given maximum width
+/// when drawn with the given font and size.
+///
+/// The [`Ellipsis`] decides which part of the text is kept.
+///
+/// # Example
+/// ```no_run
+/// use iced_widget::core::Font;
+/// use iced_widget::text::{self, Ellipsis};
+///
+/// let path = "/home/ferris/Documents/projects/iced/src/main.rs";
+/// let truncated = text::truncate(path, Font::MONOSPACE, 14, 200.0, Ellipsis::Middle);
+///
+/// assert!(truncated.contains('…'));
+/// ```
+pub fn truncate(
+    content: &str,
+    font: Font,
+    size: impl Into<Pixels>,
+    max_width: f32,
+    ellipsis: Ellipsis,
+) -> Cow<'_, str> {
+    core::text::truncate::<Paragraph>(plain(content, font, size.into(), max_width), ellipsis)
+}
+
+fn plain(content: &str, font: Font, size: Pixels, max_width: f32) -> core::Text<&str> {
+    core::Text {
+        content,
+        bounds: Size::new(max_width, f32::INFINITY),
+        size,
+        line_height: LineHeight::default(),
+        font,
+        align_x: Alignment::Default,
+        align_y: core::alignment::Vertical::Top,
+        shaping: Shaping::default(),
+        wrapping: Wrapping::default(),
+        hint_factor: None,
+    }
+}
@@ -1,8 +1,9 @@
 use crate::core::alignment;
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::text::{Paragraph, Span};
+use crate::core::text::{Hyphenation, Paragraph, Span, link};
 use crate::core::widget::text::{
     self, Alignment, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
 };
@@ -28,9 +29,10 @@ where
     align_x: Alignment,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    hyphenation: Option<&'static Hyphenation>,
     class: Theme::Class<'a>,
     hovered_link: Option<usize>,
-    on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    on_link: Option<Box<dyn Fn(Link, link::Click) -> Message + 'a>>,
 }
 
 impl<'a, Link, Message, Theme, Renderer> Rich<'a, Link, Message, Theme, Renderer>
@@ -52,9 +54,10 @@ where
             align_x: Alignment::Default,
             align_y: alignment::Vertical::Top,
             wrapping: Wrapping::default(),
+            hyphenation: None,
             class: Theme::default(),
             hovered_link: None,
-            on_link_click: None,
+            on_link: None,
         }
     }
 
@@ -120,14 +123,47 @@ where
         self
     }
 
+    /// Sets the [`Hyphenation`] dictionary of the [`Rich`] text.
+    ///
+    /// Words will be broken across lines where the dictionary allows it,
+    /// and a hyphen will be drawn at the end of each broken line.
+    pub fn hyphenation(mut self, hyphenation: &'static Hyphenation) -> Self {
+        self.hyphenation = Some(hyphenation);
+        self
+    }
+
     /// Sets the message that will be produced when a link of the [`Rich`] text
     /// is clicked.
     ///
     /// If the spans of the [`Rich`] text contain no links, you may need to call
     /// this method with `on_link_click(never)` in order for the compiler to infer
     /// the proper `Link` generic type.
-    pub fn on_link_click(mut self, on_link_click: impl Fn(Link) -> Message + 'a) -> Self {
-        self.on_link_click = Some(Box::new(on_link_click));
+    pub fn on_link_click(self, on_link_click: impl Fn(Link) -> Message + 'a) -> Self {
+        self.on_link(move |link, _click| on_link_click(link))
+    }
+
+    /// Sets the message that will be produced when a link of the [`Rich`] text
+    /// is clicked, together with the [`link::Click`] describing how.
+    ///
+    /// Links can be clicked with both the left and the middle mouse buttons.
+    /// Use [`link::Click::is_auxiliary`] to tell apart middle clicks and
+    /// command clicks, which usually open a link in the background.
+    pub fn on_link(mut self, on_link: impl Fn(Link, link::Click) -> Message + 'a) -> Self {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Turns every URL and email address in the spans of the [`Rich`] text
+    /// into a link.
+    ///
+    /// Spans that already have a link are left untouched.
+    pub fn linkify(mut self) -> Self
+    where
+        Link: From<String>,
+    {
+        let spans = link::linkify(self.spans.as_ref().as_ref());
+
+        self.spans = Box::new(spans);
         self
     }
 
@@ -156,7 +192,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            selection: None,
+        })
     }
 
     /// Sets the default style class of the [`Rich`] text.
@@ -182,7 +221,8 @@ where
 
 struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
-    span_pressed: Option<usize>,
+    span_pressed: Option<(usize, mouse::Button)>,
+    modifiers: keyboard::Modifiers,
     paragraph: P,
 }
 
@@ -201,6 +241,7 @@ where
         tree::State::new(State::<Link, _> {
             spans: Vec::new(),
             span_pressed: None,
+            modifiers: keyboard::Modifiers::default(),
             paragraph: Renderer::Paragraph::default(),
         })
     }
@@ -232,6 +273,7 @@ where
             self.align_x,
             self.align_y,
             self.wrapping,
+            self.hyphenation,
         )
     }
 
@@ -256,7 +298,7 @@ where
         let style = theme.style(&self.class);
 
         for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
-            let is_hovered_link = self.on_link_click.is_some() && Some(index) == self.hovered_link;
+            let is_hovered_link = self.on_link.is_some() && Some(index) == self.hovered_link;
 
             if span.highlight.is_some() || span.underline || span.strikethrough || is_hovered_link {
                 let translation = layout.position() - Point::ORIGIN;
@@ -349,7 +391,7 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
-        let Some(on_link_clicked) = &self.on_link_click else {
+        let Some(on_link) = &self.on_link else {
             return;
         };
 
@@ -376,23 +418,29 @@ where
         }
 
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            Event::Mouse(mouse::Event::ButtonPressed(
+                button @ (mouse::Button::Left | mouse::Button::Middle),
+            )) => {
                 let state = tree
                     .state
                     .downcast_mut::<State<Link, Renderer::Paragraph>>();
 
-                if self.hovered_link.is_some() {
-                    state.span_pressed = self.hovered_link;
+                if let Some(span) = self.hovered_link {
+                    state.span_pressed = Some((span, *button));
                     shell.capture_event();
                 }
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+            Event::Mouse(mouse::Event::ButtonReleased(
+                button @ (mouse::Button::Left | mouse::Button::Middle),
+            )) => {
                 let state = tree
                     .state
                     .downcast_mut::<State<Link, Renderer::Paragraph>>();
 
                 match state.span_pressed {
-                    Some(span) if Some(span) == self.hovered_link => {
+                    Some((span, pressed))
+                        if pressed == *button && Some(span) == self.hovered_link =>
+                    {
                         if let Some(link) = self
                             .spans
                             .as_ref()
@@ -400,7 +448,13 @@ where
                             .get(span)
                             .and_then(|span| span.link.clone())
                         {
-                            shell.publish(on_link_clicked(link));
+                            shell.publish(on_link(
+                                link,
+                                link::Click {
+                                    button: *button,
+                                    modifiers: state.modifiers,
+                                },
+                            ));
                         }
                     }
                     _ => {}
@@ -408,6 +462,13 @@ where
 
                 state.span_pressed = None;
             }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                let state = tree
+                    .state
+                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+                state.modifiers = *modifiers;
+            }
             _ => {}
         }
     }
@@ -441,6 +502,7 @@ fn layout<Link, Renderer>(
     align_x: Alignment,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    hyphenation: Option<&'static Hyphenation>,
 ) -> layout::Node
 where
     Link: Clone,
@@ -452,21 +514,37 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let text_with_spans = || core::Text {
-            content: spans,
-            bounds,
-            size,
-            line_height,
-            font,
-            align_x,
-            align_y,
-            shaping: Shaping::Advanced,
-            wrapping,
-            hint_factor: renderer.scale_factor(),
+        let paragraph = || {
+            let text = core::Text {
+                content: spans,
+                bounds,
+                size,
+                line_height,
+                font,
+                align_x,
+                align_y,
+                shaping: Shaping::Advanced,
+                wrapping,
+                hint_factor: renderer.scale_factor(),
+            };
+
+            let Some(hyphenation) = hyphenation else {
+                return Renderer::Paragraph::with_spans(text);
+            };
+
+            let hyphenated: Vec<_> = spans
+                .iter()
+                .map(|span| Span {
+                    text: hyphenation.hyphenate(&span.text).into_owned().into(),
+                    ..span.clone()
+                })
+                .collect();
+
+            Renderer::Paragraph::with_spans(text.with_content(hyphenated.as_slice()))
         };
 
         if state.spans != spans {
-            state.paragraph = Renderer::Paragraph::with_spans(text_with_spans());
+            state.paragraph = paragraph();
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
         } else {
             match state.paragraph.compare(core::Text {
@@ -486,7 +564,7 @@ where
                     state.paragraph.resize(bounds);
                 }
                 core::text::Difference::Shape => {
-                    state.paragraph = Renderer::Paragraph::with_spans(text_with_spans());
+                    state.paragraph = paragraph();
                 }
             }
         }
@@ -0,0 +1,195 @@
+//! Check the spelling of text.
+#[cfg(feature = "hunspell")]
+pub mod hunspell;
+
+#[cfg(feature = "hunspell")]
+pub use hunspell::Hunspell;
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A spell checker.
+pub trait Checker {
+    /// Returns true if the given word is spelled correctly.
+    fn check(&self, word: &str) -> bool;
+
+    /// Returns the suggested corrections of the given word, from best
+    /// to worst.
+    fn suggest(&self, word: &str) -> Vec<String>;
+}
+
+/// A misspelled word, together with its suggested corrections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// The misspelled word.
+    pub word: String,
+    /// The line of the misspelled word.
+    ///
+    /// This is always `0` for single-line text.
+    pub line: usize,
+    /// The byte range of the misspelled word in its line.
+    pub range: Range<usize>,
+    /// The suggested corrections of the word, from best to worst.
+    pub suggestions: Vec<String>,
+}
+
+/// Returns the byte ranges of the misspelled words of the given text.
+///
+/// Words containing digits are ignored.
+pub fn misspelled(checker: &dyn Checker, text: &str) -> Vec<Range<usize>> {
+    text.split_word_bound_indices()
+        .filter(|(_, word)| {
+            word.chars().any(char::is_alphabetic) && !word.chars().any(char::is_numeric)
+        })
+        .filter(|(_, word)| !checker.check(word))
+        .map(|(start, word)| start..start + word.len())
+        .collect()
+}
+
+/// Returns the [`Misspelling`] at the given byte offset of a line, if any.
+pub fn misspelling_at(
+    checker: &dyn Checker,
+    line: usize,
+    text: &str,
+    offset: usize,
+) -> Option<Misspelling> {
+    let range = misspelled(checker, text)
+        .into_iter()
+        .find(|range| range.start <= offset && offset <= range.end)?;
+
+    let word = text[range.clone()].to_owned();
+
+    Some(Misspelling {
+        suggestions: checker.suggest(&word),
+        word,
+        line,
+        range,
+    })
+}
+
+/// A custom dictionary of words.
+///
+/// A [`Dictionary`] is a [`Checker`] on its own, but it is mostly useful
+/// to extend another [`Checker`] with the words of a user or a domain.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    words: BTreeSet<String>,
+}
+
+impl Dictionary {
+    /// Creates a new empty [`Dictionary`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a [`Dictionary`] with one word per line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    pub fn parse(words: &str) -> Self {
+        words
+            .lines()
+            .map(str::trim)
+            .filter(|word| !word.is_empty() && !word.starts_with('#'))
+            .collect()
+    }
+
+    /// Adds a word to the [`Dictionary`].
+    pub fn insert(&mut self, word: impl Into<String>) {
+        let _ = self.words.insert(word.into());
+    }
+
+    /// Removes a word from the [`Dictionary`].
+    pub fn remove(&mut self, word: &str) {
+        let _ = self.words.remove(word);
+    }
+
+    /// Returns true if the [`Dictionary`] contains the given word.
+    ///
+    /// Capitalized and uppercase variants of lowercase words are
+    /// considered contained as well.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(word) || self.words.contains(&word.to_lowercase())
+    }
+
+    /// Returns an iterator over the words of the [`Dictionary`].
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(String::as_str)
+    }
+
+    /// Returns true if the [`Dictionary`] has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl Checker for Dictionary {
+    fn check(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+        let word = word.to_lowercase();
+
+        let mut suggestions: Vec<(usize, &String)> = self
+            .words
+            .iter()
+            .map(|candidate| (distance(&word, &candidate.to_lowercase()), candidate))
+            .filter(|(distance, _)| *distance <= 2)
+            .collect();
+
+        suggestions.sort_by_key(|(distance, _)| *distance);
+
+        suggestions
+            .into_iter()
+            .map(|(_, candidate)| candidate.clone())
+            .collect()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Dictionary {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(words: T) -> Self {
+        Self {
+            words: words.into_iter().map(str::to_owned).collect(),
+        }
+    }
+}
+
+impl FromIterator<String> for Dictionary {
+    fn from_iter<T: IntoIterator<Item = String>>(words: T) -> Self {
+        Self {
+            words: words.into_iter().collect(),
+        }
+    }
+}
+
+/// Returns the Damerau-Levenshtein distance between two words.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut before_previous = previous.clone();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before_previous[j - 2] + 1);
+            }
+        }
+
+        before_previous.clone_from(&previous);
+        previous.clone_from(&current);
+    }
+
+    previous[b.len()]
+}
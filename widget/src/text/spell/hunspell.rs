@@ -0,0 +1,156 @@
+use crate::text::spell::{Checker, Dictionary};
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A [`Checker`] backed by a Hunspell dictionary.
+///
+/// Dictionaries are parsed and checked by
+/// [`spellbook`](https://docs.rs/spellbook); both files must be encoded in
+/// UTF-8.
+pub struct Hunspell {
+    raw: spellbook::Dictionary,
+    dictionary: Dictionary,
+}
+
+impl Hunspell {
+    /// Creates a [`Hunspell`] checker from the contents of its affix
+    /// (`.aff`) and dictionary (`.dic`) files.
+    pub fn new(affixes: &str, words: &str) -> Result<Self, Error> {
+        let raw = spellbook::Dictionary::new(affixes, words)
+            .map_err(|error| Error::Parse(error.to_string()))?;
+
+        Ok(Self {
+            raw,
+            dictionary: Dictionary::new(),
+        })
+    }
+
+    /// Loads a [`Hunspell`] checker from the given affix (`.aff`) and
+    /// dictionary (`.dic`) files.
+    pub fn load(affixes: impl AsRef<Path>, words: impl AsRef<Path>) -> Result<Self, Error> {
+        let affixes = fs::read_to_string(affixes)?;
+        let words = fs::read_to_string(words)?;
+
+        Self::new(&affixes, &words)
+    }
+
+    /// Extends the [`Hunspell`] checker with a custom [`Dictionary`].
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+
+    /// Returns the custom [`Dictionary`] of the [`Hunspell`] checker.
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    /// Returns a mutable reference to the custom [`Dictionary`] of the
+    /// [`Hunspell`] checker; useful to let users add new words.
+    pub fn dictionary_mut(&mut self) -> &mut Dictionary {
+        &mut self.dictionary
+    }
+}
+
+impl Checker for Hunspell {
+    fn check(&self, word: &str) -> bool {
+        self.dictionary.contains(word) || self.raw.check(word)
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+        let mut suggestions = self.dictionary.suggest(word);
+
+        let mut candidates = Vec::new();
+        self.raw.suggest(word, &mut candidates);
+
+        for candidate in candidates {
+            if !suggestions.contains(&candidate) {
+                suggestions.push(candidate);
+            }
+        }
+
+        suggestions
+    }
+}
+
+impl fmt::Debug for Hunspell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hunspell")
+            .field("dictionary", &self.dictionary)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error loading a [`Hunspell`] checker.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// A file could not be read.
+    #[error("the dictionary could not be read: {0}")]
+    Io(std::sync::Arc<io::Error>),
+
+    /// The affix or dictionary file is invalid.
+    #[error("the dictionary is invalid: {0}")]
+    Parse(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(std::sync::Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFFIXES: &str = "\
+SET UTF-8
+TRY esianrtolcdugmphbyfvkwz
+
+PFX U Y 1
+PFX U   0     un         .
+
+SFX S Y 3
+SFX S   y     ies        [^aeiou]y
+SFX S   0     s          [aeiou]y
+SFX S   0     s          [^y]
+";
+
+    const WORDS: &str = "\
+3
+happy/U
+city/S
+toy/S
+";
+
+    #[test]
+    fn it_checks_affixed_words() {
+        let hunspell = Hunspell::new(AFFIXES, WORDS).expect("Parse dictionary");
+
+        assert!(hunspell.check("happy"));
+        assert!(hunspell.check("unhappy"));
+        assert!(hunspell.check("Cities"));
+        assert!(hunspell.check("toys"));
+        assert!(!hunspell.check("citys"));
+        assert!(!hunspell.check("uncity"));
+
+        assert_eq!(
+            hunspell.suggest("hapy").first().map(String::as_str),
+            Some("happy")
+        );
+    }
+
+    #[test]
+    fn it_extends_the_dictionary() {
+        let hunspell = Hunspell::new(AFFIXES, WORDS)
+            .expect("Parse dictionary")
+            .with_dictionary(Dictionary::parse("iced\n"));
+
+        assert!(hunspell.check("iced"));
+        assert!(!hunspell.check("icde"));
+        assert!(hunspell.suggest("icde").contains(&String::from("iced")));
+    }
+}
@@ -51,6 +51,7 @@ use crate::core::{
     Background, Border, Color, Element, Event, InputMethod, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::text::spell;
 
 use std::borrow::Cow;
 use std::cell::RefCell;
@@ -115,6 +116,8 @@ where
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    spellcheck: Option<&'a dyn spell::Checker>,
+    on_misspelling: Option<Box<dyn Fn(spell::Misspelling) -> Message + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(&Highlighter::Highlight, &Theme) -> highlighter::Format<Renderer::Font>,
     last_status: Option<Status>,
@@ -143,6 +146,8 @@ where
             class: <Theme as Catalog>::default(),
             key_binding: None,
             on_edit: None,
+            spellcheck: None,
+            on_misspelling: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| highlighter::Format::default(),
             last_status: None,
@@ -202,6 +207,28 @@ where
         self
     }
 
+    /// Sets the [`spell::Checker`] of the [`TextEditor`].
+    ///
+    /// Misspelled words will be underlined.
+    pub fn spellcheck(mut self, checker: &'a dyn spell::Checker) -> Self {
+        self.spellcheck = Some(checker);
+        self
+    }
+
+    /// Sets the message that should be produced when a misspelled word of
+    /// the [`TextEditor`] is right clicked.
+    ///
+    /// The word will be selected and the [`spell::Misspelling`] will contain
+    /// its suggested corrections; ready to be shown in a context menu.
+    /// A correction can be applied by pasting it with an [`Action::Edit`].
+    pub fn on_misspelling(
+        mut self,
+        on_misspelling: impl Fn(spell::Misspelling) -> Message + 'a,
+    ) -> Self {
+        self.on_misspelling = Some(Box::new(on_misspelling));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextEditor`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -276,6 +303,8 @@ where
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
+            spellcheck: self.spellcheck,
+            on_misspelling: self.on_misspelling,
             highlighter_settings: settings,
             highlighter_format: to_format,
             last_status: self.last_status,
@@ -690,6 +719,32 @@ where
             _ => {}
         }
 
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) = event
+            && let Some(checker) = self.spellcheck
+            && let Some(on_misspelling) = &self.on_misspelling
+            && let Some(position) = cursor.position_in(layout.bounds())
+        {
+            let position = position - Vector::new(self.padding.left, self.padding.top);
+            let editor = &self.content.0.borrow().editor;
+
+            let misspelling = editor.hit(position).and_then(|hit| {
+                let line = editor.line(hit.line)?;
+
+                spell::misspelling_at(checker, hit.line, &line.text, hit.column)
+            });
+
+            if let Some(misspelling) = misspelling {
+                state.focus = Some(Focus::now());
+
+                shell.publish(on_edit(Action::Click(position)));
+                shell.publish(on_edit(Action::SelectWord));
+                shell.publish(on_misspelling(misspelling));
+                shell.capture_event();
+
+                return;
+            }
+        }
+
         if let Some(update) = Update::from_event(
             event,
             state,
@@ -945,6 +1000,34 @@ where
 
         let translation = text_bounds.position() - Point::ORIGIN;
 
+        if let Some(checker) = self.spellcheck {
+            for index in 0..internal.editor.line_count() {
+                let Some(line) = internal.editor.line(index) else {
+                    continue;
+                };
+
+                for range in spell::misspelled(checker, &line.text) {
+                    for bounds in internal.editor.range_bounds(index, range) {
+                        let underline = Rectangle {
+                            y: bounds.y + bounds.height - 2.0,
+                            height: 1.0,
+                            ..bounds
+                        } + translation;
+
+                        if let Some(underline) = text_bounds.intersection(&underline) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: underline,
+                                    ..renderer::Quad::default()
+                                },
+                                style.misspelling,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
         if let Some(focus) = state.focus.as_ref() {
             match internal.editor.selection() {
                 Selection::Caret(position) if focus.is_cursor_visible() => {
@@ -1344,6 +1427,8 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the underline of misspelled words in the text input.
+    pub misspelling: Color,
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -1387,6 +1472,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.secondary.base.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        misspelling: palette.danger.base.color,
     };
 
     match status {
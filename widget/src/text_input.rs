@@ -41,6 +41,7 @@ pub use value::Value;
 use editor::Editor;
 
 use crate::core::alignment;
+use crate::core::animation::{self, Animation, Interpolable};
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::input_method;
 use crate::core::keyboard;
@@ -60,6 +61,7 @@ use crate::core::{
     Alignment, Background, Border, Color, Element, Event, InputMethod, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::text::spell;
 
 /// A field that can be filled with text.
 ///
@@ -111,9 +113,12 @@ where
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    spellcheck: Option<&'a dyn spell::Checker>,
+    on_misspelling: Option<Box<dyn Fn(spell::Misspelling) -> Message + 'a>>,
     icon: Option<Icon<Renderer::Font>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
+    transition: Option<Duration>,
 }
 
 /// The default [`Padding`] of a [`TextInput`].
@@ -142,9 +147,12 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            spellcheck: None,
+            on_misspelling: None,
             icon: None,
             class: Theme::default(),
             last_status: None,
+            transition: None,
         }
     }
 
@@ -206,6 +214,28 @@ where
         self
     }
 
+    /// Sets the [`spell::Checker`] of the [`TextInput`].
+    ///
+    /// Misspelled words will be underlined. Secure inputs are never
+    /// checked.
+    pub fn spellcheck(mut self, checker: &'a dyn spell::Checker) -> Self {
+        self.spellcheck = Some(checker);
+        self
+    }
+
+    /// Sets the message that should be produced when a misspelled word of
+    /// the [`TextInput`] is right clicked.
+    ///
+    /// The word will be selected and the [`spell::Misspelling`] will contain
+    /// its suggested corrections; ready to be shown in a context menu.
+    pub fn on_misspelling(
+        mut self,
+        on_misspelling: impl Fn(spell::Misspelling) -> Message + 'a,
+    ) -> Self {
+        self.on_misspelling = Some(Box::new(on_misspelling));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -250,6 +280,15 @@ where
         self
     }
 
+    /// Sets the duration of the style transitions of the [`TextInput`].
+    ///
+    /// When set, the [`Style`] of the [`TextInput`] will be interpolated over the
+    /// given duration every time its [`Status`] changes; like when it gains focus.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -431,7 +470,12 @@ where
         let mut children_layout = layout.children();
         let text_bounds = children_layout.next().unwrap().bounds();
 
-        let style = theme.style(&self.class, self.last_status.unwrap_or(Status::Disabled));
+        let style = match &state.transition {
+            Some((animation, now)) if self.transition.is_some() => {
+                animation.interpolate_with(|status| theme.style(&self.class, status), *now)
+            }
+            _ => theme.style(&self.class, self.last_status.unwrap_or(Status::Disabled)),
+        };
 
         renderer.fill_quad(
             renderer::Quad {
@@ -564,10 +608,13 @@ where
                 renderer.with_translation(Vector::ZERO, |_| {});
             }
 
+            let position =
+                text_bounds.anchor(paragraph.min_bounds(), Alignment::Start, Alignment::Center)
+                    + Vector::new(alignment_offset - offset, 0.0);
+
             renderer.fill_paragraph(
                 paragraph,
-                text_bounds.anchor(paragraph.min_bounds(), Alignment::Start, Alignment::Center)
-                    + Vector::new(alignment_offset - offset, 0.0),
+                position,
                 if text.is_empty() {
                     style.placeholder
                 } else {
@@ -575,6 +622,31 @@ where
                 },
                 viewport,
             );
+
+            if let Some(checker) = self.spellcheck.filter(|_| !self.is_secure) {
+                let top = position.y - paragraph.min_height() / 2.0;
+
+                for range in spell::misspelled(checker, &text) {
+                    for bounds in paragraph.range_bounds(range) {
+                        let underline = Rectangle {
+                            x: position.x + bounds.x,
+                            y: top + bounds.y + bounds.height - 2.0,
+                            width: bounds.width,
+                            height: 1.0,
+                        };
+
+                        if let Some(underline) = underline.intersection(&text_bounds) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: underline,
+                                    ..renderer::Quad::default()
+                                },
+                                style.misspelling,
+                            );
+                        }
+                    }
+                }
+            }
         };
 
         if is_selecting {
@@ -761,6 +833,51 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) if !self.is_secure => {
+                if let Some(checker) = self.spellcheck
+                    && let Some(on_misspelling) = &self.on_misspelling
+                    && let Some(cursor_position) = cursor.position_over(layout.bounds())
+                {
+                    let state = state::<Renderer>(tree);
+                    let text_bounds = layout.children().next().unwrap().bounds();
+                    let value = self.value.to_string();
+
+                    let target = cursor_position.x
+                        - text_bounds.x
+                        - alignment_offset(
+                            text_bounds.width,
+                            state.value.raw().min_width(),
+                            self.alignment,
+                        )
+                        + offset(text_bounds, &self.value, state);
+
+                    let misspelling = state
+                        .value
+                        .raw()
+                        .hit_test(Point::new(target, text_bounds.height / 2.0))
+                        .map(text::Hit::cursor)
+                        .and_then(|offset| spell::misspelling_at(checker, 0, &value, offset));
+
+                    if let Some(misspelling) = misspelling {
+                        let graphemes = |end: usize| {
+                            unicode_segmentation::UnicodeSegmentation::graphemes(
+                                &value[..end],
+                                true,
+                            )
+                            .count()
+                        };
+
+                        state.cursor.select_range(
+                            graphemes(misspelling.range.start),
+                            graphemes(misspelling.range.end),
+                        );
+
+                        shell.publish(on_misspelling(misspelling));
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                }
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
@@ -1251,8 +1368,24 @@ where
             Status::Active
         };
 
-        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
             self.last_status = Some(status);
+
+            if let Some(duration) = self.transition {
+                let (animation, last_redraw) = state
+                    .transition
+                    .get_or_insert_with(|| (Animation::new(status).duration(duration), *now));
+
+                if animation.value() != status {
+                    animation.go_mut(status, *now);
+                }
+
+                *last_redraw = *now;
+
+                if animation.is_animating(*now) {
+                    shell.request_redraw();
+                }
+            }
         } else if self
             .last_status
             .is_some_and(|last_status| status != last_status)
@@ -1345,6 +1478,7 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    transition: Option<(Animation<Status>, Instant)>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1576,6 +1710,18 @@ pub enum Status {
     Disabled,
 }
 
+impl animation::Float for Status {
+    fn float_value(&self) -> f32 {
+        match self {
+            Status::Active => 0.0,
+            Status::Hovered => 1.0,
+            Status::Focused { is_hovered: false } => 2.0,
+            Status::Focused { is_hovered: true } => 3.0,
+            Status::Disabled => 4.0,
+        }
+    }
+}
+
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
@@ -1591,6 +1737,22 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the underline of misspelled words in the text input.
+    pub misspelling: Color,
+}
+
+impl Interpolable for Style {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolated(other.background, ratio),
+            border: self.border.interpolated(other.border, ratio),
+            icon: self.icon.interpolated(other.icon, ratio),
+            placeholder: self.placeholder.interpolated(other.placeholder, ratio),
+            value: self.value.interpolated(other.value, ratio),
+            selection: self.selection.interpolated(other.selection, ratio),
+            misspelling: self.misspelling.interpolated(other.misspelling, ratio),
+        }
+    }
 }
 
 /// The theme catalog of a [`TextInput`].
@@ -1637,6 +1799,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.secondary.base.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        misspelling: palette.danger.base.color,
     };
 
     match status {
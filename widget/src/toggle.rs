@@ -0,0 +1,590 @@
+//! Toggles let users switch a setting on or off with an animated switch.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::toggle;
+//!
+//! struct State {
+//!    is_enabled: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggled(bool),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     toggle(state.is_enabled).on_toggle(Message::Toggled).into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Toggled(is_enabled) => {
+//!             state.is_enabled = is_enabled;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Unlike a [`Toggler`](crate::Toggler), a [`Toggle`] has no label and
+//! animates its thumb between both positions. It can be focused with the
+//! [`focus_next`] and [`focus_previous`] operations, and toggled with the
+//! space, enter, or select keys; which makes it usable with a controller or
+//! a remote.
+//!
+//! [`focus_next`]: crate::core::widget::operation::focusable::focus_next
+//! [`focus_previous`]: crate::core::widget::operation::focusable::focus_previous
+use crate::core::animation::{Animation, Interpolable};
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Theme,
+    Widget,
+};
+
+/// An animated switch that toggles a boolean value.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::toggle;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     WifiToggled(bool),
+/// }
+///
+/// fn view(is_wifi_enabled: bool) -> Element<'static, Message> {
+///     toggle(is_wifi_enabled)
+///         .on_toggle(Message::WifiToggled)
+///         .size(24)
+///         .into()
+/// }
+/// ```
+pub struct Toggle<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    id: Option<widget::Id>,
+    is_toggled: bool,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    size: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme> Toggle<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`Toggle`].
+    pub const DEFAULT_SIZE: f32 = 20.0;
+
+    /// Creates a new [`Toggle`].
+    ///
+    /// It expects a boolean describing whether the [`Toggle`] is on or not.
+    pub fn new(is_toggled: bool) -> Self {
+        Toggle {
+            id: None,
+            is_toggled,
+            on_toggle: None,
+            size: Self::DEFAULT_SIZE,
+            duration: Duration::from_millis(150),
+            class: Theme::default(),
+            last_status: None,
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`Toggle`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a user toggles
+    /// the [`Toggle`].
+    ///
+    /// If this method is not called, the [`Toggle`] will be disabled.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message that should be produced when a user toggles
+    /// the [`Toggle`], if `Some`.
+    ///
+    /// If `None`, the [`Toggle`] will be disabled.
+    pub fn on_toggle_maybe(mut self, on_toggle: Option<impl Fn(bool) -> Message + 'a>) -> Self {
+        self.on_toggle = on_toggle.map(|on_toggle| Box::new(on_toggle) as _);
+        self
+    }
+
+    /// Sets the size of the [`Toggle`].
+    ///
+    /// This is the height of the switch; its width is always twice as much.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the duration of the animation of the [`Toggle`].
+    ///
+    /// By default, it is 150 milliseconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Toggle`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Toggle`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    is_focused: bool,
+    animation: Option<Animation<bool>>,
+    now: Instant,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            is_focused: false,
+            animation: None,
+            now: Instant::now(),
+        }
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Toggle<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, 2.0 * self.size, self.size)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(on_toggle) = &self.on_toggle {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    state.is_focused = cursor.is_over(layout.bounds());
+
+                    if state.is_focused {
+                        shell.publish(on_toggle(!self.is_toggled));
+                        shell.capture_event();
+                    }
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key:
+                        keyboard::Key::Named(key::Named::Space | key::Named::Enter | key::Named::Select),
+                    ..
+                }) if state.is_focused => {
+                    shell.publish(on_toggle(!self.is_toggled));
+                    shell.capture_event();
+                }
+                _ => {}
+            }
+        } else {
+            state.is_focused = false;
+        }
+
+        let current_status = if self.on_toggle.is_none() {
+            Status::Disabled {
+                is_toggled: self.is_toggled,
+            }
+        } else if state.is_focused {
+            Status::Focused {
+                is_toggled: self.is_toggled,
+            }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered {
+                is_toggled: self.is_toggled,
+            }
+        } else {
+            Status::Active {
+                is_toggled: self.is_toggled,
+            }
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.last_status = Some(current_status);
+            state.now = *now;
+
+            let animation = state
+                .animation
+                .get_or_insert_with(|| Animation::new(self.is_toggled).duration(self.duration));
+
+            if animation.value() != self.is_toggled {
+                animation.go_mut(self.is_toggled, *now);
+            }
+
+            if animation.is_animating(*now) {
+                shell.request_redraw();
+            }
+        } else if self
+            .last_status
+            .is_some_and(|status| status != current_status)
+            || state
+                .animation
+                .as_ref()
+                .is_some_and(|animation| animation.value() != self.is_toggled)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            if self.on_toggle.is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::NotAllowed
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = self.last_status.unwrap_or(Status::Disabled {
+            is_toggled: self.is_toggled,
+        });
+
+        // The progress of the thumb from off to on
+        let progress: f32 = state
+            .animation
+            .as_ref()
+            .map(|animation| animation.interpolate(0.0, 1.0, state.now))
+            .unwrap_or(if self.is_toggled { 1.0 } else { 0.0 });
+
+        let style = theme
+            .style(&self.class, status.with_toggled(false))
+            .interpolated(
+                theme.style(&self.class, status.with_toggled(true)),
+                progress,
+            );
+
+        let border_radius = style
+            .border_radius
+            .unwrap_or_else(|| border::Radius::new(bounds.height / 2.0));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: border_radius,
+                    ..style.track_border
+                },
+                ..renderer::Quad::default()
+            },
+            style.track,
+        );
+
+        let padding = (style.padding_ratio * bounds.height).round();
+        let thumb_size = bounds.height - 2.0 * padding;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + padding + progress * (bounds.width - bounds.height),
+                    y: bounds.y + padding,
+                    width: thumb_size,
+                    height: thumb_size,
+                },
+                border: Border {
+                    radius: border_radius,
+                    ..style.thumb_border
+                },
+                ..renderer::Quad::default()
+            },
+            style.thumb,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Toggle<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(toggle: Toggle<'a, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(toggle)
+    }
+}
+
+/// The possible status of a [`Toggle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Toggle`] can be interacted with.
+    Active {
+        /// Indicates whether the [`Toggle`] is on.
+        is_toggled: bool,
+    },
+    /// The [`Toggle`] is being hovered.
+    Hovered {
+        /// Indicates whether the [`Toggle`] is on.
+        is_toggled: bool,
+    },
+    /// The [`Toggle`] is focused.
+    Focused {
+        /// Indicates whether the [`Toggle`] is on.
+        is_toggled: bool,
+    },
+    /// The [`Toggle`] is disabled.
+    Disabled {
+        /// Indicates whether the [`Toggle`] is on.
+        is_toggled: bool,
+    },
+}
+
+impl Status {
+    fn with_toggled(self, is_toggled: bool) -> Self {
+        match self {
+            Status::Active { .. } => Status::Active { is_toggled },
+            Status::Hovered { .. } => Status::Hovered { is_toggled },
+            Status::Focused { .. } => Status::Focused { is_toggled },
+            Status::Disabled { .. } => Status::Disabled { is_toggled },
+        }
+    }
+}
+
+/// The appearance of a toggle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the track of the toggle.
+    pub track: Background,
+    /// The [`Border`] of the track of the toggle.
+    ///
+    /// Its radius is ignored in favor of [`Style::border_radius`].
+    pub track_border: Border,
+    /// The [`Background`] of the thumb of the toggle.
+    pub thumb: Background,
+    /// The [`Border`] of the thumb of the toggle.
+    ///
+    /// Its radius is ignored in favor of [`Style::border_radius`].
+    pub thumb_border: Border,
+    /// The border radius of the toggle.
+    ///
+    /// If `None`, the toggle will be perfectly round.
+    pub border_radius: Option<border::Radius>,
+    /// The ratio of separation between the track and the thumb in relative height.
+    pub padding_ratio: f32,
+}
+
+impl Interpolable for Style {
+    fn interpolated(&self, other: Self, ratio: f32) -> Self {
+        Self {
+            track: self.track.interpolated(other.track, ratio),
+            track_border: self.track_border.interpolated(other.track_border, ratio),
+            thumb: self.thumb.interpolated(other.thumb, ratio),
+            thumb_border: self.thumb_border.interpolated(other.thumb_border, ratio),
+            border_radius: if ratio < 0.5 {
+                self.border_radius
+            } else {
+                other.border_radius
+            },
+            padding_ratio: self.padding_ratio.interpolated(other.padding_ratio, ratio),
+        }
+    }
+}
+
+/// The theme catalog of a [`Toggle`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Toggle`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Toggle`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let (track, thumb) = match status {
+        Status::Active { is_toggled } => {
+            if is_toggled {
+                (palette.primary.base.color, palette.primary.base.text)
+            } else {
+                (
+                    palette.background.strong.color,
+                    palette.background.base.color,
+                )
+            }
+        }
+        Status::Hovered { is_toggled } | Status::Focused { is_toggled } => {
+            if is_toggled {
+                (palette.primary.strong.color, palette.primary.strong.text)
+            } else {
+                (
+                    palette.background.strong.color,
+                    palette.background.weak.color,
+                )
+            }
+        }
+        Status::Disabled { is_toggled } => {
+            if is_toggled {
+                (
+                    palette.background.strong.color,
+                    palette.background.weakest.color,
+                )
+            } else {
+                (
+                    palette.background.weak.color,
+                    palette.background.weakest.color,
+                )
+            }
+        }
+    };
+
+    let track_border = match status {
+        Status::Focused { .. } => Border {
+            color: palette.primary.strong.color,
+            width: 2.0,
+            radius: border::Radius::default(),
+        },
+        _ => Border::default(),
+    };
+
+    Style {
+        track: track.into(),
+        track_border,
+        thumb: thumb.into(),
+        thumb_border: Border::default(),
+        border_radius: None,
+        padding_ratio: 0.1,
+    }
+}
@@ -300,6 +300,8 @@ where
                             align_y: alignment::Vertical::Top,
                             shaping: self.text_shaping,
                             wrapping: self.text_wrapping,
+                            hyphenation: None,
+                            max_lines: None,
                         },
                     )
                 } else {
@@ -411,6 +413,7 @@ where
                 state.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -1,7 +1,10 @@
 //! Tooltips display a hint of information over some element when hovered.
 //!
 //! By default, the tooltip is displayed immediately, however, this can be adjusted
-//! with [`Tooltip::delay`].
+//! with [`Tooltip::delay`] and [`Tooltip::hide_delay`]. On touch screens, the
+//! tooltip is shown after a long press on its content.
+//!
+//! The contents of a tooltip can be any widget, not just text.
 //!
 //! # Example
 //! ```no_run
@@ -31,11 +34,14 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::{Duration, Instant};
+use crate::core::touch;
 use crate::core::widget::{self, Widget};
 use crate::core::window;
 use crate::core::{
-    Clipboard, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shell, Size, Vector,
+    Alignment, Clipboard, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector,
 };
+use crate::popover::{Placement, Positioning};
 
 /// An element to display a widget over another.
 ///
@@ -72,6 +78,8 @@ where
     padding: f32,
     snap_within_viewport: bool,
     delay: Duration,
+    hide_delay: Duration,
+    long_press: Duration,
     class: Theme::Class<'a>,
 }
 
@@ -83,6 +91,9 @@ where
     /// The default padding of a [`Tooltip`] drawn by this renderer.
     const DEFAULT_PADDING: f32 = 5.0;
 
+    /// The default duration of a long press that shows a [`Tooltip`].
+    const DEFAULT_LONG_PRESS: Duration = Duration::from_millis(500);
+
     /// Creates a new [`Tooltip`].
     ///
     /// [`Tooltip`]: struct.Tooltip.html
@@ -99,6 +110,8 @@ where
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
             delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            long_press: Self::DEFAULT_LONG_PRESS,
             class: Theme::default(),
         }
     }
@@ -123,7 +136,27 @@ where
         self
     }
 
-    /// Sets whether the [`Tooltip`] is snapped within the viewport.
+    /// Sets the delay before the [`Tooltip`] is hidden once the cursor
+    /// leaves its content.
+    ///
+    /// Moving the cursor back over the content during this delay keeps
+    /// the [`Tooltip`] open.
+    pub fn hide_delay(mut self, hide_delay: Duration) -> Self {
+        self.hide_delay = hide_delay;
+        self
+    }
+
+    /// Sets the duration a finger must be held on the content to show
+    /// the [`Tooltip`] on touch screens.
+    pub fn long_press(mut self, duration: Duration) -> Self {
+        self.long_press = duration;
+        self
+    }
+
+    /// Sets whether the [`Tooltip`] is kept within the viewport.
+    ///
+    /// When enabled, a [`Tooltip`] that would overflow the viewport is
+    /// flipped to the opposite side of its content or shifted back into view.
     pub fn snap_within_viewport(mut self, snap: bool) -> Self {
         self.snap_within_viewport = snap;
         self
@@ -203,53 +236,164 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        if let Event::Mouse(_) | Event::Window(window::Event::RedrawRequested(_)) = event {
-            let state = tree.state.downcast_mut::<State>();
-            let now = Instant::now();
-            let cursor_position = cursor.position_over(layout.bounds());
-
-            match (*state, cursor_position) {
-                (State::Idle, Some(cursor_position)) => {
-                    if self.delay == Duration::ZERO {
-                        *state = State::Open { cursor_position };
-                        shell.invalidate_layout();
-                    } else {
-                        *state = State::Hovered { at: now };
+        let state = tree.state.downcast_mut::<State>();
+        let now = Instant::now();
+
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if layout.bounds().contains(*position) {
+                    if !state.is_open() {
+                        *state = State::Pressed {
+                            at: now,
+                            finger: *id,
+                            origin: *position,
+                        };
+
+                        shell.request_redraw_at(now + self.long_press);
                     }
-
-                    shell.request_redraw_at(now + self.delay);
-                }
-                (State::Hovered { .. }, None) => {
+                } else if state.is_open() {
                     *state = State::Idle;
-                }
-                (State::Hovered { at, .. }, _) if at.elapsed() < self.delay => {
-                    shell.request_redraw_at(now + self.delay - at.elapsed());
-                }
-                (State::Hovered { .. }, Some(cursor_position)) => {
-                    *state = State::Open { cursor_position };
                     shell.invalidate_layout();
+                    shell.request_redraw();
                 }
-                (
-                    State::Open {
-                        cursor_position: last_position,
-                    },
-                    Some(cursor_position),
-                ) if self.position == Position::FollowCursor
-                    && last_position != cursor_position =>
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let State::Pressed { finger, origin, .. } = *state
+                    && finger == *id
+                    && position.distance(origin) > LONG_PRESS_TOLERANCE
                 {
-                    *state = State::Open { cursor_position };
-                    shell.request_redraw();
+                    *state = State::Idle;
                 }
-                (State::Open { .. }, None) => {
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => match *state {
+                State::Pressed { finger, .. } if finger == *id => {
                     *state = State::Idle;
-                    shell.invalidate_layout();
-
-                    if !matches!(event, Event::Window(window::Event::RedrawRequested(_)),) {
+                }
+                State::Open {
+                    cursor_position,
+                    is_touch: true,
+                } => {
+                    *state = State::Closing {
+                        at: now,
+                        cursor_position,
+                        is_touch: true,
+                    };
+
+                    shell.request_redraw_at(now + self.hide_delay);
+                }
+                _ => {}
+            },
+            Event::Mouse(_) | Event::Window(window::Event::RedrawRequested(_)) => {
+                let is_redraw = matches!(event, Event::Window(window::Event::RedrawRequested(_)));
+                let cursor_position = cursor.position_over(layout.bounds());
+
+                match (*state, cursor_position) {
+                    (State::Idle, Some(cursor_position)) => {
+                        if self.delay == Duration::ZERO {
+                            *state = State::Open {
+                                cursor_position,
+                                is_touch: false,
+                            };
+                            shell.invalidate_layout();
+                        } else {
+                            *state = State::Hovered { at: now };
+                        }
+
+                        shell.request_redraw_at(now + self.delay);
+                    }
+                    (State::Hovered { .. }, None) => {
+                        *state = State::Idle;
+                    }
+                    (State::Hovered { at, .. }, _) if at.elapsed() < self.delay => {
+                        shell.request_redraw_at(now + self.delay - at.elapsed());
+                    }
+                    (State::Hovered { .. }, Some(cursor_position)) => {
+                        *state = State::Open {
+                            cursor_position,
+                            is_touch: false,
+                        };
+                        shell.invalidate_layout();
+                    }
+                    (State::Pressed { at, origin, .. }, _) => {
+                        if at.elapsed() >= self.long_press {
+                            *state = State::Open {
+                                cursor_position: origin,
+                                is_touch: true,
+                            };
+                            shell.invalidate_layout();
+                        } else {
+                            shell.request_redraw_at(now + self.long_press - at.elapsed());
+                        }
+                    }
+                    (State::Open { is_touch: true, .. }, _) => {}
+                    (
+                        State::Open {
+                            cursor_position: last_position,
+                            ..
+                        },
+                        Some(cursor_position),
+                    ) if self.position == Position::FollowCursor
+                        && last_position != cursor_position =>
+                    {
+                        *state = State::Open {
+                            cursor_position,
+                            is_touch: false,
+                        };
                         shell.request_redraw();
                     }
+                    (
+                        State::Open {
+                            cursor_position, ..
+                        },
+                        None,
+                    ) => {
+                        if self.hide_delay == Duration::ZERO {
+                            *state = State::Idle;
+                            shell.invalidate_layout();
+
+                            if !is_redraw {
+                                shell.request_redraw();
+                            }
+                        } else {
+                            *state = State::Closing {
+                                at: now,
+                                cursor_position,
+                                is_touch: false,
+                            };
+
+                            shell.request_redraw_at(now + self.hide_delay);
+                        }
+                    }
+                    (
+                        State::Closing {
+                            is_touch: false, ..
+                        },
+                        Some(cursor_position),
+                    ) => {
+                        *state = State::Open {
+                            cursor_position,
+                            is_touch: false,
+                        };
+                        shell.request_redraw();
+                    }
+                    (State::Closing { at, .. }, _) => {
+                        if at.elapsed() >= self.hide_delay {
+                            *state = State::Idle;
+                            shell.invalidate_layout();
+
+                            if !is_redraw {
+                                shell.request_redraw();
+                            }
+                        } else {
+                            shell.request_redraw_at(now + self.hide_delay - at.elapsed());
+                        }
+                    }
+                    (State::Open { .. }, Some(_)) | (State::Idle, None) => (),
                 }
-                (State::Open { .. }, Some(_)) | (State::Idle, None) => (),
             }
+            _ => {}
         }
 
         self.content.as_widget_mut().update(
@@ -322,7 +466,7 @@ where
             translation,
         );
 
-        let tooltip = if let State::Open { cursor_position } = *state {
+        let tooltip = if let Some(cursor_position) = state.cursor_position() {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
                 tooltip: &mut self.tooltip,
@@ -398,6 +542,10 @@ pub enum Position {
     FollowCursor,
 }
 
+/// The distance a finger may travel during a long press before it is
+/// no longer considered one.
+const LONG_PRESS_TOLERANCE: f32 = 10.0;
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 enum State {
     #[default]
@@ -405,11 +553,40 @@ enum State {
     Hovered {
         at: Instant,
     },
+    Pressed {
+        at: Instant,
+        finger: touch::Finger,
+        origin: Point,
+    },
     Open {
         cursor_position: Point,
+        is_touch: bool,
+    },
+    Closing {
+        at: Instant,
+        cursor_position: Point,
+        is_touch: bool,
     },
 }
 
+impl State {
+    fn is_open(&self) -> bool {
+        self.cursor_position().is_some()
+    }
+
+    fn cursor_position(&self) -> Option<Point> {
+        match *self {
+            State::Open {
+                cursor_position, ..
+            }
+            | State::Closing {
+                cursor_position, ..
+            } => Some(cursor_position),
+            State::Idle | State::Hovered { .. } | State::Pressed { .. } => None,
+        }
+    }
+}
+
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
     Theme: container::Catalog,
@@ -427,6 +604,16 @@ where
     class: &'b Theme::Class<'a>,
 }
 
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: text::Renderer,
+{
+    fn anchor(&self) -> Rectangle {
+        Rectangle::new(self.position, self.content_bounds.size())
+    }
+}
+
 impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
     for Overlay<'_, '_, Message, Theme, Renderer>
 where
@@ -451,58 +638,42 @@ where
         );
 
         let text_bounds = tooltip_layout.bounds();
-        let x_center = self.position.x + (self.content_bounds.width - text_bounds.width) / 2.0;
-        let y_center = self.position.y + (self.content_bounds.height - text_bounds.height) / 2.0;
-
-        let mut tooltip_bounds = {
-            let offset = match self.positioning {
-                Position::Top => Vector::new(
-                    x_center,
-                    self.position.y - text_bounds.height - self.gap - self.padding,
-                ),
-                Position::Bottom => Vector::new(
-                    x_center,
-                    self.position.y + self.content_bounds.height + self.gap + self.padding,
-                ),
-                Position::Left => Vector::new(
-                    self.position.x - text_bounds.width - self.gap - self.padding,
-                    y_center,
-                ),
-                Position::Right => Vector::new(
-                    self.position.x + self.content_bounds.width + self.gap + self.padding,
-                    y_center,
-                ),
-                Position::FollowCursor => {
-                    let translation = self.position - self.content_bounds.position();
-
-                    Vector::new(
-                        self.cursor_position.x,
-                        self.cursor_position.y - text_bounds.height,
-                    ) + translation
-                }
-            };
-
-            Rectangle {
-                x: offset.x - self.padding,
-                y: offset.y - self.padding,
-                width: text_bounds.width + self.padding * 2.0,
-                height: text_bounds.height + self.padding * 2.0,
-            }
+        let size = text_bounds
+            .size()
+            .expand(Size::new(self.padding, self.padding) * 2.0);
+
+        let positioning = |placement| Positioning {
+            placement,
+            align: Alignment::Center,
+            gap: self.gap,
+            flip: self.snap_within_viewport,
+            shift: self.snap_within_viewport,
         };
 
-        if self.snap_within_viewport {
-            if tooltip_bounds.x < viewport.x {
-                tooltip_bounds.x = viewport.x;
-            } else if viewport.x + viewport.width < tooltip_bounds.x + tooltip_bounds.width {
-                tooltip_bounds.x = viewport.x + viewport.width - tooltip_bounds.width;
-            }
+        let (anchor, positioning) = match self.positioning {
+            Position::Top => (self.anchor(), positioning(Placement::Top)),
+            Position::Bottom => (self.anchor(), positioning(Placement::Bottom)),
+            Position::Left => (self.anchor(), positioning(Placement::Left)),
+            Position::Right => (self.anchor(), positioning(Placement::Right)),
+            Position::FollowCursor => {
+                let translation = self.position - self.content_bounds.position();
+                let cursor = self.cursor_position + translation;
 
-            if tooltip_bounds.y < viewport.y {
-                tooltip_bounds.y = viewport.y;
-            } else if viewport.y + viewport.height < tooltip_bounds.y + tooltip_bounds.height {
-                tooltip_bounds.y = viewport.y + viewport.height - tooltip_bounds.height;
+                (
+                    Rectangle::new(
+                        Point::new(cursor.x - self.padding, cursor.y + self.padding),
+                        Size::ZERO,
+                    ),
+                    Positioning {
+                        align: Alignment::Start,
+                        gap: 0.0,
+                        ..positioning(Placement::Top)
+                    },
+                )
             }
-        }
+        };
+
+        let tooltip_bounds = positioning.place(anchor, size, viewport).bounds;
 
         layout::Node::with_children(
             tooltip_bounds.size(),
@@ -30,7 +30,7 @@
 //! ```
 use std::ops::RangeInclusive;
 
-pub use crate::slider::{Catalog, Handle, HandleShape, Status, Style, StyleFn, default};
+pub use crate::slider::{Catalog, Handle, HandleShape, Status, Style, StyleFn, Ticks, default};
 
 use crate::core::border::Border;
 use crate::core::keyboard;
@@ -94,6 +94,7 @@ where
     on_release: Option<Message>,
     width: f32,
     height: Length,
+    ticks: Option<Ticks<T>>,
     class: Theme::Class<'a>,
     status: Option<Status>,
 }
@@ -141,6 +142,7 @@ where
             on_release: None,
             width: Self::DEFAULT_WIDTH,
             height: Length::Fill,
+            ticks: None,
             class: Theme::default(),
             status: None,
         }
@@ -191,6 +193,14 @@ where
         self
     }
 
+    /// Sets the [`Ticks`] of the [`VerticalSlider`].
+    ///
+    /// The labels of the [`Ticks`] are displayed to the right of the rail.
+    pub fn ticks(mut self, ticks: Ticks<T>) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
     /// Sets the style of the [`VerticalSlider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -216,7 +226,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: core::text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -236,10 +246,16 @@ where
     fn layout(
         &mut self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        let labels = self
+            .ticks
+            .as_ref()
+            .map(|ticks| ticks.label_space(renderer, true))
+            .unwrap_or_default();
+
+        layout::atomic(limits, self.width + labels, self.height)
     }
 
     fn update(
@@ -281,11 +297,25 @@ where
                 let steps = (percent * (end - start) / step).round();
                 let value = steps * step + start;
 
-                T::from_f64(value.min(end))
+                let value = self
+                    .ticks
+                    .as_ref()
+                    .and_then(|ticks| ticks.nearest(start + percent * (end - start)))
+                    .unwrap_or(value);
+
+                T::from_f64(value.max(start).min(end))
             }
         };
 
         let increment = |value: T| -> Option<T> {
+            if let Some(tick) = self
+                .ticks
+                .as_ref()
+                .and_then(|ticks| ticks.next(value.into()))
+            {
+                return T::from_f64(tick.min((*self.range.end()).into()));
+            }
+
             let step = if state.keyboard_modifiers.shift() {
                 self.shift_step.unwrap_or(self.step)
             } else {
@@ -304,6 +334,14 @@ where
         };
 
         let decrement = |value: T| -> Option<T> {
+            if let Some(tick) = self
+                .ticks
+                .as_ref()
+                .and_then(|ticks| ticks.previous(value.into()))
+            {
+                return T::from_f64(tick.max((*self.range.start()).into()));
+            }
+
             let step = if state.keyboard_modifiers.shift() {
                 self.shift_step.unwrap_or(self.step)
             } else {
@@ -421,10 +459,10 @@ where
         _tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        defaults: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
 
@@ -435,7 +473,7 @@ where
             HandleShape::Rectangle {
                 width,
                 border_radius,
-            } => (f32::from(width), bounds.width, border_radius),
+            } => (f32::from(width), self.width, border_radius),
         };
 
         let value = self.value.into() as f32;
@@ -451,7 +489,7 @@ where
             (bounds.height - handle_width) * (value - range_end) / (range_start - range_end)
         };
 
-        let rail_x = bounds.x + bounds.width / 2.0;
+        let rail_x = bounds.x + self.width / 2.0;
 
         renderer.fill_quad(
             renderer::Quad {
@@ -481,6 +519,27 @@ where
             style.rail.backgrounds.0,
         );
 
+        if let Some(ticks) = &self.ticks {
+            ticks.draw(
+                renderer,
+                |value| {
+                    let offset = if range_start >= range_end {
+                        0.0
+                    } else {
+                        (bounds.height - handle_width) * (value as f32 - range_end)
+                            / (range_start - range_end)
+                    };
+
+                    Point::new(rail_x, bounds.y + offset + handle_width / 2.0)
+                },
+                true,
+                bounds.x + self.width,
+                defaults.text_color.scale_alpha(0.5),
+                defaults.text_color,
+                viewport,
+            );
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -536,7 +595,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: core::text::Renderer + 'a,
 {
     fn from(
         slider: VerticalSlider<'a, T, Message, Theme>,
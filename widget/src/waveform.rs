@@ -0,0 +1,467 @@
+//! Waveforms visualize the most recent samples of an audio signal.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::waveform;
+//! use iced::widget::waveform::Samples;
+//!
+//! struct State {
+//!    samples: Samples,
+//! }
+//!
+//! enum Message {
+//!     Recorded(Vec<f32>),
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Recorded(samples) => {
+//!             state.samples.extend(samples);
+//!         }
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     waveform(&state.samples).height(48).into()
+//! }
+//! ```
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{
+    self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme, Transformation,
+    Vector, Widget,
+};
+use crate::graphics::color;
+use crate::graphics::mesh::{self, Mesh, SolidVertex2D};
+
+use std::collections::VecDeque;
+
+/// A graph of the most recent [`Samples`] of an audio signal.
+///
+/// Every column of the [`Waveform`] displays the range of the samples that
+/// fall into it; so any amount of samples is drawn with a single mesh of,
+/// at most, one quad per column.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::waveform;
+/// use iced::widget::waveform::Samples;
+///
+/// struct State {
+///    samples: Samples,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     waveform(&state.samples).bar_width(3).spacing(2).into()
+/// }
+/// ```
+pub struct Waveform<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    samples: &'a Samples,
+    width: Length,
+    height: Length,
+    bar_width: f32,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Waveform<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`Waveform`].
+    pub const DEFAULT_HEIGHT: f32 = 40.0;
+
+    /// Creates a new [`Waveform`] of the given [`Samples`].
+    pub fn new(samples: &'a Samples) -> Self {
+        Self {
+            samples,
+            width: Length::Fill,
+            height: Length::from(Self::DEFAULT_HEIGHT),
+            bar_width: 1.0,
+            spacing: 0.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Waveform`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Waveform`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of every bar of the [`Waveform`].
+    ///
+    /// By default, every bar is a single pixel wide.
+    pub fn bar_width(mut self, bar_width: impl Into<core::Pixels>) -> Self {
+        self.bar_width = bar_width.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the spacing between the bars of the [`Waveform`].
+    pub fn spacing(mut self, spacing: impl Into<core::Pixels>) -> Self {
+        self.spacing = spacing.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the style of the [`Waveform`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Waveform`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Waveform<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer + mesh::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let stride = self.bar_width + self.spacing;
+        let columns = ((bounds.width + self.spacing) / stride).floor() as usize;
+
+        if self.samples.is_empty() || columns == 0 || style.color.a == 0.0 {
+            return;
+        }
+
+        let center = bounds.height / 2.0;
+        let mut buffers = mesh::Indexed {
+            vertices: Vec::with_capacity(columns * 4),
+            indices: Vec::with_capacity(columns * 6),
+        };
+
+        for (column, (min, max)) in envelope(self.samples, columns).enumerate() {
+            let top = center - max * center;
+            let bottom = center - min * center;
+
+            // Silence is still drawn as a line along the center
+            let height = (bottom - top).max(1.0);
+
+            push_quad(
+                &mut buffers,
+                Rectangle {
+                    x: column as f32 * stride,
+                    y: top.min(center - height / 2.0),
+                    width: self.bar_width,
+                    height,
+                },
+                style.color,
+            );
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_mesh(Mesh::Solid {
+                buffers,
+                transformation: Transformation::IDENTITY,
+                clip_bounds: Rectangle::with_size(bounds.size()),
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Waveform<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + core::Renderer + mesh::Renderer,
+{
+    fn from(waveform: Waveform<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(waveform)
+    }
+}
+
+/// A ring buffer of the most recent samples of an audio signal.
+///
+/// Samples are expected to be in the `[-1.0, 1.0]` range. Once the
+/// [`Samples`] are full, pushing a new sample discards the oldest one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Samples {
+    buffer: VecDeque<f32>,
+    capacity: usize,
+}
+
+impl Samples {
+    /// Creates new empty [`Samples`] holding, at most, the given amount of
+    /// samples.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffer: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Pushes a new sample; discarding the oldest one if the [`Samples`]
+    /// are full.
+    pub fn push(&mut self, sample: f32) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.buffer.len() == self.capacity {
+            let _ = self.buffer.pop_front();
+        }
+
+        self.buffer.push_back(sample.clamp(-1.0, 1.0));
+    }
+
+    /// Removes all the samples.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns the amount of samples.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns true if there are no samples.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns the maximum amount of samples.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns an iterator over the samples; from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = f32> + ExactSizeIterator + '_ {
+        self.buffer.iter().copied()
+    }
+
+    /// Returns the highest absolute amplitude of the samples.
+    pub fn peak(&self) -> f32 {
+        self.iter().fold(0.0, |peak, sample| peak.max(sample.abs()))
+    }
+
+    /// Returns the root mean square of the samples; a measure of their
+    /// loudness.
+    pub fn rms(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let sum: f32 = self.iter().map(|sample| sample * sample).sum();
+
+        (sum / self.len() as f32).sqrt()
+    }
+}
+
+impl Extend<f32> for Samples {
+    fn extend<T: IntoIterator<Item = f32>>(&mut self, samples: T) {
+        for sample in samples {
+            self.push(sample);
+        }
+    }
+}
+
+/// The appearance of a waveform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the waveform, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of the waveform.
+    pub border: Border,
+    /// The [`Color`] of the bars of the waveform.
+    pub color: Color,
+}
+
+/// The theme catalog of a [`Waveform`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Waveform`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The primary style of a [`Waveform`].
+pub fn primary(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        border: border::rounded(2),
+        color: palette.primary.base.color,
+    }
+}
+
+/// A [`Waveform`] drawn over a weak background.
+pub fn contained(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        ..primary(theme)
+    }
+}
+
+/// Returns the lowest and highest sample of every one of the given amount
+/// of columns.
+///
+/// If there are fewer samples than columns, samples span multiple columns.
+fn envelope(samples: &Samples, columns: usize) -> impl Iterator<Item = (f32, f32)> + '_ {
+    let len = samples.len();
+
+    (0..columns).map(move |column| {
+        let start = column * len / columns;
+        let end = ((column + 1) * len / columns).max(start + 1).min(len);
+
+        samples
+            .buffer
+            .range(start..end)
+            .fold((0.0_f32, 0.0_f32), |(min, max), &sample| {
+                (min.min(sample), max.max(sample))
+            })
+    })
+}
+
+/// Pushes a quad with the given bounds and [`Color`] to the given buffers.
+pub(crate) fn push_quad(
+    buffers: &mut mesh::Indexed<SolidVertex2D>,
+    bounds: Rectangle,
+    color: Color,
+) {
+    let color = color::pack(color);
+    let start = buffers.vertices.len() as u32;
+
+    buffers.vertices.extend(
+        [
+            [bounds.x, bounds.y],
+            [bounds.x + bounds.width, bounds.y],
+            [bounds.x + bounds.width, bounds.y + bounds.height],
+            [bounds.x, bounds.y + bounds.height],
+        ]
+        .map(|position| SolidVertex2D { position, color }),
+    );
+
+    buffers
+        .indices
+        .extend([0, 1, 2, 0, 2, 3].map(|index| start + index));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_discards_the_oldest_samples() {
+        let mut samples = Samples::new(3);
+        samples.extend([0.1, 0.2, 0.3, 0.4]);
+
+        assert_eq!(samples.iter().collect::<Vec<_>>(), vec![0.2, 0.3, 0.4]);
+    }
+
+    #[test]
+    fn it_measures_the_loudness_of_samples() {
+        let mut samples = Samples::new(4);
+        samples.extend([0.5, -0.5, 0.5, -1.5]);
+
+        assert_eq!(samples.peak(), 1.0);
+        assert_eq!(samples.rms(), (1.75_f32 / 4.0).sqrt());
+    }
+
+    #[test]
+    fn it_computes_the_envelope_of_every_column() {
+        let mut samples = Samples::new(4);
+        samples.extend([0.5, -0.25, 0.75, 0.25]);
+
+        assert_eq!(
+            envelope(&samples, 2).collect::<Vec<_>>(),
+            vec![(-0.25, 0.5), (0.0, 0.75)]
+        );
+
+        assert_eq!(envelope(&samples, 8).count(), 8);
+    }
+}
@@ -0,0 +1,307 @@
+//! Control the paint order of sibling widgets.
+//!
+//! A [`ZIndex`] widget does nothing on its own; it marks its contents with an
+//! index that the parent widget uses to decide the order in which its
+//! children are drawn. Children with a higher index are drawn on top of
+//! their siblings, regardless of their position in the parent.
+//!
+//! [`Column`], [`Row`], [`Grid`], and [`Masonry`] honor z-indices. Custom
+//! widgets can do the same by drawing their children with [`draw_children`].
+//!
+//! [`Column`]: crate::Column
+//! [`Row`]: crate::Row
+//! [`Grid`]: crate::Grid
+//! [`Masonry`]: crate::Masonry
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
+};
+
+/// A widget that changes the paint order of its contents among its siblings.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, container, float, z_index};
+///
+/// struct State {
+///     cards: Vec<String>,
+///     dragging: Option<usize>,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     column(state.cards.iter().enumerate().map(|(i, card)| {
+///         let card = container(card.as_str()).padding(10);
+///
+///         if state.dragging == Some(i) {
+///             z_index(1, float(card).scale(1.05)).into()
+///         } else {
+///             card.into()
+///         }
+///     }))
+///     .spacing(10)
+///     .into()
+/// }
+/// ```
+pub struct ZIndex<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    index: i32,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> ZIndex<'a, Message, Theme, Renderer> {
+    /// Creates a new [`ZIndex`] widget with the given index and content.
+    pub fn new(index: i32, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            index,
+            content: content.into(),
+        }
+    }
+}
+
+/// The local state of a [`ZIndex`] widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct State(i32);
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ZIndex<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State(self.index))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        *tree.state.downcast_mut::<State>() = State(self.index);
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ZIndex<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(z_index: ZIndex<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(z_index)
+    }
+}
+
+/// Returns the z-index of the widget with the given [`Tree`].
+///
+/// Widgets that are not wrapped in a [`ZIndex`] have a z-index of `0`.
+pub fn index_of(tree: &Tree) -> i32 {
+    if tree.tag == tree::Tag::of::<State>() {
+        tree.state.downcast_ref::<State>().0
+    } else {
+        0
+    }
+}
+
+/// Draws the given children that are visible in the viewport in ascending
+/// z-index order.
+///
+/// Children sharing the same z-index are drawn in order. Each z-index above
+/// the lowest one is drawn in a new layer, so it is never covered by the
+/// primitives of the children below it.
+pub fn draw_children<Message, Theme, Renderer>(
+    children: &[Element<'_, Message, Theme, Renderer>],
+    tree: &Tree,
+    layout: Layout<'_>,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) where
+    Renderer: core::Renderer,
+{
+    let visible = children
+        .iter()
+        .zip(&tree.children)
+        .zip(layout.children())
+        .filter(|(_, layout)| layout.bounds().intersects(viewport));
+
+    if tree.children.iter().all(|tree| index_of(tree) == 0) {
+        for ((child, tree), layout) in visible {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+
+        return;
+    }
+
+    let mut visible: Vec<_> = visible.collect();
+    visible.sort_by_key(|((_, tree), _)| index_of(tree));
+
+    let groups = visible.chunk_by(|((_, a), _), ((_, b), _)| index_of(a) == index_of(b));
+
+    for (i, group) in groups.enumerate() {
+        let draw_group = |renderer: &mut Renderer| {
+            for ((child, tree), layout) in group {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, *layout, cursor, viewport);
+            }
+        };
+
+        if i == 0 {
+            draw_group(renderer);
+        } else {
+            renderer.with_layer(*viewport, draw_group);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    type Element = core::Element<'static, (), crate::Theme, ()>;
+
+    #[test]
+    fn it_reads_the_index_of_a_tree() {
+        let raised: Element = ZIndex::new(3, Space::new()).into();
+        let plain: Element = Space::new().into();
+
+        let mut tree = Tree::new(&raised);
+        assert_eq!(index_of(&tree), 3);
+        assert_eq!(index_of(&Tree::new(&plain)), 0);
+
+        let lowered: Element = ZIndex::new(-1, Space::new()).into();
+        tree.diff(&lowered);
+        assert_eq!(index_of(&tree), -1);
+    }
+}
@@ -33,6 +33,9 @@ mod error;
 mod proxy;
 mod window;
 
+#[cfg(feature = "record")]
+mod record;
+
 pub use clipboard::Clipboard;
 pub use error::Error;
 pub use proxy::Proxy;
@@ -40,9 +43,9 @@ pub use proxy::Proxy;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::theme;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
-use crate::core::{Point, Renderer, Size};
+use crate::core::{Point, Rectangle, Renderer, Size, Transformation};
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::task;
@@ -50,6 +53,7 @@ use crate::futures::futures::{Future, StreamExt};
 use crate::futures::subscription;
 use crate::futures::{Executor, Runtime};
 use crate::graphics::{Compositor, Shell, compositor};
+use crate::runtime::font;
 use crate::runtime::image;
 use crate::runtime::system;
 use crate::runtime::user_interface::{self, UserInterface};
@@ -300,6 +304,7 @@ where
                                 on_open,
                             } => {
                                 let exit_on_close_request = settings.exit_on_close_request;
+                                let elastic_resize = settings.elastic_resize;
 
                                 let visible = settings.visible;
 
@@ -384,6 +389,7 @@ where
                                         id,
                                         window: Arc::new(window),
                                         exit_on_close_request,
+                                        elastic_resize,
                                         make_visible: visible,
                                         on_open,
                                     },
@@ -442,6 +448,7 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: Arc<winit::window::Window>,
         exit_on_close_request: bool,
+        elastic_resize: Option<Duration>,
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
     },
@@ -495,6 +502,12 @@ async fn run_instance<P>(
     let mut user_interfaces = ManuallyDrop::new(FxHashMap::default());
     let mut clipboard = Clipboard::unconnected();
 
+    #[cfg(feature = "record")]
+    let mut recorder = record::Recorder::from_env();
+
+    #[cfg(feature = "record")]
+    let mut replay = record::Replay::from_env();
+
     #[cfg(all(feature = "linux-theme-detection", target_os = "linux"))]
     let mut system_theme = {
         let to_mode = |color_scheme| match color_scheme {
@@ -546,6 +559,7 @@ async fn run_instance<P>(
                 id,
                 window,
                 exit_on_close_request,
+                elastic_resize,
                 make_visible,
                 on_open,
             } => {
@@ -572,7 +586,9 @@ async fn run_instance<P>(
 
                             if let Ok(compositor) = &mut compositor {
                                 for font in default_fonts {
-                                    compositor.load_font(font.clone());
+                                    if !compositor.load_font(font.clone()) {
+                                        log::warn!("Invalid font in the settings was ignored");
+                                    }
                                 }
                             }
 
@@ -623,12 +639,14 @@ async fn run_instance<P>(
                 }
 
                 let is_first = window_manager.is_empty();
+                let direction = window_manager.direction();
                 let window = window_manager.insert(
                     id,
                     window,
                     &program,
                     compositor.as_mut().expect("Compositor must be initialized"),
                     exit_on_close_request,
+                    elastic_resize,
                     system_theme,
                 );
 
@@ -649,17 +667,18 @@ async fn run_instance<P>(
                 #[cfg(feature = "hinting")]
                 window.renderer.hint(window.state.scale_factor());
 
+                let mut cache = user_interface::Cache::default();
+                cache.set_direction(direction);
+
                 let _ = user_interfaces.insert(
                     id,
-                    build_user_interface(
-                        &program,
-                        user_interface::Cache::default(),
-                        &mut window.renderer,
-                        logical_size,
-                        id,
-                    ),
+                    build_user_interface(&program, cache, &mut window.renderer, logical_size, id),
                 );
-                let _ = ui_caches.insert(id, user_interface::Cache::default());
+
+                let mut cache = user_interface::Cache::default();
+                cache.set_direction(direction);
+
+                let _ = ui_caches.insert(id, cache);
 
                 if make_visible {
                     window.raw.set_visible(true);
@@ -677,6 +696,17 @@ async fn run_instance<P>(
                     clipboard = Clipboard::connect(window.raw.clone());
                 }
 
+                #[cfg(feature = "record")]
+                {
+                    if let Some(recorder) = &mut recorder {
+                        recorder.start();
+                    }
+
+                    if let Some(replay) = &mut replay {
+                        replay.start(proxy.clone());
+                    }
+                }
+
                 let _ = on_open.send(id);
                 is_window_opening = false;
             }
@@ -755,17 +785,12 @@ async fn run_instance<P>(
                         }
 
                         // Window was resized between redraws
-                        if window.surface_version != window.state.surface_version() {
+                        let is_resized = window.surface_version != window.state.surface_version();
+
+                        if is_resized {
                             #[cfg(feature = "hinting")]
                             window.renderer.hint(window.state.scale_factor());
 
-                            let ui = user_interfaces.remove(&id).expect("Remove user interface");
-
-                            let layout_span = debug::layout(id);
-                            let _ = user_interfaces
-                                .insert(id, ui.relayout(logical_size, &mut window.renderer));
-                            layout_span.finish();
-
                             current_compositor.configure_surface(
                                 &mut window.surface,
                                 physical_size.width,
@@ -775,14 +800,30 @@ async fn run_instance<P>(
                             window.surface_version = window.state.surface_version();
                         }
 
+                        let is_stale = user_interfaces
+                            .get(&id)
+                            .is_some_and(|ui| ui.bounds() != logical_size);
+
+                        // Elastic windows scale their last layout until resizing settles
+                        let settles_at = window.settles_at().filter(|_| is_stale);
+
+                        if (is_resized || is_stale) && settles_at.is_none() {
+                            let ui = user_interfaces.remove(&id).expect("Remove user interface");
+
+                            let layout_span = debug::layout(id);
+                            let _ = user_interfaces
+                                .insert(id, ui.relayout(logical_size, &mut window.renderer));
+                            layout_span.finish();
+                        }
+
                         let redraw_event =
                             core::Event::Window(window::Event::RedrawRequested(Instant::now()));
 
-                        let cursor = window.state.cursor();
-
                         let mut interface =
                             user_interfaces.get_mut(&id).expect("Get user interface");
 
+                        let cursor = window.cursor(interface.bounds());
+
                         let interact_span = debug::interact(id);
                         let mut redraw_count = 0;
 
@@ -892,14 +933,37 @@ async fn run_instance<P>(
                         interact_span.finish();
 
                         let draw_span = debug::draw(id);
-                        interface.draw(
-                            &mut window.renderer,
-                            window.state.theme(),
-                            &renderer::Style {
-                                text_color: window.state.text_color(),
-                            },
-                            cursor,
-                        );
+                        let bounds = interface.bounds();
+                        let background_color = window.draw_background();
+
+                        let mut draw = |renderer: &mut P::Renderer| {
+                            interface.draw(
+                                renderer,
+                                window.state.theme(),
+                                &renderer::Style {
+                                    text_color: window.state.text_color(),
+                                },
+                                cursor,
+                            );
+                        };
+
+                        if bounds == logical_size {
+                            draw(&mut window.renderer);
+                        } else {
+                            // Letterbox the stale layout, keeping its aspect ratio
+                            let scale = (logical_size.width / bounds.width)
+                                .min(logical_size.height / bounds.height);
+
+                            let translation = Transformation::translate(
+                                (logical_size.width - bounds.width * scale) / 2.0,
+                                (logical_size.height - bounds.height * scale) / 2.0,
+                            );
+
+                            window.renderer.with_transformation(
+                                translation * Transformation::scale(scale),
+                                draw,
+                            );
+                        }
                         draw_span.finish();
 
                         if let user_interface::State::Updated {
@@ -914,6 +978,10 @@ async fn run_instance<P>(
                             window.update_mouse(mouse_interaction);
                         }
 
+                        if let Some(settles_at) = settles_at {
+                            window.redraw_no_later_than(settles_at);
+                        }
+
                         runtime.broadcast(subscription::Event::Interaction {
                             window: id,
                             event: redraw_event,
@@ -927,7 +995,7 @@ async fn run_instance<P>(
                             &mut window.renderer,
                             &mut window.surface,
                             window.state.viewport(),
-                            window.state.background_color(),
+                            background_color,
                             || window.raw.pre_present_notify(),
                         ) {
                             Ok(()) => {
@@ -999,6 +1067,7 @@ async fn run_instance<P>(
 
                         match window_event {
                             winit::event::WindowEvent::Resized(_) => {
+                                window.resized_at = Some(Instant::now());
                                 window.raw.request_redraw();
                             }
                             winit::event::WindowEvent::ThemeChanged(theme) => {
@@ -1040,6 +1109,11 @@ async fn run_instance<P>(
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
+                                #[cfg(feature = "record")]
+                                if let Some(recorder) = &mut recorder {
+                                    recorder.record(id, &event);
+                                }
+
                                 events.push((id, event));
                             }
                         }
@@ -1050,6 +1124,17 @@ async fn run_instance<P>(
                             actions = 0;
                         }
 
+                        #[cfg(feature = "record")]
+                        if let Some(replay) = &replay {
+                            for (id, event) in replay.take() {
+                                if let Some(window) = window_manager.get_mut(id) {
+                                    window.state.replay(&event);
+                                }
+
+                                events.push((id, event));
+                            }
+                        }
+
                         if events.is_empty() && messages.is_empty() && window_manager.is_idle() {
                             continue;
                         }
@@ -1069,20 +1154,28 @@ async fn run_instance<P>(
                                 }
                             });
 
+                            let interface =
+                                user_interfaces.get_mut(&id).expect("Get user interface");
+
+                            // A stale layout is drawn scaled; so pointer events would
+                            // hit the wrong widgets until it is laid out again
+                            if window.is_scaling(interface.bounds()) {
+                                window_events.retain(|event| {
+                                    !matches!(event, core::Event::Mouse(_) | core::Event::Touch(_))
+                                });
+                            }
+
                             if window_events.is_empty() {
                                 continue;
                             }
 
-                            let (ui_state, statuses) = user_interfaces
-                                .get_mut(&id)
-                                .expect("Get user interface")
-                                .update(
-                                    &window_events,
-                                    window.state.cursor(),
-                                    &mut window.renderer,
-                                    &mut clipboard,
-                                    &mut messages,
-                                );
+                            let (ui_state, statuses) = interface.update(
+                                &window_events,
+                                window.cursor(interface.bounds()),
+                                &mut window.renderer,
+                                &mut clipboard,
+                                &mut messages,
+                            );
 
                             #[cfg(feature = "unconditional-rendering")]
                             window.request_redraw(window::RedrawRequest::NextFrame);
@@ -1497,6 +1590,16 @@ fn run_action<'a, P, C>(
                     window.raw.set_window_level(conversion::window_level(level));
                 }
             }
+            window::Action::SetCornerRadius(id, radius) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.set_corner_radius(radius);
+                }
+            }
+            window::Action::SetShadow(id, shadow) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.set_shadow(shadow);
+                }
+            }
             window::Action::ShowSystemMenu(id) => {
                 if let Some(window) = window_manager.get_mut(id)
                     && let mouse::Cursor::Available(point) = window.state.cursor()
@@ -1556,6 +1659,22 @@ fn run_action<'a, P, C>(
                     let _ = channel.send(size);
                 }
             }
+            window::Action::GetMonitorBounds(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let bounds = window.raw.current_monitor().map(|monitor| {
+                        let scale = window.raw.scale_factor();
+                        let position = monitor.position().to_logical::<f32>(scale);
+                        let size = monitor.size().to_logical::<f32>(scale);
+
+                        Rectangle::new(
+                            Point::new(position.x, position.y),
+                            Size::new(size.width, size.height),
+                        )
+                    });
+
+                    let _ = channel.send(bounds);
+                }
+            }
             window::Action::SetAllowAutomaticTabbing(enabled) => {
                 control_sender
                     .start_send(Control::SetAutomaticWindowTabbing(enabled))
@@ -1566,6 +1685,11 @@ fn run_action<'a, P, C>(
                     window.raw.request_redraw();
                 }
             }
+            window::Action::SetLayoutDirection(id, direction) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    rebuild_with_direction(program, interfaces, ui_caches, id, window, direction);
+                }
+            }
             window::Action::RelayoutAll => {
                 for (id, window) in window_manager.iter_mut() {
                     if let Some(ui) = interfaces.remove(&id) {
@@ -1648,10 +1772,13 @@ fn run_action<'a, P, C>(
         },
         Action::LoadFont { bytes, channel } => {
             if let Some(compositor) = compositor {
-                // TODO: Error handling (?)
-                compositor.load_font(bytes.clone());
+                let result = if compositor.load_font(bytes.clone()) {
+                    Ok(())
+                } else {
+                    Err(font::Error::Invalid)
+                };
 
-                let _ = channel.send(Ok(()));
+                let _ = channel.send(result);
             }
         }
         Action::Tick => {
@@ -1676,6 +1803,13 @@ fn run_action<'a, P, C>(
                 window.raw.request_redraw();
             }
         }
+        Action::SetDirection(direction) => {
+            window_manager.set_direction(direction);
+
+            for (id, window) in window_manager.iter_mut() {
+                rebuild_with_direction(program, interfaces, ui_caches, id, window, direction);
+            }
+        }
         Action::Exit => {
             control_sender
                 .start_send(Control::Exit)
@@ -1684,6 +1818,43 @@ fn run_action<'a, P, C>(
     }
 }
 
+/// Changes the layout [`Direction`] of the user interface of a window and
+/// rebuilds it.
+///
+/// [`Direction`]: core::layout::Direction
+fn rebuild_with_direction<'a, P, C>(
+    program: &'a program::Instance<P>,
+    interfaces: &mut FxHashMap<window::Id, UserInterface<'a, P::Message, P::Theme, P::Renderer>>,
+    ui_caches: &mut FxHashMap<window::Id, user_interface::Cache>,
+    id: window::Id,
+    window: &mut window::Window<P, C>,
+    direction: core::layout::Direction,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer>,
+    P::Theme: theme::Base,
+{
+    if let Some(cache) = ui_caches.get_mut(&id) {
+        cache.set_direction(direction);
+    }
+
+    let Some(ui) = interfaces.remove(&id) else {
+        return;
+    };
+
+    let mut cache = ui.into_cache();
+    cache.set_direction(direction);
+
+    let size = window.logical_size();
+
+    let _ = interfaces.insert(
+        id,
+        build_user_interface(program, cache, &mut window.renderer, size, id),
+    );
+
+    window.raw.request_redraw();
+}
+
 /// Build the user interface for every window.
 pub fn build_user_interfaces<'a, P: Program, C>(
     program: &'a program::Instance<P>,
@@ -0,0 +1,211 @@
+//! Record and replay the input events of an application.
+//!
+//! Recording is enabled by setting the `ICED_RECORD` environment variable
+//! to the path of a file. Every mouse, keyboard, and touch event received
+//! by any window will be appended to it as a line of JSON, together with
+//! the id of its window and the time it was received.
+//!
+//! A recording can be replayed by setting the `ICED_REPLAY` environment
+//! variable to its path. Events will be delivered with the same pacing
+//! they were recorded with, which makes it possible to reproduce layout
+//! and interaction bugs deterministically.
+//!
+//! Window ids are stable as long as the application opens its windows
+//! in the same order.
+use crate::Proxy;
+use crate::core::time::{Duration, Instant};
+use crate::core::{Event, keyboard, mouse, touch, window};
+use crate::runtime::Action;
+
+use serde::{Deserialize, Serialize};
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::mem;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A single recorded input event.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    /// The elapsed time since the recording started, in microseconds.
+    at: u64,
+    window: window::Id,
+    event: Input,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Input {
+    Mouse(mouse::Event),
+    Keyboard(keyboard::Event),
+    Touch(touch::Event),
+}
+
+impl Input {
+    fn from_event(event: &Event) -> Option<Self> {
+        Some(match event {
+            Event::Mouse(event) => Self::Mouse(*event),
+            Event::Keyboard(event) => Self::Keyboard(event.clone()),
+            Event::Touch(event) => Self::Touch(*event),
+            Event::Window(_) | Event::InputMethod(_) => None?,
+        })
+    }
+
+    fn into_event(self) -> Event {
+        match self {
+            Self::Mouse(event) => Event::Mouse(event),
+            Self::Keyboard(event) => Event::Keyboard(event),
+            Self::Touch(event) => Event::Touch(event),
+        }
+    }
+}
+
+/// Appends input events to the file in `ICED_RECORD`.
+#[derive(Debug)]
+pub struct Recorder {
+    path: PathBuf,
+    file: File,
+    started_at: Option<Instant>,
+}
+
+impl Recorder {
+    pub fn from_env() -> Option<Self> {
+        let path = PathBuf::from(env::var_os("ICED_RECORD")?);
+
+        match File::create(&path) {
+            Ok(file) => {
+                log::info!("Recording input events to {}", path.display());
+
+                Some(Self {
+                    path,
+                    file,
+                    started_at: None,
+                })
+            }
+            Err(error) => {
+                log::error!("Failed to create recording {}: {error}", path.display());
+
+                None
+            }
+        }
+    }
+
+    /// Starts the clock of the [`Recorder`], if it has not started yet.
+    pub fn start(&mut self) {
+        let _ = self.started_at.get_or_insert_with(Instant::now);
+    }
+
+    pub fn record(&mut self, window: window::Id, event: &Event) {
+        let Some(started_at) = self.started_at else {
+            return;
+        };
+
+        let Some(input) = Input::from_event(event) else {
+            return;
+        };
+
+        let entry = Entry {
+            at: started_at.elapsed().as_micros() as u64,
+            window,
+            event: input,
+        };
+
+        // Every entry is flushed right away, so a recording
+        // survives a crash of the application
+        let result = serde_json::to_string(&entry)
+            .map_err(std::io::Error::from)
+            .and_then(|line| self.file.write_all(format!("{line}\n").as_bytes()));
+
+        if let Err(error) = result {
+            log::error!("Failed to record event to {}: {error}", self.path.display());
+        }
+    }
+}
+
+/// Replays the input events of the file in `ICED_REPLAY`.
+#[derive(Debug)]
+pub struct Replay {
+    entries: Option<Vec<Entry>>,
+    pending: Arc<Mutex<Vec<(window::Id, Event)>>>,
+}
+
+impl Replay {
+    pub fn from_env() -> Option<Self> {
+        let path = PathBuf::from(env::var_os("ICED_REPLAY")?);
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                log::error!("Failed to read recording {}: {error}", path.display());
+
+                return None;
+            }
+        };
+
+        let entries = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(i, line)| match serde_json::from_str(line) {
+                Ok(entry) => Some(entry),
+                Err(error) => {
+                    log::warn!("Skipping line {} of {}: {error}", i + 1, path.display());
+
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        log::info!(
+            "Replaying {} input events from {}",
+            entries.len(),
+            path.display()
+        );
+
+        Some(Self {
+            entries: Some(entries),
+            pending: Arc::new(Mutex::new(Vec::new())),
+        })
+    }
+
+    /// Starts replaying the events, if the [`Replay`] has not started yet.
+    ///
+    /// Events are delivered in a background thread, which wakes up the
+    /// runtime with the given [`Proxy`] when they are due.
+    pub fn start<Message: Send + 'static>(&mut self, proxy: Proxy<Message>) {
+        let Some(entries) = self.entries.take() else {
+            return;
+        };
+
+        let pending = self.pending.clone();
+
+        let _ = thread::spawn(move || {
+            let started_at = Instant::now();
+
+            for entry in entries {
+                let at = started_at + Duration::from_micros(entry.at);
+                let now = Instant::now();
+
+                if at > now {
+                    thread::sleep(at - now);
+                }
+
+                pending
+                    .lock()
+                    .expect("Lock pending replay events")
+                    .push((entry.window, entry.event.into_event()));
+
+                proxy.send_action(Action::Tick);
+            }
+
+            log::info!("Replay finished");
+        });
+    }
+
+    /// Takes all the events that are due.
+    pub fn take(&self) -> Vec<(window::Id, Event)> {
+        mem::take(&mut *self.pending.lock().expect("Lock pending replay events"))
+    }
+}
@@ -6,12 +6,14 @@ pub use crate::core::window::{Event, Id, RedrawRequest, Settings};
 
 use crate::conversion;
 use crate::core::alignment;
+use crate::core::border;
 use crate::core::input_method;
+use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::theme;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
 use crate::core::{Color, InputMethod, Padding, Point, Rectangle, Size, Text, Vector};
 use crate::graphics::Compositor;
 use crate::program::{self, Program};
@@ -31,6 +33,7 @@ where
 {
     aliases: BTreeMap<winit::window::WindowId, Id>,
     entries: BTreeMap<Id, Window<P, C>>,
+    direction: layout::Direction,
 }
 
 impl<P, C> WindowManager<P, C>
@@ -43,6 +46,7 @@ where
         Self {
             aliases: BTreeMap::new(),
             entries: BTreeMap::new(),
+            direction: layout::Direction::default(),
         }
     }
 
@@ -53,6 +57,7 @@ where
         program: &program::Instance<P>,
         compositor: &mut C,
         exit_on_close_request: bool,
+        elastic_resize: Option<Duration>,
         system_theme: theme::Mode,
     ) -> &mut Window<P, C> {
         let state = State::new(program, id, &window, system_theme);
@@ -70,11 +75,14 @@ where
                 raw: window,
                 state,
                 exit_on_close_request,
+                elastic_resize,
+                resized_at: None,
                 surface,
                 surface_version,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
                 redraw_at: None,
+                corner_radius: 0.0,
                 preedit: None,
                 ime_state: None,
             },
@@ -127,6 +135,16 @@ where
         Some((id, self.get_mut(id)?))
     }
 
+    /// Returns the layout [`Direction`](layout::Direction) of new windows.
+    pub fn direction(&self) -> layout::Direction {
+        self.direction
+    }
+
+    /// Sets the layout [`Direction`](layout::Direction) of new windows.
+    pub fn set_direction(&mut self, direction: layout::Direction) {
+        self.direction = direction;
+    }
+
     pub fn last_monitor(&self) -> Option<MonitorHandle> {
         self.entries.values().last()?.raw.current_monitor()
     }
@@ -159,11 +177,14 @@ where
     pub raw: Arc<winit::window::Window>,
     pub state: State<P>,
     pub exit_on_close_request: bool,
+    pub elastic_resize: Option<Duration>,
+    pub resized_at: Option<Instant>,
     pub mouse_interaction: mouse::Interaction,
     pub surface: C::Surface,
     pub surface_version: u64,
     pub renderer: P::Renderer,
     pub redraw_at: Option<Instant>,
+    corner_radius: f32,
     preedit: Option<Preedit<P::Renderer>>,
     ime_state: Option<(Rectangle, input_method::Purpose)>,
 }
@@ -202,6 +223,91 @@ where
         }
     }
 
+    pub fn redraw_no_later_than(&mut self, at: Instant) {
+        self.redraw_at = Some(self.redraw_at.map_or(at, |redraw_at| redraw_at.min(at)));
+    }
+
+    pub fn set_corner_radius(&mut self, radius: f32) {
+        self.corner_radius = radius.max(0.0);
+
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::{CornerPreference, WindowExtWindows};
+
+            let preference = if self.corner_radius == 0.0 {
+                CornerPreference::DoNotRound
+            } else if self.corner_radius <= 4.0 {
+                CornerPreference::RoundSmall
+            } else {
+                CornerPreference::Round
+            };
+
+            self.raw.set_corner_preference(preference);
+        }
+
+        self.raw.request_redraw();
+    }
+
+    pub fn set_shadow(&self, _shadow: bool) {
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::WindowExtWindows;
+
+            self.raw.set_undecorated_shadow(_shadow);
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            use winit::platform::macos::WindowExtMacOS;
+
+            self.raw.set_has_shadow(_shadow);
+        }
+    }
+
+    /// Draws the background of the window with rounded corners, if the
+    /// compositor cannot round them; returning the color to clear the
+    /// window with.
+    pub fn draw_background(&mut self) -> Color {
+        let background_color = self.state.background_color();
+
+        if cfg!(target_os = "windows") || self.corner_radius == 0.0 {
+            return background_color;
+        }
+
+        self.renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(Point::ORIGIN, self.state.logical_size()),
+                border: border::rounded(self.corner_radius),
+                ..Default::default()
+            },
+            background_color,
+        );
+
+        Color::TRANSPARENT
+    }
+
+    /// Returns true if the window draws a stale layout of the given bounds
+    /// scaled to its current size.
+    pub fn is_scaling(&self, bounds: Size) -> bool {
+        self.elastic_resize.is_some() && bounds != self.state.logical_size()
+    }
+
+    /// Returns the cursor for a layout of the given bounds; which is
+    /// unavailable while the layout is scaled.
+    pub fn cursor(&self, bounds: Size) -> mouse::Cursor {
+        if self.is_scaling(bounds) {
+            mouse::Cursor::Unavailable
+        } else {
+            self.state.cursor()
+        }
+    }
+
+    pub fn settles_at(&self) -> Option<Instant> {
+        let settles_at = self.resized_at? + self.elastic_resize?;
+
+        (Instant::now() < settles_at).then_some(settles_at)
+    }
+
     pub fn request_input_method(&mut self, input_method: InputMethod) {
         match input_method {
             InputMethod::Disabled => {
@@ -1,6 +1,9 @@
 use crate::conversion;
 use crate::core::{Color, Size};
 use crate::core::{mouse, theme, window};
+
+#[cfg(feature = "record")]
+use crate::core::{Event, touch};
 use crate::graphics::Viewport;
 use crate::program::{self, Program};
 
@@ -179,6 +182,32 @@ where
         }
     }
 
+    /// Processes a replayed input [`Event`] and updates the [`State`] accordingly,
+    /// as if it had been received from the window.
+    #[cfg(feature = "record")]
+    pub fn replay(&mut self, event: &Event) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(
+                touch::Event::FingerPressed { position, .. }
+                | touch::Event::FingerMoved { position, .. }
+                | touch::Event::FingerLifted { position, .. }
+                | touch::Event::FingerLost { position, .. },
+            ) => {
+                let scale_factor = f64::from(self.viewport.scale_factor());
+
+                self.cursor_position = Some(winit::dpi::PhysicalPosition::new(
+                    f64::from(position.x) * scale_factor,
+                    f64::from(position.y) * scale_factor,
+                ));
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor_position = None;
+            }
+            _ => {}
+        }
+    }
+
     pub fn synchronize(
         &mut self,
         program: &program::Instance<P>,